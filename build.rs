@@ -1,6 +1,35 @@
+use std::env;
+use std::fs;
 use std::path::Path;
 use std::process::Command;
 
+/// Subsystems reported in the `features` contract metadata and by
+/// `get_capabilities`, with the Cargo feature that compiles each one in
+/// (`None` for subsystems that are always present).
+const SUBSYSTEMS: [(&str, Option<&str>); 14] = [
+    ("attestations", Some("attestations")),
+    ("quotes", Some("quotes")),
+    ("transfers", Some("transfers")),
+    ("routing", Some("routing")),
+    ("sessions", None),
+    ("credentials", None),
+    ("did_binding", None),
+    ("sponsorship", None),
+    ("intent_funding", Some("transfers")),
+    ("htlc", Some("transfers")),
+    ("oracle", Some("quotes")),
+    ("travel_rule", Some("transfers")),
+    ("compliance", Some("compliance")),
+    ("zk_kyc", Some("compliance")),
+];
+
+/// Interfaces reported by `get_capabilities`, with the Cargo features their
+/// entrypoints need.
+const INTERFACES: [(&str, &[&str]); 2] = [
+    ("AnchorKitInterface", &["attestations", "quotes", "routing"]),
+    ("sep38_quotes", &["quotes"]),
+];
+
 fn main() {
     println!("cargo:rerun-if-changed=config_schema.json");
     println!("cargo:rerun-if-changed=configs/");
@@ -10,6 +39,54 @@ fn main() {
     // Strict compile-time validation to prevent misconfiguration bugs
     validate_configs_at_build();
     validate_schema_consistency();
+
+    write_contract_features();
+}
+
+fn feature_enabled(feature: &str) -> bool {
+    env::var_os(format!("CARGO_FEATURE_{}", feature.to_uppercase())).is_some()
+}
+
+/// Generate the `interfaces`/`features` contract metadata and the matching
+/// constants for the enabled Cargo features, so the Wasm custom section and
+/// `get_capabilities` always describe what was actually compiled in.
+fn write_contract_features() {
+    let features: Vec<&str> = SUBSYSTEMS
+        .iter()
+        .filter(|(_, feature)| feature.is_none_or(feature_enabled))
+        .map(|(name, _)| *name)
+        .collect();
+    let interfaces: Vec<&str> = INTERFACES
+        .iter()
+        .filter(|(_, needs)| needs.iter().all(|feature| feature_enabled(feature)))
+        .map(|(name, _)| *name)
+        .collect();
+
+    let quoted = |names: &[&str]| {
+        names
+            .iter()
+            .map(|name| format!("{name:?}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let code = format!(
+        "contractmeta!(key = \"interfaces\", val = \"{}\");\n\
+         contractmeta!(key = \"features\", val = \"{}\");\n\n\
+         /// Interfaces this build implements, reported by `get_capabilities`.\n\
+         const CONTRACT_INTERFACES: [&str; {}] = [{}];\n\n\
+         /// Subsystems compiled into this build, reported by `get_capabilities`.\n\
+         const CONTRACT_FEATURES: [&str; {}] = [{}];\n",
+        interfaces.join(","),
+        features.join(","),
+        interfaces.len(),
+        quoted(&interfaces),
+        features.len(),
+        quoted(&features),
+    );
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("contract_features.rs"), code)
+        .expect("failed to write contract feature metadata");
 }
 
 fn validate_configs_at_build() {
@@ -1,4 +1,5 @@
-use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, BytesN, Env, String};
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env};
+use anchorkit::testutils::{attestor_public_key, classic_asset, set_time, sign_attestation};
 use anchorkit::{AnchorKitContract, ServiceType};
 
 fn main() {
@@ -8,6 +9,7 @@ fn main() {
     // Setup environment with mock transport
     let env = Env::default();
     env.mock_all_auths();
+    set_time(&env, 1_700_000_000);
 
     let contract_id = env.register_contract(None, AnchorKitContract);
     let client = anchorkit::AnchorKitContractClient::new(&env, &contract_id);
@@ -28,7 +30,7 @@ fn main() {
 
     // Step 2: Register Anchor
     println!("2️⃣  Registering anchor...");
-    client.register_attestor(&anchor);
+    client.register_attestor(&anchor, &attestor_public_key(&env));
     println!("   ✅ Anchor registered\n");
 
     // Step 3: Configure Services
@@ -42,11 +44,11 @@ fn main() {
     println!("4️⃣  Configuring supported assets...");
     let assets = vec![
         &env,
-        String::from_str(&env, "USDC"),
-        String::from_str(&env, "BTC"),
-        String::from_str(&env, "ETH"),
+        classic_asset(&env, "USDC"),
+        classic_asset(&env, "BTC"),
+        classic_asset(&env, "ETH"),
     ];
-    client.set_supported_assets(&anchor, &assets);
+    client.set_asset_support(&anchor, &ServiceType::Deposits, &assets);
     println!("   → Assets: USDC, BTC, ETH");
     println!("   ✅ Assets configured\n");
 
@@ -57,8 +59,8 @@ fn main() {
     println!("   → Amount: 1000");
 
     // Validate asset
-    let usdc = String::from_str(&env, "USDC");
-    let is_supported = client.is_asset_supported(&anchor, &usdc);
+    let usdc = classic_asset(&env, "USDC");
+    let is_supported = client.supports_asset(&anchor, &ServiceType::Deposits, &usdc);
     println!("   → Asset supported: {}", is_supported);
 
     // Generate request ID
@@ -66,13 +68,14 @@ fn main() {
     println!("   → Request ID generated");
 
     // Submit deposit attestation
+    let timestamp = env.ledger().timestamp();
     let payload_hash = BytesN::from_array(&env, &[1u8; 32]);
-    let signature = Bytes::new(&env);
+    let signature = sign_attestation(&env, &anchor, &user, timestamp, &payload_hash);
     let attestation_id = client.submit_with_request_id(
         &request_id,
         &anchor,
         &user,
-        &env.ledger().timestamp(),
+        &timestamp,
         &payload_hash,
         &signature,
     );
@@ -85,8 +88,8 @@ fn main() {
 
     let quote_id = client.submit_quote(
         &anchor,
-        &String::from_str(&env, "USDC"),
-        &String::from_str(&env, "USD"),
+        &classic_asset(&env, "USDC"),
+        &classic_asset(&env, "USD"),
         &10000, // 1.0000
         &100,   // 1%
         &100,
@@ -106,13 +109,14 @@ fn main() {
 
     let request_id2 = client.generate_request_id();
     let payload_hash2 = BytesN::from_array(&env, &[2u8; 32]);
+    let signature2 = sign_attestation(&env, &anchor, &user, timestamp, &payload_hash2);
     let attestation_id2 = client.submit_with_request_id(
         &request_id2,
         &anchor,
         &user,
-        &env.ledger().timestamp(),
+        &timestamp,
         &payload_hash2,
-        &signature,
+        &signature2,
     );
     println!("   ✅ Withdraw attestation recorded (ID: {})\n", attestation_id2);
 
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod alias_tests {
+    use crate::testutils::AnchorKitFixture;
+    use crate::{Error, ServiceType};
+    use soroban_sdk::{testutils::Address as _, Address, Env, Symbol};
+
+    #[test]
+    fn test_alias_registry() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        let other = kit.register_anchor(&[ServiceType::Deposits]);
+        let cowrie = Symbol::new(&env, "cowrie_ng");
+
+        assert_eq!(
+            kit.client.try_set_alias(&Address::generate(&env), &cowrie),
+            Err(Ok(Error::AttestorNotRegistered))
+        );
+        kit.client.set_alias(&anchor, &cowrie);
+        assert_eq!(kit.client.resolve_alias(&cowrie), anchor);
+        assert_eq!(kit.client.get_alias(&anchor), Some(cowrie.clone()));
+
+        // Aliases are unique
+        assert_eq!(
+            kit.client.try_set_alias(&other, &cowrie),
+            Err(Ok(Error::AlreadyExists))
+        );
+
+        // A new alias frees the old one
+        let renamed = Symbol::new(&env, "cowrie");
+        kit.client.set_alias(&anchor, &renamed);
+        assert_eq!(kit.client.try_resolve_alias(&cowrie), Err(Ok(Error::NotFound)));
+        kit.client.set_alias(&other, &cowrie);
+        assert_eq!(kit.client.resolve_alias(&cowrie), other);
+
+        // Admin can release a name and assign names to non-anchors
+        kit.client.release_alias(&cowrie);
+        assert_eq!(kit.client.get_alias(&other), None);
+        let contract = Address::generate(&env);
+        kit.client.set_alias_for(&contract, &cowrie);
+        assert_eq!(kit.client.resolve_alias(&cowrie), contract);
+        assert_eq!(
+            kit.client.try_release_alias(&Symbol::new(&env, "unknown")),
+            Err(Ok(Error::NotFound))
+        );
+    }
+}
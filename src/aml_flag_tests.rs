@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod aml_flag_tests {
+    use crate::testutils::{classic_asset, AnchorKitFixture};
+    use crate::{AmlFlagStatus, Asset, Error, ServiceType};
+    use soroban_sdk::{testutils::Address as _, token, Address, Bytes, BytesN, Env};
+
+    #[test]
+    fn test_large_intent_raises_flag() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        kit.client
+            .set_aml_threshold(&kit.admin, &classic_asset(&env, "USDC"), &10_000);
+
+        kit.client
+            .build_transaction_intent(&kit.deposit_intent(&anchor, "USDC", "EUR", 10_000));
+        assert_eq!(kit.client.try_get_aml_flag(&1), Err(Ok(Error::NotFound)));
+
+        let intent = kit
+            .client
+            .build_transaction_intent(&kit.deposit_intent(&anchor, "USDC", "EUR", 25_000));
+        let flag = kit.client.get_aml_flag(&1);
+        assert_eq!(flag.reference_id, intent.intent_id);
+        assert_eq!(flag.amount, 25_000);
+        assert_eq!(flag.status, AmlFlagStatus::Open);
+
+        // Thresholds are per currency
+        kit.client
+            .build_transaction_intent(&kit.deposit_intent(&anchor, "EURC", "EUR", 25_000));
+        assert_eq!(kit.client.try_get_aml_flag(&2), Err(Ok(Error::NotFound)));
+    }
+
+    #[test]
+    fn test_review_flow() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let token_id = env
+            .register_stellar_asset_contract_v2(kit.admin.clone())
+            .address();
+        kit.client.add_supported_token(&token_id);
+        kit.client
+            .set_aml_threshold(&kit.admin, &Asset::ContractToken(token_id.clone()), &500);
+
+        let sender = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token_id).mint(&sender, &1_000);
+        let preimage = Bytes::from_slice(&env, b"secret");
+        let hash: BytesN<32> = env.crypto().sha256(&preimage).into();
+        kit.client.lock_with_hash(
+            &sender,
+            &Address::generate(&env),
+            &token_id,
+            &800,
+            &hash,
+            &1_000,
+        );
+
+        let flag = kit.client.escalate_aml_flag(&kit.admin, &1);
+        assert_eq!(flag.status, AmlFlagStatus::Escalated);
+        let result = kit.client.try_escalate_aml_flag(&kit.admin, &1);
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+
+        assert_eq!(
+            kit.client.clear_aml_flag(&kit.admin, &1).status,
+            AmlFlagStatus::Cleared
+        );
+        let result = kit.client.try_clear_aml_flag(&kit.admin, &1);
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+    }
+}
@@ -0,0 +1,102 @@
+use soroban_sdk::{contracttype, Env};
+
+use crate::{errors::Error, storage::Storage, types::Asset};
+
+/// Precision assumed for assets without registered decimals (Stellar's 7).
+pub const DEFAULT_DECIMALS: u32 = 7;
+
+/// Highest precision accepted for an asset.
+pub const MAX_DECIMALS: u32 = 18;
+
+/// An amount in an asset's smallest unit together with that unit's precision,
+/// e.g. 1.5 USDC with 7 decimals is `Amount { value: 15_000_000, decimals: 7 }`.
+///
+/// Conversions between assets go through these helpers so assets with
+/// different precision are never compared or priced as raw integers.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Amount {
+    pub value: i128,
+    pub decimals: u32,
+}
+
+impl Amount {
+    pub fn new(value: i128, decimals: u32) -> Self {
+        Amount { value, decimals }
+    }
+
+    /// `value` in the smallest unit of `asset`, using its registered decimals.
+    pub fn of(env: &Env, asset: &Asset, value: i128) -> Self {
+        Amount::new(value, Self::decimals_of(env, asset))
+    }
+
+    /// Registered decimals of `asset`, or `DEFAULT_DECIMALS`.
+    pub fn decimals_of(env: &Env, asset: &Asset) -> u32 {
+        Storage::get_asset_decimals(env, asset).unwrap_or(DEFAULT_DECIMALS)
+    }
+
+    /// Express the amount with `decimals` precision. Fails with `InvalidConfig`
+    /// on overflow or if reducing precision would drop a non-zero remainder.
+    pub fn rescale(&self, decimals: u32) -> Result<Amount, Error> {
+        let scaled = self.rescale_floor(decimals)?;
+        if scaled.rescale_floor(self.decimals)?.value != self.value {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(scaled)
+    }
+
+    /// Like `rescale`, rounding toward zero when reducing precision.
+    pub fn rescale_floor(&self, decimals: u32) -> Result<Amount, Error> {
+        self.convert(1, 1, decimals)
+    }
+
+    /// Sum of two amounts at the higher of their precisions.
+    pub fn checked_add(&self, other: &Amount) -> Result<Amount, Error> {
+        let decimals = self.decimals.max(other.decimals);
+        let value = self
+            .rescale(decimals)?
+            .value
+            .checked_add(other.rescale(decimals)?.value)
+            .ok_or(Error::InvalidConfig)?;
+        Ok(Amount::new(value, decimals))
+    }
+
+    /// `self * numerator / denominator`, expressed with `decimals` precision and
+    /// rounded toward zero. Used to price amounts with quote rates (10000 = 1.0).
+    pub fn convert(
+        &self,
+        numerator: i128,
+        denominator: i128,
+        decimals: u32,
+    ) -> Result<Amount, Error> {
+        if denominator == 0 || self.decimals > MAX_DECIMALS || decimals > MAX_DECIMALS {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut num = self
+            .value
+            .checked_mul(numerator)
+            .ok_or(Error::InvalidConfig)?;
+        let mut den = denominator;
+        if decimals >= self.decimals {
+            num = num
+                .checked_mul(pow10(decimals - self.decimals))
+                .ok_or(Error::InvalidConfig)?;
+        } else {
+            den = den
+                .checked_mul(pow10(self.decimals - decimals))
+                .ok_or(Error::InvalidConfig)?;
+        }
+
+        Ok(Amount::new(num / den, decimals))
+    }
+
+    /// The value as `u64`, saturating at the bounds.
+    pub fn to_u64_saturating(&self) -> u64 {
+        self.value.clamp(0, u64::MAX as i128) as u64
+    }
+}
+
+fn pow10(exponent: u32) -> i128 {
+    10i128.pow(exponent)
+}
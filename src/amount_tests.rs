@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod amount_tests {
+    use crate::testutils::{classic_asset, AnchorKitFixture};
+    use crate::{
+        Amount, AmountSide, Error, KycThreshold, QuoteContext, QuoteRequest, ServiceType,
+        DEFAULT_DECIMALS,
+    };
+    use soroban_sdk::{token, vec, Env, Vec};
+
+    #[test]
+    fn test_rescale_and_add() {
+        let usdc = Amount::new(15_000_000, 7);
+
+        assert_eq!(usdc.rescale(2), Ok(Amount::new(150, 2)));
+        assert_eq!(usdc.rescale(9), Ok(Amount::new(1_500_000_000, 9)));
+        // Dropping a non-zero remainder is refused unless rounding is explicit
+        assert_eq!(
+            Amount::new(15_000_001, 7).rescale(2),
+            Err(Error::InvalidConfig)
+        );
+        assert_eq!(
+            Amount::new(15_000_001, 7).rescale_floor(2),
+            Ok(Amount::new(150, 2))
+        );
+        assert_eq!(
+            Amount::new(i128::MAX, 0).rescale(1),
+            Err(Error::InvalidConfig)
+        );
+
+        let sum = Amount::new(150, 2).checked_add(&usdc).unwrap();
+        assert_eq!(sum, Amount::new(30_000_000, 7));
+    }
+
+    #[test]
+    fn test_convert_applies_rate_across_decimals() {
+        // 1.5 units at 7 decimals, rate 0.92, into a 2-decimal asset
+        let sold = Amount::new(15_000_000, 7);
+        assert_eq!(sold.convert(9200, 10000, 2), Ok(Amount::new(138, 2)));
+        assert_eq!(sold.convert(1, 0, 2), Err(Error::InvalidConfig));
+    }
+
+    #[test]
+    fn test_asset_decimals_registry() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let eur = classic_asset(&env, "EUR");
+
+        assert_eq!(kit.client.get_asset_decimals(&eur), DEFAULT_DECIMALS);
+        kit.client.set_asset_decimals(&eur, &2);
+        assert_eq!(kit.client.get_asset_decimals(&eur), 2);
+
+        let result = kit.client.try_set_asset_decimals(&eur, &19);
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+    }
+
+    #[test]
+    fn test_buy_side_quote_bounds_respect_decimals() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Quotes]);
+        // USDC keeps 7 decimals, EUR is quoted in cents
+        kit.client
+            .set_asset_decimals(&classic_asset(&env, "EUR"), &2);
+        kit.client.submit_quote(
+            &anchor,
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &10000,
+            &0,
+            &1_000_000_000,
+            &2_000_000_000,
+            &3600,
+        );
+
+        let anchors = vec![&env, anchor.clone()];
+        let mut request = QuoteRequest {
+            base_asset: classic_asset(&env, "USDC"),
+            quote_asset: classic_asset(&env, "EUR"),
+            amount: 15_000,
+            operation_type: ServiceType::Quotes,
+            context: QuoteContext::Sep6,
+            amount_side: AmountSide::Buy,
+            delivery_method: None,
+            country_code: None,
+        };
+        // Buying 150.00 EUR sells 150 USDC, inside the 100..=200 USDC bounds
+        assert!(kit
+            .client
+            .try_compare_rates_for_anchors(&request, &anchors)
+            .is_ok());
+
+        // Buying 250.00 EUR sells 250 USDC
+        request.amount = 25_000;
+        let result = kit.client.try_compare_rates_for_anchors(&request, &anchors);
+        assert_eq!(result, Err(Ok(Error::NoQuotesAvailable)));
+    }
+
+    #[test]
+    fn test_kyc_thresholds_are_normalized() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::KYC]);
+        kit.client
+            .set_asset_decimals(&classic_asset(&env, "EUR"), &2);
+        let mut thresholds = Vec::new(&env);
+        thresholds.push_back(KycThreshold {
+            amount_above: 10_000_000_000,
+            level: 2,
+        });
+        kit.client.set_kyc_thresholds(&anchor, &thresholds);
+
+        // 500.00 EUR is below the 1000-unit threshold even though 50_000 > 10_000
+        // would be true for a 7-decimal asset
+        let below = kit.deposit_intent(&anchor, "EUR", "USDC", 50_000);
+        assert_eq!(kit.client.build_transaction_intent(&below).kyc_level, 0);
+
+        let above = kit.deposit_intent(&anchor, "EUR", "USDC", 150_000);
+        let result = kit.client.try_build_transaction_intent(&above);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+    }
+
+    #[test]
+    fn test_execute_intent_rescales_to_token_decimals() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        kit.client
+            .set_asset_decimals(&classic_asset(&env, "EUR"), &2);
+
+        let token = env
+            .register_stellar_asset_contract_v2(kit.admin.clone())
+            .address();
+        kit.client.add_supported_token(&token);
+        let payer = kit.user.clone();
+        token::StellarAssetClient::new(&env, &token).mint(&payer, &100_000_000);
+        let balances = token::Client::new(&env, &token);
+        balances.approve(&payer, &kit.contract_id, &100_000_000, &1000);
+
+        // 12.34 EUR at 2 decimals is 123_400_000 units of the 7-decimal token
+        let builder = kit.deposit_intent(&anchor, "EUR", "USDC", 1_234);
+        let intent_id = kit.client.build_transaction_intent(&builder).intent_id;
+        let result = kit.client.try_execute_intent(&intent_id, &payer, &token);
+        assert_eq!(result, Err(Ok(Error::InsufficientAllowance)));
+
+        balances.approve(&payer, &kit.contract_id, &200_000_000, &1000);
+        token::StellarAssetClient::new(&env, &token).mint(&payer, &100_000_000);
+        kit.client.execute_intent(&intent_id, &payer, &token);
+        assert_eq!(balances.balance(&anchor), 123_400_000);
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod anchor_profile_tests {
+    use crate::config::MAX_ATTESTORS;
+    use crate::testutils::{classic_asset, AnchorKitFixture};
+    use crate::{AnchorTier, EndpointKind, Error, ServiceType};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+    #[test]
+    fn test_profile_aggregates_anchor_state() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+
+        let profile = kit.client.get_anchor_profile(&anchor);
+        assert_eq!(profile.services.len(), 2);
+        assert_eq!(profile.tier, AnchorTier::Unrated);
+        assert!(profile.corridors.is_empty());
+
+        kit.client
+            .set_anchor_metadata(&anchor, &8500, &600, &7000, &9900, &1_000_000);
+        kit.client.update_health_status(&anchor, &120, &0, &9990);
+        kit.client.configure_endpoint(
+            &anchor,
+            &EndpointKind::Sep38,
+            &String::from_str(&env, "https://anchor.example.com/sep38"),
+            &true,
+        );
+        kit.seed_quote(&anchor, "USDC", "EUR", 9100, 60);
+        let latest_eur = kit.seed_quote(&anchor, "USDC", "EUR", 9150, 60);
+        let gbp = kit.seed_quote(&anchor, "USDC", "GBP", 7900, 60);
+
+        let profile = kit.client.get_anchor_profile(&anchor);
+        assert_eq!(profile.tier, AnchorTier::Gold);
+        assert_eq!(profile.metadata.get(0).unwrap().reputation_score, 8500);
+        assert_eq!(profile.health.get(0).unwrap().latency_ms, 120);
+        assert_eq!(profile.endpoints.len(), 1);
+        assert_eq!(profile.corridors.len(), 2);
+        assert_eq!(
+            profile.corridors.get(0).unwrap(),
+            (classic_asset(&env, "USDC"), classic_asset(&env, "EUR"))
+        );
+        assert_eq!(profile.quote_heads.len(), 2);
+        assert_eq!(profile.quote_heads.get(0).unwrap().quote_id, latest_eur);
+        assert_eq!(profile.quote_heads.get(1).unwrap().quote_id, gbp);
+    }
+
+    #[test]
+    fn test_profile_requires_registered_anchor() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let stranger = Address::generate(&env);
+
+        let result = kit.client.try_get_anchor_profile(&stranger);
+        assert_eq!(result, Err(Ok(Error::AttestorNotRegistered)));
+    }
+
+    #[test]
+    fn test_bulk_services_and_endpoints() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let first = kit.register_anchor(&[ServiceType::Deposits]);
+        let second = kit.register_anchor(&[ServiceType::Quotes, ServiceType::KYC]);
+        let unknown = Address::generate(&env);
+
+        kit.client.configure_endpoint(
+            &first,
+            &EndpointKind::Sep6,
+            &String::from_str(&env, "https://one.example.com/sep6"),
+            &true,
+        );
+        kit.client.configure_endpoint(
+            &second,
+            &EndpointKind::Sep38,
+            &String::from_str(&env, "https://two.example.com/sep38"),
+            &true,
+        );
+        kit.client.configure_endpoint(
+            &second,
+            &EndpointKind::Sep12,
+            &String::from_str(&env, "https://two.example.com/sep12"),
+            &true,
+        );
+
+        let anchors = vec![&env, first.clone(), unknown.clone(), second.clone()];
+        let services = kit.client.get_services_bulk(&anchors);
+        assert_eq!(services.len(), 3);
+        assert_eq!(services.get(0).unwrap().services.len(), 1);
+        assert_eq!(services.get(1).unwrap().anchor, unknown);
+        assert!(services.get(1).unwrap().services.is_empty());
+        assert_eq!(services.get(2).unwrap().services.len(), 2);
+
+        let endpoints = kit.client.get_endpoints_bulk(&anchors);
+        assert_eq!(endpoints.len(), 3);
+        assert_eq!(endpoints.get(0).unwrap().attestor, first);
+        assert_eq!(endpoints.get(2).unwrap().kind, EndpointKind::Sep12);
+    }
+
+    #[test]
+    fn test_bulk_queries_are_capped() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let mut anchors = Vec::new(&env);
+        for _ in 0..=MAX_ATTESTORS {
+            anchors.push_back(Address::generate(&env));
+        }
+
+        let result = kit.client.try_get_services_bulk(&anchors);
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+        let result = kit.client.try_get_endpoints_bulk(&anchors);
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+    }
+}
@@ -0,0 +1,450 @@
+use std::path::Path;
+use std::process::Command;
+
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::signer::Signer;
+use crate::stellar_cli::{self, Network};
+
+/// stellar.toml keys of the endpoints AnchorKit tracks, with their
+/// `EndpointKind` as encoded by the contract
+const ENDPOINT_KEYS: [(&str, u32); 4] = [
+    ("TRANSFER_SERVER", 1),
+    ("TRANSFER_SERVER_SEP0024", 2),
+    ("ANCHOR_QUOTE_SERVER", 3),
+    ("KYC_SERVER", 4),
+];
+
+/// Seconds allowed for each stellar.toml download
+const FETCH_TIMEOUT: &str = "10";
+
+/// One anchor to sync: its home domain and, optionally, its own signer
+pub struct AnchorEntry {
+    pub domain: String,
+    /// Signer for this anchor's account; `SyncOptions::signer` when unset
+    pub signer: Option<Signer>,
+}
+
+pub struct SyncOptions {
+    pub contract_id: String,
+    /// Default signer; each anchor's transactions are signed by its own account
+    pub signer: Signer,
+    pub network: Network,
+    pub anchors: Vec<AnchorEntry>,
+    /// Report the changes without submitting them
+    pub dry_run: bool,
+}
+
+/// What syncing one anchor found and changed
+pub struct SyncReport {
+    pub domain: String,
+    pub attestor: String,
+    pub toml_hash: [u8; 32],
+    /// Human-readable changes, submitted unless this was a dry run
+    pub changes: Vec<String>,
+}
+
+/// Read anchors from a file with one `DOMAIN [SIGNER]` per line. Blank lines
+/// and `#` comments are skipped.
+pub fn read_anchor_file(path: &Path) -> Result<Vec<AnchorEntry>, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    contents
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let mut fields = line.split_whitespace();
+            let domain = fields.next().unwrap_or_default().to_string();
+            let signer = fields.next().map(Signer::parse).transpose()?;
+            if fields.next().is_some() {
+                return Err(format!("expected `DOMAIN [SIGNER]`, got `{}`", line));
+            }
+            Ok(AnchorEntry { domain, signer })
+        })
+        .collect()
+}
+
+/// Sync every anchor, continuing past failures. Returns one result per anchor.
+pub fn sync(options: &SyncOptions) -> Result<Vec<Result<SyncReport, String>>, String> {
+    stellar_cli::require_cli()?;
+    Ok(options
+        .anchors
+        .iter()
+        .map(|anchor| sync_anchor(options, anchor).map_err(|e| format!("{}: {}", anchor.domain, e)))
+        .collect())
+}
+
+/// Align one anchor's on-chain endpoints and TOML hash with its stellar.toml.
+fn sync_anchor(options: &SyncOptions, anchor: &AnchorEntry) -> Result<SyncReport, String> {
+    let signer = anchor.signer.as_ref().unwrap_or(&options.signer);
+    let attestor = signer.address()?;
+    let source = signer.source_arg()?;
+    let call = |function: &str, args: &[(&str, Value)]| {
+        let args: Vec<(&str, String)> = args
+            .iter()
+            .map(|(name, value)| (*name, value.to_string()))
+            .collect();
+        stellar_cli::invoke_args(
+            &options.contract_id,
+            &source,
+            &options.network,
+            function,
+            &args,
+        )
+    };
+
+    let toml = fetch_stellar_toml(&anchor.domain)?;
+    let toml_hash: [u8; 32] = Sha256::digest(&toml).into();
+    let fields = parse_top_level(&String::from_utf8_lossy(&toml));
+
+    let endpoints: Value = serde_json::from_str(&stellar_cli::simulate(
+        &options.contract_id,
+        &source,
+        &options.network,
+        "list_endpoints",
+        &[("attestor", json!(attestor).to_string())],
+    )?)
+    .map_err(|e| format!("unexpected list_endpoints output: {}", e))?;
+    let anchored: Value = serde_json::from_str(&stellar_cli::simulate(
+        &options.contract_id,
+        &source,
+        &options.network,
+        "get_toml_hash",
+        &[("attestor", json!(attestor).to_string())],
+    )?)
+    .map_err(|e| format!("unexpected get_toml_hash output: {}", e))?;
+
+    let changes = diff(&fields, &toml_hash, &endpoints, &anchored);
+    let transactions = changes
+        .iter()
+        .map(|change| {
+            let (function, args) = change.call(&attestor);
+            call(function, &args)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if !options.dry_run {
+        for args in &transactions {
+            signer.submit(args, &options.network)?;
+        }
+    }
+
+    Ok(SyncReport {
+        domain: anchor.domain.clone(),
+        attestor,
+        toml_hash,
+        changes: changes.iter().map(Change::to_string).collect(),
+    })
+}
+
+/// A difference between an anchor's stellar.toml and its on-chain entries
+#[derive(Debug, PartialEq)]
+enum Change {
+    /// Add, update or reactivate a published endpoint
+    Configure {
+        key: &'static str,
+        kind: u32,
+        url: String,
+    },
+    /// Deactivate an endpoint the stellar.toml no longer publishes
+    Deactivate { key: &'static str, kind: u32 },
+    /// Anchor the stellar.toml's current hash
+    TomlHash([u8; 32]),
+}
+
+impl Change {
+    /// Contract function and arguments that apply this change for `attestor`.
+    fn call(&self, attestor: &str) -> (&'static str, Vec<(&'static str, Value)>) {
+        match self {
+            Self::Configure { kind, url, .. } => (
+                "configure_endpoint",
+                vec![
+                    ("attestor", json!(attestor)),
+                    ("kind", json!(kind)),
+                    ("url", json!(url)),
+                    ("is_active", json!(true)),
+                ],
+            ),
+            Self::Deactivate { kind, .. } => (
+                "set_endpoint_active",
+                vec![
+                    ("attestor", json!(attestor)),
+                    ("kind", json!(kind)),
+                    ("is_active", json!(false)),
+                ],
+            ),
+            Self::TomlHash(hash) => (
+                "set_toml_hash",
+                vec![
+                    ("attestor", json!(attestor)),
+                    ("toml_hash", json!(hex::encode(hash))),
+                ],
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Configure { key, url, .. } => write!(f, "configure {} = {}", key, url),
+            Self::Deactivate { key, .. } => write!(f, "deactivate {} (no longer published)", key),
+            Self::TomlHash(hash) => write!(f, "anchor stellar.toml hash {}", hex::encode(hash)),
+        }
+    }
+}
+
+/// Changes that align the anchor's endpoints (`list_endpoints` output) and
+/// anchored hash (`get_toml_hash` output) with its stellar.toml `fields`.
+fn diff(
+    fields: &[(String, String)],
+    toml_hash: &[u8; 32],
+    endpoints: &Value,
+    anchored: &Value,
+) -> Vec<Change> {
+    let on_chain = |kind: u32| {
+        endpoints
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|endpoint| stellar_cli::json_u64(endpoint, "kind") == Some(u64::from(kind)))
+    };
+
+    let mut changes = Vec::new();
+    for (key, kind) in ENDPOINT_KEYS {
+        let published = fields
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, url)| url);
+        match (published, on_chain(kind)) {
+            (Some(url), Some(endpoint))
+                if endpoint["url"] == json!(url) && endpoint["is_active"] == json!(true) => {}
+            (Some(url), _) => changes.push(Change::Configure {
+                key,
+                kind,
+                url: url.clone(),
+            }),
+            (None, Some(endpoint)) if endpoint["is_active"] == json!(true) => {
+                changes.push(Change::Deactivate { key, kind })
+            }
+            (None, _) => {}
+        }
+    }
+    if anchored["hash"] != json!(hex::encode(toml_hash)) {
+        changes.push(Change::TomlHash(*toml_hash));
+    }
+    changes
+}
+
+/// Download `https://DOMAIN/.well-known/stellar.toml` with curl.
+fn fetch_stellar_toml(domain: &str) -> Result<Vec<u8>, String> {
+    let valid = !domain.is_empty()
+        && domain
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == ':');
+    if !valid {
+        return Err("home domain must be a bare host name, e.g. anchor.example.com".to_string());
+    }
+
+    let url = format!("https://{}/.well-known/stellar.toml", domain);
+    let output = Command::new("curl")
+        .args([
+            "-fsSL",
+            "--proto",
+            "=https",
+            "--max-time",
+            FETCH_TIMEOUT,
+            &url,
+        ])
+        .output()
+        .map_err(|e| format!("failed to run curl: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "failed to fetch {}: {}",
+            url,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(output.stdout)
+}
+
+/// Top-level `KEY = "string"` entries of a stellar.toml, before the first
+/// table. Enough for the endpoint URLs; other values are skipped.
+fn parse_top_level(toml: &str) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    for line in toml.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            break;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim();
+        let quote = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => quote,
+            _ => continue,
+        };
+        if let Some(end) = value[1..].find(quote) {
+            fields.push((key.trim().to_string(), value[1..1 + end].to_string()));
+        }
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STELLAR_TOML: &str = include_str!("../tests/fixtures/anchor_sync/stellar.toml");
+    const LIST_ENDPOINTS: &str = include_str!("../tests/fixtures/anchor_sync/list_endpoints.json");
+    const ATTESTOR: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+
+    fn fixture() -> (Vec<(String, String)>, [u8; 32], Value) {
+        (
+            parse_top_level(STELLAR_TOML),
+            Sha256::digest(STELLAR_TOML.as_bytes()).into(),
+            serde_json::from_str(LIST_ENDPOINTS).unwrap(),
+        )
+    }
+
+    fn field<'a>(fields: &'a [(String, String)], key: &str) -> Option<&'a str> {
+        fields
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[test]
+    fn test_parse_top_level() {
+        let fields = parse_top_level(STELLAR_TOML);
+        assert_eq!(field(&fields, "VERSION"), Some("2.0.0"));
+        assert_eq!(
+            field(&fields, "TRANSFER_SERVER"),
+            Some("https://anchor.example.com/sep6")
+        );
+        assert_eq!(
+            field(&fields, "TRANSFER_SERVER_SEP0024"),
+            Some("https://anchor.example.com/sep24")
+        );
+        assert_eq!(
+            field(&fields, "KYC_SERVER"),
+            Some("https://anchor.example.com/kyc")
+        );
+        // Arrays are skipped, and tables end the top level
+        assert_eq!(field(&fields, "ACCOUNTS"), None);
+        assert_eq!(field(&fields, "ORG_NAME"), None);
+        assert_eq!(field(&fields, "ANCHOR_QUOTE_SERVER"), None);
+    }
+
+    #[test]
+    fn test_diff_against_on_chain_state() {
+        let (fields, toml_hash, endpoints) = fixture();
+        let anchored = json!({ "hash": hex::encode(toml_hash), "updated_at": 1700000000 });
+
+        assert_eq!(
+            diff(&fields, &toml_hash, &endpoints, &anchored),
+            [
+                // Published but inactive on chain
+                Change::Configure {
+                    key: "TRANSFER_SERVER_SEP0024",
+                    kind: 2,
+                    url: "https://anchor.example.com/sep24".to_string(),
+                },
+                // No longer published
+                Change::Deactivate {
+                    key: "ANCHOR_QUOTE_SERVER",
+                    kind: 3,
+                },
+                // Moved
+                Change::Configure {
+                    key: "KYC_SERVER",
+                    kind: 4,
+                    url: "https://anchor.example.com/kyc".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_of_a_new_anchor() {
+        let (fields, toml_hash, _) = fixture();
+        let changes = diff(&fields, &toml_hash, &json!([]), &Value::Null);
+        assert_eq!(
+            changes.iter().map(Change::to_string).collect::<Vec<_>>(),
+            [
+                "configure TRANSFER_SERVER = https://anchor.example.com/sep6".to_string(),
+                "configure TRANSFER_SERVER_SEP0024 = https://anchor.example.com/sep24".to_string(),
+                "configure KYC_SERVER = https://anchor.example.com/kyc".to_string(),
+                format!("anchor stellar.toml hash {}", hex::encode(toml_hash)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_synced_anchor_has_no_changes() {
+        let (fields, toml_hash, _) = fixture();
+        let endpoints: Vec<Value> = ENDPOINT_KEYS
+            .iter()
+            .filter_map(|(key, kind)| {
+                let url = field(&fields, key)?;
+                Some(json!({ "url": url, "attestor": ATTESTOR, "kind": kind, "is_active": true }))
+            })
+            .collect();
+        let anchored = json!({ "hash": hex::encode(toml_hash), "updated_at": 1700000000 });
+        assert!(diff(&fields, &toml_hash, &json!(endpoints), &anchored).is_empty());
+
+        // Inactive endpoints that are no longer published stay as they are
+        let stale = json!([{ "url": "https://old.example.com", "kind": 3, "is_active": false }]);
+        assert_eq!(
+            diff(&fields, &toml_hash, &stale, &anchored),
+            diff(&fields, &toml_hash, &json!([]), &anchored)
+        );
+    }
+
+    #[test]
+    fn test_change_calls() {
+        let (function, args) = Change::Deactivate {
+            key: "KYC_SERVER",
+            kind: 4,
+        }
+        .call(ATTESTOR);
+        assert_eq!(function, "set_endpoint_active");
+        assert_eq!(
+            args,
+            [
+                ("attestor", json!(ATTESTOR)),
+                ("kind", json!(4)),
+                ("is_active", json!(false)),
+            ]
+        );
+
+        let (function, args) = Change::TomlHash([0xab; 32]).call(ATTESTOR);
+        assert_eq!(function, "set_toml_hash");
+        assert_eq!(args[1], ("toml_hash", json!("ab".repeat(32))));
+    }
+
+    #[test]
+    fn test_read_anchor_file() {
+        let path = std::env::temp_dir().join(format!("anchorkit-anchors-{}", std::process::id()));
+        std::fs::write(
+            &path,
+            "# anchors to sync\nanchor.example.com\n\nramp.example.com keystore:ramp.json # own key\n",
+        )
+        .unwrap();
+        let anchors = read_anchor_file(&path).unwrap();
+        assert_eq!(anchors.len(), 2);
+        assert_eq!(anchors[0].domain, "anchor.example.com");
+        assert!(anchors[0].signer.is_none());
+        assert_eq!(anchors[1].domain, "ramp.example.com");
+        assert!(matches!(anchors[1].signer, Some(Signer::Keystore { .. })));
+
+        std::fs::write(&path, "anchor.example.com alice bob\n").unwrap();
+        assert_eq!(
+            read_anchor_file(&path).err(),
+            Some("expected `DOMAIN [SIGNER]`, got `anchor.example.com alice bob`".to_string())
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod asset_support_tests {
+    use crate::testutils::{classic_asset, AnchorKitFixture};
+    use crate::{AssetSupport, Error, ServiceType};
+    use soroban_sdk::{vec, Env};
+
+    #[test]
+    fn test_asset_support_is_enforced_per_service() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[
+            ServiceType::Deposits,
+            ServiceType::Withdrawals,
+            ServiceType::Quotes,
+        ]);
+        let usdc = classic_asset(&env, "USDC");
+        let eurc = classic_asset(&env, "EURC");
+
+        kit.client.set_asset_support(
+            &anchor,
+            &ServiceType::Deposits,
+            &vec![&env, usdc.clone(), eurc.clone()],
+        );
+        kit.client.set_asset_support(
+            &anchor,
+            &ServiceType::Withdrawals,
+            &vec![&env, usdc.clone()],
+        );
+        assert_eq!(
+            kit.client.get_asset_support(&anchor).get(1),
+            Some(AssetSupport {
+                service_type: ServiceType::Withdrawals,
+                assets: vec![&env, usdc.clone()],
+            })
+        );
+
+        let withdrawal = |base: &str| {
+            let mut builder = kit.deposit_intent(&anchor, base, "EUR", 500);
+            builder.request.operation_type = ServiceType::Withdrawals;
+            builder
+        };
+        kit.client
+            .build_transaction_intent(&kit.deposit_intent(&anchor, "EURC", "EUR", 500));
+        kit.client.build_transaction_intent(&withdrawal("USDC"));
+        assert_eq!(
+            kit.client.try_build_transaction_intent(&withdrawal("EURC")),
+            Err(Ok(Error::UnsupportedAsset))
+        );
+        assert!(!kit
+            .client
+            .supports_asset(&anchor, &ServiceType::Withdrawals, &eurc));
+
+        // Quotes stay unrestricted until narrowed
+        kit.seed_quote(&anchor, "EURC", "EUR", 9_900, 60);
+        kit.client
+            .set_asset_support(&anchor, &ServiceType::Quotes, &vec![&env, usdc.clone()]);
+        assert_eq!(
+            kit.client.try_submit_quote(
+                &anchor,
+                &eurc,
+                &classic_asset(&env, "EUR"),
+                &9_900,
+                &0,
+                &1,
+                &u64::MAX,
+                &60,
+            ),
+            Err(Ok(Error::UnsupportedAsset))
+        );
+
+        // An empty list lifts the restriction
+        kit.client
+            .set_asset_support(&anchor, &ServiceType::Withdrawals, &vec![&env]);
+        kit.client.build_transaction_intent(&withdrawal("EURC"));
+    }
+
+    #[test]
+    fn test_asset_support_requires_configured_service() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        let usdc = classic_asset(&env, "USDC");
+
+        assert_eq!(
+            kit.client.try_set_asset_support(
+                &anchor,
+                &ServiceType::Withdrawals,
+                &vec![&env, usdc.clone()]
+            ),
+            Err(Ok(Error::InvalidServiceType))
+        );
+        assert_eq!(
+            kit.client.try_set_asset_support(
+                &anchor,
+                &ServiceType::Deposits,
+                &vec![&env, usdc.clone(), usdc]
+            ),
+            Err(Ok(Error::InvalidConfig))
+        );
+    }
+}
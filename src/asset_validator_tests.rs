@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod asset_validator_tests {
+    use crate::testutils::attestor_public_key;
     use crate::{AnchorKitContract, AnchorKitContractClient, Error, ServiceType};
     use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
 
@@ -14,7 +15,7 @@ mod asset_validator_tests {
         let anchor = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         let assets = vec![
             &env,
@@ -41,7 +42,7 @@ mod asset_validator_tests {
         let anchor = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         let assets = vec![
             &env,
@@ -67,7 +68,7 @@ mod asset_validator_tests {
         let anchor = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         let assets = vec![
             &env,
@@ -97,7 +98,7 @@ mod asset_validator_tests {
         let anchor = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         let assets = vec![&env, String::from_str(&env, "USDC")];
 
@@ -123,7 +124,7 @@ mod asset_validator_tests {
         let anchor = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         let assets = vec![&env, String::from_str(&env, "USD")];
 
@@ -149,7 +150,7 @@ mod asset_validator_tests {
         let anchor = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         // Don't configure assets
 
@@ -173,7 +174,7 @@ mod asset_validator_tests {
         let anchor = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         let services = vec![&env, ServiceType::Quotes];
         client.configure_services(&anchor, &services);
@@ -210,7 +211,7 @@ mod asset_validator_tests {
         let anchor = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         let services = vec![&env, ServiceType::Quotes];
         client.configure_services(&anchor, &services);
@@ -0,0 +1,234 @@
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use sha2::{Digest, Sha256};
+use stellar_strkey::Strkey;
+use stellar_xdr::curr::{AccountId, Hash, Limits, PublicKey, ScAddress, ScVal, Uint256, WriteXdr};
+
+use crate::signer::Signer;
+use crate::stellar_cli;
+
+/// Must match `serialization::ATTESTATION_DOMAIN` in the contract
+const ATTESTATION_DOMAIN: &[u8] = b"anchorkit:attestation:v1";
+/// Must match `serialization::ATTESTATION_SIGNATURE_DOMAIN` in the contract
+const ATTESTATION_SIGNATURE_DOMAIN: &[u8] = b"anchorkit:attestation-sig:v1";
+
+/// What to sign, and with which local key
+pub struct SignOptions {
+    /// File holding the raw attestation payload
+    pub payload: PathBuf,
+    /// Secret key, a file containing one, or a `keystore:`/`external:` signer
+    pub key: String,
+    /// Attestor a delegate's key signs for; the key's own account when unset
+    pub attestor: Option<String>,
+    pub subject: String,
+    pub session_id: u64,
+    /// Attestation timestamp; defaults to now
+    pub timestamp: Option<u64>,
+    /// 16-byte correlation ID, hex-encoded
+    pub correlation_id: Option<String>,
+}
+
+/// Arguments for `submit_attestation_with_session`, signed by the issuer
+pub struct SignedAttestation {
+    pub session_id: u64,
+    pub issuer: String,
+    pub subject: String,
+    pub timestamp: u64,
+    pub payload_hash: [u8; 32],
+    pub signature: [u8; 64],
+    pub correlation_id: Option<[u8; 16]>,
+}
+
+impl SignedAttestation {
+    /// `stellar contract invoke` arguments following `--`.
+    pub fn invoke_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "submit_attestation_with_session".to_string(),
+            "--session_id".to_string(),
+            self.session_id.to_string(),
+            "--issuer".to_string(),
+            self.issuer.clone(),
+            "--subject".to_string(),
+            self.subject.clone(),
+            "--timestamp".to_string(),
+            self.timestamp.to_string(),
+            "--payload_hash".to_string(),
+            hex::encode(self.payload_hash),
+            "--signature".to_string(),
+            hex::encode(self.signature),
+        ];
+        if let Some(correlation_id) = self.correlation_id {
+            args.push("--correlation_id".to_string());
+            args.push(hex::encode(correlation_id));
+        }
+        args
+    }
+}
+
+/// Hash the payload the way the contract expects and sign the hash with the
+/// issuer's ed25519 key. The issuer is the key's account address; a delegate
+/// signs over the address of the attestor it submits for.
+pub fn sign(options: &SignOptions) -> Result<SignedAttestation, String> {
+    let payload = std::fs::read(&options.payload)
+        .map_err(|e| format!("failed to read {}: {}", options.payload.display(), e))?;
+    let signer = match Signer::parse(&options.key)? {
+        Signer::Identity(key) => Signer::Identity(load_secret_key(&key)?),
+        signer => signer,
+    };
+
+    if address_xdr(&options.subject).is_none() {
+        return Err(format!(
+            "subject {} must be an account (G...) or contract (C...) address",
+            options.subject
+        ));
+    }
+
+    let timestamp = match options.timestamp {
+        Some(timestamp) => timestamp,
+        None => SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| format!("system clock is before the epoch: {}", e))?
+            .as_secs(),
+    };
+    if timestamp == 0 {
+        return Err("--timestamp must be non-zero".to_string());
+    }
+
+    let correlation_id = match &options.correlation_id {
+        Some(hex) => Some(
+            hex::decode(hex)
+                .ok()
+                .and_then(|bytes| <[u8; 16]>::try_from(bytes).ok())
+                .filter(|bytes| *bytes != [0; 16])
+                .ok_or_else(|| format!("correlation ID {} must be 16 non-zero bytes in hex", hex))?,
+        ),
+        None => None,
+    };
+
+    let payload_hash = payload_hash(&payload);
+    let issuer = signer.address()?;
+    let attestor = options.attestor.as_ref().unwrap_or(&issuer);
+    let digest = signing_digest(attestor, &options.subject, timestamp, &payload_hash)?;
+    Ok(SignedAttestation {
+        session_id: options.session_id,
+        subject: options.subject.clone(),
+        timestamp,
+        signature: signer.sign_hash(&digest, "attestation")?,
+        issuer,
+        payload_hash,
+        correlation_id,
+    })
+}
+
+/// The contract's `attestation_signing_digest`: what the issuer signs.
+fn signing_digest(
+    issuer: &str,
+    subject: &str,
+    timestamp: u64,
+    payload_hash: &[u8; 32],
+) -> Result<[u8; 32], String> {
+    let issuer = address_xdr(issuer).ok_or_else(|| format!("invalid issuer {}", issuer))?;
+    let subject = address_xdr(subject).ok_or_else(|| format!("invalid subject {}", subject))?;
+    let mut hasher = Sha256::new();
+    hasher.update(ATTESTATION_SIGNATURE_DOMAIN);
+    hasher.update(issuer);
+    hasher.update(subject);
+    hasher.update(timestamp.to_be_bytes());
+    hasher.update(payload_hash);
+    Ok(hasher.finalize().into())
+}
+
+/// `ScVal` XDR of an account (G...) or contract (C...) address.
+fn address_xdr(address: &str) -> Option<Vec<u8>> {
+    let address = match Strkey::from_string(address).ok()? {
+        Strkey::PublicKeyEd25519(key) => {
+            ScAddress::Account(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(key.0))))
+        }
+        Strkey::Contract(contract) => ScAddress::Contract(Hash(contract.0)),
+        _ => return None,
+    };
+    ScVal::Address(address).to_xdr(Limits::none()).ok()
+}
+
+/// `sha256(ATTESTATION_DOMAIN || payload)`, the contract's canonical payload hash.
+fn payload_hash(payload: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(ATTESTATION_DOMAIN);
+    hasher.update(payload);
+    hasher.finalize().into()
+}
+
+/// A secret key given inline or read from a key file.
+fn load_secret_key(key: &str) -> Result<String, String> {
+    if stellar_cli::is_secret_key(key) {
+        return Ok(key.to_string());
+    }
+    let secret = std::fs::read_to_string(key)
+        .map_err(|_| {
+            "--key must be a secret key (S...), a file containing one, or a keystore:/external: signer"
+                .to_string()
+        })?
+        .trim()
+        .to_string();
+    if !stellar_cli::is_secret_key(&secret) {
+        return Err("invalid secret key".to_string());
+    }
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signature, SigningKey, Verifier};
+
+    const ATTESTOR: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+    const SUBJECT: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+
+    fn sign_payload(attestor: Option<&str>) -> (SignedAttestation, SigningKey) {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let payload = std::env::temp_dir().join(format!(
+            "anchorkit-payload-{}-{}",
+            std::process::id(),
+            attestor.unwrap_or("self")
+        ));
+        std::fs::write(&payload, b"{\"kyc\":\"approved\"}").unwrap();
+        let signed = sign(&SignOptions {
+            payload: payload.clone(),
+            key: stellar_strkey::ed25519::PrivateKey(key.to_bytes()).to_string(),
+            attestor: attestor.map(str::to_string),
+            subject: SUBJECT.to_string(),
+            session_id: 7,
+            timestamp: Some(1_700_000_000),
+            correlation_id: None,
+        })
+        .unwrap();
+        std::fs::remove_file(payload).unwrap();
+        (signed, key)
+    }
+
+    fn verifies_for(signed: &SignedAttestation, key: &SigningKey, attestor: &str) -> bool {
+        let digest =
+            signing_digest(attestor, SUBJECT, 1_700_000_000, &signed.payload_hash).unwrap();
+        key.verifying_key()
+            .verify(&digest, &Signature::from_bytes(&signed.signature))
+            .is_ok()
+    }
+
+    #[test]
+    fn test_issuer_signs_over_its_own_address() {
+        let (signed, key) = sign_payload(None);
+        assert_eq!(signed.payload_hash, payload_hash(b"{\"kyc\":\"approved\"}"));
+        assert!(verifies_for(&signed, &key, &signed.issuer));
+    }
+
+    #[test]
+    fn test_delegate_signs_over_the_attestor() {
+        let (signed, key) = sign_payload(Some(ATTESTOR));
+        // The delegate submits, but the signature covers the attestor the
+        // attestation is recorded under
+        assert_ne!(signed.issuer, ATTESTOR);
+        assert!(verifies_for(&signed, &key, ATTESTOR));
+        assert!(!verifies_for(&signed, &key, &signed.issuer));
+    }
+}
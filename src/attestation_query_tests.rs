@@ -0,0 +1,427 @@
+#[cfg(test)]
+mod attestation_query_tests {
+    use crate::storage::Storage;
+    use crate::testutils::{attestor_public_key, sign_attestation};
+    use crate::{
+        serialization::{attestation_payload_hash, attestation_signing_digest},
+        AnchorKitClient, AnchorKitContract, AnchorKitContractClient, Error, ReplayScope, Role,
+    };
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, Bytes, BytesN, Env,
+    };
+
+    fn setup(env: &Env) -> (Address, AnchorKitContractClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let attestor = Address::generate(env);
+        client.initialize(&admin);
+        client.register_attestor(&attestor, &attestor_public_key(env));
+
+        (contract_id, client, attestor)
+    }
+
+    #[test]
+    fn test_get_attestation_and_existence_by_hash() {
+        let env = Env::default();
+        let (contract_id, client, attestor) = setup(&env);
+        let subject = Address::generate(&env);
+        let payload_hash = BytesN::from_array(&env, &[7; 32]);
+        assert!(!client.is_attestation_recorded(&payload_hash));
+
+        let session_id = client.create_session(&subject);
+        let id = client.submit_attestation_with_session(
+            &session_id,
+            &attestor,
+            &subject,
+            &1_700_000_000u64,
+            &payload_hash,
+            &sign_attestation(&env, &attestor, &subject, 1_700_000_000u64, &payload_hash),
+            &None,
+        );
+
+        let attestation = client.get_attestation(&subject, &id);
+        assert_eq!(attestation.issuer, attestor);
+        assert_eq!(attestation.subject, subject);
+        assert_eq!(attestation.payload_hash, payload_hash);
+        assert!(client.is_attestation_recorded(&payload_hash));
+
+        // Other contracts read the same data through the interface client
+        let reader = AnchorKitClient::new(&env, &contract_id);
+        assert_eq!(reader.get_attestation(&subject, &id), attestation);
+        assert!(reader.attestation_exists(&id));
+        assert!(reader.is_attestation_recorded(&payload_hash));
+    }
+
+    #[test]
+    fn test_hash_attestation_payload_is_canonical() {
+        let env = Env::default();
+        let (contract_id, client, _) = setup(&env);
+        let payload = Bytes::from_slice(&env, b"hello");
+
+        let hash = client.hash_attestation_payload(&payload);
+        assert_eq!(hash, attestation_payload_hash(&env, &payload));
+        assert_eq!(
+            AnchorKitClient::new(&env, &contract_id).hash_attestation_payload(&payload),
+            hash
+        );
+    }
+
+    #[test]
+    fn test_get_unknown_attestation() {
+        let env = Env::default();
+        let (_, client, _) = setup(&env);
+
+        let result = client.try_get_attestation(&Address::generate(&env), &42);
+        assert_eq!(result, Err(Ok(Error::AttestationNotFound)));
+        assert!(!client.attestation_exists(&42));
+    }
+
+    #[test]
+    fn test_attestation_details_need_read_access() {
+        let env = Env::default();
+        let (contract_id, client, attestor) = setup(&env);
+        let admin = env.as_contract(&contract_id, || Storage::get_admin(&env).unwrap());
+        let subject = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        let payload_hash = BytesN::from_array(&env, &[3; 32]);
+        let id = client.submit_attestation_tracked(
+            &attestor,
+            &subject,
+            &1_700_000_000u64,
+            &payload_hash,
+            &sign_attestation(&env, &attestor, &subject, 1_700_000_000u64, &payload_hash),
+        );
+
+        assert!(client.can_read_attestation(&subject, &id));
+        assert!(client.can_read_attestation(&attestor, &id));
+        assert!(!client.can_read_attestation(&verifier, &id));
+        assert_eq!(client.get_attestation(&attestor, &id).subject, subject);
+        assert_eq!(client.get_attestation(&admin, &id).subject, subject);
+        assert_eq!(
+            client.try_get_attestation(&verifier, &id),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(
+            client.try_get_latest_subject_attestation(&verifier, &subject),
+            Err(Ok(Error::Unauthorized))
+        );
+        // Anyone can still tell that it exists
+        assert!(client.attestation_exists(&id));
+
+        let now = env.ledger().timestamp();
+        assert_eq!(
+            client.try_grant_attestation_access(&subject, &verifier, &now),
+            Err(Ok(Error::InvalidTimestamp))
+        );
+        client.grant_attestation_access(&subject, &verifier, &(now + 3600));
+        assert!(client.has_attestation_access(&subject, &verifier));
+        assert_eq!(client.get_attestation(&verifier, &id).subject, subject);
+        assert_eq!(
+            client.get_latest_subject_attestation(&verifier, &subject).id,
+            id
+        );
+
+        // Grants lapse on their own and can be revoked early
+        env.ledger().with_mut(|li| li.timestamp = now + 3600);
+        assert_eq!(
+            client.try_get_attestation(&verifier, &id),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.grant_attestation_access(&subject, &verifier, &(now + 7200));
+        client.revoke_attestation_access(&subject, &verifier);
+        assert!(!client.has_attestation_access(&subject, &verifier));
+        assert_eq!(
+            client.try_revoke_attestation_access(&subject, &verifier),
+            Err(Ok(Error::NotFound))
+        );
+
+        let officer = Address::generate(&env);
+        client.grant_role(&officer, &Role::ComplianceOfficer);
+        assert_eq!(client.get_attestation(&officer, &id).subject, subject);
+        assert_eq!(
+            client.try_can_read_attestation(&officer, &42),
+            Err(Ok(Error::AttestationNotFound))
+        );
+    }
+
+    #[test]
+    fn test_sweep_expired_attestation_grants() {
+        let env = Env::default();
+        let (_, client, _) = setup(&env);
+        let subject = Address::generate(&env);
+        let short = Address::generate(&env);
+        let long = Address::generate(&env);
+        let now = env.ledger().timestamp();
+
+        client.grant_attestation_access(&subject, &short, &(now + 60));
+        client.grant_attestation_access(&subject, &long, &(now + 3600));
+        client.revoke_attestation_access(&subject, &long);
+        client.grant_attestation_access(&subject, &long, &(now + 3600));
+
+        env.ledger().with_mut(|li| li.timestamp = now + 60);
+        assert_eq!(client.sweep_attestation_grants(&subject), 1);
+        assert_eq!(
+            client.try_revoke_attestation_access(&subject, &short),
+            Err(Ok(Error::NotFound))
+        );
+        assert!(client.has_attestation_access(&subject, &long));
+    }
+
+    #[test]
+    fn test_replay_scope_per_issuer() {
+        let env = Env::default();
+        let (_, client, attestor) = setup(&env);
+        let other = Address::generate(&env);
+        client.register_attestor(&other, &attestor_public_key(&env));
+        let subject = Address::generate(&env);
+        let payload_hash = BytesN::from_array(&env, &[9; 32]);
+        let session_id = client.create_session(&subject);
+        let submit = |issuer: &Address| {
+            client.try_submit_attestation_with_session(
+                &session_id,
+                issuer,
+                &subject,
+                &1_700_000_000u64,
+                &payload_hash,
+                &sign_attestation(&env, issuer, &subject, 1_700_000_000u64, &payload_hash),
+                &None,
+            )
+        };
+
+        assert_eq!(client.get_replay_scope(), ReplayScope::Global);
+        assert!(submit(&attestor).is_ok());
+        assert_eq!(submit(&other), Err(Ok(Error::ReplayAttack)));
+
+        // Independent issuers may attest the same document, each only once
+        client.set_replay_scope(&ReplayScope::Issuer);
+        assert!(submit(&other).is_ok());
+        assert_eq!(submit(&other), Err(Ok(Error::ReplayAttack)));
+        assert_eq!(submit(&attestor), Err(Ok(Error::ReplayAttack)));
+    }
+
+    #[test]
+    fn test_attestation_signature_must_verify() {
+        let env = Env::default();
+        let (_, client, attestor) = setup(&env);
+        let subject = Address::generate(&env);
+        let payload_hash = BytesN::from_array(&env, &[4; 32]);
+        let session_id = client.create_session(&subject);
+        let submit = |signature: &Bytes| {
+            client.try_submit_attestation_with_session(
+                &session_id,
+                &attestor,
+                &subject,
+                &1_700_000_000u64,
+                &payload_hash,
+                signature,
+                &None,
+            )
+        };
+
+        let short = Bytes::from_slice(&env, &[1; 32]);
+        assert_eq!(submit(&short), Err(Ok(Error::InvalidCredentialFormat)));
+
+        // Signed over a different timestamp, so the digest does not match
+        let stale = sign_attestation(&env, &attestor, &subject, 1_600_000_000u64, &payload_hash);
+        assert!(matches!(submit(&stale), Err(Err(_))));
+        assert!(!client.is_attestation_recorded(&payload_hash));
+
+        let valid = sign_attestation(&env, &attestor, &subject, 1_700_000_000u64, &payload_hash);
+        assert!(submit(&valid).is_ok());
+    }
+
+    #[test]
+    fn test_attestation_expiry_and_revocation() {
+        let env = Env::default();
+        let (_, client, attestor) = setup(&env);
+        let subject = Address::generate(&env);
+        env.ledger().with_mut(|li| li.timestamp = 1_700_000_000);
+        let session_id = client.create_session(&subject);
+        let submit = |seed: u8| {
+            let payload_hash = BytesN::from_array(&env, &[seed; 32]);
+            client.submit_attestation_with_session(
+                &session_id,
+                &attestor,
+                &subject,
+                &1_700_000_000u64,
+                &payload_hash,
+                &sign_attestation(&env, &attestor, &subject, 1_700_000_000u64, &payload_hash),
+                &None,
+            )
+        };
+        let expiring = submit(11);
+        let revoked = submit(12);
+        assert!(client.is_attestation_valid(&expiring));
+        assert!(!client.is_attestation_valid(&99));
+
+        // Only the issuer sets the window, and it must end after the attestation
+        let other = Address::generate(&env);
+        assert_eq!(
+            client.try_set_attestation_expiry(&other, &expiring, &1_700_086_400),
+            Err(Ok(Error::UnauthorizedAttestor))
+        );
+        assert_eq!(
+            client.try_set_attestation_expiry(&attestor, &expiring, &1_700_000_000),
+            Err(Ok(Error::InvalidTimestamp))
+        );
+        client.set_attestation_expiry(&attestor, &expiring, &1_700_086_400);
+        assert_eq!(client.get_attestation(&attestor, &expiring).expires_at, 1_700_086_400);
+        assert!(client.is_attestation_valid(&expiring));
+        env.ledger().with_mut(|li| li.timestamp = 1_700_086_400);
+        assert!(!client.is_attestation_valid(&expiring));
+
+        client.revoke_attestation(&attestor, &revoked);
+        assert!(!client.is_attestation_valid(&revoked));
+        assert_eq!(
+            client.try_revoke_attestation(&attestor, &revoked),
+            Err(Ok(Error::AlreadyExists))
+        );
+    }
+
+    #[test]
+    fn test_delegate_submits_for_attestor() {
+        let env = Env::default();
+        let (_, client, attestor) = setup(&env);
+        let delegate = Address::generate(&env);
+        let subject = Address::generate(&env);
+        let session_id = client.create_session(&subject);
+        // Signed over the attestor's address, as the attestation is recorded
+        let submit_signed_for = |issuer: &Address, signed_for: &Address, hash: u8| {
+            let payload_hash = BytesN::from_array(&env, &[hash; 32]);
+            client.try_submit_attestation_with_session(
+                &session_id,
+                issuer,
+                &subject,
+                &1_700_000_000u64,
+                &payload_hash,
+                &sign_attestation(&env, signed_for, &subject, 1_700_000_000u64, &payload_hash),
+                &None,
+            )
+        };
+        let submit = |issuer: &Address, hash: u8| submit_signed_for(issuer, &attestor, hash);
+
+        assert_eq!(submit(&delegate, 1), Err(Ok(Error::UnauthorizedAttestor)));
+
+        client.add_delegate(&attestor, &delegate, &attestor_public_key(&env));
+        assert_eq!(client.get_delegates(&attestor).len(), 1);
+        assert_eq!(client.get_delegate(&delegate).unwrap().attestor, attestor);
+
+        // A delegate signing over its own address doesn't verify
+        assert!(submit_signed_for(&delegate, &delegate, 1).is_err());
+
+        // Recorded under the attestor, but the delegate signed it, and the
+        // signature verifies against the record
+        let id = submit(&delegate, 1).unwrap().unwrap();
+        let record = client.get_attestation(&attestor, &id);
+        assert_eq!(record.issuer, attestor);
+        let digest = attestation_signing_digest(
+            &env,
+            &record.issuer,
+            &record.subject,
+            record.timestamp,
+            &record.payload_hash,
+        );
+        env.crypto().ed25519_verify(
+            &attestor_public_key(&env),
+            &digest.into(),
+            &BytesN::<64>::try_from(&record.signature).unwrap(),
+        );
+        client.revoke_attestation(&attestor, &id);
+
+        let other = Address::generate(&env);
+        client.register_attestor(&other, &attestor_public_key(&env));
+        assert_eq!(
+            client.try_add_delegate(&other, &delegate, &attestor_public_key(&env)),
+            Err(Ok(Error::AlreadyExists))
+        );
+        assert_eq!(
+            client.try_add_delegate(&other, &attestor, &attestor_public_key(&env)),
+            Err(Ok(Error::AlreadyExists))
+        );
+        assert_eq!(
+            client.try_remove_delegate(&other, &delegate),
+            Err(Ok(Error::NotFound))
+        );
+
+        client.remove_delegate(&attestor, &delegate);
+        assert!(client.get_delegates(&attestor).is_empty());
+        assert_eq!(submit(&delegate, 2), Err(Ok(Error::UnauthorizedAttestor)));
+        assert_eq!(submit(&attestor, 2).unwrap().unwrap(), id + 1);
+    }
+
+    #[test]
+    fn test_supersede_attestation_follows_chain() {
+        let env = Env::default();
+        let (_, client, attestor) = setup(&env);
+        let subject = Address::generate(&env);
+        let session_id = client.create_session(&subject);
+        let timestamp = 1_700_000_000u64;
+        let hash = |byte: u8| BytesN::from_array(&env, &[byte; 32]);
+        let first = client.submit_attestation_with_session(
+            &session_id,
+            &attestor,
+            &subject,
+            &timestamp,
+            &hash(1),
+            &sign_attestation(&env, &attestor, &subject, timestamp, &hash(1)),
+            &None,
+        );
+        let supersede = |old_id: &u64, byte: u8| {
+            client.try_supersede_attestation(
+                &attestor,
+                old_id,
+                &(timestamp + 1),
+                &hash(byte),
+                &sign_attestation(&env, &attestor, &subject, timestamp + 1, &hash(byte)),
+            )
+        };
+
+        let second = supersede(&first, 2).unwrap().unwrap();
+        let third = supersede(&second, 3).unwrap().unwrap();
+        assert_eq!(client.get_superseded_by(&first), Some(second));
+        assert_eq!(client.get_supersedes(&third), Some(second));
+        assert_eq!(client.get_supersedes(&first), None);
+        assert!(!client.is_attestation_valid(&first));
+        assert!(!client.is_attestation_valid(&second));
+        assert!(client.is_attestation_valid(&third));
+
+        let latest = client.get_latest_subject_attestation(&subject, &subject);
+        assert_eq!(latest.id, third);
+        assert_eq!(latest.payload_hash, hash(3));
+
+        // A superseded attestation can't be replaced again, and only by its issuer
+        assert_eq!(supersede(&first, 4), Err(Ok(Error::AlreadyExists)));
+        let other = Address::generate(&env);
+        client.register_attestor(&other, &attestor_public_key(&env));
+        assert_eq!(
+            client.try_supersede_attestation(
+                &other,
+                &third,
+                &(timestamp + 1),
+                &hash(5),
+                &sign_attestation(&env, &other, &subject, timestamp + 1, &hash(5)),
+            ),
+            Err(Ok(Error::UnauthorizedAttestor))
+        );
+        // A delegate of the issuer can supersede on its behalf
+        let delegate = Address::generate(&env);
+        client.add_delegate(&attestor, &delegate, &attestor_public_key(&env));
+        let fourth = client.supersede_attestation(
+            &delegate,
+            &third,
+            &(timestamp + 2),
+            &hash(6),
+            &sign_attestation(&env, &attestor, &subject, timestamp + 2, &hash(6)),
+        );
+        assert_eq!(client.get_attestation(&subject, &fourth).issuer, attestor);
+        assert_eq!(client.get_latest_subject_attestation(&subject, &subject).id, fourth);
+        assert_eq!(
+            client.try_get_latest_subject_attestation(&other, &other),
+            Err(Ok(Error::AttestationNotFound))
+        );
+    }
+}
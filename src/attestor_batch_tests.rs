@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod attestor_batch_tests {
+    use crate::config::MAX_ATTESTORS;
+    use crate::testutils::{attestor_public_key, AnchorKitFixture};
+    use crate::{AttestorBatchResult, AttestorRegistration, Error};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, Vec};
+
+    #[test]
+    fn test_batch_register_and_revoke_report_each_entry() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let existing = kit.register_attestor();
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        let registration = |attestor: &Address| AttestorRegistration {
+            attestor: attestor.clone(),
+            public_key: attestor_public_key(&env),
+        };
+        let result = |attestor: &Address, error| AttestorBatchResult {
+            attestor: attestor.clone(),
+            error,
+        };
+
+        let registered = kit.client.register_attestors_batch(&vec![
+            &env,
+            registration(&first),
+            registration(&existing),
+            registration(&second),
+            registration(&first),
+        ]);
+        let already = Error::AttestorAlreadyRegistered as u32;
+        assert_eq!(
+            registered,
+            vec![
+                &env,
+                result(&first, 0),
+                result(&existing, already),
+                result(&second, 0),
+                result(&first, already),
+            ]
+        );
+        assert!(kit.client.is_attestor(&first));
+        assert!(kit.client.is_attestor(&second));
+
+        let stranger = Address::generate(&env);
+        let revoked = kit.client.revoke_attestors_batch(&vec![
+            &env,
+            existing.clone(),
+            stranger.clone(),
+            second.clone(),
+        ]);
+        assert_eq!(
+            revoked,
+            vec![
+                &env,
+                result(&existing, 0),
+                result(&stranger, Error::AttestorNotRegistered as u32),
+                result(&second, 0),
+            ]
+        );
+        assert!(!kit.client.is_attestor(&existing));
+        assert!(!kit.client.is_attestor(&second));
+        assert!(kit.client.is_attestor(&first));
+    }
+
+    #[test]
+    fn test_batch_larger_than_max_attestors_is_rejected() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let mut attestors = Vec::new(&env);
+        for _ in 0..=MAX_ATTESTORS {
+            attestors.push_back(Address::generate(&env));
+        }
+
+        let result = kit.client.try_revoke_attestors_batch(&attestors);
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+    }
+}
@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod benchmark_tests {
+    use crate::testutils::{measure, set_time, sign_attestation, AnchorKitFixture, ResourceUsage};
+    use crate::{RoutingRequest, RoutingStrategy, ServiceType};
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+    // Ceilings sit about 50% above the usage measured when they were set, so
+    // a regression fails here while small changes don't.
+
+    #[test]
+    fn benchmark_submit_attestation() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let issuer = kit.register_attestor();
+        let subject = Address::generate(&env);
+        set_time(&env, 1_700_000_000);
+        kit.client.create_session(&subject);
+        let session_id = kit.client.create_session(&subject);
+        let payload_hash = BytesN::from_array(&env, &[1; 32]);
+        let signature = sign_attestation(&env, &issuer, &subject, 1_700_000_000, &payload_hash);
+
+        let (_, usage) = measure(&env, || {
+            kit.client.submit_attestation_with_session(
+                &session_id,
+                &issuer,
+                &subject,
+                &1_700_000_000,
+                &payload_hash,
+                &signature,
+                &None,
+            )
+        });
+        usage.assert_within(
+            "submit_attestation_with_session",
+            &ResourceUsage {
+                cpu_instructions: 1_400_000,
+                memory_bytes: 140_000,
+                ledger_reads: 14,
+                ledger_writes: 15,
+            },
+        );
+    }
+
+    #[test]
+    fn benchmark_submit_quote() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Quotes]);
+        kit.seed_quote(&anchor, "USDC", "EUR", 9_200, 600);
+
+        let (_, usage) = measure(&env, || kit.seed_quote(&anchor, "USDC", "EUR", 9_100, 600));
+        usage.assert_within(
+            "submit_quote",
+            &ResourceUsage {
+                cpu_instructions: 660_000,
+                memory_bytes: 92_000,
+                ledger_reads: 14,
+                ledger_writes: 9,
+            },
+        );
+    }
+
+    #[test]
+    fn benchmark_build_transaction_intent() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+        let quote_id = kit.seed_quote(&anchor, "USDC", "EUR", 9_200, 600);
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 1_000)
+            .with_quote_id(quote_id)
+            .with_quote_sequence(1);
+
+        let (_, usage) = measure(&env, || kit.client.build_transaction_intent(&builder));
+        usage.assert_within(
+            "build_transaction_intent",
+            &ResourceUsage {
+                cpu_instructions: 900_000,
+                memory_bytes: 160_000,
+                ledger_reads: 18,
+                ledger_writes: 8,
+            },
+        );
+    }
+
+    #[test]
+    fn benchmark_route_transaction() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let services = [ServiceType::Deposits, ServiceType::Quotes];
+        let mut anchor = None;
+        for rate in [9_000, 9_100, 9_200, 9_300, 9_400] {
+            let registered = kit.register_anchor(&services);
+            kit.client
+                .set_anchor_metadata(&registered, &9000, &600, &8000, &9900, &1_000_000);
+            kit.seed_quote(&registered, "USDC", "EUR", rate, 600);
+            anchor = Some(registered);
+        }
+        let anchor = anchor.unwrap();
+        let request = RoutingRequest {
+            request: kit.deposit_intent(&anchor, "USDC", "EUR", 1_000).request,
+            strategy: RoutingStrategy::BestRate,
+            max_anchors: 5,
+            require_kyc: false,
+            min_reputation: 0,
+            corridor_id: 0,
+            record_exclusions: false,
+        };
+
+        let (_, usage) = measure(&env, || kit.client.route_transaction(&kit.admin, &request));
+        usage.assert_within(
+            "route_transaction",
+            &ResourceUsage {
+                cpu_instructions: 2_150_000,
+                memory_bytes: 300_000,
+                ledger_reads: 63,
+                ledger_writes: 3,
+            },
+        );
+    }
+}
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod blocklist_tests {
+    use crate::testutils::AnchorKitFixture;
+    use crate::{Error, ServiceType};
+    use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env};
+
+    #[test]
+    fn test_blocked_addresses_cannot_transfer() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let sanctioned = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        kit.client.block_address(&kit.admin, &sanctioned);
+        assert!(kit.client.is_address_blocked(&sanctioned));
+
+        let result = kit.client.try_initiate_transfer(&sanctioned, &other, &100);
+        assert_eq!(result, Err(Ok(Error::AddressBlocked)));
+        let result = kit.client.try_initiate_transfer(&other, &sanctioned, &100);
+        assert_eq!(result, Err(Ok(Error::AddressBlocked)));
+
+        kit.client.unblock_address(&kit.admin, &sanctioned);
+        assert!(!kit.client.is_address_blocked(&sanctioned));
+        assert!(kit
+            .client
+            .try_initiate_transfer(&sanctioned, &other, &100)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_blocked_subject_rejected_for_intents_and_attestations() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        let subject = Address::generate(&env);
+        kit.client.block_address(&kit.admin, &subject);
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 100)
+            .with_subject(subject.clone());
+        let result = kit.client.try_build_transaction_intent(&builder);
+        assert_eq!(result, Err(Ok(Error::AddressBlocked)));
+
+        let session_id = kit.client.create_session(&anchor);
+        let result = kit.client.try_submit_attestation_with_session(
+            &session_id,
+            &anchor,
+            &subject,
+            &1_700_000_001u64,
+            &BytesN::from_array(&env, &[4u8; 32]),
+            &Bytes::new(&env),
+            &None,
+        );
+        assert_eq!(result, Err(Ok(Error::AddressBlocked)));
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod capabilities_tests {
+    use crate::{AnchorKitClient, AnchorKitContract, AnchorKitContractClient, Storage};
+    use soroban_sdk::{Env, String, Symbol};
+
+    #[test]
+    fn test_capabilities_report_version_and_features() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(&env, &contract_id);
+
+        // Capabilities are readable before initialization
+        let caps = client.get_capabilities();
+        assert_eq!(
+            caps.version,
+            String::from_str(&env, env!("CARGO_PKG_VERSION"))
+        );
+        assert_eq!(
+            caps.interfaces
+                .contains(Symbol::new(&env, "AnchorKitInterface")),
+            cfg!(all(feature = "attestations", feature = "quotes", feature = "routing"))
+        );
+        // Optional subsystems are reported exactly when their Cargo feature is on
+        let has = |name: &str| caps.features.contains(Symbol::new(&env, name));
+        assert_eq!(has("attestations"), cfg!(feature = "attestations"));
+        assert_eq!(has("quotes"), cfg!(feature = "quotes"));
+        assert_eq!(has("htlc"), cfg!(feature = "transfers"));
+        assert_eq!(has("zk_kyc"), cfg!(feature = "compliance"));
+        assert!(has("sessions"));
+        assert!(!has("unknown"));
+    }
+
+    #[test]
+    fn test_capabilities_via_interface_client() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+
+        let caps = AnchorKitClient::new(&env, &contract_id).get_capabilities();
+        assert_eq!(
+            caps,
+            AnchorKitContractClient::new(&env, &contract_id).get_capabilities()
+        );
+    }
+
+    #[test]
+    fn test_version_reports_semver_schema_and_features() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(&env, &contract_id);
+
+        let version = client.version();
+        let semver = alloc::format!("{}.{}.{}", version.major, version.minor, version.patch);
+        assert_eq!(semver, env!("CARGO_PKG_VERSION"));
+        assert_eq!(version.schema_version, Storage::SCHEMA_VERSION);
+        assert_eq!(version.features, client.get_capabilities().features);
+        assert_eq!(AnchorKitClient::new(&env, &contract_id).version(), version);
+    }
+}
@@ -1,5 +1,6 @@
 #![cfg(test)]
 
+use crate::testutils::attestor_public_key;
 use crate::{
     types::{AnchorMetadata, AnchorServices, ServiceType},
     AnchorKitContract, AnchorKitContractClient, Error,
@@ -22,7 +23,7 @@ fn test_detect_deposit_only_anchor() {
     client.initialize(&admin);
 
     // Register anchor as attestor
-    client.register_attestor(&deposit_anchor);
+    client.register_attestor(&deposit_anchor, &attestor_public_key(&env));
 
     // Configure anchor with ONLY deposit service
     let mut services = Vec::new(&env);
@@ -64,7 +65,7 @@ fn test_detect_full_service_anchor() {
     client.initialize(&admin);
 
     // Register anchor as attestor
-    client.register_attestor(&full_service_anchor);
+    client.register_attestor(&full_service_anchor, &attestor_public_key(&env));
 
     // Configure anchor with ALL services
     let mut services = Vec::new(&env);
@@ -108,7 +109,7 @@ fn test_reject_empty_services() {
     client.initialize(&admin);
 
     // Register anchor as attestor
-    client.register_attestor(&anchor);
+    client.register_attestor(&anchor, &attestor_public_key(&env));
 
     // Try to configure with empty services - should fail
     let empty_services = Vec::new(&env);
@@ -133,7 +134,7 @@ fn test_reject_duplicate_services() {
     client.initialize(&admin);
 
     // Register anchor as attestor
-    client.register_attestor(&anchor);
+    client.register_attestor(&anchor, &attestor_public_key(&env));
 
     // Try to configure with duplicate services - should fail
     let mut duplicate_services = Vec::new(&env);
@@ -180,7 +181,7 @@ fn test_detect_withdrawal_only_anchor() {
     let withdrawal_anchor = Address::generate(&env);
 
     client.initialize(&admin);
-    client.register_attestor(&withdrawal_anchor);
+    client.register_attestor(&withdrawal_anchor, &attestor_public_key(&env));
 
     // Configure with only withdrawals
     let mut services = Vec::new(&env);
@@ -208,7 +209,7 @@ fn test_detect_quote_provider_anchor() {
     let quote_anchor = Address::generate(&env);
 
     client.initialize(&admin);
-    client.register_attestor(&quote_anchor);
+    client.register_attestor(&quote_anchor, &attestor_public_key(&env));
 
     // Configure with quotes and KYC
     let mut services = Vec::new(&env);
@@ -237,7 +238,7 @@ fn test_capability_detection_with_metadata() {
     let anchor = Address::generate(&env);
 
     client.initialize(&admin);
-    client.register_attestor(&anchor);
+    client.register_attestor(&anchor, &attestor_public_key(&env));
 
     // Configure services
     let mut services = Vec::new(&env);
@@ -284,7 +285,7 @@ fn test_get_services_for_non_configured_anchor() {
     let anchor = Address::generate(&env);
 
     client.initialize(&admin);
-    client.register_attestor(&anchor);
+    client.register_attestor(&anchor, &attestor_public_key(&env));
 
     // Try to get services without configuring - should fail
     let result = client.try_get_supported_services(&anchor);
@@ -307,7 +308,7 @@ fn test_update_anchor_capabilities() {
     let anchor = Address::generate(&env);
 
     client.initialize(&admin);
-    client.register_attestor(&anchor);
+    client.register_attestor(&anchor, &attestor_public_key(&env));
 
     // Initially configure with only deposits
     let mut initial_services = Vec::new(&env);
@@ -344,7 +345,7 @@ fn test_reject_invalid_metadata_scores() {
     let anchor = Address::generate(&env);
 
     client.initialize(&admin);
-    client.register_attestor(&anchor);
+    client.register_attestor(&anchor, &attestor_public_key(&env));
 
     // Try to set metadata with invalid reputation score (> 10000)
     let result = client.try_set_anchor_metadata(
@@ -393,7 +394,7 @@ fn test_capability_detection_inactive_anchor() {
     let anchor = Address::generate(&env);
 
     client.initialize(&admin);
-    client.register_attestor(&anchor);
+    client.register_attestor(&anchor, &attestor_public_key(&env));
 
     // Configure services
     let mut services = Vec::new(&env);
@@ -0,0 +1,329 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::{
+    diagnostics::CheckReport,
+    errors::Error,
+    events::{AmlFlagRaised, AmlFlagReviewed, BlocklistUpdated},
+    storage::Storage,
+    types::{
+        AmlFlag, AmlFlagStatus, Asset, AttestationResolution, CheckFailure, ComplianceReport,
+        CorridorRules, QuoteRequest, RequirementTemplate, TransactionIntent,
+    },
+};
+#[cfg(feature = "transfers")]
+use crate::travel_rule::TravelRule;
+
+/// Length of a compliance reporting period (30 days).
+pub const COMPLIANCE_PERIOD_SECONDS: u64 = 2_592_000;
+
+/// Compliance controls shared by transfer, intent and attestation entrypoints.
+pub struct Compliance;
+
+impl Compliance {
+    pub fn block(env: &Env, address: &Address) {
+        Storage::set_blocked(env, address, true);
+        BlocklistUpdated::publish_added(env, address);
+    }
+
+    pub fn unblock(env: &Env, address: &Address) {
+        Storage::set_blocked(env, address, false);
+        BlocklistUpdated::publish_removed(env, address);
+    }
+
+    /// Fails with `AddressBlocked` if `address` is on the sanctions blocklist.
+    pub fn ensure_not_blocked(env: &Env, address: &Address) -> Result<(), Error> {
+        if Storage::is_blocked(env, address) {
+            return Err(Error::AddressBlocked);
+        }
+        Ok(())
+    }
+
+    /// Evaluate an intent against the blocklist, its corridor's rules and its
+    /// requirement template.
+    ///
+    /// Used when an intent is built and again when it is executed, so rule,
+    /// blocklist or KYC changes in between are honoured. Travel-rule commitments
+    /// are recorded against a built intent, so they are only required once the
+    /// intent is stored, i.e. at execution.
+    pub fn check_compliance(env: &Env, intent: &TransactionIntent) -> Result<(), Error> {
+        let mut report = CheckReport::new(env);
+        Self::run_checks(env, intent, &mut report);
+        report.into_result()
+    }
+
+    /// Every compliance check that fails for `intent`, in evaluation order.
+    pub fn explain(env: &Env, intent: &TransactionIntent) -> Vec<CheckFailure> {
+        let mut report = CheckReport::new(env);
+        Self::run_checks(env, intent, &mut report);
+        report.failures()
+    }
+
+    fn run_checks(env: &Env, intent: &TransactionIntent, report: &mut CheckReport) {
+        if let Some(subject) = &intent.subject {
+            report.require(
+                env,
+                !Storage::is_blocked(env, subject),
+                Error::AddressBlocked,
+                "subject",
+            );
+        }
+
+        #[cfg(feature = "quotes")]
+        if intent.corridor_id != 0 {
+            match Storage::get_corridor(env, intent.corridor_id) {
+                Ok(corridor) => report.require(
+                    env,
+                    corridor.is_active && corridor.admits(&intent.request),
+                    Error::ComplianceNotMet,
+                    "corridor",
+                ),
+                Err(error) => report.fail(env, error, "corridor_id", 1, 0),
+            }
+        }
+
+        let rules = Self::rules_for(env, &intent.request, intent.corridor_id);
+        let template = match Self::template_for(env, intent.requirement_template) {
+            Ok(template) => template,
+            Err(error) => {
+                report.fail(env, error, "requirement_template", 1, 0);
+                None
+            }
+        };
+
+        if let Some(rules) = &rules {
+            if rules.max_amount != 0 && intent.request.amount > rules.max_amount {
+                report.fail(
+                    env,
+                    Error::ComplianceNotMet,
+                    "amount",
+                    rules.max_amount.into(),
+                    intent.request.amount.into(),
+                );
+            }
+        }
+
+        let (kyc_level, required) = Self::subject_requirements(env, &rules, &template);
+        if kyc_level > 0 || !required.is_empty() {
+            let Some(subject) = &intent.subject else {
+                report.fail(env, Error::ComplianceNotMet, "subject", 1, 0);
+                return;
+            };
+
+            let verified = Storage::get_kyc_record(env, subject).map_or(0, |record| record.level);
+            if verified < kyc_level {
+                report.fail(
+                    env,
+                    Error::ComplianceNotMet,
+                    "kyc_level",
+                    kyc_level.into(),
+                    verified.into(),
+                );
+            }
+
+            let resolution = Self::resolve_attestations(env, subject, &required);
+            for kind in resolution.missing.iter() {
+                report.fail_on(Error::ComplianceNotMet, kind, 1, 0);
+            }
+        }
+
+        let Some(rules) = rules else {
+            return;
+        };
+
+        #[cfg(feature = "transfers")]
+        if rules.travel_rule_threshold > 0
+            && intent.request.amount as i128 >= rules.travel_rule_threshold
+            && Storage::get_intent(env, intent.intent_id).is_ok()
+        {
+            report.require(
+                env,
+                TravelRule::require_commitments(env, intent.intent_id).is_ok(),
+                Error::ComplianceNotMet,
+                "travel_rule",
+            );
+        }
+    }
+
+    /// Persist an AML flag and emit `AmlFlagRaised` if `amount` exceeds the
+    /// threshold configured for `asset`. Returns the flag ID, if one was raised.
+    pub fn flag_if_large(env: &Env, reference_id: u64, asset: &Asset, amount: i128) -> Option<u64> {
+        let threshold = Storage::get_aml_threshold(env, asset);
+        if threshold == 0 || amount <= threshold {
+            return None;
+        }
+
+        let flag = AmlFlag {
+            flag_id: Storage::get_next_aml_flag_id(env),
+            reference_id,
+            asset: asset.clone(),
+            amount,
+            threshold,
+            status: AmlFlagStatus::Open,
+            raised_at: env.ledger().timestamp(),
+            reviewed_at: 0,
+        };
+        Storage::set_aml_flag(env, &flag);
+        AmlFlagRaised::publish(env, &flag);
+
+        Some(flag.flag_id)
+    }
+
+    /// Move a flag to `Cleared` or `Escalated`. Open and escalated flags can be
+    /// cleared; only open flags can be escalated.
+    pub fn review_flag(env: &Env, flag_id: u64, status: AmlFlagStatus) -> Result<AmlFlag, Error> {
+        let mut flag = Storage::get_aml_flag(env, flag_id)?;
+        let allowed = match status {
+            AmlFlagStatus::Cleared => flag.status != AmlFlagStatus::Cleared,
+            AmlFlagStatus::Escalated => flag.status == AmlFlagStatus::Open,
+            AmlFlagStatus::Open => false,
+        };
+        if !allowed {
+            return Err(Error::InvalidConfig);
+        }
+
+        flag.status = status;
+        flag.reviewed_at = env.ledger().timestamp();
+        Storage::set_aml_flag(env, &flag);
+        AmlFlagReviewed::publish(env, flag_id, status);
+
+        Ok(flag)
+    }
+
+    /// Look up the subject's latest valid attestation of each required kind.
+    /// Kinds without one are reported in `missing`, in rule order.
+    pub fn resolve_attestations(
+        env: &Env,
+        subject: &Address,
+        required: &Vec<Symbol>,
+    ) -> AttestationResolution {
+        let mut resolution = AttestationResolution {
+            attestation_ids: Vec::new(env),
+            missing: Vec::new(env),
+        };
+
+        for kind in required.iter() {
+            let valid = Storage::get_subject_attestation(env, subject, &kind).filter(|id| {
+                Storage::get_attestation(env, *id).is_ok_and(|attestation| {
+                    &attestation.subject == subject
+                        && !attestation.is_expired(env.ledger().timestamp())
+                        && !Storage::is_attestation_revoked(env, *id)
+                        && Storage::get_superseded_by(env, *id).is_none()
+                })
+            });
+            match valid {
+                Some(id) => resolution.attestation_ids.push_back(id),
+                None => resolution.missing.push_back(kind),
+            }
+        }
+
+        resolution
+    }
+
+    /// Rules a request is checked against: those of the registered corridor it
+    /// references, otherwise those set for its asset pair.
+    pub fn rules_for(env: &Env, request: &QuoteRequest, corridor_id: u64) -> Option<CorridorRules> {
+        #[cfg(feature = "quotes")]
+        if corridor_id != 0 {
+            return Storage::get_corridor(env, corridor_id)
+                .ok()
+                .map(|corridor| corridor.rules);
+        }
+        #[cfg(not(feature = "quotes"))]
+        let _ = corridor_id;
+
+        Storage::get_corridor_rules(env, &request.base_asset, &request.quote_asset)
+    }
+
+    /// The requirement template an intent references, if any. Fails with
+    /// `NotFound` for an unknown template ID.
+    fn template_for(env: &Env, template_id: u64) -> Result<Option<RequirementTemplate>, Error> {
+        if template_id == 0 {
+            return Ok(None);
+        }
+        Storage::get_requirement_template(env, template_id).map(Some)
+    }
+
+    /// KYC level and attestation kinds a subject must satisfy under both the
+    /// corridor's rules and the requirement template: the higher level and
+    /// every kind either one lists.
+    fn subject_requirements(
+        env: &Env,
+        rules: &Option<CorridorRules>,
+        template: &Option<RequirementTemplate>,
+    ) -> (u32, Vec<Symbol>) {
+        let mut kyc_level = 0;
+        let mut required = Vec::new(env);
+        let sources = [
+            rules
+                .as_ref()
+                .map(|rules| (rules.kyc_level, rules.required_attestations.clone())),
+            template
+                .as_ref()
+                .map(|template| (template.kyc_level, template.required_attestations.clone())),
+        ];
+        for (level, kinds) in sources.into_iter().flatten() {
+            kyc_level = kyc_level.max(level);
+            for kind in kinds.iter() {
+                if !required.contains(&kind) {
+                    required.push_back(kind);
+                }
+            }
+        }
+        (kyc_level, required)
+    }
+
+    /// Resolve the attestation kinds required by a request's corridor and
+    /// requirement template for `subject`. Without a subject every required
+    /// kind is missing.
+    pub fn resolve_corridor_attestations(
+        env: &Env,
+        request: &QuoteRequest,
+        corridor_id: u64,
+        requirement_template: u64,
+        subject: &Option<Address>,
+    ) -> AttestationResolution {
+        let rules = Self::rules_for(env, request, corridor_id);
+        let template = Self::template_for(env, requirement_template).unwrap_or(None);
+        let (_, required) = Self::subject_requirements(env, &rules, &template);
+
+        match subject {
+            Some(subject) => Self::resolve_attestations(env, subject, &required),
+            None => AttestationResolution {
+                attestation_ids: Vec::new(env),
+                missing: required,
+            },
+        }
+    }
+
+    /// Reporting period containing the current ledger time.
+    pub fn current_period(env: &Env) -> u64 {
+        env.ledger().timestamp() / COMPLIANCE_PERIOD_SECONDS
+    }
+
+    fn update_report(env: &Env, anchor: &Address, update: impl FnOnce(&mut ComplianceReport)) {
+        let mut report = Storage::get_compliance_report(env, anchor, Self::current_period(env));
+        update(&mut report);
+        Storage::set_compliance_report(env, &report);
+    }
+
+    /// Count an executed transfer and its volume in the anchor's current report.
+    pub fn record_transfer(env: &Env, anchor: &Address, asset: &Asset, amount: i128) {
+        Self::update_report(env, anchor, |report| {
+            report.transfer_count += 1;
+            let volume = report.volumes.get(asset.clone()).unwrap_or(0);
+            report
+                .volumes
+                .set(asset.clone(), volume.saturating_add(amount));
+        });
+    }
+
+    /// Count an AML flag raised on one of the anchor's intents.
+    pub fn record_flag(env: &Env, anchor: &Address) {
+        Self::update_report(env, anchor, |report| report.flags_raised += 1);
+    }
+
+    /// Count a KYC rejection reported by the anchor.
+    pub fn record_kyc_rejection(env: &Env, anchor: &Address) {
+        Self::update_report(env, anchor, |report| report.kyc_rejections += 1);
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod compliance_officer_tests {
+    use crate::testutils::{attestor_public_key, classic_asset, AnchorKitFixture};
+    use crate::{Error, Role};
+    use soroban_sdk::{
+        testutils::{Address as _, MockAuth, MockAuthInvoke},
+        Address, Env, IntoVal,
+    };
+
+    #[test]
+    fn test_officer_manages_compliance_state() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let officer = Address::generate(&env);
+        let subject = Address::generate(&env);
+
+        let result = kit.client.try_block_address(&officer, &subject);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+        kit.client.grant_role(&officer, &Role::ComplianceOfficer);
+        assert!(kit.client.has_role(&officer, &Role::ComplianceOfficer));
+
+        kit.client.block_address(&officer, &subject);
+        assert!(kit.client.is_address_blocked(&subject));
+        kit.client
+            .set_aml_threshold(&officer, &classic_asset(&env, "USDC"), &10_000);
+        kit.client.set_travel_rule_threshold(&officer, &1_000);
+
+        // Officers can write KYC records without being a KYC anchor
+        kit.client.set_kyc_level(&officer, &subject, &2);
+        assert_eq!(
+            kit.client.get_kyc_record(&subject).unwrap().verified_by,
+            officer
+        );
+
+        kit.client.revoke_role(&officer, &Role::ComplianceOfficer);
+        let result = kit.client.try_unblock_address(&officer, &subject);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    }
+
+    #[test]
+    fn test_officer_cannot_use_admin_powers() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let officer = Address::generate(&env);
+        kit.client.grant_role(&officer, &Role::ComplianceOfficer);
+
+        // An officer's signature does not satisfy admin-only entrypoints
+        let attestor = Address::generate(&env);
+        env.mock_auths(&[MockAuth {
+            address: &officer,
+            invoke: &MockAuthInvoke {
+                contract: &kit.contract_id,
+                fn_name: "register_attestor",
+                args: (attestor.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(kit.client.try_register_attestor(&attestor, &attestor_public_key(&env)).is_err());
+
+        // The admin implicitly holds the officer role
+        assert!(kit.client.has_role(&kit.admin, &Role::ComplianceOfficer));
+    }
+}
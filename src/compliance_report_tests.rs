@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod compliance_report_tests {
+    use crate::testutils::{classic_asset, set_time, AnchorKitFixture};
+    use crate::{Error, ServiceType, COMPLIANCE_PERIOD_SECONDS};
+    use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+    #[test]
+    fn test_report_counts_transfers_volume_and_flags() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        let usdc = classic_asset(&env, "USDC");
+        kit.client.set_aml_threshold(&kit.admin, &usdc, &500);
+
+        let sac = env.register_stellar_asset_contract_v2(kit.admin.clone());
+        let token_id = sac.address();
+        kit.client.add_supported_token(&token_id);
+        let payer = kit.user.clone();
+        token::StellarAssetClient::new(&env, &token_id).mint(&payer, &10_000);
+        token::Client::new(&env, &token_id).approve(&payer, &kit.contract_id, &10_000, &1000);
+
+        let small = kit
+            .client
+            .build_transaction_intent(&kit.deposit_intent(&anchor, "USDC", "EUR", 200));
+        let large = kit
+            .client
+            .build_transaction_intent(&kit.deposit_intent(&anchor, "USDC", "EUR", 800));
+        kit.client
+            .execute_intent(&small.intent_id, &payer, &token_id);
+        kit.client
+            .execute_intent(&large.intent_id, &payer, &token_id);
+
+        let period = kit.client.get_current_compliance_period();
+        let report = kit.client.get_compliance_report(&anchor, &period);
+        assert_eq!(report.transfer_count, 2);
+        assert_eq!(report.volumes.get(usdc), Some(1000));
+        assert_eq!(report.flags_raised, 1);
+        assert_eq!(report.kyc_rejections, 0);
+    }
+
+    #[test]
+    fn test_kyc_rejections_are_bucketed_by_period() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::KYC]);
+
+        set_time(&env, COMPLIANCE_PERIOD_SECONDS * 10);
+        kit.client.record_kyc_rejection(&anchor);
+        kit.client.record_kyc_rejection(&anchor);
+        set_time(&env, COMPLIANCE_PERIOD_SECONDS * 11 + 1);
+        kit.client.record_kyc_rejection(&anchor);
+
+        assert_eq!(
+            kit.client
+                .get_compliance_report(&anchor, &10)
+                .kyc_rejections,
+            2
+        );
+        assert_eq!(
+            kit.client
+                .get_compliance_report(&anchor, &11)
+                .kyc_rejections,
+            1
+        );
+        let empty = kit.client.get_compliance_report(&anchor, &12);
+        assert_eq!(empty.transfer_count, 0);
+        assert!(empty.volumes.is_empty());
+
+        let stranger = Address::generate(&env);
+        let result = kit.client.try_record_kyc_rejection(&stranger);
+        assert_eq!(result, Err(Ok(Error::UnauthorizedAttestor)));
+    }
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, BytesN, String};
 
 use crate::errors::Error;
 
@@ -60,10 +60,13 @@ pub const MIN_OPERATIONS: u64 = 1;
 pub const MAX_ROLE_LEN: u32 = 32;
 pub const MIN_ROLE_LEN: u32 = 1;
 pub const MAX_DESCRIPTION_LEN: u32 = 256;
+pub const MAX_DELEGATES: u32 = 8; // signing delegates per attestor
+pub const MAX_RESERVATIONS: u32 = 16; // partner reservations per quote
+pub const MAX_INTENT_TTL: u64 = 86400; // 24 hours; bounds how long offboarding drains
 
 /// Validated attestor configuration with strict type safety.
 ///
-/// All five fields are required. The only valid construction path is
+/// All six fields are required. The only valid construction path is
 /// [`AttestorConfig::new`], which validates the address format,
 /// endpoint URL, and role length before returning `Ok`.
 ///
@@ -99,6 +102,7 @@ pub const MAX_DESCRIPTION_LEN: u32 = 256;
 pub struct AttestorConfig {
     pub name: String,
     pub address: Address,
+    pub public_key: BytesN<32>, // ed25519 key attestation signatures are verified against
     pub endpoint: String,
     pub role: String,
     pub enabled: bool,
@@ -199,6 +203,7 @@ impl AttestorConfig {
     pub fn new(
         name: String,
         address: Address,
+        public_key: BytesN<32>,
         endpoint: String,
         role: String,
         enabled: bool,
@@ -206,6 +211,7 @@ impl AttestorConfig {
         let config = Self {
             name,
             address,
+            public_key,
             endpoint,
             role,
             enabled,
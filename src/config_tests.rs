@@ -1,5 +1,6 @@
 #![cfg(test)]
 
+use crate::testutils::attestor_public_key;
 use crate::{config::*, validation::validate_attestor_batch, Error};
 use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
 
@@ -36,6 +37,7 @@ fn test_attestor_config_validation() {
     let valid = AttestorConfig {
         name: String::from_str(&env, "kyc-provider"),
         address: Address::generate(&env),
+        public_key: attestor_public_key(&env),
         endpoint: String::from_str(&env, "https://api.example.com/verify"),
         role: String::from_str(&env, "kyc-issuer"),
         enabled: true,
@@ -48,6 +50,7 @@ fn test_attestor_config_validation() {
     let invalid_endpoint = AttestorConfig {
         name: String::from_str(&env, "kyc-provider"),
         address: Address::generate(&env),
+        public_key: attestor_public_key(&env),
         endpoint: String::from_str(&env, "bad"),
         role: String::from_str(&env, "kyc-issuer"),
         enabled: true,
@@ -99,6 +102,7 @@ fn test_batch_attestor_validation() {
     valid_attestors.push_back(AttestorConfig {
         name: String::from_str(&env, "attestor1"),
         address: Address::generate(&env),
+        public_key: attestor_public_key(&env),
         endpoint: String::from_str(&env, "https://api1.example.com"),
         role: String::from_str(&env, "issuer"),
         enabled: true,
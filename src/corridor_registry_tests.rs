@@ -0,0 +1,287 @@
+#[cfg(test)]
+mod corridor_registry_tests {
+    use crate::testutils::{classic_asset, set_time, AnchorKitFixture};
+    use crate::{
+        Corridor, CorridorRules, Error, RoutingRequest, RoutingStrategy, ServiceType,
+        TransactionIntentBuilder,
+    };
+    use soroban_sdk::{testutils::Address as _, token, Address, BytesN, Env, String, Vec};
+
+    fn corridor(env: &Env) -> Corridor {
+        Corridor {
+            corridor_id: 0,
+            base_asset: classic_asset(env, "USDC"),
+            quote_asset: classic_asset(env, "EUR"),
+            source_country: String::from_str(env, "USA"),
+            destination_country: String::from_str(env, "DEU"),
+            min_amount: 100,
+            rules: CorridorRules {
+                max_amount: 10_000,
+                required_attestations: Vec::new(env),
+                kyc_level: 2,
+                travel_rule_threshold: 0,
+            },
+            settlement_sla_seconds: 3_600,
+            late_penalty_bps_per_day: 0,
+            is_active: true,
+        }
+    }
+
+    fn to_country(
+        env: &Env,
+        builder: TransactionIntentBuilder,
+        country: &str,
+    ) -> TransactionIntentBuilder {
+        let mut builder = builder;
+        builder.request.country_code = Some(String::from_str(env, country));
+        builder
+    }
+
+    #[test]
+    fn test_register_and_update_corridor() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+
+        let mut invalid = corridor(&env);
+        invalid.min_amount = 20_000;
+        assert_eq!(
+            kit.client.try_register_corridor(&invalid),
+            Err(Ok(Error::InvalidConfig))
+        );
+
+        let corridor_id = kit.client.register_corridor(&corridor(&env));
+        assert_eq!(corridor_id, 1);
+        let mut stored = kit.client.get_corridor(&corridor_id);
+        assert_eq!(stored.corridor_id, corridor_id);
+        assert_eq!(kit.client.list_corridors().len(), 1);
+
+        stored.is_active = false;
+        stored.settlement_sla_seconds = 7_200;
+        kit.client.update_corridor(&stored);
+        assert_eq!(kit.client.get_corridor(&corridor_id), stored);
+
+        // The pair and countries identify the corridor and can't change
+        stored.destination_country = String::from_str(&env, "FRA");
+        assert_eq!(
+            kit.client.try_update_corridor(&stored),
+            Err(Ok(Error::InvalidConfig))
+        );
+        assert_eq!(kit.client.try_get_corridor(&2), Err(Ok(Error::NotFound)));
+    }
+
+    #[test]
+    fn test_intent_uses_corridor_defaults() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::KYC]);
+        let subject = Address::generate(&env);
+        set_time(&env, 1_000);
+
+        // Pair rules are superseded by the corridor's for intents referencing it
+        kit.client.set_corridor_rules(
+            &kit.admin,
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &CorridorRules {
+                max_amount: 50,
+                required_attestations: Vec::new(&env),
+                kyc_level: 0,
+                travel_rule_threshold: 0,
+            },
+        );
+        let corridor_id = kit.client.register_corridor(&corridor(&env));
+        let intent = |amount: u64, country: &str| {
+            let builder = kit
+                .deposit_intent(&anchor, "USDC", "EUR", amount)
+                .with_subject(subject.clone())
+                .with_corridor(corridor_id);
+            to_country(&env, builder, country)
+        };
+
+        assert_eq!(
+            kit.client
+                .try_build_transaction_intent(&intent(5_000, "DEU")),
+            Err(Ok(Error::ComplianceNotMet))
+        );
+        kit.client.set_kyc_level(&anchor, &subject, &2);
+
+        let built = kit.client.build_transaction_intent(&intent(5_000, "DEU"));
+        assert_eq!(built.corridor_id, corridor_id);
+        assert_eq!(built.settlement_sla_seconds, 3_600);
+
+        for (amount, country) in [(50, "DEU"), (20_000, "DEU"), (5_000, "FRA")] {
+            assert_eq!(
+                kit.client
+                    .try_build_transaction_intent(&intent(amount, country)),
+                Err(Ok(Error::ComplianceNotMet))
+            );
+        }
+
+        let mut inactive = kit.client.get_corridor(&corridor_id);
+        inactive.is_active = false;
+        kit.client.update_corridor(&inactive);
+        assert_eq!(
+            kit.client
+                .try_build_transaction_intent(&intent(5_000, "DEU")),
+            Err(Ok(Error::ComplianceNotMet))
+        );
+
+        let unknown = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 5_000)
+            .with_corridor(9);
+        assert_eq!(
+            kit.client.try_build_transaction_intent(&unknown),
+            Err(Ok(Error::NotFound))
+        );
+    }
+
+    #[test]
+    fn test_routing_respects_corridor_sla_and_limits() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let services = [ServiceType::Deposits, ServiceType::Quotes];
+        let fast = kit.register_anchor(&services);
+        let slow = kit.register_anchor(&services);
+        kit.client
+            .set_anchor_metadata(&fast, &9000, &600, &8000, &9900, &1_000_000);
+        kit.client
+            .set_anchor_metadata(&slow, &9000, &7_200, &8000, &9900, &1_000_000);
+        kit.seed_quote(&fast, "USDC", "EUR", 9_500, 600);
+        kit.seed_quote(&slow, "USDC", "EUR", 9_000, 600);
+        let corridor_id = kit.client.register_corridor(&corridor(&env));
+
+        let routing = |amount: u64, corridor_id: u64| RoutingRequest {
+            request: kit.deposit_intent(&fast, "USDC", "EUR", amount).request,
+            strategy: RoutingStrategy::BestRate,
+            max_anchors: 2,
+            require_kyc: false,
+            min_reputation: 0,
+            corridor_id,
+            record_exclusions: false,
+        };
+
+        // The slow anchor has the better rate but misses the corridor's SLA
+        let result = kit.client.route_transaction(&kit.admin, &routing(5_000, 0));
+        assert_eq!(result.selected_anchor, slow);
+        let result = kit.client.route_transaction(&kit.admin, &routing(5_000, corridor_id));
+        assert_eq!(result.selected_anchor, fast);
+        assert_eq!(result.alternatives.len(), 0);
+
+        assert_eq!(
+            kit.client
+                .try_route_transaction(&kit.admin, &routing(20_000, corridor_id)),
+            Err(Ok(Error::ComplianceNotMet))
+        );
+    }
+
+    #[test]
+    fn test_late_settlement_penalty() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::KYC]);
+        let subject = Address::generate(&env);
+        kit.client.set_kyc_level(&anchor, &subject, &2);
+        set_time(&env, 1_000);
+
+        let token = env
+            .register_stellar_asset_contract_v2(kit.admin.clone())
+            .address();
+        kit.client.add_supported_token(&token);
+        let payer = subject.clone();
+        token::StellarAssetClient::new(&env, &token).mint(&payer, &20_000);
+        token::StellarAssetClient::new(&env, &token).mint(&anchor, &1_000);
+        token::Client::new(&env, &token).approve(&payer, &kit.contract_id, &20_000, &1000);
+        kit.client.post_anchor_bond(&anchor, &token, &1_000);
+
+        // 1% of the amount per day late
+        let mut penalized = corridor(&env);
+        penalized.late_penalty_bps_per_day = 100;
+        let corridor_id = kit.client.register_corridor(&penalized);
+        let execute = || {
+            let builder = kit
+                .deposit_intent(&anchor, "USDC", "EUR", 5_000)
+                .with_subject(subject.clone())
+                .with_corridor(corridor_id);
+            let intent = kit
+                .client
+                .build_transaction_intent(&to_country(&env, builder, "DEU"));
+            kit.client.execute_intent(&intent.intent_id, &payer, &token)
+        };
+        let settlement_ref = BytesN::from_array(&env, &[1; 32]);
+
+        let on_time = execute();
+        let late = execute();
+        let very_late = execute();
+        kit.client.confirm_settlement(&on_time, &settlement_ref);
+        assert_eq!(
+            kit.client.try_get_late_penalty(&on_time),
+            Err(Ok(Error::NotFound))
+        );
+
+        // A day past the SLA, paid to the payer out of the anchor's bond
+        set_time(&env, 4_600 + 86_400);
+        kit.client.confirm_settlement(&late, &settlement_ref);
+        let penalty = kit.client.get_late_penalty(&late);
+        assert_eq!(penalty.amount, 50);
+        assert_eq!(penalty.sender, payer);
+        assert_eq!(penalty.bond_token, Some(token.clone()));
+        assert_eq!(token::Client::new(&env, &token).balance(&payer), 5_050);
+        assert_eq!(kit.client.get_anchor_bonds(&anchor).get(0).unwrap().amount, 950);
+
+        // Capped at the amount; the bond can't cover it, so it is owed out of fee revenue
+        set_time(&env, 4_600 + 200 * 86_400);
+        kit.client.confirm_settlement(&very_late, &settlement_ref);
+        let penalty = kit.client.get_late_penalty(&very_late);
+        assert_eq!(penalty.amount, 5_000);
+        assert_eq!(penalty.bond_token, None);
+        let period = kit.client.get_current_metrics_period();
+        let revenue = kit.client.get_anchor_fee_revenue(&anchor, &period);
+        assert_eq!(revenue.penalties.get(classic_asset(&env, "USDC")), Some(5_000));
+
+        // Settlement is only charged once
+        assert_eq!(
+            kit.client.try_confirm_settlement(&late, &settlement_ref),
+            Err(Ok(Error::ReplayAttack))
+        );
+        assert_eq!(kit.client.get_anchor_bonds(&anchor).get(0).unwrap().amount, 950);
+    }
+
+    #[test]
+    fn test_settlement_sla_runs_from_execution() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::KYC]);
+        let payer = kit.user.clone();
+        kit.client.set_kyc_level(&anchor, &payer, &2);
+        set_time(&env, 1_000);
+
+        let token = env
+            .register_stellar_asset_contract_v2(kit.admin.clone())
+            .address();
+        kit.client.add_supported_token(&token);
+        token::StellarAssetClient::new(&env, &token).mint(&payer, &5_000);
+        token::Client::new(&env, &token).approve(&payer, &kit.contract_id, &5_000, &1000);
+
+        let mut penalized = corridor(&env);
+        penalized.late_penalty_bps_per_day = 100;
+        let corridor_id = kit.client.register_corridor(&penalized);
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 5_000)
+            .with_corridor(corridor_id)
+            .with_ttl(10_000);
+        let intent = kit
+            .client
+            .build_transaction_intent(&to_country(&env, builder, "DEU"));
+
+        // Executed after an SLA counted from the build would have run out
+        set_time(&env, 6_000);
+        kit.client.execute_intent(&intent.intent_id, &payer, &token);
+        set_time(&env, 6_000 + 3_600);
+        kit.client
+            .confirm_settlement(&intent.intent_id, &BytesN::from_array(&env, &[1; 32]));
+        assert_eq!(
+            kit.client.try_get_late_penalty(&intent.intent_id),
+            Err(Ok(Error::NotFound))
+        );
+    }
+}
@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod corridor_rules_tests {
+    use crate::testutils::{classic_asset, sign_attestation, AnchorKitFixture};
+    use crate::{CorridorRules, Error, ServiceType, TravelRuleRole};
+    use soroban_sdk::{
+        testutils::Address as _, token, vec, Address, BytesN, Env, Symbol, Vec,
+    };
+
+    fn rules(kinds: Vec<Symbol>) -> CorridorRules {
+        CorridorRules {
+            max_amount: 50_000,
+            required_attestations: kinds,
+            kyc_level: 0,
+            travel_rule_threshold: 0,
+        }
+    }
+
+    fn setup(env: &Env) -> (AnchorKitFixture<'_>, Address) {
+        let kit = AnchorKitFixture::new(env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::KYC]);
+        (kit, anchor)
+    }
+
+    #[test]
+    fn test_max_amount_and_kyc_level() {
+        let env = Env::default();
+        let (kit, anchor) = setup(&env);
+        let subject = Address::generate(&env);
+        let mut corridor = rules(Vec::new(&env));
+        corridor.kyc_level = 2;
+        kit.client.set_corridor_rules(
+            &kit.admin,
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &corridor,
+        );
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 60_000)
+            .with_subject(subject.clone());
+        let result = kit.client.try_build_transaction_intent(&builder);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 5_000)
+            .with_subject(subject.clone());
+        let result = kit.client.try_build_transaction_intent(&builder);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+
+        kit.client.set_kyc_level(&anchor, &subject, &2);
+        let intent = kit.client.build_transaction_intent(&builder);
+        assert_eq!(intent.subject, Some(subject));
+
+        // Other corridors are unaffected
+        let other = kit.deposit_intent(&anchor, "USDC", "GBP", 60_000);
+        assert!(kit.client.try_build_transaction_intent(&other).is_ok());
+    }
+
+    #[test]
+    fn test_required_attestation_kinds() {
+        let env = Env::default();
+        let (kit, anchor) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = Symbol::new(&env, "kyc");
+        kit.client.set_corridor_rules(
+            &kit.admin,
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &rules(vec![&env, kyc.clone()]),
+        );
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 100)
+            .with_subject(subject.clone());
+        let result = kit.client.try_build_transaction_intent(&builder);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+
+        let session_id = kit.client.create_session(&anchor);
+        let payload_hash = BytesN::from_array(&env, &[5u8; 32]);
+        let attestation_id = kit.client.submit_attestation_with_session(
+            &session_id,
+            &anchor,
+            &subject,
+            &1_700_000_001u64,
+            &payload_hash,
+            &sign_attestation(&env, &anchor, &subject, 1_700_000_001u64, &payload_hash),
+            &None,
+        );
+
+        // Only the issuer can tag its attestation
+        let stranger = kit.register_attestor();
+        let result = kit
+            .client
+            .try_set_attestation_kind(&stranger, &attestation_id, &kyc);
+        assert_eq!(result, Err(Ok(Error::UnauthorizedAttestor)));
+
+        kit.client
+            .set_attestation_kind(&anchor, &attestation_id, &kyc);
+        assert_eq!(kit.client.get_attestation_kind(&attestation_id), Some(kyc));
+        assert!(kit.client.try_build_transaction_intent(&builder).is_ok());
+    }
+
+    #[test]
+    fn test_travel_rule_threshold_checked_at_execution() {
+        let env = Env::default();
+        let (kit, anchor) = setup(&env);
+        let mut corridor = rules(Vec::new(&env));
+        corridor.travel_rule_threshold = 1_000;
+        kit.client.set_corridor_rules(
+            &kit.admin,
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &corridor,
+        );
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(kit.admin.clone())
+            .address();
+        kit.client.add_supported_token(&token_id);
+        let payer = kit.user.clone();
+        token::StellarAssetClient::new(&env, &token_id).mint(&payer, &10_000);
+        token::Client::new(&env, &token_id).approve(&payer, &kit.contract_id, &10_000, &1_000);
+
+        // Building succeeds: commitments are made against the built intent
+        let intent = kit
+            .client
+            .build_transaction_intent(&kit.deposit_intent(&anchor, "USDC", "EUR", 2_000));
+        let result = kit
+            .client
+            .try_execute_intent(&intent.intent_id, &payer, &token_id);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+
+        let data_hash = BytesN::from_array(&env, &[8u8; 32]);
+        for role in [TravelRuleRole::Originator, TravelRuleRole::Beneficiary] {
+            kit.client
+                .commit_travel_rule(&anchor, &intent.intent_id, &role, &data_hash);
+        }
+        kit.client
+            .execute_intent(&intent.intent_id, &payer, &token_id);
+    }
+
+    #[test]
+    fn test_intent_embeds_resolved_attestations_and_reports_missing() {
+        let env = Env::default();
+        let (kit, anchor) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = Symbol::new(&env, "kyc");
+        let sanctions = Symbol::new(&env, "sanctions");
+        kit.client.set_corridor_rules(
+            &kit.admin,
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &rules(vec![&env, kyc.clone(), sanctions.clone()]),
+        );
+
+        let session_id = kit.client.create_session(&anchor);
+        let payload_hash = BytesN::from_array(&env, &[6u8; 32]);
+        let kyc_id = kit.client.submit_attestation_with_session(
+            &session_id,
+            &anchor,
+            &subject,
+            &1_700_000_001u64,
+            &payload_hash,
+            &sign_attestation(&env, &anchor, &subject, 1_700_000_001u64, &payload_hash),
+            &None,
+        );
+        kit.client.set_attestation_kind(&anchor, &kyc_id, &kyc);
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 100)
+            .with_subject(subject.clone());
+        let resolution = kit.client.resolve_attestation_requirements(&builder);
+        assert_eq!(resolution.attestation_ids, vec![&env, kyc_id]);
+        assert_eq!(resolution.missing, vec![&env, sanctions.clone()]);
+        let result = kit.client.try_build_transaction_intent(&builder);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+
+        let payload_hash = BytesN::from_array(&env, &[7u8; 32]);
+        let sanctions_id = kit.client.submit_attestation_with_session(
+            &session_id,
+            &anchor,
+            &subject,
+            &1_700_000_002u64,
+            &payload_hash,
+            &sign_attestation(&env, &anchor, &subject, 1_700_000_002u64, &payload_hash),
+            &None,
+        );
+        kit.client
+            .set_attestation_kind(&anchor, &sanctions_id, &sanctions);
+
+        let intent = kit.client.build_transaction_intent(&builder);
+        assert_eq!(intent.attestation_ids, vec![&env, kyc_id, sanctions_id]);
+    }
+}
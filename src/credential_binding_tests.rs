@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod credential_binding_tests {
+    use crate::testutils::attestor_public_key;
+    use crate::{
+        AnchorKitContract, AnchorKitContractClient, CredentialType, EndpointKind, Error,
+    };
+    use soroban_sdk::{
+        symbol_short, testutils::Address as _, Address, Bytes, BytesN, Env, String, Symbol,
+    };
+
+    fn primary() -> Symbol {
+        symbol_short!("primary")
+    }
+
+    fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, String) {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let attestor = Address::generate(env);
+        client.initialize(&admin);
+        client.register_attestor(&attestor, &attestor_public_key(env));
+
+        let url = String::from_str(env, "https://anchor.example.com/sep38");
+        client.configure_endpoint(&attestor, &EndpointKind::Sep38, &url, &true);
+
+        let token = Bytes::from_slice(env, &[7u8; 32]);
+        client.store_encrypted_credential(&attestor, &CredentialType::OAuth2, &token, &0);
+
+        (client, attestor, url)
+    }
+
+    #[test]
+    fn test_bind_and_get_credential_binding() {
+        let env = Env::default();
+        let (client, attestor, url) = setup(&env);
+
+        client.bind_endpoint_credential(&attestor, &url, &primary(), &CredentialType::OAuth2);
+
+        let binding = client.get_credential_binding(&attestor, &url);
+        assert_eq!(binding.credential_type, CredentialType::OAuth2);
+        assert!(!binding.orphaned);
+    }
+
+    #[test]
+    fn test_bind_rejects_unknown_endpoint() {
+        let env = Env::default();
+        let (client, attestor, _) = setup(&env);
+
+        let other = String::from_str(&env, "https://anchor.example.com/sep12");
+        let result =
+            client.try_bind_endpoint_credential(&attestor, &other, &primary(), &CredentialType::OAuth2);
+        assert_eq!(result, Err(Ok(Error::EndpointNotFound)));
+    }
+
+    #[test]
+    fn test_bind_rejects_mismatched_credential_type() {
+        let env = Env::default();
+        let (client, attestor, url) = setup(&env);
+
+        let result =
+            client.try_bind_endpoint_credential(&attestor, &url, &primary(), &CredentialType::MutualTLS);
+        assert_eq!(result, Err(Ok(Error::InvalidCredentialFormat)));
+    }
+
+    #[test]
+    fn test_revoked_credential_orphans_binding() {
+        let env = Env::default();
+        let (client, attestor, url) = setup(&env);
+
+        client.bind_endpoint_credential(&attestor, &url, &primary(), &CredentialType::OAuth2);
+        client.revoke_credential(&attestor);
+
+        assert!(client.get_credential_binding(&attestor, &url).orphaned);
+
+        let orphaned = client.validate_credential_bindings(&attestor);
+        assert_eq!(orphaned.len(), 1);
+        assert_eq!(orphaned.get(0).unwrap().endpoint_url, url);
+    }
+
+    #[test]
+    fn test_unbind_credential() {
+        let env = Env::default();
+        let (client, attestor, url) = setup(&env);
+
+        client.bind_endpoint_credential(&attestor, &url, &primary(), &CredentialType::OAuth2);
+        client.unbind_endpoint_credential(&attestor, &url);
+
+        let result = client.try_get_credential_binding(&attestor, &url);
+        assert_eq!(result, Err(Ok(Error::CredentialNotFound)));
+        assert_eq!(client.validate_credential_bindings(&attestor).len(), 0);
+    }
+
+    #[test]
+    fn test_pin_and_rotate_fingerprint() {
+        let env = Env::default();
+        let (client, attestor, url) = setup(&env);
+
+        let cert = Bytes::from_slice(&env, &[9u8; 64]);
+        client.store_encrypted_credential(&attestor, &CredentialType::MutualTLS, &cert, &0);
+        client.bind_endpoint_credential(&attestor, &url, &primary(), &CredentialType::MutualTLS);
+
+        let first = BytesN::from_array(&env, &[1u8; 32]);
+        let second = BytesN::from_array(&env, &[2u8; 32]);
+
+        client.pin_endpoint_fingerprint(&attestor, &url, &first);
+        assert!(client.verify_endpoint_fingerprint(&attestor, &url, &first));
+        assert!(!client.verify_endpoint_fingerprint(&attestor, &url, &second));
+
+        // Rotation keeps the previous fingerprint valid during rollover
+        client.pin_endpoint_fingerprint(&attestor, &url, &second);
+        assert!(client.verify_endpoint_fingerprint(&attestor, &url, &first));
+        assert!(client.verify_endpoint_fingerprint(&attestor, &url, &second));
+
+        client.retire_previous_fingerprint(&attestor, &url);
+        assert!(!client.verify_endpoint_fingerprint(&attestor, &url, &first));
+        assert!(client.verify_endpoint_fingerprint(&attestor, &url, &second));
+    }
+
+    #[test]
+    fn test_pin_requires_mtls_binding() {
+        let env = Env::default();
+        let (client, attestor, url) = setup(&env);
+
+        client.bind_endpoint_credential(&attestor, &url, &primary(), &CredentialType::OAuth2);
+
+        let fingerprint = BytesN::from_array(&env, &[1u8; 32]);
+        let result = client.try_pin_endpoint_fingerprint(&attestor, &url, &fingerprint);
+        assert_eq!(result, Err(Ok(Error::InvalidCredentialFormat)));
+    }
+
+    #[test]
+    fn test_labeled_credentials_rotate_independently() {
+        let env = Env::default();
+        let (client, attestor, url) = setup(&env);
+
+        let backup = symbol_short!("backup");
+        let token = Bytes::from_slice(&env, &[8u8; 32]);
+        client.store_labeled_credential(&attestor, &backup, &CredentialType::OAuth2, &token, &0);
+
+        let labels = client.get_credential_labels(&attestor);
+        assert_eq!(labels.len(), 2);
+        assert!(labels.contains(primary()));
+        assert!(labels.contains(&backup));
+
+        client.bind_endpoint_credential(&attestor, &url, &backup, &CredentialType::OAuth2);
+
+        // Revoking the primary credential leaves the backup binding intact
+        client.revoke_credential(&attestor);
+        assert!(!client.get_credential_binding(&attestor, &url).orphaned);
+        assert_eq!(client.get_credential_labels(&attestor).len(), 1);
+
+        let rotated = Bytes::from_slice(&env, &[9u8; 32]);
+        client.rotate_labeled_credential(&attestor, &backup, &CredentialType::OAuth2, &rotated, &0);
+        assert!(!client.check_label_rotation(&attestor, &backup));
+
+        client.revoke_labeled_credential(&attestor, &backup);
+        assert!(client.get_credential_binding(&attestor, &url).orphaned);
+    }
+
+    #[test]
+    fn test_rotate_unknown_label_fails() {
+        let env = Env::default();
+        let (client, attestor, _) = setup(&env);
+
+        let token = Bytes::from_slice(&env, &[8u8; 32]);
+        let result = client.try_rotate_labeled_credential(
+            &attestor,
+            &symbol_short!("missing"),
+            &CredentialType::OAuth2,
+            &token,
+            &0,
+        );
+        assert_eq!(result, Err(Ok(Error::CredentialNotFound)));
+    }
+}
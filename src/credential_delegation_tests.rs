@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod credential_delegation_tests {
+    use crate::testutils::attestor_public_key;
+    use crate::{AnchorKitContract, AnchorKitContractClient, CredentialType, Error};
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{Address as _, Ledger},
+        Address, Bytes, Env,
+    };
+
+    fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let attestor = Address::generate(env);
+        client.initialize(&admin);
+        client.register_attestor(&attestor, &attestor_public_key(env));
+
+        let token = Bytes::from_slice(env, &[7u8; 32]);
+        client.store_encrypted_credential(&attestor, &CredentialType::OAuth2, &token, &0);
+        let backup = Bytes::from_slice(env, &[8u8; 32]);
+        client.store_labeled_credential(
+            &attestor,
+            &symbol_short!("backup"),
+            &CredentialType::OAuth2,
+            &backup,
+            &0,
+        );
+
+        (client, attestor)
+    }
+
+    #[test]
+    fn test_operator_reads_only_granted_label() {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.timestamp = 1000);
+        let (client, attestor) = setup(&env);
+        let operator = Address::generate(&env);
+
+        client.grant_credential_access(&attestor, &operator, &symbol_short!("primary"), &2000);
+
+        assert!(client.has_credential_access(&attestor, &operator, &symbol_short!("primary")));
+        let credential =
+            client.get_delegated_credential(&attestor, &operator, &symbol_short!("primary"));
+        assert_eq!(credential.encrypted_value, Bytes::from_slice(&env, &[7u8; 32]));
+
+        let result =
+            client.try_get_delegated_credential(&attestor, &operator, &symbol_short!("backup"));
+        assert_eq!(result, Err(Ok(Error::CredentialNotFound)));
+    }
+
+    #[test]
+    fn test_grant_expires() {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.timestamp = 1000);
+        let (client, attestor) = setup(&env);
+        let operator = Address::generate(&env);
+
+        client.grant_credential_access(&attestor, &operator, &symbol_short!("primary"), &2000);
+
+        env.ledger().with_mut(|li| li.timestamp = 2000);
+        assert!(!client.has_credential_access(&attestor, &operator, &symbol_short!("primary")));
+        let result =
+            client.try_get_delegated_credential(&attestor, &operator, &symbol_short!("primary"));
+        assert_eq!(result, Err(Ok(Error::CredentialExpired)));
+    }
+
+    #[test]
+    fn test_revoke_access() {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.timestamp = 1000);
+        let (client, attestor) = setup(&env);
+        let operator = Address::generate(&env);
+
+        client.grant_credential_access(&attestor, &operator, &symbol_short!("primary"), &2000);
+        client.revoke_credential_access(&attestor, &operator, &symbol_short!("primary"));
+
+        assert!(!client.has_credential_access(&attestor, &operator, &symbol_short!("primary")));
+    }
+
+    #[test]
+    fn test_grant_rejects_past_expiry_and_unknown_label() {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.timestamp = 1000);
+        let (client, attestor) = setup(&env);
+        let operator = Address::generate(&env);
+
+        let result = client.try_grant_credential_access(
+            &attestor,
+            &operator,
+            &symbol_short!("primary"),
+            &1000,
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidTimestamp)));
+
+        let result = client.try_grant_credential_access(
+            &attestor,
+            &operator,
+            &symbol_short!("other"),
+            &2000,
+        );
+        assert_eq!(result, Err(Ok(Error::CredentialNotFound)));
+    }
+
+    #[test]
+    fn test_sweep_removes_only_expired_grants() {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.timestamp = 1000);
+        let (client, attestor) = setup(&env);
+        let expired = Address::generate(&env);
+        let active = Address::generate(&env);
+
+        client.grant_credential_access(&attestor, &expired, &symbol_short!("primary"), &2000);
+        client.grant_credential_access(&attestor, &expired, &symbol_short!("backup"), &2000);
+        client.grant_credential_access(&attestor, &active, &symbol_short!("primary"), &5000);
+        assert_eq!(client.sweep_credential_grants(&attestor), 0);
+
+        env.ledger().with_mut(|li| li.timestamp = 2000);
+        assert_eq!(client.sweep_credential_grants(&attestor), 2);
+        assert_eq!(
+            client.try_revoke_credential_access(&attestor, &expired, &symbol_short!("primary")),
+            Err(Ok(Error::CredentialNotFound))
+        );
+        assert!(client.has_credential_access(&attestor, &active, &symbol_short!("primary")));
+        assert_eq!(client.sweep_credential_grants(&attestor), 0);
+    }
+}
@@ -1,4 +1,7 @@
-use soroban_sdk::{contracttype, Address, Bytes, Env, String};
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, Env, String, Symbol};
+
+/// Maximum number of concurrently stored credentials per attestor
+pub const MAX_CREDENTIAL_LABELS: u32 = 8;
 
 /// Secure credential types for external API authentication
 #[contracttype]
@@ -46,6 +49,51 @@ pub struct CredentialPolicy {
     pub allow_plaintext_storage: bool,
 }
 
+/// Binding between an attestor endpoint and the credential used to call it
+/// (e.g., the SEP-38 URL uses OAuth2 while the SEP-12 URL uses mTLS).
+/// A binding is flagged as orphaned once its endpoint or credential disappears.
+/// mTLS bindings may pin the SHA-256 fingerprint of the endpoint certificate;
+/// the previous fingerprint is kept after rotation so clients can roll over.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CredentialBinding {
+    pub attestor: Address,
+    pub endpoint_url: String,
+    pub credential_label: Symbol,
+    pub credential_type: CredentialType,
+    pub bound_at: u64,
+    pub orphaned: bool,
+    pub tls_fingerprint: Option<Bytes>, // SHA-256, 32 bytes
+    pub previous_fingerprint: Option<Bytes>,
+    pub fingerprint_updated_at: u64,
+}
+
+impl CredentialBinding {
+    /// Check a presented certificate fingerprint against the pinned values
+    pub fn matches_fingerprint(&self, fingerprint: &Bytes) -> bool {
+        self.tls_fingerprint.as_ref() == Some(fingerprint)
+            || self.previous_fingerprint.as_ref() == Some(fingerprint)
+    }
+}
+
+/// Time-limited read access to one labeled credential, granted by an anchor
+/// to a delegated operator such as an outsourced payment processor
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CredentialGrant {
+    pub attestor: Address,
+    pub operator: Address,
+    pub credential_label: Symbol,
+    pub granted_at: u64,
+    pub expires_at: u64,
+}
+
+impl CredentialGrant {
+    pub fn is_expired(&self, current_timestamp: u64) -> bool {
+        current_timestamp >= self.expires_at
+    }
+}
+
 impl SecureCredential {
     /// Check if credential has expired
     pub fn is_expired(&self, current_timestamp: u64) -> bool {
@@ -133,6 +181,11 @@ impl CredentialManager {
         Ok(())
     }
 
+    /// Label used by the single-credential entrypoints
+    pub fn primary_label() -> Symbol {
+        symbol_short!("primary")
+    }
+
     /// Create a credential policy with secure defaults
     pub fn create_default_policy(attestor: Address) -> CredentialPolicy {
         CredentialPolicy {
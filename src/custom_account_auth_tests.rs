@@ -0,0 +1,164 @@
+#[cfg(test)]
+mod custom_account_auth_tests {
+    use crate::testutils::attestor_public_key;
+    use crate::{
+        AnchorKitContract, AnchorKitContractClient, Asset, AttestorConfig, Sep38Terms,
+        ServiceType,
+    };
+    use soroban_sdk::{
+        contract,
+        testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, MockAuth, MockAuthInvoke},
+        vec, Address, Env, IntoVal, String, Symbol,
+    };
+
+    /// Stand-in for a smart wallet: any contract address can act as an anchor.
+    #[contract]
+    struct PasskeyWallet;
+
+    fn asset(env: &Env, code: &str) -> Asset {
+        let issuer = String::from_str(env, "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN");
+        Asset::Classic(String::from_str(env, code), Address::from_string(&issuer))
+    }
+
+    fn setup<'a>(env: &'a Env, anchor: &Address) -> (Address, AnchorKitContractClient<'a>) {
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        env.mock_all_auths();
+        let admin = Address::generate(env);
+        client.initialize(&admin);
+        client.register_attestor(anchor, &attestor_public_key(env));
+        client.configure_services(anchor, &vec![env, ServiceType::Quotes]);
+        env.set_auths(&[]);
+
+        (contract_id, client)
+    }
+
+    #[test]
+    fn test_wallet_signs_quote_terms_only() {
+        let env = Env::default();
+        let wallet = env.register_contract(None, PasskeyWallet);
+        let (contract_id, client) = setup(&env, &wallet);
+
+        let base = asset(&env, "USD");
+        let quote = asset(&env, "USDC");
+        let terms = (
+            base.clone(),
+            quote.clone(),
+            10000u64,
+            25u32,
+            100u64,
+            100000u64,
+            3600u64,
+            Sep38Terms::sep6(),
+        );
+
+        // The wallet authorizes exactly the quote terms, as documented on submit_quote
+        env.mock_auths(&[MockAuth {
+            address: &wallet,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "submit_quote",
+                args: terms.clone().into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+
+        let quote_id = client.submit_quote(
+            &wallet, &base, &quote, &10000u64, &25u32, &100u64, &100000u64, &3600u64,
+        );
+        assert_eq!(quote_id, 1);
+    }
+
+    #[test]
+    fn test_tracked_quote_requires_single_authorization() {
+        let env = Env::default();
+        let anchor = Address::generate(&env);
+        let (contract_id, client) = setup(&env, &anchor);
+        env.mock_all_auths();
+
+        client.submit_quote_tracked(
+            &anchor,
+            &asset(&env, "USD"),
+            &asset(&env, "USDC"),
+            &10000u64,
+            &25u32,
+            &100u64,
+            &100000u64,
+            &3600u64,
+        );
+
+        let auths = env.auths();
+        assert_eq!(auths.len(), 1);
+        let (address, invocation) = &auths[0];
+        assert_eq!(address, &anchor);
+        match &invocation.function {
+            AuthorizedFunction::Contract((contract, function, _)) => {
+                assert_eq!(contract, &contract_id);
+                assert_eq!(function, &Symbol::new(&env, "submit_quote_tracked"));
+            }
+            _ => panic!("unexpected authorized function"),
+        }
+        assert!(invocation.sub_invocations.is_empty());
+    }
+
+    #[test]
+    fn test_transfer_auth_covers_destination_and_amount() {
+        let env = Env::default();
+        let wallet = env.register_contract(None, PasskeyWallet);
+        let (contract_id, client) = setup(&env, &wallet);
+        let destination = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initiate_transfer(&wallet, &destination, &500i128);
+
+        assert_eq!(
+            env.auths(),
+            alloc::vec![(
+                wallet.clone(),
+                AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        contract_id,
+                        Symbol::new(&env, "initiate_transfer"),
+                        (destination, 500i128).into_val(&env),
+                    )),
+                    sub_invocations: alloc::vec![],
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn test_batch_registration_is_one_admin_authorization() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let config = |name: &str| AttestorConfig {
+            name: String::from_str(&env, name),
+            address: Address::generate(&env),
+            public_key: attestor_public_key(&env),
+            endpoint: String::from_str(&env, "https://anchor.example.com"),
+            role: String::from_str(&env, "attestor"),
+            enabled: true,
+        };
+        let batch = vec![&env, config("anchor-one"), config("anchor-two")];
+        client.batch_register_attestors(&batch);
+
+        // A single authorization covers the whole batch, so a multisig or
+        // custom-account admin signs one payload regardless of batch size
+        let auths = env.auths();
+        assert_eq!(auths.len(), 1);
+        assert_eq!(auths[0].0, admin);
+        match &auths[0].1.function {
+            AuthorizedFunction::Contract((_, function, args)) => {
+                assert_eq!(function, &Symbol::new(&env, "batch_register_attestors"));
+                assert_eq!(args.len(), 1);
+            }
+            _ => panic!("unexpected authorized function"),
+        }
+    }
+}
@@ -0,0 +1,378 @@
+use std::path::PathBuf;
+
+use sha2::{Digest, Sha256};
+use stellar_strkey::ed25519;
+use stellar_xdr::curr::{
+    AccountId, ContractIdPreimage, ContractIdPreimageFromAddress, Hash, HashIdPreimage,
+    HashIdPreimageContractId, Limits, PublicKey, ScAddress, Uint256, WriteXdr,
+};
+
+use crate::signer::Signer;
+use crate::stellar_cli::{self, Network};
+
+/// Optimized contract produced by `anchorkit build --release`
+pub const DEFAULT_WASM: &str = "target/wasm32-unknown-unknown/release/anchorkit.wasm";
+
+/// Where to deploy, and which key pays for and administers the contract
+pub struct DeployOptions {
+    pub network: Network,
+    /// Deploying account's signer
+    pub source: Signer,
+    /// Admin address; defaults to the address of `source`
+    pub admin: Option<String>,
+    pub wasm: PathBuf,
+}
+
+/// Resolved deployment inputs
+struct Plan {
+    source: String,
+    admin: String,
+    /// Deploying account and salt, which fix the contract ID up front
+    deployer: String,
+    salt: [u8; 32],
+    wasm_hash: [u8; 32],
+}
+
+/// Upload and instantiate the contract, then initialize it with the admin.
+/// Uses the `stellar` CLI for RPC submission; keystore, external and Ledger
+/// signers sign outside it. Returns the contract ID.
+///
+/// soroban-sdk 21 can't initialize at deployment, so the contract is briefly
+/// uninitialized in between. The admin is read back afterwards, and a
+/// contract someone else initialized first is reported instead of returned.
+pub fn deploy(options: &DeployOptions) -> Result<String, String> {
+    let plan = preflight(options)?;
+    let submit = |args: Vec<String>| options.source.submit(&args, &options.network);
+
+    let contract_id = match &options.source {
+        Signer::Identity(_) => submit(deploy_args(options, &plan)?)?,
+        _ => {
+            submit(upload_args(options, &plan)?)?;
+            submit(deploy_args(options, &plan)?)?;
+            contract_id(&options.network, &plan.deployer, &plan.salt)?
+        }
+    };
+    if !is_contract_id(&contract_id) {
+        return Err(format!("unexpected deploy output: {}", contract_id));
+    }
+    submit(initialize_args(options, &contract_id, &plan)?)?;
+    verify_admin(
+        &plan.admin,
+        &stellar_cli::simulate(&contract_id, &plan.source, &options.network, "get_admin", &[])?,
+    )
+    .map_err(|e| format!("contract {}: {}", contract_id, e))?;
+
+    Ok(contract_id)
+}
+
+/// Validate the deployment without submitting anything. Returns the commands
+/// `deploy` would run.
+pub fn dry_run(options: &DeployOptions) -> Result<Vec<String>, String> {
+    let plan = preflight(options)?;
+
+    let mut steps = Vec::new();
+    if !matches!(options.source, Signer::Identity(_)) {
+        steps.push(upload_args(options, &plan)?);
+    }
+    steps.push(deploy_args(options, &plan)?);
+    steps.push(initialize_args(options, "<CONTRACT_ID>", &plan)?);
+
+    let signing = options.source.describe_submission();
+    Ok(steps
+        .iter()
+        .map(|args| match &signing {
+            Some(signing) => format!("{}\n{}", stellar_cli::render(args), signing),
+            None => stellar_cli::render(args),
+        })
+        .collect())
+}
+
+/// Check the WASM and the `stellar` CLI are available and resolve the
+/// deployer and admin addresses.
+fn preflight(options: &DeployOptions) -> Result<Plan, String> {
+    let wasm = std::fs::read(&options.wasm).map_err(|_| {
+        format!(
+            "WASM not found at {}; run `anchorkit build --release` first",
+            options.wasm.display()
+        )
+    })?;
+    stellar_cli::require_cli()?;
+
+    let deployer = options.source.address()?;
+    let mut salt = [0u8; 32];
+    getrandom::getrandom(&mut salt).map_err(|e| format!("no system randomness: {}", e))?;
+    Ok(Plan {
+        source: options.source.source_arg()?,
+        admin: options.admin.clone().unwrap_or_else(|| deployer.clone()),
+        deployer,
+        salt,
+        wasm_hash: Sha256::digest(&wasm).into(),
+    })
+}
+
+fn upload_args(options: &DeployOptions, plan: &Plan) -> Result<Vec<String>, String> {
+    let mut args = vec![
+        "contract".to_string(),
+        "upload".to_string(),
+        "--wasm".to_string(),
+        options.wasm.to_string_lossy().into_owned(),
+        "--source".to_string(),
+        plan.source.clone(),
+    ];
+    args.extend(options.network.args()?);
+    Ok(args)
+}
+
+/// Identities upload and deploy in one step; other signers deploy the hash
+/// uploaded by `upload_args`.
+fn deploy_args(options: &DeployOptions, plan: &Plan) -> Result<Vec<String>, String> {
+    let mut args = vec!["contract".to_string(), "deploy".to_string()];
+    match options.source {
+        Signer::Identity(_) => args.extend([
+            "--wasm".to_string(),
+            options.wasm.to_string_lossy().into_owned(),
+        ]),
+        _ => args.extend(["--wasm-hash".to_string(), hex::encode(plan.wasm_hash)]),
+    }
+    args.extend([
+        "--salt".to_string(),
+        hex::encode(plan.salt),
+        "--source".to_string(),
+        plan.source.clone(),
+    ]);
+    args.extend(options.network.args()?);
+    Ok(args)
+}
+
+fn initialize_args(
+    options: &DeployOptions,
+    contract_id: &str,
+    plan: &Plan,
+) -> Result<Vec<String>, String> {
+    let mut args = vec![
+        "contract".to_string(),
+        "invoke".to_string(),
+        "--id".to_string(),
+        contract_id.to_string(),
+        "--source".to_string(),
+        plan.source.clone(),
+    ];
+    args.extend(options.network.args()?);
+    args.extend([
+        "--".to_string(),
+        "initialize".to_string(),
+        "--admin".to_string(),
+        plan.admin.clone(),
+    ]);
+    Ok(args)
+}
+
+/// Check the `get_admin` output of a freshly initialized contract is `expected`.
+fn verify_admin(expected: &str, output: &str) -> Result<(), String> {
+    let admin: String = serde_json::from_str(output)
+        .map_err(|_| format!("unexpected get_admin output: {}", output))?;
+    if admin != expected {
+        return Err(format!(
+            "initialized with admin {} instead of {}; do not use it",
+            admin, expected
+        ));
+    }
+    Ok(())
+}
+
+/// Address of a contract deployed by `deployer` with `salt`, derived the same
+/// way the network does.
+fn contract_id(network: &Network, deployer: &str, salt: &[u8; 32]) -> Result<String, String> {
+    let deployer = ed25519::PublicKey::from_string(deployer)
+        .map_err(|_| format!("invalid deployer address {}", deployer))?;
+    let preimage = HashIdPreimage::ContractId(HashIdPreimageContractId {
+        network_id: Hash(network.id()?),
+        contract_id_preimage: ContractIdPreimage::Address(ContractIdPreimageFromAddress {
+            address: ScAddress::Account(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(
+                deployer.0,
+            )))),
+            salt: Uint256(*salt),
+        }),
+    });
+    let xdr = preimage
+        .to_xdr(Limits::none())
+        .map_err(|e| format!("failed to encode contract ID preimage: {}", e))?;
+    Ok(stellar_strkey::Contract(Sha256::digest(xdr).into()).to_string())
+}
+
+/// Contract IDs are 56-character strkeys starting with `C`.
+fn is_contract_id(value: &str) -> bool {
+    value.len() == 56
+        && value.starts_with('C')
+        && value
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEPLOYER: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+    const ADMIN: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+
+    fn options(source: &str, rpc_url: Option<&str>) -> DeployOptions {
+        DeployOptions {
+            network: Network {
+                name: "testnet".to_string(),
+                rpc_url: rpc_url.map(str::to_string),
+                passphrase: None,
+            },
+            source: Signer::parse(source).unwrap(),
+            admin: None,
+            wasm: PathBuf::from(DEFAULT_WASM),
+        }
+    }
+
+    fn plan(source: &str) -> Plan {
+        Plan {
+            source: source.to_string(),
+            admin: ADMIN.to_string(),
+            deployer: DEPLOYER.to_string(),
+            salt: [1; 32],
+            wasm_hash: [2; 32],
+        }
+    }
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_identity_uploads_and_deploys_in_one_step() {
+        let options = options("alice", None);
+        let salt = hex::encode([1u8; 32]);
+        assert_eq!(
+            deploy_args(&options, &plan("alice")).unwrap(),
+            args(&[
+                "contract",
+                "deploy",
+                "--wasm",
+                DEFAULT_WASM,
+                "--salt",
+                &salt,
+                "--source",
+                "alice",
+                "--network",
+                "testnet",
+            ])
+        );
+    }
+
+    #[test]
+    fn test_other_signers_deploy_the_uploaded_hash() {
+        let options = options(
+            &format!("ledger:{}", DEPLOYER),
+            Some("https://rpc.example.com"),
+        );
+        let network = [
+            "--rpc-url",
+            "https://rpc.example.com",
+            "--network-passphrase",
+            "Test SDF Network ; September 2015",
+        ];
+
+        let mut expected = args(&[
+            "contract",
+            "upload",
+            "--wasm",
+            DEFAULT_WASM,
+            "--source",
+            DEPLOYER,
+        ]);
+        expected.extend(args(&network));
+        assert_eq!(upload_args(&options, &plan(DEPLOYER)).unwrap(), expected);
+
+        let mut expected = args(&[
+            "contract",
+            "deploy",
+            "--wasm-hash",
+            &hex::encode([2u8; 32]),
+            "--salt",
+            &hex::encode([1u8; 32]),
+            "--source",
+            DEPLOYER,
+        ]);
+        expected.extend(args(&network));
+        assert_eq!(deploy_args(&options, &plan(DEPLOYER)).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_initialize_args() {
+        let options = options("alice", None);
+        let contract_id = contract_id(&options.network, DEPLOYER, &[1; 32]).unwrap();
+        assert_eq!(
+            initialize_args(&options, &contract_id, &plan("alice")).unwrap(),
+            args(&[
+                "contract",
+                "invoke",
+                "--id",
+                &contract_id,
+                "--source",
+                "alice",
+                "--network",
+                "testnet",
+                "--",
+                "initialize",
+                "--admin",
+                ADMIN,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_contract_id_depends_on_network_deployer_and_salt() {
+        let testnet = options("alice", None).network;
+        let id = contract_id(&testnet, DEPLOYER, &[1; 32]).unwrap();
+        assert!(is_contract_id(&id));
+        assert_eq!(contract_id(&testnet, DEPLOYER, &[1; 32]).unwrap(), id);
+        assert_ne!(contract_id(&testnet, DEPLOYER, &[2; 32]).unwrap(), id);
+        assert_ne!(contract_id(&testnet, ADMIN, &[1; 32]).unwrap(), id);
+
+        let mainnet = Network {
+            name: "mainnet".to_string(),
+            rpc_url: None,
+            passphrase: None,
+        };
+        assert_ne!(contract_id(&mainnet, DEPLOYER, &[1; 32]).unwrap(), id);
+        assert!(contract_id(&testnet, "alice", &[1; 32]).is_err());
+    }
+
+    #[test]
+    fn test_is_contract_id() {
+        let id = contract_id(&options("alice", None).network, DEPLOYER, &[1; 32]).unwrap();
+        assert!(is_contract_id(&id));
+        assert!(!is_contract_id(DEPLOYER));
+        assert!(!is_contract_id(&id[1..]));
+        assert!(!is_contract_id(&id.to_lowercase()));
+        assert!(!is_contract_id("Error: transaction failed"));
+    }
+
+    #[test]
+    fn test_verify_admin() {
+        assert!(verify_admin(ADMIN, &format!("\"{}\"", ADMIN)).is_ok());
+        assert_eq!(
+            verify_admin(ADMIN, &format!("\"{}\"", DEPLOYER)),
+            Err(format!(
+                "initialized with admin {} instead of {}; do not use it",
+                DEPLOYER, ADMIN
+            ))
+        );
+        assert!(verify_admin(ADMIN, "error").is_err());
+    }
+
+    #[test]
+    fn test_preflight_needs_the_wasm() {
+        let mut options = options("alice", None);
+        options.wasm = PathBuf::from("missing/anchorkit.wasm");
+        assert_eq!(
+            preflight(&options).err().unwrap(),
+            "WASM not found at missing/anchorkit.wasm; run `anchorkit build --release` first"
+        );
+    }
+}
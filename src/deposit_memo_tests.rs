@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod deposit_memo_tests {
+    use crate::testutils::attestor_public_key;
+    use crate::{AnchorKitContract, AnchorKitContractClient, DepositMemo, Error};
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+
+    fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let anchor = Address::generate(env);
+        client.initialize(&admin);
+        client.register_attestor(&anchor, &attestor_public_key(env));
+
+        (client, anchor)
+    }
+
+    #[test]
+    fn test_register_and_confirm_memo_deposit() {
+        let env = Env::default();
+        let (client, anchor) = setup(&env);
+        let subject = Address::generate(&env);
+        let transfer_id = client.initiate_transfer(&subject, &anchor, &250);
+
+        let memo = DepositMemo::Id(8_812_004);
+        client.register_deposit_memo(&anchor, &memo, &subject, &transfer_id);
+
+        let record = client.get_deposit_memo(&anchor, &memo);
+        assert_eq!(record.subject, subject);
+        assert_eq!(record.transfer_id, transfer_id);
+        assert!(!record.settled);
+
+        let settled =
+            client.confirm_memo_deposit(&anchor, &memo, &BytesN::from_array(&env, &[1u8; 32]));
+        assert_eq!(settled, transfer_id);
+        assert!(client.get_deposit_memo(&anchor, &memo).settled);
+
+        let replay =
+            client.try_confirm_memo_deposit(&anchor, &memo, &BytesN::from_array(&env, &[1u8; 32]));
+        assert_eq!(replay, Err(Ok(Error::ReplayAttack)));
+    }
+
+    #[test]
+    fn test_memo_cannot_be_reassigned_while_pending() {
+        let env = Env::default();
+        let (client, anchor) = setup(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let memo = DepositMemo::Text(String::from_str(&env, "INV-2041"));
+
+        client.register_deposit_memo(&anchor, &memo, &alice, &1);
+        let result = client.try_register_deposit_memo(&anchor, &memo, &bob, &2);
+        assert_eq!(result, Err(Ok(Error::AlreadyExists)));
+
+        // Once settled the memo can be recycled for a new deposit
+        client.confirm_memo_deposit(&anchor, &memo, &BytesN::from_array(&env, &[2u8; 32]));
+        client.register_deposit_memo(&anchor, &memo, &bob, &2);
+        assert_eq!(client.get_deposit_memo(&anchor, &memo).subject, bob);
+    }
+
+    #[test]
+    fn test_memos_are_scoped_per_anchor() {
+        let env = Env::default();
+        let (client, anchor) = setup(&env);
+        let other_anchor = Address::generate(&env);
+        client.register_attestor(&other_anchor, &attestor_public_key(&env));
+        let subject = Address::generate(&env);
+        let memo = DepositMemo::Hash(BytesN::from_array(&env, &[9u8; 32]));
+
+        client.register_deposit_memo(&anchor, &memo, &subject, &1);
+
+        let result = client.try_get_deposit_memo(&other_anchor, &memo);
+        assert_eq!(result, Err(Ok(Error::TransferNotFound)));
+    }
+
+    #[test]
+    fn test_rejects_invalid_memo_and_unregistered_anchor() {
+        let env = Env::default();
+        let (client, anchor) = setup(&env);
+        let subject = Address::generate(&env);
+
+        let too_long =
+            DepositMemo::Text(String::from_str(&env, "this memo text is longer than 28"));
+        let result = client.try_register_deposit_memo(&anchor, &too_long, &subject, &1);
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+
+        let stranger = Address::generate(&env);
+        let result = client.try_register_deposit_memo(&stranger, &DepositMemo::Id(1), &subject, &1);
+        assert_eq!(result, Err(Ok(Error::UnauthorizedAttestor)));
+    }
+}
@@ -3,16 +3,25 @@ mod deterministic_hash_tests {
     use crate::types::*;
     use soroban_sdk::{testutils::Address as _, xdr::ToXdr, Address, BytesN, Env, String};
 
+    fn asset(env: &Env, code: &str) -> Asset {
+        let issuer = String::from_str(env, "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN");
+        Asset::Classic(String::from_str(env, code), Address::from_string(&issuer))
+    }
+
     fn create_test_env() -> Env {
         Env::default()
     }
 
     fn create_quote_request(env: &Env, base: &str, quote: &str, amount: u64) -> QuoteRequest {
         QuoteRequest {
-            base_asset: String::from_str(env, base),
-            quote_asset: String::from_str(env, quote),
+            base_asset: asset(env, base),
+            quote_asset: asset(env, quote),
             amount,
             operation_type: ServiceType::Deposits,
+            context: QuoteContext::Sep6,
+            amount_side: AmountSide::Sell,
+            delivery_method: None,
+            country_code: None,
         }
     }
 
@@ -42,17 +51,25 @@ mod deterministic_hash_tests {
         let env = create_test_env();
 
         let req1 = QuoteRequest {
-            base_asset: String::from_str(&env, "USD"),
-            quote_asset: String::from_str(&env, "USDC"),
+            base_asset: asset(&env, "USD"),
+            quote_asset: asset(&env, "USDC"),
             amount: 1000,
             operation_type: ServiceType::Deposits,
+            context: QuoteContext::Sep6,
+            amount_side: AmountSide::Sell,
+            delivery_method: None,
+            country_code: None,
         };
 
         let req2 = QuoteRequest {
             operation_type: ServiceType::Deposits,
+            context: QuoteContext::Sep6,
+            amount_side: AmountSide::Sell,
+            delivery_method: None,
+            country_code: None,
             amount: 1000,
-            quote_asset: String::from_str(&env, "USDC"),
-            base_asset: String::from_str(&env, "USD"),
+            quote_asset: asset(&env, "USDC"),
+            base_asset: asset(&env, "USD"),
         };
 
         let hash1 = hash_struct(&env, &req1);
@@ -92,6 +109,8 @@ mod deterministic_hash_tests {
             max_anchors: 5,
             require_kyc: true,
             min_reputation: 8000,
+            corridor_id: 0,
+            record_exclusions: false,
         };
 
         let routing2 = RoutingRequest {
@@ -100,6 +119,8 @@ mod deterministic_hash_tests {
             max_anchors: 5,
             require_kyc: true,
             min_reputation: 8000,
+            corridor_id: 0,
+            record_exclusions: false,
         };
 
         let hash1 = hash_struct(&env, &routing1);
@@ -122,14 +143,18 @@ mod deterministic_hash_tests {
             max_anchors: 5,
             require_kyc: true,
             min_reputation: 8000,
+            corridor_id: 0,
+            record_exclusions: false,
         };
 
         let routing2 = RoutingRequest {
             min_reputation: 8000,
+            corridor_id: 0,
             require_kyc: true,
             max_anchors: 5,
             strategy: RoutingStrategy::BestRate,
             request: quote_req,
+            record_exclusions: false,
         };
 
         let hash1 = hash_struct(&env, &routing1);
@@ -153,6 +178,8 @@ mod deterministic_hash_tests {
             max_anchors: 5,
             require_kyc: true,
             min_reputation: 8000,
+            corridor_id: 0,
+            record_exclusions: false,
         };
 
         let routing2 = RoutingRequest {
@@ -161,6 +188,8 @@ mod deterministic_hash_tests {
             max_anchors: 5,
             require_kyc: true,
             min_reputation: 8000,
+            corridor_id: 0,
+            record_exclusions: false,
         };
 
         let hash1 = hash_struct(&env, &routing1);
@@ -182,18 +211,32 @@ mod deterministic_hash_tests {
             anchor: anchor.clone(),
             request: quote_req.clone(),
             quote_id: 42,
+            quote_sequence: 1,
             require_kyc: true,
             session_id: 100,
             ttl_seconds: 300,
+            subject: None,
+            referrer: None,
+            corridor_id: 0,
+            not_before: 0,
+            requirement_template: 0,
+            correlation_id: BytesN::from_array(&env, &[0; 16]),
         };
 
         let builder2 = TransactionIntentBuilder {
             anchor,
             request: quote_req,
             quote_id: 42,
+            quote_sequence: 1,
             require_kyc: true,
             session_id: 100,
             ttl_seconds: 300,
+            subject: None,
+            referrer: None,
+            corridor_id: 0,
+            not_before: 0,
+            requirement_template: 0,
+            correlation_id: BytesN::from_array(&env, &[0; 16]),
         };
 
         let hash1 = hash_struct(&env, &builder1);
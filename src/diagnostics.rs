@@ -0,0 +1,56 @@
+use soroban_sdk::{Env, Symbol, Vec};
+
+use crate::{errors::Error, types::CheckFailure};
+
+/// Collects every failed check of a validation pass.
+///
+/// Contract errors are bare codes and a failed call is rolled back, so the
+/// validation-heavy entrypoints run their checks through a report: the real
+/// call fails with the first recorded error, while the matching read-only
+/// entrypoint returns all failures with the field, expected and actual values.
+pub struct CheckReport {
+    failures: Vec<CheckFailure>,
+    first_error: Option<Error>,
+}
+
+impl CheckReport {
+    pub fn new(env: &Env) -> Self {
+        CheckReport {
+            failures: Vec::new(env),
+            first_error: None,
+        }
+    }
+
+    pub fn fail(&mut self, env: &Env, error: Error, field: &str, expected: i128, actual: i128) {
+        self.fail_on(error, Symbol::new(env, field), expected, actual);
+    }
+
+    /// Like `fail`, for fields only known at runtime (e.g. attestation kinds).
+    pub fn fail_on(&mut self, error: Error, field: Symbol, expected: i128, actual: i128) {
+        self.failures.push_back(CheckFailure {
+            error: error as u32,
+            field,
+            expected,
+            actual,
+        });
+        self.first_error.get_or_insert(error);
+    }
+
+    /// Record a failure if `condition` is false; booleans are reported as 1/0.
+    pub fn require(&mut self, env: &Env, condition: bool, error: Error, field: &str) {
+        if !condition {
+            self.fail(env, error, field, 1, 0);
+        }
+    }
+
+    pub fn failures(self) -> Vec<CheckFailure> {
+        self.failures
+    }
+
+    pub fn into_result(self) -> Result<(), Error> {
+        match self.first_error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
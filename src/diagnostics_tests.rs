@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod diagnostics_tests {
+    use crate::testutils::{classic_asset, set_time, AnchorKitFixture};
+    use crate::{CheckFailure, CorridorRules, Error, ServiceType};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, Symbol};
+
+    fn failure(env: &Env, error: Error, field: &str, expected: i128, actual: i128) -> CheckFailure {
+        CheckFailure {
+            error: error as u32,
+            field: Symbol::new(env, field),
+            expected,
+            actual,
+        }
+    }
+
+    #[test]
+    fn test_validate_transaction_intent_reports_every_failed_check() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::KYC]);
+
+        let mut builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 0)
+            .require_kyc()
+            .with_quote_id(42);
+        builder.subject = None;
+        let failures = kit.client.validate_transaction_intent(&builder);
+        assert_eq!(
+            failures,
+            vec![
+                &env,
+                failure(&env, Error::InvalidTransactionIntent, "amount", 1, 0),
+                failure(&env, Error::InvalidTransactionIntent, "subject", 1, 0),
+                failure(&env, Error::QuoteNotFound, "quote_id", 1, 0),
+            ]
+        );
+
+        // The real call fails with the first reported error
+        let result = kit.client.try_build_transaction_intent(&builder);
+        assert_eq!(result, Err(Ok(Error::InvalidTransactionIntent)));
+
+        let subject = Address::generate(&env);
+        kit.client.set_kyc_level(&anchor, &subject, &1);
+        let valid = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 100)
+            .require_kyc()
+            .with_subject(subject);
+        assert!(kit.client.validate_transaction_intent(&valid).is_empty());
+    }
+
+    #[test]
+    fn test_explain_compliance_reports_expected_and_actual() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::KYC]);
+        let subject = Address::generate(&env);
+        let intent = kit.client.build_transaction_intent(
+            &kit.deposit_intent(&anchor, "USDC", "EUR", 5_000)
+                .with_subject(subject.clone()),
+        );
+        assert!(kit.client.explain_compliance(&intent).is_empty());
+
+        let sanctions = Symbol::new(&env, "sanctions");
+        kit.client.set_corridor_rules(
+            &kit.admin,
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &CorridorRules {
+                max_amount: 1_000,
+                required_attestations: vec![&env, sanctions],
+                kyc_level: 2,
+                travel_rule_threshold: 0,
+            },
+        );
+
+        let failures = kit.client.explain_compliance(&intent);
+        assert_eq!(
+            failures,
+            vec![
+                &env,
+                failure(&env, Error::ComplianceNotMet, "amount", 1_000, 5_000),
+                failure(&env, Error::ComplianceNotMet, "kyc_level", 2, 0),
+                failure(&env, Error::ComplianceNotMet, "sanctions", 1, 0),
+            ]
+        );
+        let result = kit.client.try_check_compliance(&intent);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+
+        kit.client.block_address(&kit.admin, &subject);
+        let failures = kit.client.explain_compliance(&intent);
+        assert_eq!(failures.get(0).unwrap().error, Error::AddressBlocked as u32);
+        let result = kit.client.try_check_compliance(&intent);
+        assert_eq!(result, Err(Ok(Error::AddressBlocked)));
+    }
+
+    #[test]
+    fn test_validate_intent_is_a_dry_run_of_build() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+        let quote_id = kit.seed_quote(&anchor, "USDC", "EUR", 9_000, 120);
+        kit.client.set_corridor_rules(
+            &kit.admin,
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &CorridorRules {
+                max_amount: 1_000,
+                required_attestations: vec![&env],
+                kyc_level: 0,
+                travel_rule_threshold: 0,
+            },
+        );
+
+        // Corridor rules are covered too, unlike `validate_transaction_intent`
+        let too_large = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 5_000)
+            .with_quote_id(quote_id)
+            .with_quote_sequence(1);
+        let validation = kit.client.validate_intent(&too_large);
+        assert!(!validation.valid);
+        assert_eq!(
+            validation.failures,
+            vec![
+                &env,
+                failure(&env, Error::ComplianceNotMet, "amount", 1_000, 5_000)
+            ]
+        );
+        assert!(kit.client.validate_transaction_intent(&too_large).is_empty());
+        let result = kit.client.try_build_transaction_intent(&too_large);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+
+        // The expiry is clamped to the quote and no intent ID is consumed
+        let valid = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 500)
+            .with_quote_id(quote_id)
+            .with_quote_sequence(1);
+        let validation = kit.client.validate_intent(&valid);
+        assert!(validation.valid);
+        assert_eq!(validation.expires_at, 1_120);
+        let intent = kit.client.build_transaction_intent(&valid);
+        assert_eq!(intent.intent_id, 1);
+        assert_eq!(intent.expires_at, validation.expires_at);
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod did_binding_tests {
+    use crate::testutils::{attestor_public_key, sign_attestation};
+    use crate::{AnchorKitContract, AnchorKitContractClient, Error};
+    use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env};
+
+    fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let attestor = Address::generate(env);
+        client.initialize(&admin);
+        client.register_attestor(&attestor, &attestor_public_key(env));
+
+        (client, attestor)
+    }
+
+    fn did_hash(env: &Env, did: &str) -> BytesN<32> {
+        env.crypto()
+            .sha256(&Bytes::from_slice(env, did.as_bytes()))
+            .into()
+    }
+
+    #[test]
+    fn test_bind_resolve_and_revoke_did() {
+        let env = Env::default();
+        let (client, _) = setup(&env);
+        let subject = Address::generate(&env);
+        let did = did_hash(&env, "did:web:alice.example.com");
+
+        client.bind_did(&subject, &did);
+        assert_eq!(client.resolve_did(&did), Some(subject.clone()));
+        assert_eq!(client.get_did_binding(&subject).unwrap().did_hash, did);
+
+        client.revoke_did(&subject);
+        assert_eq!(client.resolve_did(&did), None);
+        assert_eq!(client.get_did_binding(&subject), None);
+        assert_eq!(client.try_revoke_did(&subject), Err(Ok(Error::CredentialNotFound)));
+    }
+
+    #[test]
+    fn test_did_cannot_be_claimed_by_second_subject() {
+        let env = Env::default();
+        let (client, _) = setup(&env);
+        let alice = Address::generate(&env);
+        let mallory = Address::generate(&env);
+        let did = did_hash(&env, "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK");
+
+        client.bind_did(&alice, &did);
+        assert_eq!(client.try_bind_did(&mallory, &did), Err(Ok(Error::AlreadyExists)));
+
+        // Rebinding to a new DID releases the old one
+        let new_did = did_hash(&env, "did:web:alice.example.com");
+        client.bind_did(&alice, &new_did);
+        assert_eq!(client.resolve_did(&did), None);
+        client.bind_did(&mallory, &did);
+        assert_eq!(client.resolve_did(&did), Some(mallory));
+    }
+
+    #[test]
+    fn test_attestation_references_subject_did() {
+        let env = Env::default();
+        let (client, attestor) = setup(&env);
+        let subject = Address::generate(&env);
+        let did = did_hash(&env, "did:web:alice.example.com");
+        let session_id = client.create_session(&subject);
+
+        let payload_hash = BytesN::from_array(&env, &[1; 32]);
+        let before = client.submit_attestation_with_session(
+            &session_id,
+            &attestor,
+            &subject,
+            &1_700_000_001u64,
+            &payload_hash,
+            &sign_attestation(&env, &attestor, &subject, 1_700_000_001u64, &payload_hash),
+            &None,
+        );
+        assert_eq!(client.get_attestation_did(&before), None);
+
+        client.bind_did(&subject, &did);
+        let payload_hash = BytesN::from_array(&env, &[2; 32]);
+        let after = client.submit_attestation_with_session(
+            &session_id,
+            &attestor,
+            &subject,
+            &1_700_000_002u64,
+            &payload_hash,
+            &sign_attestation(&env, &attestor, &subject, 1_700_000_002u64, &payload_hash),
+            &None,
+        );
+        assert_eq!(client.get_attestation_did(&after), Some(did.clone()));
+
+        // The reference is a snapshot taken at attestation time
+        client.revoke_did(&subject);
+        assert_eq!(client.get_attestation_did(&after), Some(did));
+    }
+}
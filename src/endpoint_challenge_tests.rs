@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod endpoint_challenge_tests {
+    use crate::testutils::attestor_public_key;
+    use crate::{
+        AnchorKitContract, AnchorKitContractClient, EndpointChallenge, EndpointKind, Error, Role,
+    };
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, BytesN, Env, String,
+    };
+
+    struct Setup<'a> {
+        client: AnchorKitContractClient<'a>,
+        monitor: Address,
+        attestor: Address,
+        key: SigningKey,
+    }
+
+    fn setup(env: &Env) -> Setup<'_> {
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let monitor = Address::generate(env);
+        let attestor = Address::generate(env);
+        client.initialize(&admin);
+        client.register_attestor(&attestor, &attestor_public_key(env));
+        client.grant_role(&monitor, &Role::EndpointMonitor);
+        client.configure_endpoint(
+            &attestor,
+            &EndpointKind::Sep6,
+            &String::from_str(env, "https://anchor.example.com/sep6"),
+            &true,
+        );
+
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let public_key = BytesN::from_array(env, &key.verifying_key().to_bytes());
+        client.set_endpoint_signing_key(&attestor, &public_key);
+
+        Setup {
+            client,
+            monitor,
+            attestor,
+            key,
+        }
+    }
+
+    fn sign(env: &Env, key: &SigningKey, nonce: &BytesN<32>) -> BytesN<64> {
+        BytesN::from_array(env, &key.sign(&nonce.to_array()).to_bytes())
+    }
+
+    #[test]
+    fn test_fresh_proof_updates_health() {
+        let env = Env::default();
+        let s = setup(&env);
+
+        let nonce =
+            s.client
+                .request_endpoint_challenge(&s.monitor, &s.attestor, &EndpointKind::Sep6);
+        env.ledger().with_mut(|li| li.timestamp = 1_002);
+
+        let signature = sign(&env, &s.key, &nonce);
+        assert!(s.client.submit_endpoint_proof(
+            &s.monitor,
+            &s.attestor,
+            &EndpointKind::Sep6,
+            &signature
+        ));
+
+        let health = s.client.get_health_status(&s.attestor).unwrap();
+        assert_eq!(health.latency_ms, 2_000);
+        assert_eq!(health.failure_count, 0);
+        assert_eq!(health.last_check, 1_002);
+        assert!(
+            s.client
+                .get_endpoint(&s.attestor, &EndpointKind::Sep6)
+                .is_active
+        );
+        assert_eq!(
+            s.client
+                .get_endpoint_challenge(&s.attestor, &EndpointKind::Sep6),
+            None
+        );
+    }
+
+    #[test]
+    fn test_late_proof_marks_endpoint_inactive() {
+        let env = Env::default();
+        let s = setup(&env);
+
+        let nonce =
+            s.client
+                .request_endpoint_challenge(&s.monitor, &s.attestor, &EndpointKind::Sep6);
+        env.ledger()
+            .with_mut(|li| li.timestamp = 1_000 + EndpointChallenge::TTL_SECONDS + 1);
+
+        let signature = sign(&env, &s.key, &nonce);
+        assert!(!s.client.submit_endpoint_proof(
+            &s.monitor,
+            &s.attestor,
+            &EndpointKind::Sep6,
+            &signature
+        ));
+        assert!(
+            !s.client
+                .get_endpoint(&s.attestor, &EndpointKind::Sep6)
+                .is_active
+        );
+        assert_eq!(
+            s.client
+                .get_health_status(&s.attestor)
+                .unwrap()
+                .failure_count,
+            1
+        );
+    }
+
+    #[test]
+    fn test_unanswered_challenge_is_stale_on_next_request() {
+        let env = Env::default();
+        let s = setup(&env);
+
+        let first =
+            s.client
+                .request_endpoint_challenge(&s.monitor, &s.attestor, &EndpointKind::Sep6);
+        env.ledger()
+            .with_mut(|li| li.timestamp = 1_000 + EndpointChallenge::TTL_SECONDS + 1);
+        let second =
+            s.client
+                .request_endpoint_challenge(&s.monitor, &s.attestor, &EndpointKind::Sep6);
+
+        assert_ne!(first, second);
+        assert!(
+            !s.client
+                .get_endpoint(&s.attestor, &EndpointKind::Sep6)
+                .is_active
+        );
+        assert_eq!(
+            s.client
+                .get_health_status(&s.attestor)
+                .unwrap()
+                .failure_count,
+            1
+        );
+        let pending = s
+            .client
+            .get_endpoint_challenge(&s.attestor, &EndpointKind::Sep6)
+            .unwrap();
+        assert_eq!(pending.nonce, second);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rejects_signature_from_other_key() {
+        let env = Env::default();
+        let s = setup(&env);
+
+        let nonce =
+            s.client
+                .request_endpoint_challenge(&s.monitor, &s.attestor, &EndpointKind::Sep6);
+        let impostor = SigningKey::from_bytes(&[9u8; 32]);
+        s.client.submit_endpoint_proof(
+            &s.monitor,
+            &s.attestor,
+            &EndpointKind::Sep6,
+            &sign(&env, &impostor, &nonce),
+        );
+    }
+
+    #[test]
+    fn test_requires_monitor_role_and_challenge() {
+        let env = Env::default();
+        let s = setup(&env);
+        let stranger = Address::generate(&env);
+
+        let result =
+            s.client
+                .try_request_endpoint_challenge(&stranger, &s.attestor, &EndpointKind::Sep6);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+        let result =
+            s.client
+                .try_request_endpoint_challenge(&s.monitor, &s.attestor, &EndpointKind::Sep24);
+        assert_eq!(result, Err(Ok(Error::EndpointNotFound)));
+
+        let signature = BytesN::from_array(&env, &[0u8; 64]);
+        let result = s.client.try_submit_endpoint_proof(
+            &s.monitor,
+            &s.attestor,
+            &EndpointKind::Sep6,
+            &signature,
+        );
+        assert_eq!(result, Err(Ok(Error::NotFound)));
+    }
+}
@@ -0,0 +1,84 @@
+use soroban_sdk::{xdr::ToXdr, Address, BytesN, Env, IntoVal, Val};
+
+use crate::{
+    errors::Error,
+    events::{ErasureRequested, RecordTombstoned, SubjectErased},
+    storage::Storage,
+    types::{Tombstone, TombstoneKind},
+};
+
+/// Right-to-erasure flow for subject-linked data.
+///
+/// A subject files a request; once a compliance officer approves it, the
+/// subject's attestations and KYC record are deleted and replaced by tombstones
+/// holding the SHA-256 of the erased record's XDR, so earlier events and audits
+/// can still be checked against what was stored.
+pub struct Erasure;
+
+impl Erasure {
+    pub fn request(env: &Env, subject: &Address) -> Result<(), Error> {
+        if Storage::get_erasure_request(env, subject).is_some() {
+            return Err(Error::AlreadyExists);
+        }
+
+        let requested_at = env.ledger().timestamp();
+        Storage::set_erasure_request(env, subject, requested_at);
+        ErasureRequested::publish(env, subject, requested_at);
+        Ok(())
+    }
+
+    /// Tombstone everything linked to `subject`. Returns the number of records erased.
+    pub fn approve(env: &Env, subject: &Address) -> Result<u32, Error> {
+        if Storage::get_erasure_request(env, subject).is_none() {
+            return Err(Error::NotFound);
+        }
+
+        let mut erased = 0u32;
+        for attestation_id in Storage::get_subject_attestation_ids(env, subject).iter() {
+            let Ok(attestation) = Storage::get_attestation(env, attestation_id) else {
+                continue;
+            };
+            let tombstone = Self::tombstone(
+                env,
+                subject,
+                TombstoneKind::Attestation,
+                attestation_id,
+                &attestation,
+            );
+            Storage::remove_attestation(env, attestation_id);
+            Storage::set_tombstone(env, &tombstone);
+            RecordTombstoned::publish(env, &tombstone);
+            erased += 1;
+        }
+
+        if let Some(record) = Storage::get_kyc_record(env, subject) {
+            let tombstone = Self::tombstone(env, subject, TombstoneKind::KycRecord, 0, &record);
+            Storage::remove_kyc_record(env, subject);
+            Storage::set_tombstone(env, &tombstone);
+            RecordTombstoned::publish(env, &tombstone);
+            erased += 1;
+        }
+
+        Storage::remove_erasure_request(env, subject);
+        SubjectErased::publish(env, subject, erased);
+        Ok(erased)
+    }
+
+    fn tombstone<T: IntoVal<Env, Val>>(
+        env: &Env,
+        subject: &Address,
+        kind: TombstoneKind,
+        record_id: u64,
+        record: &T,
+    ) -> Tombstone {
+        let record: Val = record.into_val(env);
+        let record_hash: BytesN<32> = env.crypto().sha256(&record.to_xdr(env)).into();
+        Tombstone {
+            subject: subject.clone(),
+            kind,
+            record_id,
+            record_hash,
+            erased_at: env.ledger().timestamp(),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod erasure_tests {
+    use crate::testutils::{sign_attestation, AnchorKitFixture};
+    use crate::{Error, ServiceType, TombstoneKind};
+    use soroban_sdk::{
+        testutils::Address as _, xdr::ToXdr, Address, BytesN, Env, IntoVal, Val,
+    };
+
+    #[test]
+    fn test_approved_erasure_tombstones_subject_records() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::KYC]);
+        let subject = Address::generate(&env);
+
+        let session_id = kit.client.create_session(&anchor);
+        let payload_hash = BytesN::from_array(&env, &[9u8; 32]);
+        let attestation_id = kit.client.submit_attestation_with_session(
+            &session_id,
+            &anchor,
+            &subject,
+            &1_700_000_001u64,
+            &payload_hash,
+            &sign_attestation(&env, &anchor, &subject, 1_700_000_001u64, &payload_hash),
+            &None,
+        );
+        kit.client.set_kyc_level(&anchor, &subject, &2);
+        let attestation = kit.client.get_attestation(&subject, &attestation_id);
+        let attestation_val: Val = attestation.into_val(&env);
+        let expected_hash: BytesN<32> = env.crypto().sha256(&attestation_val.to_xdr(&env)).into();
+
+        kit.client.request_erasure(&subject);
+        assert!(kit.client.get_erasure_request(&subject).is_some());
+        assert_eq!(kit.client.approve_erasure(&kit.admin, &subject), 2);
+
+        let result = kit.client.try_get_attestation(&subject, &attestation_id);
+        assert_eq!(result, Err(Ok(Error::AttestationNotFound)));
+        // The payload hash stays recorded so the attestation cannot be replayed
+        assert!(kit.client.is_attestation_recorded(&attestation.payload_hash));
+        assert_eq!(kit.client.get_kyc_record(&subject), None);
+        assert_eq!(kit.client.get_erasure_request(&subject), None);
+
+        let tombstone = kit
+            .client
+            .get_tombstone(&TombstoneKind::Attestation, &subject, &attestation_id)
+            .unwrap();
+        assert_eq!(tombstone.record_hash, expected_hash);
+        assert!(kit
+            .client
+            .get_tombstone(&TombstoneKind::KycRecord, &subject, &0)
+            .is_some());
+    }
+
+    #[test]
+    fn test_erasure_requires_pending_request_and_officer() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let subject = Address::generate(&env);
+
+        let result = kit.client.try_approve_erasure(&kit.admin, &subject);
+        assert_eq!(result, Err(Ok(Error::NotFound)));
+
+        kit.client.request_erasure(&subject);
+        let result = kit.client.try_request_erasure(&subject);
+        assert_eq!(result, Err(Ok(Error::AlreadyExists)));
+
+        let stranger = Address::generate(&env);
+        let result = kit.client.try_approve_erasure(&stranger, &subject);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    }
+}
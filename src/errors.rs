@@ -1,7 +1,7 @@
 use soroban_sdk::contracterror;
 
 /// Error codes for AnchorKit contract operations.
-/// Consolidated to stay within Soroban's 32 error variant limit.
+/// Consolidated to stay within Soroban's 50 error variant limit.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -28,6 +28,7 @@ pub enum Error {
     StaleQuote = 16,
     NoQuotesAvailable = 17,
     QuoteNotFound = 18,
+    QuoteConflict = 65,
 
     /// Transaction intent / compliance errors
     InvalidTransactionIntent = 19,
@@ -46,6 +47,7 @@ pub enum Error {
     InvalidAnchorMetadata = 26,
     AnchorMetadataNotFound = 27,
     NoAnchorsAvailable = 28,
+    AnchorOffboarding = 67,
 
     /// Cache errors
     CacheExpired = 48,
@@ -57,4 +59,37 @@ pub enum Error {
     /// Asset validation errors
     AssetNotConfigured = 51,
     UnsupportedAsset = 52,
+
+    /// Transfer errors
+    TransferNotFound = 53,
+    AlreadyExists = 54,
+
+    /// Intent funding errors
+    IntentNotFound = 55,
+    IntentLocked = 66,
+    InsufficientAllowance = 56,
+
+    /// Hash-time-lock errors
+    InvalidPreimage = 57,
+
+    /// Compliance errors
+    AddressBlocked = 58,
+
+    /// Generic lookup error for records without a dedicated variant
+    NotFound = 59,
+
+    /// Access control errors
+    Unauthorized = 60,
+
+    /// Endpoint URL validation errors
+    InsecureEndpointScheme = 61,
+    InvalidEndpointCharacter = 62,
+    EndpointDomainNotAllowed = 63,
+
+    /// Treasury errors
+    InsufficientBalance = 64,
+    ExposureLimitExceeded = 68,
+
+    /// Incident-response freeze errors
+    ContractFrozen = 69,
 }
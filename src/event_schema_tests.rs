@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod event_schema_tests {
+    use crate::testutils::{attestor_public_key, sign_attestation};
+    use crate::{
+        AnchorKitContract, AnchorKitContractClient, EndpointKind, EventSchema, Role, EVENT_SCHEMAS,
+    };
+    use soroban_sdk::{
+        testutils::{Address as _, Events},
+        Address, BytesN, Env, String, Symbol, TryFromVal,
+    };
+
+    /// Every event of the last invocation decodes through `EVENT_SCHEMAS`.
+    fn assert_last_events_match_schema(env: &Env) -> usize {
+        let events = env.events().all();
+        for (_, topics, _) in events.iter() {
+            let namespace = Symbol::try_from_val(env, &topics.get(0).unwrap()).unwrap();
+            let action = Symbol::try_from_val(env, &topics.get(1).unwrap()).unwrap();
+            let schema = EVENT_SCHEMAS
+                .iter()
+                .find(|schema| {
+                    Symbol::new(env, schema.namespace) == namespace
+                        && schema.action.is_none_or(|a| Symbol::new(env, a) == action)
+                })
+                .expect("event missing from EVENT_SCHEMAS");
+            assert_eq!(
+                topics.len() as usize,
+                2 + schema.topics.len(),
+                "{}",
+                schema.name
+            );
+        }
+        events.len() as usize
+    }
+
+    #[test]
+    fn test_published_events_match_schema() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let attestor = Address::generate(&env);
+        let subject = Address::generate(&env);
+        client.initialize(&admin);
+
+        client.register_attestor(&attestor, &attestor_public_key(&env));
+        assert!(assert_last_events_match_schema(&env) > 0);
+
+        let session_id = client.create_session(&subject);
+        assert!(assert_last_events_match_schema(&env) > 0);
+
+        let payload_hash = BytesN::from_array(&env, &[9; 32]);
+        client.submit_attestation_with_session(
+            &session_id,
+            &attestor,
+            &subject,
+            &1_700_000_000u64,
+            &payload_hash,
+            &sign_attestation(&env, &attestor, &subject, 1_700_000_000u64, &payload_hash),
+            &None,
+        );
+        assert!(assert_last_events_match_schema(&env) > 0);
+
+        client.configure_endpoint(
+            &attestor,
+            &EndpointKind::Sep24,
+            &String::from_str(&env, "https://anchor.example.com/sep24"),
+            &true,
+        );
+        assert!(assert_last_events_match_schema(&env) > 0);
+
+        client.grant_role(&subject, &Role::ComplianceOfficer);
+        assert!(assert_last_events_match_schema(&env) > 0);
+
+        client.revoke_attestor_with_session(&session_id, &attestor, &None);
+        assert!(assert_last_events_match_schema(&env) > 0);
+    }
+
+    #[test]
+    fn test_find_schema_by_topics() {
+        assert_eq!(
+            EventSchema::find("attest", "recorded").unwrap().name,
+            "AttestationRecorded"
+        );
+        assert_eq!(
+            EventSchema::find("sponsor", "intent").unwrap().name,
+            "OperationSponsored"
+        );
+        assert!(EventSchema::find("attest", "unknown").is_none());
+    }
+}
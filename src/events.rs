@@ -1,5 +1,9 @@
-use crate::types::ServiceType;
-use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Vec};
+use crate::scheduled_params::{ParameterChange, ScheduledChange};
+use crate::types::{
+    AmlFlag, AmlFlagStatus, Asset, EndpointKind, LateSettlementPenalty, OffboardingStage, ProceedsKind, Role,
+    RoutingReceipt, ServiceType, Tombstone, TravelRuleRole,
+};
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, Env, String, Symbol, Vec};
 
 // --- EXISTING ATTESTOR EVENTS ---
 
@@ -58,12 +62,49 @@ impl AttestationRecorded {
     }
 }
 
+/// Event emitted when an attestation's issuer sets its expiry or revokes it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationValidityChanged {
+    pub expires_at: u64, // 0 = never expires
+    pub revoked: bool,
+}
+
+impl AttestationValidityChanged {
+    pub fn publish(env: &Env, id: u64, expires_at: u64, revoked: bool) {
+        env.events().publish(
+            (symbol_short!("attest"), symbol_short!("validity"), id),
+            AttestationValidityChanged {
+                expires_at,
+                revoked,
+            },
+        );
+    }
+}
+
+/// An attestation was replaced by a newer one about the same subject
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationSuperseded {
+    pub superseded_by: u64,
+}
+
+impl AttestationSuperseded {
+    pub fn publish(env: &Env, id: u64, superseded_by: u64) {
+        env.events().publish(
+            (symbol_short!("attest"), symbol_short!("supersede"), id),
+            AttestationSuperseded { superseded_by },
+        );
+    }
+}
+
 // --- CONFIGURATION EVENTS ---
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EndpointConfigured {
     pub attestor: Address,
+    pub kind: EndpointKind,
     pub url: String,
 }
 
@@ -76,10 +117,65 @@ impl EndpointConfigured {
     }
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TomlHashAnchored {
+    pub attestor: Address,
+    pub toml_hash: BytesN<32>,
+}
+
+impl TomlHashAnchored {
+    pub fn publish(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("endpoint"), symbol_short!("tomlhash")),
+            self.clone(),
+        );
+    }
+}
+
+/// Emitted when a monitor challenges an endpoint to prove it is live.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EndpointChallenged {
+    pub attestor: Address,
+    pub kind: EndpointKind,
+    pub nonce: BytesN<32>,
+    pub expires_at: u64,
+}
+
+impl EndpointChallenged {
+    pub fn publish(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("endpoint"), symbol_short!("challenge")),
+            self.clone(),
+        );
+    }
+}
+
+/// Emitted when a challenge is resolved: `fresh` is false when the anchor did
+/// not answer in time and the endpoint was marked inactive.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EndpointChecked {
+    pub attestor: Address,
+    pub kind: EndpointKind,
+    pub fresh: bool,
+}
+
+impl EndpointChecked {
+    pub fn publish(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("endpoint"), symbol_short!("checked")),
+            self.clone(),
+        );
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EndpointRemoved {
     pub attestor: Address,
+    pub kind: EndpointKind,
 }
 
 impl EndpointRemoved {
@@ -107,6 +203,24 @@ impl ServicesConfigured {
     }
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferInfoUpdated {
+    pub anchor: Address,
+    pub asset_code: String,
+    pub operation_type: ServiceType,
+    pub enabled: bool,
+}
+
+impl TransferInfoUpdated {
+    pub fn publish(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("services"), symbol_short!("xferinfo")),
+            self.clone(),
+        );
+    }
+}
+
 // --- QUOTE & SESSION EVENTS ---
 
 #[contracttype]
@@ -114,8 +228,8 @@ impl ServicesConfigured {
 pub struct QuoteSubmitted {
     pub anchor: Address,
     pub quote_id: u64,
-    pub base_asset: String,
-    pub quote_asset: String,
+    pub base_asset: Asset,
+    pub quote_asset: Asset,
     pub rate: u64,
     pub valid_until: u64,
 }
@@ -125,8 +239,8 @@ impl QuoteSubmitted {
         env: &Env,
         anchor: &Address,
         quote_id: u64,
-        base_asset: &String,
-        quote_asset: &String,
+        base_asset: &Asset,
+        quote_asset: &Asset,
         rate: u64,
         valid_until: u64,
     ) {
@@ -177,6 +291,7 @@ pub struct OperationLogged {
     pub operation_index: u64,
     pub operation_type: String,
     pub status: String,
+    pub correlation_id: BytesN<16>, // all zeros = none
 }
 
 impl OperationLogged {
@@ -187,6 +302,7 @@ impl OperationLogged {
         operation_index: u64,
         operation_type: &String,
         status: &String,
+        correlation_id: &BytesN<16>,
     ) {
         env.events().publish(
             (symbol_short!("audit"), symbol_short!("logged"), log_id),
@@ -196,6 +312,7 @@ impl OperationLogged {
                 operation_index,
                 operation_type: operation_type.clone(),
                 status: status.clone(),
+                correlation_id: correlation_id.clone(),
             },
         );
     }
@@ -284,3 +401,832 @@ impl SettlementConfirmed {
         );
     }
 }
+
+/// Event emitted when confirming an intent's settlement after its corridor's
+/// SLA charges the anchor a penalty in favor of the payer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LatePenaltyAccrued {
+    pub intent_id: u64,
+    pub anchor: Address,
+    pub sender: Address,
+    pub amount: i128,
+    pub late_seconds: u64,
+    pub from_bond: bool, // false = deducted from the anchor's fee revenue
+}
+
+impl LatePenaltyAccrued {
+    pub fn publish(env: &Env, penalty: &LateSettlementPenalty) {
+        env.events().publish(
+            (
+                symbol_short!("settle"),
+                symbol_short!("penalty"),
+                penalty.intent_id,
+            ),
+            LatePenaltyAccrued {
+                intent_id: penalty.intent_id,
+                anchor: penalty.anchor.clone(),
+                sender: penalty.sender.clone(),
+                amount: penalty.amount,
+                late_seconds: penalty.late_seconds,
+                from_bond: penalty.bond_token.is_some(),
+            },
+        );
+    }
+}
+
+/// Event emitted when funds are locked against a hash for a transfer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HashLocked {
+    pub transfer_id: u64,
+    pub hash_lock: BytesN<32>,
+    pub recipient: Address,
+    pub timeout: u64,
+}
+
+impl HashLocked {
+    pub fn publish(
+        env: &Env,
+        transfer_id: u64,
+        hash_lock: &BytesN<32>,
+        recipient: &Address,
+        timeout: u64,
+    ) {
+        env.events().publish(
+            (symbol_short!("htlc"), symbol_short!("locked"), transfer_id),
+            HashLocked {
+                transfer_id,
+                hash_lock: hash_lock.clone(),
+                recipient: recipient.clone(),
+                timeout,
+            },
+        );
+    }
+}
+
+/// Event emitted when a hash lock is claimed. Carries the preimage so the
+/// counterparty can settle its side of the swap.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PreimageRevealed {
+    pub transfer_id: u64,
+    pub preimage: Bytes,
+}
+
+impl PreimageRevealed {
+    pub fn publish(env: &Env, transfer_id: u64, preimage: &Bytes) {
+        env.events().publish(
+            (symbol_short!("htlc"), symbol_short!("claimed"), transfer_id),
+            PreimageRevealed {
+                transfer_id,
+                preimage: preimage.clone(),
+            },
+        );
+    }
+}
+
+/// Event emitted when an expired hash lock is refunded to the sender.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HashLockRefunded {
+    pub transfer_id: u64,
+    pub sender: Address,
+    pub amount: i128,
+}
+
+impl HashLockRefunded {
+    pub fn publish(env: &Env, transfer_id: u64, sender: &Address, amount: i128) {
+        env.events().publish(
+            (symbol_short!("htlc"), symbol_short!("refunded"), transfer_id),
+            HashLockRefunded {
+                transfer_id,
+                sender: sender.clone(),
+                amount,
+            },
+        );
+    }
+}
+
+// --- CREDENTIAL EVENTS ---
+
+/// Event emitted when a credential binding loses its endpoint or credential.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CredentialOrphaned {
+    pub attestor: Address,
+    pub endpoint_url: String,
+}
+
+impl CredentialOrphaned {
+    pub fn publish(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("cred"), symbol_short!("orphaned")),
+            self.clone(),
+        );
+    }
+}
+
+/// Event emitted when the pinned TLS fingerprint of an endpoint changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FingerprintPinned {
+    pub attestor: Address,
+    pub endpoint_url: String,
+    pub fingerprint: BytesN<32>,
+}
+
+impl FingerprintPinned {
+    pub fn publish(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("cred"), symbol_short!("pinned")),
+            self.clone(),
+        );
+    }
+}
+
+/// Event emitted when an anchor grants or revokes operator access to a credential.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CredentialAccessChanged {
+    pub attestor: Address,
+    pub operator: Address,
+    pub credential_label: Symbol,
+    pub expires_at: u64,
+}
+
+impl CredentialAccessChanged {
+    pub fn publish_granted(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("cred"), symbol_short!("granted")),
+            self.clone(),
+        );
+    }
+
+    pub fn publish_revoked(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("cred"), symbol_short!("revoked")),
+            self.clone(),
+        );
+    }
+}
+
+/// Event emitted when a subject grants or revokes read access to its attestations.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationAccessChanged {
+    pub subject: Address,
+    pub grantee: Address,
+    pub expires_at: u64,
+}
+
+impl AttestationAccessChanged {
+    pub fn publish_granted(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("attest"), symbol_short!("granted")),
+            self.clone(),
+        );
+    }
+
+    pub fn publish_revoked(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("attest"), symbol_short!("revoked")),
+            self.clone(),
+        );
+    }
+}
+
+// --- IDENTITY EVENTS ---
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DidChanged {
+    pub subject: Address,
+    pub did_hash: BytesN<32>,
+}
+
+impl DidChanged {
+    pub fn publish_bound(&self, env: &Env) {
+        env.events()
+            .publish((symbol_short!("did"), symbol_short!("bound")), self.clone());
+    }
+
+    pub fn publish_revoked(&self, env: &Env) {
+        env.events()
+            .publish((symbol_short!("did"), symbol_short!("revoked")), self.clone());
+    }
+}
+
+/// Emitted when an alias is registered for an address or released.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AliasChanged {
+    pub alias: Symbol,
+    pub address: Address,
+}
+
+impl AliasChanged {
+    pub fn publish_set(&self, env: &Env) {
+        env.events()
+            .publish((symbol_short!("alias"), symbol_short!("set")), self.clone());
+    }
+
+    pub fn publish_released(&self, env: &Env) {
+        env.events()
+            .publish((symbol_short!("alias"), symbol_short!("released")), self.clone());
+    }
+}
+
+/// Emitted when an attestor adds or removes a signing delegate.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegateChanged {
+    pub attestor: Address,
+    pub delegate: Address,
+    pub added: bool,
+}
+
+impl DelegateChanged {
+    pub fn publish(env: &Env, attestor: &Address, delegate: &Address, added: bool) {
+        let action = if added {
+            symbol_short!("added")
+        } else {
+            symbol_short!("removed")
+        };
+        env.events().publish(
+            (symbol_short!("delegate"), action),
+            DelegateChanged {
+                attestor: attestor.clone(),
+                delegate: delegate.clone(),
+                added,
+            },
+        );
+    }
+}
+
+/// Emitted when the admin schedules a parameter change or cancels a pending one.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParameterChangeScheduled {
+    pub change: ParameterChange,
+    pub scheduled_at: u64,
+    pub activates_at: u64,
+}
+
+impl ParameterChangeScheduled {
+    pub fn publish_scheduled(env: &Env, scheduled: &ScheduledChange) {
+        env.events().publish(
+            (symbol_short!("param"), symbol_short!("scheduled")),
+            Self::from_change(scheduled),
+        );
+    }
+
+    pub fn publish_cancelled(env: &Env, scheduled: &ScheduledChange) {
+        env.events().publish(
+            (symbol_short!("param"), symbol_short!("cancelled")),
+            Self::from_change(scheduled),
+        );
+    }
+
+    fn from_change(scheduled: &ScheduledChange) -> Self {
+        ParameterChangeScheduled {
+            change: scheduled.change.clone(),
+            scheduled_at: scheduled.scheduled_at,
+            activates_at: scheduled.activates_at,
+        }
+    }
+}
+
+// --- ACCESS CONTROL EVENTS ---
+
+/// Emitted when the admin grants or revokes a role.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleChanged {
+    pub account: Address,
+    pub role: Role,
+    pub granted: bool,
+}
+
+impl RoleChanged {
+    pub fn publish(env: &Env, account: &Address, role: Role, granted: bool) {
+        let action = if granted {
+            symbol_short!("granted")
+        } else {
+            symbol_short!("revoked")
+        };
+        env.events().publish(
+            (symbol_short!("role"), action),
+            RoleChanged {
+                account: account.clone(),
+                role,
+                granted,
+            },
+        );
+    }
+}
+
+// --- COMPLIANCE EVENTS ---
+
+/// Emitted when an address is added to or removed from the sanctions blocklist.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlocklistUpdated {
+    pub address: Address,
+    pub blocked: bool,
+}
+
+impl BlocklistUpdated {
+    pub fn publish_added(env: &Env, address: &Address) {
+        Self::publish(env, address, true, symbol_short!("added"));
+    }
+
+    pub fn publish_removed(env: &Env, address: &Address) {
+        Self::publish(env, address, false, symbol_short!("removed"));
+    }
+
+    fn publish(env: &Env, address: &Address, blocked: bool, action: Symbol) {
+        env.events().publish(
+            (symbol_short!("blocklist"), action),
+            BlocklistUpdated {
+                address: address.clone(),
+                blocked,
+            },
+        );
+    }
+}
+
+/// Emitted when an intent or transfer exceeds its currency's AML threshold.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmlFlagRaised {
+    pub flag_id: u64,
+    pub reference_id: u64,
+    pub asset: Asset,
+    pub amount: i128,
+    pub threshold: i128,
+}
+
+impl AmlFlagRaised {
+    pub fn publish(env: &Env, flag: &AmlFlag) {
+        env.events().publish(
+            (symbol_short!("aml"), symbol_short!("raised"), flag.flag_id),
+            AmlFlagRaised {
+                flag_id: flag.flag_id,
+                reference_id: flag.reference_id,
+                asset: flag.asset.clone(),
+                amount: flag.amount,
+                threshold: flag.threshold,
+            },
+        );
+    }
+}
+
+/// Emitted when a compliance officer clears or escalates an AML flag.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmlFlagReviewed {
+    pub flag_id: u64,
+    pub status: AmlFlagStatus,
+}
+
+impl AmlFlagReviewed {
+    pub fn publish(env: &Env, flag_id: u64, status: AmlFlagStatus) {
+        env.events().publish(
+            (symbol_short!("aml"), symbol_short!("reviewed"), flag_id),
+            AmlFlagReviewed { flag_id, status },
+        );
+    }
+}
+
+/// Emitted when an anchor records a subject's KYC level.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycLevelUpdated {
+    pub subject: Address,
+    pub level: u32,
+    pub verified_by: Address,
+}
+
+impl KycLevelUpdated {
+    pub fn publish(env: &Env, subject: &Address, level: u32, verified_by: &Address) {
+        env.events().publish(
+            (symbol_short!("kyc"), symbol_short!("level")),
+            KycLevelUpdated {
+                subject: subject.clone(),
+                level,
+                verified_by: verified_by.clone(),
+            },
+        );
+    }
+}
+
+/// Emitted when an anchor commits to the travel-rule data of a transfer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TravelRuleCommitted {
+    pub transfer_id: u64,
+    pub anchor: Address,
+    pub role: TravelRuleRole,
+    pub data_hash: BytesN<32>,
+}
+
+impl TravelRuleCommitted {
+    pub fn publish(
+        env: &Env,
+        transfer_id: u64,
+        anchor: &Address,
+        role: TravelRuleRole,
+        data_hash: &BytesN<32>,
+    ) {
+        env.events().publish(
+            (symbol_short!("travel"), symbol_short!("commit"), transfer_id),
+            TravelRuleCommitted {
+                transfer_id,
+                anchor: anchor.clone(),
+                role,
+                data_hash: data_hash.clone(),
+            },
+        );
+    }
+}
+
+/// Emitted when a registered verifier accepts a subject's zero-knowledge KYC proof.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycProofVerified {
+    pub subject: Address,
+    pub verifier: Address,
+    pub commitment: BytesN<32>,
+    pub level: u32,
+}
+
+impl KycProofVerified {
+    pub fn publish(
+        env: &Env,
+        subject: &Address,
+        verifier: &Address,
+        commitment: &BytesN<32>,
+        level: u32,
+    ) {
+        env.events().publish(
+            (symbol_short!("kyc"), symbol_short!("zkproof")),
+            KycProofVerified {
+                subject: subject.clone(),
+                verifier: verifier.clone(),
+                commitment: commitment.clone(),
+                level,
+            },
+        );
+    }
+}
+
+/// Emitted when a subject files a right-to-erasure request.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErasureRequested {
+    pub subject: Address,
+    pub requested_at: u64,
+}
+
+impl ErasureRequested {
+    pub fn publish(env: &Env, subject: &Address, requested_at: u64) {
+        env.events().publish(
+            (symbol_short!("erasure"), symbol_short!("request")),
+            ErasureRequested {
+                subject: subject.clone(),
+                requested_at,
+            },
+        );
+    }
+}
+
+/// Emitted for each subject-linked record replaced by a tombstone.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordTombstoned {
+    pub tombstone: Tombstone,
+}
+
+impl RecordTombstoned {
+    pub fn publish(env: &Env, tombstone: &Tombstone) {
+        env.events().publish(
+            (symbol_short!("erasure"), symbol_short!("tomb"), tombstone.kind),
+            RecordTombstoned {
+                tombstone: tombstone.clone(),
+            },
+        );
+    }
+}
+
+/// Emitted when an approved erasure request has been carried out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubjectErased {
+    pub subject: Address,
+    pub records_erased: u32,
+}
+
+impl SubjectErased {
+    pub fn publish(env: &Env, subject: &Address, records_erased: u32) {
+        env.events().publish(
+            (symbol_short!("erasure"), symbol_short!("done")),
+            SubjectErased {
+                subject: subject.clone(),
+                records_erased,
+            },
+        );
+    }
+}
+
+// --- ROUTING EVENTS ---
+
+/// Emitted when uptime reports are aggregated into an anchor's metadata.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UptimeUpdated {
+    pub anchor: Address,
+    pub reporter: Address,
+    pub uptime_percentage: u32,
+    pub latency_ms: u64,
+    pub reports: u32,
+    pub rejected: u32,
+}
+
+impl UptimeUpdated {
+    pub fn publish(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("uptime"), symbol_short!("updated")),
+            self.clone(),
+        );
+    }
+}
+
+/// Emitted when `route_transaction` persists a routing receipt.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoutingReceiptIssued {
+    pub receipt_id: u64,
+    pub request_hash: BytesN<32>,
+    pub selected_anchor: Address,
+    pub score: u64,
+}
+
+impl RoutingReceiptIssued {
+    pub fn publish(env: &Env, receipt: &RoutingReceipt) {
+        env.events().publish(
+            (symbol_short!("route"), symbol_short!("receipt"), receipt.receipt_id),
+            RoutingReceiptIssued {
+                receipt_id: receipt.receipt_id,
+                request_hash: receipt.request_hash.clone(),
+                selected_anchor: receipt.selected_anchor.clone(),
+                score: receipt.score.total(),
+            },
+        );
+    }
+}
+
+// --- TREASURY EVENTS ---
+
+/// Emitted when fee or slash proceeds are credited to the treasury.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryCredited {
+    pub token: Address,
+    pub from: Address,
+    pub amount: i128,
+    pub kind: ProceedsKind,
+}
+
+impl TreasuryCredited {
+    pub fn publish(env: &Env, token: &Address, from: &Address, amount: i128, kind: ProceedsKind) {
+        env.events().publish(
+            (symbol_short!("treasury"), symbol_short!("credit")),
+            TreasuryCredited {
+                token: token.clone(),
+                from: from.clone(),
+                amount,
+                kind,
+            },
+        );
+    }
+}
+
+/// Emitted when the admin withdraws treasury funds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryWithdrawn {
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
+impl TreasuryWithdrawn {
+    pub fn publish(env: &Env, token: &Address, to: &Address, amount: i128) {
+        env.events().publish(
+            (symbol_short!("treasury"), symbol_short!("withdraw")),
+            TreasuryWithdrawn {
+                token: token.clone(),
+                to: to.clone(),
+                amount,
+            },
+        );
+    }
+}
+
+/// Emitted when a referrer is paid its share of a protocol fee.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralPaid {
+    pub referrer: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+impl ReferralPaid {
+    pub fn publish(env: &Env, referrer: &Address, token: &Address, amount: i128) {
+        env.events().publish(
+            (symbol_short!("referral"), symbol_short!("paid")),
+            ReferralPaid {
+                referrer: referrer.clone(),
+                token: token.clone(),
+                amount,
+            },
+        );
+    }
+}
+
+// --- OFFBOARDING EVENTS ---
+
+/// Emitted when an anchor starts offboarding and at each later stage.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OffboardingAdvanced {
+    pub anchor: Address,
+    pub stage: OffboardingStage,
+}
+
+impl OffboardingAdvanced {
+    pub fn publish(env: &Env, anchor: &Address, stage: OffboardingStage) {
+        env.events().publish(
+            (symbol_short!("offboard"), symbol_short!("stage")),
+            OffboardingAdvanced {
+                anchor: anchor.clone(),
+                stage,
+            },
+        );
+    }
+}
+
+// --- FREEZE EVENTS ---
+
+/// Emitted when the contract is frozen, an unfreeze is requested, or the
+/// freeze is lifted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FreezeChanged {
+    pub frozen: bool,
+    pub unfreeze_after: u64,
+}
+
+impl FreezeChanged {
+    pub fn publish(env: &Env, frozen: bool, unfreeze_after: u64) {
+        env.events().publish(
+            (symbol_short!("freeze"), symbol_short!("changed")),
+            FreezeChanged {
+                frozen,
+                unfreeze_after,
+            },
+        );
+    }
+}
+
+// --- SPONSORSHIP EVENTS ---
+
+/// Emitted when a sponsor submits (and pays for) an operation on a user's behalf.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationSponsored {
+    pub sponsor: Address,
+    pub user: Address,
+    pub operation: Symbol,
+}
+
+impl OperationSponsored {
+    pub fn publish(env: &Env, sponsor: &Address, user: &Address, operation: Symbol) {
+        env.events().publish(
+            (symbol_short!("sponsor"), operation.clone()),
+            OperationSponsored {
+                sponsor: sponsor.clone(),
+                user: user.clone(),
+                operation,
+            },
+        );
+    }
+}
+
+// --- EVENT SCHEMA ---
+
+/// Topic layout of an event AnchorKit publishes, for off-chain decoders
+pub struct EventSchema {
+    pub name: &'static str,
+    /// First topic symbol
+    pub namespace: &'static str,
+    /// Second topic symbol; `None` when it varies (the sponsored operation)
+    pub action: Option<&'static str>,
+    /// Names of the topic values after the two symbols
+    pub topics: &'static [&'static str],
+}
+
+impl EventSchema {
+    const fn new(
+        name: &'static str,
+        namespace: &'static str,
+        action: &'static str,
+        topics: &'static [&'static str],
+    ) -> Self {
+        Self {
+            name,
+            namespace,
+            action: Some(action),
+            topics,
+        }
+    }
+
+    /// Schema of the event published under `namespace` and `action`.
+    pub fn find(namespace: &str, action: &str) -> Option<&'static EventSchema> {
+        EVENT_SCHEMAS.iter().find(|schema| {
+            schema.namespace == namespace && schema.action.is_none_or(|a| a == action)
+        })
+    }
+}
+
+/// Every event published by the contract. Keep in sync with the `publish` fns above.
+pub const EVENT_SCHEMAS: &[EventSchema] = &[
+    EventSchema::new("AttestorAdded", "attestor", "added", &["attestor"]),
+    EventSchema::new("AttestorRemoved", "attestor", "removed", &["attestor"]),
+    EventSchema::new(
+        "AttestationRecorded",
+        "attest",
+        "recorded",
+        &["id", "subject"],
+    ),
+    EventSchema::new("AttestationValidityChanged", "attest", "validity", &["id"]),
+    EventSchema::new("AttestationSuperseded", "attest", "supersede", &["id"]),
+    EventSchema::new("EndpointConfigured", "endpoint", "config", &[]),
+    EventSchema::new("TomlHashAnchored", "endpoint", "tomlhash", &[]),
+    EventSchema::new("EndpointChallenged", "endpoint", "challenge", &[]),
+    EventSchema::new("EndpointChecked", "endpoint", "checked", &[]),
+    EventSchema::new("EndpointRemoved", "endpoint", "removed", &[]),
+    EventSchema::new("ServicesConfigured", "services", "config", &[]),
+    EventSchema::new("TransferInfoUpdated", "services", "xferinfo", &[]),
+    EventSchema::new("QuoteSubmitted", "quote", "submit", &["quote_id"]),
+    EventSchema::new("QuoteReceived", "quote", "received", &["quote_id"]),
+    EventSchema::new("SessionCreated", "session", "created", &["session_id"]),
+    EventSchema::new("OperationLogged", "audit", "logged", &["log_id"]),
+    EventSchema::new("TransferInitiated", "transfer", "init", &["transfer_id"]),
+    EventSchema::new("SettlementConfirmed", "settle", "confirm", &["transfer_id"]),
+    EventSchema::new("LatePenaltyAccrued", "settle", "penalty", &["intent_id"]),
+    EventSchema::new("HashLocked", "htlc", "locked", &["transfer_id"]),
+    EventSchema::new("PreimageRevealed", "htlc", "claimed", &["transfer_id"]),
+    EventSchema::new("HashLockRefunded", "htlc", "refunded", &["transfer_id"]),
+    EventSchema::new("CredentialOrphaned", "cred", "orphaned", &[]),
+    EventSchema::new("FingerprintPinned", "cred", "pinned", &[]),
+    EventSchema::new("CredentialAccessChanged", "cred", "granted", &[]),
+    EventSchema::new("CredentialAccessChanged", "cred", "revoked", &[]),
+    EventSchema::new("AttestationAccessChanged", "attest", "granted", &[]),
+    EventSchema::new("AttestationAccessChanged", "attest", "revoked", &[]),
+    EventSchema::new("DidChanged", "did", "bound", &[]),
+    EventSchema::new("DidChanged", "did", "revoked", &[]),
+    EventSchema::new("RoleChanged", "role", "granted", &[]),
+    EventSchema::new("RoleChanged", "role", "revoked", &[]),
+    EventSchema::new("AliasChanged", "alias", "set", &[]),
+    EventSchema::new("AliasChanged", "alias", "released", &[]),
+    EventSchema::new("DelegateChanged", "delegate", "added", &[]),
+    EventSchema::new("DelegateChanged", "delegate", "removed", &[]),
+    EventSchema::new("ParameterChangeScheduled", "param", "scheduled", &[]),
+    EventSchema::new("ParameterChangeScheduled", "param", "cancelled", &[]),
+    EventSchema::new("BlocklistUpdated", "blocklist", "added", &[]),
+    EventSchema::new("BlocklistUpdated", "blocklist", "removed", &[]),
+    EventSchema::new("AmlFlagRaised", "aml", "raised", &["flag_id"]),
+    EventSchema::new("AmlFlagReviewed", "aml", "reviewed", &["flag_id"]),
+    EventSchema::new("KycLevelUpdated", "kyc", "level", &[]),
+    EventSchema::new("KycProofVerified", "kyc", "zkproof", &[]),
+    EventSchema::new("TravelRuleCommitted", "travel", "commit", &["transfer_id"]),
+    EventSchema::new("ErasureRequested", "erasure", "request", &[]),
+    EventSchema::new("RecordTombstoned", "erasure", "tomb", &["kind"]),
+    EventSchema::new("SubjectErased", "erasure", "done", &[]),
+    EventSchema::new("UptimeUpdated", "uptime", "updated", &[]),
+    EventSchema::new("TreasuryCredited", "treasury", "credit", &[]),
+    EventSchema::new("TreasuryWithdrawn", "treasury", "withdraw", &[]),
+    EventSchema::new("ReferralPaid", "referral", "paid", &[]),
+    EventSchema::new("OffboardingAdvanced", "offboard", "stage", &[]),
+    EventSchema::new("FreezeChanged", "freeze", "changed", &[]),
+    EventSchema::new("RoutingReceiptIssued", "route", "receipt", &["receipt_id"]),
+    EventSchema {
+        name: "OperationSponsored",
+        namespace: "sponsor",
+        action: None,
+        topics: &[],
+    },
+];
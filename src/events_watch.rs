@@ -0,0 +1,189 @@
+use std::time::Duration;
+
+use anchorkit::EventSchema;
+use serde_json::{json, Map, Value};
+use stellar_xdr::curr::{Limits, PublicKey, ReadXdr, ScAddress, ScVal};
+
+use crate::stellar_cli::{self, Network};
+
+/// Events requested from RPC per poll
+const PAGE_SIZE: u32 = 100;
+
+/// Which contract to watch, and where to start
+pub struct WatchOptions {
+    pub contract_id: String,
+    pub network: Network,
+    /// First ledger to read events from
+    pub start_ledger: Option<u32>,
+    /// Paging token to resume after, from a previous run
+    pub cursor: Option<String>,
+    pub interval: Duration,
+    /// Stop after the first poll instead of following new events
+    pub once: bool,
+}
+
+/// Poll Soroban RPC for the contract's events and write each one to stdout as
+/// a line of JSON. Runs until interrupted unless `once` is set.
+pub fn watch(options: &WatchOptions) -> Result<(), String> {
+    if options.start_ledger.is_none() && options.cursor.is_none() {
+        return Err("--start-ledger or --cursor is required".to_string());
+    }
+    stellar_cli::require_cli()?;
+
+    let mut cursor = options.cursor.clone();
+    loop {
+        match poll(options, cursor.as_deref()) {
+            Ok(events) => {
+                for event in events {
+                    if let Some(token) = event["paging_token"].as_str() {
+                        cursor = Some(token.to_string());
+                    }
+                    println!("{}", event);
+                }
+            }
+            Err(e) if !options.once => eprintln!("⚠ {}; retrying", e),
+            Err(e) => return Err(e),
+        }
+        if options.once {
+            return Ok(());
+        }
+        std::thread::sleep(options.interval);
+    }
+}
+
+/// Fetch one page of events after `cursor` (or from the start ledger) and decode them.
+fn poll(options: &WatchOptions, cursor: Option<&str>) -> Result<Vec<Value>, String> {
+    let mut args = vec![
+        "events".to_string(),
+        "--id".to_string(),
+        options.contract_id.clone(),
+        "--type".to_string(),
+        "contract".to_string(),
+        "--count".to_string(),
+        PAGE_SIZE.to_string(),
+        "--output".to_string(),
+        "json".to_string(),
+    ];
+    match (cursor, options.start_ledger) {
+        (Some(cursor), _) => args.extend(["--cursor".to_string(), cursor.to_string()]),
+        (None, Some(ledger)) => args.extend(["--start-ledger".to_string(), ledger.to_string()]),
+        (None, None) => unreachable!("checked by watch"),
+    }
+    args.extend(options.network.args()?);
+
+    let output = stellar_cli::run(&args)?;
+    serde_json::Deserializer::from_str(&output)
+        .into_iter::<Value>()
+        .map(|event| {
+            let event = event.map_err(|e| format!("unexpected events output: {}", e))?;
+            decode(&event)
+        })
+        .collect()
+}
+
+/// Decode an RPC event into the NDJSON record: the AnchorKit event name from
+/// `EVENT_SCHEMAS`, named topic values and the decoded data.
+fn decode(event: &Value) -> Result<Value, String> {
+    let topics = event["topic"]
+        .as_array()
+        .ok_or_else(|| "event is missing topics".to_string())?
+        .iter()
+        .map(|topic| decode_xdr(topic.as_str().unwrap_or_default()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let value = match &event["value"] {
+        Value::String(xdr) => xdr.as_str(),
+        value => value["xdr"].as_str().unwrap_or_default(),
+    };
+    let data = decode_xdr(value)?;
+
+    let namespace = topics.first().and_then(Value::as_str).unwrap_or_default();
+    let action = topics.get(1).and_then(Value::as_str).unwrap_or_default();
+    let (name, topics) = match EventSchema::find(namespace, action) {
+        Some(schema) if topics.len() == 2 + schema.topics.len() => {
+            let named: Map<String, Value> = schema
+                .topics
+                .iter()
+                .map(|name| name.to_string())
+                .zip(topics[2..].iter().cloned())
+                .collect();
+            (json!(schema.name), Value::Object(named))
+        }
+        _ => (Value::Null, Value::Array(topics.clone())),
+    };
+
+    Ok(json!({
+        "event": name,
+        "namespace": namespace,
+        "action": action,
+        "topics": topics,
+        "data": data,
+        "contract_id": event["contractId"],
+        "ledger": event["ledger"],
+        "ledger_closed_at": event["ledgerClosedAt"],
+        "id": event["id"],
+        "paging_token": event["pagingToken"],
+        "tx_hash": event.get("txHash").cloned().unwrap_or(Value::Null),
+    }))
+}
+
+fn decode_xdr(xdr: &str) -> Result<Value, String> {
+    ScVal::from_xdr_base64(xdr, Limits::none())
+        .map(|value| to_json(&value))
+        .map_err(|e| format!("undecodable event XDR: {}", e))
+}
+
+/// JSON form of a contract value: symbols, strings and addresses as strings,
+/// 128-bit integers as decimal strings, bytes as hex, and structs (maps with
+/// symbol keys) as objects.
+fn to_json(value: &ScVal) -> Value {
+    match value {
+        ScVal::Bool(b) => json!(b),
+        ScVal::Void => Value::Null,
+        ScVal::U32(n) => json!(n),
+        ScVal::I32(n) => json!(n),
+        ScVal::U64(n) => json!(n),
+        ScVal::I64(n) => json!(n),
+        ScVal::Timepoint(t) => json!(t.0),
+        ScVal::Duration(d) => json!(d.0),
+        ScVal::U128(parts) => {
+            json!(((u128::from(parts.hi) << 64) | u128::from(parts.lo)).to_string())
+        }
+        ScVal::I128(parts) => {
+            json!(((i128::from(parts.hi) << 64) | i128::from(parts.lo)).to_string())
+        }
+        ScVal::Bytes(bytes) => json!(hex::encode(bytes.0.as_slice())),
+        ScVal::String(s) => json!(s.0.to_utf8_string_lossy()),
+        ScVal::Symbol(s) => json!(s.0.to_utf8_string_lossy()),
+        ScVal::Vec(items) => Value::Array(
+            items
+                .iter()
+                .flat_map(|items| items.0.iter())
+                .map(to_json)
+                .collect(),
+        ),
+        ScVal::Map(entries) => {
+            let entries: Vec<_> = entries.iter().flat_map(|map| map.0.iter()).collect();
+            let fields: Option<Map<String, Value>> = entries
+                .iter()
+                .map(|entry| match &entry.key {
+                    ScVal::Symbol(key) => Some((key.0.to_utf8_string_lossy(), to_json(&entry.val))),
+                    _ => None,
+                })
+                .collect();
+            fields.map(Value::Object).unwrap_or_else(|| {
+                entries
+                    .iter()
+                    .map(|entry| json!([to_json(&entry.key), to_json(&entry.val)]))
+                    .collect()
+            })
+        }
+        ScVal::Address(ScAddress::Account(account)) => {
+            let PublicKey::PublicKeyTypeEd25519(key) = &account.0;
+            json!(stellar_strkey::ed25519::PublicKey(key.0).to_string())
+        }
+        ScVal::Address(ScAddress::Contract(hash)) => {
+            json!(stellar_strkey::Contract(hash.0).to_string())
+        }
+        other => json!(format!("{:?}", other)),
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod export_tests {
+    use crate::testutils::{sign_attestation, AnchorKitFixture};
+    use crate::{Attestation, Error, QuoteData, ServiceType};
+    use soroban_sdk::{testutils::Address as _, xdr::FromXdr, Address, BytesN, Env, Vec};
+
+    #[test]
+    fn test_export_attestations_in_pages() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let attestor = kit.register_attestor();
+        let subject = Address::generate(&env);
+        for i in 0..5u8 {
+            let payload_hash = BytesN::from_array(&env, &[i + 1; 32]);
+            kit.client.submit_attestation_tracked(
+                &attestor,
+                &subject,
+                &1_700_000_000,
+                &payload_hash,
+                &sign_attestation(&env, &attestor, &subject, 1_700_000_000, &payload_hash),
+            );
+        }
+
+        let first = kit.client.export_attestations(&0, &3);
+        assert_eq!((first.count, first.next_cursor, first.done), (3, 3, false));
+        let decoded: Vec<Attestation> = Vec::from_xdr(&env, &first.data).unwrap();
+        assert_eq!(decoded.get(0).unwrap().id, 0);
+        assert_eq!(
+            decoded.get(2).unwrap().payload_hash,
+            BytesN::from_array(&env, &[3; 32])
+        );
+
+        let second = kit.client.export_attestations(&first.next_cursor, &3);
+        assert_eq!(
+            (second.count, second.next_cursor, second.done),
+            (2, 5, true)
+        );
+
+        assert_eq!(
+            kit.client.try_export_attestations(&0, &101),
+            Err(Ok(Error::InvalidConfig))
+        );
+    }
+
+    #[test]
+    fn test_export_quotes_per_anchor() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Quotes]);
+        let other = kit.register_anchor(&[ServiceType::Quotes]);
+        kit.seed_quote(&anchor, "USDC", "EUR", 9200, 60);
+        kit.seed_quote(&other, "USDC", "EUR", 9300, 60);
+        kit.seed_quote(&anchor, "USDC", "GBP", 8000, 60);
+
+        let page = kit.client.export_quotes(&anchor, &0, &10);
+        assert_eq!((page.count, page.next_cursor, page.done), (2, 4, true));
+        let quotes: Vec<QuoteData> = Vec::from_xdr(&env, &page.data).unwrap();
+        assert_eq!(quotes.get(1).unwrap().rate, 8000);
+
+        let page = kit.client.export_quotes(&other, &1, &1);
+        assert_eq!((page.count, page.next_cursor, page.done), (0, 2, false));
+    }
+}
@@ -0,0 +1,85 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::Storage,
+    types::{CounterpartyExposure, TransferRecord},
+};
+
+/// Bilateral exposure limits between anchors.
+///
+/// A transfer whose sender and destination are both registered anchors is a
+/// leg between them. Its amount counts towards the pair's outstanding value
+/// from initiation until settlement is confirmed, and a leg that would take
+/// the pair over its limit is rejected.
+pub struct Exposure;
+
+impl Exposure {
+    /// Exposure between two anchors, zero and unlimited if never recorded.
+    pub fn get(env: &Env, anchor_a: &Address, anchor_b: &Address) -> CounterpartyExposure {
+        let exposure = CounterpartyExposure::new(anchor_a, anchor_b);
+        Storage::get_exposure(env, &exposure.anchor_a, &exposure.anchor_b).unwrap_or(exposure)
+    }
+
+    pub fn set_limit(
+        env: &Env,
+        anchor_a: &Address,
+        anchor_b: &Address,
+        limit: i128,
+    ) -> Result<CounterpartyExposure, Error> {
+        if anchor_a == anchor_b || limit < 0 {
+            return Err(Error::InvalidConfig);
+        }
+        for anchor in [anchor_a, anchor_b] {
+            if !Storage::is_attestor(env, anchor) {
+                return Err(Error::AttestorNotRegistered);
+            }
+        }
+
+        let mut exposure = Self::get(env, anchor_a, anchor_b);
+        exposure.limit = limit;
+        Storage::set_exposure(env, &exposure);
+        Ok(exposure)
+    }
+
+    /// Count `transfer` against its anchors' exposure if it is a leg between
+    /// two anchors. Fails with `ExposureLimitExceeded` if the pair's
+    /// outstanding value would go over its limit.
+    pub fn open_leg(env: &Env, transfer: &TransferRecord) -> Result<(), Error> {
+        if !Self::is_leg(env, transfer) {
+            return Ok(());
+        }
+
+        let mut exposure = Self::get(env, &transfer.sender, &transfer.destination);
+        let outstanding = exposure
+            .outstanding
+            .checked_add(transfer.amount)
+            .ok_or(Error::ExposureLimitExceeded)?;
+        if exposure.limit != 0 && outstanding > exposure.limit {
+            return Err(Error::ExposureLimitExceeded);
+        }
+
+        exposure.outstanding = outstanding;
+        Storage::set_exposure(env, &exposure);
+        Storage::set_open_leg(env, transfer.transfer_id);
+        Ok(())
+    }
+
+    /// Release a settled leg's amount. Transfers that weren't counted, or
+    /// were already released, are ignored.
+    pub fn close_leg(env: &Env, transfer: &TransferRecord) {
+        if !Storage::take_open_leg(env, transfer.transfer_id) {
+            return;
+        }
+
+        let mut exposure = Self::get(env, &transfer.sender, &transfer.destination);
+        exposure.outstanding = (exposure.outstanding - transfer.amount).max(0);
+        Storage::set_exposure(env, &exposure);
+    }
+
+    fn is_leg(env: &Env, transfer: &TransferRecord) -> bool {
+        transfer.sender != transfer.destination
+            && Storage::is_attestor(env, &transfer.sender)
+            && Storage::is_attestor(env, &transfer.destination)
+    }
+}
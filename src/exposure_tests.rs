@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod exposure_tests {
+    use crate::testutils::AnchorKitFixture;
+    use crate::{Error, ServiceType};
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+    #[test]
+    fn test_exposure_limit_enforced_between_anchors() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor_a = kit.register_anchor(&[ServiceType::Deposits]);
+        let anchor_b = kit.register_anchor(&[ServiceType::Deposits]);
+        let settlement_ref = BytesN::from_array(&env, &[0u8; 32]);
+
+        let exposure = kit.client.set_exposure_limit(&anchor_a, &anchor_b, &1_000);
+        assert_eq!(kit.client.get_exposure(&anchor_b, &anchor_a), exposure);
+
+        let first = kit.client.initiate_transfer(&anchor_a, &anchor_b, &600);
+        kit.client.initiate_transfer(&anchor_b, &anchor_a, &400);
+        assert_eq!(
+            kit.client.get_exposure(&anchor_a, &anchor_b).outstanding,
+            1_000
+        );
+        assert_eq!(
+            kit.client.try_initiate_transfer(&anchor_a, &anchor_b, &1),
+            Err(Ok(Error::ExposureLimitExceeded))
+        );
+
+        // Settlement frees the leg's amount, once
+        kit.client.confirm_settlement(&first, &settlement_ref);
+        assert_eq!(
+            kit.client.try_confirm_settlement(&first, &settlement_ref),
+            Err(Ok(Error::ReplayAttack))
+        );
+        assert_eq!(
+            kit.client.get_exposure(&anchor_a, &anchor_b).outstanding,
+            400
+        );
+        kit.client.initiate_transfer(&anchor_a, &anchor_b, &600);
+
+        // Transfers to other parties are not legs
+        let user = Address::generate(&env);
+        kit.client.initiate_transfer(&anchor_a, &user, &5_000);
+        kit.client.initiate_transfer(&user, &anchor_b, &5_000);
+        assert_eq!(
+            kit.client.get_exposure(&anchor_a, &anchor_b).outstanding,
+            1_000
+        );
+    }
+
+    #[test]
+    fn test_exposure_limit_validation() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        let stranger = Address::generate(&env);
+
+        assert_eq!(
+            kit.client.try_set_exposure_limit(&anchor, &anchor, &1_000),
+            Err(Ok(Error::InvalidConfig))
+        );
+        assert_eq!(
+            kit.client
+                .try_set_exposure_limit(&anchor, &stranger, &1_000),
+            Err(Ok(Error::AttestorNotRegistered))
+        );
+
+        // Unlimited until a limit is set
+        let other = kit.register_anchor(&[ServiceType::Deposits]);
+        kit.client.initiate_transfer(&anchor, &other, &i128::MAX);
+        assert_eq!(kit.client.get_exposure(&anchor, &other).limit, 0);
+        assert_eq!(
+            kit.client.try_set_exposure_limit(&anchor, &other, &-1),
+            Err(Ok(Error::InvalidConfig))
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use soroban_sdk::Env;
+
+use crate::{errors::Error, events::FreezeChanged, storage::Storage, types::FreezeState};
+
+/// Incident-response freeze.
+///
+/// While frozen, every mutating entrypoint fails with `ContractFrozen`. Views,
+/// session audit-log writes (`append_audit_log`) and hash-lock claims keep
+/// working: a claim's timeout keeps running through the freeze, so blocking it
+/// would turn pending claims into refunds.
+///
+/// Blocked calls can't be recorded on-chain. A failed invocation rolls back
+/// everything it wrote, events included, so rejected calls only show up as
+/// failed transactions in the network's history.
+/// Lifting the freeze is timelocked. The admin requests it, and can complete
+/// it once `UNFREEZE_DELAY_SECONDS` have passed.
+pub struct Freeze;
+
+impl Freeze {
+    /// Delay between requesting and completing an unfreeze (24 hours).
+    pub const UNFREEZE_DELAY_SECONDS: u64 = 86_400;
+
+    /// Fail with `ContractFrozen` while the contract is frozen.
+    pub fn ensure_unfrozen(env: &Env) -> Result<(), Error> {
+        if Storage::get_freeze(env).is_some() {
+            return Err(Error::ContractFrozen);
+        }
+        Ok(())
+    }
+
+    pub fn freeze(env: &Env) -> Result<FreezeState, Error> {
+        if Storage::get_freeze(env).is_some() {
+            return Err(Error::ContractFrozen);
+        }
+        let state = FreezeState {
+            frozen_at: env.ledger().timestamp(),
+            unfreeze_after: 0,
+        };
+        Storage::set_freeze(env, &state);
+        FreezeChanged::publish(env, true, 0);
+        Ok(state)
+    }
+
+    /// Start the unfreeze timelock. Requesting again restarts it.
+    pub fn request_unfreeze(env: &Env) -> Result<FreezeState, Error> {
+        let mut state = Storage::get_freeze(env).ok_or(Error::NotFound)?;
+        state.unfreeze_after = env
+            .ledger()
+            .timestamp()
+            .saturating_add(Self::UNFREEZE_DELAY_SECONDS);
+        Storage::set_freeze(env, &state);
+        FreezeChanged::publish(env, true, state.unfreeze_after);
+        Ok(state)
+    }
+
+    /// Lift the freeze. Fails with `ContractFrozen` until an unfreeze was
+    /// requested and its timelock has passed.
+    pub fn unfreeze(env: &Env) -> Result<(), Error> {
+        let state = Storage::get_freeze(env).ok_or(Error::NotFound)?;
+        if state.unfreeze_after == 0 || env.ledger().timestamp() < state.unfreeze_after {
+            return Err(Error::ContractFrozen);
+        }
+        Storage::remove_freeze(env);
+        FreezeChanged::publish(env, false, 0);
+        Ok(())
+    }
+}
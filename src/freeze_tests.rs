@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod freeze_tests {
+    use crate::testutils::{advance_time, attestor_public_key, AnchorKitFixture};
+    use crate::{Error, Freeze, ServiceType};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    #[test]
+    fn test_freeze_blocks_mutations_until_timelocked_unfreeze() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        let user = Address::generate(&env);
+
+        let session_id = kit.client.create_session(&user);
+
+        let state = kit.client.freeze_all();
+        assert_eq!(kit.client.get_freeze_state(), Some(state));
+        assert_eq!(
+            kit.client.try_create_session(&user),
+            Err(Ok(Error::ContractFrozen))
+        );
+        assert_eq!(
+            kit.client.try_register_attestor(&Address::generate(&env), &attestor_public_key(&env)),
+            Err(Ok(Error::ContractFrozen))
+        );
+        assert_eq!(
+            kit.client.try_sweep_attestation_grants(&user),
+            Err(Ok(Error::ContractFrozen))
+        );
+        // Views and audit-log writes keep working
+        assert!(kit.client.is_attestor(&anchor));
+        let log_id = kit.client.append_audit_log(
+            &session_id,
+            &String::from_str(&env, "incident"),
+            &String::from_str(&env, "frozen"),
+            &None,
+        );
+        let log = kit.client.get_session_audit_log(&session_id, &0);
+        assert_eq!(log.log_id, log_id);
+        assert_eq!(log.operation.operation_type, String::from_str(&env, "incident"));
+        assert_eq!(log.actor, user);
+
+        // Unfreezing is timelocked
+        assert_eq!(kit.client.try_unfreeze(), Err(Ok(Error::ContractFrozen)));
+        let state = kit.client.request_unfreeze();
+        advance_time(&env, Freeze::UNFREEZE_DELAY_SECONDS - 1);
+        assert_eq!(kit.client.try_unfreeze(), Err(Ok(Error::ContractFrozen)));
+        advance_time(&env, 1);
+        assert_eq!(state.unfreeze_after, env.ledger().timestamp());
+        kit.client.unfreeze();
+
+        assert_eq!(kit.client.get_freeze_state(), None);
+        kit.client.create_session(&user);
+    }
+}
@@ -0,0 +1,113 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+use crate::{
+    compliance::Compliance,
+    errors::Error,
+    events::{
+        HashLockRefunded, HashLocked, PreimageRevealed, SettlementConfirmed, TransferInitiated,
+    },
+    rate_limiter::{OperationClass, RateLimiter},
+    storage::Storage,
+    token::TokenCustody,
+    types::{Asset, HashLock, HashLockStatus},
+};
+
+/// Hash-time-locked transfers for settling against systems outside Stellar.
+///
+/// Funds are escrowed against `sha256(preimage)`. Revealing the preimage before
+/// `timeout` releases them to the recipient (and publishes the preimage for the
+/// other leg of the swap); after `timeout` they can only be refunded to the sender.
+/// Locks share the transfer ID space, so the usual transfer events apply.
+pub struct HashTimeLock;
+
+impl HashTimeLock {
+    pub fn lock(
+        env: &Env,
+        sender: &Address,
+        recipient: &Address,
+        token: &Address,
+        amount: i128,
+        hash_lock: &BytesN<32>,
+        timeout: u64,
+    ) -> Result<u64, Error> {
+        if timeout <= env.ledger().timestamp() {
+            return Err(Error::InvalidTimestamp);
+        }
+        Compliance::ensure_not_blocked(env, sender)?;
+        Compliance::ensure_not_blocked(env, recipient)?;
+        RateLimiter::consume(env, sender, OperationClass::Transfer)?;
+
+        TokenCustody::escrow(env, token, sender, amount)?;
+
+        let transfer_id = Storage::get_next_intent_id(env);
+        Storage::set_hash_lock(
+            env,
+            &HashLock {
+                transfer_id,
+                token: token.clone(),
+                sender: sender.clone(),
+                recipient: recipient.clone(),
+                amount,
+                hash_lock: hash_lock.clone(),
+                timeout,
+                status: HashLockStatus::Locked,
+            },
+        );
+
+        TransferInitiated::publish(env, transfer_id, sender, recipient, amount);
+        HashLocked::publish(env, transfer_id, hash_lock, recipient, timeout);
+        Compliance::flag_if_large(
+            env,
+            transfer_id,
+            &Asset::ContractToken(token.clone()),
+            amount,
+        );
+
+        Ok(transfer_id)
+    }
+
+    pub fn claim(env: &Env, transfer_id: u64, preimage: &Bytes) -> Result<HashLock, Error> {
+        let mut lock = Self::get_locked(env, transfer_id)?;
+        let now = env.ledger().timestamp();
+        if now >= lock.timeout {
+            return Err(Error::InvalidTimestamp);
+        }
+
+        let digest: BytesN<32> = env.crypto().sha256(preimage).into();
+        if digest != lock.hash_lock {
+            return Err(Error::InvalidPreimage);
+        }
+
+        lock.status = HashLockStatus::Claimed;
+        Storage::set_hash_lock(env, &lock);
+        TokenCustody::release(env, &lock.token, &lock.recipient, lock.amount)?;
+
+        PreimageRevealed::publish(env, transfer_id, preimage);
+        SettlementConfirmed::publish(env, transfer_id, lock.hash_lock.clone(), now);
+
+        Ok(lock)
+    }
+
+    pub fn refund(env: &Env, transfer_id: u64) -> Result<HashLock, Error> {
+        let mut lock = Self::get_locked(env, transfer_id)?;
+        if env.ledger().timestamp() < lock.timeout {
+            return Err(Error::InvalidTimestamp);
+        }
+
+        lock.status = HashLockStatus::Refunded;
+        Storage::set_hash_lock(env, &lock);
+        TokenCustody::refund(env, &lock.token, &lock.sender, lock.amount)?;
+
+        HashLockRefunded::publish(env, transfer_id, &lock.sender, lock.amount);
+
+        Ok(lock)
+    }
+
+    fn get_locked(env: &Env, transfer_id: u64) -> Result<HashLock, Error> {
+        let lock = Storage::get_hash_lock(env, transfer_id)?;
+        if lock.status != HashLockStatus::Locked {
+            return Err(Error::ReplayAttack);
+        }
+        Ok(lock)
+    }
+}
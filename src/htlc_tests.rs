@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod htlc_tests {
+    use crate::{AnchorKitContract, AnchorKitContractClient, Error, HashLockStatus};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, Address, Bytes, BytesN, Env,
+    };
+
+    struct Fixture<'a> {
+        client: AnchorKitContractClient<'a>,
+        token: token::Client<'a>,
+        sender: Address,
+        recipient: Address,
+    }
+
+    fn setup(env: &Env) -> Fixture<'_> {
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        client.initialize(&admin);
+        let token_id = env.register_stellar_asset_contract_v2(admin).address();
+        client.add_supported_token(&token_id);
+
+        let sender = Address::generate(env);
+        token::StellarAssetClient::new(env, &token_id).mint(&sender, &1_000);
+
+        Fixture {
+            client,
+            token: token::Client::new(env, &token_id),
+            sender,
+            recipient: Address::generate(env),
+        }
+    }
+
+    fn hash_of(env: &Env, preimage: &Bytes) -> BytesN<32> {
+        env.crypto().sha256(preimage).into()
+    }
+
+    #[test]
+    fn test_claim_with_preimage_releases_to_recipient() {
+        let env = Env::default();
+        let f = setup(&env);
+        let preimage = Bytes::from_slice(&env, b"cross-chain secret");
+
+        let transfer_id = f.client.lock_with_hash(
+            &f.sender,
+            &f.recipient,
+            &f.token.address,
+            &400,
+            &hash_of(&env, &preimage),
+            &2_000,
+        );
+        assert_eq!(f.token.balance(&f.sender), 600);
+
+        let wrong = Bytes::from_slice(&env, b"guess");
+        let result = f.client.try_claim_with_preimage(&transfer_id, &wrong);
+        assert_eq!(result, Err(Ok(Error::InvalidPreimage)));
+
+        f.client.claim_with_preimage(&transfer_id, &preimage);
+        assert_eq!(f.token.balance(&f.recipient), 400);
+        assert_eq!(
+            f.client.get_hash_lock(&transfer_id).status,
+            HashLockStatus::Claimed
+        );
+
+        // Claimed locks cannot be claimed again or refunded
+        let result = f.client.try_claim_with_preimage(&transfer_id, &preimage);
+        assert_eq!(result, Err(Ok(Error::ReplayAttack)));
+        env.ledger().with_mut(|li| li.timestamp = 3_000);
+        let result = f.client.try_refund_after_timeout(&transfer_id);
+        assert_eq!(result, Err(Ok(Error::ReplayAttack)));
+    }
+
+    #[test]
+    fn test_claims_stay_open_during_freeze() {
+        let env = Env::default();
+        let f = setup(&env);
+        let preimage = Bytes::from_slice(&env, b"cross-chain secret");
+        let transfer_id = f.client.lock_with_hash(
+            &f.sender,
+            &f.recipient,
+            &f.token.address,
+            &400,
+            &hash_of(&env, &preimage),
+            &2_000,
+        );
+
+        f.client.freeze_all();
+        f.client.claim_with_preimage(&transfer_id, &preimage);
+        assert_eq!(f.token.balance(&f.recipient), 400);
+    }
+
+    #[test]
+    fn test_refund_only_after_timeout() {
+        let env = Env::default();
+        let f = setup(&env);
+        let preimage = Bytes::from_slice(&env, b"never revealed");
+
+        let transfer_id = f.client.lock_with_hash(
+            &f.sender,
+            &f.recipient,
+            &f.token.address,
+            &400,
+            &hash_of(&env, &preimage),
+            &2_000,
+        );
+
+        let result = f.client.try_refund_after_timeout(&transfer_id);
+        assert_eq!(result, Err(Ok(Error::InvalidTimestamp)));
+
+        env.ledger().with_mut(|li| li.timestamp = 2_000);
+        // Once the timeout is reached the preimage no longer unlocks the funds
+        let result = f.client.try_claim_with_preimage(&transfer_id, &preimage);
+        assert_eq!(result, Err(Ok(Error::InvalidTimestamp)));
+
+        f.client.refund_after_timeout(&transfer_id);
+        assert_eq!(f.token.balance(&f.sender), 1_000);
+        assert_eq!(
+            f.client.get_hash_lock(&transfer_id).status,
+            HashLockStatus::Refunded
+        );
+    }
+
+    #[test]
+    fn test_lock_rejects_past_timeout_and_unknown_transfer() {
+        let env = Env::default();
+        let f = setup(&env);
+        let hash = hash_of(&env, &Bytes::from_slice(&env, b"x"));
+
+        let result = f.client.try_lock_with_hash(
+            &f.sender,
+            &f.recipient,
+            &f.token.address,
+            &400,
+            &hash,
+            &1_000,
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidTimestamp)));
+
+        let result = f.client.try_refund_after_timeout(&42);
+        assert_eq!(result, Err(Ok(Error::TransferNotFound)));
+    }
+}
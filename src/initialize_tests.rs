@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod initialize_tests {
+    use crate::{AnchorKitContract, AnchorKitContractClient, Error};
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    #[test]
+    fn test_admin_is_readable_once_initialized() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(&env, &contract_id);
+
+        assert_eq!(client.try_get_admin(), Err(Ok(Error::NotInitialized)));
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+        assert_eq!(client.get_admin(), admin);
+
+        // Whoever initializes first keeps the contract
+        assert_eq!(
+            client.try_initialize(&Address::generate(&env)),
+            Err(Ok(Error::AlreadyInitialized))
+        );
+        assert_eq!(client.get_admin(), admin);
+    }
+}
@@ -0,0 +1,513 @@
+use std::io::{BufRead, IsTerminal, Write};
+
+use serde_json::{json, Value};
+
+use crate::quote_compare::{self, SIDE_BUY, SIDE_SELL};
+use crate::signer::Signer;
+use crate::stellar_cli::{self, Network};
+
+/// `ServiceType`, `QuoteContext` and `RoutingStrategy` as encoded by the contract
+const SERVICES: [(&str, u32); 3] = [("deposit", 1), ("withdrawal", 2), ("quotes", 3)];
+const CONTEXTS: [(&str, u32); 3] = [("sep6", 1), ("sep24", 2), ("sep31", 3)];
+const STRATEGIES: [(&str, &str); 4] = [
+    ("best-rate", "BestRate"),
+    ("lowest-fee", "LowestFee"),
+    ("fastest", "FastestSettlement"),
+    ("liquidity", "HighestLiquidity"),
+];
+
+/// Anchors `route_transaction` may consider
+const MAX_ANCHORS: u32 = 10;
+
+/// Default intent lifetime, matching `TransactionIntentBuilder::new`
+const DEFAULT_TTL: &str = "300";
+
+/// Answers given as flags. Anything missing is asked for on a terminal, or
+/// takes its default with `yes`.
+pub struct IntentOptions {
+    pub contract_id: String,
+    pub signer: Signer,
+    pub network: Network,
+    pub base: Option<String>,
+    pub quote: Option<String>,
+    pub amount: Option<String>,
+    pub buy: bool,
+    pub service: Option<String>,
+    pub context: Option<String>,
+    pub strategy: Option<String>,
+    /// Skip routing and use this anchor
+    pub anchor: Option<String>,
+    pub quote_id: Option<u64>,
+    pub kyc: bool,
+    pub subject: Option<String>,
+    /// Wallet or integrator credited with the flow
+    pub referrer: Option<String>,
+    /// Registered corridor the routing and intent reference
+    pub corridor: Option<u64>,
+    pub session: Option<u64>,
+    pub ttl: Option<u64>,
+    /// Earliest ledger time the intent may execute at
+    pub not_before: Option<u64>,
+    /// Requirement template the subject is checked against
+    pub requirement_template: Option<u64>,
+    /// Submit without asking; otherwise the intent is only simulated
+    pub submit: bool,
+    pub yes: bool,
+}
+
+/// The intent `build_transaction_intent` returned, or would return
+pub struct BuiltIntent {
+    pub intent: Value,
+    pub submitted: bool,
+}
+
+/// Walk through the intent's request, anchor, quote, KYC and TTL, check it with
+/// `validate_intent`, then simulate or submit `build_transaction_intent`.
+pub fn build(options: &IntentOptions) -> Result<BuiltIntent, String> {
+    stellar_cli::require_cli()?;
+    let wizard = Wizard {
+        interactive: !options.yes && std::io::stdin().is_terminal(),
+    };
+    let call = Call {
+        options,
+        source: options.signer.source_arg()?,
+    };
+
+    let base = quote_compare::asset_json(&wizard.ask(
+        "Sell asset (native, contract ID or CODE:ISSUER)",
+        "--base",
+        options.base.clone(),
+        None,
+    )?)?;
+    let quote = quote_compare::asset_json(&wizard.ask(
+        "Buy asset",
+        "--quote",
+        options.quote.clone(),
+        None,
+    )?)?;
+    let amount_asset = if options.buy { &quote } else { &base };
+    let decimals: u32 = call
+        .simulate("get_asset_decimals", &[("asset", amount_asset.clone())])?
+        .parse()
+        .map_err(|_| "unexpected get_asset_decimals output".to_string())?;
+    let amount = quote_compare::parse_amount(
+        &wizard.ask("Amount", "--amount", options.amount.clone(), None)?,
+        decimals,
+    )?;
+    let service = lookup(
+        &SERVICES,
+        &wizard.ask(
+            "Service (deposit, withdrawal, quotes)",
+            "--service",
+            options.service.clone(),
+            Some("deposit"),
+        )?,
+    )?;
+    let context = lookup(
+        &CONTEXTS,
+        &wizard.ask(
+            "Context (sep6, sep24, sep31)",
+            "--context",
+            options.context.clone(),
+            Some("sep6"),
+        )?,
+    )?;
+    let request = json!({
+        "base_asset": base,
+        "quote_asset": quote,
+        "amount": amount,
+        "operation_type": service,
+        "context": context,
+        "amount_side": if options.buy { SIDE_BUY } else { SIDE_SELL },
+        "delivery_method": null,
+        "country_code": null,
+    });
+
+    let (anchor, routed_quote) = match &options.anchor {
+        Some(anchor) => (anchor.clone(), None),
+        None => select_anchor(&wizard, &call, &request)?,
+    };
+    let quote_id = match (options.quote_id, routed_quote) {
+        (Some(quote_id), _) => quote_id,
+        (None, Some(quote_id))
+            if wizard.confirm(&format!("Attach quote {}?", quote_id), true)? =>
+        {
+            quote_id
+        }
+        _ => 0,
+    };
+    // The contract only accepts the quote's sequence as currently stored, so
+    // read it fresh rather than trusting the routed copy
+    let quote_sequence = match quote_id {
+        0 => 0,
+        quote_id => {
+            let quote = parse(
+                "query_quote",
+                &call.simulate(
+                    "query_quote",
+                    &[
+                        ("anchor", json!(anchor)),
+                        ("quote_id", json!(quote_id)),
+                        ("options", json!({ "notify_receiver": null })),
+                    ],
+                )?,
+            )?;
+            stellar_cli::json_u64(&quote, "sequence").unwrap_or_default()
+        }
+    };
+
+    let require_kyc = options.kyc || wizard.confirm("Require KYC?", false)?;
+    // The subject authorizes the build, so it defaults to the signer
+    let subject = match &options.subject {
+        Some(subject) => subject.clone(),
+        None => options.signer.address()?,
+    };
+    let session_id = number(&wizard.ask(
+        "Session ID (0 for none)",
+        "--session",
+        options.session.map(|id| id.to_string()),
+        Some("0"),
+    )?)?;
+    let ttl_seconds = number(&wizard.ask(
+        "TTL in seconds",
+        "--ttl",
+        options.ttl.map(|ttl| ttl.to_string()),
+        Some(DEFAULT_TTL),
+    )?)?;
+
+    let builder = json!({
+        "anchor": anchor,
+        "request": request,
+        "quote_id": quote_id,
+        "quote_sequence": quote_sequence,
+        "require_kyc": require_kyc,
+        "session_id": session_id,
+        "ttl_seconds": ttl_seconds,
+        "subject": subject,
+        "referrer": options.referrer,
+        "corridor_id": options.corridor.unwrap_or(0),
+        "not_before": options.not_before.unwrap_or(0),
+        "requirement_template": options.requirement_template.unwrap_or(0),
+    });
+
+    let validation = parse(
+        "validate_intent",
+        &call.simulate("validate_intent", &[("builder", builder.clone())])?,
+    )?;
+    if validation["valid"] != json!(true) {
+        for failure in validation_failures(&validation) {
+            println!("  ✖ {}", failure);
+        }
+        return Err("the contract would reject this intent".to_string());
+    }
+
+    let fn_args = [("builder", builder.to_string())];
+    let preview = parse(
+        "build_transaction_intent",
+        &call.simulate("build_transaction_intent", &[("builder", builder)])?,
+    )?;
+    if !(options.submit || wizard.confirm("Submit the intent? (no to only simulate)", false)?) {
+        return Ok(BuiltIntent {
+            intent: preview,
+            submitted: false,
+        });
+    }
+
+    let args = stellar_cli::invoke_args(
+        &options.contract_id,
+        &call.source,
+        &options.network,
+        "build_transaction_intent",
+        &fn_args,
+    )?;
+    let output = options.signer.submit(&args, &options.network)?;
+    // Identities return the stored intent; signed submissions only report the
+    // transaction, so show the intent as simulated just before.
+    Ok(BuiltIntent {
+        intent: serde_json::from_str(&output).unwrap_or(preview),
+        submitted: true,
+    })
+}
+
+/// Simulate `route_transaction`, list the selected anchor and alternatives and
+/// let the user pick one. Returns the anchor and its quote ID.
+fn select_anchor(
+    wizard: &Wizard,
+    call: &Call,
+    request: &Value,
+) -> Result<(String, Option<u64>), String> {
+    let strategy = lookup(
+        &STRATEGIES,
+        &wizard.ask(
+            "Routing strategy (best-rate, lowest-fee, fastest, liquidity)",
+            "--strategy",
+            call.options.strategy.clone(),
+            Some("best-rate"),
+        )?,
+    )?;
+    let routing_request = json!({
+        "request": request,
+        "strategy": strategy,
+        "max_anchors": MAX_ANCHORS,
+        "require_kyc": call.options.kyc,
+        "min_reputation": 0,
+        "corridor_id": call.options.corridor.unwrap_or(0),
+    });
+    let requester = call.options.signer.address()?;
+    let routed = parse(
+        "route_transaction",
+        &call.simulate(
+            "route_transaction",
+            &[
+                ("requester", json!(requester)),
+                ("routing_request", routing_request),
+            ],
+        )?,
+    )?;
+
+    let selected = routed["selected_anchor"].as_str().unwrap_or_default();
+    let mut options = vec![(selected.to_string(), routed["selected_quote"].clone())];
+    for alternative in routed["alternatives"].as_array().into_iter().flatten() {
+        let anchor = alternative["anchor"].as_str().unwrap_or_default();
+        if anchor != selected {
+            options.push((anchor.to_string(), alternative["quote"].clone()));
+        }
+    }
+
+    println!(
+        "  {:>2}  {:<13} {:>8} {:>10} {:>7}",
+        "#", "Anchor", "Quote", "Rate", "Fee"
+    );
+    for (index, (anchor, quote)) in options.iter().enumerate() {
+        println!(
+            "  {:>2}  {:<13} {:>8} {:>10} {:>6}%",
+            index + 1,
+            quote_compare::short_address(anchor),
+            stellar_cli::json_u64(quote, "quote_id").unwrap_or_default(),
+            quote_compare::format_units(
+                stellar_cli::json_u64(quote, "rate").unwrap_or_default() as u128,
+                4
+            ),
+            quote_compare::format_units(
+                stellar_cli::json_u64(quote, "fee_percentage").unwrap_or_default() as u128,
+                2
+            ),
+        );
+    }
+
+    let choice: usize = number(&wizard.ask("Anchor", "--anchor", None, Some("1"))?)? as usize;
+    let (anchor, quote) = options
+        .get(choice.wrapping_sub(1))
+        .ok_or_else(|| format!("choose an anchor between 1 and {}", options.len()))?;
+    Ok((anchor.clone(), stellar_cli::json_u64(quote, "quote_id")))
+}
+
+/// Read-only calls against the deployed contract
+struct Call<'a> {
+    options: &'a IntentOptions,
+    /// `--source` for simulations
+    source: String,
+}
+
+impl Call<'_> {
+    fn simulate(&self, function: &str, args: &[(&str, Value)]) -> Result<String, String> {
+        let args: Vec<(&str, String)> = args
+            .iter()
+            .map(|(name, value)| (*name, value.to_string()))
+            .collect();
+        stellar_cli::simulate(
+            &self.options.contract_id,
+            &self.source,
+            &self.options.network,
+            function,
+            &args,
+        )
+    }
+}
+
+/// Line-based prompts on stdin, or defaults when not interactive
+struct Wizard {
+    interactive: bool,
+}
+
+impl Wizard {
+    /// The flag's value, else the answer to `question`, else `default`.
+    fn ask(
+        &self,
+        question: &str,
+        flag: &str,
+        given: Option<String>,
+        default: Option<&str>,
+    ) -> Result<String, String> {
+        if let Some(given) = given {
+            return Ok(given);
+        }
+        if self.interactive {
+            let answer = self.read(&match default {
+                Some(default) => format!("{} [{}]", question, default),
+                None => question.to_string(),
+            })?;
+            if !answer.is_empty() {
+                return Ok(answer);
+            }
+        }
+        default
+            .map(str::to_string)
+            .ok_or_else(|| format!("{} is required", flag))
+    }
+
+    fn confirm(&self, question: &str, default: bool) -> Result<bool, String> {
+        if !self.interactive {
+            return Ok(default);
+        }
+        let answer = self.read(&format!(
+            "{} [{}]",
+            question,
+            if default { "Y/n" } else { "y/N" }
+        ))?;
+        Ok(match answer.to_ascii_lowercase().as_str() {
+            "" => default,
+            answer => answer.starts_with('y'),
+        })
+    }
+
+    fn read(&self, prompt: &str) -> Result<String, String> {
+        print!("? {}: ", prompt);
+        std::io::stdout().flush().map_err(|e| e.to_string())?;
+        let mut line = String::new();
+        std::io::stdin()
+            .lock()
+            .read_line(&mut line)
+            .map_err(|e| format!("failed to read answer: {}", e))?;
+        Ok(line.trim().to_string())
+    }
+}
+
+/// The failures reported by `validate_intent`, one line each.
+fn validation_failures(validation: &Value) -> Vec<String> {
+    validation["failures"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|failure| {
+            format!(
+                "{}: expected {}, got {} (error #{})",
+                plain(&failure["field"]),
+                plain(&failure["expected"]),
+                plain(&failure["actual"]),
+                plain(&failure["error"])
+            )
+        })
+        .collect()
+}
+
+fn lookup<T: Copy>(table: &[(&str, T)], name: &str) -> Result<T, String> {
+    table
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| *value)
+        .ok_or_else(|| {
+            let names: Vec<&str> = table.iter().map(|(key, _)| *key).collect();
+            format!("{} must be one of {}", name, names.join(", "))
+        })
+}
+
+fn number(value: &str) -> Result<u64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} is not a whole number", value))
+}
+
+/// JSON value without the quotes `stellar` puts around large integers and symbols.
+fn plain(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn parse(function: &str, output: &str) -> Result<Value, String> {
+    serde_json::from_str(output).map_err(|e| format!("unexpected {} output: {}", function, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NON_INTERACTIVE: Wizard = Wizard { interactive: false };
+
+    #[test]
+    fn test_flags_answer_without_asking() {
+        assert_eq!(
+            NON_INTERACTIVE.ask("Amount", "--amount", Some("12.5".to_string()), Some("1")),
+            Ok("12.5".to_string())
+        );
+    }
+
+    #[test]
+    fn test_missing_answers_take_their_default() {
+        assert_eq!(
+            NON_INTERACTIVE.ask("TTL in seconds", "--ttl", None, Some(DEFAULT_TTL)),
+            Ok(DEFAULT_TTL.to_string())
+        );
+        assert_eq!(
+            NON_INTERACTIVE.ask("Buy asset", "--quote", None, None),
+            Err("--quote is required".to_string())
+        );
+        assert_eq!(NON_INTERACTIVE.confirm("Require KYC?", false), Ok(false));
+        assert_eq!(NON_INTERACTIVE.confirm("Attach quote 7?", true), Ok(true));
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(lookup(&SERVICES, "withdrawal"), Ok(2));
+        assert_eq!(lookup(&CONTEXTS, "SEP24"), Ok(2));
+        assert_eq!(lookup(&STRATEGIES, "fastest"), Ok("FastestSettlement"));
+        assert_eq!(
+            lookup(&SERVICES, "swap"),
+            Err("swap must be one of deposit, withdrawal, quotes".to_string())
+        );
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number("300"), Ok(300));
+        assert_eq!(number("-1"), Err("-1 is not a whole number".to_string()));
+        assert_eq!(number("5m"), Err("5m is not a whole number".to_string()));
+    }
+
+    #[test]
+    fn test_validation_failures() {
+        let validation = json!({
+            "valid": false,
+            "kyc_level": 0,
+            "expires_at": 0,
+            "failures": [
+                { "error": 5, "field": "amount", "expected": "1000000", "actual": "5000000" },
+                { "error": 48, "field": "kyc_level", "expected": "1", "actual": "0" },
+            ],
+        });
+        assert_eq!(
+            validation_failures(&validation),
+            [
+                "amount: expected 1000000, got 5000000 (error #5)",
+                "kyc_level: expected 1, got 0 (error #48)",
+            ]
+        );
+
+        let valid = json!({ "valid": true, "kyc_level": 1, "expires_at": 300, "failures": [] });
+        assert!(validation_failures(&valid).is_empty());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("query_quote", r#"{"sequence": 2}"#),
+            Ok(json!({ "sequence": 2 }))
+        );
+        assert!(parse("query_quote", "Error: contract not found")
+            .err()
+            .unwrap()
+            .starts_with("unexpected query_quote output"));
+    }
+}
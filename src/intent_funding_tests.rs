@@ -0,0 +1,227 @@
+#[cfg(test)]
+mod intent_funding_tests {
+    use crate::testutils::attestor_public_key;
+    use crate::storage::Storage;
+    use crate::{
+        AmountSide, AnchorKitContract, AnchorKitContractClient, Asset, Error, QuoteContext,
+        QuoteRequest, ServiceType, TransactionIntentBuilder,
+    };
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, vec, Address, Env, String,
+    };
+
+    struct Fixture<'a> {
+        contract_id: Address,
+        client: AnchorKitContractClient<'a>,
+        anchor: Address,
+        token: Address,
+        payer: Address,
+    }
+
+    fn asset(env: &Env, code: &str) -> Asset {
+        let issuer = String::from_str(
+            env,
+            "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN",
+        );
+        Asset::Classic(String::from_str(env, code), Address::from_string(&issuer))
+    }
+
+    fn setup(env: &Env) -> Fixture<'_> {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let anchor = Address::generate(env);
+        client.initialize(&admin);
+        client.register_attestor(&anchor, &attestor_public_key(env));
+        client.configure_services(&anchor, &vec![env, ServiceType::Deposits]);
+
+        let token = env.register_stellar_asset_contract_v2(admin).address();
+        client.add_supported_token(&token);
+
+        let payer = Address::generate(env);
+        token::StellarAssetClient::new(env, &token).mint(&payer, &10_000);
+
+        Fixture {
+            contract_id,
+            client,
+            anchor,
+            token,
+            payer,
+        }
+    }
+
+    fn build_intent(env: &Env, f: &Fixture, amount: u64) -> u64 {
+        let request = QuoteRequest {
+            base_asset: asset(env, "USDC"),
+            quote_asset: asset(env, "EUR"),
+            amount,
+            operation_type: ServiceType::Deposits,
+            context: QuoteContext::Sep6,
+            amount_side: AmountSide::Sell,
+            delivery_method: None,
+            country_code: None,
+        };
+        let builder = TransactionIntentBuilder::new(env, f.anchor.clone(), request)
+            .with_subject(f.payer.clone());
+        f.client.build_transaction_intent(&builder).intent_id
+    }
+
+    #[test]
+    fn test_execute_pulls_exact_amount_from_allowance() {
+        let env = Env::default();
+        let f = setup(&env);
+        let balances = token::Client::new(&env, &f.token);
+        balances.approve(&f.payer, &f.contract_id, &5_000, &1000);
+
+        let intent_id = build_intent(&env, &f, 1_200);
+        f.client.execute_intent(&intent_id, &f.payer, &f.token);
+
+        assert_eq!(balances.balance(&f.payer), 8_800);
+        assert_eq!(balances.balance(&f.anchor), 1_200);
+        assert_eq!(balances.allowance(&f.payer, &f.contract_id), 3_800);
+
+        // An intent is funded at most once
+        let result = f.client.try_execute_intent(&intent_id, &f.payer, &f.token);
+        assert_eq!(result, Err(Ok(Error::ReplayAttack)));
+    }
+
+    #[test]
+    fn test_only_the_subject_builds_and_funds_its_intent() {
+        let env = Env::default();
+        let f = setup(&env);
+        let balances = token::Client::new(&env, &f.token);
+
+        let intent_id = build_intent(&env, &f, 1_200);
+        let (authorizer, _) = env.auths().pop().unwrap();
+        assert_eq!(authorizer, f.payer);
+
+        // Someone else can't fund it, even with their own allowance
+        let other = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &f.token).mint(&other, &10_000);
+        balances.approve(&other, &f.contract_id, &5_000, &1000);
+        let result = f.client.try_execute_intent(&intent_id, &other, &f.token);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+        assert_eq!(balances.balance(&other), 10_000);
+
+        // An intent needs a subject
+        let request = QuoteRequest {
+            base_asset: asset(&env, "USDC"),
+            quote_asset: asset(&env, "EUR"),
+            amount: 1_200,
+            operation_type: ServiceType::Deposits,
+            context: QuoteContext::Sep6,
+            amount_side: AmountSide::Sell,
+            delivery_method: None,
+            country_code: None,
+        };
+        let builder = TransactionIntentBuilder::new(&env, f.anchor.clone(), request);
+        assert_eq!(
+            f.client.try_build_transaction_intent(&builder),
+            Err(Ok(Error::InvalidTransactionIntent))
+        );
+    }
+
+    #[test]
+    fn test_reduced_allowance_fails_cleanly() {
+        let env = Env::default();
+        let f = setup(&env);
+        let balances = token::Client::new(&env, &f.token);
+        balances.approve(&f.payer, &f.contract_id, &5_000, &1000);
+
+        let intent_id = build_intent(&env, &f, 1_200);
+        balances.approve(&f.payer, &f.contract_id, &1_000, &1000);
+
+        let result = f.client.try_execute_intent(&intent_id, &f.payer, &f.token);
+        assert_eq!(result, Err(Ok(Error::InsufficientAllowance)));
+        assert_eq!(balances.balance(&f.payer), 10_000);
+
+        // Topping the allowance back up lets the same intent go through
+        balances.approve(&f.payer, &f.contract_id, &1_200, &1000);
+        assert!(f
+            .client
+            .try_execute_intent(&intent_id, &f.payer, &f.token)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_unknown_intent_and_unlisted_token() {
+        let env = Env::default();
+        let f = setup(&env);
+
+        let result = f.client.try_execute_intent(&99, &f.payer, &f.token);
+        assert_eq!(result, Err(Ok(Error::IntentNotFound)));
+
+        let intent_id = build_intent(&env, &f, 100);
+        f.client.remove_supported_token(&f.token);
+        let result = f.client.try_execute_intent(&intent_id, &f.payer, &f.token);
+        assert_eq!(result, Err(Ok(Error::UnsupportedAsset)));
+    }
+
+    #[test]
+    fn test_locked_intent_fails_fast() {
+        let env = Env::default();
+        let f = setup(&env);
+        let balances = token::Client::new(&env, &f.token);
+        balances.approve(&f.payer, &f.contract_id, &5_000, &1000);
+        let intent_id = build_intent(&env, &f, 1_200);
+
+        // As seen by an attempt arriving while another execution runs
+        env.as_contract(&f.contract_id, || {
+            Storage::lock_intent(&env, intent_id).unwrap()
+        });
+        let result = f.client.try_execute_intent(&intent_id, &f.payer, &f.token);
+        assert_eq!(result, Err(Ok(Error::IntentLocked)));
+        assert_eq!(balances.balance(&f.payer), 10_000);
+
+        env.as_contract(&f.contract_id, || Storage::unlock_intent(&env, intent_id));
+        f.client.execute_intent(&intent_id, &f.payer, &f.token);
+        assert_eq!(balances.balance(&f.anchor), 1_200);
+
+        // The lock is released once execution finishes
+        env.as_contract(&f.contract_id, || {
+            assert_eq!(Storage::lock_intent(&env, intent_id), Ok(()));
+        });
+    }
+
+    #[test]
+    fn test_execution_window() {
+        let env = Env::default();
+        let f = setup(&env);
+        let balances = token::Client::new(&env, &f.token);
+        balances.approve(&f.payer, &f.contract_id, &5_000, &1000);
+        env.ledger().set_timestamp(1_000);
+
+        let request = QuoteRequest {
+            base_asset: asset(&env, "USDC"),
+            quote_asset: asset(&env, "EUR"),
+            amount: 1_200,
+            operation_type: ServiceType::Deposits,
+            context: QuoteContext::Sep6,
+            amount_side: AmountSide::Sell,
+            delivery_method: None,
+            country_code: None,
+        };
+        let builder = TransactionIntentBuilder::new(&env, f.anchor.clone(), request)
+            .with_subject(f.payer.clone())
+            .with_ttl(3_600)
+            .with_not_before(2_000);
+
+        // The window must open before the intent expires
+        let closed = builder.clone().with_not_before(4_600);
+        assert_eq!(
+            f.client.try_build_transaction_intent(&closed),
+            Err(Ok(Error::InvalidTransactionIntent))
+        );
+
+        let intent_id = f.client.build_transaction_intent(&builder).intent_id;
+        let result = f.client.try_execute_intent(&intent_id, &f.payer, &f.token);
+        assert_eq!(result, Err(Ok(Error::InvalidTimestamp)));
+
+        env.ledger().set_timestamp(2_000);
+        f.client.execute_intent(&intent_id, &f.payer, &f.token);
+        assert_eq!(balances.balance(&f.anchor), 1_200);
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod intent_template_tests {
+    use crate::testutils::AnchorKitFixture;
+    use crate::{Error, ServiceType};
+    use soroban_sdk::{
+        testutils::{Address as _, AuthorizedFunction},
+        Address, Env, IntoVal, Symbol,
+    };
+
+    #[test]
+    fn test_build_intents_from_template() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        let owner = kit.user.clone();
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 999)
+            .with_ttl(900);
+        let template_id = kit.client.create_intent_template(&owner, &builder);
+        let template = kit.client.get_intent_template(&template_id);
+        assert_eq!(template.owner, owner);
+        assert_eq!(template.builder.request.amount, 0);
+
+        let first = kit.client.build_intent_from_template(&template_id, &100);
+        // Only the owner builds from its template
+        let (authorizer, invocation) = env.auths().pop().unwrap();
+        assert_eq!(authorizer, owner);
+        assert_eq!(
+            invocation.function,
+            AuthorizedFunction::Contract((
+                kit.contract_id.clone(),
+                Symbol::new(&env, "build_intent_from_template"),
+                (template_id, 100u64).into_val(&env),
+            ))
+        );
+        let second = kit.client.build_intent_from_template(&template_id, &250);
+        assert_eq!(first.request.amount, 100);
+        assert_eq!(second.request.amount, 250);
+        assert_eq!(second.anchor, anchor);
+        assert_eq!(second.expires_at, second.created_at + 900);
+        assert_ne!(first.intent_id, second.intent_id);
+
+        // Amount-dependent checks still run per intent
+        assert_eq!(
+            kit.client.try_build_intent_from_template(&template_id, &0),
+            Err(Ok(Error::InvalidTransactionIntent))
+        );
+        assert_eq!(
+            kit.client
+                .try_build_intent_from_template(&(template_id + 1), &100),
+            Err(Ok(Error::NotFound))
+        );
+    }
+
+    #[test]
+    fn test_template_invariants_checked_on_create() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Withdrawals]);
+        let owner = kit.user.clone();
+
+        // The anchor doesn't offer deposits
+        let builder = kit.deposit_intent(&anchor, "USDC", "EUR", 100);
+        assert_eq!(
+            kit.client.try_create_intent_template(&owner, &builder),
+            Err(Ok(Error::InvalidServiceType))
+        );
+
+        // Templates build intents for their owner only
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 100)
+            .with_subject(Address::generate(&env));
+        assert_eq!(
+            kit.client.try_create_intent_template(&owner, &builder),
+            Err(Ok(Error::Unauthorized))
+        );
+
+        // Per-flow fields can't be fixed in a template
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        kit.client.create_session(&owner);
+        let session_id = kit.client.create_session(&owner);
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 100)
+            .with_session(session_id);
+        assert_eq!(
+            kit.client.try_create_intent_template(&owner, &builder),
+            Err(Ok(Error::InvalidTransactionIntent))
+        );
+    }
+}
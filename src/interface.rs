@@ -0,0 +1,126 @@
+use soroban_sdk::{contractclient, Address, Bytes, BytesN, Env, Vec};
+
+use crate::{
+    errors::Error,
+    types::{
+        AnchorProfileView, Asset, Attestation, ContractCapabilities, ContractVersion, QuoteData,
+        QuoteQueryOptions, QuoteRequest, RateComparison, RoutingRequest, RoutingResult,
+        ServiceType, SettlementDetails,
+    },
+};
+
+/// Read-side AnchorKit API for other Soroban contracts.
+///
+/// The generated `AnchorKitClient` calls a deployed AnchorKit contract with
+/// typed arguments, e.g. `AnchorKitClient::new(&env, &anchorkit_id).query_quote(..)`.
+/// Signatures must stay in sync with the matching `AnchorKitContract` entrypoints.
+#[contractclient(name = "AnchorKitClient")]
+pub trait AnchorKitInterface {
+    /// Report version, interfaces and compiled-in subsystems for feature detection.
+    fn get_capabilities(env: Env) -> ContractCapabilities;
+
+    /// Report semantic version, storage schema version and compiled-in subsystems.
+    fn version(env: Env) -> ContractVersion;
+
+    /// Get a quote by anchor and quote ID, optionally notifying a receiver.
+    fn query_quote(
+        env: Env,
+        anchor: Address,
+        quote_id: u64,
+        options: QuoteQueryOptions,
+    ) -> Result<QuoteData, Error>;
+
+    /// Compare valid quotes for a request across the given anchors.
+    fn compare_rates_for_anchors(
+        env: Env,
+        request: QuoteRequest,
+        anchors: Vec<Address>,
+    ) -> Result<RateComparison, Error>;
+
+    /// Compare quotes like `compare_rates_for_anchors`, listing the excluded ones and why.
+    fn compare_rates_with_exclusions(
+        env: Env,
+        request: QuoteRequest,
+        anchors: Vec<Address>,
+    ) -> Result<RateComparison, Error>;
+
+    /// Up to `depth` live quotes for an asset pair across anchors, best first.
+    fn get_order_book(
+        env: Env,
+        base_asset: Asset,
+        quote_asset: Asset,
+        depth: u32,
+    ) -> Vec<QuoteData>;
+
+    /// Route a request to the best anchor for the given strategy.
+    fn route_transaction(
+        env: Env,
+        requester: Address,
+        routing_request: RoutingRequest,
+    ) -> Result<RoutingResult, Error>;
+
+    /// Check whether an address is a registered attestor.
+    fn is_attestor(env: Env, attestor: Address) -> bool;
+
+    /// Get a recorded attestation by ID, if `reader` may read it.
+    fn get_attestation(
+        env: Env,
+        reader: Address,
+        attestation_id: u64,
+    ) -> Result<Attestation, Error>;
+
+    /// Check whether an attestation exists, without revealing its details.
+    fn attestation_exists(env: Env, attestation_id: u64) -> bool;
+
+    /// Get a subject's most recent attestation, following supersession links,
+    /// if `reader` may read it.
+    fn get_latest_subject_attestation(
+        env: Env,
+        reader: Address,
+        subject: Address,
+    ) -> Result<Attestation, Error>;
+
+    /// Check whether an attestation with `payload_hash` was ever recorded.
+    fn is_attestation_recorded(env: Env, payload_hash: BytesN<32>) -> bool;
+
+    /// Check whether an attestation exists and is neither revoked nor expired.
+    fn is_attestation_valid(env: Env, attestation_id: u64) -> bool;
+
+    /// Canonical domain-separated `payload_hash` for an attestation payload.
+    fn hash_attestation_payload(env: Env, payload: Bytes) -> BytesN<32>;
+
+    /// Check whether an anchor offers a service.
+    fn supports_service(env: Env, anchor: Address, service: ServiceType) -> bool;
+
+    /// Get an anchor's services, endpoints, metadata, health, tier and quote heads.
+    fn get_anchor_profile(env: Env, anchor: Address) -> Result<AnchorProfileView, Error>;
+}
+
+/// Callback implemented by receiver contracts that want to react to settlements.
+///
+/// When `confirm_settlement` finalizes a transfer whose destination is a contract,
+/// AnchorKit calls `on_settlement` on it with the transfer details. A receiver that
+/// does not implement the hook (or fails inside it) does not block settlement.
+#[contractclient(name = "SettlementHookClient")]
+pub trait SettlementHook {
+    /// Called once per settled transfer, after the settlement is recorded.
+    fn on_settlement(env: Env, details: SettlementDetails);
+}
+
+/// Verifier implemented by privacy-preserving identity providers.
+///
+/// A registered verifier checks a zero-knowledge proof that the holder of
+/// `commitment` passed KYC at `level` or above, without revealing customer data.
+/// On success AnchorKit records the level in its KYC registry with the verifier
+/// as `verified_by`, so compliance checks treat it like an anchor-issued record.
+#[contractclient(name = "KycProofVerifierClient")]
+pub trait KycProofVerifier {
+    /// Return `true` if `proof` is valid for `subject`, `commitment` and `level`.
+    fn verify_kyc_proof(
+        env: Env,
+        subject: Address,
+        commitment: BytesN<32>,
+        proof: Bytes,
+        level: u32,
+    ) -> bool;
+}
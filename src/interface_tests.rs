@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod interface_tests {
+    use crate::testutils::attestor_public_key;
+    use crate::{
+        AmountSide, AnchorKitClient, AnchorKitContract, AnchorKitContractClient, Asset, Error,
+        QuoteContext, QuoteQueryOptions, QuoteRequest, ServiceType,
+    };
+    use soroban_sdk::{
+        contract, contractimpl, testutils::Address as _, vec, Address, Env, String, Vec,
+    };
+
+    /// Minimal downstream contract that consumes AnchorKit through the typed client.
+    #[contract]
+    struct Consumer;
+
+    #[contractimpl]
+    impl Consumer {
+        pub fn best_rate(
+            env: Env,
+            anchorkit: Address,
+            request: QuoteRequest,
+            anchors: Vec<Address>,
+        ) -> u64 {
+            let client = AnchorKitClient::new(&env, &anchorkit);
+            client.compare_rates_for_anchors(&request, &anchors).best_quote.rate
+        }
+    }
+
+    fn asset(env: &Env, code: &str) -> Asset {
+        let issuer = String::from_str(env, "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN");
+        Asset::Classic(String::from_str(env, code), Address::from_string(&issuer))
+    }
+
+    fn setup(env: &Env) -> (Address, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let anchor = Address::generate(env);
+        client.initialize(&admin);
+        client.register_attestor(&anchor, &attestor_public_key(env));
+        client.configure_services(&anchor, &vec![env, ServiceType::Quotes]);
+        client.submit_quote(
+            &anchor,
+            &asset(env, "USD"),
+            &asset(env, "USDC"),
+            &9950,
+            &10,
+            &1,
+            &100_000,
+            &3600,
+        );
+
+        (contract_id, anchor)
+    }
+
+    #[test]
+    fn test_interface_client_reads_contract() {
+        let env = Env::default();
+        let (contract_id, anchor) = setup(&env);
+        let client = AnchorKitClient::new(&env, &contract_id);
+
+        assert!(client.is_attestor(&anchor));
+        assert!(client.supports_service(&anchor, &ServiceType::Quotes));
+        let read = QuoteQueryOptions::read_only();
+        assert_eq!(client.query_quote(&anchor, &1, &read).rate, 9950);
+        assert_eq!(client.try_query_quote(&anchor, &99, &read), Err(Ok(Error::QuoteNotFound)));
+    }
+
+    #[test]
+    fn test_cross_contract_consumer() {
+        let env = Env::default();
+        let (contract_id, anchor) = setup(&env);
+        let consumer_id = env.register_contract(None, Consumer);
+        let consumer = ConsumerClient::new(&env, &consumer_id);
+
+        let request = QuoteRequest {
+            base_asset: asset(&env, "USD"),
+            quote_asset: asset(&env, "USDC"),
+            amount: 1000,
+            operation_type: ServiceType::Quotes,
+            context: QuoteContext::Sep6,
+            amount_side: AmountSide::Sell,
+            delivery_method: None,
+            country_code: None,
+        };
+
+        let rate = consumer.best_rate(&contract_id, &request, &vec![&env, anchor]);
+        assert_eq!(rate, 9950);
+    }
+}
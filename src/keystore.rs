@@ -0,0 +1,302 @@
+use std::io::{IsTerminal, Read};
+use std::path::Path;
+
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::ChaCha20Poly1305;
+use ed25519_dalek::SigningKey;
+use serde::{Deserialize, Serialize};
+use stellar_strkey::ed25519;
+
+use crate::stellar_cli;
+
+const VERSION: u32 = 1;
+
+/// scrypt cost: 2^15 rounds with r = 8 takes about 32 MiB and well under a
+/// second, the same work factor as most wallet keystores
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// Environment variable holding the keystore password for unattended use
+pub const PASSWORD_ENV: &str = "ANCHORKIT_KEYSTORE_PASSWORD";
+
+/// A signing key encrypted at rest: ChaCha20-Poly1305 over the ed25519 seed,
+/// keyed by scrypt of the password, with the address as associated data.
+#[derive(Serialize, Deserialize)]
+pub struct Keystore {
+    pub version: u32,
+    /// Account address of the key, readable without the password
+    pub address: String,
+    pub kdf: Kdf,
+    /// Hex-encoded 96-bit nonce
+    pub nonce: String,
+    /// Hex-encoded encrypted seed and authentication tag
+    pub ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Kdf {
+    pub name: String,
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+    /// Hex-encoded 128-bit salt
+    pub salt: String,
+}
+
+impl Keystore {
+    /// Encrypt `key` under `password` with a fresh salt and nonce.
+    pub fn encrypt(key: &SigningKey, password: &str) -> Result<Self, String> {
+        let salt: [u8; 16] = random()?;
+        let nonce: [u8; 12] = random()?;
+        let kdf = Kdf {
+            name: "scrypt".to_string(),
+            log_n: SCRYPT_LOG_N,
+            r: SCRYPT_R,
+            p: SCRYPT_P,
+            salt: hex::encode(salt),
+        };
+        let address = ed25519::PublicKey(key.verifying_key().to_bytes()).to_string();
+        let ciphertext = cipher(&kdf, password)?
+            .encrypt(
+                &nonce.into(),
+                Payload {
+                    msg: key.as_bytes(),
+                    aad: address.as_bytes(),
+                },
+            )
+            .map_err(|_| "failed to encrypt key".to_string())?;
+
+        Ok(Self {
+            version: VERSION,
+            address,
+            kdf,
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    /// Decrypt the signing key. Fails on a wrong password or a tampered file.
+    pub fn decrypt(&self, password: &str) -> Result<SigningKey, String> {
+        if self.version != VERSION || self.kdf.name != "scrypt" {
+            return Err(format!(
+                "unsupported keystore version {} ({})",
+                self.version, self.kdf.name
+            ));
+        }
+        let nonce: [u8; 12] = hex::decode(&self.nonce)
+            .ok()
+            .and_then(|nonce| nonce.try_into().ok())
+            .ok_or_else(|| "invalid keystore nonce".to_string())?;
+        let ciphertext =
+            hex::decode(&self.ciphertext).map_err(|_| "invalid keystore ciphertext".to_string())?;
+        let seed = cipher(&self.kdf, password)?
+            .decrypt(
+                &nonce.into(),
+                Payload {
+                    msg: &ciphertext,
+                    aad: self.address.as_bytes(),
+                },
+            )
+            .map_err(|_| "wrong keystore password".to_string())?;
+        let seed: [u8; 32] = seed
+            .try_into()
+            .map_err(|_| "invalid keystore key length".to_string())?;
+
+        let key = SigningKey::from_bytes(&seed);
+        if ed25519::PublicKey(key.verifying_key().to_bytes()).to_string() != self.address {
+            return Err("keystore key does not match its address".to_string());
+        }
+        Ok(key)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read keystore {}: {}", path.display(), e))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| format!("invalid keystore {}: {}", path.display(), e))
+    }
+
+    /// Write the keystore to a new file, readable only by the owner. Never
+    /// overwrites an existing keystore.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let contents = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        let mut file = options
+            .open(path)
+            .map_err(|e| format!("failed to create keystore {}: {}", path.display(), e))?;
+        std::io::Write::write_all(&mut file, (contents + "\n").as_bytes())
+            .map_err(|e| format!("failed to write keystore {}: {}", path.display(), e))
+    }
+
+    /// Load and decrypt the keystore at `path`, asking for its password.
+    pub fn unlock(path: &Path) -> Result<SigningKey, String> {
+        let keystore = Self::load(path)?;
+        let prompt = format!("Password for {}: ", path.display());
+        keystore.decrypt(&read_password(&prompt, false)?)
+    }
+}
+
+/// Read a password from `ANCHORKIT_KEYSTORE_PASSWORD`, or prompt for it on the
+/// terminal without echo. `confirm` asks twice, for new keystores.
+pub fn read_password(prompt: &str, confirm: bool) -> Result<String, String> {
+    if let Ok(password) = std::env::var(PASSWORD_ENV) {
+        return non_empty(password);
+    }
+    let password = non_empty(
+        rpassword::prompt_password(prompt)
+            .map_err(|e| format!("failed to read password (or set {}): {}", PASSWORD_ENV, e))?,
+    )?;
+    if confirm
+        && rpassword::prompt_password("Confirm password: ")
+            .map_err(|e| format!("failed to read password: {}", e))?
+            != password
+    {
+        return Err("passwords do not match".to_string());
+    }
+    Ok(password)
+}
+
+fn non_empty(password: String) -> Result<String, String> {
+    if password.is_empty() {
+        return Err("password must not be empty".to_string());
+    }
+    Ok(password)
+}
+
+/// Read a secret key to import: prompted without echo on a terminal,
+/// otherwise read from stdin (e.g. piped from `stellar keys show`).
+pub fn read_secret_key() -> Result<SigningKey, String> {
+    let secret = if std::io::stdin().is_terminal() {
+        rpassword::prompt_password("Secret key: ")
+            .map_err(|e| format!("failed to read secret key: {}", e))?
+    } else {
+        let mut secret = String::new();
+        std::io::stdin()
+            .read_to_string(&mut secret)
+            .map_err(|e| format!("failed to read secret key: {}", e))?;
+        secret
+    };
+    let secret = secret.trim();
+    if !stellar_cli::is_secret_key(secret) {
+        return Err("expected a secret key (S...)".to_string());
+    }
+    ed25519::PrivateKey::from_string(secret)
+        .map(|private| SigningKey::from_bytes(&private.0))
+        .map_err(|_| "invalid secret key".to_string())
+}
+
+/// A new random signing key.
+pub fn generate() -> Result<SigningKey, String> {
+    Ok(SigningKey::from_bytes(&random()?))
+}
+
+fn cipher(kdf: &Kdf, password: &str) -> Result<ChaCha20Poly1305, String> {
+    let salt = hex::decode(&kdf.salt).map_err(|_| "invalid keystore salt".to_string())?;
+    let params = scrypt::Params::new(kdf.log_n, kdf.r, kdf.p, 32)
+        .map_err(|e| format!("invalid scrypt parameters: {}", e))?;
+    let mut key = [0u8; 32];
+    scrypt::scrypt(password.as_bytes(), &salt, &params, &mut key)
+        .map_err(|e| format!("scrypt failed: {}", e))?;
+    Ok(ChaCha20Poly1305::new(&key.into()))
+}
+
+fn random<const N: usize>() -> Result<[u8; N], String> {
+    let mut bytes = [0u8; N];
+    getrandom::getrandom(&mut bytes).map_err(|e| format!("no system randomness: {}", e))?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> SigningKey {
+        SigningKey::from_bytes(&[byte; 32])
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let keystore = Keystore::encrypt(&key(1), "hunter2").unwrap();
+        assert_eq!(
+            keystore.address,
+            ed25519::PublicKey(key(1).verifying_key().to_bytes()).to_string()
+        );
+        assert_eq!(keystore.decrypt("hunter2").unwrap().as_bytes(), key(1).as_bytes());
+
+        // The file format carries everything needed to decrypt
+        let json = serde_json::to_string(&keystore).unwrap();
+        let loaded: Keystore = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.decrypt("hunter2").unwrap().as_bytes(), key(1).as_bytes());
+    }
+
+    #[test]
+    fn test_wrong_password_is_rejected() {
+        let keystore = Keystore::encrypt(&key(1), "hunter2").unwrap();
+        assert_eq!(
+            keystore.decrypt("hunter3").err().unwrap(),
+            "wrong keystore password"
+        );
+    }
+
+    #[test]
+    fn test_tampering_is_rejected() {
+        let mut keystore = Keystore::encrypt(&key(1), "hunter2").unwrap();
+        let mut ciphertext = hex::decode(&keystore.ciphertext).unwrap();
+        ciphertext[0] ^= 1;
+        keystore.ciphertext = hex::encode(ciphertext);
+        assert!(keystore.decrypt("hunter2").is_err());
+
+        // The address is authenticated too
+        let mut keystore = Keystore::encrypt(&key(1), "hunter2").unwrap();
+        keystore.address = ed25519::PublicKey(key(2).verifying_key().to_bytes()).to_string();
+        assert!(keystore.decrypt("hunter2").is_err());
+    }
+
+    #[test]
+    fn test_key_must_match_its_address() {
+        // Validly encrypted, but under another key's address
+        let mut keystore = Keystore::encrypt(&key(1), "hunter2").unwrap();
+        keystore.address = ed25519::PublicKey(key(2).verifying_key().to_bytes()).to_string();
+        let nonce: [u8; 12] = hex::decode(&keystore.nonce).unwrap().try_into().unwrap();
+        let ciphertext = cipher(&keystore.kdf, "hunter2")
+            .unwrap()
+            .encrypt(
+                &nonce.into(),
+                Payload {
+                    msg: key(1).as_bytes(),
+                    aad: keystore.address.as_bytes(),
+                },
+            )
+            .unwrap();
+        keystore.ciphertext = hex::encode(ciphertext);
+
+        assert_eq!(
+            keystore.decrypt("hunter2").err().unwrap(),
+            "keystore key does not match its address"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_version_is_rejected() {
+        let mut keystore = Keystore::encrypt(&key(1), "hunter2").unwrap();
+        keystore.version = VERSION + 1;
+        assert!(keystore
+            .decrypt("hunter2")
+            .err()
+            .unwrap()
+            .starts_with("unsupported keystore version"));
+    }
+
+    #[test]
+    fn test_empty_password_from_env_is_rejected() {
+        std::env::set_var(PASSWORD_ENV, "");
+        let result = read_password("Password: ", false);
+        std::env::remove_var(PASSWORD_ENV);
+        assert_eq!(result, Err("password must not be empty".to_string()));
+    }
+}
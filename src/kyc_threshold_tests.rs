@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod kyc_threshold_tests {
+    use crate::testutils::AnchorKitFixture;
+    use crate::{Error, KycThreshold, ServiceType};
+    use soroban_sdk::{
+        testutils::{Address as _, MockAuth, MockAuthInvoke},
+        vec, Address, Env, IntoVal,
+    };
+
+    fn setup(env: &Env) -> (AnchorKitFixture<'_>, Address) {
+        let kit = AnchorKitFixture::new(env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::KYC]);
+        kit.client.set_kyc_thresholds(
+            &anchor,
+            &vec![
+                env,
+                KycThreshold {
+                    amount_above: 1_000,
+                    level: 2,
+                },
+                KycThreshold {
+                    amount_above: 10_000,
+                    level: 3,
+                },
+            ],
+        );
+        (kit, anchor)
+    }
+
+    #[test]
+    fn test_amount_thresholds_select_required_level() {
+        let env = Env::default();
+        let (kit, anchor) = setup(&env);
+        let subject = Address::generate(&env);
+        kit.client.set_kyc_level(&anchor, &subject, &2);
+
+        // Below every threshold no KYC is needed, even for an unverified subject
+        let intent = kit
+            .client
+            .build_transaction_intent(&kit.deposit_intent(&anchor, "USDC", "EUR", 1_000));
+        assert!(!intent.requires_kyc);
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 5_000)
+            .with_subject(subject.clone());
+        let intent = kit.client.build_transaction_intent(&builder);
+        assert_eq!(intent.kyc_level, 2);
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 20_000)
+            .with_subject(subject.clone());
+        let result = kit.client.try_build_transaction_intent(&builder);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+
+        kit.client.set_kyc_level(&anchor, &subject, &3);
+        assert_eq!(kit.client.build_transaction_intent(&builder).kyc_level, 3);
+    }
+
+    #[test]
+    fn test_required_kyc_needs_verified_subject() {
+        let env = Env::default();
+        let (kit, anchor) = setup(&env);
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 100)
+            .require_kyc();
+        let result = kit.client.try_build_transaction_intent(&builder);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+
+        let subject = Address::generate(&env);
+        let builder = builder.with_subject(subject.clone());
+        let result = kit.client.try_build_transaction_intent(&builder);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+
+        kit.client.set_kyc_level(&anchor, &subject, &1);
+        let intent = kit.client.build_transaction_intent(&builder);
+        assert_eq!(intent.kyc_level, 1);
+        assert_eq!(
+            kit.client.get_kyc_record(&subject).unwrap().verified_by,
+            anchor
+        );
+    }
+
+    #[test]
+    fn test_verified_subject_must_authorize_its_intents() {
+        let env = Env::default();
+        let (kit, anchor) = setup(&env);
+        let subject = Address::generate(&env);
+        kit.client.set_kyc_level(&anchor, &subject, &3);
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 20_000)
+            .with_subject(subject.clone());
+
+        // Naming a verified subject doesn't borrow its KYC level
+        env.set_auths(&[]);
+        assert!(kit.client.try_build_transaction_intent(&builder).is_err());
+
+        env.mock_auths(&[MockAuth {
+            address: &subject,
+            invoke: &MockAuthInvoke {
+                contract: &kit.contract_id,
+                fn_name: "build_transaction_intent",
+                args: (builder.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert_eq!(kit.client.build_transaction_intent(&builder).kyc_level, 3);
+    }
+
+    #[test]
+    fn test_registry_writes_require_kyc_service() {
+        let env = Env::default();
+        let (kit, _) = setup(&env);
+        let deposit_only = kit.register_anchor(&[ServiceType::Deposits]);
+
+        let result = kit
+            .client
+            .try_set_kyc_level(&deposit_only, &Address::generate(&env), &2);
+        assert_eq!(result, Err(Ok(Error::InvalidServiceType)));
+
+        let zero = vec![
+            &env,
+            KycThreshold {
+                amount_above: 0,
+                level: 0,
+            },
+        ];
+        let result = kit.client.try_set_kyc_thresholds(&deposit_only, &zero);
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+    }
+}
@@ -1,23 +1,64 @@
 #![no_std]
 extern crate alloc;
 
+#[cfg(feature = "quotes")]
+mod amount;
 mod asset_validator;
+#[cfg(feature = "compliance")]
+mod compliance;
 mod config;
 mod connection_pool;
 mod credentials;
+#[cfg(feature = "compliance")]
+mod diagnostics;
+#[cfg(feature = "compliance")]
+mod erasure;
 mod error_mapping;
 mod errors;
 mod events;
+#[cfg(feature = "transfers")]
+mod exposure;
+mod freeze;
+#[cfg(feature = "transfers")]
+mod htlc;
+mod interface;
 mod skeleton_loaders;
+#[cfg(feature = "routing")]
 mod metadata_cache;
+#[cfg(feature = "transfers")]
+mod metrics;
+#[cfg(feature = "quotes")]
+mod oracle;
+#[cfg(feature = "quotes")]
+mod order_book;
+#[cfg(feature = "transfers")]
+mod quote_capacity;
+mod rbac;
 mod rate_limiter;
 mod request_history;
 mod request_id;
 mod retry;
+mod scheduled_params;
 mod serialization;
+#[cfg(feature = "transfers")]
+mod settlement_penalty;
 mod storage;
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
+#[cfg(feature = "transfers")]
+mod offboarding;
+#[cfg(feature = "transfers")]
+mod retry_queue;
+#[cfg(feature = "transfers")]
+mod token;
 mod transport;
+#[cfg(feature = "transfers")]
+mod travel_rule;
+#[cfg(feature = "transfers")]
+mod treasury;
 mod types;
+#[cfg(feature = "routing")]
+mod uptime_oracle;
 mod validation;
 
 #[cfg(test)]
@@ -28,10 +69,10 @@ mod config_builder_tests;
 mod deterministic_hash_tests;
 #[cfg(test)]
 mod sdk_config_tests;
-#[cfg(test)]
+#[cfg(all(test, feature = "attestations"))]
 mod session_tests;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "routing"))]
 mod capability_detection_tests;
 
 #[cfg(test)]
@@ -46,10 +87,10 @@ mod retry_tests;
 #[cfg(test)]
 mod error_mapping_tests;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "attestations", feature = "quotes"))]
 mod streaming_flow_tests;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "quotes"))]
 mod routing_tests;
 
 #[cfg(test)]
@@ -64,49 +105,285 @@ mod cross_platform_tests;
 #[cfg(test)]
 mod zerocopy_tests;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "routing"))]
 mod metadata_cache_tests;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "attestations", feature = "quotes"))]
 mod request_id_tests;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "attestations", feature = "quotes"))]
 mod request_history_tests;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "attestations"))]
 mod tracing_span_tests;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "quotes"))]
 mod load_simulation_tests;
 
+#[cfg(test)]
+mod credential_binding_tests;
+
+#[cfg(test)]
+mod credential_delegation_tests;
+
+#[cfg(all(test, feature = "quotes"))]
+mod sep38_quote_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod transfer_info_tests;
+
+#[cfg(test)]
+mod toml_hash_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod token_tests;
+
+#[cfg(all(test, feature = "quotes"))]
+mod interface_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod custom_account_auth_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod deposit_memo_tests;
+
+#[cfg(all(test, feature = "attestations"))]
+mod did_binding_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod sponsored_ops_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod intent_funding_tests;
+
+#[cfg(test)]
+mod capabilities_tests;
+
+#[cfg(test)]
+mod initialize_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod htlc_tests;
+
+#[cfg(all(test, feature = "quotes"))]
+mod oracle_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod settlement_hook_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod testutils_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod kyc_threshold_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod travel_rule_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod blocklist_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod corridor_rules_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod aml_flag_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod compliance_officer_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod zk_kyc_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod compliance_report_tests;
+
+#[cfg(all(test, feature = "compliance"))]
+mod erasure_tests;
+
+#[cfg(all(test, feature = "quotes"))]
+mod quote_query_tests;
+
+#[cfg(all(test, feature = "quotes"))]
+mod quote_submission_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod diagnostics_tests;
+
+#[cfg(all(test, feature = "routing"))]
+mod anchor_profile_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod amount_tests;
+
+#[cfg(all(test, feature = "attestations"))]
+mod attestation_query_tests;
+
+#[cfg(test)]
+mod endpoint_tests;
+
+#[cfg(test)]
+mod endpoint_challenge_tests;
+
+#[cfg(all(test, feature = "attestations"))]
+mod event_schema_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod operation_limit_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod treasury_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod referral_tests;
+
+#[cfg(all(test, feature = "routing"))]
+mod uptime_oracle_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod metrics_tests;
+
+#[cfg(all(test, feature = "transfers", feature = "routing"))]
+mod corridor_registry_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod quote_sequence_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod offboarding_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod asset_support_tests;
+
+#[cfg(all(test, feature = "transfers", feature = "routing"))]
+mod settlement_stats_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod retry_queue_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod export_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod requirement_template_tests;
 
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String, Vec};
+#[cfg(all(test, feature = "transfers"))]
+mod exposure_tests;
 
+#[cfg(all(test, feature = "attestations"))]
+mod ttl_touch_tests;
+
+#[cfg(all(test, feature = "transfers", feature = "routing"))]
+mod routing_receipt_tests;
+
+#[cfg(all(test, feature = "transfers", feature = "routing"))]
+mod quote_exclusion_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod quote_capacity_tests;
+
+#[cfg(all(test, feature = "transfers"))]
+mod intent_template_tests;
+
+#[cfg(all(test, feature = "attestations"))]
+mod freeze_tests;
+
+#[cfg(test)]
+mod alias_tests;
+
+#[cfg(test)]
+mod attestor_batch_tests;
+
+#[cfg(all(test, feature = "benchmarks", feature = "transfers", feature = "routing"))]
+mod benchmark_tests;
+
+
+use soroban_sdk::{
+    contract, contractimpl, contractmeta, symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, String, Symbol,
+    Vec,
+};
+
+#[cfg(feature = "quotes")]
+pub use amount::{Amount, DEFAULT_DECIMALS, MAX_DECIMALS};
 pub use asset_validator::{AssetConfig, AssetValidator};
+#[cfg(feature = "compliance")]
+pub use compliance::{Compliance, COMPLIANCE_PERIOD_SECONDS};
+#[cfg(feature = "compliance")]
+pub use diagnostics::CheckReport;
+#[cfg(feature = "compliance")]
+pub use erasure::Erasure;
 pub use config::{AttestorConfig, ContractConfig, SessionConfig};
 pub use connection_pool::{ConnectionPool, ConnectionPoolConfig, ConnectionStats};
-pub use credentials::{CredentialManager, CredentialPolicy, CredentialType, SecureCredential};
+pub use credentials::{
+    CredentialBinding, CredentialGrant, CredentialManager, CredentialPolicy, CredentialType, SecureCredential,
+};
 pub use errors::Error;
 pub use events::{
-    AttestationRecorded, AttestorAdded, AttestorRemoved, EndpointConfigured, EndpointRemoved,
-    OperationLogged, QuoteReceived, QuoteSubmitted, ServicesConfigured, SessionCreated,
-    SettlementConfirmed, TransferInitiated,
+    AliasChanged, AmlFlagRaised, AmlFlagReviewed, AttestationAccessChanged, AttestationRecorded, AttestationSuperseded, AttestationValidityChanged, BlocklistUpdated, AttestorAdded, AttestorRemoved, CredentialAccessChanged, DelegateChanged, ParameterChangeScheduled,
+    CredentialOrphaned, DidChanged, EventSchema, EVENT_SCHEMAS, FreezeChanged, EndpointChallenged, EndpointChecked, EndpointConfigured, ErasureRequested, RecordTombstoned, SubjectErased, HashLockRefunded, HashLocked,
+    KycLevelUpdated, KycProofVerified, LatePenaltyAccrued, OperationSponsored, RoleChanged, PreimageRevealed, TravelRuleCommitted,
+    EndpointRemoved, FingerprintPinned, OperationLogged, QuoteReceived, QuoteSubmitted, ServicesConfigured, SessionCreated,
+    SettlementConfirmed, TomlHashAnchored, TransferInfoUpdated, TransferInitiated, TreasuryCredited,
+    TreasuryWithdrawn, ReferralPaid, UptimeUpdated, OffboardingAdvanced, RoutingReceiptIssued,
+};
+#[cfg(feature = "transfers")]
+pub use htlc::HashTimeLock;
+pub use interface::{
+    AnchorKitClient, AnchorKitInterface, KycProofVerifier, KycProofVerifierClient, SettlementHook,
+    SettlementHookClient,
 };
 pub use skeleton_loaders::{
     AnchorInfoSkeleton, AuthValidationSkeleton, TransactionStatusSkeleton, ValidationStep,
 };
+#[cfg(feature = "routing")]
 pub use metadata_cache::{CachedCapabilities, CachedMetadata, MetadataCache};
-pub use rate_limiter::{RateLimitConfig, RateLimiter};
+#[cfg(feature = "transfers")]
+pub use metrics::{Metrics, METRICS_PERIOD_SECONDS};
+#[cfg(feature = "quotes")]
+pub use oracle::{OracleAdapter, OracleClient, PriceOracle};
+#[cfg(feature = "quotes")]
+pub use order_book::OrderBook;
+pub use rate_limiter::{OperationClass, OperationLimit, RateLimitConfig, RateLimiter};
+pub use scheduled_params::{Parameter, ParameterChange, ScheduledChange, ScheduledParams};
+pub use rbac::Rbac;
 pub use request_history::{ApiCallDetails, ApiCallRecord, ApiCallStatus, RequestHistory, RequestHistoryPanel};
 pub use request_id::{RequestId, RequestTracker, TracingSpan};
 pub use storage::Storage;
+#[cfg(feature = "transfers")]
+pub use exposure::Exposure;
+#[cfg(feature = "transfers")]
+pub use quote_capacity::ReservedCapacity;
+pub use freeze::Freeze;
+#[cfg(feature = "transfers")]
+pub use offboarding::Offboarding;
+#[cfg(feature = "transfers")]
+pub use retry_queue::RetryQueue;
+#[cfg(feature = "transfers")]
+pub use settlement_penalty::SettlementPenalty;
+#[cfg(feature = "transfers")]
+pub use token::TokenCustody;
+#[cfg(feature = "transfers")]
+pub use travel_rule::TravelRule;
+#[cfg(feature = "transfers")]
+pub use treasury::Treasury;
 pub use types::{
-    AnchorMetadata, AnchorOption, AnchorProfile, AnchorSearchQuery, AnchorServices, Attestation, AuditLog, Endpoint, HealthStatus,
-    InteractionSession, OperationContext, QuoteData, QuoteRequest, RateComparison, RoutingRequest,
-    RoutingResult, RoutingStrategy, ServiceType, TransactionIntent, TransactionIntentBuilder,
+    AmlFlag, AmlFlagStatus, AmountSide, AnchorActivity, AnchorBond, AnchorFeeRevenue, Asset, AssetSupport, AttestationResolution, AttestorBatchResult, AttestorDelegate, AttestorRegistration, AuditMode, CapacityReservation, CheckFailure, ExclusionReason, IntentValidation, ComplianceReport, ContractCapabilities, ContractVersion, Corridor, CounterpartyExposure, ExportPage, CorridorRules, DepositMemo, DepositMemoRecord, DidBinding, HashLock, HashLockStatus, IntentExecution, IntentTemplate, KycRecord, LateSettlementPenalty, Role, KycThreshold, RequirementTemplate, ReplayScope, SessionQuotePin, ScoreBreakdown, OracleAsset, OracleFeed, PriceData, AnchorMetadata, AnchorOption, AnchorProfile, AnchorProfileView, AnchorSearchQuery, AnchorTier, AnchorServices, Attestation, AttestationGrant, AuditLog, Endpoint, EndpointChallenge, EndpointKind, FreezeState, HealthStatus,
+    InteractionSession, OperationContext, QuoteBinding, QuoteContext, QuoteCapacity, QuoteData, QuoteExclusion, QuoteQueryOptions, QuoteRequest, QuoteSubmission, RateComparison,
+    ReplayVerdict, ReplayVerification, RetryRecord, RetryTarget, RoutingReceipt, RoutingRequest, RoutingResult, RoutingStrategy, Sep38Terms, ServiceType, SettlementDetails, SettlementStats, TransactionIntent,
+    TransactionIntentBuilder, Tombstone, TombstoneKind, TomlHash, TransferInfo, TransferRecord, TravelRuleCommitment,
+    TravelRuleRole, OffboardingStage, OffboardingState, ProceedsKind, TreasuryBalance, ReferralVolume, UptimeReport, UsageMetrics,
 };
+#[cfg(feature = "routing")]
+pub use uptime_oracle::UptimeOracle;
 pub use validation::{validate_attestor_batch, validate_init_config, validate_session_config};
 
+// Machine-readable build metadata embedded in the Wasm custom section.
+// `interfaces`, `features` and CONTRACT_INTERFACES / CONTRACT_FEATURES are
+// generated by build.rs from the enabled Cargo features.
+contractmeta!(key = "name", val = "AnchorKit");
+contractmeta!(key = "version", val = "0.1.0");
+include!(concat!(env!("OUT_DIR"), "/contract_features.rs"));
+
 #[contract]
 pub struct AnchorKitContract;
 
@@ -122,6 +399,52 @@ impl AnchorKitContract {
         Ok(())
     }
 
+    /// The contract's admin. Fails with `NotInitialized` before `initialize`.
+    ///
+    /// soroban-sdk 21 doesn't run a `__constructor` at deployment, so deploying
+    /// and initializing are separate transactions and anyone could initialize
+    /// in between. Deployers check this afterwards, as `anchorkit deploy` does.
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        Storage::get_admin(&env)
+    }
+
+    /// Report the contract version, supported interfaces and compiled-in subsystems
+    /// so SDKs can feature-detect which AnchorKit build they are talking to.
+    pub fn get_capabilities(env: Env) -> ContractCapabilities {
+        let mut interfaces = Vec::new(&env);
+        for name in CONTRACT_INTERFACES {
+            interfaces.push_back(Symbol::new(&env, name));
+        }
+
+        ContractCapabilities {
+            version: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            interfaces,
+            features: Self::compiled_features(&env),
+        }
+    }
+
+    /// Report the semantic version, storage schema version and compiled-in
+    /// subsystems, so clients can adapt to the deployed build.
+    pub fn version(env: Env) -> ContractVersion {
+        let part = |value: &str| value.parse().unwrap_or(0);
+
+        ContractVersion {
+            major: part(env!("CARGO_PKG_VERSION_MAJOR")),
+            minor: part(env!("CARGO_PKG_VERSION_MINOR")),
+            patch: part(env!("CARGO_PKG_VERSION_PATCH")),
+            schema_version: Storage::SCHEMA_VERSION,
+            features: Self::compiled_features(&env),
+        }
+    }
+
+    fn compiled_features(env: &Env) -> Vec<Symbol> {
+        let mut features = Vec::new(env);
+        for name in CONTRACT_FEATURES {
+            features.push_back(Symbol::new(env, name));
+        }
+        features
+    }
+
     /// Initialize with validated configuration to prevent misconfiguration bugs
     pub fn initialize_with_config(
         env: Env,
@@ -144,6 +467,8 @@ impl AnchorKitContract {
 
     /// Batch register attestors with strict validation
     pub fn batch_register_attestors(env: Env, attestors: Vec<AttestorConfig>) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
@@ -163,6 +488,7 @@ impl AnchorKitContract {
             }
 
             Storage::set_attestor(&env, &attestor_addr, true);
+            Storage::set_attestor_key(&env, &attestor_addr, &config.public_key);
             AttestorAdded::publish(&env, &attestor_addr);
         }
 
@@ -171,6 +497,8 @@ impl AnchorKitContract {
 
     /// Configure session settings with strict validation
     pub fn configure_session_settings(env: Env, config: SessionConfig) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
@@ -181,215 +509,538 @@ impl AnchorKitContract {
         Ok(())
     }
 
-    /// Register a new attestor. Only callable by admin.
-    pub fn register_attestor(env: Env, attestor: Address) -> Result<(), Error> {
+    /// Freeze the contract for incident response. Every mutating entrypoint
+    /// then fails with `ContractFrozen` until the freeze is lifted through
+    /// `request_unfreeze` and `unfreeze`. Only callable by admin.
+    pub fn freeze_all(env: Env) -> Result<FreezeState, Error> {
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
-        if Storage::is_attestor(&env, &attestor) {
-            return Err(Error::AttestorAlreadyRegistered);
-        }
+        Freeze::freeze(&env)
+    }
 
-        Storage::set_attestor(&env, &attestor, true);
-        AttestorAdded::publish(&env, &attestor);
+    /// Start the unfreeze timelock. Only callable by admin.
+    pub fn request_unfreeze(env: Env) -> Result<FreezeState, Error> {
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
 
-        Ok(())
+        Freeze::request_unfreeze(&env)
     }
 
-    /// Get a specific quote and notify listeners that it has been received.
-    /// This fulfills the "Quote Received" requirement.
-    pub fn receive_quote(
-        env: Env,
-        receiver: Address,
-        anchor: Address,
-        quote_id: u64,
-    ) -> Result<QuoteData, Error> {
-        receiver.require_auth();
+    /// Lift the freeze once the timelock started by `request_unfreeze` has
+    /// passed. Only callable by admin.
+    pub fn unfreeze(env: Env) -> Result<(), Error> {
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
 
-        // Use your existing storage method
-        let quote = Storage::get_quote(&env, &anchor, quote_id).ok_or(Error::QuoteNotFound)?;
+        Freeze::unfreeze(&env)
+    }
 
-        // Emit the event
-        QuoteReceived::publish(&env, quote_id, &receiver, env.ledger().timestamp());
+    pub fn get_freeze_state(env: Env) -> Option<FreezeState> {
+        Storage::get_freeze(&env)
+    }
 
-        Ok(quote)
+    /// Register a new attestor with the ed25519 public key its attestation
+    /// signatures are verified against. Only callable by admin.
+    pub fn register_attestor(
+        env: Env,
+        attestor: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        Self::add_attestor(&env, &attestor, &public_key)
     }
 
-    /// Helper function to initiate a transfer (Lifecycle Event 2)
-    pub fn initiate_transfer(
+    /// Register up to `MAX_ATTESTORS` attestors in one call. Only callable by
+    /// admin. Entries are applied independently: the result for each, in the
+    /// order of `registrations`, carries the error code it failed with or 0.
+    pub fn register_attestors_batch(
         env: Env,
-        sender: Address,
-        destination: Address,
-        amount: i128,
-    ) -> Result<u64, Error> {
-        sender.require_auth();
+        registrations: Vec<AttestorRegistration>,
+    ) -> Result<Vec<AttestorBatchResult>, Error> {
+        Freeze::ensure_unfrozen(&env)?;
 
-        // 1. Logic for fund movement or intent recording would go here
-        let transfer_id = Storage::get_next_intent_id(&env);
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
 
-        // 2. Emit the "Transfer Initiated" event
-        TransferInitiated::publish(&env, transfer_id, &sender, &destination, amount);
+        if registrations.len() > config::MAX_ATTESTORS {
+            return Err(Error::InvalidConfig);
+        }
 
-        Ok(transfer_id)
+        let mut results = Vec::new(&env);
+        for registration in registrations.iter() {
+            let AttestorRegistration {
+                attestor,
+                public_key,
+            } = registration;
+            let outcome = Self::add_attestor(&env, &attestor, &public_key);
+            results.push_back(Self::batch_result(attestor, &outcome));
+        }
+        Ok(results)
     }
 
-    /// Confirm the final settlement of a transfer (Lifecycle Event 3)
-    pub fn confirm_settlement(
+    /// Revoke up to `MAX_ATTESTORS` attestors in one call. Only callable by
+    /// admin. Like `register_attestors_batch`, each entry succeeds or fails on
+    /// its own.
+    pub fn revoke_attestors_batch(
         env: Env,
-        transfer_id: u64,
-        settlement_ref: BytesN<32>,
-    ) -> Result<(), Error> {
-        // Only admin can confirm settlement in this example
+        attestors: Vec<Address>,
+    ) -> Result<Vec<AttestorBatchResult>, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
-        // 1. Update internal state (if applicable)
+        if attestors.len() > config::MAX_ATTESTORS {
+            return Err(Error::InvalidConfig);
+        }
 
-        // 2. Emit the "Settlement Confirmed" event
-        SettlementConfirmed::publish(&env, transfer_id, settlement_ref, env.ledger().timestamp());
+        let mut results = Vec::new(&env);
+        for attestor in attestors.iter() {
+            let outcome = Self::remove_attestor(&env, &attestor);
+            results.push_back(Self::batch_result(attestor, &outcome));
+        }
+        Ok(results)
+    }
+
+    fn batch_result(attestor: Address, outcome: &Result<(), Error>) -> AttestorBatchResult {
+        AttestorBatchResult {
+            attestor,
+            error: outcome.as_ref().err().map_or(0, |e| *e as u32),
+        }
+    }
+
+    fn add_attestor(env: &Env, attestor: &Address, public_key: &BytesN<32>) -> Result<(), Error> {
+        if Storage::is_attestor(env, attestor) {
+            return Err(Error::AttestorAlreadyRegistered);
+        }
+
+        Storage::set_attestor(env, attestor, true);
+        Storage::set_attestor_key(env, attestor, public_key);
+        // A completed offboarding doesn't carry over to a re-registered anchor
+        Storage::remove_offboarding(env, attestor);
+        AttestorAdded::publish(env, attestor);
+
+        Ok(())
+    }
+
+    fn remove_attestor(env: &Env, attestor: &Address) -> Result<(), Error> {
+        if !Storage::is_attestor(env, attestor) {
+            return Err(Error::AttestorNotRegistered);
+        }
+
+        Storage::set_attestor(env, attestor, false);
+        AttestorRemoved::publish(env, attestor);
 
         Ok(())
     }
 
-    /// Get the endpoint configuration for an attestor.
-    pub fn get_endpoint(env: Env, attestor: Address) -> Result<Endpoint, Error> {
-        Storage::get_endpoint(&env, &attestor)
+    /// Check whether an address is a registered attestor.
+    pub fn is_attestor(env: Env, attestor: Address) -> bool {
+        Storage::is_attestor(&env, &attestor)
     }
 
-    /// Configure supported services for an anchor. Callable by the anchor.
-    pub fn configure_services(
-        env: Env,
-        anchor: Address,
-        services: Vec<ServiceType>,
-    ) -> Result<(), Error> {
-        Storage::get_admin(&env)?;
-        anchor.require_auth();
+    /// Bind a DID-style identifier hash to the calling subject, replacing any
+    /// previous binding. A DID can only be bound to one subject at a time.
+    pub fn bind_did(env: Env, subject: Address, did_hash: BytesN<32>) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
 
-        Self::validate_services(&services)?;
+        subject.require_auth();
 
-        if !Storage::is_attestor(&env, &anchor) {
-            return Err(Error::AttestorNotRegistered);
+        if let Some(owner) = Storage::get_did_subject(&env, &did_hash) {
+            if owner != subject {
+                return Err(Error::AlreadyExists);
+            }
         }
 
-        let anchor_services = AnchorServices {
-            anchor: anchor.clone(),
-            services: services.clone(),
+        if let Some(previous) = Storage::get_did_binding(&env, &subject) {
+            Storage::remove_did_binding(&env, &previous);
+        }
+
+        let binding = DidBinding {
+            subject: subject.clone(),
+            did_hash: did_hash.clone(),
+            bound_at: env.ledger().timestamp(),
         };
+        Storage::set_did_binding(&env, &binding);
+        DidChanged { subject, did_hash }.publish_bound(&env);
 
-        Storage::set_anchor_services(&env, &anchor_services);
-        ServicesConfigured { anchor, services }.publish(&env);
+        Ok(())
+    }
+
+    /// Revoke the calling subject's DID binding.
+    pub fn revoke_did(env: Env, subject: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        subject.require_auth();
+
+        let binding = Storage::get_did_binding(&env, &subject).ok_or(Error::CredentialNotFound)?;
+        Storage::remove_did_binding(&env, &binding);
+        DidChanged {
+            subject,
+            did_hash: binding.did_hash,
+        }
+        .publish_revoked(&env);
 
         Ok(())
     }
 
-    /// Get the list of supported services for an anchor.
-    pub fn get_supported_services(env: Env, anchor: Address) -> Result<Vec<ServiceType>, Error> {
-        let anchor_services = Storage::get_anchor_services(&env, &anchor)?;
-        Ok(anchor_services.services)
+    /// Get the DID binding for a subject, if any.
+    pub fn get_did_binding(env: Env, subject: Address) -> Option<DidBinding> {
+        Storage::get_did_binding(&env, &subject)
     }
 
-    /// Check if an anchor supports a specific service.
-    pub fn supports_service(env: Env, anchor: Address, service: ServiceType) -> bool {
-        if let Ok(anchor_services) = Storage::get_anchor_services(&env, &anchor) {
-            anchor_services.services.contains(&service)
-        } else {
-            false
-        }
+    /// Resolve a DID hash to the subject address it is bound to.
+    pub fn resolve_did(env: Env, did_hash: BytesN<32>) -> Option<Address> {
+        Storage::get_did_subject(&env, &did_hash)
     }
 
-    /// Create a high-level transaction intent and automatically enforce anchor compliance rules.
-    pub fn build_transaction_intent(
+    /// Configure the endpoint an attestor serves for `kind`, replacing (rotating)
+    /// any previous URL of that kind. Callable by the attestor. Endpoints of other
+    /// kinds are unaffected; credential bindings are re-checked.
+    pub fn configure_endpoint(
         env: Env,
-        builder: TransactionIntentBuilder,
-    ) -> Result<TransactionIntent, Error> {
-        Storage::get_admin(&env)?;
+        attestor: Address,
+        kind: EndpointKind,
+        url: String,
+        is_active: bool,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
 
-        if !Storage::is_attestor(&env, &builder.anchor) {
-            return Err(Error::UnauthorizedAttestor);
+        attestor.require_auth();
+
+        if !Storage::is_attestor(&env, &attestor) {
+            return Err(Error::AttestorNotRegistered);
         }
+        Self::validate_endpoint_url(&env, &attestor, &url)?;
 
-        Self::validate_transaction_operation(&builder.request.operation_type)?;
+        Storage::set_endpoint(
+            &env,
+            &Endpoint {
+                url: url.clone(),
+                attestor: attestor.clone(),
+                kind,
+                is_active,
+                updated_at: env.ledger().timestamp(),
+            },
+        );
+        Self::refresh_credential_bindings(&env, &attestor);
 
-        if builder.request.amount == 0 || builder.ttl_seconds == 0 {
-            return Err(Error::InvalidTransactionIntent);
+        EndpointConfigured {
+            attestor,
+            kind,
+            url,
         }
+        .publish(&env);
 
-        let anchor_services = Storage::get_anchor_services(&env, &builder.anchor)?;
-        if !anchor_services
-            .services
-            .contains(&builder.request.operation_type)
-        {
-            return Err(Error::InvalidServiceType);
+        Ok(())
+    }
+
+    /// Activate or deactivate an attestor's endpoint of `kind` without changing
+    /// its URL. Callable by the attestor.
+    pub fn set_endpoint_active(
+        env: Env,
+        attestor: Address,
+        kind: EndpointKind,
+        is_active: bool,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        attestor.require_auth();
+
+        let mut endpoint = Storage::get_endpoint(&env, &attestor, kind)?;
+        if endpoint.is_active == is_active {
+            return Ok(());
         }
+        endpoint.is_active = is_active;
+        Storage::set_endpoint(&env, &endpoint);
 
-        if builder.require_kyc && !anchor_services.services.contains(&ServiceType::KYC) {
-            return Err(Error::ComplianceNotMet);
+        EndpointConfigured {
+            attestor,
+            kind,
+            url: endpoint.url,
         }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Remove an attestor's endpoint of `kind`. Callable by the attestor.
+    /// Credential bindings to a URL no longer served are flagged as orphaned.
+    pub fn remove_endpoint(env: Env, attestor: Address, kind: EndpointKind) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
 
-        if builder.session_id != 0 {
-            Storage::get_session(&env, builder.session_id)?;
+        attestor.require_auth();
+
+        if !Storage::has_endpoint(&env, &attestor, kind) {
+            return Err(Error::EndpointNotFound);
         }
+        Storage::remove_endpoint(&env, &attestor, kind);
+        Storage::remove_endpoint_challenge(&env, &attestor, kind);
+        Self::refresh_credential_bindings(&env, &attestor);
 
-        let now = env.ledger().timestamp();
-        let mut expires_at = now
-            .checked_add(builder.ttl_seconds)
-            .ok_or(Error::InvalidTransactionIntent)?;
+        EndpointRemoved { attestor, kind }.publish(&env);
 
-        let mut has_quote = false;
-        let mut rate = 0u64;
-        let mut fee_percentage = 0u32;
+        Ok(())
+    }
 
-        if builder.quote_id != 0 {
-            let quote = Storage::get_quote(&env, &builder.anchor, builder.quote_id)
-                .ok_or(Error::QuoteNotFound)?;
+    /// Restrict the hosts an attestor may configure as its endpoint. Only callable
+    /// by admin. A host matches a listed domain exactly or as a subdomain of it;
+    /// an empty list lifts the restriction. Does not affect an already configured
+    /// endpoint until it is reconfigured.
+    pub fn set_endpoint_domains(
+        env: Env,
+        attestor: Address,
+        domains: Vec<String>,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
 
-            if quote.valid_until <= now {
-                return Err(Error::StaleQuote);
-            }
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
 
-            if quote.base_asset != builder.request.base_asset
-                || quote.quote_asset != builder.request.quote_asset
-                || builder.request.amount < quote.minimum_amount
-                || builder.request.amount > quote.maximum_amount
-            {
-                return Err(Error::InvalidQuote);
+        if !Storage::is_attestor(&env, &attestor) {
+            return Err(Error::AttestorNotRegistered);
+        }
+        let mut buf = [0u8; config::MAX_ENDPOINT_LEN as usize];
+        for domain in domains.iter() {
+            let len = domain.len();
+            if len == 0 || len > config::MAX_ENDPOINT_LEN {
+                return Err(Error::InvalidConfig);
             }
+            let bytes = &mut buf[..len as usize];
+            domain.copy_into_slice(bytes);
+            let valid = bytes
+                .iter()
+                .all(|b| b.is_ascii_alphanumeric() || *b == b'-' || *b == b'.');
+            if !valid || bytes[0] == b'.' {
+                return Err(Error::InvalidConfig);
+            }
+        }
 
-            has_quote = true;
-            rate = quote.rate;
-            fee_percentage = quote.fee_percentage;
-            if quote.valid_until < expires_at {
-                expires_at = quote.valid_until;
-            }
+        Storage::set_endpoint_domains(&env, &attestor, &domains);
+        Ok(())
+    }
+
+    /// Get the endpoint domain allowlist of an attestor. Empty when unrestricted.
+    pub fn get_endpoint_domains(env: Env, attestor: Address) -> Vec<String> {
+        Storage::get_endpoint_domains(&env, &attestor)
+    }
+
+    /// Get an attestor's endpoint of `kind`.
+    pub fn get_endpoint(env: Env, attestor: Address, kind: EndpointKind) -> Result<Endpoint, Error> {
+        Storage::get_endpoint(&env, &attestor, kind)
+    }
+
+    /// List all endpoints of an attestor, active or not.
+    pub fn list_endpoints(env: Env, attestor: Address) -> Vec<Endpoint> {
+        Storage::get_endpoints(&env, &attestor)
+    }
+
+    /// List the endpoints of up to `MAX_ATTESTORS` attestors in one call, grouped
+    /// by attestor in the order of `attestors`.
+    pub fn get_endpoints_bulk(env: Env, attestors: Vec<Address>) -> Result<Vec<Endpoint>, Error> {
+        if attestors.len() > config::MAX_ATTESTORS {
+            return Err(Error::InvalidConfig);
         }
 
-        let intent_id = Storage::get_next_intent_id(&env);
-        let intent = TransactionIntent {
-            intent_id,
-            anchor: builder.anchor,
-            request: builder.request,
-            quote_id: builder.quote_id,
-            has_quote,
-            rate,
-            fee_percentage,
-            requires_kyc: builder.require_kyc,
-            session_id: builder.session_id,
-            created_at: now,
-            expires_at,
+        let mut results = Vec::new(&env);
+        for attestor in attestors.iter() {
+            results.append(&Storage::get_endpoints(&env, &attestor));
+        }
+        Ok(results)
+    }
+
+    /// Anchor the SHA-256 of the attestor's current stellar.toml. Callable by the
+    /// attestor whenever the published TOML changes; requires at least one endpoint.
+    pub fn set_toml_hash(env: Env, attestor: Address, toml_hash: BytesN<32>) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        attestor.require_auth();
+
+        if Storage::get_endpoint_kinds(&env, &attestor).is_empty() {
+            return Err(Error::EndpointNotFound);
+        }
+        Storage::set_toml_hash(
+            &env,
+            &attestor,
+            &TomlHash {
+                hash: toml_hash.clone(),
+                updated_at: env.ledger().timestamp(),
+            },
+        );
+
+        TomlHashAnchored { attestor, toml_hash }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the stellar.toml hash anchored by an attestor, if any.
+    pub fn get_toml_hash(env: Env, attestor: Address) -> Option<TomlHash> {
+        Storage::get_toml_hash(&env, &attestor)
+    }
+
+    /// Check a locally computed stellar.toml hash against the anchored one.
+    /// Returns false when the TOML is stale/tampered or no hash has been anchored.
+    pub fn verify_toml_hash(
+        env: Env,
+        attestor: Address,
+        toml_hash: BytesN<32>,
+    ) -> Result<bool, Error> {
+        if Storage::get_endpoint_kinds(&env, &attestor).is_empty() {
+            return Err(Error::EndpointNotFound);
+        }
+        Ok(matches!(Storage::get_toml_hash(&env, &attestor), Some(t) if t.hash == toml_hash))
+    }
+
+    /// Configure supported services for an anchor. Callable by the anchor.
+    pub fn configure_services(
+        env: Env,
+        anchor: Address,
+        services: Vec<ServiceType>,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Storage::get_admin(&env)?;
+        anchor.require_auth();
+
+        Self::validate_services(&services)?;
+
+        if !Storage::is_attestor(&env, &anchor) {
+            return Err(Error::AttestorNotRegistered);
+        }
+
+        let anchor_services = AnchorServices {
+            anchor: anchor.clone(),
+            services: services.clone(),
         };
 
-        if intent.session_id != 0 {
-            Self::log_session_operation(
-                &env,
-                intent.session_id,
-                &intent.anchor,
-                "intent",
-                "success",
-                intent.intent_id,
-            )?;
+        Storage::set_anchor_services(&env, &anchor_services);
+        ServicesConfigured { anchor, services }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the list of supported services for an anchor.
+    pub fn get_supported_services(env: Env, anchor: Address) -> Result<Vec<ServiceType>, Error> {
+        let anchor_services = Storage::get_anchor_services(&env, &anchor)?;
+        Ok(anchor_services.services)
+    }
+
+    /// Get the configured services of up to `MAX_ATTESTORS` anchors in one call.
+    /// Results follow the order of `anchors`; unconfigured anchors have no services.
+    pub fn get_services_bulk(
+        env: Env,
+        anchors: Vec<Address>,
+    ) -> Result<Vec<AnchorServices>, Error> {
+        if anchors.len() > config::MAX_ATTESTORS {
+            return Err(Error::InvalidConfig);
         }
 
-        Ok(intent)
+        let mut results = Vec::new(&env);
+        for anchor in anchors.iter() {
+            let services =
+                Storage::get_anchor_services(&env, &anchor).unwrap_or_else(|_| AnchorServices {
+                    anchor,
+                    services: Vec::new(&env),
+                });
+            results.push_back(services);
+        }
+        Ok(results)
+    }
+
+    /// Check if an anchor supports a specific service.
+    pub fn supports_service(env: Env, anchor: Address, service: ServiceType) -> bool {
+        if let Ok(anchor_services) = Storage::get_anchor_services(&env, &anchor) {
+            anchor_services.services.contains(&service)
+        } else {
+            false
+        }
+    }
+
+    /// Narrow `service` to the listed assets for `anchor`, e.g. deposits in USDC
+    /// and EURC only. Quotes and intents for the service are then accepted only
+    /// when their base asset is listed. An empty list lifts the restriction.
+    pub fn set_asset_support(
+        env: Env,
+        anchor: Address,
+        service: ServiceType,
+        assets: Vec<Asset>,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Storage::get_admin(&env)?;
+        anchor.require_auth();
+
+        let services = Storage::get_anchor_services(&env, &anchor)?;
+        if !services.services.contains(service) {
+            return Err(Error::InvalidServiceType);
+        }
+        for (i, asset) in assets.iter().enumerate() {
+            if !asset.is_valid() || assets.first_index_of(&asset) != Some(i as u32) {
+                return Err(Error::InvalidConfig);
+            }
+        }
+
+        let mut support = Storage::get_asset_support(&env, &anchor);
+        if let Some(index) = support.iter().position(|s| s.service_type == service) {
+            support.remove(index as u32);
+        }
+        if !assets.is_empty() {
+            support.push_back(AssetSupport {
+                service_type: service,
+                assets,
+            });
+        }
+        Storage::set_asset_support(&env, &anchor, &support);
+
+        Ok(())
+    }
+
+    /// Get the per-service asset lists `anchor` has declared.
+    pub fn get_asset_support(env: Env, anchor: Address) -> Vec<AssetSupport> {
+        Storage::get_asset_support(&env, &anchor)
+    }
+
+    /// Check if an anchor supports `asset` for `service`. Services the anchor
+    /// hasn't narrowed with `set_asset_support` accept every asset.
+    pub fn supports_asset(env: Env, anchor: Address, service: ServiceType, asset: Asset) -> bool {
+        Storage::get_asset_support(&env, &anchor)
+            .iter()
+            .find(|support| support.service_type == service)
+            .is_none_or(|support| support.assets.contains(&asset))
+    }
+
+    // ============ Roles ============
+
+    /// Grant a scoped role to an account (admin only).
+    pub fn grant_role(env: Env, account: Address, role: Role) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        Rbac::grant(&env, role, &account);
+        Ok(())
+    }
+
+    /// Revoke a scoped role from an account (admin only).
+    pub fn revoke_role(env: Env, account: Address, role: Role) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        Rbac::revoke(&env, role, &account);
+        Ok(())
+    }
+
+    /// Check whether an account holds a role. The admin holds every role.
+    pub fn has_role(env: Env, account: Address, role: Role) -> bool {
+        Rbac::has_role(&env, role, &account)
     }
 
     // ============ Session Management for Reproducibility ============
@@ -397,14 +1048,43 @@ impl AnchorKitContract {
     /// Create a new interaction session for tracing operations.
     /// Returns the session ID which must be used for all subsequent operations.
     pub fn create_session(env: Env, initiator: Address) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         initiator.require_auth();
+        Self::open_session(&env, &initiator)
+    }
 
-        Storage::get_admin(&env)?;
+    fn open_session(env: &Env, initiator: &Address) -> Result<u64, Error> {
+        Storage::get_admin(env)?;
+        RateLimiter::consume(env, initiator, OperationClass::Session)?;
 
-        let session_id = Storage::create_session(&env, &initiator);
+        let session_id = Storage::create_session(env, initiator);
         let timestamp = env.ledger().timestamp();
 
-        SessionCreated::publish(&env, session_id, &initiator, timestamp);
+        SessionCreated::publish(env, session_id, initiator, timestamp);
+
+        Ok(session_id)
+    }
+
+    // ============ Sponsored Operations ============
+    //
+    // The sponsor submits the transaction and pays its fees; the user only signs
+    // an authorization entry. User auth args never include the sponsor, so a
+    // wallet can hand the same signed entry to any sponsor.
+
+    /// Create a session for `initiator`, submitted and paid for by `sponsor`.
+    pub fn sponsored_create_session(
+        env: Env,
+        sponsor: Address,
+        initiator: Address,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        sponsor.require_auth();
+        initiator.require_auth_for_args(().into_val(&env));
+
+        let session_id = Self::open_session(&env, &initiator)?;
+        OperationSponsored::publish(&env, &sponsor, &initiator, symbol_short!("session"));
 
         Ok(session_id)
     }
@@ -419,65 +1099,146 @@ impl AnchorKitContract {
         Storage::get_audit_log(&env, log_id)
     }
 
-    /// Get the total number of operations in a session.
-    pub fn get_session_operation_count(env: Env, session_id: u64) -> Result<u64, Error> {
+    /// Get the audit log of the `operation_index`-th operation in a session.
+    pub fn get_session_audit_log(
+        env: Env,
+        session_id: u64,
+        operation_index: u64,
+    ) -> Result<AuditLog, Error> {
+        let log_id = Storage::get_session_log_id(&env, session_id, operation_index)?;
+        Storage::get_audit_log(&env, log_id)
+    }
+
+    /// Get the head of a session's audit hash chain. It commits to every audit
+    /// log in operation order, so an exported transcript can be checked against it.
+    pub fn get_session_commitment(env: Env, session_id: u64) -> Result<BytesN<32>, Error> {
         Storage::get_session(&env, session_id)?;
-        Ok(Storage::get_session_operation_count(&env, session_id))
+        Ok(Storage::get_session_chain(&env, session_id))
     }
 
-    /// Submit an attestation within a session for full traceability.
-    pub fn submit_attestation_with_session(
+    /// Recompute a session's audit hash chain from its stored logs and compare
+    /// it with `claimed_ops_hash`, the digest an off-chain replay produced with
+    /// the same encoding (`serialization::chain_audit_log`).
+    pub fn verify_replay(
         env: Env,
         session_id: u64,
-        issuer: Address,
-        subject: Address,
-        timestamp: u64,
-        payload_hash: BytesN<32>,
-        signature: Bytes,
-    ) -> Result<u64, Error> {
-        issuer.require_auth();
-
-        if timestamp == 0 {
-            Self::log_session_operation(&env, session_id, &issuer, "attest", "failed", 0)?;
-            return Err(Error::InvalidTimestamp);
+        claimed_ops_hash: BytesN<32>,
+    ) -> Result<ReplayVerification, Error> {
+        Storage::get_session(&env, session_id)?;
+        let operation_count = Storage::get_session_operation_count(&env, session_id);
+
+        let mut digest = BytesN::from_array(&env, &[0; 32]);
+        let mut complete = true;
+        for index in 0..operation_count {
+            let log = Storage::get_session_log_id(&env, session_id, index)
+                .and_then(|log_id| Storage::get_audit_log(&env, log_id));
+            match log {
+                Ok(log) => digest = serialization::chain_audit_log(&env, &digest, &log),
+                Err(_) => complete = false,
+            }
         }
 
-        if !Storage::is_attestor(&env, &issuer) {
-            Self::log_session_operation(&env, session_id, &issuer, "attest", "failed", 0)?;
-            return Err(Error::UnauthorizedAttestor);
-        }
+        let verdict = if !complete {
+            ReplayVerdict::Incomplete
+        } else if digest == claimed_ops_hash {
+            ReplayVerdict::Match
+        } else {
+            ReplayVerdict::Mismatch
+        };
+        Ok(ReplayVerification {
+            session_id,
+            verdict,
+            operation_count,
+            digest,
+        })
+    }
 
-        if Storage::is_hash_used(&env, &payload_hash) {
-            Self::log_session_operation(&env, session_id, &issuer, "attest", "failed", 0)?;
-            return Err(Error::ReplayAttack);
-        }
+    /// Set how session operations are recorded by default. Only callable by
+    /// admin. In `EventsOnly` mode operations emit `OperationLogged` but store
+    /// no `AuditLog` and don't advance the session commitment.
+    pub fn set_audit_mode(env: Env, mode: AuditMode) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
 
-        Self::verify_signature(
-            &env,
-            &issuer,
-            &subject,
-            timestamp,
-            &payload_hash,
-            &signature,
-        )?;
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
 
-        let id = Storage::get_and_increment_counter(&env);
-        let attestation = Attestation {
-            id,
-            issuer: issuer.clone(),
-            subject: subject.clone(),
-            timestamp,
-            payload_hash: payload_hash.clone(),
-            signature,
-        };
+        Storage::set_audit_mode(&env, mode);
+        Ok(())
+    }
 
-        Storage::set_attestation(&env, id, &attestation);
-        Storage::mark_hash_used(&env, &payload_hash);
-        AttestationRecorded::publish(&env, id, &subject, timestamp, payload_hash);
+    pub fn get_audit_mode(env: Env) -> AuditMode {
+        Storage::get_audit_mode(&env)
+    }
 
-        Self::log_session_operation(&env, session_id, &issuer, "attest", "success", id)?;
+    /// Override the audit mode for one session. Only callable by the session's
+    /// initiator. Operations already logged are unaffected.
+    pub fn set_session_audit_mode(env: Env, session_id: u64, mode: AuditMode) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
 
-        Ok(id)
+        let session = Storage::get_session(&env, session_id)?;
+        session.initiator.require_auth();
+
+        Storage::set_session_audit_mode(&env, session_id, mode);
+        Ok(())
+    }
+
+    /// Get the audit mode a session's operations are recorded with.
+    pub fn get_session_audit_mode(env: Env, session_id: u64) -> Result<AuditMode, Error> {
+        Storage::get_session(&env, session_id)?;
+        Ok(Self::effective_audit_mode(&env, session_id))
+    }
+
+    fn effective_audit_mode(env: &Env, session_id: u64) -> AuditMode {
+        Storage::get_session_audit_mode(env, session_id)
+            .unwrap_or_else(|| Storage::get_audit_mode(env))
+    }
+
+    /// Append an audit log entry to a session, e.g. an incident note. Only
+    /// callable by the session's initiator. Audit-log writes stay open while
+    /// the contract is frozen.
+    pub fn append_audit_log(
+        env: Env,
+        session_id: u64,
+        operation_type: String,
+        status: String,
+        correlation_id: Option<BytesN<16>>,
+    ) -> Result<u64, Error> {
+        let session = Storage::get_session(&env, session_id)?;
+        session.initiator.require_auth();
+
+        Self::record_session_operation(
+            &env,
+            session_id,
+            &session.initiator,
+            operation_type,
+            status,
+            0,
+            &correlation_id,
+        )
+    }
+
+    /// Get the total number of operations in a session.
+    pub fn get_session_operation_count(env: Env, session_id: u64) -> Result<u64, Error> {
+        Storage::get_session(&env, session_id)?;
+        Ok(Storage::get_session_operation_count(&env, session_id))
+    }
+
+    /// Get the number of audit logs recorded under a correlation ID, across
+    /// all sessions. Operations in `EventsOnly` sessions aren't counted; their
+    /// `OperationLogged` events carry the ID instead.
+    pub fn get_correlation_operation_count(env: Env, correlation_id: BytesN<16>) -> u64 {
+        Storage::get_correlation_count(&env, &correlation_id)
+    }
+
+    /// Get the `index`-th audit log recorded under a correlation ID, in the
+    /// order the operations ran.
+    pub fn get_correlated_audit_log(
+        env: Env,
+        correlation_id: BytesN<16>,
+        index: u64,
+    ) -> Result<AuditLog, Error> {
+        let log_id = Storage::get_correlated_log_id(&env, &correlation_id, index)?;
+        Storage::get_audit_log(&env, log_id)
     }
 
     /// Register an attestor within a session for full traceability.
@@ -485,19 +1246,38 @@ impl AnchorKitContract {
         env: Env,
         session_id: u64,
         attestor: Address,
+        public_key: BytesN<32>,
+        correlation_id: Option<BytesN<16>>,
     ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
         if Storage::is_attestor(&env, &attestor) {
-            Self::log_session_operation(&env, session_id, &admin, "register", "failed", 0)?;
+            Self::log_session_operation(
+                &env,
+                session_id,
+                &admin,
+                "register",
+                "failed",
+                0,
+                &correlation_id,
+            )?;
             return Err(Error::AttestorAlreadyRegistered);
         }
 
-        Storage::set_attestor(&env, &attestor, true);
-        AttestorAdded::publish(&env, &attestor);
+        Self::add_attestor(&env, &attestor, &public_key)?;
 
-        Self::log_session_operation(&env, session_id, &admin, "register", "success", 0)?;
+        Self::log_session_operation(
+            &env,
+            session_id,
+            &admin,
+            "register",
+            "success",
+            0,
+            &correlation_id,
+        )?;
 
         Ok(())
     }
@@ -507,148 +1287,39 @@ impl AnchorKitContract {
         env: Env,
         session_id: u64,
         attestor: Address,
+        correlation_id: Option<BytesN<16>>,
     ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
-        if !Storage::is_attestor(&env, &attestor) {
-            Self::log_session_operation(&env, session_id, &admin, "revoke", "failed", 0)?;
-            return Err(Error::AttestorNotRegistered);
+        if let Err(error) = Self::remove_attestor(&env, &attestor) {
+            Self::log_session_operation(
+                &env,
+                session_id,
+                &admin,
+                "revoke",
+                "failed",
+                0,
+                &correlation_id,
+            )?;
+            return Err(error);
         }
 
-        Storage::set_attestor(&env, &attestor, false);
-        AttestorRemoved::publish(&env, &attestor);
-
-        Self::log_session_operation(&env, session_id, &admin, "revoke", "success", 0)?;
+        Self::log_session_operation(
+            &env,
+            session_id,
+            &admin,
+            "revoke",
+            "success",
+            0,
+            &correlation_id,
+        )?;
 
         Ok(())
     }
 
-    /// Submit a quote from an anchor. Only callable by registered attestors.
-    pub fn submit_quote(
-        env: Env,
-        anchor: Address,
-        base_asset: String,
-        quote_asset: String,
-        rate: u64,
-        fee_percentage: u32,
-        minimum_amount: u64,
-        maximum_amount: u64,
-        valid_until: u64,
-    ) -> Result<u64, Error> {
-        anchor.require_auth();
-
-        if !Storage::is_attestor(&env, &anchor) {
-            return Err(Error::UnauthorizedAttestor);
-        }
-
-        // Check rate limit if configured
-        if let Some(config) = Storage::get_rate_limit_config(&env, &anchor) {
-            RateLimiter::check_and_update(&env, &anchor, &config)?;
-        }
-
-        if rate == 0 || valid_until <= env.ledger().timestamp() {
-            return Err(Error::InvalidQuote);
-        }
-
-        if let Ok(services) = Storage::get_anchor_services(&env, &anchor) {
-            if !services.services.contains(&ServiceType::Quotes) {
-                return Err(Error::InvalidServiceType);
-            }
-        } else {
-            return Err(Error::ServicesNotConfigured);
-        }
-
-        let quote_id = Storage::get_next_quote_id(&env);
-        let quote = QuoteData {
-            anchor: anchor.clone(),
-            base_asset: base_asset.clone(),
-            quote_asset: quote_asset.clone(),
-            rate,
-            fee_percentage,
-            minimum_amount,
-            maximum_amount,
-            valid_until,
-            quote_id,
-        };
-
-        Storage::set_quote(&env, &quote);
-        Storage::set_latest_quote(&env, &anchor, quote_id);
-
-        QuoteSubmitted::publish(
-            &env,
-            &anchor,
-            quote_id,
-            &base_asset,
-            &quote_asset,
-            rate,
-            valid_until,
-        );
-
-        Ok(quote_id)
-    }
-
-    /// Get a specific quote by anchor and quote ID.
-    pub fn get_quote(env: Env, anchor: Address, quote_id: u64) -> Result<QuoteData, Error> {
-        Storage::get_quote(&env, &anchor, quote_id).ok_or(Error::QuoteNotFound)
-    }
-
-    /// Compare rates for specific anchors and return the best option.
-    pub fn compare_rates_for_anchors(
-        env: Env,
-        request: QuoteRequest,
-        anchors: Vec<Address>,
-    ) -> Result<RateComparison, Error> {
-        let current_timestamp = env.ledger().timestamp();
-        let mut valid_quotes: Vec<QuoteData> = Vec::new(&env);
-
-        for i in 0..anchors.len() {
-            let anchor = anchors.get(i).unwrap();
-            if let Some(quote) = Self::get_latest_quote_for_anchor(&env, &anchor, &request) {
-                if quote.valid_until > current_timestamp
-                    && quote.base_asset == request.base_asset
-                    && quote.quote_asset == request.quote_asset
-                    && request.amount >= quote.minimum_amount
-                    && request.amount <= quote.maximum_amount
-                {
-                    valid_quotes.push_back(quote);
-                }
-            }
-        }
-
-        if valid_quotes.is_empty() {
-            return Err(Error::NoQuotesAvailable);
-        }
-
-        let mut best_quote = match valid_quotes.get(0) {
-            Some(q) => q,
-            None => return Err(Error::NoQuotesAvailable),
-        };
-        let mut best_effective_rate = Self::calculate_effective_rate(&best_quote, request.amount);
-
-        for i in 1..valid_quotes.len() {
-            let quote = match valid_quotes.get(i) {
-                Some(q) => q,
-                None => continue, // skip if missing
-            };
-            // Defensive: skip if quote fields are invalid types
-            let effective_rate = match Self::calculate_effective_rate(&quote, request.amount) {
-                rate => rate,
-                // If calculation fails due to type, skip
-            };
-            if effective_rate < best_effective_rate {
-                best_quote = quote;
-                best_effective_rate = effective_rate;
-            }
-        }
-
-        Ok(RateComparison {
-            best_quote: best_quote.clone(),
-            all_quotes: valid_quotes,
-            comparison_timestamp: current_timestamp,
-        })
-    }
-
     fn validate_services(services: &Vec<ServiceType>) -> Result<(), Error> {
         if services.is_empty() {
             return Err(Error::InvalidServiceType);
@@ -672,13 +1343,6 @@ impl AnchorKitContract {
         Ok(())
     }
 
-    fn validate_transaction_operation(operation_type: &ServiceType) -> Result<(), Error> {
-        match operation_type {
-            ServiceType::Deposits | ServiceType::Withdrawals => Ok(()),
-            _ => Err(Error::InvalidServiceType),
-        }
-    }
-
     fn log_session_operation(
         env: &Env,
         session_id: u64,
@@ -686,8 +1350,30 @@ impl AnchorKitContract {
         operation_type: &str,
         status: &str,
         result_data: u64,
+        correlation_id: &Option<BytesN<16>>,
+    ) -> Result<u64, Error> {
+        Self::record_session_operation(
+            env,
+            session_id,
+            actor,
+            String::from_str(env, operation_type),
+            String::from_str(env, status),
+            result_data,
+            correlation_id,
+        )
+    }
+
+    fn record_session_operation(
+        env: &Env,
+        session_id: u64,
+        actor: &Address,
+        operation_type: String,
+        status: String,
+        result_data: u64,
+        correlation_id: &Option<BytesN<16>>,
     ) -> Result<u64, Error> {
         Storage::get_session(env, session_id)?;
+        Storage::touch_session(env, session_id);
 
         let operation_index = Storage::increment_session_operation_count(env, session_id);
         let timestamp = env.ledger().timestamp();
@@ -695,66 +1381,99 @@ impl AnchorKitContract {
         let operation = OperationContext {
             session_id,
             operation_index,
-            operation_type: String::from_str(env, operation_type),
+            operation_type,
             timestamp,
-            status: String::from_str(env, status),
+            status,
             result_data,
+            correlation_id: correlation_id
+                .clone()
+                .unwrap_or_else(|| BytesN::from_array(env, &[0; 16])),
         };
 
-        let log_id = Storage::log_operation(env, session_id, actor, &operation);
+        let log = match Self::effective_audit_mode(env, session_id) {
+            AuditMode::Full => {
+                let log = Storage::log_operation(env, session_id, actor, &operation);
+                let head = serialization::chain_audit_log(
+                    env,
+                    &Storage::get_session_chain(env, session_id),
+                    &log,
+                );
+                Storage::set_session_chain(env, session_id, &head);
+                log
+            }
+            AuditMode::EventsOnly => Storage::new_audit_log(env, session_id, actor, &operation),
+        };
 
         OperationLogged::publish(
             env,
-            log_id,
+            log.log_id,
             session_id,
             operation_index,
             &operation.operation_type,
             &operation.status,
+            &operation.correlation_id,
         );
 
-        Ok(log_id)
+        Ok(log.log_id)
     }
 
-    fn calculate_effective_rate(quote: &QuoteData, amount: u64) -> u64 {
-        let base_rate = quote.rate;
-        let fee_amount = (amount * quote.fee_percentage as u64) / 10000;
-        let effective_amount = amount + fee_amount;
+    /// Validate an endpoint URL for `attestor`. It must use `https://`, contain
+    /// only printable ASCII, have a host and no userinfo, and fit within
+    /// `MIN_ENDPOINT_LEN..=MAX_ENDPOINT_LEN` bytes. If the admin has set a domain
+    /// allowlist for the attestor, the host must be a listed domain or a
+    /// subdomain of one.
+    fn validate_endpoint_url(env: &Env, attestor: &Address, url: &String) -> Result<(), Error> {
+        const SCHEME: &[u8] = b"https://";
 
-        (base_rate * effective_amount) / amount
-    }
+        let len = url.len();
+        if !(config::MIN_ENDPOINT_LEN..=config::MAX_ENDPOINT_LEN).contains(&len) {
+            return Err(Error::InvalidEndpointFormat);
+        }
 
-    fn get_latest_quote_for_anchor(
-        env: &Env,
-        anchor: &Address,
-        _request: &QuoteRequest,
-    ) -> Option<QuoteData> {
-        let quote_id = Storage::get_latest_quote(env, anchor)?;
-        Storage::get_quote(env, anchor, quote_id)
-    }
+        let mut buf = [0u8; config::MAX_ENDPOINT_LEN as usize];
+        let bytes = &mut buf[..len as usize];
+        url.copy_into_slice(bytes);
 
-    fn validate_endpoint_url(url: &String) -> Result<(), Error> {
-        let len = url.len();
+        if !bytes.iter().all(|b| (0x21..=0x7e).contains(b)) {
+            return Err(Error::InvalidEndpointCharacter);
+        }
+        if !bytes.starts_with(SCHEME) {
+            return Err(Error::InsecureEndpointScheme);
+        }
 
-        if len == 0 || len > 256 {
+        let rest = &bytes[SCHEME.len()..];
+        let authority_len = rest
+            .iter()
+            .position(|b| matches!(b, b'/' | b'?' | b'#'))
+            .unwrap_or(rest.len());
+        let authority = &rest[..authority_len];
+        if authority.contains(&b'@') {
             return Err(Error::InvalidEndpointFormat);
         }
-
-        if len < 8 {
+        let host_len = authority.iter().position(|b| *b == b':').unwrap_or(authority.len());
+        let host = &authority[..host_len];
+        if host.is_empty() {
             return Err(Error::InvalidEndpointFormat);
         }
 
-        Ok(())
-    }
-
-    fn verify_signature(
-        _env: &Env,
-        _issuer: &Address,
-        _subject: &Address,
-        _timestamp: u64,
-        _payload_hash: &BytesN<32>,
-        _signature: &Bytes,
-    ) -> Result<(), Error> {
-        Ok(())
+        let domains = Storage::get_endpoint_domains(env, attestor);
+        if domains.is_empty() {
+            return Ok(());
+        }
+        let mut domain_buf = [0u8; config::MAX_ENDPOINT_LEN as usize];
+        for domain in domains.iter() {
+            let domain_bytes = &mut domain_buf[..domain.len() as usize];
+            domain.copy_into_slice(domain_bytes);
+            let Some(prefix_len) = host.len().checked_sub(domain_bytes.len()) else {
+                continue;
+            };
+            if host[prefix_len..].eq_ignore_ascii_case(domain_bytes)
+                && (prefix_len == 0 || host[prefix_len - 1] == b'.')
+            {
+                return Ok(());
+            }
+        }
+        Err(Error::EndpointDomainNotAllowed)
     }
 
     // ============ Secure Credential Management ============
@@ -767,6 +1486,8 @@ impl AnchorKitContract {
         rotation_interval_seconds: u64,
         require_encryption: bool,
     ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
@@ -799,6 +1520,30 @@ impl AnchorKitContract {
         encrypted_value: Bytes,
         expires_at: u64,
     ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Self::store_labeled_credential(
+            env,
+            attestor,
+            CredentialManager::primary_label(),
+            credential_type,
+            encrypted_value,
+            expires_at,
+        )
+    }
+
+    /// Store an encrypted credential under a label (e.g. "primary", "backup").
+    /// Only callable by admin. Several labels allow blue/green credential rollover.
+    pub fn store_labeled_credential(
+        env: Env,
+        attestor: Address,
+        label: Symbol,
+        credential_type: CredentialType,
+        encrypted_value: Bytes,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
@@ -815,6 +1560,11 @@ impl AnchorKitContract {
             return Err(Error::InsecureCredentialStorage);
         }
 
+        let labels = Storage::get_credential_labels(&env, &attestor);
+        if !labels.contains(&label) && labels.len() >= credentials::MAX_CREDENTIAL_LABELS {
+            return Err(Error::InvalidConfig);
+        }
+
         let credential = SecureCredential {
             attestor: attestor.clone(),
             credential_type,
@@ -824,7 +1574,8 @@ impl AnchorKitContract {
             rotation_required: false,
         };
 
-        Storage::set_secure_credential(&env, &credential);
+        Storage::set_secure_credential(&env, &label, &credential);
+        Self::refresh_credential_bindings(&env, &attestor);
         Ok(())
     }
 
@@ -837,6 +1588,8 @@ impl AnchorKitContract {
         new_encrypted_value: Bytes,
         expires_at: u64,
     ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
@@ -844,25 +1597,58 @@ impl AnchorKitContract {
             return Err(Error::AttestorNotRegistered);
         }
 
-        CredentialManager::validate_credential_format(&credential_type, &new_encrypted_value)?;
-
-        let credential = SecureCredential {
-            attestor: attestor.clone(),
+        Self::replace_credential(
+            &env,
+            &attestor,
+            &CredentialManager::primary_label(),
             credential_type,
-            encrypted_value: new_encrypted_value,
-            created_at: env.ledger().timestamp(),
+            new_encrypted_value,
             expires_at,
-            rotation_required: false,
-        };
+        )
+    }
 
-        Storage::set_secure_credential(&env, &credential);
-        Ok(())
+    /// Rotate a single labeled credential, leaving the other labels untouched.
+    /// Only callable by admin. The label must already exist.
+    pub fn rotate_labeled_credential(
+        env: Env,
+        attestor: Address,
+        label: Symbol,
+        credential_type: CredentialType,
+        new_encrypted_value: Bytes,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        if Storage::get_secure_credential(&env, &attestor, &label).is_none() {
+            return Err(Error::CredentialNotFound);
+        }
+
+        Self::replace_credential(
+            &env,
+            &attestor,
+            &label,
+            credential_type,
+            new_encrypted_value,
+            expires_at,
+        )
     }
 
     /// Check if credential needs rotation based on policy.
     pub fn check_credential_rotation(env: Env, attestor: Address) -> Result<bool, Error> {
-        let credential =
-            Storage::get_secure_credential(&env, &attestor).ok_or(Error::CredentialNotFound)?;
+        Self::check_label_rotation(env, attestor, CredentialManager::primary_label())
+    }
+
+    /// Check if a labeled credential needs rotation based on policy.
+    pub fn check_label_rotation(
+        env: Env,
+        attestor: Address,
+        label: Symbol,
+    ) -> Result<bool, Error> {
+        let credential = Storage::get_secure_credential(&env, &attestor, &label)
+            .ok_or(Error::CredentialNotFound)?;
 
         let policy = Storage::get_credential_policy(&env, &attestor)
             .unwrap_or_else(|| CredentialManager::create_default_policy(attestor.clone()));
@@ -879,6 +1665,8 @@ impl AnchorKitContract {
     /// Revoke credential for an attestor. Only callable by admin.
     /// Removes the credential from storage immediately.
     pub fn revoke_credential(env: Env, attestor: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
@@ -886,120 +1674,401 @@ impl AnchorKitContract {
             return Err(Error::AttestorNotRegistered);
         }
 
-        Storage::remove_secure_credential(&env, &attestor);
+        Storage::remove_secure_credential(&env, &attestor, &CredentialManager::primary_label());
+        Self::refresh_credential_bindings(&env, &attestor);
         Ok(())
     }
-}
 
-#[contractimpl]
-impl AnchorKitContract {
-    // ============ Multi-Anchor Routing ============
+    /// Revoke a single labeled credential. Only callable by admin.
+    pub fn revoke_labeled_credential(env: Env, attestor: Address, label: Symbol) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
 
-    /// Set metadata for an anchor. Only callable by admin or the anchor itself.
-    pub fn set_anchor_metadata(
-        env: Env,
-        anchor: Address,
-        reputation_score: u32,
-        average_settlement_time: u64,
-        liquidity_score: u32,
-        uptime_percentage: u32,
-        total_volume: u64,
-    ) -> Result<(), Error> {
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
-        if !Storage::is_attestor(&env, &anchor) {
+        if Storage::get_secure_credential(&env, &attestor, &label).is_none() {
+            return Err(Error::CredentialNotFound);
+        }
+
+        Storage::remove_secure_credential(&env, &attestor, &label);
+        Self::refresh_credential_bindings(&env, &attestor);
+        Ok(())
+    }
+
+    /// Get the labels of all credentials stored for an attestor.
+    pub fn get_credential_labels(env: Env, attestor: Address) -> Vec<Symbol> {
+        Storage::get_credential_labels(&env, &attestor)
+    }
+
+    /// Grant a delegated operator read access to one labeled credential until
+    /// `expires_at`. Callable by the anchor; other labels stay inaccessible.
+    pub fn grant_credential_access(
+        env: Env,
+        attestor: Address,
+        operator: Address,
+        label: Symbol,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        attestor.require_auth();
+
+        if !Storage::is_attestor(&env, &attestor) {
             return Err(Error::AttestorNotRegistered);
         }
 
-        // Validate scores (0-10000 = 0-100%)
-        if reputation_score > 10000 || liquidity_score > 10000 || uptime_percentage > 10000 {
-            return Err(Error::InvalidAnchorMetadata);
+        if Storage::get_secure_credential(&env, &attestor, &label).is_none() {
+            return Err(Error::CredentialNotFound);
         }
 
-        let metadata = AnchorMetadata {
-            anchor: anchor.clone(),
-            reputation_score,
-            average_settlement_time,
-            liquidity_score,
-            uptime_percentage,
-            total_volume,
-            is_active: true,
+        let now = env.ledger().timestamp();
+        if expires_at <= now {
+            return Err(Error::InvalidTimestamp);
+        }
+
+        let grant = CredentialGrant {
+            attestor: attestor.clone(),
+            operator: operator.clone(),
+            credential_label: label.clone(),
+            granted_at: now,
+            expires_at,
         };
+        Storage::set_credential_grant(&env, &grant);
 
-        Storage::set_anchor_metadata(&env, &metadata);
-        Storage::add_to_anchor_list(&env, &anchor);
+        CredentialAccessChanged {
+            attestor,
+            operator,
+            credential_label: label,
+            expires_at,
+        }
+        .publish_granted(&env);
 
         Ok(())
     }
 
-    /// Get metadata for an anchor.
-    pub fn get_anchor_metadata(env: Env, anchor: Address) -> Result<AnchorMetadata, Error> {
-        Storage::get_anchor_metadata(&env, &anchor).ok_or(Error::AnchorMetadataNotFound)
-    }
-
-    /// Cache anchor metadata with TTL. Only callable by admin.
-    pub fn cache_metadata(
+    /// Cut off an operator's access to a labeled credential. Callable by the anchor.
+    pub fn revoke_credential_access(
         env: Env,
-        anchor: Address,
-        metadata: AnchorMetadata,
-        ttl_seconds: u64,
+        attestor: Address,
+        operator: Address,
+        label: Symbol,
     ) -> Result<(), Error> {
-        let admin = Storage::get_admin(&env)?;
-        admin.require_auth();
+        Freeze::ensure_unfrozen(&env)?;
 
-        MetadataCache::set_metadata(&env, &anchor, &metadata, ttl_seconds);
-        Ok(())
-    }
+        attestor.require_auth();
 
-    /// Get cached metadata for an anchor.
-    pub fn get_cached_metadata(env: Env, anchor: Address) -> Result<AnchorMetadata, Error> {
-        MetadataCache::get_metadata(&env, &anchor)
+        if Storage::get_credential_grant(&env, &attestor, &operator, &label).is_none() {
+            return Err(Error::CredentialNotFound);
+        }
+
+        Storage::remove_credential_grant(&env, &attestor, &operator, &label);
+
+        CredentialAccessChanged {
+            attestor,
+            operator,
+            credential_label: label,
+            expires_at: env.ledger().timestamp(),
+        }
+        .publish_revoked(&env);
+
+        Ok(())
     }
 
-    /// Refresh (invalidate) cached metadata for an anchor. Only callable by admin.
-    pub fn refresh_metadata_cache(env: Env, anchor: Address) -> Result<(), Error> {
+    /// Remove an attestor's expired operator grants. Callable by anyone, since
+    /// expired grants no longer give access. Returns the number removed.
+    pub fn sweep_credential_grants(env: Env, attestor: Address) -> Result<u32, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let now = env.ledger().timestamp();
+        let mut removed = 0;
+        for (operator, label) in Storage::get_credential_grant_list(&env, &attestor).iter() {
+            let Some(grant) = Storage::get_credential_grant(&env, &attestor, &operator, &label)
+            else {
+                continue;
+            };
+            if !grant.is_expired(now) {
+                continue;
+            }
+
+            Storage::remove_credential_grant(&env, &attestor, &operator, &label);
+            CredentialAccessChanged {
+                attestor: attestor.clone(),
+                operator,
+                credential_label: label,
+                expires_at: grant.expires_at,
+            }
+            .publish_revoked(&env);
+            removed += 1;
+        }
+        Ok(removed)
+    }
+
+    /// Check whether an operator currently holds a grant for a labeled credential.
+    pub fn has_credential_access(
+        env: Env,
+        attestor: Address,
+        operator: Address,
+        label: Symbol,
+    ) -> bool {
+        match Storage::get_credential_grant(&env, &attestor, &operator, &label) {
+            Some(grant) => !grant.is_expired(env.ledger().timestamp()),
+            None => false,
+        }
+    }
+
+    /// Read a delegated credential commitment. Callable by a granted operator.
+    pub fn get_delegated_credential(
+        env: Env,
+        attestor: Address,
+        operator: Address,
+        label: Symbol,
+    ) -> Result<SecureCredential, Error> {
+        operator.require_auth();
+
+        let grant = Storage::get_credential_grant(&env, &attestor, &operator, &label)
+            .ok_or(Error::CredentialNotFound)?;
+
+        if grant.is_expired(env.ledger().timestamp()) {
+            return Err(Error::CredentialExpired);
+        }
+
+        Storage::get_secure_credential(&env, &attestor, &label).ok_or(Error::CredentialNotFound)
+    }
+
+    fn replace_credential(
+        env: &Env,
+        attestor: &Address,
+        label: &Symbol,
+        credential_type: CredentialType,
+        new_encrypted_value: Bytes,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        CredentialManager::validate_credential_format(&credential_type, &new_encrypted_value)?;
+
+        let credential = SecureCredential {
+            attestor: attestor.clone(),
+            credential_type,
+            encrypted_value: new_encrypted_value,
+            created_at: env.ledger().timestamp(),
+            expires_at,
+            rotation_required: false,
+        };
+
+        Storage::set_secure_credential(env, label, &credential);
+        Self::refresh_credential_bindings(env, attestor);
+        Ok(())
+    }
+
+    /// Bind an endpoint of an attestor to the labeled credential used to call it.
+    /// Only callable by admin. Both the endpoint and a matching credential must exist.
+    pub fn bind_endpoint_credential(
+        env: Env,
+        attestor: Address,
+        endpoint_url: String,
+        credential_label: Symbol,
+        credential_type: CredentialType,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
-        MetadataCache::invalidate_metadata(&env, &anchor);
+        if !Storage::is_attestor(&env, &attestor) {
+            return Err(Error::AttestorNotRegistered);
+        }
+
+        if !Self::serves_endpoint_url(&env, &attestor, &endpoint_url) {
+            return Err(Error::EndpointNotFound);
+        }
+
+        let credential = Storage::get_secure_credential(&env, &attestor, &credential_label)
+            .ok_or(Error::CredentialNotFound)?;
+        if credential.credential_type != credential_type {
+            return Err(Error::InvalidCredentialFormat);
+        }
+
+        let binding = CredentialBinding {
+            attestor,
+            endpoint_url,
+            credential_label,
+            credential_type,
+            bound_at: env.ledger().timestamp(),
+            orphaned: false,
+            tls_fingerprint: None,
+            previous_fingerprint: None,
+            fingerprint_updated_at: 0,
+        };
+
+        Storage::set_credential_binding(&env, &binding);
         Ok(())
     }
 
-    /// Cache anchor capabilities (TOML) with TTL. Only callable by admin.
-    pub fn cache_capabilities(
+    /// Remove the credential binding for an attestor endpoint. Only callable by admin.
+    pub fn unbind_endpoint_credential(
         env: Env,
-        anchor: Address,
-        toml_url: String,
-        capabilities: String,
-        ttl_seconds: u64,
+        attestor: Address,
+        endpoint_url: String,
     ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
-        MetadataCache::set_capabilities(&env, &anchor, toml_url, capabilities, ttl_seconds);
+        if Storage::get_credential_binding(&env, &attestor, &endpoint_url).is_none() {
+            return Err(Error::CredentialNotFound);
+        }
+
+        Storage::remove_credential_binding(&env, &attestor, &endpoint_url);
         Ok(())
     }
 
-    /// Get cached capabilities for an anchor.
-    pub fn get_cached_capabilities(env: Env, anchor: Address) -> Result<CachedCapabilities, Error> {
-        MetadataCache::get_capabilities(&env, &anchor)
+    /// Get the credential binding for an attestor endpoint.
+    pub fn get_credential_binding(
+        env: Env,
+        attestor: Address,
+        endpoint_url: String,
+    ) -> Result<CredentialBinding, Error> {
+        Storage::get_credential_binding(&env, &attestor, &endpoint_url)
+            .ok_or(Error::CredentialNotFound)
     }
 
-    /// Refresh (invalidate) cached capabilities for an anchor. Only callable by admin.
-    pub fn refresh_capabilities_cache(env: Env, anchor: Address) -> Result<(), Error> {
+    /// Pin or rotate the TLS certificate fingerprint of an mTLS-bound endpoint.
+    /// Callable by the attestor. The previously pinned fingerprint stays valid
+    /// until the next rotation so clients can roll certificates without downtime.
+    pub fn pin_endpoint_fingerprint(
+        env: Env,
+        attestor: Address,
+        endpoint_url: String,
+        fingerprint: BytesN<32>,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        attestor.require_auth();
+
+        let mut binding = Storage::get_credential_binding(&env, &attestor, &endpoint_url)
+            .ok_or(Error::CredentialNotFound)?;
+
+        if binding.credential_type != CredentialType::MutualTLS || binding.orphaned {
+            return Err(Error::InvalidCredentialFormat);
+        }
+
+        let pinned = Bytes::from(fingerprint.clone());
+        if binding.tls_fingerprint.as_ref() == Some(&pinned) {
+            return Ok(());
+        }
+
+        binding.previous_fingerprint = binding.tls_fingerprint.take();
+        binding.tls_fingerprint = Some(pinned);
+        binding.fingerprint_updated_at = env.ledger().timestamp();
+        Storage::set_credential_binding(&env, &binding);
+
+        FingerprintPinned {
+            attestor,
+            endpoint_url,
+            fingerprint,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Drop the previous fingerprint once the rollover window is over.
+    /// Callable by the attestor.
+    pub fn retire_previous_fingerprint(
+        env: Env,
+        attestor: Address,
+        endpoint_url: String,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        attestor.require_auth();
+
+        let mut binding = Storage::get_credential_binding(&env, &attestor, &endpoint_url)
+            .ok_or(Error::CredentialNotFound)?;
+
+        binding.previous_fingerprint = None;
+        Storage::set_credential_binding(&env, &binding);
+        Ok(())
+    }
+
+    /// Check whether a certificate fingerprint matches the one pinned on-chain.
+    pub fn verify_endpoint_fingerprint(
+        env: Env,
+        attestor: Address,
+        endpoint_url: String,
+        fingerprint: BytesN<32>,
+    ) -> bool {
+        match Storage::get_credential_binding(&env, &attestor, &endpoint_url) {
+            Some(binding) => {
+                !binding.orphaned && binding.matches_fingerprint(&Bytes::from(fingerprint))
+            }
+            None => false,
+        }
+    }
+
+    /// Re-check all credential bindings of an attestor against the configured
+    /// endpoint and stored credential. Returns the bindings that are orphaned.
+    pub fn validate_credential_bindings(
+        env: Env,
+        attestor: Address,
+    ) -> Result<Vec<CredentialBinding>, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
-        MetadataCache::invalidate_capabilities(&env, &anchor);
-        Ok(())
+        Ok(Self::refresh_credential_bindings(&env, &attestor))
     }
 
-    /// Get list of all registered anchors.
-    pub fn get_all_anchors(env: Env) -> Vec<Address> {
-        Storage::get_anchor_list(&env)
+    /// Whether any endpoint of `attestor`, of any kind, is configured with `url`.
+    fn serves_endpoint_url(env: &Env, attestor: &Address, url: &String) -> bool {
+        Storage::get_endpoints(env, attestor).iter().any(|e| e.url == *url)
+    }
+
+    /// Flag bindings whose endpoint or credential no longer exists.
+    /// Must be called whenever an attestor's endpoint or credential changes.
+    fn refresh_credential_bindings(env: &Env, attestor: &Address) -> Vec<CredentialBinding> {
+        let endpoints = Storage::get_endpoints(env, attestor);
+        let mut orphaned: Vec<CredentialBinding> = Vec::new(env);
+
+        for url in Storage::get_credential_binding_urls(env, attestor).iter() {
+            let mut binding = match Storage::get_credential_binding(env, attestor, &url) {
+                Some(b) => b,
+                None => continue,
+            };
+
+            let endpoint_ok = endpoints.iter().any(|e| e.url == binding.endpoint_url);
+            let credential =
+                Storage::get_secure_credential(env, attestor, &binding.credential_label);
+            let credential_ok =
+                matches!(&credential, Some(c) if c.credential_type == binding.credential_type);
+
+            if endpoint_ok && credential_ok {
+                if binding.orphaned {
+                    binding.orphaned = false;
+                    Storage::set_credential_binding(env, &binding);
+                }
+                continue;
+            }
+
+            if !binding.orphaned {
+                binding.orphaned = true;
+                Storage::set_credential_binding(env, &binding);
+                CredentialOrphaned {
+                    attestor: attestor.clone(),
+                    endpoint_url: binding.endpoint_url.clone(),
+                }
+                .publish(env);
+            }
+            orphaned.push_back(binding);
+        }
+
+        orphaned
     }
+}
 
+#[contractimpl]
+impl AnchorKitContract {
     // ============ Health Monitoring ============
 
     /// Update health status for an anchor. Only callable by admin or the anchor itself.
@@ -1010,6 +2079,8 @@ impl AnchorKitContract {
         failure_count: u32,
         availability_percent: u32,
     ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         anchor.require_auth();
 
         if !Storage::is_attestor(&env, &anchor) {
@@ -1037,12 +2108,224 @@ impl AnchorKitContract {
         Storage::get_health_status(&env, &anchor)
     }
 
+    // ============ Aliases ============
+
+    /// Register `alias` (e.g. `cowrie_ng`) as the calling anchor's short name,
+    /// replacing any alias it had. Fails with `AlreadyExists` if another
+    /// address holds the alias.
+    pub fn set_alias(env: Env, anchor: Address, alias: Symbol) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        anchor.require_auth();
+
+        if !Storage::is_attestor(&env, &anchor) {
+            return Err(Error::AttestorNotRegistered);
+        }
+        Self::assign_alias(&env, &anchor, &alias)
+    }
+
+    /// Register `alias` for any address, such as a contract that isn't an
+    /// attestor. Only callable by admin; the alias must be free or already
+    /// the address's.
+    pub fn set_alias_for(env: Env, address: Address, alias: Symbol) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        Self::assign_alias(&env, &address, &alias)
+    }
+
+    /// Free `alias` so another address can claim it. Only callable by admin.
+    pub fn release_alias(env: Env, alias: Symbol) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        let address = Storage::get_alias_address(&env, &alias).ok_or(Error::NotFound)?;
+        Storage::remove_alias(&env, &alias, &address);
+        AliasChanged { alias, address }.publish_released(&env);
+        Ok(())
+    }
+
+    /// Address registered under `alias`. Fails with `NotFound` if none is.
+    pub fn resolve_alias(env: Env, alias: Symbol) -> Result<Address, Error> {
+        Storage::get_alias_address(&env, &alias).ok_or(Error::NotFound)
+    }
+
+    /// Alias registered for `address`, if any.
+    pub fn get_alias(env: Env, address: Address) -> Option<Symbol> {
+        Storage::get_address_alias(&env, &address)
+    }
+
+    fn assign_alias(env: &Env, address: &Address, alias: &Symbol) -> Result<(), Error> {
+        match Storage::get_alias_address(env, alias) {
+            Some(holder) if &holder == address => return Ok(()),
+            Some(_) => return Err(Error::AlreadyExists),
+            None => {}
+        }
+        if let Some(previous) = Storage::get_address_alias(env, address) {
+            Storage::remove_alias(env, &previous, address);
+        }
+
+        Storage::set_alias(env, alias, address);
+        AliasChanged {
+            alias: alias.clone(),
+            address: address.clone(),
+        }
+        .publish_set(env);
+        Ok(())
+    }
+
+    // ============ Endpoint Challenges ============
+
+    /// Register the ed25519 key an attestor's endpoints sign challenges with.
+    /// Callable by the attestor.
+    pub fn set_endpoint_signing_key(
+        env: Env,
+        attestor: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        attestor.require_auth();
+
+        if !Storage::is_attestor(&env, &attestor) {
+            return Err(Error::AttestorNotRegistered);
+        }
+        Storage::set_endpoint_signing_key(&env, &attestor, &public_key);
+        Ok(())
+    }
+
+    /// Issue a fresh nonce the anchor must sign to prove its endpoint of `kind`
+    /// is live. Only callable by an endpoint monitor. An earlier challenge that
+    /// expired unanswered is recorded as stale before the new one replaces it.
+    pub fn request_endpoint_challenge(
+        env: Env,
+        monitor: Address,
+        attestor: Address,
+        kind: EndpointKind,
+    ) -> Result<BytesN<32>, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &monitor, Role::EndpointMonitor)?;
+
+        if !Storage::has_endpoint(&env, &attestor, kind) {
+            return Err(Error::EndpointNotFound);
+        }
+        let now = env.ledger().timestamp();
+        if let Some(previous) = Storage::get_endpoint_challenge(&env, &attestor, kind) {
+            if now > previous.expires_at {
+                Self::record_endpoint_check(&env, &previous, false);
+            }
+        }
+
+        let nonce: BytesN<32> = env.prng().gen();
+        let challenge = EndpointChallenge {
+            attestor: attestor.clone(),
+            kind,
+            nonce: nonce.clone(),
+            issued_at: now,
+            expires_at: now + EndpointChallenge::TTL_SECONDS,
+        };
+        Storage::set_endpoint_challenge(&env, &challenge);
+
+        EndpointChallenged {
+            attestor,
+            kind,
+            nonce: nonce.clone(),
+            expires_at: challenge.expires_at,
+        }
+        .publish(&env);
+
+        Ok(nonce)
+    }
+
+    /// Post the anchor's ed25519 signature over the outstanding challenge nonce.
+    /// Only callable by an endpoint monitor. Returns `true` when the endpoint
+    /// answered in time; a late answer marks the endpoint inactive and returns
+    /// `false`. Either way the anchor's health status is updated. An invalid
+    /// signature fails the call.
+    pub fn submit_endpoint_proof(
+        env: Env,
+        monitor: Address,
+        attestor: Address,
+        kind: EndpointKind,
+        signature: BytesN<64>,
+    ) -> Result<bool, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &monitor, Role::EndpointMonitor)?;
+
+        let challenge =
+            Storage::get_endpoint_challenge(&env, &attestor, kind).ok_or(Error::NotFound)?;
+        let public_key =
+            Storage::get_endpoint_signing_key(&env, &attestor).ok_or(Error::CredentialNotFound)?;
+
+        let fresh = env.ledger().timestamp() <= challenge.expires_at;
+        if fresh {
+            let message = Bytes::from(challenge.nonce.clone());
+            env.crypto().ed25519_verify(&public_key, &message, &signature);
+        }
+        Self::record_endpoint_check(&env, &challenge, fresh);
+
+        Ok(fresh)
+    }
+
+    /// Get the outstanding challenge for an attestor's endpoint, if any.
+    pub fn get_endpoint_challenge(
+        env: Env,
+        attestor: Address,
+        kind: EndpointKind,
+    ) -> Option<EndpointChallenge> {
+        Storage::get_endpoint_challenge(&env, &attestor, kind)
+    }
+
+    /// Resolve a challenge and feed the outcome into the anchor's health status.
+    /// A stale challenge deactivates the endpoint and counts as a failure; a
+    /// fresh one resets the failure count and records the response delay.
+    fn record_endpoint_check(env: &Env, challenge: &EndpointChallenge, fresh: bool) {
+        let attestor = &challenge.attestor;
+        let now = env.ledger().timestamp();
+        Storage::remove_endpoint_challenge(env, attestor, challenge.kind);
+
+        let mut health = Storage::get_health_status(env, attestor).unwrap_or(HealthStatus {
+            anchor: attestor.clone(),
+            latency_ms: 0,
+            failure_count: 0,
+            availability_percent: 10000,
+            last_check: 0,
+        });
+        health.last_check = now;
+        if fresh {
+            health.latency_ms = (now - challenge.issued_at) * 1000;
+            health.failure_count = 0;
+        } else {
+            health.failure_count = health.failure_count.saturating_add(1);
+            if let Ok(mut endpoint) = Storage::get_endpoint(env, attestor, challenge.kind) {
+                endpoint.is_active = false;
+                Storage::set_endpoint(env, &endpoint);
+            }
+        }
+        Storage::set_health_status(env, attestor, &health);
+
+        EndpointChecked {
+            attestor: attestor.clone(),
+            kind: challenge.kind,
+            fresh,
+        }
+        .publish(env);
+    }
+
     /// Configure rate limiting for an anchor. Only callable by admin.
     pub fn configure_rate_limit(
         env: Env,
         anchor: Address,
         config: RateLimitConfig,
     ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
@@ -1063,232 +2346,90 @@ impl AnchorKitContract {
         Storage::get_rate_limit_config(&env, &anchor)
     }
 
-    /// Route a transaction request to the best anchor based on strategy.
-    pub fn route_transaction(
+    /// Limit how often any single address may perform `class` operations
+    /// (attestations, quotes, sessions or transfers). Only callable by admin.
+    pub fn set_operation_limit(
         env: Env,
-        routing_request: RoutingRequest,
-    ) -> Result<RoutingResult, Error> {
-        Storage::get_admin(&env)?;
+        class: OperationClass,
+        limit: OperationLimit,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
 
-        let current_timestamp = env.ledger().timestamp();
-        let anchors = Storage::get_anchor_list(&env);
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
 
-        if anchors.is_empty() {
-            return Err(Error::NoAnchorsAvailable);
+        if !limit.is_valid() {
+            return Err(Error::InvalidConfig);
         }
 
-        let mut options: Vec<AnchorOption> = Vec::new(&env);
+        ScheduledParams::settle(&env, Parameter::OperationLimit(class));
+        Storage::set_operation_limit(&env, class, &limit);
+        Ok(())
+    }
 
-        // Collect valid options from all anchors
-        for anchor in anchors.iter() {
-            // Check if anchor is registered and active
-            if !Storage::is_attestor(&env, &anchor) {
-                continue;
-            }
+    /// Lift the limit on `class` operations. Only callable by admin.
+    pub fn remove_operation_limit(env: Env, class: OperationClass) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
 
-            // Get anchor metadata
-            let metadata = match Storage::get_anchor_metadata(&env, &anchor) {
-                Some(m) => m,
-                None => continue,
-            };
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
 
-            if !metadata.is_active {
-                continue;
-            }
+        ScheduledParams::settle(&env, Parameter::OperationLimit(class));
+        Storage::remove_operation_limit(&env, class);
+        Ok(())
+    }
 
-            // Check reputation threshold
-            if metadata.reputation_score < routing_request.min_reputation {
-                continue;
-            }
-
-            // Check if anchor supports the required service
-            let services = match Storage::get_anchor_services(&env, &anchor) {
-                Ok(s) => s,
-                Err(_) => continue,
-            };
-
-            if !services
-                .services
-                .contains(&routing_request.request.operation_type)
-            {
-                continue;
-            }
-
-            // Check KYC requirement
-            if routing_request.require_kyc && !services.services.contains(&ServiceType::KYC) {
-                continue;
-            }
-
-            // Try to get a quote from this anchor
-            if let Some(quote) =
-                Self::get_latest_quote_for_anchor(&env, &anchor, &routing_request.request)
-            {
-                // Validate quote
-                if quote.valid_until > current_timestamp
-                    && quote.base_asset == routing_request.request.base_asset
-                    && quote.quote_asset == routing_request.request.quote_asset
-                    && routing_request.request.amount >= quote.minimum_amount
-                    && routing_request.request.amount <= quote.maximum_amount
-                {
-                    // Calculate score based on strategy
-                    let score = Self::calculate_routing_score(
-                        &routing_request.strategy,
-                        &quote,
-                        &metadata,
-                        routing_request.request.amount,
-                    );
-
-                    options.push_back(AnchorOption {
-                        anchor: anchor.clone(),
-                        quote: quote.clone(),
-                        score,
-                        metadata: metadata.clone(),
-                    });
-                }
-            }
-        }
-
-        if options.is_empty() {
-            return Err(Error::NoQuotesAvailable);
-        }
-
-        // Sort options by score (descending)
-        let mut sorted_options = options.clone();
-        for i in 0..sorted_options.len() {
-            for j in (i + 1)..sorted_options.len() {
-                let score_i = sorted_options.get(i).unwrap().score;
-                let score_j = sorted_options.get(j).unwrap().score;
-                if score_j > score_i {
-                    let temp = sorted_options.get(i).unwrap();
-                    sorted_options.set(i, sorted_options.get(j).unwrap());
-                    sorted_options.set(j, temp);
-                }
-            }
-        }
-
-        // Limit alternatives
-        let max_alternatives = routing_request.max_anchors.min(sorted_options.len());
-        let mut alternatives: Vec<AnchorOption> = Vec::new(&env);
-        for i in 1..max_alternatives {
-            alternatives.push_back(sorted_options.get(i).unwrap());
-        }
-
-        let best = sorted_options.get(0).unwrap();
-
-        Ok(RoutingResult {
-            selected_anchor: best.anchor.clone(),
-            selected_quote: best.quote.clone(),
-            score: best.score,
-            alternatives,
-            routing_timestamp: current_timestamp,
-        })
+    /// Get the limit on `class` operations, if any.
+    pub fn get_operation_limit(env: Env, class: OperationClass) -> Option<OperationLimit> {
+        ScheduledParams::operation_limit(&env, class)
     }
 
-    /// Find best anchor for a specific service and asset pair.
-    pub fn find_best_anchor(
+    // ============ Scheduled Parameter Changes ============
+
+    /// Announce a change to a fee or limit parameter that takes effect on its
+    /// own at `activates_at`, replacing any pending change to the same
+    /// parameter. Only callable by admin. Setting the parameter directly in
+    /// the meantime doesn't cancel the scheduled change.
+    pub fn schedule_parameter_change(
         env: Env,
-        base_asset: String,
-        quote_asset: String,
-        amount: u64,
-        operation_type: ServiceType,
-        strategy: RoutingStrategy,
-    ) -> Result<Address, Error> {
-        let request = QuoteRequest {
-            base_asset,
-            quote_asset,
-            amount,
-            operation_type,
-        };
+        change: ParameterChange,
+        activates_at: u64,
+    ) -> Result<ScheduledChange, Error> {
+        Freeze::ensure_unfrozen(&env)?;
 
-        let routing_request = RoutingRequest {
-            request,
-            strategy,
-            max_anchors: 1,
-            require_kyc: false,
-            min_reputation: 0,
-        };
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
 
-        let result = Self::route_transaction(env, routing_request)?;
-        Ok(result.selected_anchor)
+        let scheduled = ScheduledParams::schedule(&env, change, activates_at)?;
+        ParameterChangeScheduled::publish_scheduled(&env, &scheduled);
+        Ok(scheduled)
     }
 
-    /// Calculate routing score based on strategy.
-    fn calculate_routing_score(
-        strategy: &RoutingStrategy,
-        quote: &QuoteData,
-        metadata: &AnchorMetadata,
-        amount: u64,
-    ) -> u64 {
-        match strategy {
-            RoutingStrategy::BestRate => {
-                // Higher rate is better (inverted for scoring)
-                let effective_rate = Self::calculate_effective_rate(quote, amount);
-                // Invert so lower effective rate = higher score
-                if effective_rate > 0 {
-                    1_000_000_000 / effective_rate
-                } else {
-                    0
-                }
-            }
-            RoutingStrategy::LowestFee => {
-                // Lower fee is better
-                let max_fee = 10000u32; // 100%
-                let fee_score = max_fee.saturating_sub(quote.fee_percentage);
-                fee_score as u64 * 100_000
-            }
-            RoutingStrategy::FastestSettlement => {
-                // Lower settlement time is better
-                let max_time = 86400u64; // 24 hours
-                let time_score = max_time.saturating_sub(metadata.average_settlement_time);
-                time_score * 10_000
-            }
-            RoutingStrategy::HighestLiquidity => {
-                // Higher liquidity is better
-                metadata.liquidity_score as u64 * 100_000
-            }
-            RoutingStrategy::Custom => {
-                // Weighted combination of all factors
-                let rate_score = if quote.rate > 0 {
-                    (1_000_000 / quote.rate) * 30 // 30% weight
-                } else {
-                    0
-                };
-                let fee_score = (10000u32.saturating_sub(quote.fee_percentage) as u64) * 25; // 25% weight
-                let reputation_score = metadata.reputation_score as u64 * 20; // 20% weight
-                let liquidity_score = metadata.liquidity_score as u64 * 15; // 15% weight
-                let uptime_score = metadata.uptime_percentage as u64 * 10; // 10% weight
-
-                rate_score + fee_score + reputation_score + liquidity_score + uptime_score
-            }
-        }
-    }
+    /// Withdraw a scheduled change before it takes effect. Only callable by admin.
+    pub fn cancel_parameter_change(env: Env, parameter: Parameter) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
 
-    /// Deactivate an anchor (admin only).
-    pub fn deactivate_anchor(env: Env, anchor: Address) -> Result<(), Error> {
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
-        let mut metadata =
-            Storage::get_anchor_metadata(&env, &anchor).ok_or(Error::AnchorMetadataNotFound)?;
-
-        metadata.is_active = false;
-        Storage::set_anchor_metadata(&env, &metadata);
-
+        let scheduled = ScheduledParams::cancel(&env, parameter)?;
+        ParameterChangeScheduled::publish_cancelled(&env, &scheduled);
         Ok(())
     }
 
-    /// Reactivate an anchor (admin only).
-    pub fn reactivate_anchor(env: Env, anchor: Address) -> Result<(), Error> {
-        let admin = Storage::get_admin(&env)?;
-        admin.require_auth();
-
-        let mut metadata =
-            Storage::get_anchor_metadata(&env, &anchor).ok_or(Error::AnchorMetadataNotFound)?;
-
-        metadata.is_active = true;
-        Storage::set_anchor_metadata(&env, &metadata);
+    /// The change to `parameter` scheduled to take effect later, if any.
+    pub fn get_scheduled_change(env: Env, parameter: Parameter) -> Option<ScheduledChange> {
+        ScheduledParams::pending(&env, parameter)
+    }
 
-        Ok(())
+    /// Operations `address` may perform in `class` right now, or `None` when
+    /// the class is unlimited.
+    pub fn get_operation_allowance(
+        env: Env,
+        address: Address,
+        class: OperationClass,
+    ) -> Option<u32> {
+        RateLimiter::allowance(&env, &address, class)
     }
 
     // ========== Skeleton Loader Methods ==========
@@ -1306,11 +2447,12 @@ impl AnchorKitContract {
             ));
         }
 
-        // Check if metadata is available
-        match Storage::get_anchor_metadata(&env, &anchor) {
-            Some(_) => Ok(AnchorInfoSkeleton::loaded(anchor)),
-            None => Ok(AnchorInfoSkeleton::loading(anchor)),
+        // Check if metadata is available (tracked by the routing subsystem)
+        #[cfg(feature = "routing")]
+        if Storage::get_anchor_metadata(&env, &anchor).is_none() {
+            return Ok(AnchorInfoSkeleton::loading(anchor));
         }
+        Ok(AnchorInfoSkeleton::loaded(anchor))
     }
 
     /// Get skeleton loader state for transaction status.
@@ -1384,7 +2526,7 @@ impl AnchorKitContract {
         }
 
         // Step 3: Check endpoint configuration
-        let has_endpoint = Storage::get_endpoint(&env, &attestor).is_ok();
+        let has_endpoint = !Storage::get_endpoint_kinds(&env, &attestor).is_empty();
         if has_endpoint {
             steps.push_back(ValidationStep::complete(String::from_str(
                 &env,
@@ -1417,6 +2559,8 @@ impl AnchorKitContract {
         connection_timeout_seconds: u64,
         reuse_connections: bool,
     ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
@@ -1443,6 +2587,8 @@ impl AnchorKitContract {
 
     /// Reset connection pool statistics.
     pub fn reset_pool_stats(env: Env) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
@@ -1463,113 +2609,11 @@ impl AnchorKitContract {
         RequestId::generate(&env)
     }
 
-    /// Submit attestation with request ID for tracing.
-    pub fn submit_with_request_id(
-        env: Env,
-        request_id: RequestId,
-        issuer: Address,
-        subject: Address,
-        timestamp: u64,
-        payload_hash: BytesN<32>,
-        signature: Bytes,
-    ) -> Result<u64, Error> {
-        issuer.require_auth();
-
-        let started_at = env.ledger().timestamp();
-        let result = Self::submit_attestation_internal(&env, &issuer, &subject, timestamp, &payload_hash, &signature);
-        let completed_at = env.ledger().timestamp();
-
-        let status = if result.is_ok() { String::from_str(&env, "success") } else { String::from_str(&env, "failed") };
-        let span = TracingSpan {
-            request_id: request_id.clone(),
-            operation: String::from_str(&env, "submit_attestation"),
-            actor: issuer.clone(),
-            started_at,
-            completed_at,
-            status,
-        };
-        RequestTracker::store_span(&env, &span);
-
-        result
-    }
-
-    /// Submit quote with request ID for tracing.
-    pub fn quote_with_request_id(
-        env: Env,
-        request_id: RequestId,
-        anchor: Address,
-        base_asset: String,
-        quote_asset: String,
-        rate: u64,
-        fee_percentage: u32,
-        minimum_amount: u64,
-        maximum_amount: u64,
-        valid_until: u64,
-    ) -> Result<u64, Error> {
-        anchor.require_auth();
-
-        let started_at = env.ledger().timestamp();
-        let result = Self::submit_quote(env.clone(), anchor.clone(), base_asset, quote_asset, rate, fee_percentage, minimum_amount, maximum_amount, valid_until);
-        let completed_at = env.ledger().timestamp();
-
-        let status = if result.is_ok() { String::from_str(&env, "success") } else { String::from_str(&env, "failed") };
-        let span = TracingSpan {
-            request_id: request_id.clone(),
-            operation: String::from_str(&env, "submit_quote"),
-            actor: anchor.clone(),
-            started_at,
-            completed_at,
-            status,
-        };
-        RequestTracker::store_span(&env, &span);
-
-        result
-    }
-
     /// Get tracing span by request ID.
     pub fn get_tracing_span(env: Env, request_id: BytesN<16>) -> Option<TracingSpan> {
         RequestTracker::get_span(&env, &request_id)
     }
-
-    fn submit_attestation_internal(
-        env: &Env,
-        issuer: &Address,
-        subject: &Address,
-        timestamp: u64,
-        payload_hash: &BytesN<32>,
-        signature: &Bytes,
-    ) -> Result<u64, Error> {
-        if timestamp == 0 {
-            return Err(Error::InvalidTimestamp);
-        }
-
-        if !Storage::is_attestor(env, issuer) {
-            return Err(Error::UnauthorizedAttestor);
-        }
-
-        if Storage::is_hash_used(env, payload_hash) {
-            return Err(Error::ReplayAttack);
-        }
-
-        Self::verify_signature(env, issuer, subject, timestamp, payload_hash, signature)?;
-
-        let id = Storage::get_and_increment_counter(env);
-        let attestation = Attestation {
-            id,
-            issuer: issuer.clone(),
-            subject: subject.clone(),
-            timestamp,
-            payload_hash: payload_hash.clone(),
-            signature: signature.clone(),
-        };
-
-        Storage::set_attestation(env, id, &attestation);
-        Storage::mark_hash_used(env, payload_hash);
-        AttestationRecorded::publish(env, id, subject, timestamp, payload_hash.clone());
-
-        Ok(id)
-    }
-
+}
 
 #[contractimpl]
 impl AnchorKitContract {
@@ -1591,29 +2635,22 @@ impl AnchorKitContract {
         RequestHistory::get_call(&env, call_id)
     }
 
-    /// Submit attestation with automatic request history tracking
-    pub fn submit_attestation_tracked(
+    /// Register attestor with automatic request history tracking
+    pub fn register_attestor_tracked(
         env: Env,
-        issuer: Address,
-        subject: Address,
-        timestamp: u64,
-        payload_hash: BytesN<32>,
-        signature: Bytes,
-    ) -> Result<u64, Error> {
-        issuer.require_auth();
+        attestor: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
 
         let request_id = RequestId::generate(&env);
         let call_id = RequestHistory::get_next_call_id(&env);
         let started_at = env.ledger().timestamp();
 
-        let result = Self::submit_attestation_internal(
-            &env,
-            &issuer,
-            &subject,
-            timestamp,
-            &payload_hash,
-            &signature,
-        );
+        let result = Self::add_attestor(&env, &attestor, &public_key);
 
         let completed_at = env.ledger().timestamp();
         let duration_ms = (completed_at.saturating_sub(started_at)) * 1000;
@@ -1627,8 +2664,8 @@ impl AnchorKitContract {
             &env,
             call_id,
             request_id.id.clone(),
-            String::from_str(&env, "submit_attestation"),
-            issuer.clone(),
+            String::from_str(&env, "register_attestor"),
+            admin.clone(),
             status,
             duration_ms,
         );
@@ -1640,47 +2677,211 @@ impl AnchorKitContract {
         RequestHistory::record_call(&env, &record);
 
         // Store detailed information
-        if let Ok(attestation_id) = &result {
-            let details = ApiCallDetails {
-                record: record.clone(),
-                target_address: Some(subject.clone()),
-                amount: None,
-                result_data: None, // Store ID in amount field instead
-            };
-            RequestHistory::store_call_details(&env, &details);
+        let details = ApiCallDetails {
+            record: record.clone(),
+            target_address: Some(attestor.clone()),
+            amount: None,
+            result_data: None,
+        };
+        RequestHistory::store_call_details(&env, &details);
+
+        result
+    }
+
+    /// Helper function to convert Error to error code
+    fn error_to_code(error: &Error) -> u32 {
+        *error as u32
+    }
+}
+
+#[cfg(feature = "attestations")]
+#[contractimpl]
+impl AnchorKitContract {
+    // ============ Attestations ============
+
+    /// Submit an attestation within a session for full traceability.
+    /// `issuer` is a registered attestor or one of its delegates; a delegate's
+    /// attestation is recorded under its attestor, signed with the delegate's key
+    /// over the attestor's address.
+    pub fn submit_attestation_with_session(
+        env: Env,
+        session_id: u64,
+        issuer: Address,
+        subject: Address,
+        timestamp: u64,
+        payload_hash: BytesN<32>,
+        signature: Bytes,
+        correlation_id: Option<BytesN<16>>,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Self::require_attestation_auth(&env, &issuer, &subject, timestamp, &payload_hash);
+        let attestor = Self::attesting_for(&env, &issuer);
+
+        if timestamp == 0 {
+            Self::log_session_operation(
+                &env,
+                session_id,
+                &issuer,
+                "attest",
+                "failed",
+                0,
+                &correlation_id,
+            )?;
+            return Err(Error::InvalidTimestamp);
+        }
+
+        if !Storage::is_attestor(&env, &attestor) {
+            Self::log_session_operation(
+                &env,
+                session_id,
+                &issuer,
+                "attest",
+                "failed",
+                0,
+                &correlation_id,
+            )?;
+            return Err(Error::UnauthorizedAttestor);
+        }
+
+        if let Err(err) = RateLimiter::consume(&env, &attestor, OperationClass::Attestation) {
+            Self::log_session_operation(
+                &env,
+                session_id,
+                &issuer,
+                "attest",
+                "failed",
+                0,
+                &correlation_id,
+            )?;
+            return Err(err);
+        }
+
+        #[cfg(feature = "compliance")]
+        if let Err(err) = Compliance::ensure_not_blocked(&env, &subject) {
+            Self::log_session_operation(
+                &env,
+                session_id,
+                &issuer,
+                "attest",
+                "failed",
+                0,
+                &correlation_id,
+            )?;
+            return Err(err);
+        }
+
+        if Self::is_replayed_hash(&env, &attestor, &payload_hash) {
+            Self::log_session_operation(
+                &env,
+                session_id,
+                &issuer,
+                "attest",
+                "failed",
+                0,
+                &correlation_id,
+            )?;
+            return Err(Error::ReplayAttack);
         }
 
+        Self::verify_signature(
+            &env,
+            &issuer,
+            &attestor,
+            &subject,
+            timestamp,
+            &payload_hash,
+            &signature,
+        )?;
+
+        let id = Storage::get_and_increment_counter(&env);
+        let attestation = Attestation {
+            id,
+            issuer: attestor.clone(),
+            subject: subject.clone(),
+            timestamp,
+            payload_hash: payload_hash.clone(),
+            signature,
+            expires_at: 0,
+        };
+
+        Storage::set_attestation(&env, id, &attestation);
+        Self::link_attestation_did(&env, id, &subject);
+        Self::mark_hash_attested(&env, &attestor, &payload_hash);
+        AttestationRecorded::publish(&env, id, &subject, timestamp, payload_hash);
+
+        Self::log_session_operation(
+            &env,
+            session_id,
+            &issuer,
+            "attest",
+            "success",
+            id,
+            &correlation_id,
+        )?;
+
+        Ok(id)
+    }
+
+    /// Submit attestation with request ID for tracing. The span is written
+    /// with the call's other state, so a failed submission leaves none.
+    pub fn submit_with_request_id(
+        env: Env,
+        request_id: RequestId,
+        issuer: Address,
+        subject: Address,
+        timestamp: u64,
+        payload_hash: BytesN<32>,
+        signature: Bytes,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Self::require_attestation_auth(&env, &issuer, &subject, timestamp, &payload_hash);
+
+        let started_at = env.ledger().timestamp();
+        let result = Self::submit_attestation_internal(&env, &issuer, &issuer, &subject, timestamp, &payload_hash, &signature);
+        let completed_at = env.ledger().timestamp();
+
+        let status = if result.is_ok() { String::from_str(&env, "success") } else { String::from_str(&env, "failed") };
+        let span = TracingSpan {
+            request_id: request_id.clone(),
+            operation: String::from_str(&env, "submit_attestation"),
+            actor: issuer.clone(),
+            started_at,
+            completed_at,
+            status,
+        };
+        RequestTracker::store_span(&env, &span);
+
         result
     }
 
-    /// Submit quote with automatic request history tracking
-    pub fn submit_quote_tracked(
+    /// Submit attestation with automatic request history tracking. A failed
+    /// call is rolled back along with its history entry.
+    pub fn submit_attestation_tracked(
         env: Env,
-        anchor: Address,
-        base_asset: String,
-        quote_asset: String,
-        rate: u64,
-        fee_percentage: u32,
-        minimum_amount: u64,
-        maximum_amount: u64,
-        valid_until: u64,
+        issuer: Address,
+        subject: Address,
+        timestamp: u64,
+        payload_hash: BytesN<32>,
+        signature: Bytes,
     ) -> Result<u64, Error> {
-        anchor.require_auth();
+        Freeze::ensure_unfrozen(&env)?;
+
+        Self::require_attestation_auth(&env, &issuer, &subject, timestamp, &payload_hash);
 
         let request_id = RequestId::generate(&env);
         let call_id = RequestHistory::get_next_call_id(&env);
         let started_at = env.ledger().timestamp();
 
-        let result = Self::submit_quote(
-            env.clone(),
-            anchor.clone(),
-            base_asset.clone(),
-            quote_asset.clone(),
-            rate,
-            fee_percentage,
-            minimum_amount,
-            maximum_amount,
-            valid_until,
+        let result = Self::submit_attestation_internal(
+            &env,
+            &issuer,
+            &issuer,
+            &subject,
+            timestamp,
+            &payload_hash,
+            &signature,
         );
 
         let completed_at = env.ledger().timestamp();
@@ -1695,8 +2896,8 @@ impl AnchorKitContract {
             &env,
             call_id,
             request_id.id.clone(),
-            String::from_str(&env, "submit_quote"),
-            anchor.clone(),
+            String::from_str(&env, "submit_attestation"),
+            issuer.clone(),
             status,
             duration_ms,
         );
@@ -1708,12 +2909,12 @@ impl AnchorKitContract {
         RequestHistory::record_call(&env, &record);
 
         // Store detailed information
-        if let Ok(quote_id) = &result {
+        if let Ok(attestation_id) = &result {
             let details = ApiCallDetails {
                 record: record.clone(),
-                target_address: Some(anchor.clone()),
-                amount: Some(rate),
-                result_data: None, // Store quote_id in amount field if needed
+                target_address: Some(subject.clone()),
+                amount: None,
+                result_data: None, // Store ID in amount field instead
             };
             RequestHistory::store_call_details(&env, &details);
         }
@@ -1721,90 +2922,3545 @@ impl AnchorKitContract {
         result
     }
 
-    /// Register attestor with automatic request history tracking
-    pub fn register_attestor_tracked(
+    /// Get a recorded attestation by ID, for `reader`. Fails with
+    /// `AttestationNotFound` if no attestation has that ID or it was erased,
+    /// and with `Unauthorized` unless `can_read_attestation` allows `reader`.
+    /// Use `attestation_exists` to only check that it is there.
+    ///
+    /// This gates the contract interface only: the ledger entries themselves
+    /// stay public.
+    pub fn get_attestation(
         env: Env,
-        attestor: Address,
-    ) -> Result<(), Error> {
-        let admin = Storage::get_admin(&env)?;
-        admin.require_auth();
+        reader: Address,
+        attestation_id: u64,
+    ) -> Result<Attestation, Error> {
+        reader.require_auth();
+
+        let attestation = Storage::get_attestation(&env, attestation_id)?;
+        if !Self::may_read_attestation(&env, &reader, &attestation) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(attestation)
+    }
 
-        let request_id = RequestId::generate(&env);
-        let call_id = RequestHistory::get_next_call_id(&env);
-        let started_at = env.ledger().timestamp();
+    /// Check whether an attestation with `attestation_id` exists, without
+    /// revealing its details.
+    pub fn attestation_exists(env: Env, attestation_id: u64) -> bool {
+        Storage::get_attestation(&env, attestation_id).is_ok()
+    }
 
-        let result = Self::register_attestor(env.clone(), attestor.clone());
+    /// Whether `reader` may see an attestation's details: `reader` is the
+    /// subject, the issuer, the admin, a compliance officer or holds an
+    /// unexpired grant from the subject.
+    pub fn can_read_attestation(
+        env: Env,
+        reader: Address,
+        attestation_id: u64,
+    ) -> Result<bool, Error> {
+        let attestation = Storage::get_attestation(&env, attestation_id)?;
+        Ok(Self::may_read_attestation(&env, &reader, &attestation))
+    }
 
-        let completed_at = env.ledger().timestamp();
-        let duration_ms = (completed_at.saturating_sub(started_at)) * 1000;
+    fn may_read_attestation(env: &Env, reader: &Address, attestation: &Attestation) -> bool {
+        *reader == attestation.subject
+            || *reader == attestation.issuer
+            || Storage::get_admin(env).is_ok_and(|admin| admin == *reader)
+            || Rbac::has_role(env, Role::ComplianceOfficer, reader)
+            || Storage::get_attestation_grant(env, &attestation.subject, reader)
+                .is_some_and(|grant| !grant.is_expired(env.ledger().timestamp()))
+    }
 
-        let (status, error_code) = match &result {
-            Ok(_) => (ApiCallStatus::Success, None),
-            Err(e) => (ApiCallStatus::Failed, Some(Self::error_to_code(e))),
-        };
+    /// Let `grantee` read the details of `subject`'s attestations through
+    /// `get_attestation` until `expires_at`. Callable by the subject; a new
+    /// grant replaces the old one.
+    pub fn grant_attestation_access(
+        env: Env,
+        subject: Address,
+        grantee: Address,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
 
-        let mut record = ApiCallRecord::new(
+        subject.require_auth();
+
+        let now = env.ledger().timestamp();
+        if expires_at <= now {
+            return Err(Error::InvalidTimestamp);
+        }
+
+        Storage::set_attestation_grant(
             &env,
-            call_id,
-            request_id.id.clone(),
-            String::from_str(&env, "register_attestor"),
-            admin.clone(),
-            status,
-            duration_ms,
+            &AttestationGrant {
+                subject: subject.clone(),
+                grantee: grantee.clone(),
+                granted_at: now,
+                expires_at,
+            },
         );
 
-        if let Some(code) = error_code {
-            record = record.with_error(code);
+        AttestationAccessChanged {
+            subject,
+            grantee,
+            expires_at,
         }
+        .publish_granted(&env);
 
-        RequestHistory::record_call(&env, &record);
+        Ok(())
+    }
 
-        // Store detailed information
-        let details = ApiCallDetails {
-            record: record.clone(),
-            target_address: Some(attestor.clone()),
-            amount: None,
-            result_data: None,
-        };
-        RequestHistory::store_call_details(&env, &details);
+    /// Cut off a grantee's access to the subject's attestations. Callable by
+    /// the subject.
+    pub fn revoke_attestation_access(
+        env: Env,
+        subject: Address,
+        grantee: Address,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
 
-        result
+        subject.require_auth();
+
+        if Storage::get_attestation_grant(&env, &subject, &grantee).is_none() {
+            return Err(Error::NotFound);
+        }
+
+        Storage::remove_attestation_grant(&env, &subject, &grantee);
+
+        AttestationAccessChanged {
+            subject,
+            grantee,
+            expires_at: env.ledger().timestamp(),
+        }
+        .publish_revoked(&env);
+
+        Ok(())
     }
 
-    /// Helper function to convert Error to error code
-    fn error_to_code(error: &Error) -> u32 {
-        match error {
-            Error::AlreadyInitialized => 1,
-            Error::NotInitialized => 2,
-            Error::UnauthorizedAttestor => 3,
-            Error::AttestorAlreadyRegistered => 4,
-            Error::AttestorNotRegistered => 5,
-            Error::ReplayAttack => 6,
-            Error::InvalidTimestamp => 7,
-            Error::AttestationNotFound => 8,
-            Error::InvalidEndpointFormat => 9,
-            Error::EndpointNotFound => 10,
-            Error::ServicesNotConfigured => 11,
-            Error::InvalidServiceType => 12,
-            Error::SessionNotFound => 13,
-            Error::InvalidSessionId => 14,
-            Error::InvalidQuote => 15,
-            Error::StaleQuote => 16,
-            Error::NoQuotesAvailable => 17,
-            Error::QuoteNotFound => 18,
-            Error::InvalidTransactionIntent => 19,
-            Error::ComplianceNotMet => 20,
-            Error::InvalidConfig => 21,
-            Error::InvalidCredentialFormat => 22,
-            Error::CredentialNotFound => 23,
-            Error::InsecureCredentialStorage => 24,
-            Error::CredentialExpired => 25,
-            Error::InvalidAnchorMetadata => 26,
-            Error::AnchorMetadataNotFound => 27,
-            Error::NoAnchorsAvailable => 28,
-            Error::RateLimitExceeded => 29,
-            Error::AssetNotConfigured => 30,
-            Error::UnsupportedAsset => 31,
+    /// Remove a subject's expired attestation grants. Callable by anyone, since
+    /// expired grants no longer give access. Returns the number removed.
+    pub fn sweep_attestation_grants(env: Env, subject: Address) -> Result<u32, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let now = env.ledger().timestamp();
+        let mut removed = 0;
+        for grantee in Storage::get_attestation_grantees(&env, &subject).iter() {
+            let Some(grant) = Storage::get_attestation_grant(&env, &subject, &grantee) else {
+                continue;
+            };
+            if !grant.is_expired(now) {
+                continue;
+            }
+
+            Storage::remove_attestation_grant(&env, &subject, &grantee);
+            AttestationAccessChanged {
+                subject: subject.clone(),
+                grantee,
+                expires_at: grant.expires_at,
+            }
+            .publish_revoked(&env);
+            removed += 1;
+        }
+        Ok(removed)
+    }
+
+    /// Check whether `grantee` currently holds a grant for `subject`'s attestations.
+    pub fn has_attestation_access(env: Env, subject: Address, grantee: Address) -> bool {
+        match Storage::get_attestation_grant(&env, &subject, &grantee) {
+            Some(grant) => !grant.is_expired(env.ledger().timestamp()),
+            None => false,
+        }
+    }
+
+    /// Export attestations with IDs from `cursor` for up to `limit` IDs, for
+    /// migrating state to a replacement contract or an archive. Only callable
+    /// by admin. Erased attestations are skipped; pass `next_cursor` to continue.
+    pub fn export_attestations(env: Env, cursor: u64, limit: u32) -> Result<ExportPage, Error> {
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+        if limit == 0 || limit > ExportPage::MAX_LIMIT {
+            return Err(Error::InvalidConfig);
         }
+
+        let last = Storage::get_counter(&env);
+        let end = cursor.saturating_add(limit as u64).min(last).max(cursor);
+        let mut attestations: Vec<Attestation> = Vec::new(&env);
+        for id in cursor..end {
+            if let Ok(attestation) = Storage::get_attestation(&env, id) {
+                attestations.push_back(attestation);
+            }
+        }
+
+        Ok(ExportPage {
+            count: attestations.len(),
+            data: attestations.to_xdr(&env),
+            next_cursor: end,
+            done: end >= last,
+        })
+    }
+
+    /// Check whether an attestation with `payload_hash` was ever recorded. Stays
+    /// true after erasure, since the hash can never be submitted again.
+    pub fn is_attestation_recorded(env: Env, payload_hash: BytesN<32>) -> bool {
+        Storage::is_hash_used(&env, &payload_hash)
+    }
+
+    /// Check whether an attestation exists and is neither revoked by its
+    /// issuer, superseded, nor past its `expires_at`.
+    pub fn is_attestation_valid(env: Env, attestation_id: u64) -> bool {
+        Storage::get_attestation(&env, attestation_id).is_ok_and(|attestation| {
+            !attestation.is_expired(env.ledger().timestamp())
+                && !Storage::is_attestation_revoked(&env, attestation_id)
+                && Storage::get_superseded_by(&env, attestation_id).is_none()
+        })
+    }
+
+    /// Set when an attestation stops being valid, e.g. the end of a KYC
+    /// check's validity window. 0 removes the expiry. Only the attestation's
+    /// issuer can set it, and it must fall after the attestation's timestamp.
+    pub fn set_attestation_expiry(
+        env: Env,
+        issuer: Address,
+        attestation_id: u64,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        issuer.require_auth();
+
+        let mut attestation = Storage::get_attestation(&env, attestation_id)?;
+        if attestation.issuer != issuer {
+            return Err(Error::UnauthorizedAttestor);
+        }
+        if expires_at != 0 && expires_at <= attestation.timestamp {
+            return Err(Error::InvalidTimestamp);
+        }
+
+        attestation.expires_at = expires_at;
+        Storage::set_attestation(&env, attestation_id, &attestation);
+        AttestationValidityChanged::publish(&env, attestation_id, expires_at, false);
+        Ok(())
+    }
+
+    /// Revoke an attestation, e.g. when the claim it records no longer holds.
+    /// The record stays readable but is no longer valid. Only the
+    /// attestation's issuer can revoke it, once.
+    pub fn revoke_attestation(env: Env, issuer: Address, attestation_id: u64) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        issuer.require_auth();
+
+        let attestation = Storage::get_attestation(&env, attestation_id)?;
+        if attestation.issuer != issuer {
+            return Err(Error::UnauthorizedAttestor);
+        }
+        if Storage::is_attestation_revoked(&env, attestation_id) {
+            return Err(Error::AlreadyExists);
+        }
+
+        Storage::mark_attestation_revoked(&env, attestation_id);
+        AttestationValidityChanged::publish(&env, attestation_id, attestation.expires_at, true);
+        Ok(())
+    }
+
+    /// Replace attestation `old_id` with a new attestation about the same
+    /// subject, e.g. when a KYC check is redone. The new attestation is
+    /// signed and checked like one from `submit_attestation`, and the old one
+    /// stops being valid. Returns the new attestation's ID.
+    ///
+    /// `timestamp` is when the new attestation was made. It must be non-zero
+    /// and is covered by `signature` along with the subject and
+    /// `new_payload_hash`. `issuer` is the old attestation's attestor or one
+    /// of its delegates, signing with its own key; either way the new
+    /// attestation is recorded under the attestor. An attestation can only be
+    /// superseded once.
+    pub fn supersede_attestation(
+        env: Env,
+        issuer: Address,
+        old_id: u64,
+        timestamp: u64,
+        new_payload_hash: BytesN<32>,
+        signature: Bytes,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let old = Storage::get_attestation(&env, old_id)?;
+        Self::require_attestation_auth(&env, &issuer, &old.subject, timestamp, &new_payload_hash);
+        let attestor = Self::attesting_for(&env, &issuer);
+
+        if old.issuer != attestor {
+            return Err(Error::UnauthorizedAttestor);
+        }
+        if Storage::get_superseded_by(&env, old_id).is_some() {
+            return Err(Error::AlreadyExists);
+        }
+
+        let new_id = Self::submit_attestation_internal(
+            &env,
+            &issuer,
+            &attestor,
+            &old.subject,
+            timestamp,
+            &new_payload_hash,
+            &signature,
+        )?;
+        Storage::set_supersession(&env, old_id, new_id);
+        #[cfg(feature = "compliance")]
+        if let Some(kind) = Storage::get_attestation_kind(&env, old_id) {
+            Storage::set_attestation_kind(&env, &Storage::get_attestation(&env, new_id)?, &kind);
+        }
+        AttestationSuperseded::publish(&env, old_id, new_id);
+        Ok(new_id)
+    }
+
+    /// The attestation that replaced `attestation_id`, if it was superseded.
+    pub fn get_superseded_by(env: Env, attestation_id: u64) -> Option<u64> {
+        Storage::get_superseded_by(&env, attestation_id)
+    }
+
+    /// The attestation `attestation_id` replaced, if any.
+    pub fn get_supersedes(env: Env, attestation_id: u64) -> Option<u64> {
+        Storage::get_supersedes(&env, attestation_id)
+    }
+
+    /// Get `subject`'s most recent attestation for `reader`, following
+    /// supersession links to the end of its chain. Fails with
+    /// `AttestationNotFound` if the subject has none left, and like
+    /// `get_attestation` if `reader` may not read it.
+    /// (`get_latest_attestation_for_subject` would be over the 32-character
+    /// limit on contract function names.)
+    pub fn get_latest_subject_attestation(
+        env: Env,
+        reader: Address,
+        subject: Address,
+    ) -> Result<Attestation, Error> {
+        let ids = Storage::get_subject_attestation_ids(&env, &subject);
+        let mut id = ids
+            .iter()
+            .rev()
+            .find(|id| Storage::get_attestation(&env, *id).is_ok())
+            .ok_or(Error::AttestationNotFound)?;
+        while let Some(next) = Storage::get_superseded_by(&env, id) {
+            id = next;
+        }
+        Self::get_attestation(env, reader, id)
+    }
+
+    /// Let `delegate`, an operational address, submit attestations on the
+    /// attestor's behalf through `submit_attestation_with_session` and
+    /// supersede them with `supersede_attestation`, signing them with
+    /// `public_key` over the attestor's address. Only callable by the attestor,
+    /// so its own key can stay cold. An address can be a delegate of one
+    /// attestor at a time and can't itself be an attestor.
+    pub fn add_delegate(
+        env: Env,
+        attestor: Address,
+        delegate: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        attestor.require_auth();
+
+        if !Storage::is_attestor(&env, &attestor) {
+            return Err(Error::AttestorNotRegistered);
+        }
+        let delegated = Storage::get_delegate(&env, &delegate).is_some();
+        if delegated || Storage::is_attestor(&env, &delegate) {
+            return Err(Error::AlreadyExists);
+        }
+        if Storage::get_delegates(&env, &attestor).len() >= config::MAX_DELEGATES {
+            return Err(Error::InvalidConfig);
+        }
+
+        Storage::add_delegate(
+            &env,
+            &AttestorDelegate {
+                attestor: attestor.clone(),
+                delegate: delegate.clone(),
+                public_key,
+                added_at: env.ledger().timestamp(),
+            },
+        );
+        DelegateChanged::publish(&env, &attestor, &delegate, true);
+        Ok(())
+    }
+
+    /// Withdraw a delegate's authority to attest for the attestor. Attestations
+    /// it already submitted stay valid.
+    pub fn remove_delegate(env: Env, attestor: Address, delegate: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        attestor.require_auth();
+
+        let record = Storage::get_delegate(&env, &delegate)
+            .filter(|record| record.attestor == attestor)
+            .ok_or(Error::NotFound)?;
+
+        Storage::remove_delegate(&env, &record);
+        DelegateChanged::publish(&env, &attestor, &delegate, false);
+        Ok(())
+    }
+
+    /// The delegates currently allowed to attest for `attestor`.
+    pub fn get_delegates(env: Env, attestor: Address) -> Vec<Address> {
+        Storage::get_delegates(&env, &attestor)
+    }
+
+    pub fn get_delegate(env: Env, delegate: Address) -> Option<AttestorDelegate> {
+        Storage::get_delegate(&env, &delegate)
+    }
+
+    /// Set whether a `payload_hash` may be attested once overall (the default)
+    /// or once per issuer. Only callable by admin.
+    pub fn set_replay_scope(env: Env, scope: ReplayScope) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        Storage::set_replay_scope(&env, scope);
+        Ok(())
+    }
+
+    pub fn get_replay_scope(env: Env) -> ReplayScope {
+        Storage::get_replay_scope(&env)
+    }
+
+    /// Canonical domain-separated `payload_hash` for an attestation payload,
+    /// the value issuers sign and submit.
+    pub fn hash_attestation_payload(env: Env, payload: Bytes) -> BytesN<32> {
+        serialization::attestation_payload_hash(&env, &payload)
+    }
+
+    /// Get the DID the subject was bound to when an attestation was recorded.
+    pub fn get_attestation_did(env: Env, attestation_id: u64) -> Option<BytesN<32>> {
+        Storage::get_attestation_did(&env, attestation_id)
+    }
+
+    /// Issuer authorization shared by the attestation entrypoints. Only the
+    /// attested content is covered, not the detached signature bytes, so custom
+    /// account contracts can build the payload before signing.
+    fn require_attestation_auth(
+        env: &Env,
+        issuer: &Address,
+        subject: &Address,
+        timestamp: u64,
+        payload_hash: &BytesN<32>,
+    ) {
+        issuer.require_auth_for_args((subject.clone(), timestamp, payload_hash.clone()).into_val(env));
+    }
+
+    /// The attestor `issuer` submits for: the attestor it is a delegate of,
+    /// or otherwise `issuer` itself.
+    fn attesting_for(env: &Env, issuer: &Address) -> Address {
+        Storage::get_delegate(env, issuer)
+            .map(|delegate| delegate.attestor)
+            .unwrap_or_else(|| issuer.clone())
+    }
+
+    /// Whether `payload_hash` was already attested within the configured
+    /// replay scope.
+    fn is_replayed_hash(env: &Env, issuer: &Address, payload_hash: &BytesN<32>) -> bool {
+        match Storage::get_replay_scope(env) {
+            ReplayScope::Global => Storage::is_hash_used(env, payload_hash),
+            ReplayScope::Issuer => Storage::is_issuer_hash_used(env, issuer, payload_hash),
+        }
+    }
+
+    /// Mark `payload_hash` under both scopes, so switching scope later never
+    /// reopens a hash that was already accepted.
+    fn mark_hash_attested(env: &Env, issuer: &Address, payload_hash: &BytesN<32>) {
+        Storage::mark_hash_used(env, payload_hash);
+        Storage::mark_issuer_hash_used(env, issuer, payload_hash);
+    }
+
+    /// Record the subject's current DID (if any) against a new attestation.
+    /// The link is a snapshot, so revoking the DID later does not rewrite history.
+    fn link_attestation_did(env: &Env, id: u64, subject: &Address) {
+        if let Some(binding) = Storage::get_did_binding(env, subject) {
+            Storage::set_attestation_did(env, id, &binding.did_hash);
+        }
+    }
+
+    /// Record an attestation signed by `issuer` under `attestor`, which is
+    /// `issuer` itself or the attestor it is a delegate of.
+    fn submit_attestation_internal(
+        env: &Env,
+        issuer: &Address,
+        attestor: &Address,
+        subject: &Address,
+        timestamp: u64,
+        payload_hash: &BytesN<32>,
+        signature: &Bytes,
+    ) -> Result<u64, Error> {
+        if timestamp == 0 {
+            return Err(Error::InvalidTimestamp);
+        }
+
+        if !Storage::is_attestor(env, attestor) {
+            return Err(Error::UnauthorizedAttestor);
+        }
+
+        RateLimiter::consume(env, attestor, OperationClass::Attestation)?;
+
+        #[cfg(feature = "compliance")]
+        Compliance::ensure_not_blocked(env, subject)?;
+
+        if Self::is_replayed_hash(env, attestor, payload_hash) {
+            return Err(Error::ReplayAttack);
+        }
+
+        Self::verify_signature(
+            env,
+            issuer,
+            attestor,
+            subject,
+            timestamp,
+            payload_hash,
+            signature,
+        )?;
+
+        let id = Storage::get_and_increment_counter(env);
+        let attestation = Attestation {
+            id,
+            issuer: attestor.clone(),
+            subject: subject.clone(),
+            timestamp,
+            payload_hash: payload_hash.clone(),
+            signature: signature.clone(),
+            expires_at: 0,
+        };
+
+        Storage::set_attestation(env, id, &attestation);
+        Self::link_attestation_did(env, id, subject);
+        Self::mark_hash_attested(env, attestor, payload_hash);
+        AttestationRecorded::publish(env, id, subject, timestamp, payload_hash.clone());
+
+        Ok(id)
+    }
+
+    /// Check `signature` is the issuer's ed25519 signature over the canonical
+    /// attestation digest, using the key registered with the attestor or
+    /// delegate. The digest names `attestor`, the issuer the attestation is
+    /// recorded under, so a delegate's signature verifies against the stored
+    /// record. A signature that doesn't verify traps the host, failing the call.
+    fn verify_signature(
+        env: &Env,
+        issuer: &Address,
+        attestor: &Address,
+        subject: &Address,
+        timestamp: u64,
+        payload_hash: &BytesN<32>,
+        signature: &Bytes,
+    ) -> Result<(), Error> {
+        let public_key = Storage::get_attestor_key(env, issuer)
+            .or_else(|| Storage::get_delegate(env, issuer).map(|delegate| delegate.public_key))
+            .ok_or(Error::UnauthorizedAttestor)?;
+        let signature =
+            BytesN::<64>::try_from(signature).map_err(|_| Error::InvalidCredentialFormat)?;
+        let digest = serialization::attestation_signing_digest(
+            env,
+            attestor,
+            subject,
+            timestamp,
+            payload_hash,
+        );
+        env.crypto()
+            .ed25519_verify(&public_key, &digest.into(), &signature);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "quotes")]
+#[contractimpl]
+impl AnchorKitContract {
+    // ============ Quotes ============
+
+    /// Submit a quote from an anchor. Only callable by registered attestors.
+    /// The quote is recorded with SEP-6 context and no delivery/country restrictions.
+    pub fn submit_quote(
+        env: Env,
+        anchor: Address,
+        base_asset: Asset,
+        quote_asset: Asset,
+        rate: u64,
+        fee_percentage: u32,
+        minimum_amount: u64,
+        maximum_amount: u64,
+        valid_until: u64,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Self::submit_sep38_quote(
+            env,
+            anchor,
+            base_asset,
+            quote_asset,
+            rate,
+            fee_percentage,
+            minimum_amount,
+            maximum_amount,
+            valid_until,
+            Sep38Terms::sep6(),
+        )
+    }
+
+    /// Submit a quote carrying SEP-38 terms (context, delivery methods, country),
+    /// so it maps one-to-one to the anchor's SEP-38 `/quote` response.
+    pub fn submit_sep38_quote(
+        env: Env,
+        anchor: Address,
+        base_asset: Asset,
+        quote_asset: Asset,
+        rate: u64,
+        fee_percentage: u32,
+        minimum_amount: u64,
+        maximum_amount: u64,
+        valid_until: u64,
+        terms: Sep38Terms,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Self::submit_quote_v2(
+            env,
+            QuoteSubmission {
+                anchor,
+                base_asset,
+                quote_asset,
+                rate,
+                fee_percentage,
+                minimum_amount,
+                maximum_amount,
+                valid_until,
+                context: terms.context,
+                sell_delivery_method: terms.sell_delivery_method,
+                buy_delivery_method: terms.buy_delivery_method,
+                country_code: terms.country_code,
+            },
+        )
+    }
+
+    /// Submit a quote as a single struct. Every quote entrypoint funnels into
+    /// this one, so validation lives in one place.
+    pub fn submit_quote_v2(env: Env, submission: QuoteSubmission) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let anchor = submission.anchor.clone();
+
+        // Explicit args give wallets (including custom account contracts) a stable
+        // payload: the quote terms, identical for every quote entrypoint.
+        anchor.require_auth_for_args(
+            (
+                submission.base_asset.clone(),
+                submission.quote_asset.clone(),
+                submission.rate,
+                submission.fee_percentage,
+                submission.minimum_amount,
+                submission.maximum_amount,
+                submission.valid_until,
+                submission.terms(),
+            )
+                .into_val(&env),
+        );
+
+        if !Storage::is_attestor(&env, &anchor) {
+            return Err(Error::UnauthorizedAttestor);
+        }
+        if Storage::get_offboarding(&env, &anchor).is_some() {
+            return Err(Error::AnchorOffboarding);
+        }
+
+        // Check rate limit if configured
+        if let Some(config) = Storage::get_rate_limit_config(&env, &anchor) {
+            RateLimiter::check_and_update(&env, &anchor, &config)?;
+        }
+        RateLimiter::consume(&env, &anchor, OperationClass::Quote)?;
+
+        if submission.rate == 0 || submission.valid_until <= env.ledger().timestamp() {
+            return Err(Error::InvalidQuote);
+        }
+
+        if submission.minimum_amount > submission.maximum_amount {
+            return Err(Error::InvalidQuote);
+        }
+
+        if !submission.base_asset.is_valid()
+            || !submission.quote_asset.is_valid()
+            || submission.base_asset == submission.quote_asset
+        {
+            return Err(Error::InvalidQuote);
+        }
+
+        if let Ok(services) = Storage::get_anchor_services(&env, &anchor) {
+            if !services.services.contains(&ServiceType::Quotes) {
+                return Err(Error::InvalidServiceType);
+            }
+        } else {
+            return Err(Error::ServicesNotConfigured);
+        }
+        if !Self::supports_asset(
+            env.clone(),
+            anchor.clone(),
+            ServiceType::Quotes,
+            submission.base_asset.clone(),
+        ) {
+            return Err(Error::UnsupportedAsset);
+        }
+
+        // Quote-deviation guard against the pair's oracle, if one is registered
+        PriceOracle::check_deviation(
+            &env,
+            &submission.base_asset,
+            &submission.quote_asset,
+            submission.rate,
+        )?;
+
+        let quote_id = Storage::get_next_quote_id(&env);
+        let sequence = Storage::get_latest_quote(
+            &env,
+            &anchor,
+            &submission.base_asset,
+            &submission.quote_asset,
+        )
+        .and_then(|head| Storage::get_quote(&env, &anchor, head))
+        .map_or(1, |head| head.sequence + 1);
+        let quote = submission.into_quote(quote_id, sequence);
+
+        Storage::set_quote(&env, &quote);
+        Storage::set_anchor_latest_quote(&env, &anchor, quote_id);
+        Storage::set_latest_quote(&env, &quote);
+        OrderBook::insert(&env, &quote);
+
+        QuoteSubmitted::publish(
+            &env,
+            &anchor,
+            quote_id,
+            &quote.base_asset,
+            &quote.quote_asset,
+            quote.rate,
+            quote.valid_until,
+        );
+
+        Ok(quote_id)
+    }
+
+    /// Submit quote with automatic request history tracking
+    pub fn submit_quote_tracked(
+        env: Env,
+        anchor: Address,
+        base_asset: Asset,
+        quote_asset: Asset,
+        rate: u64,
+        fee_percentage: u32,
+        minimum_amount: u64,
+        maximum_amount: u64,
+        valid_until: u64,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        // Authorization is checked once, inside submit_quote
+
+        let request_id = RequestId::generate(&env);
+        let call_id = RequestHistory::get_next_call_id(&env);
+        let started_at = env.ledger().timestamp();
+
+        let result = Self::submit_quote(
+            env.clone(),
+            anchor.clone(),
+            base_asset.clone(),
+            quote_asset.clone(),
+            rate,
+            fee_percentage,
+            minimum_amount,
+            maximum_amount,
+            valid_until,
+        );
+
+        let completed_at = env.ledger().timestamp();
+        let duration_ms = (completed_at.saturating_sub(started_at)) * 1000;
+
+        let (status, error_code) = match &result {
+            Ok(_) => (ApiCallStatus::Success, None),
+            Err(e) => (ApiCallStatus::Failed, Some(Self::error_to_code(e))),
+        };
+
+        let mut record = ApiCallRecord::new(
+            &env,
+            call_id,
+            request_id.id.clone(),
+            String::from_str(&env, "submit_quote"),
+            anchor.clone(),
+            status,
+            duration_ms,
+        );
+
+        if let Some(code) = error_code {
+            record = record.with_error(code);
+        }
+
+        RequestHistory::record_call(&env, &record);
+
+        // Store detailed information
+        if let Ok(quote_id) = &result {
+            let details = ApiCallDetails {
+                record: record.clone(),
+                target_address: Some(anchor.clone()),
+                amount: Some(rate),
+                result_data: None, // Store quote_id in amount field if needed
+            };
+            RequestHistory::store_call_details(&env, &details);
+        }
+
+        result
+    }
+
+    /// Submit quote with request ID for tracing.
+    pub fn quote_with_request_id(
+        env: Env,
+        request_id: RequestId,
+        anchor: Address,
+        base_asset: Asset,
+        quote_asset: Asset,
+        rate: u64,
+        fee_percentage: u32,
+        minimum_amount: u64,
+        maximum_amount: u64,
+        valid_until: u64,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        // Authorization is checked once, inside submit_quote
+
+        let started_at = env.ledger().timestamp();
+        let result = Self::submit_quote(env.clone(), anchor.clone(), base_asset, quote_asset, rate, fee_percentage, minimum_amount, maximum_amount, valid_until);
+        let completed_at = env.ledger().timestamp();
+
+        let status = if result.is_ok() { String::from_str(&env, "success") } else { String::from_str(&env, "failed") };
+        let span = TracingSpan {
+            request_id: request_id.clone(),
+            operation: String::from_str(&env, "submit_quote"),
+            actor: anchor.clone(),
+            started_at,
+            completed_at,
+            status,
+        };
+        RequestTracker::store_span(&env, &span);
+
+        result
+    }
+
+    /// Get a quote by anchor and quote ID.
+    ///
+    /// With `QuoteQueryOptions::read_only()` this is a plain read. With
+    /// `QuoteQueryOptions::notify(receiver)` the receiver must authorize
+    /// `(anchor, quote_id)` and a `QuoteReceived` event is emitted.
+    pub fn query_quote(
+        env: Env,
+        anchor: Address,
+        quote_id: u64,
+        options: QuoteQueryOptions,
+    ) -> Result<QuoteData, Error> {
+        match options.notify_receiver {
+            Some(receiver) => {
+                Freeze::ensure_unfrozen(&env)?;
+                receiver.require_auth_for_args((anchor.clone(), quote_id).into_val(&env));
+                Self::ack_quote(&env, &receiver, &anchor, quote_id)
+            }
+            None => Storage::get_quote(&env, &anchor, quote_id).ok_or(Error::QuoteNotFound),
+        }
+    }
+
+    /// Acknowledge receipt of a quote, emitting `QuoteReceived` without
+    /// returning the quote. The receiver authorizes `(anchor, quote_id)`.
+    pub fn ack_quote_received(
+        env: Env,
+        receiver: Address,
+        anchor: Address,
+        quote_id: u64,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        receiver.require_auth_for_args((anchor.clone(), quote_id).into_val(&env));
+        Self::ack_quote(&env, &receiver, &anchor, quote_id)?;
+        Ok(())
+    }
+
+    /// Acknowledge a quote for `receiver`, submitted by `sponsor`.
+    pub fn sponsored_ack_quote_received(
+        env: Env,
+        sponsor: Address,
+        receiver: Address,
+        anchor: Address,
+        quote_id: u64,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        sponsor.require_auth();
+        receiver.require_auth_for_args((anchor.clone(), quote_id).into_val(&env));
+
+        Self::ack_quote(&env, &receiver, &anchor, quote_id)?;
+        OperationSponsored::publish(&env, &sponsor, &receiver, symbol_short!("quote"));
+
+        Ok(())
+    }
+
+    fn ack_quote(
+        env: &Env,
+        receiver: &Address,
+        anchor: &Address,
+        quote_id: u64,
+    ) -> Result<QuoteData, Error> {
+        let quote = Storage::get_quote(env, anchor, quote_id).ok_or(Error::QuoteNotFound)?;
+        Storage::touch_quote(env, &quote);
+
+        // Emit the event
+        QuoteReceived::publish(env, quote_id, receiver, env.ledger().timestamp());
+
+        Ok(quote)
+    }
+
+    /// Export `anchor`'s quotes with IDs from `cursor` for up to `limit` IDs.
+    /// Quote IDs are shared by all anchors, so a page may hold fewer quotes
+    /// than IDs covered. Only callable by admin; pass `next_cursor` to continue.
+    pub fn export_quotes(
+        env: Env,
+        anchor: Address,
+        cursor: u64,
+        limit: u32,
+    ) -> Result<ExportPage, Error> {
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+        if limit == 0 || limit > ExportPage::MAX_LIMIT {
+            return Err(Error::InvalidConfig);
+        }
+
+        // Quote IDs start at 1
+        let cursor = cursor.max(1);
+        let last = Storage::get_quote_count(&env);
+        let end = cursor.saturating_add(limit as u64).min(last + 1).max(cursor);
+        let mut quotes: Vec<QuoteData> = Vec::new(&env);
+        for quote_id in cursor..end {
+            if let Some(quote) = Storage::get_quote(&env, &anchor, quote_id) {
+                quotes.push_back(quote);
+            }
+        }
+
+        Ok(ExportPage {
+            count: quotes.len(),
+            data: quotes.to_xdr(&env),
+            next_cursor: end,
+            done: end > last,
+        })
+    }
+
+    /// Up to `depth` live quotes for an asset pair across anchors, lowest rate
+    /// first, one per anchor. At most `OrderBook::CAPACITY` quotes are kept.
+    pub fn get_order_book(
+        env: Env,
+        base_asset: Asset,
+        quote_asset: Asset,
+        depth: u32,
+    ) -> Vec<QuoteData> {
+        OrderBook::depth(&env, &base_asset, &quote_asset, depth)
+    }
+
+    /// Compare rates for specific anchors and return the best option.
+    pub fn compare_rates_for_anchors(
+        env: Env,
+        request: QuoteRequest,
+        anchors: Vec<Address>,
+    ) -> Result<RateComparison, Error> {
+        Self::compare_rates(&env, &request, &anchors, false)
+    }
+
+    /// Like `compare_rates_for_anchors`, also listing the anchors whose quotes
+    /// were left out and why, so an anchor can audit why it wasn't selected.
+    pub fn compare_rates_with_exclusions(
+        env: Env,
+        request: QuoteRequest,
+        anchors: Vec<Address>,
+    ) -> Result<RateComparison, Error> {
+        Self::compare_rates(&env, &request, &anchors, true)
+    }
+
+    fn compare_rates(
+        env: &Env,
+        request: &QuoteRequest,
+        anchors: &Vec<Address>,
+        record_exclusions: bool,
+    ) -> Result<RateComparison, Error> {
+        let current_timestamp = env.ledger().timestamp();
+        let mut valid_quotes: Vec<QuoteData> = Vec::new(env);
+        let mut exclusions = Vec::new(env);
+
+        for anchor in anchors.iter() {
+            match Self::comparable_quote(env, &anchor, request) {
+                Ok(quote) => valid_quotes.push_back(quote),
+                Err(exclusion) if record_exclusions => exclusions.push_back(exclusion),
+                Err(_) => {}
+            }
+        }
+
+        if valid_quotes.is_empty() {
+            return Err(Error::NoQuotesAvailable);
+        }
+
+        let mut best_quote = match valid_quotes.get(0) {
+            Some(q) => q,
+            None => return Err(Error::NoQuotesAvailable),
+        };
+        // Quotes without an effective rate were excluded by `comparable_quote`
+        let effective_rate = |quote: &QuoteData| {
+            Self::calculate_effective_rate(quote, quote.sell_amount_for(env, request))
+                .unwrap_or(u64::MAX)
+        };
+        let mut best_effective_rate = effective_rate(&best_quote);
+
+        for i in 1..valid_quotes.len() {
+            let quote = match valid_quotes.get(i) {
+                Some(q) => q,
+                None => continue, // skip if missing
+            };
+            let effective_rate = effective_rate(&quote);
+            if effective_rate < best_effective_rate {
+                best_quote = quote;
+                best_effective_rate = effective_rate;
+            }
+        }
+
+        Ok(RateComparison {
+            best_quote: best_quote.clone(),
+            all_quotes: valid_quotes,
+            comparison_timestamp: current_timestamp,
+            exclusions,
+        })
+    }
+
+    /// `anchor`'s latest quote if it is live and fits `request`.
+    fn comparable_quote(
+        env: &Env,
+        anchor: &Address,
+        request: &QuoteRequest,
+    ) -> Result<QuoteData, QuoteExclusion> {
+        let exclusion = |quote_id, reason| QuoteExclusion {
+            anchor: anchor.clone(),
+            quote_id,
+            reason,
+        };
+        let quote = Self::get_latest_quote_for_anchor(env, anchor, request)
+            .ok_or_else(|| exclusion(0, ExclusionReason::NoQuote))?;
+        if quote.valid_until <= env.ledger().timestamp() {
+            return Err(exclusion(quote.quote_id, ExclusionReason::Expired));
+        }
+        if !quote.matches_request(env, request)
+            || Self::calculate_effective_rate(&quote, quote.sell_amount_for(env, request)).is_none()
+        {
+            return Err(exclusion(quote.quote_id, ExclusionReason::OutOfBounds));
+        }
+        Ok(quote)
+    }
+
+    /// Rate including the quote's fee on `amount`, or `None` for a zero
+    /// amount or one too large to price.
+    fn calculate_effective_rate(quote: &QuoteData, amount: u64) -> Option<u64> {
+        if amount == 0 {
+            return None;
+        }
+        let base_rate = quote.rate;
+        let fee_amount = amount.checked_mul(quote.fee_percentage as u64)? / 10000;
+        let effective_amount = amount.checked_add(fee_amount)?;
+
+        Some(base_rate.checked_mul(effective_amount)? / amount)
+    }
+
+    /// `anchor`'s most recent quote for the request's asset pair. A newer
+    /// quote for another pair doesn't hide it.
+    fn get_latest_quote_for_anchor(
+        env: &Env,
+        anchor: &Address,
+        request: &QuoteRequest,
+    ) -> Option<QuoteData> {
+        let quote_id =
+            Storage::get_latest_quote(env, anchor, &request.base_asset, &request.quote_asset)?;
+        Storage::get_quote(env, anchor, quote_id)
+    }
+
+    /// Register the number of decimals `asset` amounts are expressed in.
+    /// Unregistered assets use `DEFAULT_DECIMALS`. Only callable by admin.
+    pub fn set_asset_decimals(env: Env, asset: Asset, decimals: u32) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        if decimals > MAX_DECIMALS {
+            return Err(Error::InvalidConfig);
+        }
+
+        Storage::set_asset_decimals(&env, &asset, decimals);
+        Ok(())
+    }
+
+    /// Get the decimals used for `asset` amounts.
+    pub fn get_asset_decimals(env: Env, asset: Asset) -> u32 {
+        Amount::decimals_of(&env, &asset)
+    }
+
+    // ============ Corridors ============
+
+    /// Register a corridor (asset pair and country pair) with its limits,
+    /// compliance rules and settlement SLA, so intents and routes reference it
+    /// by ID instead of each anchor repeating the configuration. Only callable
+    /// by admin. Returns the assigned corridor ID.
+    pub fn register_corridor(env: Env, corridor: Corridor) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        if !corridor.is_valid() {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut corridor = corridor;
+        corridor.corridor_id = Storage::get_next_corridor_id(&env);
+        Storage::set_corridor(&env, &corridor);
+        Ok(corridor.corridor_id)
+    }
+
+    /// Change a corridor's limits, rules, SLA or active flag. Its assets and
+    /// countries are fixed once registered. Only callable by admin.
+    pub fn update_corridor(env: Env, corridor: Corridor) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        let existing = Storage::get_corridor(&env, corridor.corridor_id)?;
+        if !corridor.is_valid()
+            || corridor.base_asset != existing.base_asset
+            || corridor.quote_asset != existing.quote_asset
+            || corridor.source_country != existing.source_country
+            || corridor.destination_country != existing.destination_country
+        {
+            return Err(Error::InvalidConfig);
+        }
+
+        Storage::set_corridor(&env, &corridor);
+        Ok(())
+    }
+
+    pub fn get_corridor(env: Env, corridor_id: u64) -> Result<Corridor, Error> {
+        Storage::get_corridor(&env, corridor_id)
+    }
+
+    /// Every registered corridor, in registration order.
+    pub fn list_corridors(env: Env) -> Vec<Corridor> {
+        let mut corridors = Vec::new(&env);
+        for corridor_id in 1..=Storage::get_corridor_count(&env) {
+            if let Ok(corridor) = Storage::get_corridor(&env, corridor_id) {
+                corridors.push_back(corridor);
+            }
+        }
+        corridors
+    }
+
+    // ============ Price Oracles ============
+
+    /// Register the price oracle for an asset pair (admin only). Quotes for the
+    /// pair are then rejected if they deviate from the oracle by more than
+    /// `feed.max_deviation_bps`.
+    pub fn set_oracle_feed(
+        env: Env,
+        base_asset: Asset,
+        quote_asset: Asset,
+        feed: OracleFeed,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        if feed.max_deviation_bps == 0 || feed.max_deviation_bps > 10_000 {
+            return Err(Error::InvalidConfig);
+        }
+
+        Storage::set_oracle_feed(&env, &base_asset, &quote_asset, &feed);
+        Ok(())
+    }
+
+    /// Remove the price oracle for an asset pair (admin only).
+    pub fn remove_oracle_feed(env: Env, base_asset: Asset, quote_asset: Asset) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        Storage::remove_oracle_feed(&env, &base_asset, &quote_asset);
+        Ok(())
+    }
+
+    /// Get the oracle registered for an asset pair, if any.
+    pub fn get_oracle_feed(env: Env, base_asset: Asset, quote_asset: Asset) -> Option<OracleFeed> {
+        Storage::get_oracle_feed(&env, &base_asset, &quote_asset)
+    }
+
+    /// Current oracle reference price for a pair, in quote rate units (10000 = 1.0).
+    pub fn get_reference_price(env: Env, base_asset: Asset, quote_asset: Asset) -> Result<u64, Error> {
+        PriceOracle::reference_rate(&env, &base_asset, &quote_asset)
+    }
+}
+
+#[cfg(feature = "transfers")]
+#[contractimpl]
+impl AnchorKitContract {
+    // ============ Transfers ============
+
+    /// Helper function to initiate a transfer (Lifecycle Event 2)
+    pub fn initiate_transfer(
+        env: Env,
+        sender: Address,
+        destination: Address,
+        amount: i128,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        sender.require_auth_for_args((destination.clone(), amount).into_val(&env));
+        Self::start_transfer(env, sender, destination, amount, None)
+    }
+
+    /// `initiate_transfer` for flow routed by `referrer`, such as a wallet
+    /// integrator. The transfer counts towards the referrer's volume.
+    pub fn initiate_referred_transfer(
+        env: Env,
+        sender: Address,
+        destination: Address,
+        amount: i128,
+        referrer: Address,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        sender
+            .require_auth_for_args((destination.clone(), amount, referrer.clone()).into_val(&env));
+        Self::start_transfer(env, sender, destination, amount, Some(referrer))
+    }
+
+    fn start_transfer(
+        env: Env,
+        sender: Address,
+        destination: Address,
+        amount: i128,
+        referrer: Option<Address>,
+    ) -> Result<u64, Error> {
+        Compliance::ensure_not_blocked(&env, &sender)?;
+        Compliance::ensure_not_blocked(&env, &destination)?;
+        RateLimiter::consume(&env, &sender, OperationClass::Transfer)?;
+
+        // 1. Logic for fund movement or intent recording would go here
+        let transfer_id = Storage::get_next_intent_id(&env);
+        let transfer = TransferRecord {
+            transfer_id,
+            sender: sender.clone(),
+            destination: destination.clone(),
+            amount,
+            initiated_at: env.ledger().timestamp(),
+            referrer: referrer.clone(),
+            settled: false,
+        };
+        Exposure::open_leg(&env, &transfer)?;
+        Storage::set_transfer(&env, &transfer);
+        if let Some(referrer) = &referrer {
+            Self::record_referral(&env, referrer, false, amount);
+        }
+
+        // 2. Emit the "Transfer Initiated" event
+        TransferInitiated::publish(&env, transfer_id, &sender, &destination, amount);
+
+        Ok(transfer_id)
+    }
+
+    /// Cap the unsettled value of transfers between two anchors, in either
+    /// direction (admin only, 0 removes the cap). Legs that would exceed it
+    /// fail with `ExposureLimitExceeded` when initiated.
+    pub fn set_exposure_limit(
+        env: Env,
+        anchor_a: Address,
+        anchor_b: Address,
+        limit: i128,
+    ) -> Result<CounterpartyExposure, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        Exposure::set_limit(&env, &anchor_a, &anchor_b, limit)
+    }
+
+    /// Outstanding unsettled value between two anchors and its limit. The
+    /// order of the anchors doesn't matter.
+    pub fn get_exposure(env: Env, anchor_a: Address, anchor_b: Address) -> CounterpartyExposure {
+        Exposure::get(&env, &anchor_a, &anchor_b)
+    }
+
+    /// Cap the total sell amount intents may draw from one of the anchor's
+    /// quotes, so part of it can be reserved for partners with
+    /// `reserve_quote_capacity`. Callable by the anchor.
+    pub fn set_quote_capacity(
+        env: Env,
+        anchor: Address,
+        quote_id: u64,
+        capacity: u64,
+    ) -> Result<QuoteCapacity, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        anchor.require_auth();
+
+        let quote = Storage::get_quote(&env, &anchor, quote_id).ok_or(Error::QuoteNotFound)?;
+        ReservedCapacity::set_capacity(&env, &quote, capacity)
+    }
+
+    /// Reserve `amount` of a capped quote's capacity for `partner`, e.g. under
+    /// a committed-volume agreement. Intents referred by the partner draw on
+    /// it first; everyone else can only use the unreserved remainder.
+    /// Callable by the anchor.
+    pub fn reserve_quote_capacity(
+        env: Env,
+        anchor: Address,
+        quote_id: u64,
+        partner: Address,
+        amount: u64,
+    ) -> Result<QuoteCapacity, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        anchor.require_auth();
+
+        let quote = Storage::get_quote(&env, &anchor, quote_id).ok_or(Error::QuoteNotFound)?;
+        ReservedCapacity::reserve(&env, &quote, &partner, amount)
+    }
+
+    /// Get a quote's capacity cap and reservations, if it has a cap.
+    pub fn get_quote_capacity(env: Env, anchor: Address, quote_id: u64) -> Option<QuoteCapacity> {
+        Storage::get_quote_capacity(&env, &anchor, quote_id)
+    }
+
+    /// Get a transfer recorded by `initiate_transfer`.
+    pub fn get_transfer(env: Env, transfer_id: u64) -> Result<TransferRecord, Error> {
+        Storage::get_transfer(&env, transfer_id)
+    }
+
+    /// Confirm the final settlement of a transfer (Lifecycle Event 3)
+    ///
+    /// For an executed intent confirmed more than its corridor's settlement
+    /// SLA after it executed, the anchor is charged the corridor's
+    /// late-settlement penalty in favor of the payer (see
+    /// `SettlementPenalty`). A recorded transfer or executed intent can only
+    /// be confirmed once; confirming it again fails with `ReplayAttack`.
+    pub fn confirm_settlement(
+        env: Env,
+        transfer_id: u64,
+        settlement_ref: BytesN<32>,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        // Only admin can confirm settlement in this example
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        // 1. Update internal state (if applicable)
+        let mut transfer = Storage::get_transfer(&env, transfer_id).ok();
+        if let Some(transfer) = &transfer {
+            if transfer.settled {
+                return Err(Error::ReplayAttack);
+            }
+            TravelRule::check_settlement(&env, transfer)?;
+        }
+        let settled_at = env.ledger().timestamp();
+
+        if let Some(transfer) = &mut transfer {
+            transfer.settled = true;
+            if let Some(anchor) = Self::settling_anchor(&env, transfer) {
+                Self::record_settlement(&env, &anchor, transfer.initiated_at);
+            }
+            Exposure::close_leg(&env, transfer);
+            Storage::set_transfer(&env, transfer);
+        }
+
+        let penalty = SettlementPenalty::settle(&env, transfer_id)?;
+
+        // 2. Emit the "Settlement Confirmed" event
+        SettlementConfirmed::publish(&env, transfer_id, settlement_ref.clone(), settled_at);
+        if let Some(penalty) = &penalty {
+            LatePenaltyAccrued::publish(&env, penalty);
+        }
+
+        // 3. Notify contract receivers of recorded transfers
+        if let Some(transfer) = transfer {
+            if Self::is_contract_address(&transfer.destination) {
+                let details = SettlementDetails {
+                    transfer_id,
+                    sender: transfer.sender,
+                    destination: transfer.destination.clone(),
+                    amount: transfer.amount,
+                    settlement_ref,
+                    settled_at,
+                };
+                // Receivers without a hook (e.g. plain smart wallets) are skipped
+                let _ = SettlementHookClient::new(&env, &transfer.destination)
+                    .try_on_settlement(&details);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The penalty charged for confirming `intent_id`'s settlement late.
+    /// Fails with `NotFound` if none was charged.
+    pub fn get_late_penalty(env: Env, intent_id: u64) -> Result<LateSettlementPenalty, Error> {
+        Storage::get_late_penalty(&env, intent_id)
+    }
+
+    /// Initiated-to-settled durations of `anchor`'s recent transfers and
+    /// their p50/p95, as used by `RoutingStrategy::FastestSettlement`.
+    pub fn get_settlement_stats(env: Env, anchor: Address) -> SettlementStats {
+        Storage::get_settlement_stats(&env, &anchor)
+            .unwrap_or_else(|| SettlementStats::empty(&env, &anchor))
+    }
+
+    /// The anchor a transfer settles through: its destination if that is a
+    /// registered anchor, otherwise its sender. Transfers between two other
+    /// parties have none and aren't counted in settlement stats.
+    fn settling_anchor(env: &Env, transfer: &TransferRecord) -> Option<Address> {
+        [&transfer.destination, &transfer.sender]
+            .into_iter()
+            .find(|party| Storage::is_attestor(env, party))
+            .cloned()
+    }
+
+    /// Add a transfer initiated at `initiated_at` and settled now to `anchor`'s stats.
+    fn record_settlement(env: &Env, anchor: &Address, initiated_at: u64) {
+        let mut stats = Storage::get_settlement_stats(env, anchor)
+            .unwrap_or_else(|| SettlementStats::empty(env, anchor));
+        stats.record(env, env.ledger().timestamp().saturating_sub(initiated_at));
+        Storage::set_settlement_stats(env, &stats);
+    }
+
+    /// Add one executed intent or transfer to `referrer`'s volume.
+    fn record_referral(env: &Env, referrer: &Address, intent: bool, amount: i128) {
+        let mut volume = Storage::get_referral_volume(env, referrer);
+        if intent {
+            volume.intents += 1;
+        } else {
+            volume.transfers += 1;
+        }
+        volume.volume = volume.volume.saturating_add(amount);
+        Storage::set_referral_volume(env, referrer, &volume);
+    }
+
+    fn is_contract_address(address: &Address) -> bool {
+        // Contract strkeys start with 'C', account strkeys with 'G'
+        let strkey = address.to_string();
+        let mut buf = [0u8; 56];
+        if strkey.len() as usize != buf.len() {
+            return false;
+        }
+        strkey.copy_into_slice(&mut buf);
+        buf[0] == b'C'
+    }
+
+    /// Lock `amount` of `token` for `recipient` against `hash_lock` (SHA-256)
+    /// until `timeout`. Returns the transfer ID of the lock.
+    pub fn lock_with_hash(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token: Address,
+        amount: i128,
+        hash_lock: BytesN<32>,
+        timeout: u64,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        sender.require_auth_for_args(
+            (
+                recipient.clone(),
+                token.clone(),
+                amount,
+                hash_lock.clone(),
+                timeout,
+            )
+                .into_val(&env),
+        );
+        HashTimeLock::lock(&env, &sender, &recipient, &token, amount, &hash_lock, timeout)
+    }
+
+    /// Release a hash-locked transfer to its recipient by revealing the preimage.
+    /// Callable by anyone before the timeout; the preimage is published in an event.
+    /// Works during a freeze, since the timeout doesn't stop for it.
+    pub fn claim_with_preimage(env: Env, transfer_id: u64, preimage: Bytes) -> Result<(), Error> {
+        HashTimeLock::claim(&env, transfer_id, &preimage)?;
+        Ok(())
+    }
+
+    /// Return an unclaimed hash-locked transfer to its sender once the timeout has passed.
+    pub fn refund_after_timeout(env: Env, transfer_id: u64) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        HashTimeLock::refund(&env, transfer_id)?;
+        Ok(())
+    }
+
+    /// Get a hash-locked transfer by ID.
+    pub fn get_hash_lock(env: Env, transfer_id: u64) -> Result<HashLock, Error> {
+        Storage::get_hash_lock(&env, transfer_id)
+    }
+
+    /// Map a classic-Stellar deposit memo to the subject and transfer it credits.
+    /// Callable by the anchor; a memo can be reused once its deposit has settled.
+    pub fn register_deposit_memo(
+        env: Env,
+        anchor: Address,
+        memo: DepositMemo,
+        subject: Address,
+        transfer_id: u64,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        anchor.require_auth();
+
+        if !Storage::is_attestor(&env, &anchor) {
+            return Err(Error::UnauthorizedAttestor);
+        }
+
+        if !memo.is_valid() {
+            return Err(Error::InvalidConfig);
+        }
+
+        Offboarding::ensure_active(&env, &anchor)?;
+
+        if let Some(existing) = Storage::get_deposit_memo(&env, &anchor, &memo) {
+            if !existing.settled {
+                return Err(Error::AlreadyExists);
+            }
+        }
+
+        let record = DepositMemoRecord {
+            anchor,
+            memo,
+            subject,
+            transfer_id,
+            registered_at: env.ledger().timestamp(),
+            settled: false,
+        };
+        Storage::set_deposit_memo(&env, &record);
+        Offboarding::open_deposit(&env, &record.anchor);
+
+        Ok(())
+    }
+
+    /// Look up the subject and transfer registered for a deposit memo.
+    pub fn get_deposit_memo(
+        env: Env,
+        anchor: Address,
+        memo: DepositMemo,
+    ) -> Result<DepositMemoRecord, Error> {
+        Storage::get_deposit_memo(&env, &anchor, &memo).ok_or(Error::TransferNotFound)
+    }
+
+    /// Confirm settlement of a deposit identified only by its memo. Resolves the
+    /// transfer from the registry and emits the usual settlement event.
+    pub fn confirm_memo_deposit(
+        env: Env,
+        anchor: Address,
+        memo: DepositMemo,
+        settlement_ref: BytesN<32>,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        anchor.require_auth();
+
+        let mut record =
+            Storage::get_deposit_memo(&env, &anchor, &memo).ok_or(Error::TransferNotFound)?;
+        if record.settled {
+            return Err(Error::ReplayAttack);
+        }
+
+        record.settled = true;
+        Storage::set_deposit_memo(&env, &record);
+        Offboarding::settle_deposit(&env, &anchor);
+        if let Ok(mut transfer) = Storage::get_transfer(&env, record.transfer_id) {
+            if transfer.settled {
+                return Err(Error::ReplayAttack);
+            }
+            transfer.settled = true;
+            Self::record_settlement(&env, &anchor, transfer.initiated_at);
+            Storage::set_transfer(&env, &transfer);
+        }
+
+        SettlementConfirmed::publish(
+            &env,
+            record.transfer_id,
+            settlement_ref,
+            env.ledger().timestamp(),
+        );
+
+        Ok(record.transfer_id)
+    }
+
+    /// Publish deposit or withdrawal info for one asset, matching an entry of the
+    /// SEP-6 `/info` response. Callable by the anchor; replaces any previous record.
+    pub fn publish_transfer_info(env: Env, info: TransferInfo) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Storage::get_admin(&env)?;
+        info.anchor.require_auth();
+
+        if !Storage::is_attestor(&env, &info.anchor) {
+            return Err(Error::AttestorNotRegistered);
+        }
+
+        if info.operation_type != ServiceType::Deposits
+            && info.operation_type != ServiceType::Withdrawals
+        {
+            return Err(Error::InvalidServiceType);
+        }
+
+        let services = Storage::get_anchor_services(&env, &info.anchor)?;
+        if !services.services.contains(info.operation_type) {
+            return Err(Error::InvalidServiceType);
+        }
+
+        if info.asset_code.is_empty()
+            || info.fee_percent > 10000
+            || (info.max_amount != 0 && info.min_amount > info.max_amount)
+        {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut info = info;
+        info.updated_at = env.ledger().timestamp();
+        Storage::set_transfer_info(&env, &info);
+
+        TransferInfoUpdated {
+            anchor: info.anchor,
+            asset_code: info.asset_code,
+            operation_type: info.operation_type,
+            enabled: info.enabled,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the published deposit or withdrawal info for an asset.
+    pub fn get_transfer_info(
+        env: Env,
+        anchor: Address,
+        operation_type: ServiceType,
+        asset_code: String,
+    ) -> Result<TransferInfo, Error> {
+        Storage::get_transfer_info(&env, &anchor, operation_type, &asset_code)
+            .ok_or(Error::UnsupportedAsset)
+    }
+
+    /// List all published info records for an anchor and direction,
+    /// equivalent to the `deposit` or `withdraw` map of SEP-6 `/info`.
+    pub fn list_transfer_info(
+        env: Env,
+        anchor: Address,
+        operation_type: ServiceType,
+    ) -> Vec<TransferInfo> {
+        let mut records = Vec::new(&env);
+        for asset_code in Storage::get_transfer_info_assets(&env, &anchor, operation_type).iter() {
+            if let Some(info) =
+                Storage::get_transfer_info(&env, &anchor, operation_type, &asset_code)
+            {
+                records.push_back(info);
+            }
+        }
+        records
+    }
+
+    /// Remove a published info record. Callable by the anchor.
+    pub fn remove_transfer_info(
+        env: Env,
+        anchor: Address,
+        operation_type: ServiceType,
+        asset_code: String,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        anchor.require_auth();
+
+        if Storage::get_transfer_info(&env, &anchor, operation_type, &asset_code).is_none() {
+            return Err(Error::UnsupportedAsset);
+        }
+
+        Storage::remove_transfer_info(&env, &anchor, operation_type, &asset_code);
+        Ok(())
+    }
+
+    /// Add a Stellar Asset Contract to the custody allowlist. Only callable by admin.
+    pub fn add_supported_token(env: Env, token: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        TokenCustody::add_supported_token(&env, &token);
+        Ok(())
+    }
+
+    /// Remove a token from the custody allowlist. Only callable by admin.
+    pub fn remove_supported_token(env: Env, token: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        TokenCustody::remove_supported_token(&env, &token);
+        Ok(())
+    }
+
+    /// Get the token addresses accepted for escrow, fees, bonds and refunds.
+    pub fn get_supported_tokens(env: Env) -> Vec<Address> {
+        TokenCustody::get_supported_tokens(&env)
+    }
+
+    /// Get the treasury's balance of every token it holds fee or slash
+    /// proceeds in.
+    pub fn get_treasury_balances(env: Env) -> Vec<TreasuryBalance> {
+        Treasury::balances(&env)
+    }
+
+    /// Withdraw `amount` of `token` from the treasury to `to`. Only callable
+    /// by admin; escrowed funds and bonds can't be withdrawn this way.
+    pub fn withdraw(env: Env, token: Address, to: Address, amount: i128) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        Treasury::withdraw(&env, &token, &to, amount)
+    }
+
+    /// Set the share of protocol fees paid to the referrer of a flow, in
+    /// basis points. Only callable by admin.
+    pub fn set_referral_share(env: Env, share_bps: u32) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        if share_bps > 10_000 {
+            return Err(Error::InvalidConfig);
+        }
+        ScheduledParams::settle(&env, Parameter::ReferralShare);
+        Storage::set_referral_share(&env, share_bps);
+        Ok(())
+    }
+
+    pub fn get_referral_share(env: Env) -> u32 {
+        ScheduledParams::referral_share(&env)
+    }
+
+    /// Get intent and transfer counters by service type and asset pair for a
+    /// metrics period (`timestamp / METRICS_PERIOD_SECONDS`, i.e. days since
+    /// the epoch).
+    pub fn get_metrics(env: Env, period: u64) -> Vec<UsageMetrics> {
+        Storage::get_usage_metrics(&env, period)
+    }
+
+    /// Get the metrics period containing the current ledger time.
+    pub fn get_current_metrics_period(env: Env) -> u64 {
+        Metrics::current_period(&env)
+    }
+
+    /// Gross fees `anchor` earned in a metrics period from executed intents,
+    /// at each intent's quoted fee, for revenue-share reconciliation.
+    pub fn get_anchor_fee_revenue(env: Env, anchor: Address, period: u64) -> AnchorFeeRevenue {
+        Storage::get_fee_revenue(&env, &anchor, period)
+    }
+
+    /// Intents and transfers routed by `referrer`, and their total amount.
+    pub fn get_referral_volume(env: Env, referrer: Address) -> ReferralVolume {
+        Storage::get_referral_volume(&env, &referrer)
+    }
+
+    // ============ Retry Bookkeeping ============
+
+    /// Record a failed attempt at a transfer or webhook delivery, reported by a
+    /// worker holding `RetryWorker`. Returns the updated record with the
+    /// attempt count and when the next attempt is due.
+    pub fn record_retry_failure(
+        env: Env,
+        worker: Address,
+        target: RetryTarget,
+        error_code: u32,
+    ) -> Result<RetryRecord, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &worker, Role::RetryWorker)?;
+        RetryQueue::record_failure(&env, &target, error_code)
+    }
+
+    /// Clear the retry record of an operation that succeeded or was abandoned.
+    /// Callable by a `RetryWorker`.
+    pub fn clear_retry(env: Env, worker: Address, target: RetryTarget) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &worker, Role::RetryWorker)?;
+        RetryQueue::clear(&env, &target)
+    }
+
+    pub fn get_retry(env: Env, target: RetryTarget) -> Result<RetryRecord, Error> {
+        Storage::get_retry_record(&env, &target).ok_or(Error::NotFound)
+    }
+
+    /// Every open retry record, including exhausted ones.
+    pub fn list_retries(env: Env) -> Vec<RetryRecord> {
+        RetryQueue::records(&env, false)
+    }
+
+    /// Retry records whose next attempt is due now.
+    pub fn list_due_retries(env: Env) -> Vec<RetryRecord> {
+        RetryQueue::records(&env, true)
+    }
+
+    // ============ Anchor Offboarding ============
+
+    /// Post `amount` of `token` as `anchor`'s bond. The bond stays in custody,
+    /// slashable by admin, until the anchor completes offboarding.
+    pub fn post_anchor_bond(
+        env: Env,
+        anchor: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        anchor.require_auth();
+        Offboarding::post_bond(&env, &anchor, &token, amount)
+    }
+
+    /// Bonds `anchor` holds in custody.
+    pub fn get_anchor_bonds(env: Env, anchor: Address) -> Vec<AnchorBond> {
+        Storage::get_anchor_bonds(&env, &anchor)
+    }
+
+    /// Move `amount` of `anchor`'s `token` bond to the treasury. Only callable
+    /// by admin, and only until the bond is released.
+    pub fn slash_anchor_bond(
+        env: Env,
+        anchor: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        Offboarding::slash_bond(&env, &anchor, &token, amount)
+    }
+
+    /// Start removing `anchor` (admin only). The anchor stops taking quotes,
+    /// intents and deposits at once, but stays registered until its open
+    /// activity settles and the challenge window ends; see `advance_offboarding`.
+    /// Unlike `revoke_attestor_with_session`, nothing in flight is stranded.
+    pub fn initiate_offboarding(env: Env, anchor: Address) -> Result<OffboardingState, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        Offboarding::initiate(&env, &anchor)
+    }
+
+    /// Move an offboarding anchor to its next stage once it is due: to the
+    /// challenge window when its deposits have settled and intents expired,
+    /// then, after the window, release its bond and remove it. Callable by anyone.
+    pub fn advance_offboarding(env: Env, anchor: Address) -> Result<OffboardingState, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Offboarding::advance(&env, &anchor)
+    }
+
+    pub fn get_offboarding(env: Env, anchor: Address) -> Result<OffboardingState, Error> {
+        Storage::get_offboarding(&env, &anchor).ok_or(Error::NotFound)
+    }
+
+    /// In-flight activity `anchor`'s offboarding waits on.
+    pub fn get_anchor_activity(env: Env, anchor: Address) -> AnchorActivity {
+        Storage::get_anchor_activity(&env, &anchor)
+    }
+
+    // ============ Transaction Intents ============
+
+    /// Create a high-level transaction intent and automatically enforce anchor compliance rules.
+    /// The builder's subject must be set and authorize the build; only the
+    /// subject can later fund the intent with `execute_intent`.
+    pub fn build_transaction_intent(
+        env: Env,
+        builder: TransactionIntentBuilder,
+    ) -> Result<TransactionIntent, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let subject = builder.subject.clone().ok_or(Error::InvalidTransactionIntent)?;
+        subject.require_auth();
+
+        Self::build_intent(env, builder)
+    }
+
+    /// Build and store an intent whose subject has authorized it.
+    fn build_intent(env: Env, builder: TransactionIntentBuilder) -> Result<TransactionIntent, Error> {
+        Storage::get_admin(&env)?;
+
+        let mut report = CheckReport::new(&env);
+        Self::check_intent(&env, &builder, &mut report);
+        report.into_result()?;
+
+        let mut intent = Self::draft_intent(&env, &builder)?;
+        intent.intent_id = Storage::get_next_intent_id(&env);
+
+        Compliance::check_compliance(&env, &intent)?;
+        intent.attestation_ids = Compliance::resolve_corridor_attestations(
+            &env,
+            &intent.request,
+            intent.corridor_id,
+            intent.requirement_template,
+            &intent.subject,
+        )
+        .attestation_ids;
+        if Compliance::flag_if_large(
+            &env,
+            intent.intent_id,
+            &intent.request.base_asset,
+            intent.request.amount as i128,
+        )
+        .is_some()
+        {
+            Compliance::record_flag(&env, &intent.anchor);
+        }
+
+        if intent.session_id != 0 {
+            Self::log_session_operation(
+                &env,
+                intent.session_id,
+                &intent.anchor,
+                "intent",
+                "success",
+                intent.intent_id,
+                &Some(builder.correlation_id.clone()),
+            )?;
+        }
+
+        Storage::set_intent(&env, &intent);
+        if intent.has_quote {
+            Storage::set_quote_binding(&env, &QuoteBinding::for_intent(&env, &intent));
+            if let Some(quote) = Storage::get_quote(&env, &intent.anchor, intent.quote_id) {
+                ReservedCapacity::authorize(&env, &quote, &intent);
+                Storage::touch_quote(&env, &quote);
+            }
+        }
+        Offboarding::record_intent(&env, &intent);
+        Metrics::record_intent(&env, &intent.request);
+
+        Ok(intent)
+    }
+
+    /// Build a transaction intent on behalf of `user`, submitted by `sponsor`.
+    /// `user` must be the builder's subject.
+    pub fn sponsored_build_intent(
+        env: Env,
+        sponsor: Address,
+        user: Address,
+        builder: TransactionIntentBuilder,
+    ) -> Result<TransactionIntent, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        sponsor.require_auth();
+        user.require_auth_for_args((builder.clone(),).into_val(&env));
+        if builder.subject.as_ref() != Some(&user) {
+            return Err(Error::Unauthorized);
+        }
+
+        let intent = Self::build_intent(env.clone(), builder)?;
+        OperationSponsored::publish(&env, &sponsor, &user, symbol_short!("intent"));
+
+        Ok(intent)
+    }
+
+    /// Store `builder` as a template for a recurring flow and return its ID.
+    /// The anchor, operation, TTL, services and asset are checked here so a
+    /// bad template is rejected up front; each build re-runs them. The
+    /// builder's subject must be `owner`, its amount is ignored and its
+    /// per-flow fields (quote, session, `not_before`) must be unset.
+    pub fn create_intent_template(
+        env: Env,
+        owner: Address,
+        builder: TransactionIntentBuilder,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Storage::get_admin(&env)?;
+        owner.require_auth();
+
+        if builder.subject.as_ref() != Some(&owner) {
+            return Err(Error::Unauthorized);
+        }
+
+        if builder.quote_id != 0
+            || builder.quote_sequence != 0
+            || builder.session_id != 0
+            || builder.not_before != 0
+        {
+            return Err(Error::InvalidTransactionIntent);
+        }
+        let mut report = CheckReport::new(&env);
+        Self::check_intent_terms(&env, &builder, &mut report);
+        report.into_result()?;
+
+        let mut template = IntentTemplate {
+            template_id: Storage::get_next_intent_template_id(&env),
+            owner,
+            builder,
+            created_at: env.ledger().timestamp(),
+        };
+        template.builder.request.amount = 0;
+        Storage::set_intent_template(&env, &template);
+        Ok(template.template_id)
+    }
+
+    pub fn get_intent_template(env: Env, template_id: u64) -> Result<IntentTemplate, Error> {
+        Storage::get_intent_template(&env, template_id)
+    }
+
+    /// Build an intent for `amount` from a stored template. Only the template's
+    /// owner can build from it. Goes through `build_transaction_intent`, so
+    /// every check applies again against current state.
+    pub fn build_intent_from_template(
+        env: Env,
+        template_id: u64,
+        amount: u64,
+    ) -> Result<TransactionIntent, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let template = Storage::get_intent_template(&env, template_id)?;
+        template.owner.require_auth();
+
+        let mut builder = template.builder;
+        builder.request.amount = amount;
+        Self::build_intent(env, builder)
+    }
+
+    /// Run the checks of `build_transaction_intent` without building anything
+    /// and return every failure with its field, expected and actual value.
+    /// An empty result means those checks pass.
+    pub fn validate_transaction_intent(
+        env: Env,
+        builder: TransactionIntentBuilder,
+    ) -> Vec<CheckFailure> {
+        let mut report = CheckReport::new(&env);
+        Self::check_intent(&env, &builder, &mut report);
+        report.failures()
+    }
+
+    /// Dry-run `build_transaction_intent`: run the builder checks, derive the
+    /// KYC level and expiry, and run the compliance checks against the would-be
+    /// intent, without allocating an intent ID or writing anything.
+    /// `valid` is true exactly when `build_transaction_intent` would succeed.
+    pub fn validate_intent(env: Env, builder: TransactionIntentBuilder) -> IntentValidation {
+        let mut report = CheckReport::new(&env);
+        Self::check_intent(&env, &builder, &mut report);
+        let mut failures = report.failures();
+
+        let mut validation = IntentValidation {
+            valid: false,
+            kyc_level: Self::required_kyc_level(&env, &builder),
+            expires_at: 0,
+            failures: Vec::new(&env),
+        };
+        match Self::draft_intent(&env, &builder) {
+            Ok(intent) => {
+                validation.expires_at = intent.expires_at;
+                failures.append(&Compliance::explain(&env, &intent));
+            }
+            // A missing quote is already reported as `quote_id`
+            Err(Error::QuoteNotFound) => {}
+            Err(error) => {
+                let mut report = CheckReport::new(&env);
+                report.fail(&env, error, "ttl_seconds", 1, 0);
+                failures.append(&report.failures());
+            }
+        }
+
+        validation.valid = failures.is_empty();
+        validation.failures = failures;
+        validation
+    }
+
+    /// Intent terms derived from a checked builder: KYC level, quote terms and
+    /// an expiry clamped to the quote's validity. The intent ID is left at 0.
+    fn draft_intent(
+        env: &Env,
+        builder: &TransactionIntentBuilder,
+    ) -> Result<TransactionIntent, Error> {
+        let kyc_level = Self::required_kyc_level(env, builder);
+
+        let now = env.ledger().timestamp();
+        let mut expires_at = now
+            .checked_add(builder.ttl_seconds)
+            .ok_or(Error::InvalidTransactionIntent)?;
+
+        let mut has_quote = false;
+        let mut rate = 0u64;
+        let mut fee_percentage = 0u32;
+
+        if builder.quote_id != 0 {
+            let quote = Storage::get_quote(env, &builder.anchor, builder.quote_id)
+                .ok_or(Error::QuoteNotFound)?;
+
+            has_quote = true;
+            rate = quote.rate;
+            fee_percentage = quote.fee_percentage;
+            if quote.valid_until < expires_at {
+                expires_at = quote.valid_until;
+            }
+        }
+
+        if builder.not_before >= expires_at {
+            return Err(Error::InvalidTransactionIntent);
+        }
+
+        let mut settlement_sla_seconds = 0u64;
+        if builder.corridor_id != 0 {
+            let corridor = Storage::get_corridor(env, builder.corridor_id)?;
+            settlement_sla_seconds = corridor.settlement_sla_seconds;
+        }
+
+        Ok(TransactionIntent {
+            intent_id: 0,
+            anchor: builder.anchor.clone(),
+            request: builder.request.clone(),
+            quote_id: builder.quote_id,
+            has_quote,
+            rate,
+            fee_percentage,
+            requires_kyc: kyc_level > 0,
+            kyc_level,
+            subject: builder.subject.clone(),
+            attestation_ids: Vec::new(env),
+            session_id: builder.session_id,
+            created_at: now,
+            expires_at,
+            referrer: builder.referrer.clone(),
+            corridor_id: builder.corridor_id,
+            settlement_sla_seconds,
+            not_before: builder.not_before,
+            requirement_template: builder.requirement_template,
+        })
+    }
+
+    /// Fund and execute a stored intent from a token allowance.
+    ///
+    /// The payer approves this contract on `token` beforehand; exactly the intent
+    /// amount is pulled to the anchor with `transfer_from`, so no upfront escrow
+    /// is needed. Fails with `InsufficientAllowance` if the approval was reduced.
+    /// The amount is rescaled from the base asset's decimals to the token's, and
+    /// fails with `InvalidConfig` if the token cannot represent it exactly.
+    /// The quote's fee on that amount is pulled on top of it into the treasury,
+    /// less the referral share paid to the intent's referrer, so the approval
+    /// has to cover both.
+    ///
+    /// The intent is locked while it executes, so a second attempt reaching it
+    /// meanwhile (e.g. re-entering through the token) fails with `IntentLocked`.
+    /// An intent built from a quote must still hash to the request it was
+    /// quoted for and carry the quote's terms, or it fails with `QuoteConflict`.
+    /// Execution before the intent's `not_before` fails with `InvalidTimestamp`.
+    pub fn execute_intent(
+        env: Env,
+        intent_id: u64,
+        payer: Address,
+        token: Address,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        payer.require_auth_for_args((intent_id, token.clone()).into_val(&env));
+        Compliance::ensure_not_blocked(&env, &payer)?;
+
+        let intent = Storage::get_intent(&env, intent_id)?;
+        if intent.subject.as_ref() != Some(&payer) {
+            return Err(Error::Unauthorized);
+        }
+        if Storage::is_intent_executed(&env, intent_id) {
+            return Err(Error::ReplayAttack);
+        }
+        Storage::lock_intent(&env, intent_id)?;
+        let result = Self::run_intent(&env, &intent, &payer, &token);
+        Storage::unlock_intent(&env, intent_id);
+        result
+    }
+
+    /// Pin a session to one of `anchor`'s quotes (session initiator). Every
+    /// intent later built in the session must reference exactly that quote, or
+    /// `build_transaction_intent` fails with `QuoteConflict`; the anchor
+    /// superseding the quote doesn't affect it, but it still has to be valid.
+    /// A session can be pinned once.
+    pub fn pin_session_quote(
+        env: Env,
+        session_id: u64,
+        anchor: Address,
+        quote_id: u64,
+    ) -> Result<SessionQuotePin, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let session = Storage::get_session(&env, session_id)?;
+        session.initiator.require_auth();
+
+        if Storage::get_session_quote_pin(&env, session_id).is_some() {
+            return Err(Error::AlreadyExists);
+        }
+        let quote = Storage::get_quote(&env, &anchor, quote_id).ok_or(Error::QuoteNotFound)?;
+        let now = env.ledger().timestamp();
+        if quote.valid_until <= now {
+            return Err(Error::StaleQuote);
+        }
+        Storage::touch_quote(&env, &quote);
+
+        let pin = SessionQuotePin {
+            session_id,
+            anchor,
+            quote_id,
+            pinned_at: now,
+        };
+        Storage::set_session_quote_pin(&env, &pin);
+        Ok(pin)
+    }
+
+    /// The quote a session is pinned to, if any.
+    pub fn get_session_quote_pin(env: Env, session_id: u64) -> Option<SessionQuotePin> {
+        Storage::get_session_quote_pin(&env, session_id)
+    }
+
+    /// The request hash and quote terms an intent built from a quote is bound to.
+    pub fn get_quote_binding(env: Env, intent_id: u64) -> Result<QuoteBinding, Error> {
+        Storage::get_quote_binding(&env, intent_id).ok_or(Error::NotFound)
+    }
+
+    fn run_intent(
+        env: &Env,
+        intent: &TransactionIntent,
+        payer: &Address,
+        token: &Address,
+    ) -> Result<u64, Error> {
+        let intent_id = intent.intent_id;
+        if intent.expires_at <= env.ledger().timestamp() {
+            return Err(Error::InvalidTransactionIntent);
+        }
+        if env.ledger().timestamp() < intent.not_before {
+            return Err(Error::InvalidTimestamp);
+        }
+        Compliance::check_compliance(env, intent)?;
+        if intent.has_quote {
+            let binding =
+                Storage::get_quote_binding(env, intent_id).ok_or(Error::QuoteConflict)?;
+            if binding != QuoteBinding::for_intent(env, intent) {
+                return Err(Error::QuoteConflict);
+            }
+            if let Some(quote) = Storage::get_quote(env, &intent.anchor, intent.quote_id) {
+                ReservedCapacity::consume(env, &quote, intent)?;
+            }
+        }
+
+        let amount = intent.request.amount as i128;
+        let token_amount = Amount::of(env, &intent.request.base_asset, amount)
+            .rescale(TokenCustody::decimals(env, token))?;
+        TokenCustody::pull_from_allowance(env, token, payer, &intent.anchor, token_amount.value)?;
+        // The quoted fee is charged on top of the amount
+        let fee = token_amount.value.saturating_mul(intent.fee_percentage as i128) / 10_000;
+        if fee > 0 {
+            Treasury::collect_fee(env, token, payer, fee, intent.referrer.as_ref())?;
+        }
+        Storage::mark_intent_executed(env, intent_id);
+        let executed_at = env.ledger().timestamp();
+        Storage::set_intent_execution(
+            env,
+            &IntentExecution {
+                intent_id,
+                payer: payer.clone(),
+                token: token.clone(),
+                executed_at,
+                settle_by: match intent.settlement_sla_seconds {
+                    0 => 0,
+                    sla => executed_at.saturating_add(sla),
+                },
+                settled_at: 0,
+            },
+        );
+        Compliance::record_transfer(env, &intent.anchor, &intent.request.base_asset, amount);
+        Metrics::record_transfer(env, &intent.request, amount);
+        Metrics::record_fee(env, intent, amount);
+        if let Some(referrer) = &intent.referrer {
+            Self::record_referral(env, referrer, true, amount);
+        }
+
+        TransferInitiated::publish(env, intent_id, payer, &intent.anchor, amount);
+
+        Ok(intent_id)
+    }
+
+    /// Checks shared by `build_transaction_intent` and `validate_transaction_intent`.
+    fn check_intent(env: &Env, builder: &TransactionIntentBuilder, report: &mut CheckReport) {
+        Self::check_intent_terms(env, builder, report);
+        if builder.request.amount == 0 {
+            report.fail(env, Error::InvalidTransactionIntent, "amount", 1, 0);
+        }
+        report.require(
+            env,
+            builder.subject.is_some(),
+            Error::InvalidTransactionIntent,
+            "subject",
+        );
+        let expires_at = env.ledger().timestamp().saturating_add(builder.ttl_seconds);
+        if builder.not_before >= expires_at {
+            report.fail(
+                env,
+                Error::InvalidTransactionIntent,
+                "not_before",
+                expires_at.into(),
+                builder.not_before.into(),
+            );
+        }
+
+        let services = Storage::get_anchor_services(env, &builder.anchor).ok();
+        let kyc_level = Self::required_kyc_level(env, builder);
+        if kyc_level > 0 {
+            if let Some(services) = &services {
+                report.require(
+                    env,
+                    services.services.contains(ServiceType::KYC),
+                    Error::ComplianceNotMet,
+                    "kyc_service",
+                );
+            }
+            if let Some(subject) = &builder.subject {
+                let verified =
+                    Storage::get_kyc_record(env, subject).map_or(0, |record| record.level);
+                if verified < kyc_level {
+                    report.fail(
+                        env,
+                        Error::ComplianceNotMet,
+                        "kyc_level",
+                        kyc_level.into(),
+                        verified.into(),
+                    );
+                }
+            }
+        }
+
+        if builder.session_id != 0 && Storage::get_session(env, builder.session_id).is_err() {
+            report.fail(env, Error::SessionNotFound, "session_id", 1, 0);
+        }
+        let pinned = match Storage::get_session_quote_pin(env, builder.session_id) {
+            Some(pin) if builder.session_id != 0 => {
+                let matches = pin.anchor == builder.anchor && pin.quote_id == builder.quote_id;
+                if !matches {
+                    report.fail(
+                        env,
+                        Error::QuoteConflict,
+                        "pinned_quote",
+                        pin.quote_id.into(),
+                        builder.quote_id.into(),
+                    );
+                }
+                matches
+            }
+            _ => false,
+        };
+
+        if builder.quote_id != 0 {
+            match Storage::get_quote(env, &builder.anchor, builder.quote_id) {
+                Some(quote) => {
+                    let now = env.ledger().timestamp();
+                    if quote.valid_until <= now {
+                        report.fail(
+                            env,
+                            Error::StaleQuote,
+                            "valid_until",
+                            now.into(),
+                            quote.valid_until.into(),
+                        );
+                    }
+                    report.require(
+                        env,
+                        quote.matches_request(env, &builder.request),
+                        Error::InvalidQuote,
+                        "quote_terms",
+                    );
+                    let available = ReservedCapacity::available(env, &quote, &builder.referrer);
+                    let amount = quote.sell_amount_for(env, &builder.request);
+                    if let Some(available) = available.filter(|available| amount > *available) {
+                        report.fail(
+                            env,
+                            Error::InvalidQuote,
+                            "quote_capacity",
+                            amount.into(),
+                            available.into(),
+                        );
+                    }
+                    // Terms read by the client must still be the anchor's
+                    // current ones for the pair, so it can re-read and retry
+                    if quote.sequence != builder.quote_sequence {
+                        report.fail(
+                            env,
+                            Error::QuoteConflict,
+                            "quote_sequence",
+                            quote.sequence.into(),
+                            builder.quote_sequence.into(),
+                        );
+                    }
+                    let head = Storage::get_latest_quote(
+                        env,
+                        &builder.anchor,
+                        &quote.base_asset,
+                        &quote.quote_asset,
+                    );
+                    // A session's pinned quote stays usable once superseded
+                    if let Some(head) = head.filter(|head| *head != quote.quote_id && !pinned) {
+                        report.fail(
+                            env,
+                            Error::QuoteConflict,
+                            "quote_id",
+                            head.into(),
+                            quote.quote_id.into(),
+                        );
+                    }
+                }
+                None => report.fail(env, Error::QuoteNotFound, "quote_id", 1, 0),
+            }
+        }
+    }
+
+    /// Builder checks that hold independently of the amount, subject and
+    /// flow: the anchor, operation, TTL, services and asset. Intent templates
+    /// are checked against these when created, and again on every build.
+    fn check_intent_terms(
+        env: &Env,
+        builder: &TransactionIntentBuilder,
+        report: &mut CheckReport,
+    ) {
+        report.require(
+            env,
+            Storage::is_attestor(env, &builder.anchor),
+            Error::UnauthorizedAttestor,
+            "anchor",
+        );
+        report.require(
+            env,
+            Offboarding::ensure_active(env, &builder.anchor).is_ok(),
+            Error::AnchorOffboarding,
+            "anchor",
+        );
+        report.require(
+            env,
+            Self::validate_transaction_operation(&builder.request.operation_type).is_ok(),
+            Error::InvalidServiceType,
+            "operation_type",
+        );
+        if builder.ttl_seconds == 0 {
+            report.fail(env, Error::InvalidTransactionIntent, "ttl_seconds", 1, 0);
+        }
+        if builder.ttl_seconds > config::MAX_INTENT_TTL {
+            report.fail(
+                env,
+                Error::InvalidTransactionIntent,
+                "ttl_seconds",
+                config::MAX_INTENT_TTL.into(),
+                builder.ttl_seconds.into(),
+            );
+        }
+
+        match Storage::get_anchor_services(env, &builder.anchor) {
+            Ok(services) => report.require(
+                env,
+                services.services.contains(builder.request.operation_type),
+                Error::InvalidServiceType,
+                "services",
+            ),
+            Err(_) => report.fail(env, Error::ServicesNotConfigured, "services", 1, 0),
+        }
+        report.require(
+            env,
+            Self::supports_asset(
+                env.clone(),
+                builder.anchor.clone(),
+                builder.request.operation_type,
+                builder.request.base_asset.clone(),
+            ),
+            Error::UnsupportedAsset,
+            "base_asset",
+        );
+    }
+
+    /// KYC level required by the anchor's thresholds for the builder's amount;
+    /// an explicit `require_kyc` asks for at least level 1. It is checked
+    /// against the builder's subject, which authorizes the build and is the
+    /// only payer `execute_intent` accepts.
+    fn required_kyc_level(env: &Env, builder: &TransactionIntentBuilder) -> u32 {
+        let amount = Amount::of(env, &builder.request.base_asset, builder.request.amount.into())
+            .rescale_floor(DEFAULT_DECIMALS)
+            .map_or(u64::MAX, |normalized| normalized.to_u64_saturating());
+        let mut level = 0;
+        for threshold in Storage::get_kyc_thresholds(env, &builder.anchor).iter() {
+            if amount > threshold.amount_above && threshold.level > level {
+                level = threshold.level;
+            }
+        }
+        if builder.require_kyc && level == 0 {
+            level = 1;
+        }
+        level
+    }
+
+    fn validate_transaction_operation(operation_type: &ServiceType) -> Result<(), Error> {
+        match operation_type {
+            ServiceType::Deposits | ServiceType::Withdrawals => Ok(()),
+            _ => Err(Error::InvalidServiceType),
+        }
+    }
+
+    // ============ Travel Rule ============
+
+    /// Record the hash of the travel-rule data exchanged for a transfer, as the
+    /// originating or beneficiary anchor. Each side can commit once.
+    pub fn commit_travel_rule(
+        env: Env,
+        anchor: Address,
+        transfer_id: u64,
+        role: TravelRuleRole,
+        data_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        anchor.require_auth();
+        TravelRule::commit(&env, &anchor, transfer_id, role, &data_hash)
+    }
+
+    /// Get the travel-rule commitment recorded for one side of a transfer.
+    pub fn get_travel_rule_commitment(
+        env: Env,
+        transfer_id: u64,
+        role: TravelRuleRole,
+    ) -> Option<TravelRuleCommitment> {
+        Storage::get_travel_rule_commitment(&env, transfer_id, role)
+    }
+
+    /// Set the transfer amount at or above which settlement requires both
+    /// travel-rule commitments (compliance officer, 0 disables the requirement).
+    pub fn set_travel_rule_threshold(env: Env, officer: Address, threshold: i128) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &officer, Role::ComplianceOfficer)?;
+
+        if threshold < 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        Storage::set_travel_rule_threshold(&env, threshold);
+        Ok(())
+    }
+
+    /// Get the travel-rule settlement threshold (0 = disabled).
+    pub fn get_travel_rule_threshold(env: Env) -> i128 {
+        Storage::get_travel_rule_threshold(&env)
+    }
+}
+
+#[cfg(feature = "compliance")]
+#[contractimpl]
+impl AnchorKitContract {
+    // ============ Sanctions Blocklist ============
+
+    /// Add an address to the sanctions blocklist (compliance officer). Blocked addresses
+    /// cannot send or receive transfers, build intents, or be attested.
+    pub fn block_address(env: Env, officer: Address, address: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &officer, Role::ComplianceOfficer)?;
+
+        Compliance::block(&env, &address);
+        Ok(())
+    }
+
+    /// Remove an address from the sanctions blocklist (compliance officer).
+    pub fn unblock_address(env: Env, officer: Address, address: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &officer, Role::ComplianceOfficer)?;
+
+        Compliance::unblock(&env, &address);
+        Ok(())
+    }
+
+    /// Check whether an address is on the sanctions blocklist.
+    pub fn is_address_blocked(env: Env, address: Address) -> bool {
+        Storage::is_blocked(&env, &address)
+    }
+
+    // ============ Corridor Compliance Rules ============
+
+    /// Set the compliance rules for a corridor (compliance officer). Replaces any
+    /// existing rules for the pair.
+    pub fn set_corridor_rules(
+        env: Env,
+        officer: Address,
+        base_asset: Asset,
+        quote_asset: Asset,
+        rules: CorridorRules,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &officer, Role::ComplianceOfficer)?;
+
+        if rules.travel_rule_threshold < 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        Storage::set_corridor_rules(&env, &base_asset, &quote_asset, &rules);
+        Ok(())
+    }
+
+    /// Remove the compliance rules for a corridor (compliance officer).
+    pub fn remove_corridor_rules(
+        env: Env,
+        officer: Address,
+        base_asset: Asset,
+        quote_asset: Asset,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &officer, Role::ComplianceOfficer)?;
+
+        Storage::remove_corridor_rules(&env, &base_asset, &quote_asset);
+        Ok(())
+    }
+
+    /// Get the compliance rules for a corridor, if any.
+    pub fn get_corridor_rules(env: Env, base_asset: Asset, quote_asset: Asset) -> Option<CorridorRules> {
+        Storage::get_corridor_rules(&env, &base_asset, &quote_asset)
+    }
+
+    /// Evaluate an intent against the blocklist and its corridor's rules.
+    pub fn check_compliance(env: Env, intent: TransactionIntent) -> Result<(), Error> {
+        Compliance::check_compliance(&env, &intent)
+    }
+
+    /// Every compliance check that fails for an intent, with its field, expected
+    /// and actual value. `check_compliance` fails with the first one's error.
+    pub fn explain_compliance(env: Env, intent: TransactionIntent) -> Vec<CheckFailure> {
+        Compliance::explain(&env, &intent)
+    }
+
+    /// Report which attestation kinds required by an intent's corridor are
+    /// satisfied for its subject and which are missing. Call this when
+    /// `build_transaction_intent` fails with `ComplianceNotMet` to find out why.
+    pub fn resolve_attestation_requirements(
+        env: Env,
+        builder: TransactionIntentBuilder,
+    ) -> AttestationResolution {
+        Compliance::resolve_corridor_attestations(
+            &env,
+            &builder.request,
+            builder.corridor_id,
+            builder.requirement_template,
+            &builder.subject,
+        )
+    }
+
+    /// Tag an attestation with a kind (e.g. `kyc`, `sanctions`) so corridor rules
+    /// can require it. Only the attestation's issuer can tag it.
+    pub fn set_attestation_kind(
+        env: Env,
+        issuer: Address,
+        attestation_id: u64,
+        kind: Symbol,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        issuer.require_auth();
+
+        let attestation = Storage::get_attestation(&env, attestation_id)?;
+        if attestation.issuer != issuer {
+            return Err(Error::UnauthorizedAttestor);
+        }
+
+        Storage::set_attestation_kind(&env, &attestation, &kind);
+        Ok(())
+    }
+
+    /// Get the kind an attestation was tagged with, if any.
+    pub fn get_attestation_kind(env: Env, attestation_id: u64) -> Option<Symbol> {
+        Storage::get_attestation_kind(&env, attestation_id)
+    }
+
+    // ============ Requirement Templates ============
+
+    /// Define a named requirement template (compliance officer), e.g.
+    /// `retail_onramp` = KYC level 1 + a `sanctions` attestation. Intents
+    /// reference it by the returned ID through their builder's
+    /// `requirement_template`. Names are unique.
+    pub fn define_requirement_template(
+        env: Env,
+        officer: Address,
+        name: Symbol,
+        kyc_level: u32,
+        required_attestations: Vec<Symbol>,
+    ) -> Result<u64, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &officer, Role::ComplianceOfficer)?;
+
+        let mut template = RequirementTemplate {
+            template_id: 0,
+            name,
+            kyc_level,
+            required_attestations,
+        };
+        Self::validate_requirement_template(&env, &template)?;
+
+        template.template_id = Storage::get_next_requirement_template_id(&env);
+        Storage::set_requirement_template(&env, &template);
+        Ok(template.template_id)
+    }
+
+    /// Change a template's KYC level and attestation kinds (compliance
+    /// officer). Its name is fixed. Intents referencing it are checked against
+    /// the new requirements when they execute.
+    pub fn update_requirement_template(
+        env: Env,
+        officer: Address,
+        template: RequirementTemplate,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &officer, Role::ComplianceOfficer)?;
+
+        let existing = Storage::get_requirement_template(&env, template.template_id)?;
+        if template.name != existing.name {
+            return Err(Error::InvalidConfig);
+        }
+        Self::validate_requirement_template(&env, &template)?;
+
+        Storage::set_requirement_template(&env, &template);
+        Ok(())
+    }
+
+    pub fn get_requirement_template(
+        env: Env,
+        template_id: u64,
+    ) -> Result<RequirementTemplate, Error> {
+        Storage::get_requirement_template(&env, template_id)
+    }
+
+    /// Every requirement template, in definition order.
+    pub fn list_requirement_templates(env: Env) -> Vec<RequirementTemplate> {
+        let mut templates = Vec::new(&env);
+        for template_id in 1..=Storage::get_requirement_template_count(&env) {
+            if let Ok(template) = Storage::get_requirement_template(&env, template_id) {
+                templates.push_back(template);
+            }
+        }
+        templates
+    }
+
+    /// A template must require something, list each attestation kind once
+    /// and not reuse another template's name.
+    fn validate_requirement_template(
+        env: &Env,
+        template: &RequirementTemplate,
+    ) -> Result<(), Error> {
+        let kinds = &template.required_attestations;
+        if template.kyc_level == 0 && kinds.is_empty() {
+            return Err(Error::InvalidConfig);
+        }
+        for (index, kind) in kinds.iter().enumerate() {
+            if kinds.first_index_of(&kind) != Some(index as u32) {
+                return Err(Error::InvalidConfig);
+            }
+        }
+        for template_id in 1..=Storage::get_requirement_template_count(env) {
+            if let Ok(other) = Storage::get_requirement_template(env, template_id) {
+                if other.template_id != template.template_id && other.name == template.name {
+                    return Err(Error::InvalidConfig);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // ============ AML Flags ============
+
+    /// Set the amount above which intents and transfers in `asset` raise an
+    /// AML flag (compliance officer, 0 disables flagging for the asset).
+    pub fn set_aml_threshold(
+        env: Env,
+        officer: Address,
+        asset: Asset,
+        threshold: i128,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &officer, Role::ComplianceOfficer)?;
+
+        if threshold < 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        Storage::set_aml_threshold(&env, &asset, threshold);
+        Ok(())
+    }
+
+    /// Get the AML flagging threshold for an asset (0 = not monitored).
+    pub fn get_aml_threshold(env: Env, asset: Asset) -> i128 {
+        Storage::get_aml_threshold(&env, &asset)
+    }
+
+    /// Get an AML flag by ID.
+    pub fn get_aml_flag(env: Env, flag_id: u64) -> Result<AmlFlag, Error> {
+        Storage::get_aml_flag(&env, flag_id)
+    }
+
+    /// Clear an AML flag after review (compliance officer).
+    pub fn clear_aml_flag(env: Env, officer: Address, flag_id: u64) -> Result<AmlFlag, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &officer, Role::ComplianceOfficer)?;
+
+        Compliance::review_flag(&env, flag_id, AmlFlagStatus::Cleared)
+    }
+
+    /// Escalate an open AML flag for further investigation (compliance officer).
+    pub fn escalate_aml_flag(env: Env, officer: Address, flag_id: u64) -> Result<AmlFlag, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &officer, Role::ComplianceOfficer)?;
+
+        Compliance::review_flag(&env, flag_id, AmlFlagStatus::Escalated)
+    }
+
+    // ============ Compliance Reporting ============
+
+    /// Get an anchor's compliance counters for a reporting period
+    /// (`timestamp / COMPLIANCE_PERIOD_SECONDS`, i.e. 30-day periods since the epoch).
+    /// Periods without activity return an empty report.
+    pub fn get_compliance_report(env: Env, anchor: Address, period: u64) -> ComplianceReport {
+        Storage::get_compliance_report(&env, &anchor, period)
+    }
+
+    /// Get the reporting period containing the current ledger time.
+    pub fn get_current_compliance_period(env: Env) -> u64 {
+        Compliance::current_period(&env)
+    }
+
+    /// Record a KYC rejection in the anchor's current report. Anchors call this
+    /// when they reject a customer during SEP-12 review; failed contract calls
+    /// are rolled back and cannot be counted on-chain.
+    pub fn record_kyc_rejection(env: Env, anchor: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        anchor.require_auth();
+
+        if !Storage::is_attestor(&env, &anchor) {
+            return Err(Error::UnauthorizedAttestor);
+        }
+
+        Compliance::record_kyc_rejection(&env, &anchor);
+        Ok(())
+    }
+
+    // ============ Right to Erasure ============
+
+    /// File a right-to-erasure request for the caller's subject-linked data.
+    pub fn request_erasure(env: Env, subject: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        subject.require_auth();
+
+        Erasure::request(&env, &subject)
+    }
+
+    /// Approve a pending erasure request (compliance officer). The subject's
+    /// attestations and KYC record are replaced with tombstones that keep only
+    /// a hash of each erased record. Returns the number of records erased.
+    pub fn approve_erasure(env: Env, officer: Address, subject: Address) -> Result<u32, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &officer, Role::ComplianceOfficer)?;
+
+        Erasure::approve(&env, &subject)
+    }
+
+    /// Get the time a pending erasure request was filed, if any.
+    pub fn get_erasure_request(env: Env, subject: Address) -> Option<u64> {
+        Storage::get_erasure_request(&env, &subject)
+    }
+
+    /// Get the tombstone left for an erased record. `record_id` is the
+    /// attestation ID, or 0 for a KYC record.
+    pub fn get_tombstone(
+        env: Env,
+        kind: TombstoneKind,
+        subject: Address,
+        record_id: u64,
+    ) -> Option<Tombstone> {
+        Storage::get_tombstone(&env, kind, &subject, record_id)
+    }
+
+    // ============ KYC Registry ============
+
+    /// Set the amount thresholds at which `anchor` requires higher KYC levels,
+    /// e.g. `[{amount_above: 1000, level: 2}]`. Replaces previous thresholds.
+    pub fn set_kyc_thresholds(
+        env: Env,
+        anchor: Address,
+        thresholds: Vec<KycThreshold>,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        anchor.require_auth();
+
+        if !Storage::is_attestor(&env, &anchor) {
+            return Err(Error::UnauthorizedAttestor);
+        }
+
+        for threshold in thresholds.iter() {
+            if threshold.level == 0 {
+                return Err(Error::InvalidConfig);
+            }
+        }
+
+        Storage::set_kyc_thresholds(&env, &anchor, &thresholds);
+        Ok(())
+    }
+
+    /// Get the KYC thresholds configured by an anchor.
+    pub fn get_kyc_thresholds(env: Env, anchor: Address) -> Vec<KycThreshold> {
+        Storage::get_kyc_thresholds(&env, &anchor)
+    }
+
+    /// Record the KYC level verified for a subject. The verifier is either an
+    /// anchor offering the KYC service or a compliance officer.
+    pub fn set_kyc_level(env: Env, verifier: Address, subject: Address, level: u32) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        verifier.require_auth();
+
+        if !Rbac::has_role(&env, Role::ComplianceOfficer, &verifier) {
+            if !Storage::is_attestor(&env, &verifier) {
+                return Err(Error::UnauthorizedAttestor);
+            }
+
+            let services = Storage::get_anchor_services(&env, &verifier)?;
+            if !services.services.contains(ServiceType::KYC) {
+                return Err(Error::InvalidServiceType);
+            }
+        }
+
+        Storage::set_kyc_record(
+            &env,
+            &KycRecord {
+                subject: subject.clone(),
+                level,
+                verified_by: verifier.clone(),
+                updated_at: env.ledger().timestamp(),
+            },
+        );
+        KycLevelUpdated::publish(&env, &subject, level, &verifier);
+
+        Ok(())
+    }
+
+    /// Register a zero-knowledge KYC proof verifier contract (compliance officer).
+    pub fn register_kyc_verifier(env: Env, officer: Address, verifier: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &officer, Role::ComplianceOfficer)?;
+
+        Storage::set_kyc_verifier(&env, &verifier, true);
+        Ok(())
+    }
+
+    /// Remove a zero-knowledge KYC proof verifier contract (compliance officer).
+    pub fn remove_kyc_verifier(env: Env, officer: Address, verifier: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &officer, Role::ComplianceOfficer)?;
+
+        Storage::set_kyc_verifier(&env, &verifier, false);
+        Ok(())
+    }
+
+    /// Check whether a contract is a registered KYC proof verifier.
+    pub fn is_kyc_verifier(env: Env, verifier: Address) -> bool {
+        Storage::is_kyc_verifier(&env, &verifier)
+    }
+
+    /// Prove KYC at `level` with a zero-knowledge proof instead of a plain KYC
+    /// record. The registered `verifier` checks the proof; on success the level is
+    /// recorded in the KYC registry and only `commitment` is kept on-chain.
+    pub fn submit_kyc_proof(
+        env: Env,
+        subject: Address,
+        verifier: Address,
+        commitment: BytesN<32>,
+        proof: Bytes,
+        level: u32,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        subject.require_auth();
+
+        if !Storage::is_kyc_verifier(&env, &verifier) {
+            return Err(Error::Unauthorized);
+        }
+
+        let valid = KycProofVerifierClient::new(&env, &verifier).verify_kyc_proof(
+            &subject,
+            &commitment,
+            &proof,
+            &level,
+        );
+        if !valid {
+            return Err(Error::ComplianceNotMet);
+        }
+
+        Storage::set_kyc_record(
+            &env,
+            &KycRecord {
+                subject: subject.clone(),
+                level,
+                verified_by: verifier.clone(),
+                updated_at: env.ledger().timestamp(),
+            },
+        );
+        Storage::set_kyc_proof_commitment(&env, &subject, &commitment);
+        KycProofVerified::publish(&env, &subject, &verifier, &commitment, level);
+
+        Ok(())
+    }
+
+    /// Get the commitment of a subject's latest verified KYC proof, if any.
+    pub fn get_kyc_proof_commitment(env: Env, subject: Address) -> Option<BytesN<32>> {
+        Storage::get_kyc_proof_commitment(&env, &subject)
+    }
+
+    /// Get a subject's KYC record, if any.
+    pub fn get_kyc_record(env: Env, subject: Address) -> Option<KycRecord> {
+        Storage::get_kyc_record(&env, &subject)
+    }
+}
+
+#[cfg(feature = "routing")]
+#[contractimpl]
+impl AnchorKitContract {
+    // ============ Multi-Anchor Routing ============
+
+    /// Set metadata for an anchor. Only callable by admin or the anchor itself.
+    pub fn set_anchor_metadata(
+        env: Env,
+        anchor: Address,
+        reputation_score: u32,
+        average_settlement_time: u64,
+        liquidity_score: u32,
+        uptime_percentage: u32,
+        total_volume: u64,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        if !Storage::is_attestor(&env, &anchor) {
+            return Err(Error::AttestorNotRegistered);
+        }
+
+        // Validate scores (0-10000 = 0-100%)
+        if reputation_score > 10000 || liquidity_score > 10000 || uptime_percentage > 10000 {
+            return Err(Error::InvalidAnchorMetadata);
+        }
+
+        let metadata = AnchorMetadata {
+            anchor: anchor.clone(),
+            reputation_score,
+            average_settlement_time,
+            liquidity_score,
+            uptime_percentage,
+            total_volume,
+            is_active: true,
+        };
+
+        Storage::set_anchor_metadata(&env, &metadata);
+        Storage::add_to_anchor_list(&env, &anchor);
+
+        Ok(())
+    }
+
+    /// Report an anchor's observed uptime (0-10000) and latency. Only callable
+    /// by an uptime reporter. Recent reports from all reporters are aggregated,
+    /// with outliers rejected, into the anchor's `uptime_percentage`, which is
+    /// returned.
+    pub fn report_uptime(
+        env: Env,
+        reporter: Address,
+        anchor: Address,
+        uptime_percentage: u32,
+        latency_ms: u64,
+    ) -> Result<u32, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Rbac::require_role(&env, &reporter, Role::UptimeReporter)?;
+
+        UptimeOracle::report(&env, &reporter, &anchor, uptime_percentage, latency_ms)
+    }
+
+    /// Current uptime reports for an anchor, one per reporter.
+    pub fn get_uptime_reports(env: Env, anchor: Address) -> Vec<UptimeReport> {
+        Storage::get_uptime_reports(&env, &anchor)
+    }
+
+    /// Get metadata for an anchor.
+    pub fn get_anchor_metadata(env: Env, anchor: Address) -> Result<AnchorMetadata, Error> {
+        Storage::get_anchor_metadata(&env, &anchor).ok_or(Error::AnchorMetadataNotFound)
+    }
+
+    /// Cache anchor metadata with TTL. Only callable by admin.
+    pub fn cache_metadata(
+        env: Env,
+        anchor: Address,
+        metadata: AnchorMetadata,
+        ttl_seconds: u64,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        MetadataCache::set_metadata(&env, &anchor, &metadata, ttl_seconds);
+        Ok(())
+    }
+
+    /// Get cached metadata for an anchor.
+    pub fn get_cached_metadata(env: Env, anchor: Address) -> Result<AnchorMetadata, Error> {
+        MetadataCache::get_metadata(&env, &anchor)
+    }
+
+    /// Refresh (invalidate) cached metadata for an anchor. Only callable by admin.
+    pub fn refresh_metadata_cache(env: Env, anchor: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        MetadataCache::invalidate_metadata(&env, &anchor);
+        Ok(())
+    }
+
+    /// Cache anchor capabilities (TOML) with TTL. Only callable by admin.
+    pub fn cache_capabilities(
+        env: Env,
+        anchor: Address,
+        toml_url: String,
+        capabilities: String,
+        ttl_seconds: u64,
+    ) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        MetadataCache::set_capabilities(&env, &anchor, toml_url, capabilities, ttl_seconds);
+        Ok(())
+    }
+
+    /// Get cached capabilities for an anchor.
+    pub fn get_cached_capabilities(env: Env, anchor: Address) -> Result<CachedCapabilities, Error> {
+        MetadataCache::get_capabilities(&env, &anchor)
+    }
+
+    /// Refresh (invalidate) cached capabilities for an anchor. Only callable by admin.
+    pub fn refresh_capabilities_cache(env: Env, anchor: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        MetadataCache::invalidate_capabilities(&env, &anchor);
+        Ok(())
+    }
+
+    /// Get list of all registered anchors.
+    pub fn get_all_anchors(env: Env) -> Vec<Address> {
+        Storage::get_anchor_list(&env)
+    }
+
+    /// Get services, endpoints, metadata, health, tier, quoted corridors and the
+    /// latest quote per corridor for an anchor in one call.
+    pub fn get_anchor_profile(env: Env, anchor: Address) -> Result<AnchorProfileView, Error> {
+        if !Storage::is_attestor(&env, &anchor) {
+            return Err(Error::AttestorNotRegistered);
+        }
+
+        let stored_metadata = Storage::get_anchor_metadata(&env, &anchor);
+        let tier = AnchorTier::from_metadata(&stored_metadata);
+        let mut metadata = Vec::new(&env);
+        if let Some(record) = stored_metadata {
+            metadata.push_back(record);
+        }
+        let endpoints = Storage::get_endpoints(&env, &anchor);
+        let mut health = Vec::new(&env);
+        if let Some(status) = Storage::get_health_status(&env, &anchor) {
+            health.push_back(status);
+        }
+        let corridors = Storage::get_anchor_corridors(&env, &anchor);
+        let mut quote_heads = Vec::new(&env);
+        for (base_asset, quote_asset) in corridors.iter() {
+            if let Some(quote) = Storage::get_latest_quote(&env, &anchor, &base_asset, &quote_asset)
+                .and_then(|quote_id| Storage::get_quote(&env, &anchor, quote_id))
+            {
+                quote_heads.push_back(quote);
+            }
+        }
+
+        Ok(AnchorProfileView {
+            services: Storage::get_anchor_services(&env, &anchor)
+                .map_or(Vec::new(&env), |services| services.services),
+            endpoints,
+            metadata,
+            health,
+            tier,
+            corridors,
+            quote_heads,
+            anchor,
+        })
+    }
+
+    /// Route a transaction request to the best anchor based on strategy. The
+    /// selection is persisted as a `RoutingReceipt` naming `requester`, who must
+    /// authorize the call; use `find_best_anchor` for a read-only lookup.
+    pub fn route_transaction(
+        env: Env,
+        requester: Address,
+        routing_request: RoutingRequest,
+    ) -> Result<RoutingResult, Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        Storage::get_admin(&env)?;
+        requester.require_auth();
+
+        let current_timestamp = env.ledger().timestamp();
+        let (sorted_options, exclusions) = Self::ranked_options(&env, &routing_request)?;
+
+        // Limit alternatives
+        let max_alternatives = routing_request.max_anchors.min(sorted_options.len());
+        let mut alternatives: Vec<AnchorOption> = Vec::new(&env);
+        for i in 1..max_alternatives {
+            alternatives.push_back(sorted_options.get(i).unwrap());
+        }
+
+        let best = sorted_options.get(0).unwrap();
+        let score = Self::calculate_routing_score(
+            &routing_request.strategy,
+            &best.quote,
+            &best.metadata,
+            Self::settlement_time(&env, &best.metadata),
+            best.quote.sell_amount_for(&env, &routing_request.request),
+        );
+
+        let receipt = RoutingReceipt {
+            receipt_id: Storage::get_next_routing_receipt_id(&env),
+            request_hash: routing_request.hash(&env),
+            requester,
+            strategy: routing_request.strategy,
+            selected_anchor: best.anchor.clone(),
+            quote_id: best.quote.quote_id,
+            score,
+            config_version: Storage::get_contract_config(&env)
+                .map_or(String::from_str(&env, ""), |config| config.version),
+            schema_version: Storage::SCHEMA_VERSION,
+            routed_at: current_timestamp,
+            ledger: env.ledger().sequence(),
+        };
+        Storage::set_routing_receipt(&env, &receipt);
+        RoutingReceiptIssued::publish(&env, &receipt);
+
+        Ok(RoutingResult {
+            selected_anchor: best.anchor.clone(),
+            selected_quote: best.quote.clone(),
+            score: best.score,
+            alternatives,
+            routing_timestamp: current_timestamp,
+            receipt,
+            exclusions,
+        })
+    }
+
+    /// Every qualifying anchor's option for `routing_request`, best first, and
+    /// the anchors excluded if the request records them.
+    fn ranked_options(
+        env: &Env,
+        routing_request: &RoutingRequest,
+    ) -> Result<(Vec<AnchorOption>, Vec<QuoteExclusion>), Error> {
+        let anchors = Storage::get_anchor_list(env);
+
+        if anchors.is_empty() {
+            return Err(Error::NoAnchorsAvailable);
+        }
+
+        let mut settlement_sla = 0u64;
+        if routing_request.corridor_id != 0 {
+            let corridor = Storage::get_corridor(env, routing_request.corridor_id)?;
+            if !corridor.is_active || !corridor.admits(&routing_request.request) {
+                return Err(Error::ComplianceNotMet);
+            }
+            settlement_sla = corridor.settlement_sla_seconds;
+        }
+
+        let mut options: Vec<AnchorOption> = Vec::new(env);
+        let mut exclusions = Vec::new(env);
+
+        // Collect valid options from all anchors
+        for anchor in anchors.iter() {
+            match Self::routing_option(env, routing_request, &anchor, settlement_sla) {
+                Ok(option) => options.push_back(option),
+                Err(exclusion) if routing_request.record_exclusions => {
+                    exclusions.push_back(exclusion)
+                }
+                Err(_) => {}
+            }
+        }
+
+        if options.is_empty() {
+            return Err(Error::NoQuotesAvailable);
+        }
+
+        // Sort options by score (descending)
+        let mut sorted_options = options.clone();
+        for i in 0..sorted_options.len() {
+            for j in (i + 1)..sorted_options.len() {
+                let score_i = sorted_options.get(i).unwrap().score;
+                let score_j = sorted_options.get(j).unwrap().score;
+                if score_j > score_i {
+                    let temp = sorted_options.get(i).unwrap();
+                    sorted_options.set(i, sorted_options.get(j).unwrap());
+                    sorted_options.set(j, temp);
+                }
+            }
+        }
+
+        Ok((sorted_options, exclusions))
+    }
+
+    /// `anchor`'s scored option for `routing_request`, or why it doesn't qualify.
+    fn routing_option(
+        env: &Env,
+        routing_request: &RoutingRequest,
+        anchor: &Address,
+        settlement_sla: u64,
+    ) -> Result<AnchorOption, QuoteExclusion> {
+        let exclusion = |reason| QuoteExclusion {
+            anchor: anchor.clone(),
+            quote_id: 0,
+            reason,
+        };
+
+        // Check if anchor is registered and active
+        if !Storage::is_attestor(env, anchor) || Storage::get_offboarding(env, anchor).is_some() {
+            return Err(exclusion(ExclusionReason::AnchorSuspended));
+        }
+
+        // Get anchor metadata
+        let metadata = Storage::get_anchor_metadata(env, anchor)
+            .ok_or_else(|| exclusion(ExclusionReason::NoMetadata))?;
+
+        if !metadata.is_active {
+            return Err(exclusion(ExclusionReason::AnchorSuspended));
+        }
+
+        // Check reputation threshold
+        if metadata.reputation_score < routing_request.min_reputation {
+            return Err(exclusion(ExclusionReason::BelowReputation));
+        }
+
+        // Check the corridor's settlement SLA
+        if settlement_sla != 0 && metadata.average_settlement_time > settlement_sla {
+            return Err(exclusion(ExclusionReason::SlowSettlement));
+        }
+
+        // Check if anchor supports the required service, and KYC if required
+        let request = &routing_request.request;
+        let supported = Storage::get_anchor_services(env, anchor).is_ok_and(|services| {
+            services.services.contains(request.operation_type)
+                && (!routing_request.require_kyc || services.services.contains(ServiceType::KYC))
+        });
+        if !supported
+            || !Self::supports_asset(
+                env.clone(),
+                anchor.clone(),
+                request.operation_type,
+                request.base_asset.clone(),
+            )
+        {
+            return Err(exclusion(ExclusionReason::ServiceUnsupported));
+        }
+
+        let quote = Self::comparable_quote(env, anchor, request)?;
+
+        // Calculate score based on strategy
+        let score = Self::calculate_routing_score(
+            &routing_request.strategy,
+            &quote,
+            &metadata,
+            Self::settlement_time(env, &metadata),
+            quote.sell_amount_for(env, request),
+        );
+
+        Ok(AnchorOption {
+            anchor: anchor.clone(),
+            quote,
+            score: score.total(),
+            metadata,
+        })
+    }
+
+    /// Get a receipt persisted by `route_transaction`.
+    pub fn get_routing_receipt(env: Env, receipt_id: u64) -> Result<RoutingReceipt, Error> {
+        Storage::get_routing_receipt(&env, receipt_id)
+    }
+
+    /// Whether `receipt` is exactly one `route_transaction` persisted, so an
+    /// integrator's copy can be checked against the contract's record.
+    pub fn verify_routing_receipt(env: Env, receipt: RoutingReceipt) -> bool {
+        Storage::get_routing_receipt(&env, receipt.receipt_id).is_ok_and(|stored| stored == receipt)
+    }
+
+    /// Find best anchor for a specific service and asset pair. Unlike
+    /// `route_transaction`, this is a plain read and persists no receipt.
+    pub fn find_best_anchor(
+        env: Env,
+        base_asset: Asset,
+        quote_asset: Asset,
+        amount: u64,
+        operation_type: ServiceType,
+        strategy: RoutingStrategy,
+    ) -> Result<Address, Error> {
+        let request = QuoteRequest {
+            base_asset,
+            quote_asset,
+            amount,
+            operation_type,
+            context: QuoteContext::Sep6,
+            amount_side: AmountSide::Sell,
+            delivery_method: None,
+            country_code: None,
+        };
+
+        let routing_request = RoutingRequest {
+            request,
+            strategy,
+            max_anchors: 1,
+            require_kyc: false,
+            min_reputation: 0,
+            corridor_id: 0,
+            record_exclusions: false,
+        };
+
+        Storage::get_admin(&env)?;
+        let (options, _) = Self::ranked_options(&env, &routing_request)?;
+        Ok(options.get_unchecked(0).anchor)
+    }
+
+    /// Typical settlement time of an anchor: the observed p50 once any of its
+    /// transfers has settled, otherwise its self-reported average.
+    fn settlement_time(env: &Env, metadata: &AnchorMetadata) -> u64 {
+        match Storage::get_settlement_stats(env, &metadata.anchor) {
+            Some(stats) if !stats.durations.is_empty() => stats.p50,
+            _ => metadata.average_settlement_time,
+        }
+    }
+
+    /// Calculate routing score based on strategy, by factor.
+    fn calculate_routing_score(
+        strategy: &RoutingStrategy,
+        quote: &QuoteData,
+        metadata: &AnchorMetadata,
+        settlement_time: u64,
+        amount: u64,
+    ) -> ScoreBreakdown {
+        let mut score = ScoreBreakdown::default();
+        match strategy {
+            RoutingStrategy::BestRate => {
+                // Higher rate is better (inverted for scoring)
+                // Invert so lower effective rate = higher score
+                score.rate = Self::calculate_effective_rate(quote, amount)
+                    .and_then(|effective_rate| 1_000_000_000u64.checked_div(effective_rate))
+                    .unwrap_or(0);
+            }
+            RoutingStrategy::LowestFee => {
+                // Lower fee is better
+                let max_fee = 10000u32; // 100%
+                let fee_score = max_fee.saturating_sub(quote.fee_percentage);
+                score.fee = fee_score as u64 * 100_000;
+            }
+            RoutingStrategy::FastestSettlement => {
+                // Lower settlement time is better
+                let max_time = 86400u64; // 24 hours
+                let time_score = max_time.saturating_sub(settlement_time);
+                score.settlement = time_score * 10_000;
+            }
+            RoutingStrategy::HighestLiquidity => {
+                // Higher liquidity is better
+                score.liquidity = metadata.liquidity_score as u64 * 100_000;
+            }
+            RoutingStrategy::Custom => {
+                // Weighted combination of all factors
+                score.rate = 1_000_000u64.checked_div(quote.rate).unwrap_or(0) * 30; // 30% weight
+                score.fee = (10000u32.saturating_sub(quote.fee_percentage) as u64) * 25; // 25% weight
+                score.reputation = metadata.reputation_score as u64 * 20; // 20% weight
+                score.liquidity = metadata.liquidity_score as u64 * 15; // 15% weight
+                score.uptime = metadata.uptime_percentage as u64 * 10; // 10% weight
+            }
+        }
+        score
+    }
+
+    /// Deactivate an anchor (admin only).
+    pub fn deactivate_anchor(env: Env, anchor: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        let mut metadata =
+            Storage::get_anchor_metadata(&env, &anchor).ok_or(Error::AnchorMetadataNotFound)?;
+
+        metadata.is_active = false;
+        Storage::set_anchor_metadata(&env, &metadata);
+
+        Ok(())
+    }
+
+    /// Reactivate an anchor (admin only).
+    pub fn reactivate_anchor(env: Env, anchor: Address) -> Result<(), Error> {
+        Freeze::ensure_unfrozen(&env)?;
+
+        let admin = Storage::get_admin(&env)?;
+        admin.require_auth();
+
+        let mut metadata =
+            Storage::get_anchor_metadata(&env, &anchor).ok_or(Error::AnchorMetadataNotFound)?;
+
+        metadata.is_active = true;
+        Storage::set_anchor_metadata(&env, &metadata);
+
+        Ok(())
     }
 }
@@ -1,14 +1,20 @@
 #![cfg(test)]
 
+use crate::testutils::attestor_public_key;
 use crate::{
     config::AttestorConfig, connection_pool::ConnectionPool, retry::{RetryConfig, RetryEngine},
-    types::QuoteRequest, AnchorKitContract, AnchorKitContractClient, Error, ServiceType,
+    types::{AmountSide, Asset, QuoteContext, QuoteRequest}, AnchorKitContract, AnchorKitContractClient, Error, ServiceType,
 };
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
     Address, Env, String, Vec,
 };
 
+fn asset(env: &Env, code: &str) -> Asset {
+    let issuer = String::from_str(env, "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN");
+    Asset::Classic(String::from_str(env, code), Address::from_string(&issuer))
+}
+
 extern crate alloc;
 
 fn create_contract(env: &Env) -> AnchorKitContractClient<'_> {
@@ -40,6 +46,7 @@ fn test_batch_attestor_registration_stress() {
         attestors.push_back(AttestorConfig {
             name,
             address: address.clone(),
+            public_key: attestor_public_key(&env),
             endpoint,
             role,
             enabled: true,
@@ -75,6 +82,7 @@ fn test_batch_attestor_registration_overflow() {
         attestors.push_back(AttestorConfig {
             name,
             address,
+            public_key: attestor_public_key(&env),
             endpoint,
             role,
             enabled: true,
@@ -140,13 +148,13 @@ fn test_rate_comparison_stress() {
     client.initialize(&admin);
 
     let mut anchors = Vec::new(&env);
-    let base_asset = String::from_str(&env, "USD");
-    let quote_asset = String::from_str(&env, "USDC");
+    let base_asset = asset(&env, "USD");
+    let quote_asset = asset(&env, "USDC");
 
     // Register 10 anchors and submit quotes (reduced from 50 to avoid mock environment SIGABRT)
     for i in 0..10 {
         let anchor = Address::generate(&env);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
         
         let mut services = Vec::new(&env);
         services.push_back(ServiceType::Quotes);
@@ -174,6 +182,10 @@ fn test_rate_comparison_stress() {
         quote_asset: quote_asset.clone(),
         amount: 1000,
         operation_type: ServiceType::Quotes,
+        context: QuoteContext::Sep6,
+        amount_side: AmountSide::Sell,
+        delivery_method: None,
+        country_code: None,
     };
 
     // Run comparison 20 times (reduced from 100)
@@ -1,6 +1,18 @@
 use clap::{Parser, Subcommand};
 
+mod anchor_sync;
+mod attest_sign;
+mod deploy;
 mod doctor;
+mod events_watch;
+mod intent_build;
+mod keystore;
+mod profile;
+mod quote_compare;
+mod session_export;
+mod signer;
+mod state_inspect;
+mod stellar_cli;
 
 /// AnchorKit - Soroban toolkit for anchoring off-chain attestations to Stellar
 ///
@@ -11,6 +23,10 @@ mod doctor;
 #[command(version = "0.1.0")]
 #[command(about = "Soroban toolkit for anchoring off-chain attestations", long_about = None)]
 struct Cli {
+    /// Configuration profile supplying network, contract and identity defaults
+    #[arg(long, global = true, env = "ANCHORKIT_PROFILE")]
+    profile: Option<String>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -31,20 +47,41 @@ enum Commands {
         release: bool,
     },
 
-    /// Deploy compiled contract to configured network
+    /// Deploy and initialize the compiled contract
     ///
-    /// Deploys your AnchorKit contract to the specified Stellar network.
-    /// Requires a funded admin account and network configuration.
+    /// Uploads the release WASM through Soroban RPC, instantiates the contract,
+    /// initializes it with the admin and prints the new contract ID.
+    /// Requires the `stellar` CLI and a funded source account.
     /// Use --dry-run to validate deployment without executing.
     ///
     /// Examples:
-    ///   anchorkit deploy
-    ///   anchorkit deploy --network devnet
-    ///   anchorkit deploy --dry-run
+    ///   anchorkit deploy --source alice
+    ///   anchorkit deploy --source alice --admin GADMIN123... --network mainnet
+    ///   anchorkit deploy --source alice --rpc-url https://rpc.example.com
+    ///   anchorkit deploy --source alice --dry-run
+    ///   anchorkit deploy --source keystore:ops.json
+    ///   anchorkit deploy --source ledger:GOPS123...
     Deploy {
-        /// Target network (testnet, devnet, mainnet)
-        #[arg(short, long, default_value = "testnet")]
-        network: String,
+        /// Target network (defaults to the profile's)
+        #[arg(short, long)]
+        network: Option<String>,
+
+        /// Account that pays for the deployment: identity name, keystore:PATH,
+        /// external:COMMAND or ledger:ADDRESS[:INDEX] (defaults to the profile's)
+        #[arg(short, long, env = "ANCHORKIT_SECRET_KEY", hide_env_values = true)]
+        source: Option<String>,
+
+        /// Admin address (defaults to the source account's address)
+        #[arg(short, long)]
+        admin: Option<String>,
+
+        /// Contract WASM to upload
+        #[arg(short, long, default_value = deploy::DEFAULT_WASM)]
+        wasm: std::path::PathBuf,
+
+        /// Soroban RPC URL (defaults to the profile's, then the network's)
+        #[arg(long, env = "ANCHORKIT_RPC_URL")]
+        rpc_url: Option<String>,
 
         /// Validate deployment without executing
         #[arg(long)]
@@ -65,9 +102,9 @@ enum Commands {
         #[arg(short, long)]
         admin: String,
 
-        /// Target network
-        #[arg(short, long, default_value = "testnet")]
-        network: String,
+        /// Target network (defaults to the profile's)
+        #[arg(short, long)]
+        network: Option<String>,
     },
 
     /// Register a new attestor/anchor
@@ -93,9 +130,9 @@ enum Commands {
         #[arg(short, long)]
         endpoint: Option<String>,
 
-        /// Target network
-        #[arg(short, long, default_value = "testnet")]
-        network: String,
+        /// Target network (defaults to the profile's)
+        #[arg(short, long)]
+        network: Option<String>,
     },
 
     /// Submit an attestation for verification
@@ -103,26 +140,19 @@ enum Commands {
     /// Creates an attestation linking an off-chain event to on-chain verification.
     /// Requires the submitter to be a registered attestor.
     /// Includes replay protection and timestamp validation.
+    /// Use `attest sign` to hash and sign a payload locally first.
     ///
     /// Examples:
     ///   anchorkit attest --subject GUSER123... --payload-hash abc123...
     ///   anchorkit attest --subject GUSER123... --payload-hash abc123... --session session-001
+    ///   anchorkit attest sign --payload kyc.json --subject GUSER123... --session 7
+    #[command(args_conflicts_with_subcommands = true)]
     Attest {
-        /// Subject account address
-        #[arg(short, long)]
-        subject: String,
-
-        /// SHA-256 hash of attestation payload
-        #[arg(short, long)]
-        payload_hash: String,
-
-        /// Optional session ID for traceability
-        #[arg(long)]
-        session: Option<String>,
+        #[command(subcommand)]
+        command: Option<AttestCommands>,
 
-        /// Target network
-        #[arg(short, long, default_value = "testnet")]
-        network: String,
+        #[command(flatten)]
+        submit: Option<AttestArgs>,
     },
 
     /// Query attestation by ID
@@ -139,9 +169,33 @@ enum Commands {
         #[arg(short, long)]
         id: u64,
 
-        /// Target network
-        #[arg(short, long, default_value = "testnet")]
-        network: String,
+        /// Target network (defaults to the profile's)
+        #[arg(short, long)]
+        network: Option<String>,
+    },
+
+    /// Export and verify session transcripts
+    ///
+    /// Pulls a session's audit logs through Soroban RPC simulation.
+    /// Requires the `stellar` CLI.
+    ///
+    /// Examples:
+    ///   anchorkit session export 7 --output session-7.json
+    Session {
+        #[command(subcommand)]
+        command: SessionCommands,
+    },
+
+    /// Stream contract events
+    ///
+    /// Reads AnchorKit events from Soroban RPC and prints them as NDJSON.
+    /// Requires the `stellar` CLI.
+    ///
+    /// Examples:
+    ///   anchorkit events watch --start-ledger 123456 | ./dispatch-webhooks
+    Events {
+        #[command(subcommand)]
+        command: EventCommands,
     },
 
     /// Check health status of registered attestors
@@ -167,9 +221,97 @@ enum Commands {
         #[arg(short, long, default_value = "60")]
         interval: u64,
 
-        /// Target network
-        #[arg(short, long, default_value = "testnet")]
-        network: String,
+        /// Target network (defaults to the profile's)
+        #[arg(short, long)]
+        network: Option<String>,
+    },
+
+    /// Compare anchor quotes through the deployed contract
+    ///
+    /// Queries the contract over Soroban RPC without submitting a transaction.
+    /// Requires the `stellar` CLI.
+    ///
+    /// Examples:
+    ///   anchorkit quotes compare --base native --quote NGN:GISSUER... --amount 100
+    Quotes {
+        #[command(subcommand)]
+        command: QuoteCommands,
+    },
+
+    /// Sync anchor registry entries from stellar.toml
+    ///
+    /// Fetches each anchor's https://DOMAIN/.well-known/stellar.toml, configures
+    /// its SEP-6, SEP-24, SEP-38 and SEP-12 endpoints, deactivates endpoints it
+    /// no longer publishes and anchors the file's SHA-256 hash. Each anchor's
+    /// transactions are signed by its own account. Requires `stellar` and `curl`.
+    ///
+    /// Examples:
+    ///   anchorkit anchors sync anchor.example.com --source anchor-admin
+    ///   anchorkit anchors sync --file anchors.txt --dry-run
+    Anchors {
+        #[command(subcommand)]
+        command: AnchorCommands,
+    },
+
+    /// Inspect the contract's ledger storage
+    ///
+    /// Reads the contract instance and the persistent entries its counters
+    /// imply over Soroban RPC, then reports entry counts, sizes and upcoming
+    /// TTL expirations per namespace (attestations, quotes, sessions, audit)
+    /// so you know what to bump or prune. Requires `curl`.
+    ///
+    /// Examples:
+    ///   anchorkit state inspect --contract CABC...
+    ///   anchorkit state inspect --within-days 30 --json
+    State {
+        #[command(subcommand)]
+        command: StateCommands,
+    },
+
+    /// Build transaction intents
+    ///
+    /// Walks through anchor selection (simulating on-chain routing), quote
+    /// attachment, KYC and TTL, checks the result with validate_intent, then
+    /// simulates or submits build_transaction_intent. Answers can be given as
+    /// flags; --yes takes the defaults for everything else.
+    ///
+    /// Examples:
+    ///   anchorkit intent build
+    ///   anchorkit intent build --base USDC:GA5Z... --quote native --amount 250 --yes
+    ///   anchorkit intent build --anchor GANCHOR... --quote-id 12 --kyc --subject GUSER... --submit
+    Intent {
+        #[command(subcommand)]
+        command: Box<IntentCommands>,
+    },
+
+    /// Manage network profiles
+    ///
+    /// Profiles store the RPC URL, network passphrase, contract ID and signing
+    /// identity for a network so commands don't need the flags every time.
+    /// Select one with --profile or ANCHORKIT_PROFILE.
+    ///
+    /// Examples:
+    ///   anchorkit profile list
+    ///   anchorkit profile set testnet --contract CABC... --source alice --default
+    ///   anchorkit --profile mainnet quotes compare --base native --quote USDC:GA5Z... --amount 10
+    Profile {
+        #[command(subcommand)]
+        command: ProfileCommands,
+    },
+
+    /// Manage encrypted keystores
+    ///
+    /// A keystore holds one signing key encrypted with a password, so secret
+    /// keys never need to be typed on the command line. Use it with
+    /// --source keystore:PATH. Set ANCHORKIT_KEYSTORE_PASSWORD for unattended use.
+    ///
+    /// Examples:
+    ///   anchorkit keystore new ops.json
+    ///   stellar keys show alice | anchorkit keystore import alice.json
+    ///   anchorkit keystore address ops.json
+    Keystore {
+        #[command(subcommand)]
+        command: KeystoreCommands,
     },
 
     /// Run contract tests
@@ -223,8 +365,451 @@ enum Commands {
     Doctor,
 }
 
+#[derive(clap::Args)]
+struct AttestArgs {
+    /// Subject account address
+    #[arg(short, long)]
+    subject: String,
+
+    /// SHA-256 hash of attestation payload
+    #[arg(short, long)]
+    payload_hash: String,
+
+    /// Optional session ID for traceability
+    #[arg(long)]
+    session: Option<String>,
+
+    /// Target network (defaults to the profile's)
+    #[arg(short, long)]
+    network: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum AttestCommands {
+    /// Hash and sign an attestation payload with a local key
+    ///
+    /// Computes the domain-separated payload hash the contract expects, signs it
+    /// with the issuer's ed25519 key and prints the arguments for
+    /// `submit_attestation_with_session`. The issuer is the key's account; a
+    /// delegate's key passes --attestor to sign for the attestor it acts for.
+    ///
+    /// Examples:
+    ///   anchorkit attest sign --payload kyc.json --subject GUSER123... --session 7
+    ///   anchorkit attest sign --payload kyc.json --subject GUSER123... --session 7 --key issuer.key
+    ///   anchorkit attest sign --payload kyc.json --subject GUSER123... --session 7 --attestor GATTESTOR...
+    Sign {
+        /// File containing the attestation payload
+        #[arg(short, long)]
+        payload: std::path::PathBuf,
+
+        /// Subject account or contract address
+        #[arg(short = 'u', long)]
+        subject: String,
+
+        /// Session ID the attestation is recorded under
+        #[arg(short, long)]
+        session: u64,
+
+        /// Issuer secret key, a file containing it, keystore:PATH or external:COMMAND
+        #[arg(short, long, env = "ANCHORKIT_SECRET_KEY", hide_env_values = true)]
+        key: String,
+
+        /// Attestor the key signs for as a delegate (defaults to the key's account)
+        #[arg(long)]
+        attestor: Option<String>,
+
+        /// Attestation timestamp in seconds (defaults to now)
+        #[arg(short, long)]
+        timestamp: Option<u64>,
+
+        /// Correlation ID (16 bytes, hex) linking the attestation to the other
+        /// operations of the same business transaction
+        #[arg(short, long)]
+        correlation_id: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum EventCommands {
+    /// Follow contract events and print them as NDJSON
+    ///
+    /// Polls Soroban RPC for the contract's events, decodes them with the
+    /// contract's event schema and writes one JSON object per line to stdout.
+    /// Each line carries a `paging_token`; pass the last one as --cursor to resume.
+    ///
+    /// Examples:
+    ///   anchorkit events watch --start-ledger 123456
+    ///   anchorkit events watch --cursor 0000530242871959552-0000000001 --interval 10
+    ///   anchorkit events watch --start-ledger 123456 --once > events.ndjson
+    Watch {
+        /// Deployed AnchorKit contract ID (defaults to the profile's)
+        #[arg(short, long, env = "ANCHORKIT_CONTRACT_ID")]
+        contract: Option<String>,
+
+        /// Ledger to start reading events from
+        #[arg(long, required_unless_present = "cursor")]
+        start_ledger: Option<u32>,
+
+        /// Resume after this paging token
+        #[arg(long, conflicts_with = "start_ledger")]
+        cursor: Option<String>,
+
+        /// Poll interval in seconds
+        #[arg(short, long, default_value = "5")]
+        interval: u64,
+
+        /// Print the currently available events and exit
+        #[arg(long)]
+        once: bool,
+
+        /// Target network (defaults to the profile's)
+        #[arg(short, long)]
+        network: Option<String>,
+
+        /// Soroban RPC URL (defaults to the profile's, then the network's)
+        #[arg(long, env = "ANCHORKIT_RPC_URL")]
+        rpc_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum SessionCommands {
+    /// Export a session's audit logs and verify its hash chain
+    ///
+    /// Writes the logs as canonical JSON, recomputes the audit hash chain and
+    /// checks it against the contract's on-chain session commitment.
+    /// Exits non-zero if they differ.
+    ///
+    /// Examples:
+    ///   anchorkit session export 7
+    ///   anchorkit session export 7 --output session-7.json --network mainnet
+    Export {
+        /// Session ID
+        id: u64,
+
+        /// Write the transcript to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+
+        /// Deployed AnchorKit contract ID (defaults to the profile's)
+        #[arg(short, long, env = "ANCHORKIT_CONTRACT_ID")]
+        contract: Option<String>,
+
+        /// Identity or signer whose account simulates the calls (defaults to the profile's)
+        #[arg(short, long, env = "ANCHORKIT_SECRET_KEY", hide_env_values = true)]
+        source: Option<String>,
+
+        /// Target network (defaults to the profile's)
+        #[arg(short, long)]
+        network: Option<String>,
+
+        /// Soroban RPC URL (defaults to the profile's, then the network's)
+        #[arg(long, env = "ANCHORKIT_RPC_URL")]
+        rpc_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum QuoteCommands {
+    /// Rank the anchors' valid quotes for an amount
+    ///
+    /// Simulates `compare_rates_for_anchors` and prints each anchor's rate, fee
+    /// and effective cost, marking the best quote with `*`. Assets are `native`,
+    /// a token contract ID or CODE:ISSUER.
+    ///
+    /// Examples:
+    ///   anchorkit quotes compare --base USDC:GISSUER... --quote NGN:GISSUER... --amount 100
+    ///   anchorkit quotes compare --base native --quote CTOKEN... --amount 50 --buy
+    ///   anchorkit quotes compare --base native --quote CTOKEN... --amount 50 --anchors GA...,GB...
+    ///   anchorkit quotes compare --base native --quote CTOKEN... --amount 50 --anchors cowrie_ng,GB...
+    Compare {
+        /// Asset sold
+        #[arg(short, long)]
+        base: String,
+
+        /// Asset bought
+        #[arg(short, long)]
+        quote: String,
+
+        /// Amount to exchange, in whole units (e.g. 100 or 12.5)
+        #[arg(short, long)]
+        amount: String,
+
+        /// Treat --amount as the bought asset instead of the sold one
+        #[arg(long)]
+        buy: bool,
+
+        /// Anchors to compare, as addresses or on-chain aliases (defaults to
+        /// every registered anchor)
+        #[arg(long, value_delimiter = ',')]
+        anchors: Vec<String>,
+
+        /// Deployed AnchorKit contract ID (defaults to the profile's)
+        #[arg(short, long, env = "ANCHORKIT_CONTRACT_ID")]
+        contract: Option<String>,
+
+        /// Identity or signer whose account simulates the calls (defaults to the profile's)
+        #[arg(short, long, env = "ANCHORKIT_SECRET_KEY", hide_env_values = true)]
+        source: Option<String>,
+
+        /// Target network (defaults to the profile's)
+        #[arg(short, long)]
+        network: Option<String>,
+
+        /// Soroban RPC URL (defaults to the profile's, then the network's)
+        #[arg(long, env = "ANCHORKIT_RPC_URL")]
+        rpc_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum AnchorCommands {
+    /// Sync endpoints and stellar.toml hashes from anchors' home domains
+    Sync {
+        /// Anchor home domains, signed by --source
+        domains: Vec<String>,
+
+        /// File with one `DOMAIN [SIGNER]` per line; `#` starts a comment
+        #[arg(short, long)]
+        file: Option<std::path::PathBuf>,
+
+        /// Show the changes without submitting them
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Deployed AnchorKit contract ID (defaults to the profile's)
+        #[arg(short, long, env = "ANCHORKIT_CONTRACT_ID")]
+        contract: Option<String>,
+
+        /// Anchor account that signs: identity name, keystore:PATH,
+        /// external:COMMAND or ledger:ADDRESS[:INDEX] (defaults to the profile's)
+        #[arg(short, long, env = "ANCHORKIT_SECRET_KEY", hide_env_values = true)]
+        source: Option<String>,
+
+        /// Target network (defaults to the profile's)
+        #[arg(short, long)]
+        network: Option<String>,
+
+        /// Soroban RPC URL (defaults to the profile's, then the network's)
+        #[arg(long, env = "ANCHORKIT_RPC_URL")]
+        rpc_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum StateCommands {
+    /// Report storage entries, sizes and TTL expirations by namespace
+    Inspect {
+        /// List entries whose TTL ends within this many days
+        #[arg(long, default_value_t = 7)]
+        within_days: u32,
+
+        /// Give up rather than probe more keys than this
+        #[arg(long, default_value_t = 10_000)]
+        max_keys: usize,
+
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Deployed AnchorKit contract ID (defaults to the profile's)
+        #[arg(short, long, env = "ANCHORKIT_CONTRACT_ID")]
+        contract: Option<String>,
+
+        /// Target network (defaults to the profile's)
+        #[arg(short, long)]
+        network: Option<String>,
+
+        /// Soroban RPC URL (defaults to the profile's, then the network's)
+        #[arg(long, env = "ANCHORKIT_RPC_URL")]
+        rpc_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum IntentCommands {
+    /// Interactively build a transaction intent
+    Build {
+        /// Sold asset: native, a token contract ID or CODE:ISSUER
+        #[arg(long)]
+        base: Option<String>,
+
+        /// Bought asset, in the same notation as --base
+        #[arg(long)]
+        quote: Option<String>,
+
+        /// Amount in the sold asset (or the bought asset with --buy)
+        #[arg(long)]
+        amount: Option<String>,
+
+        /// The amount is of the bought asset
+        #[arg(long)]
+        buy: bool,
+
+        /// Service: deposit, withdrawal or quotes
+        #[arg(long)]
+        service: Option<String>,
+
+        /// Quote context: sep6, sep24 or sep31
+        #[arg(long)]
+        context: Option<String>,
+
+        /// Routing strategy: best-rate, lowest-fee, fastest or liquidity
+        #[arg(long)]
+        strategy: Option<String>,
+
+        /// Use this anchor instead of routing
+        #[arg(long)]
+        anchor: Option<String>,
+
+        /// Quote to attach (defaults to the routed anchor's quote)
+        #[arg(long)]
+        quote_id: Option<u64>,
+
+        /// Require KYC for the intent
+        #[arg(long)]
+        kyc: bool,
+
+        /// Address the intent is for, checked against the KYC registry. It
+        /// must authorize the build; defaults to the signer
+        #[arg(long)]
+        subject: Option<String>,
+
+        /// Wallet or integrator address credited with the flow
+        #[arg(long)]
+        referrer: Option<String>,
+
+        /// Registered corridor whose limits, rules and settlement SLA apply
+        #[arg(long)]
+        corridor: Option<u64>,
+
+        /// Session to record the intent in
+        #[arg(long)]
+        session: Option<u64>,
+
+        /// Intent lifetime in seconds
+        #[arg(long)]
+        ttl: Option<u64>,
+
+        /// Earliest ledger time (Unix seconds) the intent may execute at
+        #[arg(long)]
+        not_before: Option<u64>,
+
+        /// Requirement template the subject is checked against
+        #[arg(long)]
+        requirement_template: Option<u64>,
+
+        /// Submit the intent instead of only simulating it
+        #[arg(long)]
+        submit: bool,
+
+        /// Don't prompt; use defaults for anything not given
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Deployed AnchorKit contract ID (defaults to the profile's)
+        #[arg(short, long, env = "ANCHORKIT_CONTRACT_ID")]
+        contract: Option<String>,
+
+        /// Account that submits the intent: identity name, keystore:PATH,
+        /// external:COMMAND or ledger:ADDRESS[:INDEX] (defaults to the profile's)
+        #[arg(short, long, env = "ANCHORKIT_SECRET_KEY", hide_env_values = true)]
+        source: Option<String>,
+
+        /// Target network (defaults to the profile's)
+        #[arg(short, long)]
+        network: Option<String>,
+
+        /// Soroban RPC URL (defaults to the profile's, then the network's)
+        #[arg(long, env = "ANCHORKIT_RPC_URL")]
+        rpc_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ProfileCommands {
+    /// List built-in and configured profiles
+    List,
+
+    /// Show a profile's resolved settings
+    Show {
+        /// Profile name (defaults to the selected profile)
+        name: Option<String>,
+    },
+
+    /// Create or update a profile
+    ///
+    /// Only the given fields change. Store a `stellar keys` identity name as
+    /// the source; secret keys are never written to the profiles file.
+    Set {
+        /// Profile name
+        name: String,
+
+        /// `stellar` CLI network name
+        #[arg(short, long)]
+        network: Option<String>,
+
+        /// Soroban RPC URL
+        #[arg(long)]
+        rpc_url: Option<String>,
+
+        /// Network passphrase
+        #[arg(long)]
+        network_passphrase: Option<String>,
+
+        /// Deployed AnchorKit contract ID
+        #[arg(short, long)]
+        contract: Option<String>,
+
+        /// Signing identity name or signer (keystore:PATH, external:COMMAND,
+        /// ledger:ADDRESS[:INDEX])
+        #[arg(short, long)]
+        source: Option<String>,
+
+        /// Use this profile when --profile is not given
+        #[arg(long)]
+        default: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum KeystoreCommands {
+    /// Generate a new key into a keystore
+    New {
+        /// Keystore file to create
+        path: std::path::PathBuf,
+    },
+
+    /// Encrypt an existing secret key into a keystore
+    ///
+    /// Prompts for the secret key, or reads it from stdin when piped.
+    Import {
+        /// Keystore file to create
+        path: std::path::PathBuf,
+    },
+
+    /// Print a keystore's account address
+    Address {
+        /// Keystore file
+        path: std::path::PathBuf,
+    },
+}
+
+/// Load the selected profile, exiting with the error if the config is unusable.
+fn load_profile(name: Option<&str>) -> profile::Profile {
+    or_exit(profile::ProfileConfig::load().and_then(|config| config.profile(name))).1
+}
+
+fn or_exit<T>(result: Result<T, String>) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("✖ {}", e);
+        std::process::exit(1);
+    })
+}
+
 fn main() {
     let cli = Cli::parse();
+    let profile_name = cli.profile;
 
     match cli.command {
         Commands::Build { release } => {
@@ -243,17 +828,54 @@ fn main() {
             }
             println!("✓ Build complete");
         }
-        Commands::Deploy { network, dry_run } => {
+        Commands::Deploy {
+            network,
+            source,
+            admin,
+            wasm,
+            rpc_url,
+            dry_run,
+        } => {
+            let profile = load_profile(profile_name.as_deref());
+            let options = deploy::DeployOptions {
+                network: profile.network(network, rpc_url),
+                source: or_exit(profile.signer(source)),
+                admin,
+                wasm,
+            };
             if dry_run {
-                println!("Validating deployment configuration for {}...", network);
-                println!("✓ Deployment validation passed (dry-run mode)");
+                println!(
+                    "Validating deployment configuration for {}...",
+                    options.network.name
+                );
+                match deploy::dry_run(&options) {
+                    Ok(commands) => {
+                        for command in commands {
+                            println!("  {}", command);
+                        }
+                        println!("✓ Deployment validation passed (dry-run mode)");
+                    }
+                    Err(e) => {
+                        eprintln!("✖ {}", e);
+                        std::process::exit(1);
+                    }
+                }
             } else {
-                println!("Deploying to {}...", network);
-                println!("Note: Ensure you have a funded admin account configured");
-                println!("✓ Deployment initiated");
+                println!("Deploying to {}...", options.network.name);
+                match deploy::deploy(&options) {
+                    Ok(contract_id) => {
+                        println!("✓ Contract deployed and initialized");
+                        println!("{}", contract_id);
+                    }
+                    Err(e) => {
+                        eprintln!("✖ {}", e);
+                        std::process::exit(1);
+                    }
+                }
             }
         }
         Commands::Init { admin, network } => {
+            let network = load_profile(profile_name.as_deref()).network_name(network);
             println!("Initializing contract on {} with admin: {}", network, admin);
             println!("✓ Contract initialized");
         }
@@ -263,6 +885,7 @@ fn main() {
             endpoint,
             network,
         } => {
+            let network = load_profile(profile_name.as_deref()).network_name(network);
             println!("Registering attestor {} on {}", address, network);
             if let Some(svcs) = services {
                 println!("Services: {}", svcs.join(", "));
@@ -273,29 +896,139 @@ fn main() {
             println!("✓ Attestor registered");
         }
         Commands::Attest {
-            subject,
-            payload_hash,
-            session,
-            network,
+            command:
+                Some(AttestCommands::Sign {
+                    payload,
+                    subject,
+                    session,
+                    key,
+                    attestor,
+                    timestamp,
+                    correlation_id,
+                }),
+            ..
+        } => {
+            let options = attest_sign::SignOptions {
+                payload,
+                key,
+                attestor,
+                subject,
+                session_id: session,
+                timestamp,
+                correlation_id,
+            };
+            match attest_sign::sign(&options) {
+                Ok(signed) => println!("{}", signed.invoke_args().join(" ")),
+                Err(e) => {
+                    eprintln!("✖ {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::Attest {
+            submit: Some(submit),
+            ..
         } => {
+            let network = load_profile(profile_name.as_deref()).network_name(submit.network);
             println!("Submitting attestation on {}", network);
-            println!("Subject: {}", subject);
-            println!("Payload hash: {}", payload_hash);
-            if let Some(sess) = session {
+            println!("Subject: {}", submit.subject);
+            println!("Payload hash: {}", submit.payload_hash);
+            if let Some(sess) = submit.session {
                 println!("Session: {}", sess);
             }
             println!("✓ Attestation submitted");
         }
+        Commands::Attest { .. } => {
+            eprintln!("✖ attest requires --subject and --payload-hash, or a subcommand");
+            std::process::exit(2);
+        }
         Commands::Query { id, network } => {
+            let network = load_profile(profile_name.as_deref()).network_name(network);
             println!("Querying attestation {} on {}", id, network);
             println!("✓ Attestation retrieved");
         }
+        Commands::Events {
+            command:
+                EventCommands::Watch {
+                    contract,
+                    start_ledger,
+                    cursor,
+                    interval,
+                    once,
+                    network,
+                    rpc_url,
+                },
+        } => {
+            let profile = load_profile(profile_name.as_deref());
+            let options = events_watch::WatchOptions {
+                contract_id: or_exit(profile.contract_id(contract)),
+                network: profile.network(network, rpc_url),
+                start_ledger,
+                cursor,
+                interval: std::time::Duration::from_secs(interval),
+                once,
+            };
+            if let Err(e) = events_watch::watch(&options) {
+                eprintln!("✖ {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Session {
+            command:
+                SessionCommands::Export {
+                    id,
+                    output,
+                    contract,
+                    source,
+                    network,
+                    rpc_url,
+                },
+        } => {
+            let profile = load_profile(profile_name.as_deref());
+            let options = session_export::ExportOptions {
+                contract_id: or_exit(profile.contract_id(contract)),
+                source: or_exit(profile.signer(source).and_then(|s| s.source_arg())),
+                network: profile.network(network, rpc_url),
+                session_id: id,
+            };
+            let transcript = match session_export::export(&options) {
+                Ok(transcript) => transcript,
+                Err(e) => {
+                    eprintln!("✖ {}", e);
+                    std::process::exit(1);
+                }
+            };
+            match output {
+                Some(path) => {
+                    if let Err(e) = std::fs::write(&path, transcript.json.to_string()) {
+                        eprintln!("✖ failed to write {}: {}", path.display(), e);
+                        std::process::exit(1);
+                    }
+                }
+                None => println!("{}", transcript.json),
+            }
+            if transcript.verified() {
+                eprintln!(
+                    "✓ Session {}: {} operation(s), hash chain matches on-chain commitment",
+                    id, transcript.operation_count
+                );
+            } else {
+                eprintln!(
+                    "✖ Session {}: hash chain {} does not match on-chain commitment {}",
+                    id,
+                    hex::encode(transcript.computed),
+                    hex::encode(transcript.commitment)
+                );
+                std::process::exit(1);
+            }
+        }
         Commands::Health {
             attestor,
             watch,
             interval,
             network,
         } => {
+            let network = load_profile(profile_name.as_deref()).network_name(network);
             if watch {
                 println!("Monitoring health on {} (interval: {}s)", network, interval);
                 if let Some(addr) = attestor {
@@ -310,6 +1043,300 @@ fn main() {
                 println!("✓ Health check complete");
             }
         }
+        Commands::Quotes {
+            command:
+                QuoteCommands::Compare {
+                    base,
+                    quote,
+                    amount,
+                    buy,
+                    anchors,
+                    contract,
+                    source,
+                    network,
+                    rpc_url,
+                },
+        } => {
+            let profile = load_profile(profile_name.as_deref());
+            let options = quote_compare::CompareOptions {
+                contract_id: or_exit(profile.contract_id(contract)),
+                source: or_exit(profile.signer(source).and_then(|s| s.source_arg())),
+                network: profile.network(network, rpc_url),
+                base,
+                quote,
+                amount,
+                buy,
+                anchors,
+            };
+            println!("Comparing quotes on {}...", options.network.name);
+            match quote_compare::compare(&options) {
+                Ok(comparison) => {
+                    print!("{}", quote_compare::render_table(&comparison));
+                    println!("✓ {} quote(s) compared", comparison.rows.len());
+                }
+                Err(e) => {
+                    eprintln!("✖ {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::Anchors {
+            command:
+                AnchorCommands::Sync {
+                    domains,
+                    file,
+                    dry_run,
+                    contract,
+                    source,
+                    network,
+                    rpc_url,
+                },
+        } => {
+            let profile = load_profile(profile_name.as_deref());
+            let mut anchors: Vec<anchor_sync::AnchorEntry> = domains
+                .into_iter()
+                .map(|domain| anchor_sync::AnchorEntry {
+                    domain,
+                    signer: None,
+                })
+                .collect();
+            if let Some(file) = file {
+                anchors.extend(or_exit(anchor_sync::read_anchor_file(&file)));
+            }
+            if anchors.is_empty() {
+                eprintln!("✖ give anchor home domains or --file");
+                std::process::exit(1);
+            }
+            let options = anchor_sync::SyncOptions {
+                contract_id: or_exit(profile.contract_id(contract)),
+                signer: or_exit(profile.signer(source)),
+                network: profile.network(network, rpc_url),
+                anchors,
+                dry_run,
+            };
+            println!(
+                "Syncing {} anchor(s) on {}...",
+                options.anchors.len(),
+                options.network.name
+            );
+            let results = or_exit(anchor_sync::sync(&options));
+            let mut failed = 0;
+            for result in &results {
+                match result {
+                    Ok(report) => {
+                        println!(
+                            "● {} ({})",
+                            report.domain,
+                            quote_compare::short_address(&report.attestor)
+                        );
+                        println!("  stellar.toml sha256 {}", hex::encode(report.toml_hash));
+                        if report.changes.is_empty() {
+                            println!("  up to date");
+                        }
+                        for change in &report.changes {
+                            println!("  {}", change);
+                        }
+                    }
+                    Err(e) => {
+                        failed += 1;
+                        eprintln!("✖ {}", e);
+                    }
+                }
+            }
+            if failed > 0 {
+                eprintln!("✖ {} of {} anchor(s) failed", failed, results.len());
+                std::process::exit(1);
+            }
+            if dry_run {
+                println!("✓ Dry run: nothing submitted");
+            } else {
+                println!("✓ {} anchor(s) synced", results.len());
+            }
+        }
+        Commands::State {
+            command:
+                StateCommands::Inspect {
+                    within_days,
+                    max_keys,
+                    json,
+                    contract,
+                    network,
+                    rpc_url,
+                },
+        } => {
+            let profile = load_profile(profile_name.as_deref());
+            let options = state_inspect::InspectOptions {
+                contract_id: or_exit(profile.contract_id(contract)),
+                network: profile.network(network, rpc_url),
+                within_ledgers: (u64::from(within_days) * 86_400 / state_inspect::LEDGER_SECONDS)
+                    .try_into()
+                    .unwrap_or(u32::MAX),
+                max_keys,
+            };
+            if !json {
+                println!("Inspecting contract state on {}...", options.network.name);
+            }
+            let report = or_exit(state_inspect::inspect(&options));
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&report).unwrap_or_default()
+                );
+            } else {
+                print!("{}", state_inspect::render(&report, options.within_ledgers));
+                println!("✓ Inspected at ledger {}", report.latest_ledger);
+            }
+        }
+        Commands::Intent { command } => {
+            let IntentCommands::Build {
+                base,
+                quote,
+                amount,
+                buy,
+                service,
+                context,
+                strategy,
+                anchor,
+                quote_id,
+                kyc,
+                subject,
+                referrer,
+                corridor,
+                session,
+                ttl,
+                not_before,
+                requirement_template,
+                submit,
+                yes,
+                contract,
+                source,
+                network,
+                rpc_url,
+            } = *command;
+            let profile = load_profile(profile_name.as_deref());
+            let options = intent_build::IntentOptions {
+                contract_id: or_exit(profile.contract_id(contract)),
+                signer: or_exit(profile.signer(source)),
+                network: profile.network(network, rpc_url),
+                base,
+                quote,
+                amount,
+                buy,
+                service,
+                context,
+                strategy,
+                anchor,
+                quote_id,
+                kyc,
+                subject,
+                referrer,
+                corridor,
+                session,
+                ttl,
+                not_before,
+                requirement_template,
+                submit,
+                yes,
+            };
+            println!("Building transaction intent on {}...", options.network.name);
+            match intent_build::build(&options) {
+                Ok(built) => {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&built.intent).unwrap_or_default()
+                    );
+                    if built.submitted {
+                        println!("✓ Intent submitted");
+                    } else {
+                        println!("✓ Intent simulated (not submitted)");
+                    }
+                }
+                Err(e) => {
+                    eprintln!("✖ {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::Profile { command } => {
+            let mut config = or_exit(profile::ProfileConfig::load());
+            match command {
+                ProfileCommands::List => {
+                    let (selected, _) = or_exit(config.profile(profile_name.as_deref()));
+                    for name in config.names() {
+                        let marker = if name == selected { "*" } else { " " };
+                        println!("{} {}", marker, name);
+                    }
+                }
+                ProfileCommands::Show { name } => {
+                    let (name, profile) =
+                        or_exit(config.profile(name.as_deref().or(profile_name.as_deref())));
+                    let unset = || "(not set)".to_string();
+                    println!("Profile: {}", name);
+                    println!("  network:            {}", profile.network_name(None));
+                    println!(
+                        "  rpc_url:            {}",
+                        profile.rpc_url.unwrap_or_else(unset)
+                    );
+                    println!(
+                        "  network_passphrase: {}",
+                        profile.network_passphrase.unwrap_or_else(unset)
+                    );
+                    println!(
+                        "  contract_id:        {}",
+                        profile.contract_id.unwrap_or_else(unset)
+                    );
+                    println!(
+                        "  source:             {}",
+                        profile.source.unwrap_or_else(unset)
+                    );
+                }
+                ProfileCommands::Set {
+                    name,
+                    network,
+                    rpc_url,
+                    network_passphrase,
+                    contract,
+                    source,
+                    default,
+                } => {
+                    if source.as_deref().is_some_and(stellar_cli::is_secret_key) {
+                        eprintln!("✖ --source must be an identity name, not a secret key");
+                        std::process::exit(1);
+                    }
+                    let update = profile::Profile {
+                        network,
+                        rpc_url,
+                        network_passphrase,
+                        contract_id: contract,
+                        source,
+                    };
+                    let existing = config.profiles.get(&name).cloned().unwrap_or_default();
+                    config
+                        .profiles
+                        .insert(name.clone(), existing.overlay(&update));
+                    if default {
+                        config.default_profile = Some(name.clone());
+                    }
+                    let path = or_exit(config.save());
+                    println!("✓ Profile {} saved to {}", name, path.display());
+                }
+            }
+        }
+        Commands::Keystore { command } => {
+            let (path, key) = match command {
+                KeystoreCommands::New { path } => (path, or_exit(keystore::generate())),
+                KeystoreCommands::Import { path } => (path, or_exit(keystore::read_secret_key())),
+                KeystoreCommands::Address { path } => {
+                    println!("{}", or_exit(keystore::Keystore::load(&path)).address);
+                    return;
+                }
+            };
+            let password = or_exit(keystore::read_password("New password: ", true));
+            let store = or_exit(keystore::Keystore::encrypt(&key, &password));
+            or_exit(store.save(&path));
+            println!("✓ Keystore written to {}", path.display());
+            println!("{}", store.address);
+        }
         Commands::Test { verbose, filter } => {
             println!("Running tests...");
             let mut cmd = std::process::Command::new("cargo");
@@ -342,3 +1369,172 @@ fn main() {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    fn parse(args: &[&str]) -> Cli {
+        Cli::try_parse_from(std::iter::once("anchorkit").chain(args.iter().copied())).unwrap()
+    }
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_deploy_defaults() {
+        let Commands::Deploy {
+            network,
+            source,
+            admin,
+            wasm,
+            rpc_url,
+            dry_run,
+        } = parse(&["deploy"]).command
+        else {
+            panic!("expected deploy");
+        };
+        assert_eq!(network, None);
+        assert_eq!(source, None);
+        assert_eq!(admin, None);
+        assert_eq!(wasm, std::path::PathBuf::from(deploy::DEFAULT_WASM));
+        assert_eq!(rpc_url, None);
+        assert!(!dry_run);
+    }
+
+    #[test]
+    fn test_deploy_flags() {
+        let cli = parse(&[
+            "deploy",
+            "-n",
+            "mainnet",
+            "-s",
+            "keystore:ops.json",
+            "--admin",
+            "GADMIN",
+            "--wasm",
+            "anchorkit.wasm",
+            "--rpc-url",
+            "https://rpc.example.com",
+            "--dry-run",
+            "--profile",
+            "prod",
+        ]);
+        assert_eq!(cli.profile.as_deref(), Some("prod"));
+        let Commands::Deploy {
+            network,
+            source,
+            admin,
+            wasm,
+            rpc_url,
+            dry_run,
+        } = cli.command
+        else {
+            panic!("expected deploy");
+        };
+        assert_eq!(network.as_deref(), Some("mainnet"));
+        assert_eq!(source.as_deref(), Some("keystore:ops.json"));
+        assert_eq!(admin.as_deref(), Some("GADMIN"));
+        assert_eq!(wasm, std::path::PathBuf::from("anchorkit.wasm"));
+        assert_eq!(rpc_url.as_deref(), Some("https://rpc.example.com"));
+        assert!(dry_run);
+    }
+
+    #[test]
+    fn test_quotes_compare_args() {
+        let Commands::Quotes {
+            command:
+                QuoteCommands::Compare {
+                    base,
+                    quote,
+                    amount,
+                    buy,
+                    anchors,
+                    contract,
+                    ..
+                },
+        } = parse(&[
+            "quotes",
+            "compare",
+            "--base",
+            "native",
+            "-q",
+            "USDC:GISSUER",
+            "-a",
+            "12.5",
+            "--buy",
+            "--anchors",
+            "cowrie_ng,GANCHOR",
+            "-c",
+            "CCONTRACT",
+        ])
+        .command
+        else {
+            panic!("expected quotes compare");
+        };
+        assert_eq!(base, "native");
+        assert_eq!(quote, "USDC:GISSUER");
+        assert_eq!(amount, "12.5");
+        assert!(buy);
+        assert_eq!(anchors, ["cowrie_ng", "GANCHOR"]);
+        assert_eq!(contract.as_deref(), Some("CCONTRACT"));
+    }
+
+    #[test]
+    fn test_quotes_compare_needs_an_amount() {
+        assert!(Cli::try_parse_from([
+            "anchorkit",
+            "quotes",
+            "compare",
+            "--base",
+            "native",
+            "--quote",
+            "USDC:GISSUER",
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_intent_build_args() {
+        let Commands::Intent { command } = parse(&[
+            "intent",
+            "build",
+            "--base",
+            "native",
+            "--anchor",
+            "GANCHOR",
+            "--quote-id",
+            "7",
+            "--kyc",
+            "--ttl",
+            "600",
+            "-y",
+        ])
+        .command
+        else {
+            panic!("expected intent");
+        };
+        let IntentCommands::Build {
+            base,
+            anchor,
+            quote_id,
+            kyc,
+            ttl,
+            submit,
+            yes,
+            ..
+        } = *command;
+        assert_eq!(base.as_deref(), Some("native"));
+        assert_eq!(anchor.as_deref(), Some("GANCHOR"));
+        assert_eq!(quote_id, Some(7));
+        assert!(kyc);
+        assert_eq!(ttl, Some(600));
+        assert!(!submit);
+        assert!(yes);
+
+        assert!(Cli::try_parse_from(["anchorkit", "intent", "build", "--ttl", "5m"]).is_err());
+    }
+}
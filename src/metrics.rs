@@ -0,0 +1,86 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    storage::Storage,
+    types::{Asset, QuoteRequest, TransactionIntent, UsageMetrics},
+};
+
+/// Length of a usage metrics period (1 day).
+pub const METRICS_PERIOD_SECONDS: u64 = 86_400;
+
+/// Usage counters by service type and asset pair, kept per period so
+/// dashboards can read them without replaying events.
+pub struct Metrics;
+
+impl Metrics {
+    /// Metrics period containing the current ledger time.
+    pub fn current_period(env: &Env) -> u64 {
+        env.ledger().timestamp() / METRICS_PERIOD_SECONDS
+    }
+
+    /// Count a built intent.
+    pub fn record_intent(env: &Env, request: &QuoteRequest) {
+        Self::update(env, request, |metrics| metrics.intents += 1);
+    }
+
+    /// Count an executed intent and its amount.
+    pub fn record_transfer(env: &Env, request: &QuoteRequest, amount: i128) {
+        Self::update(env, request, |metrics| {
+            metrics.transfers += 1;
+            metrics.volume = metrics.volume.saturating_add(amount);
+        });
+    }
+
+    /// Add an executed intent's fee, at its quoted rate in basis points, to
+    /// the anchor's revenue for the current period.
+    pub fn record_fee(env: &Env, intent: &TransactionIntent, amount: i128) {
+        let mut revenue = Storage::get_fee_revenue(env, &intent.anchor, Self::current_period(env));
+        revenue.transfers += 1;
+
+        let fee = amount.saturating_mul(intent.fee_percentage as i128) / 10_000;
+        if fee > 0 {
+            let asset = &intent.request.base_asset;
+            let total = revenue.fees.get(asset.clone()).unwrap_or(0);
+            revenue.fees.set(asset.clone(), total.saturating_add(fee));
+        }
+        Storage::set_fee_revenue(env, &revenue);
+    }
+
+    /// Record a late-settlement penalty not covered by `anchor`'s bond against
+    /// its fee revenue for the current period.
+    pub fn record_penalty(env: &Env, anchor: &Address, asset: &Asset, amount: i128) {
+        let mut revenue = Storage::get_fee_revenue(env, anchor, Self::current_period(env));
+        let total = revenue.penalties.get(asset.clone()).unwrap_or(0);
+        revenue.penalties.set(asset.clone(), total.saturating_add(amount));
+        Storage::set_fee_revenue(env, &revenue);
+    }
+
+    fn update(env: &Env, request: &QuoteRequest, update: impl FnOnce(&mut UsageMetrics)) {
+        let period = Self::current_period(env);
+        let mut all = Storage::get_usage_metrics(env, period);
+        let index = all.iter().position(|metrics| {
+            metrics.service_type == request.operation_type
+                && metrics.base_asset == request.base_asset
+                && metrics.quote_asset == request.quote_asset
+        });
+
+        let mut metrics = match index {
+            Some(index) => all.get(index as u32).unwrap(),
+            None => UsageMetrics {
+                period,
+                service_type: request.operation_type,
+                base_asset: request.base_asset.clone(),
+                quote_asset: request.quote_asset.clone(),
+                intents: 0,
+                transfers: 0,
+                volume: 0,
+            },
+        };
+        update(&mut metrics);
+        match index {
+            Some(index) => all.set(index as u32, metrics),
+            None => all.push_back(metrics),
+        }
+        Storage::set_usage_metrics(env, period, &all);
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod metrics_tests {
+    use crate::testutils::{classic_asset, AnchorKitFixture};
+    use crate::{ServiceType, UsageMetrics, METRICS_PERIOD_SECONDS};
+    use soroban_sdk::{testutils::Ledger, token, Env};
+
+    #[test]
+    fn test_metrics_by_service_type_and_pair() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Withdrawals]);
+
+        let token = env
+            .register_stellar_asset_contract_v2(kit.admin.clone())
+            .address();
+        kit.client.add_supported_token(&token);
+        let payer = kit.user.clone();
+        token::StellarAssetClient::new(&env, &token).mint(&payer, &1_000);
+        token::Client::new(&env, &token).approve(&payer, &kit.contract_id, &1_000, &1000);
+
+        let deposit = kit.deposit_intent(&anchor, "USDC", "EUR", 300);
+        let first = kit.client.build_transaction_intent(&deposit).intent_id;
+        kit.client.build_transaction_intent(&deposit);
+        kit.client.execute_intent(&first, &payer, &token);
+
+        let mut withdrawal = kit.deposit_intent(&anchor, "USDC", "EUR", 50);
+        withdrawal.request.operation_type = ServiceType::Withdrawals;
+        kit.client.build_transaction_intent(&withdrawal);
+
+        let period = kit.client.get_current_metrics_period();
+        assert_eq!(period, env.ledger().timestamp() / METRICS_PERIOD_SECONDS);
+        let metrics = kit.client.get_metrics(&period);
+        assert_eq!(metrics.len(), 2);
+        assert_eq!(
+            metrics.get(0).unwrap(),
+            UsageMetrics {
+                period,
+                service_type: ServiceType::Deposits,
+                base_asset: classic_asset(&env, "USDC"),
+                quote_asset: classic_asset(&env, "EUR"),
+                intents: 2,
+                transfers: 1,
+                volume: 300,
+            }
+        );
+        let withdrawals = metrics.get(1).unwrap();
+        assert_eq!(withdrawals.service_type, ServiceType::Withdrawals);
+        assert_eq!((withdrawals.intents, withdrawals.transfers), (1, 0));
+
+        // A new period starts from zero
+        env.ledger()
+            .with_mut(|li| li.timestamp += METRICS_PERIOD_SECONDS);
+        assert_eq!(kit.client.get_metrics(&(period + 1)).len(), 0);
+        assert_eq!(kit.client.get_metrics(&period).len(), 2);
+    }
+
+    #[test]
+    fn test_fee_revenue_from_quoted_intents() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+
+        let token = env
+            .register_stellar_asset_contract_v2(kit.admin.clone())
+            .address();
+        kit.client.add_supported_token(&token);
+        let payer = kit.user.clone();
+        token::StellarAssetClient::new(&env, &token).mint(&payer, &10_000);
+        token::Client::new(&env, &token).approve(&payer, &kit.contract_id, &10_000, &1000);
+
+        // 2.5% fee
+        let quote_id = kit.client.submit_quote(
+            &anchor,
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &9_000,
+            &250,
+            &1,
+            &u64::MAX,
+            &(env.ledger().timestamp() + 600),
+        );
+        let quoted = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 2_000)
+            .with_quote_id(quote_id)
+            .with_quote_sequence(1);
+        for builder in [quoted, kit.deposit_intent(&anchor, "USDC", "EUR", 1_000)] {
+            let intent_id = kit.client.build_transaction_intent(&builder).intent_id;
+            kit.client.execute_intent(&intent_id, &payer, &token);
+        }
+
+        let period = kit.client.get_current_metrics_period();
+        let revenue = kit.client.get_anchor_fee_revenue(&anchor, &period);
+        assert_eq!(revenue.transfers, 2);
+        assert_eq!(revenue.fees.get(classic_asset(&env, "USDC")), Some(50));
+        assert_eq!(
+            kit.client
+                .get_anchor_fee_revenue(&anchor, &(period + 1))
+                .transfers,
+            0
+        );
+    }
+}
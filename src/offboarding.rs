@@ -0,0 +1,191 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    events::{AttestorRemoved, OffboardingAdvanced},
+    storage::Storage,
+    token::TokenCustody,
+    treasury::Treasury,
+    types::{AnchorBond, OffboardingStage, OffboardingState, ProceedsKind, TransactionIntent},
+};
+
+/// Winds an anchor down without stranding in-flight activity.
+///
+/// Once offboarding starts the anchor can't take new quotes, intents or
+/// deposits. It drains when every deposit memo it registered has settled and
+/// every intent built against it has expired, then enters a challenge window
+/// during which its bond can still be slashed. After the window the bond is
+/// released and the anchor removed. Registering it again starts it over.
+pub struct Offboarding;
+
+impl Offboarding {
+    /// Fail with `AnchorOffboarding` once `anchor` has started offboarding.
+    pub fn ensure_active(env: &Env, anchor: &Address) -> Result<(), Error> {
+        match Storage::get_offboarding(env, anchor) {
+            Some(_) => Err(Error::AnchorOffboarding),
+            None => Ok(()),
+        }
+    }
+
+    pub fn initiate(env: &Env, anchor: &Address) -> Result<OffboardingState, Error> {
+        if !Storage::is_attestor(env, anchor) {
+            return Err(Error::AttestorNotRegistered);
+        }
+        Self::ensure_active(env, anchor)?;
+
+        let state = OffboardingState {
+            anchor: anchor.clone(),
+            stage: OffboardingStage::Draining,
+            initiated_at: env.ledger().timestamp(),
+            challenge_ends_at: 0,
+        };
+        Storage::set_offboarding(env, &state);
+        OffboardingAdvanced::publish(env, anchor, state.stage);
+        Ok(state)
+    }
+
+    /// Move `anchor` to the next stage if it is due. Returns the resulting state,
+    /// unchanged while activity is still open or the window hasn't ended.
+    pub fn advance(env: &Env, anchor: &Address) -> Result<OffboardingState, Error> {
+        let mut state = Storage::get_offboarding(env, anchor).ok_or(Error::NotFound)?;
+        let now = env.ledger().timestamp();
+
+        match state.stage {
+            OffboardingStage::Draining => {
+                let activity = Storage::get_anchor_activity(env, anchor);
+                if activity.open_deposits > 0 || activity.intents_expire_at > now {
+                    return Ok(state);
+                }
+                state.stage = OffboardingStage::Challenge;
+                state.challenge_ends_at =
+                    now.saturating_add(OffboardingState::CHALLENGE_WINDOW_SECONDS);
+            }
+            OffboardingStage::Challenge => {
+                if now < state.challenge_ends_at {
+                    return Ok(state);
+                }
+                for bond in Storage::get_anchor_bonds(env, anchor).iter() {
+                    TokenCustody::release(env, &bond.token, anchor, bond.amount)?;
+                }
+                Storage::set_anchor_bonds(env, anchor, &Vec::new(env));
+                #[cfg(feature = "routing")]
+                Storage::remove_from_anchor_list(env, anchor);
+                Storage::set_attestor(env, anchor, false);
+                AttestorRemoved::publish(env, anchor);
+                state.stage = OffboardingStage::Completed;
+            }
+            OffboardingStage::Completed => return Ok(state),
+        }
+
+        Storage::set_offboarding(env, &state);
+        OffboardingAdvanced::publish(env, anchor, state.stage);
+        Ok(state)
+    }
+
+    /// Add `amount` of `token` from `anchor` to its bond.
+    pub fn post_bond(
+        env: &Env,
+        anchor: &Address,
+        token: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if !Storage::is_attestor(env, anchor) {
+            return Err(Error::AttestorNotRegistered);
+        }
+        Self::ensure_active(env, anchor)?;
+        TokenCustody::post_bond(env, token, anchor, amount)?;
+
+        let mut bonds = Storage::get_anchor_bonds(env, anchor);
+        match bonds.iter().position(|bond| &bond.token == token) {
+            Some(index) => {
+                let mut bond = bonds.get(index as u32).unwrap();
+                bond.amount += amount;
+                bonds.set(index as u32, bond);
+            }
+            None => bonds.push_back(AnchorBond {
+                token: token.clone(),
+                amount,
+            }),
+        }
+        Storage::set_anchor_bonds(env, anchor, &bonds);
+        Ok(())
+    }
+
+    /// Move `amount` of `anchor`'s `token` bond to the treasury. Possible until
+    /// the bond is released at the end of the challenge window.
+    pub fn slash_bond(
+        env: &Env,
+        anchor: &Address,
+        token: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        Self::debit_bond(env, anchor, token, amount)?;
+        Treasury::credit(env, token, anchor, amount, ProceedsKind::Slash)
+    }
+
+    /// Pay `amount` of `anchor`'s `token` bond out to `to`, such as a
+    /// late-settlement penalty owed to a sender.
+    pub fn pay_from_bond(
+        env: &Env,
+        anchor: &Address,
+        token: &Address,
+        to: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        Self::debit_bond(env, anchor, token, amount)?;
+        TokenCustody::release(env, token, to, amount)
+    }
+
+    /// How much of `token` `anchor` holds as bond.
+    pub fn bond_amount(env: &Env, anchor: &Address, token: &Address) -> i128 {
+        Storage::get_anchor_bonds(env, anchor)
+            .iter()
+            .find(|bond| &bond.token == token)
+            .map_or(0, |bond| bond.amount)
+    }
+
+    fn debit_bond(env: &Env, anchor: &Address, token: &Address, amount: i128) -> Result<(), Error> {
+        let mut bonds = Storage::get_anchor_bonds(env, anchor);
+        let index = bonds
+            .iter()
+            .position(|bond| &bond.token == token)
+            .ok_or(Error::NotFound)? as u32;
+        let mut bond = bonds.get(index).unwrap();
+        if amount > bond.amount {
+            return Err(Error::InsufficientBalance);
+        }
+
+        bond.amount -= amount;
+        if bond.amount == 0 {
+            bonds.remove(index);
+        } else {
+            bonds.set(index, bond);
+        }
+        Storage::set_anchor_bonds(env, anchor, &bonds);
+        Ok(())
+    }
+
+    /// Count a deposit memo registered by `anchor` until it settles.
+    pub fn open_deposit(env: &Env, anchor: &Address) {
+        let mut activity = Storage::get_anchor_activity(env, anchor);
+        activity.open_deposits += 1;
+        Storage::set_anchor_activity(env, anchor, &activity);
+    }
+
+    pub fn settle_deposit(env: &Env, anchor: &Address) {
+        let mut activity = Storage::get_anchor_activity(env, anchor);
+        activity.open_deposits = activity.open_deposits.saturating_sub(1);
+        Storage::set_anchor_activity(env, anchor, &activity);
+    }
+
+    /// Keep the anchor draining until `intent` can no longer execute. Only
+    /// intents their subject authorized get here, and none once offboarding
+    /// starts, so `MAX_INTENT_TTL` bounds how long draining can be held open.
+    pub fn record_intent(env: &Env, intent: &TransactionIntent) {
+        let mut activity = Storage::get_anchor_activity(env, &intent.anchor);
+        if intent.expires_at > activity.intents_expire_at {
+            activity.intents_expire_at = intent.expires_at;
+            Storage::set_anchor_activity(env, &intent.anchor, &activity);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod offboarding_tests {
+    use crate::testutils::{advance_time, attestor_public_key, classic_asset, AnchorKitFixture};
+    use crate::{config, DepositMemo, Error, OffboardingStage, OffboardingState, ServiceType};
+    use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env};
+
+    #[test]
+    fn test_offboarding_drains_then_releases_bond() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+        let token = env
+            .register_stellar_asset_contract_v2(kit.admin.clone())
+            .address();
+        kit.client.add_supported_token(&token);
+        token::StellarAssetClient::new(&env, &token).mint(&anchor, &1_000);
+        let balance = || token::Client::new(&env, &token).balance(&anchor);
+
+        kit.client.post_anchor_bond(&anchor, &token, &500);
+        let subject = Address::generate(&env);
+        let transfer_id = kit.client.initiate_transfer(&subject, &anchor, &250);
+        let memo = DepositMemo::Id(1);
+        kit.client
+            .register_deposit_memo(&anchor, &memo, &subject, &transfer_id);
+        kit.client
+            .build_transaction_intent(&kit.deposit_intent(&anchor, "USDC", "EUR", 500));
+
+        let state = kit.client.initiate_offboarding(&anchor);
+        assert_eq!(state.stage, OffboardingStage::Draining);
+
+        // No new activity while draining
+        assert_eq!(
+            kit.client.try_submit_quote(
+                &anchor,
+                &classic_asset(&env, "USDC"),
+                &classic_asset(&env, "EUR"),
+                &9_000,
+                &0,
+                &1,
+                &u64::MAX,
+                &600,
+            ),
+            Err(Ok(Error::AnchorOffboarding))
+        );
+        assert_eq!(
+            kit.client
+                .try_build_transaction_intent(&kit.deposit_intent(&anchor, "USDC", "EUR", 500)),
+            Err(Ok(Error::AnchorOffboarding))
+        );
+        assert_eq!(
+            kit.client.try_register_deposit_memo(
+                &anchor,
+                &DepositMemo::Id(2),
+                &subject,
+                &transfer_id
+            ),
+            Err(Ok(Error::AnchorOffboarding))
+        );
+
+        // The open deposit and the live intent hold the anchor in draining
+        let stage = || kit.client.advance_offboarding(&anchor).stage;
+        assert_eq!(stage(), OffboardingStage::Draining);
+        kit.client
+            .confirm_memo_deposit(&anchor, &memo, &BytesN::from_array(&env, &[1u8; 32]));
+        assert_eq!(stage(), OffboardingStage::Draining);
+        advance_time(&env, 300);
+        assert_eq!(stage(), OffboardingStage::Challenge);
+
+        // The bond can still be slashed during the challenge window
+        kit.client.slash_anchor_bond(&anchor, &token, &100);
+        advance_time(&env, OffboardingState::CHALLENGE_WINDOW_SECONDS - 1);
+        assert_eq!(stage(), OffboardingStage::Challenge);
+        assert!(kit.client.is_attestor(&anchor));
+
+        advance_time(&env, 1);
+        assert_eq!(stage(), OffboardingStage::Completed);
+        assert_eq!(balance(), 900);
+        assert_eq!(kit.client.get_anchor_bonds(&anchor), vec![&env]);
+        assert!(!kit.client.is_attestor(&anchor));
+        assert_eq!(
+            kit.client.try_slash_anchor_bond(&anchor, &token, &1),
+            Err(Ok(Error::NotFound))
+        );
+    }
+
+    #[test]
+    fn test_offboarding_requires_registered_anchor() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        let stranger = Address::generate(&env);
+
+        assert_eq!(
+            kit.client.try_initiate_offboarding(&stranger),
+            Err(Ok(Error::AttestorNotRegistered))
+        );
+        assert_eq!(
+            kit.client.try_advance_offboarding(&anchor),
+            Err(Ok(Error::NotFound))
+        );
+
+        kit.client.initiate_offboarding(&anchor);
+        assert_eq!(
+            kit.client.try_initiate_offboarding(&anchor),
+            Err(Ok(Error::AnchorOffboarding))
+        );
+        // Nothing was in flight, so the anchor goes straight to the challenge window
+        let state = kit.client.advance_offboarding(&anchor);
+        assert_eq!(state.stage, OffboardingStage::Challenge);
+        assert_eq!(
+            state.challenge_ends_at,
+            OffboardingState::CHALLENGE_WINDOW_SECONDS
+        );
+    }
+
+    #[test]
+    fn test_reregistered_anchor_operates_again() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+
+        kit.client.initiate_offboarding(&anchor);
+        kit.client.advance_offboarding(&anchor);
+        advance_time(&env, OffboardingState::CHALLENGE_WINDOW_SECONDS);
+        assert_eq!(
+            kit.client.advance_offboarding(&anchor).stage,
+            OffboardingStage::Completed
+        );
+
+        kit.client
+            .register_attestor(&anchor, &attestor_public_key(&env));
+        kit.client
+            .configure_services(&anchor, &vec![&env, ServiceType::Deposits]);
+        assert_eq!(
+            kit.client.try_get_offboarding(&anchor),
+            Err(Ok(Error::NotFound))
+        );
+        kit.client
+            .build_transaction_intent(&kit.deposit_intent(&anchor, "USDC", "EUR", 500));
+    }
+
+    #[test]
+    fn test_intent_ttl_is_capped() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+
+        // An intent outliving the cap could hold an offboarding anchor in draining
+        assert_eq!(
+            kit.client.try_build_transaction_intent(
+                &kit.deposit_intent(&anchor, "USDC", "EUR", 500)
+                    .with_ttl(config::MAX_INTENT_TTL + 1)
+            ),
+            Err(Ok(Error::InvalidTransactionIntent))
+        );
+        kit.client.build_transaction_intent(
+            &kit.deposit_intent(&anchor, "USDC", "EUR", 500)
+                .with_ttl(config::MAX_INTENT_TTL),
+        );
+    }
+}
@@ -0,0 +1,193 @@
+#[cfg(test)]
+mod operation_limit_tests {
+    use crate::testutils::{classic_asset, set_time, AnchorKitFixture};
+    use crate::{Error, OperationClass, OperationLimit, Parameter, ParameterChange, ServiceType};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, Env,
+    };
+
+    fn limit(max_operations: u32, window_ledgers: u32) -> OperationLimit {
+        OperationLimit {
+            max_operations,
+            window_ledgers,
+        }
+    }
+
+    fn advance_ledgers(env: &Env, ledgers: u32) {
+        env.ledger().with_mut(|li| li.sequence_number += ledgers);
+    }
+
+    #[test]
+    fn test_session_limit_refills_over_window() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let user = Address::generate(&env);
+        kit.client
+            .set_operation_limit(&OperationClass::Session, &limit(2, 100));
+
+        kit.client.create_session(&user);
+        kit.client.create_session(&user);
+        assert_eq!(
+            kit.client.try_create_session(&user),
+            Err(Ok(Error::RateLimitExceeded))
+        );
+        assert_eq!(
+            kit.client
+                .get_operation_allowance(&user, &OperationClass::Session),
+            Some(0)
+        );
+
+        // Half the window refills half the bucket
+        advance_ledgers(&env, 50);
+        assert_eq!(
+            kit.client
+                .get_operation_allowance(&user, &OperationClass::Session),
+            Some(1)
+        );
+        kit.client.create_session(&user);
+        assert_eq!(
+            kit.client.try_create_session(&user),
+            Err(Ok(Error::RateLimitExceeded))
+        );
+
+        advance_ledgers(&env, 100);
+        assert_eq!(
+            kit.client
+                .get_operation_allowance(&user, &OperationClass::Session),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_limits_apply_per_address_and_class() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let busy = kit.register_anchor(&[ServiceType::Quotes]);
+        let quiet = kit.register_anchor(&[ServiceType::Quotes]);
+        kit.client
+            .set_operation_limit(&OperationClass::Quote, &limit(1, 100));
+
+        kit.seed_quote(&busy, "USDC", "EUR", 9200, 3600);
+        let result = kit.client.try_submit_quote(
+            &busy,
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &9200,
+            &0,
+            &1,
+            &u64::MAX,
+            &(env.ledger().timestamp() + 3600),
+        );
+        assert_eq!(result, Err(Ok(Error::RateLimitExceeded)));
+
+        // Other anchors keep their own bucket, and other classes are unlimited
+        kit.seed_quote(&quiet, "USDC", "EUR", 9100, 3600);
+        kit.client.create_session(&busy);
+        assert_eq!(
+            kit.client
+                .get_operation_allowance(&busy, &OperationClass::Session),
+            None
+        );
+    }
+
+    #[test]
+    fn test_transfer_limit() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let sender = Address::generate(&env);
+        let destination = Address::generate(&env);
+        kit.client
+            .set_operation_limit(&OperationClass::Transfer, &limit(1, 10));
+
+        kit.client.initiate_transfer(&sender, &destination, &100);
+        assert_eq!(
+            kit.client
+                .try_initiate_transfer(&sender, &destination, &100),
+            Err(Ok(Error::RateLimitExceeded))
+        );
+
+        advance_ledgers(&env, 10);
+        kit.client.initiate_transfer(&sender, &destination, &100);
+    }
+
+    #[test]
+    fn test_operation_limit_configuration() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let class = OperationClass::Attestation;
+
+        for invalid in [
+            limit(0, 100),
+            limit(5, 0),
+            limit(5, OperationLimit::MAX_WINDOW_LEDGERS + 1),
+        ] {
+            assert_eq!(
+                kit.client.try_set_operation_limit(&class, &invalid),
+                Err(Ok(Error::InvalidConfig))
+            );
+        }
+
+        kit.client.set_operation_limit(&class, &limit(5, 100));
+        assert_eq!(kit.client.get_operation_limit(&class), Some(limit(5, 100)));
+
+        kit.client.remove_operation_limit(&class);
+        assert_eq!(kit.client.get_operation_limit(&class), None);
+    }
+
+    #[test]
+    fn test_scheduled_change_takes_effect_at_activation() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let user = Address::generate(&env);
+        let parameter = Parameter::OperationLimit(OperationClass::Session);
+        set_time(&env, 1_000);
+        kit.client
+            .set_operation_limit(&OperationClass::Session, &limit(1, 100));
+
+        assert_eq!(
+            kit.client.try_schedule_parameter_change(
+                &ParameterChange::OperationLimit(OperationClass::Session, limit(3, 100)),
+                &1_000,
+            ),
+            Err(Ok(Error::InvalidTimestamp))
+        );
+        let scheduled = kit.client.schedule_parameter_change(
+            &ParameterChange::OperationLimit(OperationClass::Session, limit(3, 100)),
+            &2_000,
+        );
+        assert_eq!(kit.client.get_scheduled_change(&parameter), Some(scheduled));
+
+        // The current limit holds until the activation time, then the new one
+        // applies without another admin transaction
+        kit.client.create_session(&user);
+        assert_eq!(
+            kit.client.try_create_session(&user),
+            Err(Ok(Error::RateLimitExceeded))
+        );
+        set_time(&env, 2_000);
+        assert_eq!(
+            kit.client.get_operation_limit(&OperationClass::Session),
+            Some(limit(3, 100))
+        );
+        assert_eq!(kit.client.get_scheduled_change(&parameter), None);
+        advance_ledgers(&env, 100);
+        assert_eq!(
+            kit.client
+                .get_operation_allowance(&user, &OperationClass::Session),
+            Some(3)
+        );
+        assert_eq!(
+            kit.client.try_cancel_parameter_change(&parameter),
+            Err(Ok(Error::NotFound))
+        );
+
+        // A cancelled change never applies
+        kit.client.set_referral_share(&1_000);
+        kit.client
+            .schedule_parameter_change(&ParameterChange::ReferralShare(2_000), &3_000);
+        kit.client.cancel_parameter_change(&Parameter::ReferralShare);
+        set_time(&env, 3_000);
+        assert_eq!(kit.client.get_referral_share(), 1_000);
+    }
+}
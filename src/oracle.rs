@@ -0,0 +1,83 @@
+use soroban_sdk::{contractclient, Env};
+
+use crate::{
+    errors::Error,
+    storage::Storage,
+    types::{Asset, OracleAsset, PriceData},
+};
+
+/// Price feed interface, compatible with Reflector-style oracles.
+///
+/// Only the cross-price read is required: `x_last_price(base, quote)` returns the
+/// latest price of `base` in `quote`, scaled by `10^decimals()`.
+#[contractclient(name = "OracleClient")]
+pub trait OracleAdapter {
+    /// Number of decimals used by the oracle's prices.
+    fn decimals(env: Env) -> u32;
+
+    /// Latest cross price of `base_asset` quoted in `quote_asset`, if any.
+    fn x_last_price(
+        env: Env,
+        base_asset: OracleAsset,
+        quote_asset: OracleAsset,
+    ) -> Option<PriceData>;
+}
+
+/// Reads reference prices from the oracle registered for an asset pair.
+pub struct PriceOracle;
+
+impl PriceOracle {
+    /// Scale of `QuoteData::rate` (10000 = 1.0).
+    const RATE_SCALE: i128 = 10_000;
+
+    /// Reference rate for a pair, in `QuoteData::rate` units.
+    ///
+    /// Fails with `AssetNotConfigured` if no oracle is registered for the pair and
+    /// with `StaleQuote` if the oracle has no price or the price is too old.
+    pub fn reference_rate(
+        env: &Env,
+        base_asset: &Asset,
+        quote_asset: &Asset,
+    ) -> Result<u64, Error> {
+        let feed = Storage::get_oracle_feed(env, base_asset, quote_asset)
+            .ok_or(Error::AssetNotConfigured)?;
+        let client = OracleClient::new(env, &feed.oracle);
+
+        let price = client
+            .x_last_price(&feed.base, &feed.quote)
+            .ok_or(Error::StaleQuote)?;
+        let now = env.ledger().timestamp();
+        if price.price <= 0 || price.timestamp.saturating_add(feed.max_age_seconds) < now {
+            return Err(Error::StaleQuote);
+        }
+
+        let divisor = 10i128
+            .checked_pow(client.decimals())
+            .ok_or(Error::InvalidConfig)?;
+        let rate = price
+            .price
+            .checked_mul(Self::RATE_SCALE)
+            .ok_or(Error::InvalidConfig)?
+            / divisor;
+        u64::try_from(rate).map_err(|_| Error::InvalidConfig)
+    }
+
+    /// Reject quotes whose rate deviates from the oracle reference by more than the
+    /// feed's `max_deviation_bps`. Pairs without a registered oracle are not checked.
+    pub fn check_deviation(
+        env: &Env,
+        base_asset: &Asset,
+        quote_asset: &Asset,
+        rate: u64,
+    ) -> Result<(), Error> {
+        let Some(feed) = Storage::get_oracle_feed(env, base_asset, quote_asset) else {
+            return Ok(());
+        };
+        let reference = Self::reference_rate(env, base_asset, quote_asset)? as u128;
+        let deviation = (rate as u128).abs_diff(reference);
+        if deviation * 10_000 > reference * feed.max_deviation_bps as u128 {
+            return Err(Error::InvalidQuote);
+        }
+        Ok(())
+    }
+}
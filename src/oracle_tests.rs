@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod oracle_tests {
+    use crate::testutils::attestor_public_key;
+    use crate::{
+        AnchorKitContract, AnchorKitContractClient, Asset, Error, OracleAdapter, OracleAsset,
+        OracleFeed, PriceData, ServiceType,
+    };
+    use soroban_sdk::{
+        contract, contractimpl, symbol_short,
+        testutils::{Address as _, Ledger},
+        vec, Address, Env, String, Symbol,
+    };
+
+    /// Reflector-style feed returning a fixed price with 7 decimals.
+    #[contract]
+    struct MockOracle;
+
+    #[contractimpl]
+    impl MockOracle {
+        pub fn set_price(env: Env, price: i128, timestamp: u64) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("PRICE"), &PriceData { price, timestamp });
+        }
+    }
+
+    #[contractimpl]
+    impl OracleAdapter for MockOracle {
+        fn decimals(_env: Env) -> u32 {
+            7
+        }
+
+        fn x_last_price(env: Env, _base: OracleAsset, _quote: OracleAsset) -> Option<PriceData> {
+            env.storage().instance().get(&symbol_short!("PRICE"))
+        }
+    }
+
+    fn asset(env: &Env, code: &str) -> Asset {
+        let issuer = String::from_str(
+            env,
+            "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN",
+        );
+        Asset::Classic(String::from_str(env, code), Address::from_string(&issuer))
+    }
+
+    fn setup(env: &Env) -> (AnchorKitContractClient<'_>, MockOracleClient<'_>, Address) {
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 10_000);
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let anchor = Address::generate(env);
+        client.initialize(&admin);
+        client.register_attestor(&anchor, &attestor_public_key(env));
+        client.configure_services(&anchor, &vec![env, ServiceType::Quotes]);
+
+        let oracle_id = env.register_contract(None, MockOracle);
+        let oracle = MockOracleClient::new(env, &oracle_id);
+        // 1 USDC = 0.92 EUR
+        oracle.set_price(&9_200_000, &10_000);
+
+        client.set_oracle_feed(
+            &asset(env, "USDC"),
+            &asset(env, "EUR"),
+            &OracleFeed {
+                oracle: oracle_id,
+                base: OracleAsset::Other(Symbol::new(env, "USDC")),
+                quote: OracleAsset::Other(Symbol::new(env, "EUR")),
+                max_deviation_bps: 200,
+                max_age_seconds: 300,
+            },
+        );
+
+        (client, oracle, anchor)
+    }
+
+    fn submit(
+        env: &Env,
+        client: &AnchorKitContractClient,
+        anchor: &Address,
+        rate: u64,
+    ) -> Result<u64, Error> {
+        match client.try_submit_quote(
+            anchor,
+            &asset(env, "USDC"),
+            &asset(env, "EUR"),
+            &rate,
+            &10,
+            &100,
+            &10_000,
+            &20_000,
+        ) {
+            Ok(Ok(id)) => Ok(id),
+            Err(Ok(err)) => Err(err),
+            _ => panic!("unexpected invocation failure"),
+        }
+    }
+
+    #[test]
+    fn test_reference_price_is_scaled_to_rate_units() {
+        let env = Env::default();
+        let (client, _, _) = setup(&env);
+
+        let price = client.get_reference_price(&asset(&env, "USDC"), &asset(&env, "EUR"));
+        assert_eq!(price, 9200);
+
+        let result = client.try_get_reference_price(&asset(&env, "EUR"), &asset(&env, "USDC"));
+        assert_eq!(result, Err(Ok(Error::AssetNotConfigured)));
+    }
+
+    #[test]
+    fn test_deviation_guard_rejects_outlier_quotes() {
+        let env = Env::default();
+        let (client, _, anchor) = setup(&env);
+
+        // Within 2% of 0.92
+        assert!(submit(&env, &client, &anchor, 9300).is_ok());
+        assert!(submit(&env, &client, &anchor, 9016).is_ok());
+        // More than 2% away
+        assert_eq!(
+            submit(&env, &client, &anchor, 9500),
+            Err(Error::InvalidQuote)
+        );
+        assert_eq!(
+            submit(&env, &client, &anchor, 8000),
+            Err(Error::InvalidQuote)
+        );
+
+        // Pairs without an oracle are not guarded
+        client.remove_oracle_feed(&asset(&env, "USDC"), &asset(&env, "EUR"));
+        assert!(submit(&env, &client, &anchor, 8000).is_ok());
+    }
+
+    #[test]
+    fn test_stale_oracle_price_is_rejected() {
+        let env = Env::default();
+        let (client, oracle, anchor) = setup(&env);
+
+        env.ledger().with_mut(|li| li.timestamp = 10_301);
+        let result = client.try_get_reference_price(&asset(&env, "USDC"), &asset(&env, "EUR"));
+        assert_eq!(result, Err(Ok(Error::StaleQuote)));
+        assert_eq!(submit(&env, &client, &anchor, 9200), Err(Error::StaleQuote));
+
+        oracle.set_price(&9_200_000, &10_301);
+        assert!(submit(&env, &client, &anchor, 9200).is_ok());
+    }
+}
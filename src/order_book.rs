@@ -0,0 +1,64 @@
+use soroban_sdk::{Env, Vec};
+
+use crate::{
+    storage::Storage,
+    types::{Asset, QuoteData},
+};
+
+/// Market depth per asset pair across anchors.
+///
+/// Each pair keeps its `CAPACITY` best live quotes, lowest rate first (then
+/// lowest fee, then earliest submitted), with only the latest quote of each
+/// anchor. The book is refreshed on every submission for the pair: the new
+/// quote replaces the anchor's previous one and quotes that have expired are
+/// dropped. Reads also skip quotes that expired since. A quote that didn't
+/// make the cut when submitted doesn't re-enter when better ones expire.
+pub struct OrderBook;
+
+impl OrderBook {
+    /// Most quotes kept per pair.
+    pub const CAPACITY: u32 = 10;
+
+    /// Rank a newly submitted quote into its pair's book.
+    pub fn insert(env: &Env, quote: &QuoteData) {
+        let now = env.ledger().timestamp();
+        let mut book = Vec::new(env);
+        let mut inserted = false;
+        for entry in Storage::get_order_book(env, &quote.base_asset, &quote.quote_asset).iter() {
+            if entry.anchor == quote.anchor || entry.valid_until <= now {
+                continue;
+            }
+            if !inserted && Self::ranks_before(quote, &entry) {
+                book.push_back(quote.clone());
+                inserted = true;
+            }
+            book.push_back(entry);
+        }
+        if !inserted {
+            book.push_back(quote.clone());
+        }
+        while book.len() > Self::CAPACITY {
+            book.pop_back();
+        }
+        Storage::set_order_book(env, &quote.base_asset, &quote.quote_asset, &book);
+    }
+
+    /// Up to `depth` live quotes for a pair, best first.
+    pub fn depth(env: &Env, base: &Asset, quote: &Asset, depth: u32) -> Vec<QuoteData> {
+        let now = env.ledger().timestamp();
+        let mut levels = Vec::new(env);
+        for entry in Storage::get_order_book(env, base, quote).iter() {
+            if levels.len() >= depth {
+                break;
+            }
+            if entry.valid_until > now {
+                levels.push_back(entry);
+            }
+        }
+        levels
+    }
+
+    fn ranks_before(a: &QuoteData, b: &QuoteData) -> bool {
+        (a.rate, a.fee_percentage, a.quote_id) < (b.rate, b.fee_percentage, b.quote_id)
+    }
+}
@@ -0,0 +1,387 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::signer::Signer;
+use crate::stellar_cli::{self, Network};
+
+/// Profile used when neither `--profile` nor the config file picks one
+pub const DEFAULT_PROFILE: &str = "testnet";
+
+/// Profiles every install has, before any config file overrides
+const BUILTIN_PROFILES: [&str; 4] = ["testnet", "mainnet", "futurenet", "local"];
+
+/// RPC URL of a local `stellar container` quickstart node
+const LOCAL_RPC_URL: &str = "http://localhost:8000/soroban/rpc";
+
+/// Defaults for the network, contract and identity flags of every command.
+/// Flags and environment variables still take precedence.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    /// `stellar` CLI network name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rpc_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_passphrase: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract_id: Option<String>,
+    /// `stellar keys` identity name or signer (`keystore:`, `external:`,
+    /// `ledger:`); secret keys are never stored
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+/// Profiles file, `~/.anchorkit/config.json` unless `ANCHORKIT_CONFIG` is set
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ProfileConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_profile: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+impl ProfileConfig {
+    pub fn path() -> Result<PathBuf, String> {
+        if let Ok(path) = std::env::var("ANCHORKIT_CONFIG") {
+            return Ok(PathBuf::from(path));
+        }
+        std::env::var("HOME")
+            .map(|home| PathBuf::from(home).join(".anchorkit").join("config.json"))
+            .map_err(|_| "HOME is not set; set ANCHORKIT_CONFIG to the profiles file".to_string())
+    }
+
+    /// Read the profiles file. A missing file is an empty config.
+    pub fn load() -> Result<Self, String> {
+        let path = Self::path()?;
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| format!("invalid profiles file {}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("failed to read {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self) -> Result<PathBuf, String> {
+        let path = Self::path()?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("failed to create {}: {}", dir.display(), e))?;
+        }
+        let contents = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(&path, contents + "\n")
+            .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+        Ok(path)
+    }
+
+    /// Resolve `name`, the configured default or `DEFAULT_PROFILE`. Fields set
+    /// in the file override the built-in profile of the same name.
+    pub fn profile(&self, name: Option<&str>) -> Result<(String, Profile), String> {
+        let name = name
+            .or(self.default_profile.as_deref())
+            .unwrap_or(DEFAULT_PROFILE)
+            .to_string();
+        let builtin = Profile::builtin(&name);
+        let profile = match (builtin, self.profiles.get(&name)) {
+            (Some(builtin), Some(configured)) => builtin.overlay(configured),
+            (Some(builtin), None) => builtin,
+            (None, Some(configured)) => configured.clone(),
+            (None, None) => {
+                return Err(format!(
+                    "unknown profile {}; create it with `anchorkit profile set {}`",
+                    name, name
+                ))
+            }
+        };
+        Ok((name, profile))
+    }
+
+    /// Built-in and configured profile names.
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = BUILTIN_PROFILES.iter().map(|n| n.to_string()).collect();
+        for name in self.profiles.keys() {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+        names
+    }
+}
+
+impl Profile {
+    fn builtin(name: &str) -> Option<Self> {
+        if !BUILTIN_PROFILES.contains(&name) {
+            return None;
+        }
+        Some(Self {
+            network: Some(name.to_string()),
+            rpc_url: (name == "local").then(|| LOCAL_RPC_URL.to_string()),
+            network_passphrase: stellar_cli::known_passphrase(name).map(str::to_string),
+            contract_id: None,
+            source: None,
+        })
+    }
+
+    /// This profile with every field set in `other` replaced.
+    pub fn overlay(&self, other: &Profile) -> Self {
+        Self {
+            network: other.network.clone().or_else(|| self.network.clone()),
+            rpc_url: other.rpc_url.clone().or_else(|| self.rpc_url.clone()),
+            network_passphrase: other
+                .network_passphrase
+                .clone()
+                .or_else(|| self.network_passphrase.clone()),
+            contract_id: other
+                .contract_id
+                .clone()
+                .or_else(|| self.contract_id.clone()),
+            source: other.source.clone().or_else(|| self.source.clone()),
+        }
+    }
+
+    /// Network to use given the `--network` and `--rpc-url` flags. An explicit
+    /// `--network` replaces the profile's network settings entirely.
+    pub fn network(&self, name: Option<String>, rpc_url: Option<String>) -> Network {
+        match name {
+            Some(name) => Network {
+                name,
+                rpc_url,
+                passphrase: None,
+            },
+            None => Network {
+                name: self
+                    .network
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_PROFILE.to_string()),
+                rpc_url: rpc_url.or_else(|| self.rpc_url.clone()),
+                passphrase: self.network_passphrase.clone(),
+            },
+        }
+    }
+
+    /// Network name for display, given the `--network` flag.
+    pub fn network_name(&self, name: Option<String>) -> String {
+        self.network(name, None).name
+    }
+
+    pub fn contract_id(&self, flag: Option<String>) -> Result<String, String> {
+        flag.or_else(|| self.contract_id.clone()).ok_or_else(|| {
+            "--contract is required (or set contract_id with `anchorkit profile set`)".to_string()
+        })
+    }
+
+    pub fn source(&self, flag: Option<String>) -> Result<String, String> {
+        flag.or_else(|| self.source.clone()).ok_or_else(|| {
+            "--source is required (or set source with `anchorkit profile set`)".to_string()
+        })
+    }
+
+    /// Signer for the `--source` flag or the profile's source.
+    pub fn signer(&self, flag: Option<String>) -> Result<Signer, String> {
+        Signer::parse(&self.source(flag)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(json: &str) -> ProfileConfig {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn staging() -> Profile {
+        Profile {
+            network: Some("staging".to_string()),
+            rpc_url: Some("https://rpc.staging.example.com".to_string()),
+            network_passphrase: Some("Staging Network".to_string()),
+            contract_id: Some("CSTAGING".to_string()),
+            source: Some("keystore:ops.json".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_parse_config_file() {
+        let config = config(
+            r#"{
+                "default_profile": "staging",
+                "profiles": {
+                    "staging": {
+                        "network": "staging",
+                        "rpc_url": "https://rpc.staging.example.com",
+                        "network_passphrase": "Staging Network",
+                        "contract_id": "CSTAGING",
+                        "source": "keystore:ops.json"
+                    },
+                    "testnet": { "contract_id": "CTESTNET" }
+                }
+            }"#,
+        );
+        assert_eq!(config.default_profile.as_deref(), Some("staging"));
+        assert_eq!(config.profiles["staging"], staging());
+        assert_eq!(
+            config.profiles["testnet"],
+            Profile {
+                contract_id: Some("CTESTNET".to_string()),
+                ..Profile::default()
+            }
+        );
+
+        let empty = self::config("{}");
+        assert_eq!(empty.default_profile, None);
+        assert!(empty.profiles.is_empty());
+        assert!(serde_json::from_str::<ProfileConfig>(r#"{"profiles": []}"#).is_err());
+    }
+
+    #[test]
+    fn test_unset_fields_are_not_written() {
+        let mut config = ProfileConfig::default();
+        config.profiles.insert(
+            "testnet".to_string(),
+            Profile {
+                contract_id: Some("CTESTNET".to_string()),
+                ..Profile::default()
+            },
+        );
+        assert_eq!(
+            serde_json::to_string(&config).unwrap(),
+            r#"{"profiles":{"testnet":{"contract_id":"CTESTNET"}}}"#
+        );
+    }
+
+    #[test]
+    fn test_profile_selection() {
+        let mut config = ProfileConfig::default();
+        assert_eq!(config.profile(None).unwrap().0, DEFAULT_PROFILE);
+        assert_eq!(config.profile(Some("mainnet")).unwrap().0, "mainnet");
+
+        config.profiles.insert("staging".to_string(), staging());
+        config.default_profile = Some("staging".to_string());
+        assert_eq!(
+            config.profile(None).unwrap(),
+            ("staging".to_string(), staging())
+        );
+        assert_eq!(config.profile(Some("local")).unwrap().0, "local");
+
+        assert_eq!(
+            config.profile(Some("devnet")),
+            Err(
+                "unknown profile devnet; create it with `anchorkit profile set devnet`".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_builtin_profiles() {
+        let config = ProfileConfig::default();
+        let (_, testnet) = config.profile(Some("testnet")).unwrap();
+        assert_eq!(testnet.network.as_deref(), Some("testnet"));
+        assert_eq!(testnet.rpc_url, None);
+        assert_eq!(
+            testnet.network_passphrase.as_deref(),
+            stellar_cli::known_passphrase("testnet")
+        );
+        assert_eq!(testnet.contract_id, None);
+        assert_eq!(testnet.source, None);
+
+        let (_, local) = config.profile(Some("local")).unwrap();
+        assert_eq!(local.rpc_url.as_deref(), Some(LOCAL_RPC_URL));
+    }
+
+    #[test]
+    fn test_configured_fields_override_builtin_ones() {
+        let config = config(
+            r#"{ "profiles": { "mainnet": {
+                "rpc_url": "https://rpc.example.com",
+                "contract_id": "CMAINNET"
+            } } }"#,
+        );
+        let (_, mainnet) = config.profile(Some("mainnet")).unwrap();
+        assert_eq!(
+            mainnet,
+            Profile {
+                network: Some("mainnet".to_string()),
+                rpc_url: Some("https://rpc.example.com".to_string()),
+                network_passphrase: stellar_cli::known_passphrase("mainnet").map(str::to_string),
+                contract_id: Some("CMAINNET".to_string()),
+                source: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_names() {
+        let mut config = ProfileConfig::default();
+        config
+            .profiles
+            .insert("testnet".to_string(), Profile::default());
+        config.profiles.insert("staging".to_string(), staging());
+        assert_eq!(
+            config.names(),
+            ["testnet", "mainnet", "futurenet", "local", "staging"]
+        );
+    }
+
+    #[test]
+    fn test_flags_take_precedence() {
+        let profile = staging();
+
+        let network = profile.network(None, None);
+        assert_eq!(network.name, "staging");
+        assert_eq!(network.rpc_url, profile.rpc_url);
+        assert_eq!(network.passphrase, profile.network_passphrase);
+
+        let network = profile.network(None, Some("https://rpc.example.com".to_string()));
+        assert_eq!(network.rpc_url.as_deref(), Some("https://rpc.example.com"));
+        assert_eq!(network.passphrase, profile.network_passphrase);
+
+        let network = profile.network(Some("mainnet".to_string()), None);
+        assert_eq!(network.name, "mainnet");
+        assert_eq!(network.rpc_url, None);
+        assert_eq!(network.passphrase, None);
+        assert_eq!(Profile::default().network_name(None), DEFAULT_PROFILE);
+
+        assert_eq!(profile.contract_id(None).unwrap(), "CSTAGING");
+        assert_eq!(
+            profile.contract_id(Some("CFLAG".to_string())).unwrap(),
+            "CFLAG"
+        );
+        assert_eq!(profile.source(None).unwrap(), "keystore:ops.json");
+        assert_eq!(profile.source(Some("alice".to_string())).unwrap(), "alice");
+        assert!(Profile::default().contract_id(None).is_err());
+        assert!(Profile::default().source(None).is_err());
+    }
+
+    #[test]
+    fn test_load_and_save() {
+        let dir = std::env::temp_dir().join(format!("anchorkit-profile-{}", std::process::id()));
+        let path = dir.join("config.json");
+        std::env::set_var("ANCHORKIT_CONFIG", &path);
+
+        let missing = ProfileConfig::load().unwrap();
+        assert_eq!(missing.default_profile, None);
+        assert!(missing.profiles.is_empty());
+
+        let mut config = ProfileConfig {
+            default_profile: Some("staging".to_string()),
+            ..ProfileConfig::default()
+        };
+        config.profiles.insert("staging".to_string(), staging());
+        assert_eq!(config.save().unwrap(), path);
+
+        let loaded = ProfileConfig::load().unwrap();
+        assert_eq!(loaded.default_profile.as_deref(), Some("staging"));
+        assert_eq!(loaded.profiles, config.profiles);
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(ProfileConfig::load()
+            .err()
+            .unwrap()
+            .starts_with(&format!("invalid profiles file {}", path.display())));
+
+        std::env::remove_var("ANCHORKIT_CONFIG");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,155 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    config,
+    errors::Error,
+    storage::Storage,
+    types::{CapacityReservation, QuoteCapacity, QuoteData, TransactionIntent},
+};
+
+/// Quote capacity held back for partner integrators.
+///
+/// An anchor can cap the total sell amount intents may draw from one of its
+/// quotes and reserve part of it for partner addresses. An intent's partner
+/// is its `referrer`: a partner draws on its own reservation first and then
+/// on the unreserved remainder, which is all anyone else can use. Quotes
+/// without a cap aren't tracked. Capacity is checked when an intent is built
+/// but only drawn when it executes, so intents left to expire hold none.
+pub struct ReservedCapacity;
+
+impl ReservedCapacity {
+    /// Cap the sell amount intents may draw from `quote` in total. The cap
+    /// can't go below what is already used plus what is still reserved.
+    pub fn set_capacity(
+        env: &Env,
+        quote: &QuoteData,
+        capacity: u64,
+    ) -> Result<QuoteCapacity, Error> {
+        let mut record = Storage::get_quote_capacity(env, &quote.anchor, quote.quote_id).unwrap_or(
+            QuoteCapacity {
+                capacity,
+                used: 0,
+                reservations: Vec::new(env),
+            },
+        );
+        if capacity < record.used + Self::reserved_remaining(&record) {
+            return Err(Error::InvalidConfig);
+        }
+
+        record.capacity = capacity;
+        Storage::set_quote_capacity(env, &quote.anchor, quote.quote_id, &record);
+        Ok(record)
+    }
+
+    /// Reserve `amount` of a capped quote's capacity for `partner`, replacing
+    /// its previous reservation. The reservation can't drop below what the
+    /// partner already drew from it; reserving that much closes it.
+    pub fn reserve(
+        env: &Env,
+        quote: &QuoteData,
+        partner: &Address,
+        amount: u64,
+    ) -> Result<QuoteCapacity, Error> {
+        let mut record = Storage::get_quote_capacity(env, &quote.anchor, quote.quote_id)
+            .ok_or(Error::NotFound)?;
+
+        let index = record
+            .reservations
+            .iter()
+            .position(|r| &r.partner == partner);
+        let mut reservation = match index {
+            Some(index) => record.reservations.get_unchecked(index as u32),
+            None if record.reservations.len() >= config::MAX_RESERVATIONS => {
+                return Err(Error::InvalidConfig)
+            }
+            None => CapacityReservation {
+                partner: partner.clone(),
+                amount: 0,
+                used: 0,
+            },
+        };
+        if amount < reservation.used {
+            return Err(Error::InvalidConfig);
+        }
+        let others = Self::reserved_remaining(&record) - (reservation.amount - reservation.used);
+        if record.used + others + (amount - reservation.used) > record.capacity {
+            return Err(Error::InvalidConfig);
+        }
+
+        reservation.amount = amount;
+        match index {
+            Some(index) => record.reservations.set(index as u32, reservation),
+            None => record.reservations.push_back(reservation),
+        }
+        Storage::set_quote_capacity(env, &quote.anchor, quote.quote_id, &record);
+        Ok(record)
+    }
+
+    /// Sell amount `partner` may still draw from `quote`, or `None` if the
+    /// quote isn't capped.
+    pub fn available(env: &Env, quote: &QuoteData, partner: &Option<Address>) -> Option<u64> {
+        let record = Storage::get_quote_capacity(env, &quote.anchor, quote.quote_id)?;
+        let unreserved = record.capacity - record.used - Self::reserved_remaining(&record);
+        let own = Self::reservation(&record, partner).map_or(0, |r| r.amount - r.used);
+        Some(unreserved + own)
+    }
+
+    /// Require the partner's authorization for an intent built against its
+    /// reservation on `quote`, unless the partner is the intent's subject and
+    /// already authorized the build.
+    pub fn authorize(env: &Env, quote: &QuoteData, intent: &TransactionIntent) {
+        let Some(record) = Storage::get_quote_capacity(env, &quote.anchor, quote.quote_id) else {
+            return;
+        };
+        if let Some(reservation) = Self::reservation(&record, &intent.referrer) {
+            if intent.subject.as_ref() != Some(&reservation.partner) {
+                reservation.partner.require_auth();
+            }
+        }
+    }
+
+    /// Draw executing `intent`'s sell amount from its quote's capacity, from
+    /// the partner's reservation first. Fails with `InvalidQuote` if the
+    /// amount is no longer available to the partner.
+    pub fn consume(env: &Env, quote: &QuoteData, intent: &TransactionIntent) -> Result<(), Error> {
+        let Some(mut record) = Storage::get_quote_capacity(env, &quote.anchor, quote.quote_id)
+        else {
+            return Ok(());
+        };
+        let amount = quote.sell_amount_for(env, &intent.request);
+        if amount > Self::available(env, quote, &intent.referrer).unwrap_or(0) {
+            return Err(Error::InvalidQuote);
+        }
+
+        if let Some(partner) = &intent.referrer {
+            let index = record
+                .reservations
+                .iter()
+                .position(|r| &r.partner == partner);
+            if let Some(index) = index {
+                let mut reservation = record.reservations.get_unchecked(index as u32);
+                let drawn = amount.min(reservation.amount - reservation.used);
+                if drawn > 0 {
+                    reservation.used += drawn;
+                    record.reservations.set(index as u32, reservation);
+                }
+            }
+        }
+        record.used += amount;
+        Storage::set_quote_capacity(env, &quote.anchor, quote.quote_id, &record);
+        Ok(())
+    }
+
+    fn reservation(
+        record: &QuoteCapacity,
+        partner: &Option<Address>,
+    ) -> Option<CapacityReservation> {
+        let partner = partner.as_ref()?;
+        record.reservations.iter().find(|r| &r.partner == partner)
+    }
+
+    /// Reserved capacity partners haven't drawn yet.
+    fn reserved_remaining(record: &QuoteCapacity) -> u64 {
+        record.reservations.iter().map(|r| r.amount - r.used).sum()
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod quote_capacity_tests {
+    use crate::testutils::{advance_time, AnchorKitFixture};
+    use crate::{CapacityReservation, Error, ServiceType};
+    use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+    fn funded_token(kit: &AnchorKitFixture) -> Address {
+        let token = kit
+            .env
+            .register_stellar_asset_contract_v2(kit.admin.clone())
+            .address();
+        kit.client.add_supported_token(&token);
+        token::StellarAssetClient::new(kit.env, &token).mint(&kit.user, &10_000);
+        token::Client::new(kit.env, &token).approve(&kit.user, &kit.contract_id, &10_000, &1000);
+        token
+    }
+
+    #[test]
+    fn test_reserved_capacity_is_only_drawn_by_its_partner() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+        let quote_id = kit.seed_quote(&anchor, "USDC", "EUR", 9_200, 600);
+        let token = funded_token(&kit);
+        let partner = Address::generate(&env);
+        let other = Address::generate(&env);
+        let intent = |amount: u64, referrer: &Address| {
+            kit.deposit_intent(&anchor, "USDC", "EUR", amount)
+                .with_quote_id(quote_id)
+                .with_quote_sequence(1)
+                .with_referrer(referrer.clone())
+        };
+        let execute = |amount: u64, referrer: &Address| {
+            let intent_id = kit
+                .client
+                .build_transaction_intent(&intent(amount, referrer))
+                .intent_id;
+            kit.client.execute_intent(&intent_id, &kit.user, &token);
+        };
+
+        assert_eq!(
+            kit.client
+                .try_reserve_quote_capacity(&anchor, &quote_id, &partner, &600),
+            Err(Ok(Error::NotFound))
+        );
+        kit.client.set_quote_capacity(&anchor, &quote_id, &1_000);
+        kit.client
+            .reserve_quote_capacity(&anchor, &quote_id, &partner, &600);
+        assert_eq!(
+            kit.client
+                .try_reserve_quote_capacity(&anchor, &quote_id, &other, &500),
+            Err(Ok(Error::InvalidConfig))
+        );
+
+        // Others only get the unreserved 400
+        assert_eq!(
+            kit.client
+                .try_build_transaction_intent(&intent(500, &other)),
+            Err(Ok(Error::InvalidQuote))
+        );
+        execute(400, &other);
+        assert_eq!(
+            kit.client.try_build_transaction_intent(&intent(1, &other)),
+            Err(Ok(Error::InvalidQuote))
+        );
+
+        // The partner still has its reservation
+        assert_eq!(
+            kit.client
+                .try_build_transaction_intent(&intent(700, &partner)),
+            Err(Ok(Error::InvalidQuote))
+        );
+        execute(600, &partner);
+
+        let capacity = kit.client.get_quote_capacity(&anchor, &quote_id).unwrap();
+        assert_eq!(capacity.used, 1_000);
+        assert_eq!(
+            capacity.reservations.get(0),
+            Some(CapacityReservation {
+                partner: partner.clone(),
+                amount: 600,
+                used: 600,
+            })
+        );
+        assert_eq!(
+            kit.client.try_set_quote_capacity(&anchor, &quote_id, &900),
+            Err(Ok(Error::InvalidConfig))
+        );
+        assert_eq!(
+            kit.client
+                .try_reserve_quote_capacity(&anchor, &quote_id, &partner, &500),
+            Err(Ok(Error::InvalidConfig))
+        );
+    }
+
+    #[test]
+    fn test_capacity_is_drawn_on_execution() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+        let quote_id = kit.seed_quote(&anchor, "USDC", "EUR", 9_200, 600);
+        let token = funded_token(&kit);
+        kit.client.set_quote_capacity(&anchor, &quote_id, &1_000);
+        let intent = |amount: u64| {
+            kit.deposit_intent(&anchor, "USDC", "EUR", amount)
+                .with_quote_id(quote_id)
+                .with_quote_sequence(1)
+                .with_ttl(60)
+        };
+
+        // Intents left to expire don't hold capacity
+        kit.client.build_transaction_intent(&intent(1_000));
+        advance_time(&env, 61);
+        let first = kit.client.build_transaction_intent(&intent(700)).intent_id;
+        let second = kit.client.build_transaction_intent(&intent(700)).intent_id;
+        let capacity = kit.client.get_quote_capacity(&anchor, &quote_id).unwrap();
+        assert_eq!(capacity.used, 0);
+
+        // Whichever executes first gets it
+        kit.client.execute_intent(&second, &kit.user, &token);
+        assert_eq!(
+            kit.client.try_execute_intent(&first, &kit.user, &token),
+            Err(Ok(Error::InvalidQuote))
+        );
+        let capacity = kit.client.get_quote_capacity(&anchor, &quote_id).unwrap();
+        assert_eq!(capacity.used, 700);
+    }
+}
@@ -0,0 +1,410 @@
+use serde_json::{json, Value};
+
+use crate::stellar_cli::{self, Network};
+
+/// Rates and fees are expressed in basis points (10000 = 1.0 / 100%)
+const BPS: u128 = 10_000;
+
+/// `ServiceType::Quotes`, `QuoteContext::Sep6` and `AmountSide` as encoded by the contract
+const SERVICE_QUOTES: u32 = 3;
+const CONTEXT_SEP6: u32 = 1;
+pub const SIDE_SELL: u32 = 1;
+pub const SIDE_BUY: u32 = 2;
+
+/// Contract error code returned when no anchor has a matching quote
+const NO_QUOTES_AVAILABLE: &str = "#17";
+/// Contract error code returned when no address is registered under an alias
+const NOT_FOUND: &str = "#59";
+
+/// What to compare, and which deployed contract to ask
+pub struct CompareOptions {
+    pub contract_id: String,
+    /// Identity name or secret key used to simulate the calls
+    pub source: String,
+    pub network: Network,
+    /// Sold asset: `native`, a token contract ID or `CODE:ISSUER`
+    pub base: String,
+    /// Bought asset, in the same notation as `base`
+    pub quote: String,
+    /// Decimal amount, e.g. `100` or `12.5`
+    pub amount: String,
+    /// Whether `amount` is of the bought asset rather than the sold one
+    pub buy: bool,
+    /// Anchors to compare, as addresses or on-chain aliases; all registered
+    /// anchors when empty
+    pub anchors: Vec<String>,
+}
+
+/// One anchor's valid quote
+pub struct QuoteRow {
+    pub anchor: String,
+    pub quote_id: u64,
+    pub rate: u64,
+    pub fee_bps: u64,
+    pub effective_rate: u64,
+    pub best: bool,
+}
+
+/// Quotes returned by `compare_rates_for_anchors`, best first
+pub struct Comparison {
+    pub rows: Vec<QuoteRow>,
+    /// Requested amount in the smallest units of its asset
+    pub amount: u64,
+    pub decimals: u32,
+}
+
+/// Simulate `compare_rates_for_anchors` on the deployed contract.
+pub fn compare(options: &CompareOptions) -> Result<Comparison, String> {
+    stellar_cli::require_cli()?;
+
+    let base = asset_json(&options.base)?;
+    let quote = asset_json(&options.quote)?;
+    let amount_asset = if options.buy { &quote } else { &base };
+    let decimals: u32 = simulate(options, "get_asset_decimals", &[("asset", amount_asset)])?
+        .parse()
+        .map_err(|_| "unexpected get_asset_decimals output".to_string())?;
+    let amount = parse_amount(&options.amount, decimals)?;
+
+    let anchors = if options.anchors.is_empty() {
+        serde_json::from_str(&simulate(options, "get_all_anchors", &[])?)
+            .map_err(|e| format!("unexpected get_all_anchors output: {}", e))?
+    } else {
+        let mut anchors = Vec::new();
+        for anchor in &options.anchors {
+            anchors.push(resolve_anchor(options, anchor)?);
+        }
+        json!(anchors)
+    };
+
+    let request = json!({
+        "base_asset": base,
+        "quote_asset": quote,
+        "amount": amount,
+        "operation_type": SERVICE_QUOTES,
+        "context": CONTEXT_SEP6,
+        "amount_side": if options.buy { SIDE_BUY } else { SIDE_SELL },
+        "delivery_method": null,
+        "country_code": null,
+    });
+    let output = simulate(
+        options,
+        "compare_rates_for_anchors",
+        &[("request", &request), ("anchors", &anchors)],
+    )
+    .map_err(|e| {
+        if e.contains(NO_QUOTES_AVAILABLE) {
+            "no anchor has a valid quote for this request".to_string()
+        } else {
+            e
+        }
+    })?;
+    Ok(Comparison {
+        rows: quote_rows(&output)?,
+        amount,
+        decimals,
+    })
+}
+
+/// Rows of a `compare_rates_for_anchors` result, cheapest first.
+fn quote_rows(output: &str) -> Result<Vec<QuoteRow>, String> {
+    let comparison: Value = serde_json::from_str(output)
+        .map_err(|e| format!("unexpected compare_rates_for_anchors output: {}", e))?;
+
+    let best_id = field_u64(&comparison["best_quote"], "quote_id")?;
+    let best_anchor = comparison["best_quote"]["anchor"]
+        .as_str()
+        .unwrap_or_default();
+    let mut rows = Vec::new();
+    for quote in comparison["all_quotes"].as_array().into_iter().flatten() {
+        let anchor = quote["anchor"].as_str().unwrap_or_default().to_string();
+        let quote_id = field_u64(quote, "quote_id")?;
+        let rate = field_u64(quote, "rate")?;
+        let fee_bps = field_u64(quote, "fee_percentage")?;
+        rows.push(QuoteRow {
+            best: quote_id == best_id && anchor == best_anchor,
+            anchor,
+            quote_id,
+            rate,
+            fee_bps,
+            effective_rate: effective_rate(rate, fee_bps),
+        });
+    }
+    rows.sort_by_key(|row| row.effective_rate);
+
+    Ok(rows)
+}
+
+/// Render the comparison as a table of anchors, rates, fees and effective cost.
+pub fn render_table(comparison: &Comparison) -> String {
+    let mut out = format!(
+        "  {:<13} {:>8} {:>10} {:>7} {:>10} {:>20}\n",
+        "Anchor", "Quote", "Rate", "Fee", "Eff. rate", "Effective cost"
+    );
+    for row in &comparison.rows {
+        let cost = comparison.amount as u128 * row.effective_rate as u128 / BPS;
+        out.push_str(&format!(
+            "{} {:<13} {:>8} {:>10} {:>6}% {:>10} {:>20}\n",
+            if row.best { "*" } else { " " },
+            short_address(&row.anchor),
+            row.quote_id,
+            format_units(row.rate as u128, 4),
+            format_units(row.fee_bps as u128, 2),
+            format_units(row.effective_rate as u128, 4),
+            format_units(cost, comparison.decimals),
+        ));
+    }
+    out
+}
+
+fn simulate(
+    options: &CompareOptions,
+    function: &str,
+    args: &[(&str, &Value)],
+) -> Result<String, String> {
+    let args: Vec<(&str, String)> = args
+        .iter()
+        .map(|(name, value)| (*name, value.to_string()))
+        .collect();
+    stellar_cli::simulate(
+        &options.contract_id,
+        &options.source,
+        &options.network,
+        function,
+        &args,
+    )
+}
+
+/// An anchor given as an address, or as an alias it registered with
+/// `set_alias` (e.g. `cowrie_ng`), as an address.
+fn resolve_anchor(options: &CompareOptions, anchor: &str) -> Result<String, String> {
+    if anchor.len() == 56 && (anchor.starts_with('G') || anchor.starts_with('C')) {
+        return Ok(anchor.to_string());
+    }
+    let output = simulate(options, "resolve_alias", &[("alias", &json!(anchor))]).map_err(|e| {
+        if e.contains(NOT_FOUND) {
+            format!("no anchor is registered under alias {}", anchor)
+        } else {
+            e
+        }
+    })?;
+    serde_json::from_str(&output).map_err(|_| "unexpected resolve_alias output".to_string())
+}
+
+/// Same ranking the contract uses: the rate with the fee applied on top.
+fn effective_rate(rate: u64, fee_bps: u64) -> u64 {
+    (rate as u128 * (BPS + fee_bps as u128) / BPS) as u64
+}
+
+/// Encode an asset in the `stellar` CLI's JSON notation for `Asset`.
+pub fn asset_json(asset: &str) -> Result<Value, String> {
+    if asset.eq_ignore_ascii_case("native") {
+        return Ok(json!("Native"));
+    }
+    if asset.len() == 56 && asset.starts_with('C') {
+        return Ok(json!({ "ContractToken": asset }));
+    }
+    match asset.split_once(':') {
+        Some((code, issuer))
+            if !code.is_empty() && issuer.len() == 56 && issuer.starts_with('G') =>
+        {
+            Ok(json!({ "Classic": [code, issuer] }))
+        }
+        _ => Err(format!(
+            "asset {} must be `native`, a token contract ID or CODE:ISSUER",
+            asset
+        )),
+    }
+}
+
+/// Parse a decimal amount into the smallest units of an asset with `decimals`.
+pub fn parse_amount(amount: &str, decimals: u32) -> Result<u64, String> {
+    let invalid = || format!("invalid amount {}", amount);
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if whole.is_empty() && fraction.is_empty() || fraction.len() > decimals as usize {
+        return Err(invalid());
+    }
+    let digits = format!("{}{:0<width$}", whole, fraction, width = decimals as usize);
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    match digits.parse::<u64>() {
+        Ok(0) | Err(_) => Err(invalid()),
+        Ok(units) => Ok(units),
+    }
+}
+
+/// Format `value` as a decimal with `decimals` fractional digits.
+pub fn format_units(value: u128, decimals: u32) -> String {
+    let scale = 10u128.pow(decimals);
+    if decimals == 0 {
+        return value.to_string();
+    }
+    format!(
+        "{}.{:0width$}",
+        value / scale,
+        value % scale,
+        width = decimals as usize
+    )
+}
+
+fn field_u64(value: &Value, field: &str) -> Result<u64, String> {
+    stellar_cli::json_u64(value, field).ok_or_else(|| format!("quote is missing {}", field))
+}
+
+pub fn short_address(address: &str) -> String {
+    if address.len() <= 13 {
+        return address.to_string();
+    }
+    format!("{}…{}", &address[..6], &address[address.len() - 6..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANCHOR_A: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+    const ANCHOR_B: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+    const TOKEN: &str = "CAS3J7GYLGXMF6TDJBBYYSE3HQ6BBSMLNUQ34T6TZMYMW2EVH34XOWMA";
+
+    fn quote(anchor: &str, quote_id: u64, rate: u64, fee_percentage: u64) -> Value {
+        json!({
+            "anchor": anchor,
+            "quote_id": quote_id,
+            "rate": rate.to_string(),
+            "fee_percentage": fee_percentage,
+        })
+    }
+
+    #[test]
+    fn test_parse_amount() {
+        assert_eq!(parse_amount("100", 7), Ok(1_000_000_000));
+        assert_eq!(parse_amount("12.5", 2), Ok(1250));
+        assert_eq!(parse_amount(".5", 1), Ok(5));
+        assert_eq!(parse_amount("5.", 2), Ok(500));
+        assert_eq!(parse_amount("7", 0), Ok(7));
+
+        for invalid in ["", ".", "1.234", "-1", "1e3", "1,5", "0", "0.00", "1.5.0"] {
+            assert_eq!(
+                parse_amount(invalid, 2),
+                Err(format!("invalid amount {}", invalid))
+            );
+        }
+        assert!(parse_amount("18446744073709551616", 0).is_err());
+    }
+
+    #[test]
+    fn test_asset_json() {
+        assert_eq!(asset_json("native"), Ok(json!("Native")));
+        assert_eq!(asset_json("NATIVE"), Ok(json!("Native")));
+        assert_eq!(asset_json(TOKEN), Ok(json!({ "ContractToken": TOKEN })));
+        assert_eq!(
+            asset_json(&format!("USDC:{}", ANCHOR_A)),
+            Ok(json!({ "Classic": ["USDC", ANCHOR_A] }))
+        );
+
+        for invalid in [
+            "USDC".to_string(),
+            "USDC:GISSUER".to_string(),
+            format!(":{}", ANCHOR_A),
+            format!("USDC:{}", TOKEN),
+        ] {
+            assert_eq!(
+                asset_json(&invalid),
+                Err(format!(
+                    "asset {} must be `native`, a token contract ID or CODE:ISSUER",
+                    invalid
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_units() {
+        assert_eq!(format_units(1_234_567, 4), "123.4567");
+        assert_eq!(format_units(5, 2), "0.05");
+        assert_eq!(format_units(42, 0), "42");
+    }
+
+    #[test]
+    fn test_short_address() {
+        assert_eq!(short_address(ANCHOR_B), "GBRPYH…C7OX2H");
+        assert_eq!(short_address("cowrie_ng"), "cowrie_ng");
+    }
+
+    #[test]
+    fn test_quote_rows_rank_by_effective_rate() {
+        let output = json!({
+            "best_quote": quote(ANCHOR_B, 2, 10_050, 0),
+            "all_quotes": [
+                quote(ANCHOR_A, 1, 10_000, 100),
+                quote(ANCHOR_B, 2, 10_050, 0),
+            ],
+        })
+        .to_string();
+
+        let rows = quote_rows(&output).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].anchor, ANCHOR_B);
+        assert_eq!(rows[0].effective_rate, 10_050);
+        assert!(rows[0].best);
+        assert_eq!(rows[1].anchor, ANCHOR_A);
+        assert_eq!(rows[1].quote_id, 1);
+        assert_eq!(rows[1].rate, 10_000);
+        assert_eq!(rows[1].fee_bps, 100);
+        assert_eq!(rows[1].effective_rate, 10_100);
+        assert!(!rows[1].best);
+    }
+
+    #[test]
+    fn test_quote_rows_reject_malformed_output() {
+        assert!(quote_rows("Error: simulation failed")
+            .err()
+            .unwrap()
+            .starts_with("unexpected compare_rates_for_anchors output"));
+
+        let output = json!({
+            "best_quote": quote(ANCHOR_A, 1, 10_000, 0),
+            "all_quotes": [{ "anchor": ANCHOR_A, "quote_id": 1, "rate": 10_000 }],
+        })
+        .to_string();
+        assert_eq!(
+            quote_rows(&output).err(),
+            Some("quote is missing fee_percentage".to_string())
+        );
+    }
+
+    #[test]
+    fn test_render_table() {
+        let comparison = Comparison {
+            rows: vec![
+                QuoteRow {
+                    anchor: ANCHOR_B.to_string(),
+                    quote_id: 2,
+                    rate: 10_050,
+                    fee_bps: 0,
+                    effective_rate: 10_050,
+                    best: true,
+                },
+                QuoteRow {
+                    anchor: ANCHOR_A.to_string(),
+                    quote_id: 1,
+                    rate: 10_000,
+                    fee_bps: 100,
+                    effective_rate: 10_100,
+                    best: false,
+                },
+            ],
+            amount: 1_000_000_000,
+            decimals: 7,
+        };
+
+        assert_eq!(
+            render_table(&comparison),
+            concat!(
+                "  Anchor           Quote       Rate     Fee  Eff. rate       Effective cost\n",
+                "* GBRPYH…C7OX2H        2     1.0050   0.00%     1.0050          100.5000000\n",
+                "  GAAAAA…AAAWHF        1     1.0000   1.00%     1.0100          101.0000000\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod quote_exclusion_tests {
+    use crate::testutils::{advance_time, classic_asset, AnchorKitFixture};
+    use crate::{Error, ExclusionReason, QuoteExclusion, RoutingRequest, RoutingStrategy, ServiceType};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+    #[test]
+    fn test_comparison_reports_excluded_quotes() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let services = [ServiceType::Quotes];
+        let live = kit.register_anchor(&services);
+        let expired = kit.register_anchor(&services);
+        let bounded = kit.register_anchor(&services);
+        let silent = Address::generate(&env);
+
+        let expired_quote = kit.seed_quote(&expired, "USDC", "EUR", 9_300, 60);
+        advance_time(&env, 120);
+        let live_quote = kit.seed_quote(&live, "USDC", "EUR", 9_100, 600);
+        // A newer quote for another pair doesn't hide it
+        kit.seed_quote(&live, "USDC", "GBP", 8_000, 600);
+        let bounded_quote = kit.client.submit_quote(
+            &bounded,
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &9_000,
+            &0,
+            &1,
+            &500,
+            &(env.ledger().timestamp() + 600),
+        );
+
+        let request = kit.deposit_intent(&live, "USDC", "EUR", 1_000).request;
+        let anchors = vec![
+            &env,
+            live.clone(),
+            expired.clone(),
+            silent.clone(),
+            bounded.clone(),
+        ];
+        let comparison = kit.client.compare_rates_with_exclusions(&request, &anchors);
+
+        assert_eq!(comparison.best_quote.quote_id, live_quote);
+        assert_eq!(comparison.all_quotes.len(), 1);
+        assert_eq!(
+            comparison.exclusions,
+            vec![
+                &env,
+                QuoteExclusion {
+                    anchor: expired,
+                    quote_id: expired_quote,
+                    reason: ExclusionReason::Expired,
+                },
+                QuoteExclusion {
+                    anchor: silent,
+                    quote_id: 0,
+                    reason: ExclusionReason::NoQuote,
+                },
+                QuoteExclusion {
+                    anchor: bounded,
+                    quote_id: bounded_quote,
+                    reason: ExclusionReason::OutOfBounds,
+                },
+            ]
+        );
+
+        // The plain comparison doesn't report them
+        let plain = kit.client.compare_rates_for_anchors(&request, &anchors);
+        assert!(plain.exclusions.is_empty());
+        assert_eq!(plain.best_quote, comparison.best_quote);
+    }
+
+    #[test]
+    fn test_comparison_excludes_quotes_it_cannot_price() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let services = [ServiceType::Quotes];
+        let unbounded = kit.register_anchor(&services);
+        let unbounded_quote = kit.client.submit_quote(
+            &unbounded,
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &9_000,
+            &100,
+            &0,
+            &u64::MAX,
+            &(env.ledger().timestamp() + 600),
+        );
+        let anchors = vec![&env, unbounded.clone()];
+
+        // A zero amount and a fee that overflows can't be priced
+        for amount in [0, u64::MAX] {
+            let mut request = kit.deposit_intent(&unbounded, "USDC", "EUR", 1).request;
+            request.amount = amount;
+            assert_eq!(
+                kit.client.try_compare_rates_with_exclusions(&request, &anchors),
+                Err(Ok(Error::NoQuotesAvailable))
+            );
+        }
+
+        let request = kit.deposit_intent(&unbounded, "USDC", "EUR", 1_000).request;
+        let comparison = kit.client.compare_rates_with_exclusions(&request, &anchors);
+        assert_eq!(comparison.best_quote.quote_id, unbounded_quote);
+    }
+
+    #[test]
+    fn test_route_reports_excluded_anchors() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let services = [ServiceType::Deposits, ServiceType::Quotes];
+        let selected = kit.register_anchor(&services);
+        let unrated = kit.register_anchor(&services);
+        let stale = kit.register_anchor(&services);
+        let quotes_only = kit.register_anchor(&[ServiceType::Quotes]);
+        kit.client
+            .set_anchor_metadata(&selected, &9000, &600, &8000, &9900, &1_000_000);
+        kit.client
+            .set_anchor_metadata(&unrated, &4000, &600, &8000, &9900, &1_000_000);
+        kit.client
+            .set_anchor_metadata(&stale, &9000, &600, &8000, &9900, &1_000_000);
+        kit.client
+            .set_anchor_metadata(&quotes_only, &9000, &600, &8000, &9900, &1_000_000);
+        let stale_quote = kit.seed_quote(&stale, "USDC", "EUR", 9_500, 60);
+        for anchor in [&selected, &unrated, &quotes_only] {
+            kit.seed_quote(anchor, "USDC", "EUR", 9_000, 600);
+        }
+        advance_time(&env, 120);
+
+        let mut request = RoutingRequest {
+            request: kit.deposit_intent(&selected, "USDC", "EUR", 1_000).request,
+            strategy: RoutingStrategy::BestRate,
+            max_anchors: 4,
+            require_kyc: false,
+            min_reputation: 5000,
+            corridor_id: 0,
+            record_exclusions: true,
+        };
+        let result = kit.client.route_transaction(&kit.admin, &request);
+
+        assert_eq!(result.selected_anchor, selected);
+        let exclusion = |anchor: &Address, quote_id, reason| QuoteExclusion {
+            anchor: anchor.clone(),
+            quote_id,
+            reason,
+        };
+        assert_eq!(
+            result.exclusions,
+            vec![
+                &env,
+                exclusion(&unrated, 0, ExclusionReason::BelowReputation),
+                exclusion(&stale, stale_quote, ExclusionReason::Expired),
+                exclusion(&quotes_only, 0, ExclusionReason::ServiceUnsupported),
+            ]
+        );
+
+        request.record_exclusions = false;
+        assert!(kit.client.route_transaction(&kit.admin, &request).exclusions.is_empty());
+    }
+}
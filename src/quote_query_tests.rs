@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod quote_query_tests {
+    use crate::testutils::{classic_asset, set_time, AnchorKitFixture};
+    use crate::{Error, QuoteQueryOptions, ServiceType};
+    use soroban_sdk::{
+        testutils::{Address as _, AuthorizedFunction, Events},
+        vec, Address, Env, IntoVal, Symbol, Vec,
+    };
+
+    #[test]
+    fn test_read_only_query_needs_no_auth_and_emits_nothing() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Quotes]);
+        let quote_id = kit.seed_quote(&anchor, "USDC", "EUR", 9200, 60);
+
+        let events_before = env.events().all().len();
+        let quote = kit
+            .client
+            .query_quote(&anchor, &quote_id, &QuoteQueryOptions::read_only());
+        assert_eq!(quote.rate, 9200);
+        assert!(env.auths().is_empty());
+        assert_eq!(env.events().all().len(), events_before);
+
+        let result = kit
+            .client
+            .try_query_quote(&anchor, &99, &QuoteQueryOptions::read_only());
+        assert_eq!(result, Err(Ok(Error::QuoteNotFound)));
+    }
+
+    #[test]
+    fn test_notify_query_requires_receiver_auth_and_emits_event() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Quotes]);
+        let quote_id = kit.seed_quote(&anchor, "USDC", "EUR", 9200, 60);
+        let receiver = Address::generate(&env);
+
+        kit.client.query_quote(
+            &anchor,
+            &quote_id,
+            &QuoteQueryOptions::notify(receiver.clone()),
+        );
+
+        let auths = env.auths();
+        assert_eq!(auths.len(), 1);
+        assert_eq!(auths[0].0, receiver);
+        match &auths[0].1.function {
+            AuthorizedFunction::Contract((_, function, args)) => {
+                assert_eq!(function, &Symbol::new(&env, "query_quote"));
+                assert_eq!(args, &(anchor.clone(), quote_id).into_val(&env));
+            }
+            _ => panic!("unexpected authorized function"),
+        }
+
+        let (_, topics, _) = env.events().all().last().unwrap();
+        assert_eq!(
+            topics,
+            (
+                Symbol::new(&env, "quote"),
+                Symbol::new(&env, "received"),
+                quote_id
+            )
+                .into_val(&env)
+        );
+    }
+
+    #[test]
+    fn test_ack_quote_received() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Quotes]);
+        let quote_id = kit.seed_quote(&anchor, "USDC", "EUR", 9200, 60);
+        let receiver = Address::generate(&env);
+
+        let events_before = env.events().all().len();
+        kit.client.ack_quote_received(&receiver, &anchor, &quote_id);
+        assert_eq!(env.auths()[0].0, receiver);
+        assert_eq!(env.events().all().len(), events_before + 1);
+
+        let result = kit.client.try_ack_quote_received(&receiver, &anchor, &99);
+        assert_eq!(result, Err(Ok(Error::QuoteNotFound)));
+    }
+
+    #[test]
+    fn test_order_book_depth() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let a = kit.register_anchor(&[ServiceType::Quotes]);
+        let b = kit.register_anchor(&[ServiceType::Quotes]);
+        let c = kit.register_anchor(&[ServiceType::Quotes]);
+        set_time(&env, 1_000);
+        let usdc = classic_asset(&env, "USDC");
+        let eur = classic_asset(&env, "EUR");
+        let book = |depth: u32| {
+            let mut anchors = Vec::new(&env);
+            for quote in kit.client.get_order_book(&usdc, &eur, &depth).iter() {
+                anchors.push_back((quote.anchor, quote.rate));
+            }
+            anchors
+        };
+
+        kit.seed_quote(&a, "USDC", "EUR", 9_300, 600);
+        kit.seed_quote(&b, "USDC", "EUR", 9_100, 60);
+        kit.seed_quote(&c, "USDC", "EUR", 9_200, 600);
+        kit.seed_quote(&a, "USDC", "GBP", 8_000, 600);
+        assert_eq!(
+            book(5),
+            vec![&env, (b.clone(), 9_100), (c.clone(), 9_200), (a.clone(), 9_300)]
+        );
+        assert_eq!(book(1), vec![&env, (b.clone(), 9_100)]);
+
+        // A new quote replaces the anchor's previous one
+        kit.seed_quote(&a, "USDC", "EUR", 9_000, 600);
+        assert_eq!(
+            book(5),
+            vec![&env, (a.clone(), 9_000), (b.clone(), 9_100), (c.clone(), 9_200)]
+        );
+
+        // Expired quotes drop out
+        set_time(&env, 1_060);
+        assert_eq!(book(5), vec![&env, (a, 9_000), (c, 9_200)]);
+        assert_eq!(kit.client.get_order_book(&eur, &usdc, &5).len(), 0);
+    }
+}
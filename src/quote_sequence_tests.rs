@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod quote_sequence_tests {
+    use crate::storage::Storage;
+    use crate::testutils::AnchorKitFixture;
+    use crate::{CheckFailure, Error, QuoteQueryOptions, ServiceType};
+    use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, Symbol};
+
+    #[test]
+    fn test_sequence_counts_quotes_per_pair() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+        let read = |quote_id: u64| {
+            kit.client
+                .query_quote(&anchor, &quote_id, &QuoteQueryOptions::read_only())
+        };
+
+        let first = kit.seed_quote(&anchor, "USDC", "EUR", 9200, 60);
+        let second = kit.seed_quote(&anchor, "USDC", "EUR", 9300, 60);
+        let other_pair = kit.seed_quote(&anchor, "USDC", "GBP", 8000, 60);
+
+        assert_eq!(read(first).sequence, 1);
+        assert_eq!(read(second).sequence, 2);
+        assert_eq!(read(other_pair).sequence, 1);
+    }
+
+    #[test]
+    fn test_stale_or_superseded_quote_conflicts() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+        let first = kit.seed_quote(&anchor, "USDC", "EUR", 9200, 60);
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 500)
+            .with_quote_id(first);
+        assert_eq!(
+            kit.client.try_build_transaction_intent(&builder),
+            Err(Ok(Error::QuoteConflict))
+        );
+        let builder = builder.with_quote_sequence(1);
+        assert!(kit.client.validate_intent(&builder).valid);
+
+        // A newer quote for the pair supersedes the one the client read
+        let second = kit.seed_quote(&anchor, "USDC", "EUR", 9300, 60);
+        assert_eq!(
+            kit.client.validate_intent(&builder).failures,
+            vec![
+                &env,
+                CheckFailure {
+                    error: Error::QuoteConflict as u32,
+                    field: Symbol::new(&env, "quote_id"),
+                    expected: second.into(),
+                    actual: first.into(),
+                }
+            ]
+        );
+        assert_eq!(
+            kit.client.try_build_transaction_intent(&builder),
+            Err(Ok(Error::QuoteConflict))
+        );
+
+        // Retrying against the fresh quote succeeds
+        let builder = builder.with_quote_id(second).with_quote_sequence(2);
+        let intent = kit.client.build_transaction_intent(&builder);
+        assert_eq!(intent.rate, 9300);
+    }
+
+    #[test]
+    fn test_execution_requires_quoted_request() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+        let quote_id = kit.seed_quote(&anchor, "USDC", "EUR", 9200, 600);
+        let token = env
+            .register_stellar_asset_contract_v2(kit.admin.clone())
+            .address();
+        kit.client.add_supported_token(&token);
+        let payer = kit.user.clone();
+        token::StellarAssetClient::new(&env, &token).mint(&payer, &10_000);
+        token::Client::new(&env, &token).approve(&payer, &kit.contract_id, &10_000, &1_000);
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 500)
+            .with_quote_id(quote_id)
+            .with_quote_sequence(1);
+        let intent = kit.client.build_transaction_intent(&builder);
+        let binding = kit.client.get_quote_binding(&intent.intent_id);
+        assert_eq!(binding.quote_id, quote_id);
+        assert_eq!(binding.request_hash, intent.request.hash(&env));
+        assert_eq!(binding.rate, 9200);
+
+        // Parameters swapped after quoting no longer match the binding
+        let mut swapped = intent.clone();
+        swapped.request.amount = 5_000;
+        env.as_contract(&kit.contract_id, || Storage::set_intent(&env, &swapped));
+        assert_eq!(
+            kit.client
+                .try_execute_intent(&intent.intent_id, &payer, &token),
+            Err(Ok(Error::QuoteConflict))
+        );
+
+        env.as_contract(&kit.contract_id, || Storage::set_intent(&env, &intent));
+        kit.client.execute_intent(&intent.intent_id, &payer, &token);
+        assert_eq!(token::Client::new(&env, &token).balance(&anchor), 500);
+    }
+
+    #[test]
+    fn test_session_pinned_quote() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+        let initiator = Address::generate(&env);
+        // Session 0 reads as "no session" in a builder
+        kit.client.create_session(&initiator);
+        let session_id = kit.client.create_session(&initiator);
+        let pinned = kit.seed_quote(&anchor, "USDC", "EUR", 9200, 600);
+
+        let pin = kit.client.pin_session_quote(&session_id, &anchor, &pinned);
+        assert_eq!(kit.client.get_session_quote_pin(&session_id), Some(pin));
+        assert_eq!(
+            kit.client
+                .try_pin_session_quote(&session_id, &anchor, &pinned),
+            Err(Ok(Error::AlreadyExists))
+        );
+
+        // Superseding the pinned quote doesn't break the session's flow
+        let newer = kit.seed_quote(&anchor, "USDC", "EUR", 9300, 600);
+        let builder = |quote_id: u64| {
+            kit.deposit_intent(&anchor, "USDC", "EUR", 500)
+                .with_session(session_id)
+                .with_quote_id(quote_id)
+                .with_quote_sequence(if quote_id == pinned { 1 } else { 2 })
+        };
+        let intent = kit.client.build_transaction_intent(&builder(pinned));
+        assert_eq!(intent.rate, 9200);
+        kit.client.build_transaction_intent(&builder(pinned));
+
+        assert_eq!(
+            kit.client.try_build_transaction_intent(&builder(newer)),
+            Err(Ok(Error::QuoteConflict))
+        );
+        let unquoted = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 500)
+            .with_session(session_id);
+        assert_eq!(
+            kit.client.try_build_transaction_intent(&unquoted),
+            Err(Ok(Error::QuoteConflict))
+        );
+
+        // Outside the session the superseded quote conflicts as usual
+        let outside = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 500)
+            .with_quote_id(pinned)
+            .with_quote_sequence(1);
+        assert_eq!(
+            kit.client.try_build_transaction_intent(&outside),
+            Err(Ok(Error::QuoteConflict))
+        );
+    }
+}
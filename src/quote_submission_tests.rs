@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod quote_submission_tests {
+    use crate::testutils::{classic_asset, AnchorKitFixture};
+    use crate::{Error, QuoteContext, QuoteQueryOptions, QuoteSubmission, ServiceType};
+    use soroban_sdk::{testutils::AuthorizedFunction, Address, Env, IntoVal, String, Val, Vec};
+
+    fn submission(env: &Env, anchor: &Address) -> QuoteSubmission {
+        QuoteSubmission {
+            anchor: anchor.clone(),
+            base_asset: classic_asset(env, "USDC"),
+            quote_asset: classic_asset(env, "EUR"),
+            rate: 9200,
+            fee_percentage: 15,
+            minimum_amount: 10,
+            maximum_amount: 5_000,
+            valid_until: env.ledger().timestamp() + 600,
+            context: QuoteContext::Sep24,
+            sell_delivery_method: None,
+            buy_delivery_method: Some(String::from_str(env, "SEPA")),
+            country_code: Some(String::from_str(env, "DEU")),
+        }
+    }
+
+    fn authorized_args(env: &Env) -> Vec<Val> {
+        match &env.auths()[0].1.function {
+            AuthorizedFunction::Contract((_, _, args)) => args.clone(),
+            _ => panic!("unexpected authorized function"),
+        }
+    }
+
+    #[test]
+    fn test_submit_quote_v2_stores_all_fields() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Quotes]);
+
+        let submitted = submission(&env, &anchor);
+        let quote_id = kit.client.submit_quote_v2(&submitted);
+
+        let quote = kit
+            .client
+            .query_quote(&anchor, &quote_id, &QuoteQueryOptions::read_only());
+        assert_eq!(quote, submitted.into_quote(quote_id, 1));
+    }
+
+    #[test]
+    fn test_submit_quote_v2_signs_same_payload_as_positional_entrypoint() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Quotes]);
+        let s = submission(&env, &anchor);
+
+        kit.client.submit_quote_v2(&s);
+        let v2_args = authorized_args(&env);
+
+        kit.client.submit_sep38_quote(
+            &anchor,
+            &s.base_asset,
+            &s.quote_asset,
+            &s.rate,
+            &s.fee_percentage,
+            &s.minimum_amount,
+            &s.maximum_amount,
+            &s.valid_until,
+            &s.terms(),
+        );
+        assert_eq!(authorized_args(&env), v2_args);
+        let expected: Vec<Val> = (
+            s.base_asset.clone(),
+            s.quote_asset.clone(),
+            s.rate,
+            s.fee_percentage,
+            s.minimum_amount,
+            s.maximum_amount,
+            s.valid_until,
+            s.terms(),
+        )
+            .into_val(&env);
+        assert_eq!(v2_args, expected);
+    }
+
+    #[test]
+    fn test_submit_quote_v2_validation() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Quotes]);
+
+        let mut bounds = submission(&env, &anchor);
+        bounds.minimum_amount = 10_000;
+        let result = kit.client.try_submit_quote_v2(&bounds);
+        assert_eq!(result, Err(Ok(Error::InvalidQuote)));
+
+        let mut same_pair = submission(&env, &anchor);
+        same_pair.quote_asset = same_pair.base_asset.clone();
+        let result = kit.client.try_submit_quote_v2(&same_pair);
+        assert_eq!(result, Err(Ok(Error::InvalidQuote)));
+
+        let deposits_only = kit.register_anchor(&[ServiceType::Deposits]);
+        let result = kit
+            .client
+            .try_submit_quote_v2(&submission(&env, &deposits_only));
+        assert_eq!(result, Err(Ok(Error::InvalidServiceType)));
+    }
+}
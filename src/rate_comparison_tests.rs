@@ -6,7 +6,7 @@ fn test_compare_rates_missing_fields_graceful_fail() {
     let anchor = Address::generate(&env);
     let (_contract_id, client) = create_test_contract(&env);
     client.initialize(&admin);
-    client.register_attestor(&anchor);
+    client.register_attestor(&anchor, &attestor_public_key(&env));
     let mut anchors = Vec::new(&env);
     anchors.push_back(anchor.clone());
     // No quotes submitted, so missing fields
@@ -15,6 +15,10 @@ fn test_compare_rates_missing_fields_graceful_fail() {
         quote_asset: String::from_str(&env, "USDC"),
         amount: 1000_000000u64,
         operation_type: ServiceType::Deposits,
+        context: QuoteContext::Sep6,
+        amount_side: AmountSide::Sell,
+        delivery_method: None,
+        country_code: None,
     };
     let result = client.try_compare_rates_for_anchors(&request, &anchors);
     assert_eq!(result, Err(Error::NoQuotesAvailable));
@@ -28,7 +32,7 @@ fn test_compare_rates_unexpected_types_graceful_fail() {
     let anchor = Address::generate(&env);
     let (_contract_id, client) = create_test_contract(&env);
     client.initialize(&admin);
-    client.register_attestor(&anchor);
+    client.register_attestor(&anchor, &attestor_public_key(&env));
     let mut services = Vec::new(&env);
     services.push_back(ServiceType::Quotes);
     client.configure_services(&anchor, &services);
@@ -50,6 +54,10 @@ fn test_compare_rates_unexpected_types_graceful_fail() {
         quote_asset: quote_asset.clone(),
         amount: 1000_000000u64,
         operation_type: ServiceType::Deposits,
+        context: QuoteContext::Sep6,
+        amount_side: AmountSide::Sell,
+        delivery_method: None,
+        country_code: None,
     };
     let mut anchors = Vec::new(&env);
     anchors.push_back(anchor.clone());
@@ -59,6 +67,7 @@ fn test_compare_rates_unexpected_types_graceful_fail() {
 }
 #[cfg(test)]
 mod rate_comparison_tests {
+    use crate::testutils::attestor_public_key;
     use super::*;
     use soroban_sdk::{
         testutils::{Address as _, Events},
@@ -82,7 +91,7 @@ mod rate_comparison_tests {
 
         // Initialize and setup
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         // Configure anchor to support quotes
         let mut services = Vec::new(&env);
@@ -112,7 +121,7 @@ mod rate_comparison_tests {
         assert_eq!(quote_id, 1); // First quote ID should be 1
 
         // Verify quote can be retrieved
-        let quote = client.get_quote(&anchor, &quote_id);
+        let quote = client.query_quote(&anchor, &quote_id, &QuoteQueryOptions::read_only());
         assert_eq!(quote.anchor, anchor);
         assert_eq!(quote.base_asset, base_asset);
         assert_eq!(quote.quote_asset, quote_asset);
@@ -165,7 +174,7 @@ mod rate_comparison_tests {
         let (_contract_id, client) = create_test_contract(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         let mut services = Vec::new(&env);
         services.push_back(ServiceType::Quotes);
@@ -198,7 +207,7 @@ mod rate_comparison_tests {
         let (_contract_id, client) = create_test_contract(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
         // Don't configure services
 
         let base_asset = String::from_str(&env, "USD");
@@ -229,8 +238,8 @@ mod rate_comparison_tests {
 
         // Setup
         client.initialize(&admin);
-        client.register_attestor(&anchor1);
-        client.register_attestor(&anchor2);
+        client.register_attestor(&anchor1, &attestor_public_key(&env));
+        client.register_attestor(&anchor2, &attestor_public_key(&env));
 
         let mut services = Vec::new(&env);
         services.push_back(ServiceType::Quotes);
@@ -272,6 +281,10 @@ mod rate_comparison_tests {
             quote_asset: quote_asset.clone(),
             amount: 1000_000000u64, // $1,000
             operation_type: ServiceType::Deposits,
+            context: QuoteContext::Sep6,
+            amount_side: AmountSide::Sell,
+            delivery_method: None,
+            country_code: None,
         };
 
         let mut anchors = Vec::new(&env);
@@ -310,10 +323,10 @@ mod rate_comparison_tests {
         let (_contract_id, client) = create_test_contract(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         // Try to get non-existent quote - should fail
-        client.get_quote(&anchor, &999);
+        client.query_quote(&anchor, &999, &QuoteQueryOptions::read_only());
     }
 
     #[test]
@@ -326,7 +339,7 @@ mod rate_comparison_tests {
         let (_contract_id, client) = create_test_contract(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         let mut services = Vec::new(&env);
         services.push_back(ServiceType::Quotes);
@@ -363,8 +376,8 @@ mod rate_comparison_tests {
         // Verify both quotes exist and are different
         assert_ne!(quote_id1, quote_id2);
 
-        let quote1 = client.get_quote(&anchor, &quote_id1);
-        let quote2 = client.get_quote(&anchor, &quote_id2);
+        let quote1 = client.query_quote(&anchor, &quote_id1, &QuoteQueryOptions::read_only());
+        let quote2 = client.query_quote(&anchor, &quote_id2, &QuoteQueryOptions::read_only());
 
         assert_eq!(quote1.rate, 10000u64);
         assert_eq!(quote2.rate, 10050u64);
@@ -1,6 +1,7 @@
 use soroban_sdk::{contracttype, Address, Env};
 
 use crate::errors::Error;
+use crate::scheduled_params::ScheduledParams;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -18,6 +19,51 @@ pub struct RateLimitConfig {
     pub refill_rate: u32, // tokens per second (for token bucket)
 }
 
+/// Operations that share per-address limits
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum OperationClass {
+    Attestation = 1,
+    Quote = 2,
+    Session = 3,
+    Transfer = 4,
+}
+
+/// Token bucket applied to every address for one operation class: it holds
+/// `max_operations` and refills completely over `window_ledgers`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationLimit {
+    pub max_operations: u32,
+    pub window_ledgers: u32,
+}
+
+impl OperationLimit {
+    /// Longest window: temporary bucket entries can't outlive it anyway
+    pub const MAX_WINDOW_LEDGERS: u32 = 17280 * 30;
+
+    pub fn is_valid(&self) -> bool {
+        self.max_operations > 0
+            && self.window_ledgers > 0
+            && self.window_ledgers <= Self::MAX_WINDOW_LEDGERS
+    }
+
+    /// Bucket size in units; one operation costs `window_ledgers` units and
+    /// each ledger refills `max_operations`, so refills stay exact.
+    fn capacity(&self) -> u64 {
+        self.max_operations as u64 * self.window_ledgers as u64
+    }
+}
+
+/// An address's bucket for one class. Missing or expired buckets are full.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct OperationBucket {
+    pub units: u64,
+    pub ledger: u32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct RateLimitState {
@@ -84,4 +130,66 @@ impl RateLimiter {
         env.storage().temporary().set(&key, state);
         env.storage().temporary().extend_ttl(&key, 17280, 17280); // 1 day
     }
+
+    /// Take one operation from `address`'s bucket for `class`, if the admin
+    /// has limited that class.
+    pub fn consume(env: &Env, address: &Address, class: OperationClass) -> Result<(), Error> {
+        let Some(limit) = ScheduledParams::operation_limit(env, class) else {
+            return Ok(());
+        };
+
+        let mut bucket = Self::refilled_bucket(env, address, class, &limit);
+        let cost = limit.window_ledgers as u64;
+        if bucket.units < cost {
+            return Err(Error::RateLimitExceeded);
+        }
+        bucket.units -= cost;
+
+        let key = (
+            soroban_sdk::symbol_short!("OPBUCKET"),
+            address.clone(),
+            class,
+        );
+        env.storage().temporary().set(&key, &bucket);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, limit.window_ledgers, limit.window_ledgers);
+        Ok(())
+    }
+
+    /// Operations `address` may still perform in `class`, or `None` when the
+    /// class is unlimited.
+    pub fn allowance(env: &Env, address: &Address, class: OperationClass) -> Option<u32> {
+        let limit = ScheduledParams::operation_limit(env, class)?;
+        let bucket = Self::refilled_bucket(env, address, class, &limit);
+        Some((bucket.units / limit.window_ledgers as u64) as u32)
+    }
+
+    fn refilled_bucket(
+        env: &Env,
+        address: &Address,
+        class: OperationClass,
+        limit: &OperationLimit,
+    ) -> OperationBucket {
+        let now = env.ledger().sequence();
+        let key = (
+            soroban_sdk::symbol_short!("OPBUCKET"),
+            address.clone(),
+            class,
+        );
+        let bucket: Option<OperationBucket> = env.storage().temporary().get(&key);
+        let units = match bucket {
+            Some(bucket) => {
+                let elapsed = now.saturating_sub(bucket.ledger) as u64;
+                bucket
+                    .units
+                    .saturating_add(elapsed * limit.max_operations as u64)
+            }
+            None => limit.capacity(),
+        };
+        OperationBucket {
+            units: units.min(limit.capacity()),
+            ledger: now,
+        }
+    }
 }
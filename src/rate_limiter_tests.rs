@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod rate_limiter_tests {
+    use crate::testutils::attestor_public_key;
     use crate::{
         AnchorKitContract, AnchorKitContractClient, Error, RateLimitConfig, RateLimitStrategy,
         ServiceType,
@@ -16,7 +17,7 @@ mod rate_limiter_tests {
         let anchor = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         let services = vec![&env, ServiceType::Quotes];
         client.configure_services(&anchor, &services);
@@ -79,7 +80,7 @@ mod rate_limiter_tests {
         let anchor = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         let services = vec![&env, ServiceType::Quotes];
         client.configure_services(&anchor, &services);
@@ -133,8 +134,8 @@ mod rate_limiter_tests {
         let anchor2 = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor1);
-        client.register_attestor(&anchor2);
+        client.register_attestor(&anchor1, &attestor_public_key(&env));
+        client.register_attestor(&anchor2, &attestor_public_key(&env));
 
         let services = vec![&env, ServiceType::Quotes];
         client.configure_services(&anchor1, &services);
@@ -209,7 +210,7 @@ mod rate_limiter_tests {
         let anchor = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         let services = vec![&env, ServiceType::Quotes];
         client.configure_services(&anchor, &services);
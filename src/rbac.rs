@@ -0,0 +1,35 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{errors::Error, events::RoleChanged, storage::Storage, types::Role};
+
+/// Role-based access control for powers delegated by the admin.
+///
+/// The admin implicitly holds every role. Roles only widen access to the
+/// entrypoints that check them; admin-only operations stay admin-only.
+pub struct Rbac;
+
+impl Rbac {
+    pub fn grant(env: &Env, role: Role, account: &Address) {
+        Storage::set_role(env, role, account, true);
+        RoleChanged::publish(env, account, role, true);
+    }
+
+    pub fn revoke(env: &Env, role: Role, account: &Address) {
+        Storage::set_role(env, role, account, false);
+        RoleChanged::publish(env, account, role, false);
+    }
+
+    pub fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+        Storage::has_role(env, role, account)
+            || Storage::get_admin(env).is_ok_and(|admin| &admin == account)
+    }
+
+    /// Require `caller`'s authorization and that it holds `role` (or is the admin).
+    pub fn require_role(env: &Env, caller: &Address, role: Role) -> Result<(), Error> {
+        caller.require_auth();
+        if !Self::has_role(env, role, caller) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod referral_tests {
+    use crate::testutils::{classic_asset, AnchorKitFixture};
+    use crate::{Error, ReferralPaid, ReferralVolume, ServiceType};
+    use soroban_sdk::{
+        testutils::{Address as _, Events},
+        token, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+    };
+
+    #[test]
+    fn test_referred_intents_and_transfers_count_towards_volume() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        let referrer = Address::generate(&env);
+
+        let token = env
+            .register_stellar_asset_contract_v2(kit.admin.clone())
+            .address();
+        kit.client.add_supported_token(&token);
+        let payer = kit.user.clone();
+        token::StellarAssetClient::new(&env, &token).mint(&payer, &1_000);
+        token::Client::new(&env, &token).approve(&payer, &kit.contract_id, &1_000, &1000);
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 400)
+            .with_referrer(referrer.clone());
+        let intent = kit.client.build_transaction_intent(&builder);
+        assert_eq!(intent.referrer, Some(referrer.clone()));
+        // Volume counts executed flow only
+        assert_eq!(
+            kit.client.get_referral_volume(&referrer),
+            ReferralVolume::default()
+        );
+        kit.client.execute_intent(&intent.intent_id, &payer, &token);
+
+        let sender = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let transfer_id =
+            kit.client
+                .initiate_referred_transfer(&sender, &destination, &250, &referrer);
+        assert_eq!(
+            kit.client.get_transfer(&transfer_id).referrer,
+            Some(referrer.clone())
+        );
+        kit.client.initiate_transfer(&sender, &destination, &999);
+
+        assert_eq!(
+            kit.client.get_referral_volume(&referrer),
+            ReferralVolume {
+                intents: 1,
+                transfers: 1,
+                volume: 650,
+            }
+        );
+    }
+
+    #[test]
+    fn test_referrer_is_paid_its_share_of_protocol_fees() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+        let referrer = Address::generate(&env);
+        assert_eq!(
+            kit.client.try_set_referral_share(&10_001),
+            Err(Ok(Error::InvalidConfig))
+        );
+        kit.client.set_referral_share(&2_500);
+        assert_eq!(kit.client.get_referral_share(), 2_500);
+
+        // 2% fee
+        let quote_id = kit.client.submit_quote(
+            &anchor,
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &9_200,
+            &200,
+            &1,
+            &u64::MAX,
+            &600,
+        );
+        let token = env
+            .register_stellar_asset_contract_v2(kit.admin.clone())
+            .address();
+        kit.client.add_supported_token(&token);
+        let payer = kit.user.clone();
+        token::StellarAssetClient::new(&env, &token).mint(&payer, &20_000);
+        token::Client::new(&env, &token).approve(&payer, &kit.contract_id, &20_000, &1000);
+        let execute = |referrer: Option<&Address>| {
+            let mut builder = kit
+                .deposit_intent(&anchor, "USDC", "EUR", 5_000)
+                .with_quote_id(quote_id)
+                .with_quote_sequence(1);
+            if let Some(referrer) = referrer {
+                builder = builder.with_referrer(referrer.clone());
+            }
+            let intent_id = kit.client.build_transaction_intent(&builder).intent_id;
+            kit.client.execute_intent(&intent_id, &payer, &token);
+        };
+
+        execute(Some(&referrer));
+        let paid_topics: Vec<Val> =
+            (Symbol::new(&env, "referral"), Symbol::new(&env, "paid")).into_val(&env);
+        let paid_event = env
+            .events()
+            .all()
+            .iter()
+            .find(|(_, topics, _)| *topics == paid_topics)
+            .map(|(_, _, data)| ReferralPaid::try_from_val(&env, &data).unwrap());
+        assert_eq!(
+            paid_event,
+            Some(ReferralPaid {
+                referrer: referrer.clone(),
+                token: token.clone(),
+                amount: 25,
+            })
+        );
+        assert_eq!(token::Client::new(&env, &token).balance(&referrer), 25);
+
+        // Unreferred fees go entirely to the treasury
+        execute(None);
+        assert_eq!(
+            kit.client.get_treasury_balances().get(0).unwrap().balance,
+            175
+        );
+        assert_eq!(token::Client::new(&env, &token).balance(&referrer), 25);
+    }
+}
@@ -1,10 +1,16 @@
 #![cfg(test)]
 
+use crate::testutils::{attestor_public_key, set_time, sign_attestation};
 use crate::{
     request_history::ApiCallStatus,
-    AnchorKitContract, AnchorKitContractClient,
+    AnchorKitContract, AnchorKitContractClient, Asset, Error,
 };
-use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env, String};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+
+fn asset(env: &Env, code: &str) -> Asset {
+    let issuer = String::from_str(env, "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN");
+    Asset::Classic(String::from_str(env, code), Address::from_string(&issuer))
+}
 
 #[test]
 fn test_record_api_call() {
@@ -21,7 +27,7 @@ fn test_record_api_call() {
     client.initialize(&admin);
 
     // Register attestor with tracking
-    client.register_attestor_tracked(&attestor);
+    client.register_attestor_tracked(&attestor, &attestor_public_key(&env));
 
     // Get request history
     let history = client.get_request_history(&10);
@@ -49,14 +55,15 @@ fn test_submit_attestation_tracked() {
 
     // Initialize and register attestor
     client.initialize(&admin);
-    client.register_attestor(&attestor);
+    client.register_attestor(&attestor, &attestor_public_key(&env));
 
     // Submit attestation with tracking
+    set_time(&env, 1_000);
     let timestamp = env.ledger().timestamp();
     let payload_hash = BytesN::from_array(&env, &[1u8; 32]);
-    let signature = Bytes::new(&env);
+    let signature = sign_attestation(&env, &attestor, &subject, timestamp, &payload_hash);
 
-    let result = client.submit_attestation_tracked(
+    client.submit_attestation_tracked(
         &attestor,
         &subject,
         &timestamp,
@@ -76,7 +83,7 @@ fn test_submit_attestation_tracked() {
 }
 
 #[test]
-fn test_failed_api_call_tracking() {
+fn test_failed_api_call_is_not_recorded() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -91,27 +98,25 @@ fn test_failed_api_call_tracking() {
     client.initialize(&admin);
 
     // Try to submit attestation with unregistered attestor
+    set_time(&env, 1_000);
     let timestamp = env.ledger().timestamp();
     let payload_hash = BytesN::from_array(&env, &[1u8; 32]);
-    let signature = Bytes::new(&env);
+    let signature =
+        sign_attestation(&env, &unregistered_attestor, &subject, timestamp, &payload_hash);
 
-    let result = client.submit_attestation_tracked(
+    let result = client.try_submit_attestation_tracked(
         &unregistered_attestor,
         &subject,
         &timestamp,
         &payload_hash,
         &signature,
     );
+    assert_eq!(result, Err(Ok(Error::UnauthorizedAttestor)));
 
-    // Check request history shows failure
+    // The history entry is rolled back with the failed call
     let history = client.get_request_history(&10);
-    assert_eq!(history.recent_calls.len(), 1);
-    assert_eq!(history.failed_count, 1);
-
-    let call = history.recent_calls.get(0).unwrap();
-    assert_eq!(call.status, ApiCallStatus::Failed);
-    assert!(call.error_code.is_some());
-    assert_eq!(call.error_code.unwrap(), 102); // UnauthorizedAttestor
+    assert_eq!(history.recent_calls.len(), 0);
+    assert_eq!(history.failed_count, 0);
 }
 
 #[test]
@@ -128,12 +133,13 @@ fn test_get_api_call_details() {
 
     // Initialize and register attestor
     client.initialize(&admin);
-    client.register_attestor(&attestor);
+    client.register_attestor(&attestor, &attestor_public_key(&env));
 
     // Submit attestation with tracking
+    set_time(&env, 1_000);
     let timestamp = env.ledger().timestamp();
     let payload_hash = BytesN::from_array(&env, &[1u8; 32]);
-    let signature = Bytes::new(&env);
+    let signature = sign_attestation(&env, &attestor, &subject, timestamp, &payload_hash);
 
     client
         .submit_attestation_tracked(&attestor, &subject, &timestamp, &payload_hash, &signature);
@@ -168,8 +174,8 @@ fn test_multiple_api_calls() {
     client.initialize(&admin);
 
     // Register multiple attestors
-    client.register_attestor_tracked(&attestor1);
-    client.register_attestor_tracked(&attestor2);
+    client.register_attestor_tracked(&attestor1, &attestor_public_key(&env));
+    client.register_attestor_tracked(&attestor2, &attestor_public_key(&env));
 
     // Check request history
     let history = client.get_request_history(&10);
@@ -198,7 +204,7 @@ fn test_request_history_limit() {
     // Register multiple attestors
     for _ in 0..15 {
         let attestor = Address::generate(&env);
-        client.register_attestor_tracked(&attestor);
+        client.register_attestor_tracked(&attestor, &attestor_public_key(&env));
     }
 
     // Request only 5 most recent
@@ -224,7 +230,7 @@ fn test_submit_quote_tracked() {
 
     // Initialize and register anchor
     client.initialize(&admin);
-    client.register_attestor(&anchor);
+    client.register_attestor(&anchor, &attestor_public_key(&env));
 
     // Configure services
     let mut services = soroban_sdk::Vec::new(&env);
@@ -232,8 +238,8 @@ fn test_submit_quote_tracked() {
     client.configure_services(&anchor, &services);
 
     // Submit quote with tracking
-    let base_asset = String::from_str(&env, "USD");
-    let quote_asset = String::from_str(&env, "USDC");
+    let base_asset = asset(&env, "USD");
+    let quote_asset = asset(&env, "USDC");
     let rate = 10000u64;
     let fee_percentage = 100u32;
     let minimum_amount = 100u64;
@@ -1,13 +1,20 @@
 #[cfg(test)]
 mod request_id_tests {
-    use crate::{AnchorKitContract, AnchorKitContractClient, RequestId, ServiceType};
-    use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, Bytes, BytesN, Env};
+    use crate::testutils::{attestor_public_key, set_time, sign_attestation};
+    use crate::{AnchorKitContract, AnchorKitContractClient, Asset, RequestId, ServiceType};
+    use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, BytesN, Env};
+
+    fn asset(env: &Env, code: &str) -> Asset {
+        let issuer = soroban_sdk::String::from_str(env, "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN");
+        Asset::Classic(soroban_sdk::String::from_str(env, code), Address::from_string(&issuer))
+    }
 
     #[test]
     fn test_generate_request_id() {
         let env = Env::default();
         let contract_id = env.register_contract(None, AnchorKitContract);
         let client = AnchorKitContractClient::new(&env, &contract_id);
+        set_time(&env, 1_000);
 
         let request_id = client.generate_request_id();
         
@@ -52,13 +59,13 @@ mod request_id_tests {
         let subject = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&attestor);
+        client.register_attestor(&attestor, &attestor_public_key(&env));
 
         let request_id = client.generate_request_id();
         let payload_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let signature = Bytes::new(&env);
+        let signature = sign_attestation(&env, &attestor, &subject, 1000, &payload_hash);
 
-        let attestation_id = client.submit_with_request_id(
+        client.submit_with_request_id(
             &request_id,
             &attestor,
             &subject,
@@ -67,7 +74,7 @@ mod request_id_tests {
             &signature,
         );
 
-        assert!(attestation_id > 0);
+        assert!(client.is_attestation_recorded(&payload_hash));
 
         // Verify tracing span was stored
         let span = client.get_tracing_span(&request_id.id);
@@ -79,7 +86,7 @@ mod request_id_tests {
     }
 
     #[test]
-    fn test_tracing_span_records_failure() {
+    fn test_tracing_span_rolled_back_on_failure() {
         let env = Env::default();
         env.mock_all_auths();
         let contract_id = env.register_contract(None, AnchorKitContract);
@@ -94,7 +101,7 @@ mod request_id_tests {
 
         let request_id = client.generate_request_id();
         let payload_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let signature = Bytes::new(&env);
+        let signature = sign_attestation(&env, &attestor, &subject, 1000, &payload_hash);
 
         let result = client.try_submit_with_request_id(
             &request_id,
@@ -107,9 +114,9 @@ mod request_id_tests {
 
         assert!(result.is_err());
 
-        // Verify failure was recorded
+        // The span is rolled back with the failed call
         let span = client.get_tracing_span(&request_id.id);
-        assert!(span.is_some());
+        assert!(span.is_none());
     }
 
     #[test]
@@ -123,7 +130,7 @@ mod request_id_tests {
         let anchor = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&anchor);
+        client.register_attestor(&anchor, &attestor_public_key(&env));
 
         let services = vec![&env, ServiceType::Quotes];
         client.configure_services(&anchor, &services);
@@ -133,8 +140,8 @@ mod request_id_tests {
         let quote_id = client.quote_with_request_id(
             &request_id,
             &anchor,
-            &soroban_sdk::String::from_str(&env, "USD"),
-            &soroban_sdk::String::from_str(&env, "USDC"),
+            &asset(&env, "USD"),
+            &asset(&env, "USDC"),
             &10000,
             &100,
             &100,
@@ -164,11 +171,11 @@ mod request_id_tests {
         let subject = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&attestor);
+        client.register_attestor(&attestor, &attestor_public_key(&env));
 
         let request_id = client.generate_request_id();
         let payload_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let signature = Bytes::new(&env);
+        let signature = sign_attestation(&env, &attestor, &subject, 1000, &payload_hash);
 
         client.submit_with_request_id(
             &request_id,
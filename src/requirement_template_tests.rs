@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod requirement_template_tests {
+    use crate::testutils::{sign_attestation, AnchorKitFixture};
+    use crate::{Error, ServiceType};
+    use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, Symbol};
+
+    #[test]
+    fn test_intents_reference_requirement_template() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::KYC]);
+        let subject = Address::generate(&env);
+        let sanctions = Symbol::new(&env, "sanctions");
+
+        let template_id = kit.client.define_requirement_template(
+            &kit.admin,
+            &Symbol::new(&env, "retail_onramp"),
+            &1,
+            &vec![&env, sanctions.clone()],
+        );
+        assert_eq!(kit.client.list_requirement_templates().len(), 1);
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 100)
+            .with_subject(subject.clone())
+            .with_requirement_template(template_id);
+        // Both the KYC level and the sanctions attestation are missing
+        assert_eq!(kit.client.validate_intent(&builder).failures.len(), 2);
+        assert_eq!(
+            kit.client.try_build_transaction_intent(&builder),
+            Err(Ok(Error::ComplianceNotMet))
+        );
+
+        kit.client.set_kyc_level(&anchor, &subject, &1);
+        let session_id = kit.client.create_session(&anchor);
+        let payload_hash = BytesN::from_array(&env, &[7u8; 32]);
+        let attestation_id = kit.client.submit_attestation_with_session(
+            &session_id,
+            &anchor,
+            &subject,
+            &1_700_000_001u64,
+            &payload_hash,
+            &sign_attestation(&env, &anchor, &subject, 1_700_000_001u64, &payload_hash),
+            &None,
+        );
+        kit.client
+            .set_attestation_kind(&anchor, &attestation_id, &sanctions);
+
+        let intent = kit.client.build_transaction_intent(&builder);
+        assert_eq!(intent.requirement_template, template_id);
+        assert_eq!(intent.attestation_ids, vec![&env, attestation_id]);
+
+        // Tightening the template applies to intents built from it
+        let mut template = kit.client.get_requirement_template(&template_id);
+        template.kyc_level = 2;
+        kit.client
+            .update_requirement_template(&kit.admin, &template);
+        assert_eq!(
+            kit.client.try_check_compliance(&intent),
+            Err(Ok(Error::ComplianceNotMet))
+        );
+    }
+
+    #[test]
+    fn test_requirement_template_validation() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        let name = Symbol::new(&env, "retail_onramp");
+        let kyc = Symbol::new(&env, "kyc");
+
+        assert_eq!(
+            kit.client
+                .try_define_requirement_template(&kit.admin, &name, &0, &vec![&env]),
+            Err(Ok(Error::InvalidConfig))
+        );
+        assert_eq!(
+            kit.client.try_define_requirement_template(
+                &kit.admin,
+                &name,
+                &0,
+                &vec![&env, kyc.clone(), kyc.clone()]
+            ),
+            Err(Ok(Error::InvalidConfig))
+        );
+
+        let template_id =
+            kit.client
+                .define_requirement_template(&kit.admin, &name, &1, &vec![&env]);
+        assert_eq!(
+            kit.client
+                .try_define_requirement_template(&kit.admin, &name, &2, &vec![&env]),
+            Err(Ok(Error::InvalidConfig))
+        );
+
+        let mut renamed = kit.client.get_requirement_template(&template_id);
+        renamed.name = Symbol::new(&env, "other");
+        assert_eq!(
+            kit.client
+                .try_update_requirement_template(&kit.admin, &renamed),
+            Err(Ok(Error::InvalidConfig))
+        );
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 100)
+            .with_requirement_template(template_id + 1);
+        assert_eq!(
+            kit.client.try_build_transaction_intent(&builder),
+            Err(Ok(Error::NotFound))
+        );
+    }
+}
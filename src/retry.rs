@@ -117,6 +117,8 @@ pub fn is_retryable_error(error: &Error) -> bool {
 
         // Stale data (retryable - can fetch fresh data)
         Error::StaleQuote => true,
+        Error::QuoteConflict => true,
+        Error::IntentLocked => true,
         Error::NoQuotesAvailable => true,
         Error::NoAnchorsAvailable => true,
 
@@ -0,0 +1,94 @@
+use soroban_sdk::{Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::Storage,
+    types::{RetryRecord, RetryTarget},
+};
+
+/// Shared retry bookkeeping for failed transfers and webhook deliveries.
+///
+/// Workers record each failed attempt; the record tracks the attempt count,
+/// the last error and when the next attempt is due, backing off exponentially
+/// up to `RetryRecord::MAX_ATTEMPTS`. A success clears the record.
+pub struct RetryQueue;
+
+impl RetryQueue {
+    pub fn record_failure(
+        env: &Env,
+        target: &RetryTarget,
+        error_code: u32,
+    ) -> Result<RetryRecord, Error> {
+        match target {
+            RetryTarget::Transfer(transfer_id) => {
+                Storage::get_transfer(env, *transfer_id)?;
+                Storage::touch_transfer(env, *transfer_id);
+            }
+            RetryTarget::WebhookDelivery(anchor, _) => {
+                if !Storage::is_attestor(env, anchor) {
+                    return Err(Error::AttestorNotRegistered);
+                }
+            }
+        }
+
+        let now = env.ledger().timestamp();
+        let mut record = match Storage::get_retry_record(env, target) {
+            Some(record) => record,
+            None => {
+                let mut targets = Storage::get_retry_targets(env);
+                targets.push_back(target.clone());
+                Storage::set_retry_targets(env, &targets);
+                RetryRecord {
+                    target: target.clone(),
+                    attempts: 0,
+                    last_error: 0,
+                    last_attempt_at: 0,
+                    next_retry_after: 0,
+                    exhausted: false,
+                }
+            }
+        };
+
+        record.attempts += 1;
+        record.last_error = error_code;
+        record.last_attempt_at = now;
+        record.exhausted = record.attempts >= RetryRecord::MAX_ATTEMPTS;
+        record.next_retry_after = if record.exhausted {
+            0
+        } else {
+            now.saturating_add(RetryRecord::delay_after(record.attempts))
+        };
+        Storage::set_retry_record(env, &record);
+        Ok(record)
+    }
+
+    /// Drop the record of an operation that has succeeded or been abandoned.
+    pub fn clear(env: &Env, target: &RetryTarget) -> Result<(), Error> {
+        if Storage::get_retry_record(env, target).is_none() {
+            return Err(Error::NotFound);
+        }
+        Storage::remove_retry_record(env, target);
+
+        let mut targets = Storage::get_retry_targets(env);
+        if let Some(index) = targets.first_index_of(target) {
+            targets.remove(index);
+            Storage::set_retry_targets(env, &targets);
+        }
+        Ok(())
+    }
+
+    /// Every open record, or with `due_only` just those whose next attempt is
+    /// due now.
+    pub fn records(env: &Env, due_only: bool) -> Vec<RetryRecord> {
+        let now = env.ledger().timestamp();
+        let mut records = Vec::new(env);
+        for target in Storage::get_retry_targets(env).iter() {
+            if let Some(record) = Storage::get_retry_record(env, &target) {
+                if !due_only || (!record.exhausted && record.next_retry_after <= now) {
+                    records.push_back(record);
+                }
+            }
+        }
+        records
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod retry_queue_tests {
+    use crate::testutils::{advance_time, set_time, AnchorKitFixture};
+    use crate::{Error, RetryRecord, RetryTarget, Role, ServiceType};
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    #[test]
+    fn test_failures_back_off_until_exhausted() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        let worker = Address::generate(&env);
+        set_time(&env, 1_000);
+        let transfer_id = kit
+            .client
+            .initiate_transfer(&Address::generate(&env), &anchor, &100);
+        let target = RetryTarget::Transfer(transfer_id);
+
+        assert_eq!(
+            kit.client.try_record_retry_failure(&worker, &target, &32),
+            Err(Ok(Error::Unauthorized))
+        );
+        kit.client.grant_role(&worker, &Role::RetryWorker);
+
+        let record = kit.client.record_retry_failure(&worker, &target, &32);
+        assert_eq!(record.attempts, 1);
+        assert_eq!(record.last_error, 32);
+        assert_eq!(record.next_retry_after, 1_030);
+        assert_eq!(kit.client.list_due_retries().len(), 0);
+
+        advance_time(&env, 30);
+        assert_eq!(kit.client.list_due_retries().len(), 1);
+        let record = kit.client.record_retry_failure(&worker, &target, &29);
+        assert_eq!(record.next_retry_after, 1_090);
+
+        for _ in 2..RetryRecord::MAX_ATTEMPTS {
+            kit.client.record_retry_failure(&worker, &target, &29);
+        }
+        let record = kit.client.get_retry(&target);
+        assert!(record.exhausted);
+        advance_time(&env, RetryRecord::MAX_DELAY_SECONDS);
+        assert_eq!(kit.client.list_due_retries().len(), 0);
+        assert_eq!(kit.client.list_retries().len(), 1);
+
+        kit.client.clear_retry(&worker, &target);
+        assert_eq!(kit.client.try_get_retry(&target), Err(Ok(Error::NotFound)));
+        assert_eq!(kit.client.list_retries().len(), 0);
+    }
+
+    #[test]
+    fn test_retry_targets_must_exist() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+
+        let unknown = RetryTarget::Transfer(7);
+        assert_eq!(
+            kit.client
+                .try_record_retry_failure(&kit.admin, &unknown, &1),
+            Err(Ok(Error::TransferNotFound))
+        );
+        let stranger = RetryTarget::WebhookDelivery(Address::generate(&env), 1);
+        assert_eq!(
+            kit.client
+                .try_record_retry_failure(&kit.admin, &stranger, &1),
+            Err(Ok(Error::AttestorNotRegistered))
+        );
+
+        let delivery = RetryTarget::WebhookDelivery(anchor, 1);
+        kit.client.record_retry_failure(&kit.admin, &delivery, &1);
+        assert_eq!(kit.client.list_retries().get(0).unwrap().target, delivery);
+    }
+}
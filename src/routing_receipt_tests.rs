@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod routing_receipt_tests {
+    use crate::testutils::{classic_asset, AnchorKitFixture};
+    use crate::{RoutingRequest, RoutingStrategy, ScoreBreakdown, ServiceType};
+    use soroban_sdk::{
+        testutils::{Address as _, AuthorizedFunction},
+        Address, Env, IntoVal, String, Symbol,
+    };
+
+    #[test]
+    fn test_route_persists_verifiable_receipt() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let services = [ServiceType::Deposits, ServiceType::Quotes];
+        let slow = kit.register_anchor(&services);
+        let fast = kit.register_anchor(&services);
+        kit.client
+            .set_anchor_metadata(&slow, &9000, &600, &8000, &9900, &1_000_000);
+        kit.client
+            .set_anchor_metadata(&fast, &9000, &60, &8000, &9900, &1_000_000);
+        let quote_id = kit.seed_quote(&fast, "USDC", "EUR", 9_000, 600);
+        kit.seed_quote(&slow, "USDC", "EUR", 9_000, 600);
+
+        let request = RoutingRequest {
+            request: kit.deposit_intent(&fast, "USDC", "EUR", 500).request,
+            strategy: RoutingStrategy::FastestSettlement,
+            max_anchors: 2,
+            require_kyc: false,
+            min_reputation: 0,
+            corridor_id: 0,
+            record_exclusions: false,
+        };
+        let requester = Address::generate(&env);
+        let result = kit.client.route_transaction(&requester, &request);
+        let receipt = result.receipt.clone();
+
+        // The requester authorizes the routing its receipt names
+        let (authorizer, invocation) = env.auths().pop().unwrap();
+        assert_eq!(authorizer, requester);
+        assert_eq!(
+            invocation.function,
+            AuthorizedFunction::Contract((
+                kit.contract_id.clone(),
+                Symbol::new(&env, "route_transaction"),
+                (requester.clone(), request.clone()).into_val(&env),
+            ))
+        );
+        assert_eq!(receipt.requester, requester);
+        assert_eq!(receipt.selected_anchor, fast);
+        assert_eq!(receipt.quote_id, quote_id);
+        assert_eq!(receipt.request_hash, request.hash(&env));
+        assert_eq!(
+            receipt.score,
+            ScoreBreakdown {
+                settlement: (86_400 - 60) * 10_000,
+                ..Default::default()
+            }
+        );
+        assert_eq!(receipt.score.total(), result.score);
+        assert_eq!(receipt.config_version, String::from_str(&env, ""));
+        assert_eq!(kit.client.get_routing_receipt(&receipt.receipt_id), receipt);
+        assert!(kit.client.verify_routing_receipt(&receipt));
+
+        // A doctored copy doesn't verify
+        let mut forged = receipt.clone();
+        forged.selected_anchor = slow;
+        assert!(!kit.client.verify_routing_receipt(&forged));
+
+        // find_best_anchor is a plain read that issues none
+        let best = kit.client.find_best_anchor(
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &500,
+            &ServiceType::Deposits,
+            &RoutingStrategy::FastestSettlement,
+        );
+        assert_eq!(best, fast);
+
+        // Every routing gets its own receipt
+        let again = kit.client.route_transaction(&requester, &request).receipt;
+        assert_eq!(again.receipt_id, receipt.receipt_id + 1);
+    }
+}
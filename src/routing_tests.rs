@@ -1,11 +1,17 @@
 #![cfg(test)]
 
-use crate::{AnchorKitContract, AnchorKitContractClient, ServiceType};
+use crate::testutils::attestor_public_key;
+use crate::{AnchorKitContract, AnchorKitContractClient, Asset, QuoteQueryOptions, ServiceType};
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
     Address, Env, String, Vec,
 };
 
+fn asset(env: &Env, code: &str) -> Asset {
+    let issuer = String::from_str(env, "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN");
+    Asset::Classic(String::from_str(env, code), Address::from_string(&issuer))
+}
+
 fn create_contract(env: &Env) -> AnchorKitContractClient<'_> {
     let contract_id = env.register_contract(None, AnchorKitContract);
     AnchorKitContractClient::new(env, &contract_id)
@@ -27,7 +33,7 @@ fn test_select_best_quote_from_multiple_anchors() {
 
     // Setup anchors
     for anchor in [&anchor1, &anchor2, &anchor3] {
-        client.register_attestor(anchor);
+        client.register_attestor(anchor, &attestor_public_key(&env));
         let mut services = Vec::new(&env);
         services.push_back(ServiceType::Deposits);
         services.push_back(ServiceType::Quotes);
@@ -37,8 +43,8 @@ fn test_select_best_quote_from_multiple_anchors() {
     // Submit quotes with different rates
     let q1 = client.submit_quote(
         &anchor1,
-        &String::from_str(&env, "USD"),
-        &String::from_str(&env, "USDC"),
+        &asset(&env, "USD"),
+        &asset(&env, "USDC"),
         &10100u64,
         &50u32,
         &100u64,
@@ -48,8 +54,8 @@ fn test_select_best_quote_from_multiple_anchors() {
 
     let q2 = client.submit_quote(
         &anchor2,
-        &String::from_str(&env, "USD"),
-        &String::from_str(&env, "USDC"),
+        &asset(&env, "USD"),
+        &asset(&env, "USDC"),
         &10000u64, // Best rate
         &25u32,
         &100u64,
@@ -59,8 +65,8 @@ fn test_select_best_quote_from_multiple_anchors() {
 
     let q3 = client.submit_quote(
         &anchor3,
-        &String::from_str(&env, "USD"),
-        &String::from_str(&env, "USDC"),
+        &asset(&env, "USD"),
+        &asset(&env, "USDC"),
         &10050u64,
         &30u32,
         &100u64,
@@ -69,9 +75,9 @@ fn test_select_best_quote_from_multiple_anchors() {
     );
 
     // Retrieve and compare quotes
-    let quote1 = client.get_quote(&anchor1, &q1);
-    let quote2 = client.get_quote(&anchor2, &q2);
-    let quote3 = client.get_quote(&anchor3, &q3);
+    let quote1 = client.query_quote(&anchor1, &q1, &QuoteQueryOptions::read_only());
+    let quote2 = client.query_quote(&anchor2, &q2, &QuoteQueryOptions::read_only());
+    let quote3 = client.query_quote(&anchor3, &q3, &QuoteQueryOptions::read_only());
 
     // Verify anchor2 has best rate
     assert!(quote2.rate < quote1.rate);
@@ -93,7 +99,7 @@ fn test_select_lowest_fee_anchor() {
     client.initialize(&admin);
 
     for anchor in [&anchor1, &anchor2] {
-        client.register_attestor(anchor);
+        client.register_attestor(anchor, &attestor_public_key(&env));
         let mut services = Vec::new(&env);
         services.push_back(ServiceType::Deposits);
         services.push_back(ServiceType::Quotes);
@@ -102,8 +108,8 @@ fn test_select_lowest_fee_anchor() {
 
     let q1 = client.submit_quote(
         &anchor1,
-        &String::from_str(&env, "USD"),
-        &String::from_str(&env, "USDC"),
+        &asset(&env, "USD"),
+        &asset(&env, "USDC"),
         &10000u64,
         &50u32, // Higher fee
         &100u64,
@@ -113,8 +119,8 @@ fn test_select_lowest_fee_anchor() {
 
     let q2 = client.submit_quote(
         &anchor2,
-        &String::from_str(&env, "USD"),
-        &String::from_str(&env, "USDC"),
+        &asset(&env, "USD"),
+        &asset(&env, "USDC"),
         &10000u64,
         &20u32, // Lower fee
         &100u64,
@@ -122,8 +128,8 @@ fn test_select_lowest_fee_anchor() {
         &1003600u64,
     );
 
-    let quote1 = client.get_quote(&anchor1, &q1);
-    let quote2 = client.get_quote(&anchor2, &q2);
+    let quote1 = client.query_quote(&anchor1, &q1, &QuoteQueryOptions::read_only());
+    let quote2 = client.query_quote(&anchor2, &q2, &QuoteQueryOptions::read_only());
 
     assert!(quote2.fee_percentage < quote1.fee_percentage);
     assert_eq!(quote2.fee_percentage, 20);
@@ -145,7 +151,7 @@ fn test_handle_unavailable_anchors() {
 
     // Setup only anchor1 and anchor2
     for anchor in [&anchor1, &anchor2] {
-        client.register_attestor(anchor);
+        client.register_attestor(anchor, &attestor_public_key(&env));
         let mut services = Vec::new(&env);
         services.push_back(ServiceType::Deposits);
         services.push_back(ServiceType::Quotes);
@@ -154,8 +160,8 @@ fn test_handle_unavailable_anchors() {
 
     client.submit_quote(
         &anchor1,
-        &String::from_str(&env, "USD"),
-        &String::from_str(&env, "USDC"),
+        &asset(&env, "USD"),
+        &asset(&env, "USDC"),
         &10000u64,
         &25u32,
         &100u64,
@@ -165,8 +171,8 @@ fn test_handle_unavailable_anchors() {
 
     client.submit_quote(
         &anchor2,
-        &String::from_str(&env, "USD"),
-        &String::from_str(&env, "USDC"),
+        &asset(&env, "USD"),
+        &asset(&env, "USDC"),
         &10050u64,
         &30u32,
         &100u64,
@@ -190,7 +196,7 @@ fn test_expired_quotes_filtered() {
     let client = create_contract(&env);
     client.initialize(&admin);
 
-    client.register_attestor(&anchor);
+    client.register_attestor(&anchor, &attestor_public_key(&env));
     let mut services = Vec::new(&env);
     services.push_back(ServiceType::Deposits);
     services.push_back(ServiceType::Quotes);
@@ -199,8 +205,8 @@ fn test_expired_quotes_filtered() {
     // Submit quote that will expire soon
     let soon_expired_id = client.submit_quote(
         &anchor,
-        &String::from_str(&env, "USD"),
-        &String::from_str(&env, "USDC"),
+        &asset(&env, "USD"),
+        &asset(&env, "USDC"),
         &9900u64,
         &15u32,
         &100u64,
@@ -211,8 +217,8 @@ fn test_expired_quotes_filtered() {
     // Submit valid quote
     let valid_id = client.submit_quote(
         &anchor,
-        &String::from_str(&env, "USD"),
-        &String::from_str(&env, "USDC"),
+        &asset(&env, "USD"),
+        &asset(&env, "USDC"),
         &10000u64,
         &25u32,
         &100u64,
@@ -220,8 +226,9 @@ fn test_expired_quotes_filtered() {
         &1003600u64, // Valid for 3600 seconds
     );
 
-    let soon_expired = client.get_quote(&anchor, &soon_expired_id);
-    let valid_quote = client.get_quote(&anchor, &valid_id);
+    let read = QuoteQueryOptions::read_only();
+    let soon_expired = client.query_quote(&anchor, &soon_expired_id, &read);
+    let valid_quote = client.query_quote(&anchor, &valid_id, &read);
 
     // Verify expiration times
     assert!(soon_expired.valid_until < valid_quote.valid_until);
@@ -240,7 +247,7 @@ fn test_no_anchors_available() {
     client.initialize(&admin);
 
     // Verify anchor is not registered
-    let result = client.try_get_quote(&unavailable, &1);
+    let result = client.try_query_quote(&unavailable, &1, &QuoteQueryOptions::read_only());
     assert!(result.is_err());
 }
 
@@ -256,7 +263,7 @@ fn test_amount_outside_quote_limits() {
     let client = create_contract(&env);
     client.initialize(&admin);
 
-    client.register_attestor(&anchor);
+    client.register_attestor(&anchor, &attestor_public_key(&env));
     let mut services = Vec::new(&env);
     services.push_back(ServiceType::Deposits);
     services.push_back(ServiceType::Quotes);
@@ -264,8 +271,8 @@ fn test_amount_outside_quote_limits() {
 
     let quote_id = client.submit_quote(
         &anchor,
-        &String::from_str(&env, "USD"),
-        &String::from_str(&env, "USDC"),
+        &asset(&env, "USD"),
+        &asset(&env, "USDC"),
         &10000u64,
         &25u32,
         &100u64,    // Min
@@ -273,7 +280,7 @@ fn test_amount_outside_quote_limits() {
         &1003600u64,
     );
 
-    let quote = client.get_quote(&anchor, &quote_id);
+    let quote = client.query_quote(&anchor, &quote_id, &QuoteQueryOptions::read_only());
 
     // Verify limits
     assert_eq!(quote.minimum_amount, 100);
@@ -284,3 +291,36 @@ fn test_amount_outside_quote_limits() {
     // Amount 200000 would be outside limits (> 100000)
     assert!(200000 > quote.maximum_amount);
 }
+
+#[test]
+fn test_submit_quote_rejects_malformed_assets() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000000);
+
+    let admin = Address::generate(&env);
+    let anchor = Address::generate(&env);
+    let client = create_contract(&env);
+    client.initialize(&admin);
+
+    client.register_attestor(&anchor, &attestor_public_key(&env));
+    let mut services = Vec::new(&env);
+    services.push_back(ServiceType::Quotes);
+    client.configure_services(&anchor, &services);
+
+    let submit = |base: &Asset, quote: &Asset| {
+        client.try_submit_quote(
+            &anchor, base, quote, &10000u64, &25u32, &100u64, &100000u64, &1003600u64,
+        )
+    };
+
+    // Classic codes longer than 12 characters are not valid Stellar assets
+    assert!(submit(&asset(&env, "TOOLONGASSETCODE"), &asset(&env, "USDC")).is_err());
+    // A pair must be two distinct assets
+    assert!(submit(&asset(&env, "USDC"), &asset(&env, "USDC")).is_err());
+
+    // Native and contract tokens are first-class alongside classic assets
+    let token = Asset::ContractToken(Address::generate(&env));
+    assert!(submit(&Asset::Native, &token).is_ok());
+    assert!(submit(&asset(&env, "USDC"), &Asset::Native).is_ok());
+}
@@ -0,0 +1,144 @@
+use soroban_sdk::{contracttype, Env};
+
+use crate::{
+    errors::Error,
+    rate_limiter::{OperationClass, OperationLimit},
+    storage::Storage,
+};
+
+/// An admin-set parameter whose changes can be scheduled
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Parameter {
+    OperationLimit(OperationClass),
+    ReferralShare,
+}
+
+/// A new value for a `Parameter`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParameterChange {
+    OperationLimit(OperationClass, OperationLimit),
+    ReferralShare(u32),
+}
+
+impl ParameterChange {
+    pub fn parameter(&self) -> Parameter {
+        match self {
+            ParameterChange::OperationLimit(class, _) => Parameter::OperationLimit(*class),
+            ParameterChange::ReferralShare(_) => Parameter::ReferralShare,
+        }
+    }
+
+    /// The same checks the parameter's setter applies.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            ParameterChange::OperationLimit(_, limit) => limit.is_valid(),
+            ParameterChange::ReferralShare(share_bps) => {
+                cfg!(feature = "transfers") && *share_bps <= 10_000
+            }
+        }
+    }
+}
+
+/// A parameter change announced ahead of the time it takes effect
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledChange {
+    pub change: ParameterChange,
+    pub scheduled_at: u64,
+    pub activates_at: u64,
+}
+
+/// Parameter changes that take effect at a set time.
+///
+/// Each parameter has at most one pending change. Nothing runs at the
+/// activation time: reads go through `operation_limit` / `referral_share`,
+/// which return the scheduled value once it is due, and the value is written
+/// to the parameter itself the next time the parameter is changed.
+pub struct ScheduledParams;
+
+impl ScheduledParams {
+    /// Record `change` to take effect at `activates_at`, replacing any change
+    /// to the same parameter that is still pending.
+    pub fn schedule(
+        env: &Env,
+        change: ParameterChange,
+        activates_at: u64,
+    ) -> Result<ScheduledChange, Error> {
+        let now = env.ledger().timestamp();
+        if activates_at <= now {
+            return Err(Error::InvalidTimestamp);
+        }
+        if !change.is_valid() {
+            return Err(Error::InvalidConfig);
+        }
+
+        let parameter = change.parameter();
+        Self::settle(env, parameter);
+        let scheduled = ScheduledChange {
+            change,
+            scheduled_at: now,
+            activates_at,
+        };
+        Storage::set_scheduled_change(env, parameter, &scheduled);
+        Ok(scheduled)
+    }
+
+    /// Drop the pending change to `parameter`. Fails with `NotFound` if there
+    /// is none or it has already taken effect.
+    pub fn cancel(env: &Env, parameter: Parameter) -> Result<ScheduledChange, Error> {
+        let scheduled = Self::pending(env, parameter).ok_or(Error::NotFound)?;
+        Storage::remove_scheduled_change(env, parameter);
+        Ok(scheduled)
+    }
+
+    /// The change to `parameter` that hasn't taken effect yet, if any.
+    pub fn pending(env: &Env, parameter: Parameter) -> Option<ScheduledChange> {
+        Storage::get_scheduled_change(env, parameter)
+            .filter(|scheduled| scheduled.activates_at > env.ledger().timestamp())
+    }
+
+    /// Write a due change to `parameter` through to the parameter and drop it,
+    /// before the parameter is set directly or a new change is scheduled.
+    pub fn settle(env: &Env, parameter: Parameter) {
+        let Some(change) = Self::due(env, parameter) else {
+            return;
+        };
+        match change {
+            ParameterChange::OperationLimit(class, limit) => {
+                Storage::set_operation_limit(env, class, &limit)
+            }
+            #[cfg(feature = "transfers")]
+            ParameterChange::ReferralShare(share_bps) => {
+                Storage::set_referral_share(env, share_bps)
+            }
+            #[cfg(not(feature = "transfers"))]
+            ParameterChange::ReferralShare(_) => {}
+        }
+        Storage::remove_scheduled_change(env, parameter);
+    }
+
+    /// The operation limit in force for `class`.
+    pub fn operation_limit(env: &Env, class: OperationClass) -> Option<OperationLimit> {
+        match Self::due(env, Parameter::OperationLimit(class)) {
+            Some(ParameterChange::OperationLimit(_, limit)) => Some(limit),
+            _ => Storage::get_operation_limit(env, class),
+        }
+    }
+
+    /// The referral share in force, in basis points.
+    #[cfg(feature = "transfers")]
+    pub fn referral_share(env: &Env) -> u32 {
+        match Self::due(env, Parameter::ReferralShare) {
+            Some(ParameterChange::ReferralShare(share_bps)) => share_bps,
+            _ => Storage::get_referral_share(env),
+        }
+    }
+
+    fn due(env: &Env, parameter: Parameter) -> Option<ParameterChange> {
+        Storage::get_scheduled_change(env, parameter)
+            .filter(|scheduled| scheduled.activates_at <= env.ledger().timestamp())
+            .map(|scheduled| scheduled.change)
+    }
+}
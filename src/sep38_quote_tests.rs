@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod sep38_quote_tests {
+    use crate::testutils::attestor_public_key;
+    use crate::{
+        AmountSide, AnchorKitContract, Asset, AnchorKitContractClient, Error, QuoteContext,
+        QuoteQueryOptions, QuoteRequest, Sep38Terms, ServiceType,
+    };
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+    fn asset(env: &Env, code: &str) -> Asset {
+        let issuer = String::from_str(env, "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN");
+        Asset::Classic(String::from_str(env, code), Address::from_string(&issuer))
+    }
+
+    fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let anchor = Address::generate(env);
+        client.initialize(&admin);
+        client.register_attestor(&anchor, &attestor_public_key(env));
+        client.configure_services(&anchor, &vec![env, ServiceType::Quotes]);
+
+        (client, anchor)
+    }
+
+    fn sep24_terms(env: &Env) -> Sep38Terms {
+        Sep38Terms {
+            context: QuoteContext::Sep24,
+            sell_delivery_method: None,
+            buy_delivery_method: Some(String::from_str(env, "SEPA")),
+            country_code: Some(String::from_str(env, "DEU")),
+        }
+    }
+
+    fn request(env: &Env, amount: u64, context: QuoteContext, side: AmountSide) -> QuoteRequest {
+        QuoteRequest {
+            base_asset: asset(env, "USDC"),
+            quote_asset: asset(env, "EUR"),
+            amount,
+            operation_type: ServiceType::Quotes,
+            context,
+            amount_side: side,
+            delivery_method: None,
+            country_code: None,
+        }
+    }
+
+    #[test]
+    fn test_sep38_terms_are_stored() {
+        let env = Env::default();
+        let (client, anchor) = setup(&env);
+
+        let quote_id = client.submit_sep38_quote(
+            &anchor,
+            &asset(&env, "USDC"),
+            &asset(&env, "EUR"),
+            &9000,
+            &10,
+            &100,
+            &10_000,
+            &3600,
+            &sep24_terms(&env),
+        );
+
+        let quote = client.query_quote(&anchor, &quote_id, &QuoteQueryOptions::read_only());
+        assert_eq!(quote.context, QuoteContext::Sep24);
+        assert_eq!(quote.buy_delivery_method, Some(String::from_str(&env, "SEPA")));
+        assert_eq!(quote.country_code, Some(String::from_str(&env, "DEU")));
+    }
+
+    #[test]
+    fn test_plain_quote_defaults_to_sep6() {
+        let env = Env::default();
+        let (client, anchor) = setup(&env);
+
+        let quote_id = client.submit_quote(
+            &anchor,
+            &asset(&env, "USDC"),
+            &asset(&env, "EUR"),
+            &9000,
+            &10,
+            &100,
+            &10_000,
+            &3600,
+        );
+
+        let quote = client.query_quote(&anchor, &quote_id, &QuoteQueryOptions::read_only());
+        assert_eq!(quote.context, QuoteContext::Sep6);
+        assert_eq!(quote.sell_delivery_method, None);
+    }
+
+    #[test]
+    fn test_context_mismatch_excludes_quote() {
+        let env = Env::default();
+        let (client, anchor) = setup(&env);
+        client.submit_sep38_quote(
+            &anchor,
+            &asset(&env, "USDC"),
+            &asset(&env, "EUR"),
+            &9000,
+            &10,
+            &100,
+            &10_000,
+            &3600,
+            &sep24_terms(&env),
+        );
+
+        let mut anchors = Vec::new(&env);
+        anchors.push_back(anchor.clone());
+
+        let sep6 = request(&env, 1000, QuoteContext::Sep6, AmountSide::Sell);
+        let result = client.try_compare_rates_for_anchors(&sep6, &anchors);
+        assert_eq!(result, Err(Ok(Error::NoQuotesAvailable)));
+
+        let sep24 = request(&env, 1000, QuoteContext::Sep24, AmountSide::Sell);
+        let comparison = client.compare_rates_for_anchors(&sep24, &anchors);
+        assert_eq!(comparison.best_quote.anchor, anchor);
+    }
+
+    #[test]
+    fn test_buy_amount_is_checked_against_sell_bounds() {
+        let env = Env::default();
+        let (client, anchor) = setup(&env);
+        // 1 USDC buys 0.5 EUR; sell bounds are 100..=1000 USDC
+        client.submit_sep38_quote(
+            &anchor,
+            &asset(&env, "USDC"),
+            &asset(&env, "EUR"),
+            &5000,
+            &10,
+            &100,
+            &1000,
+            &3600,
+            &Sep38Terms::sep6(),
+        );
+
+        let mut anchors = Vec::new(&env);
+        anchors.push_back(anchor.clone());
+
+        // Buying 400 EUR requires selling 800 USDC: within bounds
+        let buy = request(&env, 400, QuoteContext::Sep6, AmountSide::Buy);
+        assert!(client.try_compare_rates_for_anchors(&buy, &anchors).is_ok());
+
+        // Buying 600 EUR requires selling 1200 USDC: above maximum
+        let too_much = request(&env, 600, QuoteContext::Sep6, AmountSide::Buy);
+        let result = client.try_compare_rates_for_anchors(&too_much, &anchors);
+        assert_eq!(result, Err(Ok(Error::NoQuotesAvailable)));
+    }
+
+    #[test]
+    fn test_delivery_method_and_country_filter() {
+        let env = Env::default();
+        let (client, anchor) = setup(&env);
+        client.submit_sep38_quote(
+            &anchor,
+            &asset(&env, "USDC"),
+            &asset(&env, "EUR"),
+            &9000,
+            &10,
+            &100,
+            &10_000,
+            &3600,
+            &sep24_terms(&env),
+        );
+
+        let mut anchors = Vec::new(&env);
+        anchors.push_back(anchor.clone());
+
+        let mut sepa = request(&env, 1000, QuoteContext::Sep24, AmountSide::Sell);
+        sepa.delivery_method = Some(String::from_str(&env, "SEPA"));
+        sepa.country_code = Some(String::from_str(&env, "DEU"));
+        assert!(client.try_compare_rates_for_anchors(&sepa, &anchors).is_ok());
+
+        let mut swift = sepa.clone();
+        swift.delivery_method = Some(String::from_str(&env, "SWIFT"));
+        let result = client.try_compare_rates_for_anchors(&swift, &anchors);
+        assert_eq!(result, Err(Ok(Error::NoQuotesAvailable)));
+
+        let mut france = sepa.clone();
+        france.country_code = Some(String::from_str(&env, "FRA"));
+        let result = client.try_compare_rates_for_anchors(&france, &anchors);
+        assert_eq!(result, Err(Ok(Error::NoQuotesAvailable)));
+    }
+}
@@ -1,5 +1,5 @@
-use crate::types::{Attestation, QuoteData, QuoteRequest, ServiceType};
-use soroban_sdk::{Bytes, BytesN, Env, IntoVal, Val};
+use crate::types::{Asset, AuditLog, ServiceType};
+use soroban_sdk::{xdr::ToXdr, Bytes, BytesN, Env, IntoVal, Val};
 
 /// Deterministic serialization utilities for signature generation
 /// Ensures identical inputs always produce identical serialized output
@@ -46,8 +46,8 @@ pub fn serialize_attestation_for_signing(
 /// Field order is strictly defined to prevent signature drift
 pub fn serialize_quote_request(
     env: &Env,
-    base_asset: &soroban_sdk::String,
-    quote_asset: &soroban_sdk::String,
+    base_asset: &Asset,
+    quote_asset: &Asset,
     amount: u64,
     operation_type: ServiceType,
 ) -> Bytes {
@@ -56,15 +56,11 @@ pub fn serialize_quote_request(
     // Field order: base_asset, quote_asset, amount, operation_type
     // This order MUST NOT change to prevent signature drift
 
-    // 1. base_asset (as Val - deterministic representation)
-    let base_val: Val = base_asset.clone().into_val(env);
-    let base_u64: u64 = base_val.get_payload();
-    bytes.append(&Bytes::from_array(env, &base_u64.to_be_bytes()));
+    // 1. base_asset (XDR - deterministic representation)
+    bytes.append(&base_asset.clone().to_xdr(env));
 
-    // 2. quote_asset (as Val - deterministic representation)
-    let quote_val: Val = quote_asset.clone().into_val(env);
-    let quote_u64: u64 = quote_val.get_payload();
-    bytes.append(&Bytes::from_array(env, &quote_u64.to_be_bytes()));
+    // 2. quote_asset (XDR - deterministic representation)
+    bytes.append(&quote_asset.clone().to_xdr(env));
 
     // 3. amount (8 bytes, big-endian)
     bytes.append(&Bytes::from_array(env, &amount.to_be_bytes()));
@@ -81,8 +77,8 @@ pub fn serialize_quote_request(
 pub fn serialize_quote_data(
     env: &Env,
     anchor: &soroban_sdk::Address,
-    base_asset: &soroban_sdk::String,
-    quote_asset: &soroban_sdk::String,
+    base_asset: &Asset,
+    quote_asset: &Asset,
     rate: u64,
     fee_percentage: u32,
     minimum_amount: u64,
@@ -101,15 +97,11 @@ pub fn serialize_quote_data(
     let anchor_u64: u64 = anchor_val.get_payload();
     bytes.append(&Bytes::from_array(env, &anchor_u64.to_be_bytes()));
 
-    // 2. base_asset (as Val - deterministic representation)
-    let base_val: Val = base_asset.clone().into_val(env);
-    let base_u64: u64 = base_val.get_payload();
-    bytes.append(&Bytes::from_array(env, &base_u64.to_be_bytes()));
+    // 2. base_asset (XDR - deterministic representation)
+    bytes.append(&base_asset.clone().to_xdr(env));
 
-    // 3. quote_asset (as Val - deterministic representation)
-    let quote_val: Val = quote_asset.clone().into_val(env);
-    let quote_u64: u64 = quote_val.get_payload();
-    bytes.append(&Bytes::from_array(env, &quote_u64.to_be_bytes()));
+    // 3. quote_asset (XDR - deterministic representation)
+    bytes.append(&quote_asset.clone().to_xdr(env));
 
     // 4. rate (8 bytes, big-endian)
     bytes.append(&Bytes::from_array(env, &rate.to_be_bytes()));
@@ -162,16 +154,106 @@ pub fn serialize_session_operation(
     bytes
 }
 
+/// Longest string accepted by `serialize_audit_log`
+const MAX_AUDIT_STRING_LEN: usize = 128;
+
+/// Serialize an audit log entry for the session hash chain.
+/// Integers are big-endian; strings, including the actor's strkey, are
+/// prefixed with their length as a big-endian u32.
+pub fn serialize_audit_log(env: &Env, log: &AuditLog) -> Bytes {
+    let mut bytes = Bytes::new(env);
+
+    // Field order: log_id, session_id, operation_index, operation_type,
+    // timestamp, status, result_data, actor, then correlation_id if set
+    // This order MUST NOT change or recorded session commitments stop verifying
+
+    let operation = &log.operation;
+    bytes.append(&Bytes::from_array(env, &log.log_id.to_be_bytes()));
+    bytes.append(&Bytes::from_array(env, &log.session_id.to_be_bytes()));
+    bytes.append(&Bytes::from_array(
+        env,
+        &operation.operation_index.to_be_bytes(),
+    ));
+    append_string(env, &mut bytes, &operation.operation_type);
+    bytes.append(&Bytes::from_array(env, &operation.timestamp.to_be_bytes()));
+    append_string(env, &mut bytes, &operation.status);
+    bytes.append(&Bytes::from_array(env, &operation.result_data.to_be_bytes()));
+    append_string(env, &mut bytes, &log.actor.to_string());
+    if operation.is_correlated() {
+        bytes.append(&Bytes::from_array(env, &operation.correlation_id.to_array()));
+    }
+
+    bytes
+}
+
+/// Next head of a session's audit hash chain:
+/// `sha256(head || serialize_audit_log(log))`, starting from 32 zero bytes.
+pub fn chain_audit_log(env: &Env, head: &BytesN<32>, log: &AuditLog) -> BytesN<32> {
+    let mut bytes = Bytes::from_array(env, &head.to_array());
+    bytes.append(&serialize_audit_log(env, log));
+    compute_hash(env, &bytes)
+}
+
+fn append_string(env: &Env, bytes: &mut Bytes, value: &soroban_sdk::String) {
+    let len = value.len() as usize;
+    let mut buf = [0u8; MAX_AUDIT_STRING_LEN];
+    value.copy_into_slice(&mut buf[..len]);
+    bytes.append(&Bytes::from_array(env, &(len as u32).to_be_bytes()));
+    bytes.append(&Bytes::from_slice(env, &buf[..len]));
+}
+
 /// Compute a deterministic hash of serialized data
 pub fn compute_hash(env: &Env, data: &Bytes) -> BytesN<32> {
     env.crypto().sha256(data).into()
 }
 
+/// Domain tag prefixed to attestation payloads before hashing, so an
+/// attestation `payload_hash` never collides with a hash made for another purpose
+pub const ATTESTATION_DOMAIN: &[u8] = b"anchorkit:attestation:v1";
+
+/// Canonical `payload_hash` for an attestation payload:
+/// `sha256(ATTESTATION_DOMAIN || payload)`. The issuer's ed25519 signature
+/// covers these 32 bytes. `anchorkit attest sign` computes the same hash off-chain.
+pub fn attestation_payload_hash(env: &Env, payload: &Bytes) -> BytesN<32> {
+    let mut bytes = Bytes::from_slice(env, ATTESTATION_DOMAIN);
+    bytes.append(payload);
+    compute_hash(env, &bytes)
+}
+
+/// Domain tag prefixed to the message an issuer signs for an attestation
+pub const ATTESTATION_SIGNATURE_DOMAIN: &[u8] = b"anchorkit:attestation-sig:v1";
+
+/// The 32 bytes an issuer signs with its ed25519 key to attest `payload_hash`:
+/// `sha256(ATTESTATION_SIGNATURE_DOMAIN || xdr(issuer) || xdr(subject) ||
+/// timestamp (8 bytes, big-endian) || payload_hash)`, where `xdr(address)` is
+/// the address's `ScVal` XDR. `issuer` is the attestor the attestation is
+/// recorded under, also when one of its delegates signs. `anchorkit attest
+/// sign` computes the same digest.
+pub fn attestation_signing_digest(
+    env: &Env,
+    issuer: &soroban_sdk::Address,
+    subject: &soroban_sdk::Address,
+    timestamp: u64,
+    payload_hash: &BytesN<32>,
+) -> BytesN<32> {
+    let mut bytes = Bytes::from_slice(env, ATTESTATION_SIGNATURE_DOMAIN);
+    bytes.append(&issuer.clone().to_xdr(env));
+    bytes.append(&subject.clone().to_xdr(env));
+    bytes.append(&Bytes::from_array(env, &timestamp.to_be_bytes()));
+    bytes.append(&payload_hash.clone().into());
+    compute_hash(env, &bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use soroban_sdk::{testutils::Address as _, Address, Env, String};
 
+    fn asset(env: &Env, code: &str) -> Asset {
+        let issuer = String::from_str(env, "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN");
+        Asset::Classic(String::from_str(env, code), Address::from_string(&issuer))
+    }
+
     #[test]
     fn test_attestation_serialization_deterministic() {
         let env = Env::default();
@@ -214,8 +296,8 @@ mod tests {
     fn test_quote_request_serialization_deterministic() {
         let env = Env::default();
 
-        let base_asset = String::from_str(&env, "USD");
-        let quote_asset = String::from_str(&env, "USDC");
+        let base_asset = asset(&env, "USD");
+        let quote_asset = asset(&env, "USDC");
         let amount = 1000u64;
         let operation_type = ServiceType::Deposits;
 
@@ -240,8 +322,8 @@ mod tests {
         let env = Env::default();
 
         let anchor = Address::generate(&env);
-        let base_asset = String::from_str(&env, "EUR");
-        let quote_asset = String::from_str(&env, "EURC");
+        let base_asset = asset(&env, "EUR");
+        let quote_asset = asset(&env, "EURC");
         let rate = 10050u64;
         let fee_percentage = 25u32;
         let minimum_amount = 100u64;
@@ -324,8 +406,8 @@ mod tests {
     fn test_different_inputs_produce_different_output() {
         let env = Env::default();
 
-        let base_asset = String::from_str(&env, "USD");
-        let quote_asset = String::from_str(&env, "USDC");
+        let base_asset = asset(&env, "USD");
+        let quote_asset = asset(&env, "USDC");
 
         // Serialize with amount 1000
         let bytes1 = serialize_quote_request(
@@ -5,12 +5,18 @@ use alloc::vec;
 
 use crate::{
     serialization::{
-        compute_hash, serialize_attestation_for_signing, serialize_quote_data,
-        serialize_quote_request, serialize_session_operation,
+        attestation_payload_hash, chain_audit_log, compute_hash,
+        serialize_attestation_for_signing, serialize_quote_data, serialize_quote_request,
+        serialize_session_operation,
     },
-    types::ServiceType,
+    types::{Asset, AuditLog, OperationContext, ServiceType},
 };
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env, String};
+
+fn asset(env: &Env, code: &str) -> Asset {
+    let issuer = String::from_str(env, "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN");
+    Asset::Classic(String::from_str(env, code), Address::from_string(&issuer))
+}
 
 /// Test Goal 1: Prevent signature drift
 /// Identical inputs must always produce identical serialized output
@@ -54,8 +60,8 @@ fn test_attestation_no_signature_drift() {
 fn test_quote_request_no_signature_drift() {
     let env = Env::default();
 
-    let base_asset = String::from_str(&env, "USD");
-    let quote_asset = String::from_str(&env, "USDC");
+    let base_asset = asset(&env, "USD");
+    let quote_asset = asset(&env, "USDC");
     let amount = 5000u64;
     let operation_type = ServiceType::Withdrawals;
 
@@ -83,8 +89,8 @@ fn test_quote_data_no_signature_drift() {
     let env = Env::default();
 
     let anchor = Address::generate(&env);
-    let base_asset = String::from_str(&env, "GBP");
-    let quote_asset = String::from_str(&env, "GBPC");
+    let base_asset = asset(&env, "GBP");
+    let quote_asset = asset(&env, "GBPC");
     let rate = 10100u64;
     let fee_percentage = 30u32;
     let minimum_amount = 500u64;
@@ -185,8 +191,8 @@ fn test_attestation_field_order_matters() {
 fn test_quote_request_field_order_matters() {
     let env = Env::default();
 
-    let asset1 = String::from_str(&env, "USD");
-    let asset2 = String::from_str(&env, "USDC");
+    let asset1 = asset(&env, "USD");
+    let asset2 = asset(&env, "USDC");
 
     // Serialize with base=USD, quote=USDC
     let bytes1 = serialize_quote_request(&env, &asset1, &asset2, 1000u64, ServiceType::Deposits);
@@ -207,8 +213,8 @@ fn test_quote_data_amount_fields_distinguishable() {
     let env = Env::default();
 
     let anchor = Address::generate(&env);
-    let base_asset = String::from_str(&env, "USD");
-    let quote_asset = String::from_str(&env, "USDC");
+    let base_asset = asset(&env, "USD");
+    let quote_asset = asset(&env, "USDC");
 
     // Serialize with min=100, max=10000
     let bytes1 = serialize_quote_data(
@@ -328,8 +334,8 @@ fn test_attestation_single_field_change_detected() {
 fn test_quote_request_single_field_change_detected() {
     let env = Env::default();
 
-    let base_asset = String::from_str(&env, "USD");
-    let quote_asset = String::from_str(&env, "USDC");
+    let base_asset = asset(&env, "USD");
+    let quote_asset = asset(&env, "USDC");
 
     let base_bytes = serialize_quote_request(
         &env,
@@ -363,7 +369,7 @@ fn test_quote_request_single_field_change_detected() {
     assert_ne!(base_hash, hash_op, "Operation type change not detected");
 
     // Change base_asset
-    let base_asset2 = String::from_str(&env, "EUR");
+    let base_asset2 = asset(&env, "EUR");
     let bytes_base = serialize_quote_request(
         &env,
         &base_asset2, // Changed
@@ -380,8 +386,8 @@ fn test_quote_data_all_fields_affect_hash() {
     let env = Env::default();
 
     let anchor = Address::generate(&env);
-    let base_asset = String::from_str(&env, "USD");
-    let quote_asset = String::from_str(&env, "USDC");
+    let base_asset = asset(&env, "USD");
+    let quote_asset = asset(&env, "USDC");
 
     let base_bytes = serialize_quote_data(
         &env,
@@ -475,8 +481,8 @@ fn test_quote_data_all_fields_affect_hash() {
 fn test_service_type_serialization_unique() {
     let env = Env::default();
 
-    let base_asset = String::from_str(&env, "USD");
-    let quote_asset = String::from_str(&env, "USDC");
+    let base_asset = asset(&env, "USD");
+    let quote_asset = asset(&env, "USDC");
     let amount = 1000u64;
 
     let service_types = vec![
@@ -505,19 +511,19 @@ fn test_service_type_serialization_unique() {
     }
 }
 
-/// Test: Empty strings vs non-empty strings
+/// Test: Native asset vs classic asset
 
 #[test]
-fn test_empty_string_vs_non_empty() {
+fn test_native_vs_classic_asset() {
     let env = Env::default();
 
-    let empty = String::from_str(&env, "");
-    let non_empty = String::from_str(&env, "A");
+    let native = Asset::Native;
+    let classic = asset(&env, "A");
     let amount = 1000u64;
 
-    let bytes1 = serialize_quote_request(&env, &empty, &non_empty, amount, ServiceType::Deposits);
+    let bytes1 = serialize_quote_request(&env, &native, &classic, amount, ServiceType::Deposits);
 
-    let bytes2 = serialize_quote_request(&env, &non_empty, &empty, amount, ServiceType::Deposits);
+    let bytes2 = serialize_quote_request(&env, &classic, &native, amount, ServiceType::Deposits);
 
     assert_ne!(bytes1, bytes2);
 
@@ -564,8 +570,8 @@ fn test_large_numbers_serialization() {
 fn test_byte_order_consistency() {
     let env = Env::default();
 
-    let base_asset = String::from_str(&env, "USD");
-    let quote_asset = String::from_str(&env, "USDC");
+    let base_asset = asset(&env, "USD");
+    let quote_asset = asset(&env, "USDC");
 
     // Test with values that would differ in little-endian vs big-endian
     // 0x0100 in big-endian = 256, in little-endian = 1
@@ -618,3 +624,56 @@ fn test_address_serialization_deterministic() {
         assert_eq!(hashes[0], hashes[i]);
     }
 }
+
+/// Test: attestation payload hash is domain separated and stable
+/// (the same vector the CLI signing helper must reproduce)
+
+#[test]
+fn test_attestation_payload_hash_domain_separated() {
+    let env = Env::default();
+    let payload = Bytes::from_slice(&env, b"hello");
+
+    let hash = attestation_payload_hash(&env, &payload);
+
+    let expected: [u8; 32] = [
+        0x46, 0x58, 0xde, 0x56, 0x8a, 0x8a, 0xaf, 0x98, 0xc8, 0x5d, 0x90, 0x84, 0x25, 0x80,
+        0x27, 0x69, 0x8a, 0x2e, 0x62, 0x04, 0x76, 0xd6, 0xa8, 0x72, 0x02, 0xc1, 0x87, 0x51,
+        0x25, 0x4d, 0xf9, 0xa6,
+    ];
+    assert_eq!(hash.to_array(), expected);
+    assert_ne!(hash, compute_hash(&env, &payload));
+}
+
+/// Test: session audit chain encoding is pinned
+/// (the same vector the CLI transcript export must reproduce)
+
+#[test]
+fn test_audit_log_chain_encoding_pinned() {
+    let env = Env::default();
+    let log = AuditLog {
+        log_id: 1,
+        session_id: 2,
+        operation: OperationContext {
+            session_id: 2,
+            operation_index: 0,
+            operation_type: String::from_str(&env, "attest"),
+            timestamp: 1_700_000_000,
+            status: String::from_str(&env, "success"),
+            result_data: 5,
+            correlation_id: BytesN::from_array(&env, &[0; 16]),
+        },
+        actor: Address::from_string(&String::from_str(
+            &env,
+            "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN",
+        )),
+    };
+
+    let head = chain_audit_log(&env, &BytesN::from_array(&env, &[0; 32]), &log);
+
+    let expected: [u8; 32] = [
+        0xc4, 0x00, 0xa6, 0xa5, 0x92, 0x2b, 0x1c, 0x2d, 0x7b, 0xdd, 0x8e, 0x81, 0x64, 0xd2,
+        0xf1, 0xdb, 0x87, 0x13, 0x55, 0xe3, 0x01, 0x70, 0x54, 0x07, 0x3c, 0x9e, 0x5e, 0x74,
+        0x42, 0x4c, 0x34, 0x34,
+    ];
+    assert_eq!(head.to_array(), expected);
+}
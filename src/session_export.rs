@@ -0,0 +1,209 @@
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::stellar_cli::{self, Network};
+
+/// Which session to export, and from which deployed contract
+pub struct ExportOptions {
+    pub contract_id: String,
+    /// Identity name or secret key used to simulate the calls
+    pub source: String,
+    pub network: Network,
+    pub session_id: u64,
+}
+
+/// A session's audit logs with the hash chain recomputed off-chain
+pub struct Transcript {
+    /// Canonical JSON: sorted keys, integers as numbers
+    pub json: Value,
+    pub operation_count: u64,
+    pub computed: [u8; 32],
+    pub commitment: [u8; 32],
+}
+
+impl Transcript {
+    /// Whether the recomputed chain matches the contract's commitment.
+    pub fn verified(&self) -> bool {
+        self.computed == self.commitment
+    }
+}
+
+/// Pull every audit log of a session and recompute its hash chain.
+pub fn export(options: &ExportOptions) -> Result<Transcript, String> {
+    stellar_cli::require_cli()?;
+    let session_id = options.session_id.to_string();
+
+    let session = parse(
+        "get_session",
+        &simulate(options, "get_session", &[("session_id", &session_id)])?,
+    )?;
+    let operation_count = simulate(
+        options,
+        "get_session_operation_count",
+        &[("session_id", &session_id)],
+    )?
+    .trim_matches('"')
+    .parse::<u64>()
+    .map_err(|_| "unexpected get_session_operation_count output".to_string())?;
+
+    let mut logs = Vec::new();
+    let mut head = [0u8; 32];
+    for index in 0..operation_count {
+        let raw = parse(
+            "get_session_audit_log",
+            &simulate(
+                options,
+                "get_session_audit_log",
+                &[
+                    ("session_id", &session_id),
+                    ("operation_index", &index.to_string()),
+                ],
+            )?,
+        )?;
+        let log = AuditLog::from_json(&raw)?;
+        head = log.chain(&head);
+        logs.push(log.to_json());
+    }
+
+    let commitment = parse(
+        "get_session_commitment",
+        &simulate(
+            options,
+            "get_session_commitment",
+            &[("session_id", &session_id)],
+        )?,
+    )?;
+    let commitment = commitment
+        .as_str()
+        .and_then(|hex| hex::decode(hex).ok())
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .ok_or_else(|| "unexpected get_session_commitment output".to_string())?;
+
+    let json = json!({
+        "session": {
+            "session_id": options.session_id,
+            "initiator": session["initiator"],
+            "created_at": stellar_cli::json_u64(&session, "created_at"),
+            "nonce": stellar_cli::json_u64(&session, "nonce"),
+        },
+        "operation_count": operation_count,
+        "logs": logs,
+        "commitment": hex::encode(commitment),
+    });
+
+    Ok(Transcript {
+        json,
+        operation_count,
+        computed: head,
+        commitment,
+    })
+}
+
+/// An audit log entry as returned by `get_session_audit_log`
+struct AuditLog {
+    log_id: u64,
+    session_id: u64,
+    operation_index: u64,
+    operation_type: String,
+    timestamp: u64,
+    status: String,
+    result_data: u64,
+    actor: String,
+    /// All zeros when the operation wasn't correlated
+    correlation_id: [u8; 16],
+}
+
+impl AuditLog {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let operation = &value["operation"];
+        let number = |value: &Value, field: &str| {
+            stellar_cli::json_u64(value, field)
+                .ok_or_else(|| format!("audit log is missing {}", field))
+        };
+        let string = |value: &Value, field: &str| {
+            value[field]
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| format!("audit log is missing {}", field))
+        };
+
+        Ok(Self {
+            log_id: number(value, "log_id")?,
+            session_id: number(value, "session_id")?,
+            operation_index: number(operation, "operation_index")?,
+            operation_type: string(operation, "operation_type")?,
+            timestamp: number(operation, "timestamp")?,
+            status: string(operation, "status")?,
+            result_data: number(operation, "result_data")?,
+            actor: string(value, "actor")?,
+            correlation_id: operation["correlation_id"]
+                .as_str()
+                .and_then(|hex| hex::decode(hex).ok())
+                .and_then(|bytes| <[u8; 16]>::try_from(bytes).ok())
+                .ok_or_else(|| "audit log is missing correlation_id".to_string())?,
+        })
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "log_id": self.log_id,
+            "session_id": self.session_id,
+            "actor": self.actor,
+            "operation": {
+                "operation_index": self.operation_index,
+                "operation_type": self.operation_type,
+                "timestamp": self.timestamp,
+                "status": self.status,
+                "result_data": self.result_data,
+                "correlation_id": hex::encode(self.correlation_id),
+            },
+        })
+    }
+
+    /// Same encoding as the contract's `serialization::chain_audit_log`:
+    /// `sha256(head || entry)`, integers big-endian, strings u32-length-prefixed,
+    /// the correlation ID appended raw when set.
+    fn chain(&self, head: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(head);
+        hasher.update(self.log_id.to_be_bytes());
+        hasher.update(self.session_id.to_be_bytes());
+        hasher.update(self.operation_index.to_be_bytes());
+        hash_string(&mut hasher, &self.operation_type);
+        hasher.update(self.timestamp.to_be_bytes());
+        hash_string(&mut hasher, &self.status);
+        hasher.update(self.result_data.to_be_bytes());
+        hash_string(&mut hasher, &self.actor);
+        if self.correlation_id != [0; 16] {
+            hasher.update(self.correlation_id);
+        }
+        hasher.finalize().into()
+    }
+}
+
+fn hash_string(hasher: &mut Sha256, value: &str) {
+    hasher.update((value.len() as u32).to_be_bytes());
+    hasher.update(value.as_bytes());
+}
+
+fn simulate(
+    options: &ExportOptions,
+    function: &str,
+    args: &[(&str, &str)],
+) -> Result<String, String> {
+    let args: Vec<(&str, String)> = args
+        .iter()
+        .map(|(name, value)| (*name, value.to_string()))
+        .collect();
+    stellar_cli::simulate(
+        &options.contract_id,
+        &options.source,
+        &options.network,
+        function,
+        &args,
+    )
+}
+
+fn parse(function: &str, output: &str) -> Result<Value, String> {
+    serde_json::from_str(output).map_err(|e| format!("unexpected {} output: {}", function, e))
+}
@@ -1,7 +1,15 @@
 #[cfg(test)]
 mod session_tests {
-    use crate::{AnchorKitContract, AnchorKitContractClient, OperationContext};
-    use soroban_sdk::{testutils::Address as _, xdr::ToXdr, Address, Bytes, BytesN, Env};
+    use crate::testutils::{attestor_public_key, sign_attestation};
+    use crate::{
+        serialization::chain_audit_log, AnchorKitContract, AnchorKitContractClient, AuditMode,
+        Error, OperationContext, OperationLogged, ReplayVerdict,
+    };
+    use soroban_sdk::{
+        testutils::{Address as _, Events},
+        xdr::ToXdr,
+        Address, BytesN, Env, TryFromVal,
+    };
 
     #[derive(Clone, Debug, Eq, PartialEq)]
     struct ReplaySnapshot {
@@ -40,10 +48,15 @@ mod session_tests {
 
         // Replay a fixed, recorded workflow offline:
         // 1) register attestor, 2) submit attestation, 3) submit second attestation.
-        client.register_attestor_with_session(&session_id, &attestor);
+        client.register_attestor_with_session(
+            &session_id,
+            &attestor,
+            &attestor_public_key(env),
+            &None,
+        );
 
         let payload_hash_1 = BytesN::from_array(env, &[1; 32]);
-        let signature_1 = Bytes::from_slice(env, &[10, 11, 12, 13]);
+        let signature_1 = sign_attestation(env, &attestor, &subject, 1_700_000_001u64, &payload_hash_1);
         let attestation_id_1 = client.submit_attestation_with_session(
             &session_id,
             &attestor,
@@ -51,10 +64,11 @@ mod session_tests {
             &1_700_000_001u64,
             &payload_hash_1,
             &signature_1,
+            &None,
         );
 
         let payload_hash_2 = BytesN::from_array(env, &[2; 32]);
-        let signature_2 = Bytes::from_slice(env, &[20, 21, 22, 23]);
+        let signature_2 = sign_attestation(env, &attestor, &subject, 1_700_000_002u64, &payload_hash_2);
         let attestation_id_2 = client.submit_attestation_with_session(
             &session_id,
             &attestor,
@@ -62,6 +76,7 @@ mod session_tests {
             &1_700_000_002u64,
             &payload_hash_2,
             &signature_2,
+            &None,
         );
 
         let operation_count = client.get_session_operation_count(&session_id);
@@ -97,4 +112,190 @@ mod session_tests {
             "Recorded workflow replay must be deterministic across isolated offline runs"
         );
     }
+
+    #[test]
+    fn test_session_audit_logs_and_commitment() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = create_test_contract(&env);
+        let admin = Address::generate(&env);
+        let attestor = Address::generate(&env);
+        client.initialize(&admin);
+
+        let other = client.create_session(&admin);
+        let session_id = client.create_session(&admin);
+        assert_eq!(
+            client.get_session_commitment(&session_id),
+            BytesN::from_array(&env, &[0; 32])
+        );
+
+        client.register_attestor_with_session(&other, &attestor, &attestor_public_key(&env), &None);
+        client.revoke_attestor_with_session(&session_id, &attestor, &None);
+        client.register_attestor_with_session(
+            &session_id,
+            &attestor,
+            &attestor_public_key(&env),
+            &None,
+        );
+
+        // Logs are indexed per session, and the commitment chains them in order
+        let mut head = BytesN::from_array(&env, &[0; 32]);
+        for index in 0..client.get_session_operation_count(&session_id) {
+            let log = client.get_session_audit_log(&session_id, &index);
+            assert_eq!(log.session_id, session_id);
+            assert_eq!(log.operation.operation_index, index);
+            head = chain_audit_log(&env, &head, &log);
+        }
+        assert_eq!(client.get_session_commitment(&session_id), head);
+        assert_ne!(client.get_session_commitment(&other), head);
+
+        assert_eq!(
+            client.try_get_session_audit_log(&session_id, &2),
+            Err(Ok(Error::SessionNotFound))
+        );
+        assert_eq!(
+            client.try_get_session_commitment(&99),
+            Err(Ok(Error::SessionNotFound))
+        );
+    }
+
+    #[test]
+    fn test_events_only_sessions_skip_audit_logs() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = create_test_contract(&env);
+        let admin = Address::generate(&env);
+        let initiator = Address::generate(&env);
+        let attestor = Address::generate(&env);
+        client.initialize(&admin);
+
+        let full = client.create_session(&initiator);
+        let light = client.create_session(&initiator);
+        client.set_session_audit_mode(&light, &AuditMode::EventsOnly);
+        assert_eq!(env.auths()[0].0, initiator);
+        assert_eq!(client.get_session_audit_mode(&full), AuditMode::Full);
+
+        client.register_attestor_with_session(&light, &attestor, &attestor_public_key(&env), &None);
+        let (_, _, data) = env.events().all().last().unwrap();
+        let logged = OperationLogged::try_from_val(&env, &data).unwrap();
+        assert_eq!(logged.session_id, light);
+        assert_eq!(client.get_session_operation_count(&light), 1);
+        assert_eq!(
+            client.try_get_audit_log(&logged.log_id),
+            Err(Ok(Error::SessionNotFound))
+        );
+        assert_eq!(
+            client.get_session_commitment(&light),
+            BytesN::from_array(&env, &[0; 32])
+        );
+
+        // Sessions follow the global mode unless they override it
+        client.revoke_attestor_with_session(&full, &attestor, &None);
+        assert_eq!(client.get_audit_log(&(logged.log_id + 1)).session_id, full);
+        client.set_audit_mode(&AuditMode::EventsOnly);
+        assert_eq!(client.get_session_audit_mode(&full), AuditMode::EventsOnly);
+        client.set_session_audit_mode(&light, &AuditMode::Full);
+        assert_eq!(client.get_session_audit_mode(&light), AuditMode::Full);
+    }
+
+    #[test]
+    fn test_verify_replay() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = create_test_contract(&env);
+        let admin = Address::generate(&env);
+        let attestor = Address::generate(&env);
+        client.initialize(&admin);
+
+        let session_id = client.create_session(&admin);
+        client.register_attestor_with_session(
+            &session_id,
+            &attestor,
+            &attestor_public_key(&env),
+            &None,
+        );
+        client.revoke_attestor_with_session(&session_id, &attestor, &None);
+
+        // An off-chain replay chains the exported logs the same way
+        let mut replayed = BytesN::from_array(&env, &[0; 32]);
+        for index in 0..2 {
+            let log = client.get_session_audit_log(&session_id, &index);
+            replayed = chain_audit_log(&env, &replayed, &log);
+        }
+        let result = client.verify_replay(&session_id, &replayed);
+        assert_eq!(result.verdict, ReplayVerdict::Match);
+        assert_eq!(result.operation_count, 2);
+        assert_eq!(result.digest, client.get_session_commitment(&session_id));
+
+        let wrong = BytesN::from_array(&env, &[7; 32]);
+        let result = client.verify_replay(&session_id, &wrong);
+        assert_eq!(result.verdict, ReplayVerdict::Mismatch);
+        assert_eq!(result.digest, replayed);
+
+        // Operations logged events-only can't be replayed from storage
+        client.set_session_audit_mode(&session_id, &AuditMode::EventsOnly);
+        client.register_attestor_with_session(
+            &session_id,
+            &attestor,
+            &attestor_public_key(&env),
+            &None,
+        );
+        let result = client.verify_replay(&session_id, &replayed);
+        assert_eq!(result.verdict, ReplayVerdict::Incomplete);
+        assert_eq!(result.operation_count, 3);
+
+        assert_eq!(
+            client.try_verify_replay(&99, &replayed),
+            Err(Ok(Error::SessionNotFound))
+        );
+    }
+
+    #[test]
+    fn test_correlation_id_links_sessions() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = create_test_contract(&env);
+        let admin = Address::generate(&env);
+        let attestor = Address::generate(&env);
+        client.initialize(&admin);
+
+        let correlation_id = BytesN::from_array(&env, &[9; 16]);
+        let first = client.create_session(&admin);
+        let second = client.create_session(&admin);
+        client.register_attestor_with_session(
+            &first,
+            &attestor,
+            &attestor_public_key(&env),
+            &Some(correlation_id.clone()),
+        );
+        client.revoke_attestor_with_session(&first, &attestor, &None);
+        client.register_attestor_with_session(
+            &second,
+            &attestor,
+            &attestor_public_key(&env),
+            &Some(correlation_id.clone()),
+        );
+
+        // Only the tagged operations are indexed, in the order they ran
+        assert_eq!(client.get_correlation_operation_count(&correlation_id), 2);
+        let log = client.get_correlated_audit_log(&correlation_id, &0);
+        assert_eq!((log.session_id, log.operation.operation_index), (first, 0));
+        assert_eq!(log.operation.correlation_id, correlation_id);
+        let log = client.get_correlated_audit_log(&correlation_id, &1);
+        assert_eq!((log.session_id, log.operation.operation_index), (second, 0));
+        assert_eq!(
+            client.try_get_correlated_audit_log(&correlation_id, &2),
+            Err(Ok(Error::NotFound))
+        );
+
+        let untagged = client.get_session_audit_log(&first, &1);
+        assert!(!untagged.operation.is_correlated());
+
+        // The ID is committed to, so the chain still replays with it
+        let replayed = chain_audit_log(&env, &BytesN::from_array(&env, &[0; 32]), &log);
+        assert_eq!(
+            client.verify_replay(&second, &replayed).verdict,
+            ReplayVerdict::Match
+        );
+    }
 }
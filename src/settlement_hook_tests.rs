@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod settlement_hook_tests {
+    use crate::{AnchorKitContract, AnchorKitContractClient, SettlementDetails, SettlementHook};
+    use soroban_sdk::{
+        contract, contractimpl, symbol_short,
+        testutils::{Address as _, Ledger},
+        Address, BytesN, Env,
+    };
+
+    /// Receiver that records the settlement it was notified about.
+    #[contract]
+    struct Receiver;
+
+    #[contractimpl]
+    impl SettlementHook for Receiver {
+        fn on_settlement(env: Env, details: SettlementDetails) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("SETTLED"), &details);
+        }
+    }
+
+    #[contractimpl]
+    impl Receiver {
+        pub fn last_settlement(env: Env) -> Option<SettlementDetails> {
+            env.storage().instance().get(&symbol_short!("SETTLED"))
+        }
+    }
+
+    /// Contract receiver that does not implement the hook.
+    #[contract]
+    struct PlainWallet;
+
+    fn setup(env: &Env) -> AnchorKitContractClient<'_> {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env));
+        client
+    }
+
+    #[test]
+    fn test_contract_receiver_is_notified() {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.timestamp = 5_000);
+        let client = setup(&env);
+        let receiver_id = env.register_contract(None, Receiver);
+        let sender = Address::generate(&env);
+
+        let transfer_id = client.initiate_transfer(&sender, &receiver_id, &750);
+        let settlement_ref = BytesN::from_array(&env, &[7u8; 32]);
+        client.confirm_settlement(&transfer_id, &settlement_ref);
+
+        let details = ReceiverClient::new(&env, &receiver_id)
+            .last_settlement()
+            .unwrap();
+        assert_eq!(details.transfer_id, transfer_id);
+        assert_eq!(details.sender, sender);
+        assert_eq!(details.amount, 750);
+        assert_eq!(details.settlement_ref, settlement_ref);
+        assert_eq!(details.settled_at, 5_000);
+    }
+
+    #[test]
+    fn test_receivers_without_hook_still_settle() {
+        let env = Env::default();
+        let client = setup(&env);
+        let wallet = env.register_contract(None, PlainWallet);
+        let sender = Address::generate(&env);
+
+        let transfer_id = client.initiate_transfer(&sender, &wallet, &10);
+        client.confirm_settlement(&transfer_id, &BytesN::from_array(&env, &[1u8; 32]));
+
+        // Contract addresses with no deployed code are skipped as well
+        let undeployed = client.initiate_transfer(&sender, &Address::generate(&env), &10);
+        client.confirm_settlement(&undeployed, &BytesN::from_array(&env, &[3u8; 32]));
+
+        // Unrecorded transfer IDs settle without a notification
+        client.confirm_settlement(&999, &BytesN::from_array(&env, &[2u8; 32]));
+        assert_eq!(client.get_transfer(&transfer_id).destination, wallet);
+    }
+}
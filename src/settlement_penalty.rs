@@ -0,0 +1,95 @@
+use soroban_sdk::Env;
+
+use crate::{
+    amount::Amount,
+    errors::Error,
+    metrics::Metrics,
+    offboarding::Offboarding,
+    storage::Storage,
+    token::TokenCustody,
+    types::LateSettlementPenalty,
+};
+
+/// Late-settlement penalties.
+///
+/// An intent built against a corridor with a settlement SLA should have its
+/// settlement confirmed within the SLA of executing, by its execution's
+/// `settle_by`. Confirming it later charges the anchor the corridor's
+/// `late_penalty_bps_per_day` of the intent amount, accrued per second and
+/// capped at the amount, in favor of the payer. The
+/// penalty is paid out of the anchor's bond in the token the intent was paid
+/// with; if that bond can't cover it, it is deducted from the anchor's fee
+/// revenue instead.
+pub struct SettlementPenalty;
+
+impl SettlementPenalty {
+    const SECONDS_PER_DAY: u64 = 86_400;
+
+    /// Mark `intent_id`'s execution settled now and charge the anchor if that
+    /// is past the SLA. IDs that aren't executed intents are left alone; an
+    /// execution that was already settled fails with `ReplayAttack`.
+    pub fn settle(env: &Env, intent_id: u64) -> Result<Option<LateSettlementPenalty>, Error> {
+        let Some(mut execution) = Storage::get_intent_execution(env, intent_id) else {
+            return Ok(None);
+        };
+        if execution.settled_at != 0 {
+            return Err(Error::ReplayAttack);
+        }
+        let now = env.ledger().timestamp();
+        execution.settled_at = now;
+        Storage::set_intent_execution(env, &execution);
+
+        let intent = Storage::get_intent(env, intent_id)?;
+        if execution.settle_by == 0 || now <= execution.settle_by {
+            return Ok(None);
+        }
+        let corridor = Storage::get_corridor(env, intent.corridor_id)?;
+        let late_seconds = now - execution.settle_by;
+        let amount = Self::accrued(
+            intent.request.amount as i128,
+            corridor.late_penalty_bps_per_day,
+            late_seconds,
+        );
+        if amount == 0 {
+            return Ok(None);
+        }
+
+        let mut penalty = LateSettlementPenalty {
+            intent_id,
+            anchor: intent.anchor.clone(),
+            sender: execution.payer.clone(),
+            asset: intent.request.base_asset.clone(),
+            amount,
+            late_seconds,
+            bond_token: None,
+        };
+        let token_amount = Amount::of(env, &penalty.asset, amount)
+            .rescale_floor(TokenCustody::decimals(env, &execution.token))?
+            .value;
+        if token_amount > 0
+            && Offboarding::bond_amount(env, &penalty.anchor, &execution.token) >= token_amount
+        {
+            Offboarding::pay_from_bond(
+                env,
+                &penalty.anchor,
+                &execution.token,
+                &penalty.sender,
+                token_amount,
+            )?;
+            penalty.bond_token = Some(execution.token);
+        } else {
+            Metrics::record_penalty(env, &penalty.anchor, &penalty.asset, amount);
+        }
+        Storage::set_late_penalty(env, &penalty);
+        Ok(Some(penalty))
+    }
+
+    /// `bps_per_day` of `amount` for each day of `late_seconds`, capped at `amount`.
+    fn accrued(amount: i128, bps_per_day: u32, late_seconds: u64) -> i128 {
+        let penalty = amount
+            .saturating_mul(bps_per_day as i128)
+            .saturating_mul(late_seconds as i128)
+            / (10_000 * Self::SECONDS_PER_DAY as i128);
+        penalty.min(amount)
+    }
+}
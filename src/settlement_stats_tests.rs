@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod settlement_stats_tests {
+    use crate::testutils::{advance_time, AnchorKitFixture};
+    use crate::{Error, RoutingRequest, RoutingStrategy, ServiceType};
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+    fn settle_after(kit: &AnchorKitFixture, anchor: &Address, seconds: u64) {
+        let sender = Address::generate(kit.env);
+        let transfer_id = kit.client.initiate_transfer(&sender, anchor, &100);
+        advance_time(kit.env, seconds);
+        kit.client
+            .confirm_settlement(&transfer_id, &BytesN::from_array(kit.env, &[0u8; 32]));
+    }
+
+    #[test]
+    fn test_settlement_percentiles() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        assert_eq!(kit.client.get_settlement_stats(&anchor).p50, 0);
+
+        for i in 1..=20 {
+            settle_after(&kit, &anchor, i * 10);
+        }
+        let stats = kit.client.get_settlement_stats(&anchor);
+        assert_eq!(stats.durations.len(), 20);
+        assert_eq!(stats.p50, 100);
+        assert_eq!(stats.p95, 190);
+    }
+
+    #[test]
+    fn test_payouts_count_towards_the_sending_anchor() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Withdrawals]);
+        let user = Address::generate(&env);
+        let settlement_ref = BytesN::from_array(&env, &[0u8; 32]);
+
+        let payout = kit.client.initiate_transfer(&anchor, &user, &100);
+        advance_time(&env, 300);
+        kit.client.confirm_settlement(&payout, &settlement_ref);
+        assert_eq!(kit.client.get_settlement_stats(&anchor).p50, 300);
+        assert!(kit.client.get_settlement_stats(&user).durations.is_empty());
+
+        // A settlement is only counted once
+        assert_eq!(
+            kit.client.try_confirm_settlement(&payout, &settlement_ref),
+            Err(Ok(Error::ReplayAttack))
+        );
+        assert_eq!(kit.client.get_settlement_stats(&anchor).durations.len(), 1);
+    }
+
+    #[test]
+    fn test_fastest_settlement_uses_observed_times() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let services = [ServiceType::Deposits, ServiceType::Quotes];
+        let claims_fast = kit.register_anchor(&services);
+        let settles_fast = kit.register_anchor(&services);
+        kit.client
+            .set_anchor_metadata(&claims_fast, &9000, &60, &8000, &9900, &1_000_000);
+        kit.client
+            .set_anchor_metadata(&settles_fast, &9000, &600, &8000, &9900, &1_000_000);
+
+        let route = || {
+            kit.seed_quote(&claims_fast, "USDC", "EUR", 9_000, 600);
+            kit.seed_quote(&settles_fast, "USDC", "EUR", 9_000, 600);
+            kit.client
+                .route_transaction(&kit.admin, &RoutingRequest {
+                    request: kit.deposit_intent(&claims_fast, "USDC", "EUR", 500).request,
+                    strategy: RoutingStrategy::FastestSettlement,
+                    max_anchors: 2,
+                    require_kyc: false,
+                    min_reputation: 0,
+                    corridor_id: 0,
+                    record_exclusions: false,
+                })
+                .selected_anchor
+        };
+
+        // Without settlements, routing falls back to the reported averages
+        assert_eq!(route(), claims_fast);
+
+        settle_after(&kit, &claims_fast, 3_600);
+        settle_after(&kit, &settles_fast, 120);
+        assert_eq!(route(), settles_fast);
+    }
+}
@@ -0,0 +1,386 @@
+use std::cell::OnceCell;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use ed25519_dalek::{Signature, Signer as _, SigningKey, Verifier, VerifyingKey};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use stellar_strkey::ed25519;
+use stellar_xdr::curr::{
+    DecoratedSignature, Hash, Limits, ReadXdr, SignatureHint, TransactionEnvelope,
+    TransactionSignaturePayload, TransactionSignaturePayloadTaggedTransaction, WriteXdr,
+};
+
+use crate::keystore::Keystore;
+use crate::stellar_cli::{self, Network};
+
+const KEYSTORE_PREFIX: &str = "keystore:";
+const EXTERNAL_PREFIX: &str = "external:";
+const LEDGER_PREFIX: &str = "ledger:";
+
+/// Who signs transactions and attestation hashes, as given to `--source`
+pub enum Signer {
+    /// `stellar keys` identity name or secret key; the `stellar` CLI signs
+    Identity(String),
+    /// Encrypted keystore file, unlocked with a password on first use
+    Keystore {
+        path: PathBuf,
+        key: OnceCell<Box<SigningKey>>,
+    },
+    /// Command that signs hashes, speaking JSON on stdin and stdout
+    External(String),
+    /// Ledger account signed through `stellar tx sign --sign-with-ledger`
+    Ledger { address: String, hd_path: u32 },
+}
+
+impl Signer {
+    /// Parse `keystore:PATH`, `external:COMMAND`, `ledger:ADDRESS[:INDEX]`, or
+    /// else an identity name or secret key.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        if let Some(path) = source.strip_prefix(KEYSTORE_PREFIX) {
+            return Ok(Self::Keystore {
+                path: PathBuf::from(path),
+                key: OnceCell::new(),
+            });
+        }
+        if let Some(command) = source.strip_prefix(EXTERNAL_PREFIX) {
+            if command.trim().is_empty() {
+                return Err("external signer needs a command: external:COMMAND".to_string());
+            }
+            return Ok(Self::External(command.to_string()));
+        }
+        if let Some(ledger) = source.strip_prefix(LEDGER_PREFIX) {
+            let (address, hd_path) = match ledger.split_once(':') {
+                Some((address, index)) => (
+                    address,
+                    index
+                        .parse()
+                        .map_err(|_| format!("invalid Ledger account index {}", index))?,
+                ),
+                None => (ledger, 0),
+            };
+            if ed25519::PublicKey::from_string(address).is_err() {
+                return Err(
+                    "Ledger signer needs the device account: ledger:G...[:INDEX]".to_string(),
+                );
+            }
+            return Ok(Self::Ledger {
+                address: address.to_string(),
+                hd_path,
+            });
+        }
+        Ok(Self::Identity(source.to_string()))
+    }
+
+    /// Account address of the signing key.
+    pub fn address(&self) -> Result<String, String> {
+        match self {
+            Self::Identity(secret) if stellar_cli::is_secret_key(secret) => {
+                Ok(public_address(&secret_key(secret)?))
+            }
+            Self::Identity(name) => {
+                stellar_cli::run(&["keys".to_string(), "address".to_string(), name.clone()])
+            }
+            Self::Keystore { path, .. } => Ok(Keystore::load(path)?.address),
+            Self::External(command) => {
+                let address = external(command, json!({ "action": "address" }))?;
+                ed25519::PublicKey::from_string(&address)
+                    .map_err(|_| format!("external signer returned invalid address {}", address))?;
+                Ok(address)
+            }
+            Self::Ledger { address, .. } => Ok(address.clone()),
+        }
+    }
+
+    /// `--source` value for the `stellar` CLI: the identity itself, or the
+    /// signer's address for calls that are only built or simulated.
+    pub fn source_arg(&self) -> Result<String, String> {
+        match self {
+            Self::Identity(source) => Ok(source.clone()),
+            _ => self.address(),
+        }
+    }
+
+    /// Sign a 32-byte hash; `kind` tells external signers what they approve.
+    pub fn sign_hash(&self, hash: &[u8; 32], kind: &str) -> Result<[u8; 64], String> {
+        match self {
+            Self::Identity(secret) if stellar_cli::is_secret_key(secret) => {
+                Ok(secret_key(secret)?.sign(hash).to_bytes())
+            }
+            Self::Identity(name) => Err(format!(
+                "identity {} is held by the stellar CLI, which only signs transactions; \
+                 use a keystore: or external: signer",
+                name
+            )),
+            Self::Keystore { path, key } => {
+                if key.get().is_none() {
+                    let _ = key.set(Box::new(Keystore::unlock(path)?));
+                }
+                Ok(key.get().expect("unlocked").sign(hash).to_bytes())
+            }
+            Self::External(command) => {
+                let address = self.address()?;
+                let response = external(
+                    command,
+                    json!({ "action": "sign", "kind": kind, "address": address, "hash": hex::encode(hash) }),
+                )?;
+                let signature = hex::decode(&response)
+                    .ok()
+                    .and_then(|bytes| <[u8; 64]>::try_from(bytes).ok())
+                    .ok_or_else(|| "external signer returned an invalid signature".to_string())?;
+                verify(&address, hash, &signature)?;
+                Ok(signature)
+            }
+            Self::Ledger { .. } => Err(
+                "Ledger devices only sign transactions here; use an external: signer for hashes"
+                    .to_string(),
+            ),
+        }
+    }
+
+    /// Run a transaction-submitting `stellar` command. Identities submit
+    /// directly; other signers build the transaction with `--build-only`,
+    /// simulate it, sign it and send it, so no secret reaches the CLI.
+    pub fn submit(&self, args: &[String], network: &Network) -> Result<String, String> {
+        if let Self::Identity(_) = self {
+            return stellar_cli::run(args);
+        }
+
+        let mut build = args.to_vec();
+        let call = build
+            .iter()
+            .position(|arg| arg == "--")
+            .unwrap_or(build.len());
+        build.insert(call, "--build-only".to_string());
+        let built = stellar_cli::run(&build)?;
+
+        let assembled = stellar_cli::run_with_stdin(&tx_args("simulate", network)?, &built)?;
+        let signed = self.sign_transaction(&assembled, network)?;
+        stellar_cli::run_with_stdin(&tx_args("send", network)?, &signed)
+    }
+
+    /// Steps `submit` runs after the built command, for dry runs.
+    pub fn describe_submission(&self) -> Option<String> {
+        let sign = match self {
+            Self::Identity(_) => return None,
+            Self::Keystore { path, .. } => format!("sign with keystore {}", path.display()),
+            Self::External(command) => format!("sign with `{}`", command),
+            Self::Ledger { hd_path, .. } => {
+                format!("stellar tx sign --sign-with-ledger --hd-path {}", hd_path)
+            }
+        };
+        Some(format!(
+            "  --build-only | stellar tx simulate | {} | stellar tx send",
+            sign
+        ))
+    }
+
+    /// Add this signer's signature to a base64 transaction envelope.
+    fn sign_transaction(&self, xdr: &str, network: &Network) -> Result<String, String> {
+        if let Self::Ledger { hd_path, .. } = self {
+            let mut args = tx_args("sign", network)?;
+            args.extend([
+                "--sign-with-ledger".to_string(),
+                "--hd-path".to_string(),
+                hd_path.to_string(),
+            ]);
+            return stellar_cli::run_with_stdin(&args, xdr);
+        }
+
+        let invalid = |e: stellar_xdr::curr::Error| format!("unexpected transaction XDR: {}", e);
+        let mut envelope =
+            TransactionEnvelope::from_xdr_base64(xdr.trim(), Limits::none()).map_err(invalid)?;
+        let TransactionEnvelope::Tx(envelope_v1) = &mut envelope else {
+            return Err("unexpected transaction envelope type".to_string());
+        };
+
+        let payload = TransactionSignaturePayload {
+            network_id: Hash(network.id()?),
+            tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(
+                envelope_v1.tx.clone(),
+            ),
+        };
+        let hash: [u8; 32] =
+            Sha256::digest(payload.to_xdr(Limits::none()).map_err(invalid)?).into();
+        let signature = self.sign_hash(&hash, "transaction")?;
+
+        let address = ed25519::PublicKey::from_string(&self.address()?)
+            .map_err(|_| "invalid signer address".to_string())?;
+        let mut signatures = envelope_v1.signatures.to_vec();
+        signatures.push(DecoratedSignature {
+            hint: SignatureHint(address.0[28..].try_into().expect("4-byte hint")),
+            signature: signature.to_vec().try_into().map_err(invalid)?,
+        });
+        envelope_v1.signatures = signatures.try_into().map_err(invalid)?;
+        envelope.to_xdr_base64(Limits::none()).map_err(invalid)
+    }
+}
+
+fn tx_args(subcommand: &str, network: &Network) -> Result<Vec<String>, String> {
+    let mut args = vec!["tx".to_string(), subcommand.to_string()];
+    args.extend(network.args()?);
+    Ok(args)
+}
+
+/// Send one JSON request to an external signer and return its trimmed output.
+/// The signer's stderr stays attached so it can prompt for confirmation.
+fn external(command: &str, request: Value) -> Result<String, String> {
+    let mut shell = if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C");
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+    let mut child = shell
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|e| format!("failed to run external signer: {}", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "{}", request)
+            .map_err(|e| format!("failed to write to external signer: {}", e))?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("failed to run external signer: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("external signer exited with {}", output.status));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn secret_key(secret: &str) -> Result<SigningKey, String> {
+    ed25519::PrivateKey::from_string(secret)
+        .map(|private| SigningKey::from_bytes(&private.0))
+        .map_err(|_| "invalid secret key".to_string())
+}
+
+fn public_address(key: &SigningKey) -> String {
+    ed25519::PublicKey(key.verifying_key().to_bytes()).to_string()
+}
+
+/// Reject signatures that don't verify against `address`, so a misbehaving
+/// external signer fails here rather than on submission.
+fn verify(address: &str, hash: &[u8; 32], signature: &[u8; 64]) -> Result<(), String> {
+    let key = ed25519::PublicKey::from_string(address)
+        .ok()
+        .and_then(|key| VerifyingKey::from_bytes(&key.0).ok())
+        .ok_or_else(|| format!("invalid signer address {}", address))?;
+    key.verify(hash, &Signature::from_bytes(signature))
+        .map_err(|_| format!("external signer's signature does not match {}", address))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> SigningKey {
+        SigningKey::from_bytes(&[byte; 32])
+    }
+
+    fn secret(key: &SigningKey) -> String {
+        ed25519::PrivateKey(key.to_bytes()).to_string()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(matches!(
+            Signer::parse("keystore:ops.json").unwrap(),
+            Signer::Keystore { path, .. } if path == std::path::Path::new("ops.json")
+        ));
+        assert!(matches!(
+            Signer::parse("external:sign.sh --card 1").unwrap(),
+            Signer::External(command) if command == "sign.sh --card 1"
+        ));
+        assert!(Signer::parse("external: ").is_err());
+
+        let address = public_address(&key(1));
+        assert!(matches!(
+            Signer::parse(&format!("ledger:{}:3", address)).unwrap(),
+            Signer::Ledger { address: a, hd_path: 3 } if a == address
+        ));
+        assert!(matches!(
+            Signer::parse(&format!("ledger:{}", address)).unwrap(),
+            Signer::Ledger { hd_path: 0, .. }
+        ));
+        assert!(Signer::parse(&format!("ledger:{}:x", address)).is_err());
+        assert!(Signer::parse("ledger:alice").is_err());
+
+        assert!(matches!(
+            Signer::parse("alice").unwrap(),
+            Signer::Identity(name) if name == "alice"
+        ));
+    }
+
+    #[test]
+    fn test_secret_key_identity_signs_hashes() {
+        let signer = Signer::parse(&secret(&key(1))).unwrap();
+        let address = signer.address().unwrap();
+        assert_eq!(address, public_address(&key(1)));
+
+        let hash = [7u8; 32];
+        let signature = signer.sign_hash(&hash, "attestation").unwrap();
+        assert!(verify(&address, &hash, &signature).is_ok());
+        assert!(verify(&public_address(&key(2)), &hash, &signature).is_err());
+    }
+
+    #[test]
+    fn test_named_identity_cannot_sign_hashes() {
+        assert!(Signer::parse("alice")
+            .unwrap()
+            .sign_hash(&[0; 32], "attestation")
+            .is_err());
+    }
+
+    /// External signer answering address requests with `address` and sign
+    /// requests with `signature`.
+    #[cfg(unix)]
+    fn external_signer(address: &str, signature: &[u8; 64]) -> Signer {
+        Signer::External(format!(
+            "read request; case \"$request\" in *sign*) echo {} ;; *) echo {} ;; esac",
+            hex::encode(signature),
+            address
+        ))
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_external_signature_is_verified() {
+        let hash = [7u8; 32];
+        let address = public_address(&key(1));
+
+        let signature = key(1).sign(&hash).to_bytes();
+        let signer = external_signer(&address, &signature);
+        assert_eq!(signer.address().unwrap(), address);
+        assert_eq!(signer.sign_hash(&hash, "attestation").unwrap(), signature);
+
+        // Signed by some other key than the one it claims
+        let signature = key(2).sign(&hash).to_bytes();
+        assert_eq!(
+            external_signer(&address, &signature)
+                .sign_hash(&hash, "attestation")
+                .err()
+                .unwrap(),
+            format!("external signer's signature does not match {}", address)
+        );
+    }
+
+    #[test]
+    fn test_describe_submission() {
+        assert_eq!(Signer::parse("alice").unwrap().describe_submission(), None);
+        assert_eq!(
+            Signer::parse("ledger:GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF:2")
+                .unwrap()
+                .describe_submission()
+                .unwrap(),
+            "  --build-only | stellar tx simulate | \
+             stellar tx sign --sign-with-ledger --hd-path 2 | stellar tx send"
+        );
+    }
+}
@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod sponsored_ops_tests {
+    use crate::testutils::attestor_public_key;
+    use crate::{
+        AmountSide, AnchorKitContract, AnchorKitContractClient, Asset, Error, QuoteContext,
+        QuoteRequest, ServiceType, TransactionIntentBuilder,
+    };
+    use soroban_sdk::{
+        testutils::{Address as _, AuthorizedFunction, MockAuth, MockAuthInvoke},
+        vec, Address, Env, IntoVal, String, Symbol,
+    };
+
+    fn asset(env: &Env, code: &str) -> Asset {
+        let issuer = String::from_str(
+            env,
+            "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN",
+        );
+        Asset::Classic(String::from_str(env, code), Address::from_string(&issuer))
+    }
+
+    fn setup<'a>(env: &'a Env) -> (Address, AnchorKitContractClient<'a>, Address) {
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        env.mock_all_auths();
+        let admin = Address::generate(env);
+        let anchor = Address::generate(env);
+        client.initialize(&admin);
+        client.register_attestor(&anchor, &attestor_public_key(env));
+        client.configure_services(
+            &anchor,
+            &vec![env, ServiceType::Deposits, ServiceType::Quotes],
+        );
+        env.set_auths(&[]);
+
+        (contract_id, client, anchor)
+    }
+
+    #[test]
+    fn test_sponsored_session_records_both_authorizations() {
+        let env = Env::default();
+        let (contract_id, client, _) = setup(&env);
+        let sponsor = Address::generate(&env);
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+
+        let session_id = client.sponsored_create_session(&sponsor, &user);
+        let auths = env.auths();
+        assert_eq!(auths.len(), 2);
+        assert_eq!(auths[0].0, sponsor);
+        assert_eq!(auths[1].0, user);
+        match &auths[1].1.function {
+            AuthorizedFunction::Contract((contract, function, args)) => {
+                assert_eq!(contract, &contract_id);
+                assert_eq!(function, &Symbol::new(&env, "sponsored_create_session"));
+                // The user's signature does not name the sponsor
+                assert_eq!(args.len(), 0);
+            }
+            _ => panic!("unexpected authorized function"),
+        }
+        assert_eq!(client.get_session(&session_id).initiator, user);
+    }
+
+    #[test]
+    fn test_user_signature_is_independent_of_sponsor() {
+        let env = Env::default();
+        let (contract_id, client, anchor) = setup(&env);
+        let sponsor = Address::generate(&env);
+        let receiver = Address::generate(&env);
+
+        env.mock_all_auths();
+        let quote_id = client.submit_quote(
+            &anchor,
+            &asset(&env, "USDC"),
+            &asset(&env, "EUR"),
+            &9000,
+            &10,
+            &100,
+            &10_000,
+            &3600,
+        );
+
+        let user_auth = MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "sponsored_ack_quote_received",
+            args: (anchor.clone(), quote_id).into_val(&env),
+            sub_invokes: &[],
+        };
+        let sponsor_args =
+            (sponsor.clone(), receiver.clone(), anchor.clone(), quote_id).into_val(&env);
+        let sponsor_auth = MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "sponsored_ack_quote_received",
+            args: sponsor_args,
+            sub_invokes: &[],
+        };
+        env.mock_auths(&[
+            MockAuth {
+                address: &sponsor,
+                invoke: &sponsor_auth,
+            },
+            MockAuth {
+                address: &receiver,
+                invoke: &user_auth,
+            },
+        ]);
+
+        client.sponsored_ack_quote_received(&sponsor, &receiver, &anchor, &quote_id);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sponsor_must_authorize() {
+        let env = Env::default();
+        let (contract_id, client, _) = setup(&env);
+        let sponsor = Address::generate(&env);
+        let user = Address::generate(&env);
+
+        // Only the user signs; nobody has agreed to pay
+        env.mock_auths(&[MockAuth {
+            address: &user,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "sponsored_create_session",
+                args: ().into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+
+        client.sponsored_create_session(&sponsor, &user);
+    }
+
+    #[test]
+    fn test_sponsored_intent_build() {
+        let env = Env::default();
+        let (_, client, anchor) = setup(&env);
+        let sponsor = Address::generate(&env);
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+
+        let request = QuoteRequest {
+            base_asset: asset(&env, "USDC"),
+            quote_asset: asset(&env, "EUR"),
+            amount: 500,
+            operation_type: ServiceType::Deposits,
+            context: QuoteContext::Sep6,
+            amount_side: AmountSide::Sell,
+            delivery_method: None,
+            country_code: None,
+        };
+        let builder = TransactionIntentBuilder::new(&env, anchor.clone(), request);
+
+        // The user must be the intent's subject
+        let result = client.try_sponsored_build_intent(&sponsor, &user, &builder);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+        let builder = builder.with_subject(user.clone());
+        let intent = client.sponsored_build_intent(&sponsor, &user, &builder);
+        assert_eq!(intent.anchor, anchor);
+        assert_eq!(env.auths().len(), 2);
+    }
+}
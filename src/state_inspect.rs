@@ -0,0 +1,605 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use stellar_strkey::{ed25519, Contract};
+use stellar_xdr::curr::{
+    AccountId, ContractDataDurability, Hash, LedgerEntryData, LedgerKey, LedgerKeyContractData,
+    Limits, PublicKey, ReadXdr, ScAddress, ScSymbol, ScVal, ScVec, Uint256, WriteXdr,
+};
+
+use crate::quote_compare;
+use crate::stellar_cli::Network;
+
+/// Storage-key symbols of each namespace, as written by `StorageKey::to_storage_key`
+const NAMESPACES: [(&str, &[&str]); 4] = [
+    ("attestations", &["ATTEST"]),
+    ("quotes", &["QUOTE", "LATESTQ"]),
+    (
+        "sessions",
+        &["SESS", "SNONCE", "SOPCNT", "SCHAIN", "SESSLOG"],
+    ),
+    ("audit", &["AUDIT"]),
+];
+
+/// Keys `getLedgerEntries` accepts per request
+const RPC_BATCH: usize = 200;
+
+/// Average ledger close time, for turning TTLs into wall-clock estimates
+pub const LEDGER_SECONDS: u64 = 5;
+
+/// Seconds allowed for each RPC request
+const RPC_TIMEOUT: &str = "30";
+
+pub struct InspectOptions {
+    pub contract_id: String,
+    pub network: Network,
+    /// Entries whose TTL ends within this many ledgers are reported
+    pub within_ledgers: u32,
+    /// Refuse to probe more keys than this
+    pub max_keys: usize,
+}
+
+#[derive(Serialize)]
+pub struct StateReport {
+    pub latest_ledger: u32,
+    pub namespaces: Vec<NamespaceStats>,
+    /// Entries expiring within the window, soonest first
+    pub expiring: Vec<ExpiringEntry>,
+}
+
+#[derive(Serialize)]
+pub struct NamespaceStats {
+    pub name: String,
+    pub entries: usize,
+    /// Total XDR size of the entries
+    pub bytes: usize,
+    pub next_expiry: Option<u32>,
+    pub expiring: usize,
+    /// Persistent entries past their TTL, which need restoring before use
+    pub archived: usize,
+}
+
+#[derive(Serialize)]
+pub struct ExpiringEntry {
+    pub namespace: String,
+    pub key: String,
+    pub live_until: u32,
+    /// Negative once the entry is archived
+    pub ledgers_left: i64,
+}
+
+/// One ledger entry returned by the RPC
+struct Entry {
+    key: ScVal,
+    data: LedgerEntryData,
+    bytes: usize,
+    live_until: u32,
+}
+
+/// Enumerate the contract's instance storage and the persistent entries its
+/// counters imply, then report counts, sizes and upcoming TTL expirations per
+/// namespace.
+pub fn inspect(options: &InspectOptions) -> Result<StateReport, String> {
+    let rpc = Rpc {
+        url: options.network.rpc_url()?,
+        contract: ScAddress::Contract(Hash(
+            Contract::from_string(&options.contract_id)
+                .map_err(|_| format!("invalid contract ID {}", options.contract_id))?
+                .0,
+        )),
+    };
+
+    let (latest_ledger, instance) = rpc.entries(&[ScVal::LedgerKeyContractInstance])?;
+    let instance = instance
+        .into_iter()
+        .next()
+        .ok_or_else(|| format!("contract {} not found", options.contract_id))?;
+    let LedgerEntryData::ContractData(data) = &instance.data else {
+        return Err("unexpected contract instance entry".to_string());
+    };
+    let ScVal::ContractInstance(contract) = &data.val else {
+        return Err("unexpected contract instance value".to_string());
+    };
+    let storage = contract
+        .storage
+        .as_ref()
+        .map(|map| map.0.to_vec())
+        .unwrap_or_default();
+    let counter = |name: &str| {
+        storage
+            .iter()
+            .find(|entry| entry.key == key(name, &[]))
+            .and_then(|entry| match entry.val {
+                ScVal::U64(value) => Some(value),
+                _ => None,
+            })
+            .unwrap_or(0)
+    };
+
+    // Attestation, session and audit IDs count up from 0; quote IDs from 1.
+    let mut keys: Vec<ScVal> = Vec::new();
+    keys.extend((0..counter("COUNTER")).map(|id| key("ATTEST", &[ScVal::U64(id)])));
+    keys.extend((0..counter("ACNT")).map(|id| key("AUDIT", &[ScVal::U64(id)])));
+    for id in 0..counter("SCNT") {
+        for name in ["SESS", "SNONCE", "SOPCNT", "SCHAIN"] {
+            keys.push(key(name, &[ScVal::U64(id)]));
+        }
+    }
+    let (_, anchor_list) = rpc.entries(&[key("ANCHLIST", &[])])?;
+    let anchors = match anchor_list.first().map(|entry| &entry.data) {
+        Some(LedgerEntryData::ContractData(data)) => match &data.val {
+            ScVal::Vec(Some(anchors)) => anchors.0.to_vec(),
+            _ => Vec::new(),
+        },
+        _ => Vec::new(),
+    };
+    for anchor in &anchors {
+        keys.push(key("LATESTQ", std::slice::from_ref(anchor)));
+        keys.extend(
+            (1..=counter("QCNT")).map(|id| key("QUOTE", &[anchor.clone(), ScVal::U64(id)])),
+        );
+    }
+    if keys.len() > options.max_keys {
+        return Err(format!(
+            "the contract's counters imply {} keys; raise --max-keys to probe them all",
+            keys.len()
+        ));
+    }
+
+    let (_, mut entries) = rpc.entries(&keys)?;
+    // Session logs are indexed by operation, so probe them once the counts are known.
+    let mut session_logs = Vec::new();
+    for entry in &entries {
+        let (ScVal::Vec(Some(parts)), LedgerEntryData::ContractData(data)) =
+            (&entry.key, &entry.data)
+        else {
+            continue;
+        };
+        if let (Some("SOPCNT"), Some(session), ScVal::U64(count)) =
+            (symbol(&entry.key), parts.0.get(1), &data.val)
+        {
+            session_logs.extend(
+                (0..*count).map(|index| key("SESSLOG", &[session.clone(), ScVal::U64(index)])),
+            );
+        }
+    }
+    entries.extend(rpc.entries(&session_logs)?.1);
+
+    let mut expiring = Vec::new();
+    let mut namespaces = vec![stats(
+        "instance",
+        latest_ledger,
+        options.within_ledgers,
+        &[&instance],
+        &mut expiring,
+    )];
+    namespaces[0].entries = storage.len();
+    for (name, symbols) in NAMESPACES {
+        let members: Vec<&Entry> = entries
+            .iter()
+            .filter(|entry| symbol(&entry.key).is_some_and(|s| symbols.contains(&s)))
+            .collect();
+        namespaces.push(stats(
+            name,
+            latest_ledger,
+            options.within_ledgers,
+            &members,
+            &mut expiring,
+        ));
+    }
+    expiring.sort_by_key(|entry| entry.live_until);
+
+    Ok(StateReport {
+        latest_ledger,
+        namespaces,
+        expiring,
+    })
+}
+
+/// Render the report as a table followed by the entries that need bumping.
+pub fn render(report: &StateReport, within_ledgers: u32) -> String {
+    let mut out = format!(
+        "{:<13} {:>8} {:>10} {:>12} {:>9} {:>9}\n",
+        "Namespace", "Entries", "Bytes", "Next expiry", "Expiring", "Archived"
+    );
+    for stats in &report.namespaces {
+        out += &format!(
+            "{:<13} {:>8} {:>10} {:>12} {:>9} {:>9}\n",
+            stats.name,
+            stats.entries,
+            stats.bytes,
+            stats
+                .next_expiry
+                .map(|ledger| ledger.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            stats.expiring,
+            stats.archived
+        );
+    }
+    if !report.expiring.is_empty() {
+        out += &format!(
+            "\nExpiring within {} ledgers (~{}), from ledger {}:\n",
+            within_ledgers,
+            duration(i64::from(within_ledgers)),
+            report.latest_ledger
+        );
+        for entry in &report.expiring {
+            let left = if entry.ledgers_left < 0 {
+                "archived".to_string()
+            } else {
+                format!(
+                    "{} ledgers (~{})",
+                    entry.ledgers_left,
+                    duration(entry.ledgers_left)
+                )
+            };
+            out += &format!("  {:<13} {:<40} {}\n", entry.namespace, entry.key, left);
+        }
+    }
+    out
+}
+
+/// Size and TTL summary of a namespace's `entries`. Those archived or expiring
+/// within `within_ledgers` of `latest_ledger` are added to `expiring`.
+fn stats(
+    name: &str,
+    latest_ledger: u32,
+    within_ledgers: u32,
+    entries: &[&Entry],
+    expiring: &mut Vec<ExpiringEntry>,
+) -> NamespaceStats {
+    let mut stats = NamespaceStats {
+        name: name.to_string(),
+        entries: entries.len(),
+        bytes: entries.iter().map(|entry| entry.bytes).sum(),
+        next_expiry: entries.iter().map(|entry| entry.live_until).min(),
+        expiring: 0,
+        archived: 0,
+    };
+    for entry in entries {
+        let ledgers_left = i64::from(entry.live_until) - i64::from(latest_ledger);
+        if ledgers_left < 0 {
+            stats.archived += 1;
+        } else if ledgers_left <= i64::from(within_ledgers) {
+            stats.expiring += 1;
+        } else {
+            continue;
+        }
+        expiring.push(ExpiringEntry {
+            namespace: name.to_string(),
+            key: label(&entry.key),
+            live_until: entry.live_until,
+            ledgers_left,
+        });
+    }
+    stats
+}
+
+/// `getLedgerEntries` over the contract's persistent storage
+struct Rpc {
+    url: String,
+    contract: ScAddress,
+}
+
+impl Rpc {
+    /// Fetch the entries that exist for `keys`, in batches. Returns the latest
+    /// ledger along with them.
+    fn entries(&self, keys: &[ScVal]) -> Result<(u32, Vec<Entry>), String> {
+        let invalid = |e: stellar_xdr::curr::Error| format!("unexpected XDR from RPC: {}", e);
+        let mut latest_ledger = 0;
+        let mut entries = Vec::new();
+        for batch in keys.chunks(RPC_BATCH) {
+            let encoded = batch
+                .iter()
+                .map(|key| {
+                    LedgerKey::ContractData(LedgerKeyContractData {
+                        contract: self.contract.clone(),
+                        key: key.clone(),
+                        durability: ContractDataDurability::Persistent,
+                    })
+                    .to_xdr_base64(Limits::none())
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(invalid)?;
+            let result = self.call("getLedgerEntries", json!({ "keys": encoded }))?;
+            let (latest, found) = decode_entries(&result)?;
+            latest_ledger = latest;
+            entries.extend(found);
+        }
+        Ok((latest_ledger, entries))
+    }
+
+    /// Send one JSON-RPC request with curl and return its `result`.
+    fn call(&self, method: &str, params: Value) -> Result<Value, String> {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let mut child = Command::new("curl")
+            .args([
+                "-sS",
+                "--max-time",
+                RPC_TIMEOUT,
+                "-H",
+                "Content-Type: application/json",
+                "--data-binary",
+                "@-",
+                &self.url,
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("failed to run curl: {}", e))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(request.to_string().as_bytes())
+                .map_err(|e| format!("failed to write to curl: {}", e))?;
+        }
+        let output = child
+            .wait_with_output()
+            .map_err(|e| format!("failed to run curl: {}", e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "RPC request to {} failed: {}",
+                self.url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let mut response: Value = serde_json::from_slice(&output.stdout)
+            .map_err(|e| format!("unexpected {} response: {}", method, e))?;
+        if let Some(error) = response.get("error") {
+            return Err(format!("{} failed: {}", method, error["message"]));
+        }
+        Ok(response["result"].take())
+    }
+}
+
+/// Contract data entries of a `getLedgerEntries` result, with the latest
+/// ledger it was read at.
+fn decode_entries(result: &Value) -> Result<(u32, Vec<Entry>), String> {
+    let invalid = |e: stellar_xdr::curr::Error| format!("unexpected XDR from RPC: {}", e);
+    let mut entries = Vec::new();
+    for entry in result["entries"].as_array().into_iter().flatten() {
+        let data = LedgerEntryData::from_xdr_base64(
+            entry["xdr"].as_str().unwrap_or_default(),
+            Limits::none(),
+        )
+        .map_err(invalid)?;
+        let LedgerEntryData::ContractData(contract_data) = &data else {
+            continue;
+        };
+        entries.push(Entry {
+            key: contract_data.key.clone(),
+            bytes: data.to_xdr(Limits::none()).map_err(invalid)?.len(),
+            live_until: entry["liveUntilLedgerSeq"].as_u64().unwrap_or_default() as u32,
+            data,
+        });
+    }
+    let latest_ledger = result["latestLedger"].as_u64().unwrap_or_default() as u32;
+    Ok((latest_ledger, entries))
+}
+
+/// Storage key `(SYMBOL, args...)`, as the contract builds them.
+fn key(name: &str, args: &[ScVal]) -> ScVal {
+    let mut parts = vec![ScVal::Symbol(ScSymbol(
+        name.try_into().expect("storage symbols are short"),
+    ))];
+    parts.extend_from_slice(args);
+    ScVal::Vec(Some(ScVec(
+        parts.try_into().expect("storage keys are short"),
+    )))
+}
+
+/// Leading symbol of a storage key
+fn symbol(key: &ScVal) -> Option<&str> {
+    match key {
+        ScVal::Vec(Some(parts)) => match parts.0.first() {
+            Some(ScVal::Symbol(symbol)) => std::str::from_utf8(symbol.0.as_slice()).ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Storage key as `SYMBOL arg...`, with addresses shortened
+fn label(key: &ScVal) -> String {
+    let ScVal::Vec(Some(parts)) = key else {
+        return format!("{:?}", key);
+    };
+    parts
+        .0
+        .iter()
+        .map(|part| match part {
+            ScVal::Symbol(symbol) => symbol.0.to_utf8_string_lossy(),
+            ScVal::U64(value) => value.to_string(),
+            ScVal::Address(ScAddress::Account(AccountId(PublicKey::PublicKeyTypeEd25519(
+                Uint256(bytes),
+            )))) => quote_compare::short_address(&ed25519::PublicKey(*bytes).to_string()),
+            ScVal::Address(ScAddress::Contract(Hash(bytes))) => {
+                quote_compare::short_address(&Contract(*bytes).to_string())
+            }
+            other => format!("{:?}", other),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Rough wall-clock length of `ledgers`
+fn duration(ledgers: i64) -> String {
+    let minutes = ledgers.max(0) as u64 * LEDGER_SECONDS / 60;
+    match minutes {
+        0..=119 => format!("{}m", minutes),
+        120..=2879 => format!("{}h", minutes / 60),
+        _ => format!("{}d", minutes / 1440),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stellar_xdr::curr::{ContractDataEntry, ExtensionPoint, TtlEntry};
+
+    const ANCHOR: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+
+    fn anchor() -> ScVal {
+        let key = ed25519::PublicKey::from_string(ANCHOR).unwrap();
+        ScVal::Address(ScAddress::Account(AccountId(
+            PublicKey::PublicKeyTypeEd25519(Uint256(key.0)),
+        )))
+    }
+
+    fn contract_data(key: ScVal, val: ScVal) -> LedgerEntryData {
+        LedgerEntryData::ContractData(ContractDataEntry {
+            ext: ExtensionPoint::V0,
+            contract: ScAddress::Contract(Hash([1; 32])),
+            key,
+            durability: ContractDataDurability::Persistent,
+            val,
+        })
+    }
+
+    /// One `getLedgerEntries` result entry
+    fn rpc_entry(data: &LedgerEntryData, live_until: u32) -> Value {
+        json!({
+            "key": "",
+            "xdr": data.to_xdr_base64(Limits::none()).unwrap(),
+            "lastModifiedLedgerSeq": 900,
+            "liveUntilLedgerSeq": live_until,
+        })
+    }
+
+    fn entry(key: ScVal, bytes: usize, live_until: u32) -> Entry {
+        Entry {
+            data: contract_data(key.clone(), ScVal::Void),
+            key,
+            bytes,
+            live_until,
+        }
+    }
+
+    #[test]
+    fn test_decode_entries() {
+        let quote = contract_data(key("QUOTE", &[anchor(), ScVal::U64(7)]), ScVal::U64(42));
+        let ttl = LedgerEntryData::Ttl(TtlEntry {
+            key_hash: Hash([2; 32]),
+            live_until_ledger_seq: 5000,
+        });
+        let result = json!({
+            "entries": [rpc_entry(&quote, 5000), rpc_entry(&ttl, 0)],
+            "latestLedger": 1000,
+        });
+
+        let (latest_ledger, entries) = decode_entries(&result).unwrap();
+        assert_eq!(latest_ledger, 1000);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(symbol(&entries[0].key), Some("QUOTE"));
+        assert_eq!(entries[0].live_until, 5000);
+        assert_eq!(
+            entries[0].bytes,
+            quote.to_xdr(Limits::none()).unwrap().len()
+        );
+        assert!(matches!(
+            &entries[0].data,
+            LedgerEntryData::ContractData(data) if data.val == ScVal::U64(42)
+        ));
+
+        assert_eq!(decode_entries(&json!({})).unwrap().1.len(), 0);
+        assert!(
+            decode_entries(&json!({ "entries": [{ "xdr": "not xdr" }] }))
+                .err()
+                .unwrap()
+                .starts_with("unexpected XDR from RPC")
+        );
+    }
+
+    #[test]
+    fn test_storage_keys() {
+        let quote = key("QUOTE", &[anchor(), ScVal::U64(7)]);
+        assert_eq!(symbol(&quote), Some("QUOTE"));
+        assert_eq!(label(&quote), "QUOTE GBRPYH…C7OX2H 7");
+        assert_eq!(label(&key("ANCHLIST", &[])), "ANCHLIST");
+        assert_eq!(symbol(&ScVal::U64(7)), None);
+    }
+
+    #[test]
+    fn test_stats_count_expiring_and_archived_entries() {
+        let entries = [
+            entry(key("ATTEST", &[ScVal::U64(0)]), 100, 900),
+            entry(key("ATTEST", &[ScVal::U64(1)]), 120, 1000),
+            entry(key("ATTEST", &[ScVal::U64(2)]), 140, 1100),
+            entry(key("ATTEST", &[ScVal::U64(3)]), 160, 1101),
+        ];
+        let mut expiring = Vec::new();
+        let stats = stats(
+            "attestations",
+            1000,
+            100,
+            &entries.iter().collect::<Vec<_>>(),
+            &mut expiring,
+        );
+
+        assert_eq!(stats.entries, 4);
+        assert_eq!(stats.bytes, 520);
+        assert_eq!(stats.next_expiry, Some(900));
+        assert_eq!(stats.archived, 1);
+        assert_eq!(stats.expiring, 2);
+        assert_eq!(
+            expiring
+                .iter()
+                .map(|entry| (entry.key.as_str(), entry.ledgers_left))
+                .collect::<Vec<_>>(),
+            [("ATTEST 0", -100), ("ATTEST 1", 0), ("ATTEST 2", 100)]
+        );
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(duration(-5), "0m");
+        assert_eq!(duration(12), "1m");
+        assert_eq!(duration(1428), "119m");
+        assert_eq!(duration(1440), "2h");
+        assert_eq!(duration(17_280), "24h");
+        assert_eq!(duration(34_560), "2d");
+    }
+
+    #[test]
+    fn test_render() {
+        let report = StateReport {
+            latest_ledger: 1000,
+            namespaces: vec![NamespaceStats {
+                name: "quotes".to_string(),
+                entries: 2,
+                bytes: 312,
+                next_expiry: Some(900),
+                expiring: 1,
+                archived: 1,
+            }],
+            expiring: vec![
+                ExpiringEntry {
+                    namespace: "quotes".to_string(),
+                    key: "QUOTE GBRPYH…C7OX2H 1".to_string(),
+                    live_until: 900,
+                    ledgers_left: -100,
+                },
+                ExpiringEntry {
+                    namespace: "quotes".to_string(),
+                    key: "LATESTQ GBRPYH…C7OX2H".to_string(),
+                    live_until: 1720,
+                    ledgers_left: 720,
+                },
+            ],
+        };
+        assert_eq!(
+            render(&report, 17_280),
+            concat!(
+                "Namespace      Entries      Bytes  Next expiry  Expiring  Archived\n",
+                "quotes               2        312          900         1         1\n",
+                "\n",
+                "Expiring within 17280 ledgers (~24h), from ledger 1000:\n",
+                "  quotes        QUOTE GBRPYH…C7OX2H 1                    archived\n",
+                "  quotes        LATESTQ GBRPYH…C7OX2H                    720 ledgers (~60m)\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+/// Network passphrases for the networks AnchorKit talks to by name
+const PASSPHRASES: [(&str, &str); 4] = [
+    ("testnet", "Test SDF Network ; September 2015"),
+    ("mainnet", "Public Global Stellar Network ; September 2015"),
+    ("futurenet", "Test SDF Future Network ; October 2022"),
+    ("local", "Standalone Network ; February 2017"),
+];
+
+/// Public Soroban RPC endpoints for the networks that have one
+const RPC_URLS: [(&str, &str); 3] = [
+    ("testnet", "https://soroban-testnet.stellar.org"),
+    ("futurenet", "https://rpc-futurenet.stellar.org"),
+    ("local", "http://localhost:8000/rpc"),
+];
+
+/// Passphrase of a network known by name.
+pub fn known_passphrase(network: &str) -> Option<&'static str> {
+    PASSPHRASES
+        .iter()
+        .find(|(name, _)| *name == network)
+        .map(|(_, passphrase)| *passphrase)
+}
+
+/// Network to reach through the `stellar` CLI, by name or explicit RPC URL
+pub struct Network {
+    pub name: String,
+    /// Soroban RPC URL overriding the network's default
+    pub rpc_url: Option<String>,
+    /// Passphrase for `rpc_url`; defaults to the named network's
+    pub passphrase: Option<String>,
+}
+
+impl Network {
+    /// `stellar` arguments selecting this network.
+    pub fn args(&self) -> Result<Vec<String>, String> {
+        let Some(rpc_url) = &self.rpc_url else {
+            return Ok(vec!["--network".to_string(), self.name.clone()]);
+        };
+
+        Ok(vec![
+            "--rpc-url".to_string(),
+            rpc_url.clone(),
+            "--network-passphrase".to_string(),
+            self.passphrase()?,
+        ])
+    }
+
+    pub fn passphrase(&self) -> Result<String, String> {
+        match &self.passphrase {
+            Some(passphrase) => Ok(passphrase.clone()),
+            None => known_passphrase(&self.name)
+                .map(str::to_string)
+                .ok_or_else(|| format!("unknown network passphrase for {}", self.name)),
+        }
+    }
+
+    /// Soroban RPC URL: the explicit one, else the named network's public one.
+    pub fn rpc_url(&self) -> Result<String, String> {
+        if let Some(rpc_url) = &self.rpc_url {
+            return Ok(rpc_url.clone());
+        }
+        RPC_URLS
+            .iter()
+            .find(|(name, _)| *name == self.name)
+            .map(|(_, url)| url.to_string())
+            .ok_or_else(|| format!("no public RPC for {}; pass --rpc-url", self.name))
+    }
+
+    /// Network ID: the SHA-256 of the passphrase, as used in transaction and
+    /// contract ID hashes.
+    pub fn id(&self) -> Result<[u8; 32], String> {
+        Ok(Sha256::digest(self.passphrase()?.as_bytes()).into())
+    }
+}
+
+/// Fail with an install hint if the `stellar` CLI is not on the PATH.
+pub fn require_cli() -> Result<(), String> {
+    run(&["--version".to_string()]).map(|_| ()).map_err(|_| {
+        "stellar CLI not found; install it from https://developers.stellar.org/docs/tools/cli"
+            .to_string()
+    })
+}
+
+/// Simulate a read-only call of `function` on `contract_id` without submitting
+/// a transaction. `fn_args` are `(name, json)` pairs. Returns the JSON result.
+pub fn simulate(
+    contract_id: &str,
+    source: &str,
+    network: &Network,
+    function: &str,
+    fn_args: &[(&str, String)],
+) -> Result<String, String> {
+    let mut args = invoke_args(contract_id, source, network, function, fn_args)?;
+    let call = args
+        .iter()
+        .position(|arg| arg == "--")
+        .unwrap_or(args.len());
+    args.insert(call, "--send=no".to_string());
+    run(&args)
+}
+
+/// `stellar contract invoke` arguments calling `function` with `(name, json)`
+/// argument pairs.
+pub fn invoke_args(
+    contract_id: &str,
+    source: &str,
+    network: &Network,
+    function: &str,
+    fn_args: &[(&str, String)],
+) -> Result<Vec<String>, String> {
+    let mut args = vec![
+        "contract".to_string(),
+        "invoke".to_string(),
+        "--id".to_string(),
+        contract_id.to_string(),
+        "--source".to_string(),
+        source.to_string(),
+    ];
+    args.extend(network.args()?);
+    args.push("--".to_string());
+    args.push(function.to_string());
+    for (name, value) in fn_args {
+        args.push(format!("--{}", name));
+        args.push(value.clone());
+    }
+    Ok(args)
+}
+
+/// Run `stellar` with `args` and return its trimmed stdout.
+pub fn run(args: &[String]) -> Result<String, String> {
+    let output = Command::new("stellar")
+        .args(args)
+        .output()
+        .map_err(|e| format!("failed to run stellar: {}", e))?;
+    finish(args, output)
+}
+
+/// Run `stellar` with `args`, writing `input` to its stdin. The `tx`
+/// subcommands read transaction XDR this way.
+pub fn run_with_stdin(args: &[String], input: &str) -> Result<String, String> {
+    let mut child = Command::new("stellar")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to run stellar: {}", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(input.as_bytes())
+            .map_err(|e| format!("failed to write to stellar: {}", e))?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("failed to run stellar: {}", e))?;
+    finish(args, output)
+}
+
+fn finish(args: &[String], output: Output) -> Result<String, String> {
+    if !output.status.success() {
+        return Err(format!(
+            "`{}` failed: {}",
+            render(args),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Secret seeds are 56-character strkeys starting with `S`; never echo them.
+pub fn is_secret_key(value: &str) -> bool {
+    value.len() == 56 && value.starts_with('S')
+}
+
+/// Printable `stellar` command line with secret keys redacted.
+pub fn render(args: &[String]) -> String {
+    let mut line = String::from("stellar");
+    for arg in args {
+        line.push(' ');
+        if is_secret_key(arg) {
+            line.push_str("<SECRET_KEY>");
+        } else if arg.contains(' ') {
+            line.push_str(&format!("{:?}", arg));
+        } else {
+            line.push_str(arg);
+        }
+    }
+    line
+}
+
+/// Read a `u64` field from `stellar` JSON output, which may encode it as a
+/// number or a string.
+pub fn json_u64(value: &Value, field: &str) -> Option<u64> {
+    let raw = &value[field];
+    raw.as_u64()
+        .or_else(|| raw.as_str().and_then(|s| s.parse().ok()))
+}
@@ -1,27 +1,65 @@
-use soroban_sdk::{Address, BytesN, Env, IntoVal, Vec};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, String, Symbol, Vec};
 
 use crate::{
     config::{ContractConfig, SessionConfig},
-    credentials::{CredentialPolicy, SecureCredential},
-    rate_limiter::RateLimitConfig,
+    credentials::{CredentialBinding, CredentialGrant, CredentialPolicy, SecureCredential},
+    rate_limiter::{OperationClass, OperationLimit, RateLimitConfig},
+    scheduled_params::{Parameter, ScheduledChange},
     types::{
-        AnchorMetadata, AnchorProfile, AnchorServices, Attestation, AuditLog, Endpoint, HealthStatus,
-        InteractionSession, OperationContext, QuoteData,
+        AnchorServices, AssetSupport, AttestorDelegate, AuditLog, AuditMode, DidBinding, Endpoint, EndpointChallenge,
+        EndpointKind, FreezeState, HealthStatus, InteractionSession, OffboardingState, OperationContext, Role,
+        SettlementStats, TomlHash,
     },
     Error,
 };
+#[cfg(feature = "compliance")]
+use crate::types::{
+    AmlFlag, ComplianceReport, CorridorRules, KycRecord, KycThreshold, RequirementTemplate,
+    Tombstone, TombstoneKind,
+};
+#[cfg(any(feature = "quotes", feature = "compliance"))]
+use crate::types::Asset;
+#[cfg(feature = "attestations")]
+use crate::types::{Attestation, AttestationGrant, ReplayScope};
+#[cfg(feature = "transfers")]
+use crate::types::{
+    AnchorActivity, AnchorBond, AnchorFeeRevenue, CounterpartyExposure, ReferralVolume, RetryRecord, RetryTarget,
+    UsageMetrics,
+};
+#[cfg(feature = "routing")]
+use crate::types::{AnchorMetadata, RoutingReceipt, UptimeReport};
+#[cfg(feature = "quotes")]
+use crate::types::{Corridor, OracleFeed, QuoteData};
+#[cfg(feature = "transfers")]
+use crate::types::{
+    DepositMemo, DepositMemoRecord, HashLock, IntentExecution, IntentTemplate, LateSettlementPenalty,
+    QuoteBinding, QuoteCapacity, ServiceType, SessionQuotePin,
+    TransactionIntent,
+    TransferInfo,
+    TransferRecord, TravelRuleCommitment, TravelRuleRole,
+};
 
 #[derive(Clone)]
 enum StorageKey {
     Admin,
     Attestor(Address),
+    #[cfg(feature = "attestations")]
     Counter,
+    #[cfg(feature = "attestations")]
     Attestation(u64),
+    #[cfg(feature = "attestations")]
     UsedHash(BytesN<32>),
-    Endpoint(Address),
+    Endpoint(Address, EndpointKind),
+    EndpointKinds(Address),
+    EndpointChallenge(Address, EndpointKind),
+    EndpointSigningKey(Address),
+    TomlHash(Address),
     AnchorServices(Address),
+    #[cfg(feature = "quotes")]
     Quote(Address, u64),
+    #[cfg(feature = "quotes")]
     QuoteCounter,
+    #[cfg(feature = "transfers")]
     IntentCounter,
     SessionCounter,
     Session(u64),
@@ -29,15 +67,170 @@ enum StorageKey {
     AuditLogCounter,
     AuditLog(u64),
     SessionOperationCount(u64),
+    SessionLog(u64, u64),
+    SessionChain(u64),
     ContractConfig,
     SessionConfig,
     HealthStatus(Address),
     CredentialPolicy(Address),
-    SecureCredential(Address),
+    SecureCredential(Address, Symbol),
+    CredentialLabels(Address),
+    #[cfg(feature = "routing")]
     AnchorMetadata(Address),
+    #[cfg(feature = "routing")]
     AnchorList,
     RateLimitConfig(Address),
-    LatestQuote(Address),
+    #[cfg(feature = "quotes")]
+    AnchorLatestQuote(Address),
+    CredentialBinding(Address, String),
+    CredentialBindingList(Address),
+    CredentialGrant(Address, Address, Symbol),
+    #[cfg(feature = "transfers")]
+    TransferInfo(Address, ServiceType, String),
+    #[cfg(feature = "transfers")]
+    TransferInfoAssets(Address, ServiceType),
+    #[cfg(feature = "transfers")]
+    DepositMemo(Address, DepositMemo),
+    DidBinding(Address),
+    DidSubject(BytesN<32>),
+    #[cfg(feature = "attestations")]
+    AttestationDid(u64),
+    #[cfg(feature = "transfers")]
+    Intent(u64),
+    #[cfg(feature = "transfers")]
+    IntentExecuted(u64),
+    #[cfg(feature = "transfers")]
+    HashLock(u64),
+    #[cfg(feature = "quotes")]
+    OracleFeed(Asset, Asset),
+    #[cfg(feature = "transfers")]
+    Transfer(u64),
+    #[cfg(feature = "compliance")]
+    KycRecord(Address),
+    #[cfg(feature = "compliance")]
+    KycThresholds(Address),
+    #[cfg(feature = "transfers")]
+    TravelRule(u64, TravelRuleRole),
+    #[cfg(feature = "transfers")]
+    TravelRuleThreshold,
+    #[cfg(feature = "compliance")]
+    Blocked(Address),
+    #[cfg(feature = "compliance")]
+    CorridorRules(Asset, Asset),
+    #[cfg(feature = "compliance")]
+    AttestationKind(u64),
+    #[cfg(feature = "compliance")]
+    SubjectAttestation(Address, Symbol),
+    #[cfg(feature = "compliance")]
+    AmlThreshold(Asset),
+    #[cfg(feature = "compliance")]
+    AmlFlag(u64),
+    #[cfg(feature = "compliance")]
+    AmlFlagCounter,
+    RoleMember(Role, Address),
+    #[cfg(feature = "compliance")]
+    KycVerifier(Address),
+    #[cfg(feature = "compliance")]
+    KycProof(Address),
+    #[cfg(feature = "compliance")]
+    ComplianceReport(Address, u64),
+    #[cfg(feature = "attestations")]
+    SubjectAttestations(Address),
+    #[cfg(feature = "compliance")]
+    ErasureRequest(Address),
+    #[cfg(feature = "compliance")]
+    Tombstone(TombstoneKind, Address, u64),
+    #[cfg(feature = "quotes")]
+    AnchorCorridors(Address),
+    #[cfg(feature = "quotes")]
+    LatestQuote(Address, Asset, Asset),
+    #[cfg(feature = "quotes")]
+    AssetDecimals(Asset),
+    EndpointDomains(Address),
+    OperationLimit(OperationClass),
+    #[cfg(feature = "transfers")]
+    TreasuryBalance(Address),
+    #[cfg(feature = "transfers")]
+    TreasuryTokens,
+    #[cfg(feature = "transfers")]
+    ReferralShare,
+    #[cfg(feature = "transfers")]
+    ReferralVolume(Address),
+    #[cfg(feature = "routing")]
+    UptimeReports(Address),
+    #[cfg(feature = "transfers")]
+    UsageMetrics(u64),
+    AuditMode,
+    SessionAuditMode(u64),
+    #[cfg(feature = "attestations")]
+    AttestationGrant(Address, Address),
+    CredentialGrantList(Address),
+    #[cfg(feature = "attestations")]
+    AttestationGrantList(Address),
+    #[cfg(feature = "quotes")]
+    CorridorCounter,
+    #[cfg(feature = "quotes")]
+    Corridor(u64),
+    #[cfg(feature = "transfers")]
+    IntentLock(u64),
+    Offboarding(Address),
+    #[cfg(feature = "transfers")]
+    AnchorActivity(Address),
+    #[cfg(feature = "transfers")]
+    AnchorBonds(Address),
+    AssetSupport(Address),
+    #[cfg(feature = "transfers")]
+    QuoteBinding(u64),
+    SettlementStats(Address),
+    #[cfg(feature = "transfers")]
+    RetryRecord(RetryTarget),
+    #[cfg(feature = "transfers")]
+    RetryTargets,
+    #[cfg(feature = "compliance")]
+    RequirementTemplateCounter,
+    #[cfg(feature = "compliance")]
+    RequirementTemplate(u64),
+    #[cfg(feature = "transfers")]
+    Exposure(Address, Address),
+    #[cfg(feature = "transfers")]
+    OpenLeg(u64),
+    #[cfg(feature = "transfers")]
+    SessionQuotePin(u64),
+    #[cfg(feature = "routing")]
+    RoutingReceiptCounter,
+    #[cfg(feature = "routing")]
+    RoutingReceipt(u64),
+    #[cfg(feature = "transfers")]
+    FeeRevenue(Address, u64),
+    #[cfg(feature = "attestations")]
+    ReplayScope,
+    #[cfg(feature = "attestations")]
+    IssuerHash(Address, BytesN<32>),
+    #[cfg(feature = "transfers")]
+    IntentTemplateCounter,
+    #[cfg(feature = "transfers")]
+    IntentTemplate(u64),
+    Freeze,
+    AttestorKey(Address),
+    #[cfg(feature = "transfers")]
+    IntentExecution(u64),
+    #[cfg(feature = "transfers")]
+    LatePenalty(u64),
+    #[cfg(feature = "attestations")]
+    AttestationRevoked(u64),
+    #[cfg(feature = "quotes")]
+    OrderBook(Asset, Asset),
+    Alias(Symbol),
+    AddressAlias(Address),
+    CorrelationCount(BytesN<16>),
+    CorrelationLog(BytesN<16>, u64),
+    Delegate(Address),
+    Delegates(Address),
+    ScheduledChange(Parameter),
+    SupersededBy(u64),
+    Supersedes(u64),
+    #[cfg(feature = "transfers")]
+    QuoteCapacity(Address, u64),
 }
 
 impl StorageKey {
@@ -47,23 +240,41 @@ impl StorageKey {
             StorageKey::Attestor(addr) => {
                 (soroban_sdk::symbol_short!("ATTESTOR"), addr).into_val(env)
             }
+            #[cfg(feature = "attestations")]
             StorageKey::Counter => (soroban_sdk::symbol_short!("COUNTER"),).into_val(env),
+            #[cfg(feature = "attestations")]
             StorageKey::Attestation(id) => {
                 (soroban_sdk::symbol_short!("ATTEST"), *id).into_val(env)
             }
+            #[cfg(feature = "attestations")]
             StorageKey::UsedHash(hash) => {
                 (soroban_sdk::symbol_short!("USED"), hash.clone()).into_val(env)
             }
-            StorageKey::Endpoint(addr) => {
-                (soroban_sdk::symbol_short!("ENDPOINT"), addr).into_val(env)
+            StorageKey::Endpoint(addr, kind) => {
+                (soroban_sdk::symbol_short!("ENDPOINT"), addr, *kind).into_val(env)
+            }
+            StorageKey::EndpointKinds(addr) => {
+                (soroban_sdk::symbol_short!("EPKINDS"), addr).into_val(env)
+            }
+            StorageKey::EndpointChallenge(addr, kind) => {
+                (soroban_sdk::symbol_short!("EPCHAL"), addr, *kind).into_val(env)
+            }
+            StorageKey::EndpointSigningKey(addr) => {
+                (soroban_sdk::symbol_short!("EPSIGNER"), addr).into_val(env)
+            }
+            StorageKey::TomlHash(addr) => {
+                (soroban_sdk::symbol_short!("TOMLHASH"), addr).into_val(env)
             }
             StorageKey::AnchorServices(addr) => {
                 (soroban_sdk::symbol_short!("SERVICES"), addr).into_val(env)
             }
+            #[cfg(feature = "quotes")]
             StorageKey::Quote(addr, id) => {
                 (soroban_sdk::symbol_short!("QUOTE"), addr, *id).into_val(env)
             }
+            #[cfg(feature = "quotes")]
             StorageKey::QuoteCounter => (soroban_sdk::symbol_short!("QCNT"),).into_val(env),
+            #[cfg(feature = "transfers")]
             StorageKey::IntentCounter => (soroban_sdk::symbol_short!("ICNT"),).into_val(env),
             StorageKey::SessionCounter => (soroban_sdk::symbol_short!("SCNT"),).into_val(env),
             StorageKey::Session(id) => (soroban_sdk::symbol_short!("SESS"), *id).into_val(env),
@@ -75,6 +286,12 @@ impl StorageKey {
             StorageKey::SessionOperationCount(id) => {
                 (soroban_sdk::symbol_short!("SOPCNT"), *id).into_val(env)
             }
+            StorageKey::SessionLog(id, index) => {
+                (soroban_sdk::symbol_short!("SESSLOG"), *id, *index).into_val(env)
+            }
+            StorageKey::SessionChain(id) => {
+                (soroban_sdk::symbol_short!("SCHAIN"), *id).into_val(env)
+            }
             StorageKey::ContractConfig => (soroban_sdk::symbol_short!("CONFIG"),).into_val(env),
             StorageKey::SessionConfig => (soroban_sdk::symbol_short!("SESSCFG"),).into_val(env),
             StorageKey::HealthStatus(addr) => {
@@ -83,19 +300,323 @@ impl StorageKey {
             StorageKey::CredentialPolicy(addr) => {
                 (soroban_sdk::symbol_short!("CREDPOL"), addr).into_val(env)
             }
-            StorageKey::SecureCredential(addr) => {
-                (soroban_sdk::symbol_short!("CREDENT"), addr).into_val(env)
+            StorageKey::SecureCredential(addr, label) => {
+                (soroban_sdk::symbol_short!("CREDENT"), addr, label.clone()).into_val(env)
+            }
+            StorageKey::CredentialLabels(addr) => {
+                (soroban_sdk::symbol_short!("CREDLBLS"), addr).into_val(env)
             }
+            #[cfg(feature = "routing")]
             StorageKey::AnchorMetadata(addr) => {
                 (soroban_sdk::symbol_short!("ANCHMETA"), addr).into_val(env)
             }
+            #[cfg(feature = "routing")]
             StorageKey::AnchorList => (soroban_sdk::symbol_short!("ANCHLIST"),).into_val(env),
             StorageKey::RateLimitConfig(addr) => {
                 (soroban_sdk::symbol_short!("RATELCFG"), addr).into_val(env)
             }
-            StorageKey::LatestQuote(addr) => {
+            #[cfg(feature = "quotes")]
+            StorageKey::AnchorLatestQuote(addr) => {
                 (soroban_sdk::symbol_short!("LATESTQ"), addr).into_val(env)
             }
+            StorageKey::CredentialBinding(addr, url) => {
+                (soroban_sdk::symbol_short!("CREDBIND"), addr, url.clone()).into_val(env)
+            }
+            StorageKey::CredentialBindingList(addr) => {
+                (soroban_sdk::symbol_short!("CREDBLST"), addr).into_val(env)
+            }
+            StorageKey::CredentialGrant(attestor, operator, label) => (
+                soroban_sdk::symbol_short!("CREDGRNT"),
+                attestor,
+                operator,
+                label.clone(),
+            )
+                .into_val(env),
+            #[cfg(feature = "transfers")]
+            StorageKey::TransferInfo(addr, op, asset) => {
+                (soroban_sdk::symbol_short!("XFERINFO"), addr, *op, asset.clone()).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::TransferInfoAssets(addr, op) => {
+                (soroban_sdk::symbol_short!("XFERASST"), addr, *op).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::DepositMemo(addr, memo) => {
+                (soroban_sdk::symbol_short!("DEPMEMO"), addr, memo.clone()).into_val(env)
+            }
+            StorageKey::DidBinding(subject) => {
+                (soroban_sdk::symbol_short!("DIDBIND"), subject).into_val(env)
+            }
+            StorageKey::DidSubject(did_hash) => {
+                (soroban_sdk::symbol_short!("DIDSUBJ"), did_hash.clone()).into_val(env)
+            }
+            #[cfg(feature = "attestations")]
+            StorageKey::AttestationDid(id) => {
+                (soroban_sdk::symbol_short!("ATTDID"), *id).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::Intent(id) => (soroban_sdk::symbol_short!("INTENT"), *id).into_val(env),
+            #[cfg(feature = "transfers")]
+            StorageKey::IntentExecuted(id) => {
+                (soroban_sdk::symbol_short!("INTEXEC"), *id).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::HashLock(id) => (soroban_sdk::symbol_short!("HTLC"), *id).into_val(env),
+            #[cfg(feature = "compliance")]
+            StorageKey::KycRecord(subject) => {
+                (soroban_sdk::symbol_short!("KYCLVL"), subject).into_val(env)
+            }
+            #[cfg(feature = "compliance")]
+            StorageKey::KycThresholds(anchor) => {
+                (soroban_sdk::symbol_short!("KYCRULE"), anchor).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::TravelRule(id, role) => {
+                (soroban_sdk::symbol_short!("TRAVEL"), *id, *role).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::TravelRuleThreshold => (soroban_sdk::symbol_short!("TRVLMIN"),).into_val(env),
+            #[cfg(feature = "compliance")]
+            StorageKey::Blocked(address) => {
+                (soroban_sdk::symbol_short!("BLOCKED"), address).into_val(env)
+            }
+            #[cfg(feature = "compliance")]
+            StorageKey::CorridorRules(base, quote) => {
+                (soroban_sdk::symbol_short!("CORRULE"), base.clone(), quote.clone()).into_val(env)
+            }
+            #[cfg(feature = "compliance")]
+            StorageKey::AttestationKind(id) => {
+                (soroban_sdk::symbol_short!("ATTKIND"), *id).into_val(env)
+            }
+            #[cfg(feature = "compliance")]
+            StorageKey::SubjectAttestation(subject, kind) => {
+                (soroban_sdk::symbol_short!("SUBKIND"), subject, kind.clone()).into_val(env)
+            }
+            #[cfg(feature = "compliance")]
+            StorageKey::AmlThreshold(asset) => {
+                (soroban_sdk::symbol_short!("AMLTHR"), asset.clone()).into_val(env)
+            }
+            #[cfg(feature = "compliance")]
+            StorageKey::AmlFlag(id) => (soroban_sdk::symbol_short!("AMLFLAG"), *id).into_val(env),
+            #[cfg(feature = "compliance")]
+            StorageKey::AmlFlagCounter => (soroban_sdk::symbol_short!("AMLCNT"),).into_val(env),
+            StorageKey::RoleMember(role, account) => {
+                (soroban_sdk::symbol_short!("ROLE"), *role, account).into_val(env)
+            }
+            #[cfg(feature = "compliance")]
+            StorageKey::KycVerifier(verifier) => {
+                (soroban_sdk::symbol_short!("KYCVRFR"), verifier).into_val(env)
+            }
+            #[cfg(feature = "compliance")]
+            StorageKey::KycProof(subject) => {
+                (soroban_sdk::symbol_short!("KYCPROOF"), subject).into_val(env)
+            }
+            #[cfg(feature = "compliance")]
+            StorageKey::ComplianceReport(anchor, period) => {
+                (soroban_sdk::symbol_short!("CREPORT"), anchor, *period).into_val(env)
+            }
+            #[cfg(feature = "attestations")]
+            StorageKey::SubjectAttestations(subject) => {
+                (soroban_sdk::symbol_short!("SUBATTS"), subject).into_val(env)
+            }
+            #[cfg(feature = "compliance")]
+            StorageKey::ErasureRequest(subject) => {
+                (soroban_sdk::symbol_short!("ERASEREQ"), subject).into_val(env)
+            }
+            #[cfg(feature = "compliance")]
+            StorageKey::Tombstone(kind, subject, id) => {
+                (soroban_sdk::symbol_short!("TOMB"), *kind, subject, *id).into_val(env)
+            }
+            #[cfg(feature = "quotes")]
+            StorageKey::AnchorCorridors(anchor) => {
+                (soroban_sdk::symbol_short!("ACORR"), anchor).into_val(env)
+            }
+            #[cfg(feature = "quotes")]
+            StorageKey::LatestQuote(anchor, base, quote) => {
+                (soroban_sdk::symbol_short!("CQUOTE"), anchor, base.clone(), quote.clone())
+                    .into_val(env)
+            }
+            #[cfg(feature = "quotes")]
+            StorageKey::AssetDecimals(asset) => {
+                (soroban_sdk::symbol_short!("ADEC"), asset.clone()).into_val(env)
+            }
+            StorageKey::EndpointDomains(attestor) => {
+                (soroban_sdk::symbol_short!("EPDOMAIN"), attestor).into_val(env)
+            }
+            StorageKey::OperationLimit(class) => {
+                (soroban_sdk::symbol_short!("OPLIMIT"), *class).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::TreasuryBalance(token) => {
+                (soroban_sdk::symbol_short!("TREASURY"), token).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::TreasuryTokens => (soroban_sdk::symbol_short!("TRSTOKNS"),).into_val(env),
+            #[cfg(feature = "transfers")]
+            StorageKey::ReferralShare => (soroban_sdk::symbol_short!("REFSHARE"),).into_val(env),
+            #[cfg(feature = "transfers")]
+            StorageKey::ReferralVolume(referrer) => {
+                (soroban_sdk::symbol_short!("REFVOL"), referrer).into_val(env)
+            }
+            #[cfg(feature = "routing")]
+            StorageKey::UptimeReports(anchor) => {
+                (soroban_sdk::symbol_short!("UPTIME"), anchor).into_val(env)
+            }
+            StorageKey::AuditMode => (soroban_sdk::symbol_short!("AUDMODE"),).into_val(env),
+            #[cfg(feature = "attestations")]
+            StorageKey::AttestationGrant(subject, grantee) => {
+                (soroban_sdk::symbol_short!("ATTGRANT"), subject, grantee).into_val(env)
+            }
+            StorageKey::CredentialGrantList(attestor) => {
+                (soroban_sdk::symbol_short!("CGRNTLST"), attestor).into_val(env)
+            }
+            #[cfg(feature = "attestations")]
+            StorageKey::AttestationGrantList(subject) => {
+                (soroban_sdk::symbol_short!("AGRNTLST"), subject).into_val(env)
+            }
+            StorageKey::SessionAuditMode(session_id) => {
+                (soroban_sdk::symbol_short!("SAUDMODE"), *session_id).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::IntentLock(id) => (soroban_sdk::symbol_short!("ILOCK"), *id).into_val(env),
+            StorageKey::Offboarding(anchor) => {
+                (soroban_sdk::symbol_short!("OFFBOARD"), anchor).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::AnchorActivity(anchor) => {
+                (soroban_sdk::symbol_short!("ACTIVITY"), anchor).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::AnchorBonds(anchor) => {
+                (soroban_sdk::symbol_short!("BONDS"), anchor).into_val(env)
+            }
+            StorageKey::AssetSupport(anchor) => {
+                (soroban_sdk::symbol_short!("ASSETSUP"), anchor).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::QuoteBinding(intent_id) => {
+                (soroban_sdk::symbol_short!("QBIND"), *intent_id).into_val(env)
+            }
+            StorageKey::SettlementStats(anchor) => {
+                (soroban_sdk::symbol_short!("SETLSTAT"), anchor).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::RetryRecord(target) => {
+                (soroban_sdk::symbol_short!("RETRY"), target.clone()).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::RetryTargets => (soroban_sdk::symbol_short!("RETRYLST"),).into_val(env),
+            #[cfg(feature = "compliance")]
+            StorageKey::RequirementTemplateCounter => {
+                (soroban_sdk::symbol_short!("REQTCNT"),).into_val(env)
+            }
+            #[cfg(feature = "compliance")]
+            StorageKey::RequirementTemplate(id) => {
+                (soroban_sdk::symbol_short!("REQTMPL"), *id).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::Exposure(anchor_a, anchor_b) => {
+                (soroban_sdk::symbol_short!("EXPOSURE"), anchor_a, anchor_b).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::OpenLeg(transfer_id) => {
+                (soroban_sdk::symbol_short!("OPENLEG"), *transfer_id).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::SessionQuotePin(session_id) => {
+                (soroban_sdk::symbol_short!("SESSPIN"), *session_id).into_val(env)
+            }
+            #[cfg(feature = "routing")]
+            StorageKey::RoutingReceiptCounter => {
+                (soroban_sdk::symbol_short!("RCPTCNT"),).into_val(env)
+            }
+            #[cfg(feature = "routing")]
+            StorageKey::RoutingReceipt(id) => {
+                (soroban_sdk::symbol_short!("RCPT"), *id).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::FeeRevenue(anchor, period) => {
+                (soroban_sdk::symbol_short!("FEEREV"), anchor, *period).into_val(env)
+            }
+            #[cfg(feature = "attestations")]
+            StorageKey::ReplayScope => (soroban_sdk::symbol_short!("REPLAY"),).into_val(env),
+            #[cfg(feature = "attestations")]
+            StorageKey::IssuerHash(issuer, hash) => {
+                (soroban_sdk::symbol_short!("USEDBY"), issuer, hash.clone()).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::IntentTemplateCounter => {
+                (soroban_sdk::symbol_short!("ITPLCNT"),).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::IntentTemplate(id) => {
+                (soroban_sdk::symbol_short!("ITPL"), *id).into_val(env)
+            }
+            StorageKey::Freeze => (soroban_sdk::symbol_short!("FREEZE"),).into_val(env),
+            StorageKey::AttestorKey(addr) => {
+                (soroban_sdk::symbol_short!("ATTKEY"), addr).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::IntentExecution(id) => {
+                (soroban_sdk::symbol_short!("INTEXEC"), *id).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::LatePenalty(id) => {
+                (soroban_sdk::symbol_short!("LATEPEN"), *id).into_val(env)
+            }
+            #[cfg(feature = "attestations")]
+            StorageKey::AttestationRevoked(id) => {
+                (soroban_sdk::symbol_short!("ATTREVOK"), *id).into_val(env)
+            }
+            #[cfg(feature = "quotes")]
+            StorageKey::OrderBook(base, quote) => {
+                (soroban_sdk::symbol_short!("BOOK"), base.clone(), quote.clone()).into_val(env)
+            }
+            StorageKey::Alias(alias) => {
+                (soroban_sdk::symbol_short!("ALIAS"), alias.clone()).into_val(env)
+            }
+            StorageKey::AddressAlias(address) => {
+                (soroban_sdk::symbol_short!("ADDRALIAS"), address).into_val(env)
+            }
+            StorageKey::CorrelationCount(id) => {
+                (soroban_sdk::symbol_short!("CORRELCNT"), id.clone()).into_val(env)
+            }
+            StorageKey::CorrelationLog(id, index) => {
+                (soroban_sdk::symbol_short!("CORRELLOG"), id.clone(), *index).into_val(env)
+            }
+            StorageKey::Delegate(delegate) => {
+                (soroban_sdk::symbol_short!("DELEGATE"), delegate).into_val(env)
+            }
+            StorageKey::Delegates(attestor) => {
+                (soroban_sdk::symbol_short!("DELEGATES"), attestor).into_val(env)
+            }
+            StorageKey::ScheduledChange(parameter) => {
+                (soroban_sdk::symbol_short!("PARAMSCHD"), *parameter).into_val(env)
+            }
+            StorageKey::SupersededBy(id) => {
+                (soroban_sdk::symbol_short!("SUPERSDBY"), *id).into_val(env)
+            }
+            StorageKey::Supersedes(id) => {
+                (soroban_sdk::symbol_short!("SUPERSEDS"), *id).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::QuoteCapacity(anchor, quote_id) => {
+                (soroban_sdk::symbol_short!("QCAPACITY"), anchor, *quote_id).into_val(env)
+            }
+            #[cfg(feature = "quotes")]
+            StorageKey::CorridorCounter => (soroban_sdk::symbol_short!("CORRCNT"),).into_val(env),
+            #[cfg(feature = "quotes")]
+            StorageKey::Corridor(id) => (soroban_sdk::symbol_short!("CORRIDOR"), *id).into_val(env),
+            #[cfg(feature = "transfers")]
+            StorageKey::UsageMetrics(period) => {
+                (soroban_sdk::symbol_short!("METRICS"), *period).into_val(env)
+            }
+            #[cfg(feature = "transfers")]
+            StorageKey::Transfer(id) => (soroban_sdk::symbol_short!("XFER"), *id).into_val(env),
+            #[cfg(feature = "quotes")]
+            StorageKey::OracleFeed(base, quote) => {
+                (soroban_sdk::symbol_short!("ORACLE"), base.clone(), quote.clone()).into_val(env)
+            }
         }
     }
 }
@@ -103,9 +624,58 @@ impl StorageKey {
 pub struct Storage;
 
 impl Storage {
+    /// Layout version of persisted records. Bump whenever a key or stored type
+    /// changes incompatibly so clients and migrations can tell deployments apart.
+    pub const SCHEMA_VERSION: u32 = 1;
+
     const DAY_IN_LEDGERS: u32 = 17280;
     const INSTANCE_LIFETIME: u32 = Self::DAY_IN_LEDGERS * 30;
     const PERSISTENT_LIFETIME: u32 = Self::DAY_IN_LEDGERS * 90;
+    /// An execution finishes or rolls back within its transaction, so its
+    /// lock only has to outlive that (about a minute).
+    #[cfg(feature = "transfers")]
+    const INTENT_LOCK_LIFETIME: u32 = 12;
+    /// Least a touched entry is kept for; see `touch`.
+    const TOUCH_LIFETIME: u32 = Self::DAY_IN_LEDGERS * 30;
+    const LEDGER_SECONDS: u64 = 5;
+
+    /// Keep an entry that was just used alive for twice the entity's remaining
+    /// lifetime, at least `TOUCH_LIFETIME` and at most the usual persistent
+    /// lifetime. Writes already reset the TTL; this covers entries that are
+    /// only read mid-flow, while entries nobody touches still age out.
+    fn touch(env: &Env, key: &soroban_sdk::Val, remaining_seconds: u64) {
+        let remaining = (remaining_seconds / Self::LEDGER_SECONDS).min(u32::MAX as u64) as u32;
+        let extend_to = remaining
+            .saturating_mul(2)
+            .clamp(Self::TOUCH_LIFETIME, Self::PERSISTENT_LIFETIME);
+        if env.storage().persistent().has(key) {
+            env.storage().persistent().extend_ttl(key, extend_to, extend_to);
+        }
+    }
+
+    /// Extend a quote's TTL when it is consumed, in proportion to how long it
+    /// stays valid.
+    #[cfg(feature = "quotes")]
+    pub fn touch_quote(env: &Env, quote: &QuoteData) {
+        let key = StorageKey::Quote(quote.anchor.clone(), quote.quote_id).to_storage_key(env);
+        let remaining = quote.valid_until.saturating_sub(env.ledger().timestamp());
+        Self::touch(env, &key, remaining);
+    }
+
+    /// Extend a session's TTL when an operation is logged to it, in proportion
+    /// to the configured session timeout.
+    pub fn touch_session(env: &Env, session_id: u64) {
+        let key = StorageKey::Session(session_id).to_storage_key(env);
+        let remaining = Self::get_session_config(env).map_or(0, |config| config.timeout_seconds);
+        Self::touch(env, &key, remaining);
+    }
+
+    /// Extend a transfer's TTL when it changes state.
+    #[cfg(feature = "transfers")]
+    pub fn touch_transfer(env: &Env, transfer_id: u64) {
+        let key = StorageKey::Transfer(transfer_id).to_storage_key(env);
+        Self::touch(env, &key, 0);
+    }
 
     pub fn has_admin(env: &Env) -> bool {
         let key = StorageKey::Admin.to_storage_key(env);
@@ -138,11 +708,36 @@ impl Storage {
         );
     }
 
+    /// Ed25519 public key attestation signatures from `attestor` are checked against.
+    pub fn set_attestor_key(env: &Env, attestor: &Address, public_key: &BytesN<32>) {
+        let key = StorageKey::AttestorKey(attestor.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, public_key);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_attestor_key(env: &Env, attestor: &Address) -> Option<BytesN<32>> {
+        let key = StorageKey::AttestorKey(attestor.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
     pub fn is_attestor(env: &Env, attestor: &Address) -> bool {
         let key = StorageKey::Attestor(attestor.clone()).to_storage_key(env);
         env.storage().persistent().get(&key).unwrap_or(false)
     }
 
+    /// Number of IDs handed out by `get_and_increment_counter`, i.e. one past
+    /// the latest attestation ID.
+    #[cfg(feature = "attestations")]
+    pub fn get_counter(env: &Env) -> u64 {
+        let key = StorageKey::Counter.to_storage_key(env);
+        env.storage().instance().get(&key).unwrap_or(0)
+    }
+
+    #[cfg(feature = "attestations")]
     pub fn get_and_increment_counter(env: &Env) -> u64 {
         let key = StorageKey::Counter.to_storage_key(env);
         let counter: u64 = env.storage().instance().get(&key).unwrap_or(0);
@@ -153,8 +748,12 @@ impl Storage {
         counter
     }
 
+    #[cfg(feature = "attestations")]
     pub fn set_attestation(env: &Env, id: u64, attestation: &Attestation) {
         let key = StorageKey::Attestation(id).to_storage_key(env);
+        if !env.storage().persistent().has(&key) {
+            Self::add_subject_attestation_id(env, &attestation.subject, id);
+        }
         env.storage().persistent().set(&key, attestation);
         env.storage().persistent().extend_ttl(
             &key,
@@ -163,6 +762,7 @@ impl Storage {
         );
     }
 
+    #[cfg(feature = "attestations")]
     pub fn get_attestation(env: &Env, id: u64) -> Result<Attestation, Error> {
         let key = StorageKey::Attestation(id).to_storage_key(env);
         env.storage()
@@ -171,6 +771,7 @@ impl Storage {
             .ok_or(Error::AttestationNotFound)
     }
 
+    #[cfg(feature = "attestations")]
     pub fn mark_hash_used(env: &Env, hash: &BytesN<32>) {
         let key = StorageKey::UsedHash(hash.clone()).to_storage_key(env);
         env.storage().persistent().set(&key, &true);
@@ -181,37 +782,130 @@ impl Storage {
         );
     }
 
+    #[cfg(feature = "attestations")]
     pub fn is_hash_used(env: &Env, hash: &BytesN<32>) -> bool {
         let key = StorageKey::UsedHash(hash.clone()).to_storage_key(env);
         env.storage().persistent().get(&key).unwrap_or(false)
     }
 
+    #[cfg(feature = "attestations")]
+    pub fn mark_issuer_hash_used(env: &Env, issuer: &Address, hash: &BytesN<32>) {
+        let key = StorageKey::IssuerHash(issuer.clone(), hash.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "attestations")]
+    pub fn is_issuer_hash_used(env: &Env, issuer: &Address, hash: &BytesN<32>) -> bool {
+        let key = StorageKey::IssuerHash(issuer.clone(), hash.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key).unwrap_or(false)
+    }
+
+    #[cfg(feature = "attestations")]
+    pub fn set_replay_scope(env: &Env, scope: ReplayScope) {
+        let key = StorageKey::ReplayScope.to_storage_key(env);
+        env.storage().instance().set(&key, &scope);
+        env.storage()
+            .instance()
+            .extend_ttl(Self::INSTANCE_LIFETIME, Self::INSTANCE_LIFETIME);
+    }
+
+    #[cfg(feature = "attestations")]
+    pub fn get_replay_scope(env: &Env) -> ReplayScope {
+        let key = StorageKey::ReplayScope.to_storage_key(env);
+        env.storage()
+            .instance()
+            .get(&key)
+            .unwrap_or(ReplayScope::Global)
+    }
+
     pub fn set_endpoint(env: &Env, endpoint: &Endpoint) {
-        let key = StorageKey::Endpoint(endpoint.attestor.clone()).to_storage_key(env);
+        let key = StorageKey::Endpoint(endpoint.attestor.clone(), endpoint.kind).to_storage_key(env);
         env.storage().persistent().set(&key, endpoint);
         env.storage().persistent().extend_ttl(
             &key,
             Self::PERSISTENT_LIFETIME,
             Self::PERSISTENT_LIFETIME,
         );
+
+        let list_key = StorageKey::EndpointKinds(endpoint.attestor.clone()).to_storage_key(env);
+        let mut kinds = Self::get_endpoint_kinds(env, &endpoint.attestor);
+        if !kinds.contains(endpoint.kind) {
+            kinds.push_back(endpoint.kind);
+            env.storage().persistent().set(&list_key, &kinds);
+        }
+        env.storage().persistent().extend_ttl(
+            &list_key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
     }
 
-    pub fn get_endpoint(env: &Env, attestor: &Address) -> Result<Endpoint, Error> {
-        let key = StorageKey::Endpoint(attestor.clone()).to_storage_key(env);
+    pub fn get_endpoint(
+        env: &Env,
+        attestor: &Address,
+        kind: EndpointKind,
+    ) -> Result<Endpoint, Error> {
+        let key = StorageKey::Endpoint(attestor.clone(), kind).to_storage_key(env);
         env.storage()
             .persistent()
             .get(&key)
             .ok_or(Error::EndpointNotFound)
     }
 
-    pub fn has_endpoint(env: &Env, attestor: &Address) -> bool {
-        let key = StorageKey::Endpoint(attestor.clone()).to_storage_key(env);
+    pub fn has_endpoint(env: &Env, attestor: &Address, kind: EndpointKind) -> bool {
+        let key = StorageKey::Endpoint(attestor.clone(), kind).to_storage_key(env);
         env.storage().persistent().has(&key)
     }
 
-    pub fn remove_endpoint(env: &Env, attestor: &Address) {
-        let key = StorageKey::Endpoint(attestor.clone()).to_storage_key(env);
+    pub fn remove_endpoint(env: &Env, attestor: &Address, kind: EndpointKind) {
+        let key = StorageKey::Endpoint(attestor.clone(), kind).to_storage_key(env);
         env.storage().persistent().remove(&key);
+
+        let list_key = StorageKey::EndpointKinds(attestor.clone()).to_storage_key(env);
+        let mut kinds = Self::get_endpoint_kinds(env, attestor);
+        if let Some(index) = kinds.first_index_of(kind) {
+            kinds.remove(index);
+            env.storage().persistent().set(&list_key, &kinds);
+        }
+    }
+
+    pub fn get_endpoint_kinds(env: &Env, attestor: &Address) -> Vec<EndpointKind> {
+        let key = StorageKey::EndpointKinds(attestor.clone()).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// All endpoints of an attestor, in the order their kinds were first configured.
+    pub fn get_endpoints(env: &Env, attestor: &Address) -> Vec<Endpoint> {
+        let mut endpoints = Vec::new(env);
+        for kind in Self::get_endpoint_kinds(env, attestor).iter() {
+            if let Ok(endpoint) = Self::get_endpoint(env, attestor, kind) {
+                endpoints.push_back(endpoint);
+            }
+        }
+        endpoints
+    }
+
+    pub fn set_toml_hash(env: &Env, attestor: &Address, toml_hash: &TomlHash) {
+        let key = StorageKey::TomlHash(attestor.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, toml_hash);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_toml_hash(env: &Env, attestor: &Address) -> Option<TomlHash> {
+        let key = StorageKey::TomlHash(attestor.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
     }
 
     pub fn set_anchor_services(env: &Env, services: &AnchorServices) {
@@ -232,6 +926,25 @@ impl Storage {
             .ok_or(Error::ServicesNotConfigured)
     }
 
+    pub fn set_asset_support(env: &Env, anchor: &Address, support: &Vec<AssetSupport>) {
+        let key = StorageKey::AssetSupport(anchor.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, support);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_asset_support(env: &Env, anchor: &Address) -> Vec<AssetSupport> {
+        let key = StorageKey::AssetSupport(anchor.clone()).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    #[cfg(feature = "quotes")]
     pub fn set_quote(env: &Env, quote: &QuoteData) {
         let key = StorageKey::Quote(quote.anchor.clone(), quote.quote_id).to_storage_key(env);
         env.storage().persistent().set(&key, quote);
@@ -242,11 +955,19 @@ impl Storage {
         );
     }
 
+    #[cfg(feature = "quotes")]
     pub fn get_quote(env: &Env, anchor: &Address, quote_id: u64) -> Option<QuoteData> {
         let key = StorageKey::Quote(anchor.clone(), quote_id).to_storage_key(env);
         env.storage().persistent().get(&key)
     }
 
+    #[cfg(feature = "quotes")]
+    pub fn get_quote_count(env: &Env) -> u64 {
+        let key = StorageKey::QuoteCounter.to_storage_key(env);
+        env.storage().instance().get(&key).unwrap_or(0)
+    }
+
+    #[cfg(feature = "quotes")]
     pub fn get_next_quote_id(env: &Env) -> u64 {
         let key = StorageKey::QuoteCounter.to_storage_key(env);
         let current: u64 = env.storage().instance().get(&key).unwrap_or(0);
@@ -258,6 +979,7 @@ impl Storage {
         next
     }
 
+    #[cfg(feature = "transfers")]
     pub fn get_next_intent_id(env: &Env) -> u64 {
         let key = StorageKey::IntentCounter.to_storage_key(env);
         let current: u64 = env.storage().instance().get(&key).unwrap_or(0);
@@ -339,6 +1061,12 @@ impl Storage {
         Ok(())
     }
 
+    /// Number of sessions created; session IDs run from 0 to this, exclusive.
+    pub fn get_session_count(env: &Env) -> u64 {
+        let key = StorageKey::SessionCounter.to_storage_key(env);
+        env.storage().instance().get(&key).unwrap_or(0)
+    }
+
     fn get_and_increment_session_counter(env: &Env) -> u64 {
         let key = StorageKey::SessionCounter.to_storage_key(env);
         let counter: u64 = env.storage().instance().get(&key).unwrap_or(0);
@@ -349,30 +1077,123 @@ impl Storage {
         counter
     }
 
+    /// Record an operation's audit log and index it under its session.
     pub fn log_operation(
         env: &Env,
         session_id: u64,
         actor: &Address,
         operation: &OperationContext,
-    ) -> u64 {
-        let log_id = Self::get_and_increment_audit_counter(env);
+    ) -> AuditLog {
+        let audit_log = Self::new_audit_log(env, session_id, actor, operation);
+        let log_id = audit_log.log_id;
+
+        let key = StorageKey::AuditLog(log_id).to_storage_key(env);
+        env.storage().persistent().set(&key, &audit_log);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+
+        let index_key =
+            StorageKey::SessionLog(session_id, operation.operation_index).to_storage_key(env);
+        env.storage().persistent().set(&index_key, &log_id);
+        env.storage().persistent().extend_ttl(
+            &index_key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+
+        if operation.is_correlated() {
+            Self::index_correlated_log(env, &operation.correlation_id, log_id);
+        }
+
+        audit_log
+    }
+
+    /// Append `log_id` to the logs recorded under `correlation_id`.
+    fn index_correlated_log(env: &Env, correlation_id: &BytesN<16>, log_id: u64) {
+        let index = Self::get_correlation_count(env, correlation_id);
+
+        let key = StorageKey::CorrelationLog(correlation_id.clone(), index).to_storage_key(env);
+        env.storage().persistent().set(&key, &log_id);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+
+        let count_key = StorageKey::CorrelationCount(correlation_id.clone()).to_storage_key(env);
+        env.storage().persistent().set(&count_key, &(index + 1));
+        env.storage().persistent().extend_ttl(
+            &count_key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    /// Number of audit logs recorded under `correlation_id`.
+    pub fn get_correlation_count(env: &Env, correlation_id: &BytesN<16>) -> u64 {
+        let key = StorageKey::CorrelationCount(correlation_id.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    pub fn get_correlated_log_id(
+        env: &Env,
+        correlation_id: &BytesN<16>,
+        index: u64,
+    ) -> Result<u64, Error> {
+        let key = StorageKey::CorrelationLog(correlation_id.clone(), index).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::NotFound)
+    }
 
-        let audit_log = AuditLog {
-            log_id,
+    /// Allocate the next audit log ID for `operation` without persisting the log.
+    pub fn new_audit_log(
+        env: &Env,
+        session_id: u64,
+        actor: &Address,
+        operation: &OperationContext,
+    ) -> AuditLog {
+        AuditLog {
+            log_id: Self::get_and_increment_audit_counter(env),
             session_id,
             operation: operation.clone(),
             actor: actor.clone(),
-        };
+        }
+    }
 
-        let key = StorageKey::AuditLog(log_id).to_storage_key(env);
-        env.storage().persistent().set(&key, &audit_log);
+    pub fn get_session_log_id(
+        env: &Env,
+        session_id: u64,
+        operation_index: u64,
+    ) -> Result<u64, Error> {
+        let key = StorageKey::SessionLog(session_id, operation_index).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::SessionNotFound)
+    }
+
+    /// Head of the session's audit hash chain; 32 zero bytes before the first operation.
+    pub fn get_session_chain(env: &Env, session_id: u64) -> BytesN<32> {
+        let key = StorageKey::SessionChain(session_id).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(BytesN::from_array(env, &[0u8; 32]))
+    }
+
+    pub fn set_session_chain(env: &Env, session_id: u64, head: &BytesN<32>) {
+        let key = StorageKey::SessionChain(session_id).to_storage_key(env);
+        env.storage().persistent().set(&key, head);
         env.storage().persistent().extend_ttl(
             &key,
             Self::PERSISTENT_LIFETIME,
             Self::PERSISTENT_LIFETIME,
         );
-
-        log_id
     }
 
     pub fn get_audit_log(env: &Env, log_id: u64) -> Result<AuditLog, Error> {
@@ -455,26 +1276,59 @@ impl Storage {
         env.storage().persistent().get(&key)
     }
 
-    pub fn set_secure_credential(env: &Env, credential: &SecureCredential) {
-        let key = StorageKey::SecureCredential(credential.attestor.clone()).to_storage_key(env);
+    pub fn set_secure_credential(env: &Env, label: &Symbol, credential: &SecureCredential) {
+        let key = StorageKey::SecureCredential(credential.attestor.clone(), label.clone())
+            .to_storage_key(env);
         env.storage().persistent().set(&key, credential);
         env.storage().persistent().extend_ttl(
             &key,
             Self::PERSISTENT_LIFETIME,
             Self::PERSISTENT_LIFETIME,
         );
+
+        let labels_key = StorageKey::CredentialLabels(credential.attestor.clone()).to_storage_key(env);
+        let mut labels = Self::get_credential_labels(env, &credential.attestor);
+        if !labels.contains(label) {
+            labels.push_back(label.clone());
+            env.storage().persistent().set(&labels_key, &labels);
+        }
+        env.storage().persistent().extend_ttl(
+            &labels_key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
     }
 
-    pub fn get_secure_credential(env: &Env, attestor: &Address) -> Option<SecureCredential> {
-        let key = StorageKey::SecureCredential(attestor.clone()).to_storage_key(env);
+    pub fn get_secure_credential(
+        env: &Env,
+        attestor: &Address,
+        label: &Symbol,
+    ) -> Option<SecureCredential> {
+        let key = StorageKey::SecureCredential(attestor.clone(), label.clone()).to_storage_key(env);
         env.storage().persistent().get(&key)
     }
 
-    pub fn remove_secure_credential(env: &Env, attestor: &Address) {
-        let key = StorageKey::SecureCredential(attestor.clone()).to_storage_key(env);
+    pub fn remove_secure_credential(env: &Env, attestor: &Address, label: &Symbol) {
+        let key = StorageKey::SecureCredential(attestor.clone(), label.clone()).to_storage_key(env);
         env.storage().persistent().remove(&key);
+
+        let mut labels = Self::get_credential_labels(env, attestor);
+        if let Some(index) = labels.first_index_of(label) {
+            labels.remove(index);
+            let labels_key = StorageKey::CredentialLabels(attestor.clone()).to_storage_key(env);
+            env.storage().persistent().set(&labels_key, &labels);
+        }
+    }
+
+    pub fn get_credential_labels(env: &Env, attestor: &Address) -> Vec<Symbol> {
+        let key = StorageKey::CredentialLabels(attestor.clone()).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
     }
 
+    #[cfg(feature = "routing")]
     pub fn set_anchor_metadata(env: &Env, metadata: &AnchorMetadata) {
         let key = StorageKey::AnchorMetadata(metadata.anchor.clone()).to_storage_key(env);
         env.storage().persistent().set(&key, metadata);
@@ -485,11 +1339,13 @@ impl Storage {
         );
     }
 
+    #[cfg(feature = "routing")]
     pub fn get_anchor_metadata(env: &Env, anchor: &Address) -> Option<AnchorMetadata> {
         let key = StorageKey::AnchorMetadata(anchor.clone()).to_storage_key(env);
         env.storage().persistent().get(&key)
     }
 
+    #[cfg(feature = "routing")]
     pub fn add_to_anchor_list(env: &Env, anchor: &Address) {
         let key = StorageKey::AnchorList.to_storage_key(env);
         let mut list: Vec<Address> = env
@@ -509,6 +1365,7 @@ impl Storage {
         }
     }
 
+    #[cfg(feature = "routing")]
     pub fn get_anchor_list(env: &Env) -> Vec<Address> {
         let key = StorageKey::AnchorList.to_storage_key(env);
         env.storage()
@@ -532,8 +1389,9 @@ impl Storage {
         env.storage().persistent().get(&key)
     }
 
-    pub fn set_latest_quote(env: &Env, anchor: &Address, quote_id: u64) {
-        let key = StorageKey::LatestQuote(anchor.clone()).to_storage_key(env);
+    #[cfg(feature = "quotes")]
+    pub fn set_anchor_latest_quote(env: &Env, anchor: &Address, quote_id: u64) {
+        let key = StorageKey::AnchorLatestQuote(anchor.clone()).to_storage_key(env);
         env.storage().persistent().set(&key, &quote_id);
         env.storage().persistent().extend_ttl(
             &key,
@@ -542,8 +1400,1733 @@ impl Storage {
         );
     }
 
-    pub fn get_latest_quote(env: &Env, anchor: &Address) -> Option<u64> {
-        let key = StorageKey::LatestQuote(anchor.clone()).to_storage_key(env);
+    #[cfg(feature = "quotes")]
+    pub fn get_anchor_latest_quote(env: &Env, anchor: &Address) -> Option<u64> {
+        let key = StorageKey::AnchorLatestQuote(anchor.clone()).to_storage_key(env);
         env.storage().persistent().get(&key)
     }
+
+    pub fn set_credential_binding(env: &Env, binding: &CredentialBinding) {
+        let key = StorageKey::CredentialBinding(
+            binding.attestor.clone(),
+            binding.endpoint_url.clone(),
+        )
+        .to_storage_key(env);
+        env.storage().persistent().set(&key, binding);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+
+        let list_key = StorageKey::CredentialBindingList(binding.attestor.clone()).to_storage_key(env);
+        let mut urls: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&list_key)
+            .unwrap_or(Vec::new(env));
+
+        if !urls.contains(&binding.endpoint_url) {
+            urls.push_back(binding.endpoint_url.clone());
+            env.storage().persistent().set(&list_key, &urls);
+        }
+        env.storage().persistent().extend_ttl(
+            &list_key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_credential_binding(
+        env: &Env,
+        attestor: &Address,
+        endpoint_url: &String,
+    ) -> Option<CredentialBinding> {
+        let key = StorageKey::CredentialBinding(attestor.clone(), endpoint_url.clone())
+            .to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn remove_credential_binding(env: &Env, attestor: &Address, endpoint_url: &String) {
+        let key = StorageKey::CredentialBinding(attestor.clone(), endpoint_url.clone())
+            .to_storage_key(env);
+        env.storage().persistent().remove(&key);
+
+        let list_key = StorageKey::CredentialBindingList(attestor.clone()).to_storage_key(env);
+        let mut urls: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&list_key)
+            .unwrap_or(Vec::new(env));
+
+        if let Some(index) = urls.first_index_of(endpoint_url) {
+            urls.remove(index);
+            env.storage().persistent().set(&list_key, &urls);
+        }
+    }
+
+    pub fn get_credential_binding_urls(env: &Env, attestor: &Address) -> Vec<String> {
+        let key = StorageKey::CredentialBindingList(attestor.clone()).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    pub fn set_credential_grant(env: &Env, grant: &CredentialGrant) {
+        let key = StorageKey::CredentialGrant(
+            grant.attestor.clone(),
+            grant.operator.clone(),
+            grant.credential_label.clone(),
+        )
+        .to_storage_key(env);
+        env.storage().persistent().set(&key, grant);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+
+        let entry = (grant.operator.clone(), grant.credential_label.clone());
+        let mut grants = Self::get_credential_grant_list(env, &grant.attestor);
+        if !grants.contains(&entry) {
+            grants.push_back(entry);
+            Self::set_credential_grant_list(env, &grant.attestor, &grants);
+        }
+    }
+
+    /// `(operator, label)` of every grant an attestor has issued and not removed.
+    pub fn get_credential_grant_list(env: &Env, attestor: &Address) -> Vec<(Address, Symbol)> {
+        let key = StorageKey::CredentialGrantList(attestor.clone()).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn set_credential_grant_list(env: &Env, attestor: &Address, grants: &Vec<(Address, Symbol)>) {
+        let key = StorageKey::CredentialGrantList(attestor.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, grants);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_credential_grant(
+        env: &Env,
+        attestor: &Address,
+        operator: &Address,
+        label: &Symbol,
+    ) -> Option<CredentialGrant> {
+        let key = StorageKey::CredentialGrant(attestor.clone(), operator.clone(), label.clone())
+            .to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn remove_credential_grant(env: &Env, attestor: &Address, operator: &Address, label: &Symbol) {
+        let key = StorageKey::CredentialGrant(attestor.clone(), operator.clone(), label.clone())
+            .to_storage_key(env);
+        env.storage().persistent().remove(&key);
+
+        let mut grants = Self::get_credential_grant_list(env, attestor);
+        if let Some(index) = grants.first_index_of((operator.clone(), label.clone())) {
+            grants.remove(index);
+            Self::set_credential_grant_list(env, attestor, &grants);
+        }
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_transfer_info(env: &Env, info: &TransferInfo) {
+        let key = StorageKey::TransferInfo(
+            info.anchor.clone(),
+            info.operation_type,
+            info.asset_code.clone(),
+        )
+        .to_storage_key(env);
+        env.storage().persistent().set(&key, info);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+
+        let assets_key =
+            StorageKey::TransferInfoAssets(info.anchor.clone(), info.operation_type).to_storage_key(env);
+        let mut assets = Self::get_transfer_info_assets(env, &info.anchor, info.operation_type);
+        if !assets.contains(&info.asset_code) {
+            assets.push_back(info.asset_code.clone());
+            env.storage().persistent().set(&assets_key, &assets);
+        }
+        env.storage().persistent().extend_ttl(
+            &assets_key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_transfer_info(
+        env: &Env,
+        anchor: &Address,
+        operation_type: ServiceType,
+        asset_code: &String,
+    ) -> Option<TransferInfo> {
+        let key = StorageKey::TransferInfo(anchor.clone(), operation_type, asset_code.clone())
+            .to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn remove_transfer_info(
+        env: &Env,
+        anchor: &Address,
+        operation_type: ServiceType,
+        asset_code: &String,
+    ) {
+        let key = StorageKey::TransferInfo(anchor.clone(), operation_type, asset_code.clone())
+            .to_storage_key(env);
+        env.storage().persistent().remove(&key);
+
+        let mut assets = Self::get_transfer_info_assets(env, anchor, operation_type);
+        if let Some(index) = assets.first_index_of(asset_code) {
+            assets.remove(index);
+            let assets_key =
+                StorageKey::TransferInfoAssets(anchor.clone(), operation_type).to_storage_key(env);
+            env.storage().persistent().set(&assets_key, &assets);
+        }
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_transfer_info_assets(
+        env: &Env,
+        anchor: &Address,
+        operation_type: ServiceType,
+    ) -> Vec<String> {
+        let key = StorageKey::TransferInfoAssets(anchor.clone(), operation_type).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_deposit_memo(env: &Env, record: &DepositMemoRecord) {
+        let key = StorageKey::DepositMemo(record.anchor.clone(), record.memo.clone())
+            .to_storage_key(env);
+        env.storage().persistent().set(&key, record);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_deposit_memo(
+        env: &Env,
+        anchor: &Address,
+        memo: &DepositMemo,
+    ) -> Option<DepositMemoRecord> {
+        let key = StorageKey::DepositMemo(anchor.clone(), memo.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn set_did_binding(env: &Env, binding: &DidBinding) {
+        let key = StorageKey::DidBinding(binding.subject.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, binding);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+
+        let reverse_key = StorageKey::DidSubject(binding.did_hash.clone()).to_storage_key(env);
+        env.storage().persistent().set(&reverse_key, &binding.subject);
+        env.storage().persistent().extend_ttl(
+            &reverse_key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_did_binding(env: &Env, subject: &Address) -> Option<DidBinding> {
+        let key = StorageKey::DidBinding(subject.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn get_did_subject(env: &Env, did_hash: &BytesN<32>) -> Option<Address> {
+        let key = StorageKey::DidSubject(did_hash.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn remove_did_binding(env: &Env, binding: &DidBinding) {
+        let key = StorageKey::DidBinding(binding.subject.clone()).to_storage_key(env);
+        env.storage().persistent().remove(&key);
+        let reverse_key = StorageKey::DidSubject(binding.did_hash.clone()).to_storage_key(env);
+        env.storage().persistent().remove(&reverse_key);
+    }
+
+    #[cfg(feature = "attestations")]
+    pub fn set_attestation_did(env: &Env, id: u64, did_hash: &BytesN<32>) {
+        let key = StorageKey::AttestationDid(id).to_storage_key(env);
+        env.storage().persistent().set(&key, did_hash);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "attestations")]
+    pub fn get_attestation_did(env: &Env, id: u64) -> Option<BytesN<32>> {
+        let key = StorageKey::AttestationDid(id).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_intent(env: &Env, intent: &TransactionIntent) {
+        let key = StorageKey::Intent(intent.intent_id).to_storage_key(env);
+        env.storage().persistent().set(&key, intent);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_intent(env: &Env, intent_id: u64) -> Result<TransactionIntent, Error> {
+        let key = StorageKey::Intent(intent_id).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::IntentNotFound)
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_quote_binding(env: &Env, binding: &QuoteBinding) {
+        let key = StorageKey::QuoteBinding(binding.intent_id).to_storage_key(env);
+        env.storage().persistent().set(&key, binding);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_quote_binding(env: &Env, intent_id: u64) -> Option<QuoteBinding> {
+        let key = StorageKey::QuoteBinding(intent_id).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn mark_intent_executed(env: &Env, intent_id: u64) {
+        let key = StorageKey::IntentExecuted(intent_id).to_storage_key(env);
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn is_intent_executed(env: &Env, intent_id: u64) -> bool {
+        let key = StorageKey::IntentExecuted(intent_id).to_storage_key(env);
+        env.storage().persistent().has(&key)
+    }
+
+    /// Take the execution lock on an intent. Fails with `IntentLocked` while
+    /// another execution holds it.
+    #[cfg(feature = "transfers")]
+    pub fn lock_intent(env: &Env, intent_id: u64) -> Result<(), Error> {
+        let key = StorageKey::IntentLock(intent_id).to_storage_key(env);
+        if env.storage().temporary().has(&key) {
+            return Err(Error::IntentLocked);
+        }
+        env.storage()
+            .temporary()
+            .set(&key, &env.ledger().sequence());
+        env.storage().temporary().extend_ttl(
+            &key,
+            Self::INTENT_LOCK_LIFETIME,
+            Self::INTENT_LOCK_LIFETIME,
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn unlock_intent(env: &Env, intent_id: u64) {
+        let key = StorageKey::IntentLock(intent_id).to_storage_key(env);
+        env.storage().temporary().remove(&key);
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_hash_lock(env: &Env, lock: &HashLock) {
+        let key = StorageKey::HashLock(lock.transfer_id).to_storage_key(env);
+        env.storage().persistent().set(&key, lock);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_hash_lock(env: &Env, transfer_id: u64) -> Result<HashLock, Error> {
+        let key = StorageKey::HashLock(transfer_id).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::TransferNotFound)
+    }
+
+    #[cfg(feature = "quotes")]
+    pub fn set_oracle_feed(env: &Env, base: &Asset, quote: &Asset, feed: &OracleFeed) {
+        let key = StorageKey::OracleFeed(base.clone(), quote.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, feed);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "quotes")]
+    pub fn get_oracle_feed(env: &Env, base: &Asset, quote: &Asset) -> Option<OracleFeed> {
+        let key = StorageKey::OracleFeed(base.clone(), quote.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "quotes")]
+    pub fn remove_oracle_feed(env: &Env, base: &Asset, quote: &Asset) {
+        let key = StorageKey::OracleFeed(base.clone(), quote.clone()).to_storage_key(env);
+        env.storage().persistent().remove(&key);
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_transfer(env: &Env, transfer: &TransferRecord) {
+        let key = StorageKey::Transfer(transfer.transfer_id).to_storage_key(env);
+        env.storage().persistent().set(&key, transfer);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_transfer(env: &Env, transfer_id: u64) -> Result<TransferRecord, Error> {
+        let key = StorageKey::Transfer(transfer_id).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::TransferNotFound)
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn set_kyc_record(env: &Env, record: &KycRecord) {
+        let key = StorageKey::KycRecord(record.subject.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, record);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn get_kyc_record(env: &Env, subject: &Address) -> Option<KycRecord> {
+        let key = StorageKey::KycRecord(subject.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn set_kyc_thresholds(env: &Env, anchor: &Address, thresholds: &Vec<KycThreshold>) {
+        let key = StorageKey::KycThresholds(anchor.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, thresholds);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn get_kyc_thresholds(env: &Env, anchor: &Address) -> Vec<KycThreshold> {
+        let key = StorageKey::KycThresholds(anchor.clone()).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_travel_rule_commitment(env: &Env, commitment: &TravelRuleCommitment) {
+        let key = StorageKey::TravelRule(commitment.transfer_id, commitment.role)
+            .to_storage_key(env);
+        env.storage().persistent().set(&key, commitment);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_travel_rule_commitment(
+        env: &Env,
+        transfer_id: u64,
+        role: TravelRuleRole,
+    ) -> Option<TravelRuleCommitment> {
+        let key = StorageKey::TravelRule(transfer_id, role).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_travel_rule_threshold(env: &Env, threshold: i128) {
+        let key = StorageKey::TravelRuleThreshold.to_storage_key(env);
+        env.storage().instance().set(&key, &threshold);
+        env.storage()
+            .instance()
+            .extend_ttl(Self::INSTANCE_LIFETIME, Self::INSTANCE_LIFETIME);
+    }
+
+    #[cfg(feature = "transfers")]
+    /// Amount at or above which settlement requires both commitments (0 = disabled).
+    pub fn get_travel_rule_threshold(env: &Env) -> i128 {
+        let key = StorageKey::TravelRuleThreshold.to_storage_key(env);
+        env.storage().instance().get(&key).unwrap_or(0)
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn set_blocked(env: &Env, address: &Address, blocked: bool) {
+        let key = StorageKey::Blocked(address.clone()).to_storage_key(env);
+        if blocked {
+            env.storage().persistent().set(&key, &true);
+            env.storage().persistent().extend_ttl(
+                &key,
+                Self::PERSISTENT_LIFETIME,
+                Self::PERSISTENT_LIFETIME,
+            );
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn is_blocked(env: &Env, address: &Address) -> bool {
+        let key = StorageKey::Blocked(address.clone()).to_storage_key(env);
+        env.storage().persistent().has(&key)
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn set_corridor_rules(env: &Env, base: &Asset, quote: &Asset, rules: &CorridorRules) {
+        let key = StorageKey::CorridorRules(base.clone(), quote.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, rules);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn get_corridor_rules(env: &Env, base: &Asset, quote: &Asset) -> Option<CorridorRules> {
+        let key = StorageKey::CorridorRules(base.clone(), quote.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn remove_corridor_rules(env: &Env, base: &Asset, quote: &Asset) {
+        let key = StorageKey::CorridorRules(base.clone(), quote.clone()).to_storage_key(env);
+        env.storage().persistent().remove(&key);
+    }
+
+    #[cfg(feature = "compliance")]
+    /// Tag an attestation with a kind and index it as the subject's latest of that kind.
+    pub fn set_attestation_kind(env: &Env, attestation: &Attestation, kind: &Symbol) {
+        let key = StorageKey::AttestationKind(attestation.id).to_storage_key(env);
+        env.storage().persistent().set(&key, kind);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+
+        let index_key = StorageKey::SubjectAttestation(attestation.subject.clone(), kind.clone())
+            .to_storage_key(env);
+        env.storage().persistent().set(&index_key, &attestation.id);
+        env.storage().persistent().extend_ttl(
+            &index_key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn get_attestation_kind(env: &Env, id: u64) -> Option<Symbol> {
+        let key = StorageKey::AttestationKind(id).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn get_subject_attestation(env: &Env, subject: &Address, kind: &Symbol) -> Option<u64> {
+        let key = StorageKey::SubjectAttestation(subject.clone(), kind.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn set_aml_threshold(env: &Env, asset: &Asset, threshold: i128) {
+        let key = StorageKey::AmlThreshold(asset.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, &threshold);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "compliance")]
+    /// Flagging threshold for an asset (0 = not monitored).
+    pub fn get_aml_threshold(env: &Env, asset: &Asset) -> i128 {
+        let key = StorageKey::AmlThreshold(asset.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn get_next_aml_flag_id(env: &Env) -> u64 {
+        let key = StorageKey::AmlFlagCounter.to_storage_key(env);
+        let current: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        let next = current + 1;
+        env.storage().instance().set(&key, &next);
+        env.storage()
+            .instance()
+            .extend_ttl(Self::INSTANCE_LIFETIME, Self::INSTANCE_LIFETIME);
+        next
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn set_aml_flag(env: &Env, flag: &AmlFlag) {
+        let key = StorageKey::AmlFlag(flag.flag_id).to_storage_key(env);
+        env.storage().persistent().set(&key, flag);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn get_aml_flag(env: &Env, flag_id: u64) -> Result<AmlFlag, Error> {
+        let key = StorageKey::AmlFlag(flag_id).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::NotFound)
+    }
+
+    pub fn set_role(env: &Env, role: Role, account: &Address, granted: bool) {
+        let key = StorageKey::RoleMember(role, account.clone()).to_storage_key(env);
+        if granted {
+            env.storage().persistent().set(&key, &true);
+            env.storage().persistent().extend_ttl(
+                &key,
+                Self::PERSISTENT_LIFETIME,
+                Self::PERSISTENT_LIFETIME,
+            );
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    pub fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+        let key = StorageKey::RoleMember(role, account.clone()).to_storage_key(env);
+        env.storage().persistent().has(&key)
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn set_kyc_verifier(env: &Env, verifier: &Address, registered: bool) {
+        let key = StorageKey::KycVerifier(verifier.clone()).to_storage_key(env);
+        if registered {
+            env.storage().persistent().set(&key, &true);
+            env.storage().persistent().extend_ttl(
+                &key,
+                Self::PERSISTENT_LIFETIME,
+                Self::PERSISTENT_LIFETIME,
+            );
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn is_kyc_verifier(env: &Env, verifier: &Address) -> bool {
+        let key = StorageKey::KycVerifier(verifier.clone()).to_storage_key(env);
+        env.storage().persistent().has(&key)
+    }
+
+    #[cfg(feature = "compliance")]
+    /// Commitment of the subject's latest verified KYC proof.
+    pub fn set_kyc_proof_commitment(env: &Env, subject: &Address, commitment: &BytesN<32>) {
+        let key = StorageKey::KycProof(subject.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, commitment);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn get_kyc_proof_commitment(env: &Env, subject: &Address) -> Option<BytesN<32>> {
+        let key = StorageKey::KycProof(subject.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn set_compliance_report(env: &Env, report: &ComplianceReport) {
+        let key = StorageKey::ComplianceReport(report.anchor.clone(), report.period)
+            .to_storage_key(env);
+        env.storage().persistent().set(&key, report);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn get_compliance_report(env: &Env, anchor: &Address, period: u64) -> ComplianceReport {
+        let key = StorageKey::ComplianceReport(anchor.clone(), period).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| ComplianceReport::empty(env, anchor, period))
+    }
+
+    #[cfg(feature = "attestations")]
+    fn add_subject_attestation_id(env: &Env, subject: &Address, id: u64) {
+        let key = StorageKey::SubjectAttestations(subject.clone()).to_storage_key(env);
+        let mut ids = Self::get_subject_attestation_ids(env, subject);
+        ids.push_back(id);
+        env.storage().persistent().set(&key, &ids);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "attestations")]
+    /// IDs of every attestation issued about `subject`.
+    pub fn get_subject_attestation_ids(env: &Env, subject: &Address) -> Vec<u64> {
+        let key = StorageKey::SubjectAttestations(subject.clone()).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    #[cfg(feature = "attestations")]
+    pub fn remove_attestation(env: &Env, id: u64) {
+        let key = StorageKey::Attestation(id).to_storage_key(env);
+        env.storage().persistent().remove(&key);
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn remove_kyc_record(env: &Env, subject: &Address) {
+        let key = StorageKey::KycRecord(subject.clone()).to_storage_key(env);
+        env.storage().persistent().remove(&key);
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn set_erasure_request(env: &Env, subject: &Address, requested_at: u64) {
+        let key = StorageKey::ErasureRequest(subject.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, &requested_at);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn get_erasure_request(env: &Env, subject: &Address) -> Option<u64> {
+        let key = StorageKey::ErasureRequest(subject.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn remove_erasure_request(env: &Env, subject: &Address) {
+        let key = StorageKey::ErasureRequest(subject.clone()).to_storage_key(env);
+        env.storage().persistent().remove(&key);
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn set_tombstone(env: &Env, tombstone: &Tombstone) {
+        let key = StorageKey::Tombstone(tombstone.kind, tombstone.subject.clone(), tombstone.record_id)
+            .to_storage_key(env);
+        env.storage().persistent().set(&key, tombstone);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn get_tombstone(
+        env: &Env,
+        kind: TombstoneKind,
+        subject: &Address,
+        record_id: u64,
+    ) -> Option<Tombstone> {
+        let key = StorageKey::Tombstone(kind, subject.clone(), record_id).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "quotes")]
+    /// Track `quote` as the anchor's latest for its asset pair and list the
+    /// pair under the anchor the first time it is quoted.
+    pub fn set_latest_quote(env: &Env, quote: &QuoteData) {
+        let key = StorageKey::LatestQuote(
+            quote.anchor.clone(),
+            quote.base_asset.clone(),
+            quote.quote_asset.clone(),
+        )
+        .to_storage_key(env);
+        let is_new_corridor = !env.storage().persistent().has(&key);
+        env.storage().persistent().set(&key, &quote.quote_id);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+
+        if is_new_corridor {
+            let list_key = StorageKey::AnchorCorridors(quote.anchor.clone()).to_storage_key(env);
+            let mut corridors = Self::get_anchor_corridors(env, &quote.anchor);
+            corridors.push_back((quote.base_asset.clone(), quote.quote_asset.clone()));
+            env.storage().persistent().set(&list_key, &corridors);
+            env.storage().persistent().extend_ttl(
+                &list_key,
+                Self::PERSISTENT_LIFETIME,
+                Self::PERSISTENT_LIFETIME,
+            );
+        }
+    }
+
+    #[cfg(feature = "quotes")]
+    pub fn get_latest_quote(
+        env: &Env,
+        anchor: &Address,
+        base: &Asset,
+        quote: &Asset,
+    ) -> Option<u64> {
+        let key = StorageKey::LatestQuote(anchor.clone(), base.clone(), quote.clone())
+            .to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "quotes")]
+    pub fn get_anchor_corridors(env: &Env, anchor: &Address) -> Vec<(Asset, Asset)> {
+        let key = StorageKey::AnchorCorridors(anchor.clone()).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    #[cfg(feature = "quotes")]
+    pub fn set_asset_decimals(env: &Env, asset: &Asset, decimals: u32) {
+        let key = StorageKey::AssetDecimals(asset.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, &decimals);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "quotes")]
+    pub fn get_asset_decimals(env: &Env, asset: &Asset) -> Option<u32> {
+        let key = StorageKey::AssetDecimals(asset.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn set_endpoint_domains(env: &Env, attestor: &Address, domains: &Vec<String>) {
+        let key = StorageKey::EndpointDomains(attestor.clone()).to_storage_key(env);
+        if domains.is_empty() {
+            env.storage().persistent().remove(&key);
+            return;
+        }
+        env.storage().persistent().set(&key, domains);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_endpoint_domains(env: &Env, attestor: &Address) -> Vec<String> {
+        let key = StorageKey::EndpointDomains(attestor.clone()).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    pub fn set_endpoint_challenge(env: &Env, challenge: &EndpointChallenge) {
+        let key = StorageKey::EndpointChallenge(challenge.attestor.clone(), challenge.kind)
+            .to_storage_key(env);
+        env.storage().persistent().set(&key, challenge);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_endpoint_challenge(
+        env: &Env,
+        attestor: &Address,
+        kind: EndpointKind,
+    ) -> Option<EndpointChallenge> {
+        let key = StorageKey::EndpointChallenge(attestor.clone(), kind).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn remove_endpoint_challenge(env: &Env, attestor: &Address, kind: EndpointKind) {
+        let key = StorageKey::EndpointChallenge(attestor.clone(), kind).to_storage_key(env);
+        env.storage().persistent().remove(&key);
+    }
+
+    pub fn set_endpoint_signing_key(env: &Env, attestor: &Address, key_bytes: &BytesN<32>) {
+        let key = StorageKey::EndpointSigningKey(attestor.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, key_bytes);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_endpoint_signing_key(env: &Env, attestor: &Address) -> Option<BytesN<32>> {
+        let key = StorageKey::EndpointSigningKey(attestor.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn set_operation_limit(env: &Env, class: OperationClass, limit: &OperationLimit) {
+        let key = StorageKey::OperationLimit(class).to_storage_key(env);
+        env.storage().persistent().set(&key, limit);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_operation_limit(env: &Env, class: OperationClass) -> Option<OperationLimit> {
+        let key = StorageKey::OperationLimit(class).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn remove_operation_limit(env: &Env, class: OperationClass) {
+        let key = StorageKey::OperationLimit(class).to_storage_key(env);
+        env.storage().persistent().remove(&key);
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_treasury_balance(env: &Env, token: &Address) -> i128 {
+        let key = StorageKey::TreasuryBalance(token.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Set a token's treasury balance, recording the token on first credit.
+    #[cfg(feature = "transfers")]
+    pub fn set_treasury_balance(env: &Env, token: &Address, balance: i128) {
+        let key = StorageKey::TreasuryBalance(token.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, &balance);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+
+        let mut tokens = Self::get_treasury_tokens(env);
+        if !tokens.contains(token) {
+            tokens.push_back(token.clone());
+            let tokens_key = StorageKey::TreasuryTokens.to_storage_key(env);
+            env.storage().persistent().set(&tokens_key, &tokens);
+            env.storage().persistent().extend_ttl(
+                &tokens_key,
+                Self::PERSISTENT_LIFETIME,
+                Self::PERSISTENT_LIFETIME,
+            );
+        }
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_treasury_tokens(env: &Env) -> Vec<Address> {
+        let key = StorageKey::TreasuryTokens.to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Share of protocol fees paid to referrers, in basis points (0 when unset).
+    #[cfg(feature = "transfers")]
+    pub fn get_referral_share(env: &Env) -> u32 {
+        let key = StorageKey::ReferralShare.to_storage_key(env);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_referral_share(env: &Env, share_bps: u32) {
+        let key = StorageKey::ReferralShare.to_storage_key(env);
+        env.storage().persistent().set(&key, &share_bps);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_referral_volume(env: &Env, referrer: &Address) -> ReferralVolume {
+        let key = StorageKey::ReferralVolume(referrer.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key).unwrap_or_default()
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_referral_volume(env: &Env, referrer: &Address, volume: &ReferralVolume) {
+        let key = StorageKey::ReferralVolume(referrer.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, volume);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "routing")]
+    pub fn get_uptime_reports(env: &Env, anchor: &Address) -> Vec<UptimeReport> {
+        let key = StorageKey::UptimeReports(anchor.clone()).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    #[cfg(feature = "routing")]
+    pub fn set_uptime_reports(env: &Env, anchor: &Address, reports: &Vec<UptimeReport>) {
+        let key = StorageKey::UptimeReports(anchor.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, reports);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    /// Usage counters recorded in a metrics period, one per service type and
+    /// asset pair.
+    #[cfg(feature = "transfers")]
+    pub fn get_usage_metrics(env: &Env, period: u64) -> Vec<UsageMetrics> {
+        let key = StorageKey::UsageMetrics(period).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_usage_metrics(env: &Env, period: u64, metrics: &Vec<UsageMetrics>) {
+        let key = StorageKey::UsageMetrics(period).to_storage_key(env);
+        env.storage().persistent().set(&key, metrics);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_audit_mode(env: &Env) -> AuditMode {
+        let key = StorageKey::AuditMode.to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(AuditMode::Full)
+    }
+
+    pub fn set_audit_mode(env: &Env, mode: AuditMode) {
+        let key = StorageKey::AuditMode.to_storage_key(env);
+        env.storage().persistent().set(&key, &mode);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_session_audit_mode(env: &Env, session_id: u64) -> Option<AuditMode> {
+        let key = StorageKey::SessionAuditMode(session_id).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn set_session_audit_mode(env: &Env, session_id: u64, mode: AuditMode) {
+        let key = StorageKey::SessionAuditMode(session_id).to_storage_key(env);
+        env.storage().persistent().set(&key, &mode);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "attestations")]
+    pub fn set_attestation_grant(env: &Env, grant: &AttestationGrant) {
+        let key = StorageKey::AttestationGrant(grant.subject.clone(), grant.grantee.clone())
+            .to_storage_key(env);
+        env.storage().persistent().set(&key, grant);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+
+        let mut grantees = Self::get_attestation_grantees(env, &grant.subject);
+        if !grantees.contains(&grant.grantee) {
+            grantees.push_back(grant.grantee.clone());
+            Self::set_attestation_grantees(env, &grant.subject, &grantees);
+        }
+    }
+
+    /// Grantees of every attestation grant a subject has issued and not removed.
+    #[cfg(feature = "attestations")]
+    pub fn get_attestation_grantees(env: &Env, subject: &Address) -> Vec<Address> {
+        let key = StorageKey::AttestationGrantList(subject.clone()).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    #[cfg(feature = "attestations")]
+    fn set_attestation_grantees(env: &Env, subject: &Address, grantees: &Vec<Address>) {
+        let key = StorageKey::AttestationGrantList(subject.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, grantees);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "attestations")]
+    pub fn get_attestation_grant(
+        env: &Env,
+        subject: &Address,
+        grantee: &Address,
+    ) -> Option<AttestationGrant> {
+        let key =
+            StorageKey::AttestationGrant(subject.clone(), grantee.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "attestations")]
+    pub fn remove_attestation_grant(env: &Env, subject: &Address, grantee: &Address) {
+        let key =
+            StorageKey::AttestationGrant(subject.clone(), grantee.clone()).to_storage_key(env);
+        env.storage().persistent().remove(&key);
+
+        let mut grantees = Self::get_attestation_grantees(env, subject);
+        if let Some(index) = grantees.first_index_of(grantee) {
+            grantees.remove(index);
+            Self::set_attestation_grantees(env, subject, &grantees);
+        }
+    }
+
+    #[cfg(feature = "quotes")]
+    pub fn get_next_corridor_id(env: &Env) -> u64 {
+        let key = StorageKey::CorridorCounter.to_storage_key(env);
+        let current: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        let next = current + 1;
+        env.storage().instance().set(&key, &next);
+        env.storage()
+            .instance()
+            .extend_ttl(Self::INSTANCE_LIFETIME, Self::INSTANCE_LIFETIME);
+        next
+    }
+
+    #[cfg(feature = "quotes")]
+    pub fn get_corridor_count(env: &Env) -> u64 {
+        let key = StorageKey::CorridorCounter.to_storage_key(env);
+        env.storage().instance().get(&key).unwrap_or(0)
+    }
+
+    #[cfg(feature = "quotes")]
+    pub fn set_corridor(env: &Env, corridor: &Corridor) {
+        let key = StorageKey::Corridor(corridor.corridor_id).to_storage_key(env);
+        env.storage().persistent().set(&key, corridor);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "quotes")]
+    pub fn get_corridor(env: &Env, corridor_id: u64) -> Result<Corridor, Error> {
+        let key = StorageKey::Corridor(corridor_id).to_storage_key(env);
+        env.storage().persistent().get(&key).ok_or(Error::NotFound)
+    }
+
+    pub fn set_offboarding(env: &Env, state: &OffboardingState) {
+        let key = StorageKey::Offboarding(state.anchor.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, state);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_offboarding(env: &Env, anchor: &Address) -> Option<OffboardingState> {
+        let key = StorageKey::Offboarding(anchor.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn remove_offboarding(env: &Env, anchor: &Address) {
+        let key = StorageKey::Offboarding(anchor.clone()).to_storage_key(env);
+        env.storage().persistent().remove(&key);
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_anchor_activity(env: &Env, anchor: &Address, activity: &AnchorActivity) {
+        let key = StorageKey::AnchorActivity(anchor.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, activity);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_anchor_activity(env: &Env, anchor: &Address) -> AnchorActivity {
+        let key = StorageKey::AnchorActivity(anchor.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key).unwrap_or_default()
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_anchor_bonds(env: &Env, anchor: &Address, bonds: &Vec<AnchorBond>) {
+        let key = StorageKey::AnchorBonds(anchor.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, bonds);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_anchor_bonds(env: &Env, anchor: &Address) -> Vec<AnchorBond> {
+        let key = StorageKey::AnchorBonds(anchor.clone()).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    pub fn set_settlement_stats(env: &Env, stats: &SettlementStats) {
+        let key = StorageKey::SettlementStats(stats.anchor.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, stats);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_settlement_stats(env: &Env, anchor: &Address) -> Option<SettlementStats> {
+        let key = StorageKey::SettlementStats(anchor.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_retry_record(env: &Env, record: &RetryRecord) {
+        let key = StorageKey::RetryRecord(record.target.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, record);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_retry_record(env: &Env, target: &RetryTarget) -> Option<RetryRecord> {
+        let key = StorageKey::RetryRecord(target.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn remove_retry_record(env: &Env, target: &RetryTarget) {
+        let key = StorageKey::RetryRecord(target.clone()).to_storage_key(env);
+        env.storage().persistent().remove(&key);
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_retry_targets(env: &Env, targets: &Vec<RetryTarget>) {
+        let key = StorageKey::RetryTargets.to_storage_key(env);
+        env.storage().persistent().set(&key, targets);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_retry_targets(env: &Env) -> Vec<RetryTarget> {
+        let key = StorageKey::RetryTargets.to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn get_next_requirement_template_id(env: &Env) -> u64 {
+        let key = StorageKey::RequirementTemplateCounter.to_storage_key(env);
+        let current: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        let next = current + 1;
+        env.storage().instance().set(&key, &next);
+        env.storage()
+            .instance()
+            .extend_ttl(Self::INSTANCE_LIFETIME, Self::INSTANCE_LIFETIME);
+        next
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn get_requirement_template_count(env: &Env) -> u64 {
+        let key = StorageKey::RequirementTemplateCounter.to_storage_key(env);
+        env.storage().instance().get(&key).unwrap_or(0)
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn set_requirement_template(env: &Env, template: &RequirementTemplate) {
+        let key = StorageKey::RequirementTemplate(template.template_id).to_storage_key(env);
+        env.storage().persistent().set(&key, template);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "compliance")]
+    pub fn get_requirement_template(
+        env: &Env,
+        template_id: u64,
+    ) -> Result<RequirementTemplate, Error> {
+        let key = StorageKey::RequirementTemplate(template_id).to_storage_key(env);
+        env.storage().persistent().get(&key).ok_or(Error::NotFound)
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_exposure(env: &Env, exposure: &CounterpartyExposure) {
+        let key = StorageKey::Exposure(exposure.anchor_a.clone(), exposure.anchor_b.clone())
+            .to_storage_key(env);
+        env.storage().persistent().set(&key, exposure);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    /// Exposure between two anchors, ordered as in `CounterpartyExposure::new`.
+    #[cfg(feature = "transfers")]
+    pub fn get_exposure(
+        env: &Env,
+        anchor_a: &Address,
+        anchor_b: &Address,
+    ) -> Option<CounterpartyExposure> {
+        let key = StorageKey::Exposure(anchor_a.clone(), anchor_b.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_open_leg(env: &Env, transfer_id: u64) {
+        let key = StorageKey::OpenLeg(transfer_id).to_storage_key(env);
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    /// Clear the open-leg marker of a transfer, returning whether it was set.
+    #[cfg(feature = "transfers")]
+    pub fn take_open_leg(env: &Env, transfer_id: u64) -> bool {
+        let key = StorageKey::OpenLeg(transfer_id).to_storage_key(env);
+        let open = env.storage().persistent().has(&key);
+        if open {
+            env.storage().persistent().remove(&key);
+        }
+        open
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_session_quote_pin(env: &Env, pin: &SessionQuotePin) {
+        let key = StorageKey::SessionQuotePin(pin.session_id).to_storage_key(env);
+        env.storage().persistent().set(&key, pin);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_session_quote_pin(env: &Env, session_id: u64) -> Option<SessionQuotePin> {
+        let key = StorageKey::SessionQuotePin(session_id).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "routing")]
+    pub fn get_next_routing_receipt_id(env: &Env) -> u64 {
+        let key = StorageKey::RoutingReceiptCounter.to_storage_key(env);
+        let current: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        let next = current + 1;
+        env.storage().instance().set(&key, &next);
+        env.storage()
+            .instance()
+            .extend_ttl(Self::INSTANCE_LIFETIME, Self::INSTANCE_LIFETIME);
+        next
+    }
+
+    #[cfg(feature = "routing")]
+    pub fn set_routing_receipt(env: &Env, receipt: &RoutingReceipt) {
+        let key = StorageKey::RoutingReceipt(receipt.receipt_id).to_storage_key(env);
+        env.storage().persistent().set(&key, receipt);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "routing")]
+    pub fn get_routing_receipt(env: &Env, receipt_id: u64) -> Result<RoutingReceipt, Error> {
+        let key = StorageKey::RoutingReceipt(receipt_id).to_storage_key(env);
+        env.storage().persistent().get(&key).ok_or(Error::NotFound)
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_fee_revenue(env: &Env, revenue: &AnchorFeeRevenue) {
+        let key =
+            StorageKey::FeeRevenue(revenue.anchor.clone(), revenue.period).to_storage_key(env);
+        env.storage().persistent().set(&key, revenue);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_fee_revenue(env: &Env, anchor: &Address, period: u64) -> AnchorFeeRevenue {
+        let key = StorageKey::FeeRevenue(anchor.clone(), period).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| AnchorFeeRevenue::empty(env, anchor, period))
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_next_intent_template_id(env: &Env) -> u64 {
+        let key = StorageKey::IntentTemplateCounter.to_storage_key(env);
+        let current: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        let next = current + 1;
+        env.storage().instance().set(&key, &next);
+        env.storage()
+            .instance()
+            .extend_ttl(Self::INSTANCE_LIFETIME, Self::INSTANCE_LIFETIME);
+        next
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_intent_template(env: &Env, template: &IntentTemplate) {
+        let key = StorageKey::IntentTemplate(template.template_id).to_storage_key(env);
+        env.storage().persistent().set(&key, template);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_intent_template(env: &Env, template_id: u64) -> Result<IntentTemplate, Error> {
+        let key = StorageKey::IntentTemplate(template_id).to_storage_key(env);
+        env.storage().persistent().get(&key).ok_or(Error::NotFound)
+    }
+
+    pub fn set_freeze(env: &Env, state: &FreezeState) {
+        let key = StorageKey::Freeze.to_storage_key(env);
+        env.storage().instance().set(&key, state);
+        env.storage()
+            .instance()
+            .extend_ttl(Self::INSTANCE_LIFETIME, Self::INSTANCE_LIFETIME);
+    }
+
+    pub fn get_freeze(env: &Env) -> Option<FreezeState> {
+        let key = StorageKey::Freeze.to_storage_key(env);
+        env.storage().instance().get(&key)
+    }
+
+    pub fn remove_freeze(env: &Env) {
+        let key = StorageKey::Freeze.to_storage_key(env);
+        env.storage().instance().remove(&key);
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_intent_execution(env: &Env, execution: &IntentExecution) {
+        let key = StorageKey::IntentExecution(execution.intent_id).to_storage_key(env);
+        env.storage().persistent().set(&key, execution);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_intent_execution(env: &Env, intent_id: u64) -> Option<IntentExecution> {
+        let key = StorageKey::IntentExecution(intent_id).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_late_penalty(env: &Env, penalty: &LateSettlementPenalty) {
+        let key = StorageKey::LatePenalty(penalty.intent_id).to_storage_key(env);
+        env.storage().persistent().set(&key, penalty);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_late_penalty(env: &Env, intent_id: u64) -> Result<LateSettlementPenalty, Error> {
+        let key = StorageKey::LatePenalty(intent_id).to_storage_key(env);
+        env.storage().persistent().get(&key).ok_or(Error::NotFound)
+    }
+
+    #[cfg(feature = "attestations")]
+    pub fn mark_attestation_revoked(env: &Env, id: u64) {
+        let key = StorageKey::AttestationRevoked(id).to_storage_key(env);
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "attestations")]
+    pub fn is_attestation_revoked(env: &Env, id: u64) -> bool {
+        let key = StorageKey::AttestationRevoked(id).to_storage_key(env);
+        env.storage().persistent().has(&key)
+    }
+
+    #[cfg(feature = "quotes")]
+    pub fn set_order_book(env: &Env, base: &Asset, quote: &Asset, book: &Vec<QuoteData>) {
+        let key = StorageKey::OrderBook(base.clone(), quote.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, book);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "quotes")]
+    pub fn get_order_book(env: &Env, base: &Asset, quote: &Asset) -> Vec<QuoteData> {
+        let key = StorageKey::OrderBook(base.clone(), quote.clone()).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Record `alias` as `address`'s short name, in both directions.
+    pub fn set_alias(env: &Env, alias: &Symbol, address: &Address) {
+        let key = StorageKey::Alias(alias.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, address);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+
+        let reverse_key = StorageKey::AddressAlias(address.clone()).to_storage_key(env);
+        env.storage().persistent().set(&reverse_key, alias);
+        env.storage().persistent().extend_ttl(
+            &reverse_key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_alias_address(env: &Env, alias: &Symbol) -> Option<Address> {
+        let key = StorageKey::Alias(alias.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn get_address_alias(env: &Env, address: &Address) -> Option<Symbol> {
+        let key = StorageKey::AddressAlias(address.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn remove_alias(env: &Env, alias: &Symbol, address: &Address) {
+        let key = StorageKey::Alias(alias.clone()).to_storage_key(env);
+        env.storage().persistent().remove(&key);
+        let reverse_key = StorageKey::AddressAlias(address.clone()).to_storage_key(env);
+        env.storage().persistent().remove(&reverse_key);
+    }
+
+    /// Record `delegate` and add it to its attestor's delegate list.
+    pub fn add_delegate(env: &Env, delegate: &AttestorDelegate) {
+        let key = StorageKey::Delegate(delegate.delegate.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, delegate);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+
+        let mut delegates = Self::get_delegates(env, &delegate.attestor);
+        delegates.push_back(delegate.delegate.clone());
+        Self::set_delegates(env, &delegate.attestor, &delegates);
+    }
+
+    pub fn get_delegate(env: &Env, delegate: &Address) -> Option<AttestorDelegate> {
+        let key = StorageKey::Delegate(delegate.clone()).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Drop `delegate` and remove it from its attestor's delegate list.
+    pub fn remove_delegate(env: &Env, delegate: &AttestorDelegate) {
+        let key = StorageKey::Delegate(delegate.delegate.clone()).to_storage_key(env);
+        env.storage().persistent().remove(&key);
+
+        let mut delegates = Self::get_delegates(env, &delegate.attestor);
+        if let Some(index) = delegates.first_index_of(&delegate.delegate) {
+            delegates.remove(index);
+        }
+        Self::set_delegates(env, &delegate.attestor, &delegates);
+    }
+
+    pub fn get_delegates(env: &Env, attestor: &Address) -> Vec<Address> {
+        let key = StorageKey::Delegates(attestor.clone()).to_storage_key(env);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn set_delegates(env: &Env, attestor: &Address, delegates: &Vec<Address>) {
+        let key = StorageKey::Delegates(attestor.clone()).to_storage_key(env);
+        env.storage().persistent().set(&key, delegates);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn set_scheduled_change(env: &Env, parameter: Parameter, scheduled: &ScheduledChange) {
+        let key = StorageKey::ScheduledChange(parameter).to_storage_key(env);
+        env.storage().persistent().set(&key, scheduled);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    pub fn get_scheduled_change(env: &Env, parameter: Parameter) -> Option<ScheduledChange> {
+        let key = StorageKey::ScheduledChange(parameter).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn remove_scheduled_change(env: &Env, parameter: Parameter) {
+        let key = StorageKey::ScheduledChange(parameter).to_storage_key(env);
+        env.storage().persistent().remove(&key);
+    }
+
+    /// Link attestation `new_id` to `old_id`, the attestation it replaces.
+    pub fn set_supersession(env: &Env, old_id: u64, new_id: u64) {
+        for (key, value) in [
+            (StorageKey::SupersededBy(old_id), new_id),
+            (StorageKey::Supersedes(new_id), old_id),
+        ] {
+            let key = key.to_storage_key(env);
+            env.storage().persistent().set(&key, &value);
+            env.storage().persistent().extend_ttl(
+                &key,
+                Self::PERSISTENT_LIFETIME,
+                Self::PERSISTENT_LIFETIME,
+            );
+        }
+    }
+
+    pub fn get_superseded_by(env: &Env, id: u64) -> Option<u64> {
+        let key = StorageKey::SupersededBy(id).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    pub fn get_supersedes(env: &Env, id: u64) -> Option<u64> {
+        let key = StorageKey::Supersedes(id).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn set_quote_capacity(env: &Env, anchor: &Address, quote_id: u64, record: &QuoteCapacity) {
+        let key = StorageKey::QuoteCapacity(anchor.clone(), quote_id).to_storage_key(env);
+        env.storage().persistent().set(&key, record);
+        env.storage().persistent().extend_ttl(
+            &key,
+            Self::PERSISTENT_LIFETIME,
+            Self::PERSISTENT_LIFETIME,
+        );
+    }
+
+    #[cfg(feature = "transfers")]
+    pub fn get_quote_capacity(env: &Env, anchor: &Address, quote_id: u64) -> Option<QuoteCapacity> {
+        let key = StorageKey::QuoteCapacity(anchor.clone(), quote_id).to_storage_key(env);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Drop `anchor` from the list `get_all_anchors` reads.
+    #[cfg(feature = "routing")]
+    pub fn remove_from_anchor_list(env: &Env, anchor: &Address) {
+        let key = StorageKey::AnchorList.to_storage_key(env);
+        let mut list = Self::get_anchor_list(env);
+        if let Some(index) = list.first_index_of(anchor) {
+            list.remove(index);
+            env.storage().persistent().set(&key, &list);
+        }
+    }
 }
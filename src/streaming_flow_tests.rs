@@ -1,9 +1,10 @@
 #![cfg(test)]
 
-use crate::{AnchorKitContract, AnchorKitContractClient, ServiceType};
+use crate::testutils::{attestor_public_key, classic_asset, sign_attestation};
+use crate::{AnchorKitContract, AnchorKitContractClient, QuoteQueryOptions, ServiceType};
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
-    Address, Bytes, BytesN, Env, String, Vec,
+    Address, BytesN, Env, Vec,
 };
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -26,7 +27,7 @@ fn create_contract(env: &Env) -> AnchorKitContractClient<'_> {
 }
 
 fn setup_anchor(env: &Env, client: &AnchorKitContractClient, admin: &Address, anchor: &Address) {
-    client.register_attestor(anchor);
+    client.register_attestor(anchor, &attestor_public_key(env));
 
     let mut services = Vec::new(env);
     services.push_back(ServiceType::Deposits);
@@ -62,8 +63,8 @@ fn test_streaming_flow_pending_to_awaiting_user_to_completed() {
     // AWAITING_USER: Submit quote
     let quote_id = client.submit_quote(
         &anchor,
-        &String::from_str(&env, "USD"),
-        &String::from_str(&env, "USDC"),
+        &classic_asset(&env, "USD"),
+        &classic_asset(&env, "USDC"),
         &10000u64,
         &25u32,
         &100u64,
@@ -75,7 +76,7 @@ fn test_streaming_flow_pending_to_awaiting_user_to_completed() {
     assert_eq!(flow.state, FlowState::AwaitingUser);
 
     // COMPLETED: Receive quote
-    let quote = client.receive_quote(&user, &anchor, &quote_id);
+    let quote = client.query_quote(&anchor, &quote_id, &QuoteQueryOptions::notify(user.clone()));
     assert_eq!(quote.quote_id, quote_id);
 
     flow.state = FlowState::Completed;
@@ -103,7 +104,7 @@ fn test_multi_step_async_stream_with_attestation() {
 
     // AWAITING_USER
     let payload_hash = BytesN::from_array(&env, &[1; 32]);
-    let signature = Bytes::from_slice(&env, &[10, 11, 12]);
+    let signature = sign_attestation(&env, &anchor, &subject, 1000001, &payload_hash);
 
     let attestation_id = client.submit_attestation_with_session(
         &session_id,
@@ -112,6 +113,7 @@ fn test_multi_step_async_stream_with_attestation() {
         &1000001u64,
         &payload_hash,
         &signature,
+        &None,
     );
 
     state = FlowState::AwaitingUser;
@@ -151,8 +153,8 @@ fn test_concurrent_streaming_flows() {
     // Flow 1: AWAITING_USER
     let quote1 = client.submit_quote(
         &anchor,
-        &String::from_str(&env, "USD"),
-        &String::from_str(&env, "USDC"),
+        &classic_asset(&env, "USD"),
+        &classic_asset(&env, "USDC"),
         &10000u64,
         &25u32,
         &100u64,
@@ -164,8 +166,8 @@ fn test_concurrent_streaming_flows() {
     // Flow 2: AWAITING_USER
     let quote2 = client.submit_quote(
         &anchor,
-        &String::from_str(&env, "EUR"),
-        &String::from_str(&env, "EURC"),
+        &classic_asset(&env, "EUR"),
+        &classic_asset(&env, "EURC"),
         &10050u64,
         &30u32,
         &200u64,
@@ -175,11 +177,11 @@ fn test_concurrent_streaming_flows() {
     flow2_state = FlowState::AwaitingUser;
 
     // Flow 1: COMPLETED
-    let _ = client.receive_quote(&user1, &anchor, &quote1);
+    let _ = client.query_quote(&anchor, &quote1, &QuoteQueryOptions::notify(user1.clone()));
     flow1_state = FlowState::Completed;
 
     // Flow 2: COMPLETED
-    let _ = client.receive_quote(&user2, &anchor, &quote2);
+    let _ = client.query_quote(&anchor, &quote2, &QuoteQueryOptions::notify(user2.clone()));
     flow2_state = FlowState::Completed;
 
     assert_eq!(flow1_state, FlowState::Completed);
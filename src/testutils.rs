@@ -0,0 +1,431 @@
+//! Helpers for integration tests against AnchorKit.
+//!
+//! Enabled with the `testutils` feature:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! anchorkit = { version = "0.1", features = ["testutils"] }
+//! ```
+//!
+//! ```ignore
+//! let env = Env::default();
+//! let kit = AnchorKitFixture::new(&env);
+//! let anchor = kit.register_anchor(&[ServiceType::Quotes]);
+//! let quote_id = kit.seed_quote(&anchor, "USDC", "EUR", 9200, 3600);
+//! advance_time(&env, 3601);
+//! ```
+//!
+//! A fixture's state can be captured and re-seeded into a fresh `Env`, so
+//! tests and fuzz harnesses can start from a mid-life contract:
+//!
+//! ```ignore
+//! let snapshot = kit.snapshot();
+//! let env = Env::default();
+//! let kit = AnchorKitFixture::restore(&env, &snapshot);
+//! ```
+
+use core::cell::RefCell;
+
+use ed25519_dalek::{Signer as _, SigningKey};
+use soroban_sdk::{
+    contracttype,
+    testutils::{Address as _, Ledger},
+    xdr::{FromXdr, ToXdr},
+    Address, Bytes, BytesN, Env, String, Vec,
+};
+
+#[cfg(feature = "quotes")]
+use crate::QuoteSubmission;
+use crate::{
+    serialization, storage::Storage, AnchorKitContract, AnchorKitContractClient, AnchorMetadata, Asset,
+    InteractionSession, QuoteData, ServiceType,
+};
+#[cfg(feature = "transfers")]
+use crate::{AmountSide, QuoteContext, QuoteRequest, TransactionIntentBuilder};
+
+/// Issuer used for classic assets created by the fixture.
+pub const TEST_ISSUER: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+
+/// Ed25519 secret key fixture attestors are registered with; see
+/// [`sign_attestation`].
+pub const ATTESTOR_SECRET_KEY: [u8; 32] = [7; 32];
+
+/// A registered and initialized AnchorKit contract with all auths mocked.
+pub struct AnchorKitFixture<'a> {
+    pub env: &'a Env,
+    pub contract_id: Address,
+    pub admin: Address,
+    pub client: AnchorKitContractClient<'a>,
+    /// Subject of the intents `deposit_intent` builds
+    pub user: Address,
+    /// Attestors registered through the fixture, for `snapshot`
+    attestors: RefCell<Vec<Address>>,
+}
+
+impl<'a> AnchorKitFixture<'a> {
+    /// Register the contract in `env` and initialize it with a generated admin.
+    pub fn new(env: &'a Env) -> Self {
+        Self::with_admin(env, Address::generate(env))
+    }
+
+    fn with_admin(env: &'a Env, admin: Address) -> Self {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+        client.initialize(&admin);
+
+        Self {
+            env,
+            contract_id,
+            admin,
+            client,
+            user: Address::generate(env),
+            attestors: RefCell::new(Vec::new(env)),
+        }
+    }
+
+    /// Register a new attestor with no services.
+    pub fn register_attestor(&self) -> Address {
+        let attestor = Address::generate(self.env);
+        self.client
+            .register_attestor(&attestor, &attestor_public_key(self.env));
+        self.attestors.borrow_mut().push_back(attestor.clone());
+        attestor
+    }
+
+    /// Register a new anchor offering `services`.
+    pub fn register_anchor(&self, services: &[ServiceType]) -> Address {
+        let anchor = self.register_attestor();
+        let mut list = Vec::new(self.env);
+        for service in services {
+            list.push_back(*service);
+        }
+        self.client.configure_services(&anchor, &list);
+        anchor
+    }
+
+    /// Seed a SEP-6 quote between two classic assets, valid for `valid_for` seconds.
+    /// The anchor must offer `ServiceType::Quotes`.
+    #[cfg(feature = "quotes")]
+    pub fn seed_quote(
+        &self,
+        anchor: &Address,
+        base: &str,
+        quote: &str,
+        rate: u64,
+        valid_for: u64,
+    ) -> u64 {
+        let valid_until = self.env.ledger().timestamp() + valid_for;
+        self.client.submit_quote(
+            anchor,
+            &classic_asset(self.env, base),
+            &classic_asset(self.env, quote),
+            &rate,
+            &0,
+            &1,
+            &u64::MAX,
+            &valid_until,
+        )
+    }
+
+    /// Builder for a deposit intent of `amount` between two classic assets,
+    /// for the fixture's `user`.
+    #[cfg(feature = "transfers")]
+    pub fn deposit_intent(
+        &self,
+        anchor: &Address,
+        base: &str,
+        quote: &str,
+        amount: u64,
+    ) -> TransactionIntentBuilder {
+        let request = QuoteRequest {
+            base_asset: classic_asset(self.env, base),
+            quote_asset: classic_asset(self.env, quote),
+            amount,
+            operation_type: ServiceType::Deposits,
+            context: QuoteContext::Sep6,
+            amount_side: AmountSide::Sell,
+            delivery_method: None,
+            country_code: None,
+        };
+        TransactionIntentBuilder::new(self.env, anchor.clone(), request)
+            .with_subject(self.user.clone())
+    }
+
+    /// Capture the contract's logical state: the attestors registered through
+    /// the fixture that are still registered, with their services and
+    /// metadata, every quote they submitted and every session.
+    pub fn snapshot(&self) -> FixtureSnapshot {
+        let env = self.env;
+        let state = env.as_contract(&self.contract_id, || {
+            let mut attestors = Vec::new(env);
+            #[cfg_attr(not(feature = "routing"), allow(unused_mut))]
+            let mut metadata = Vec::new(env);
+            for address in self.attestors.borrow().iter() {
+                if !Storage::is_attestor(env, &address) {
+                    continue;
+                }
+                #[cfg(feature = "routing")]
+                if let Some(anchor_metadata) = Storage::get_anchor_metadata(env, &address) {
+                    metadata.push_back(anchor_metadata);
+                }
+                attestors.push_back(AttestorState {
+                    public_key: Storage::get_attestor_key(env, &address)
+                        .expect("registered attestor has a key"),
+                    services: Storage::get_anchor_services(env, &address)
+                        .map_or(Vec::new(env), |services| services.services),
+                    address,
+                });
+            }
+
+            #[cfg_attr(not(feature = "quotes"), allow(unused_mut))]
+            let mut quotes = Vec::new(env);
+            #[cfg(feature = "quotes")]
+            for quote_id in 1..=Storage::get_quote_count(env) {
+                let quote = attestors
+                    .iter()
+                    .find_map(|attestor| Storage::get_quote(env, &attestor.address, quote_id));
+                if let Some(quote) = quote {
+                    quotes.push_back(quote);
+                }
+            }
+
+            let mut sessions = Vec::new(env);
+            for session_id in 0..Storage::get_session_count(env) {
+                if let Ok(session) = Storage::get_session(env, session_id) {
+                    sessions.push_back(session);
+                }
+            }
+
+            FixtureState {
+                timestamp: env.ledger().timestamp(),
+                admin: self.admin.clone(),
+                attestors,
+                metadata,
+                quotes,
+                sessions,
+            }
+        });
+        FixtureSnapshot::from_state(env, &state)
+    }
+
+    /// Register a fresh contract in `env` and re-seed it from `snapshot`
+    /// through its entrypoints, leaving the ledger at the captured time.
+    ///
+    /// Quotes are re-submitted in order before the clock moves, so expired ones
+    /// are kept, and sessions are re-created at their original times; IDs
+    /// match as long as every quote came from a captured attestor. Session
+    /// operations and audit logs aren't replayed.
+    pub fn restore(env: &'a Env, snapshot: &FixtureSnapshot) -> Self {
+        let state = snapshot.state(env);
+        let kit = Self::with_admin(env, state.admin.clone());
+
+        for attestor in state.attestors.iter() {
+            kit.client
+                .register_attestor(&attestor.address, &attestor.public_key);
+            kit.attestors
+                .borrow_mut()
+                .push_back(attestor.address.clone());
+            if !attestor.services.is_empty() {
+                kit.client
+                    .configure_services(&attestor.address, &attestor.services);
+            }
+        }
+        #[cfg(feature = "routing")]
+        for metadata in state.metadata.iter() {
+            kit.client.set_anchor_metadata(
+                &metadata.anchor,
+                &metadata.reputation_score,
+                &metadata.average_settlement_time,
+                &metadata.liquidity_score,
+                &metadata.uptime_percentage,
+                &metadata.total_volume,
+            );
+            if !metadata.is_active {
+                kit.client.deactivate_anchor(&metadata.anchor);
+            }
+        }
+
+        #[cfg(feature = "quotes")]
+        for quote in state.quotes.iter() {
+            kit.client.submit_quote_v2(&QuoteSubmission {
+                anchor: quote.anchor,
+                base_asset: quote.base_asset,
+                quote_asset: quote.quote_asset,
+                rate: quote.rate,
+                fee_percentage: quote.fee_percentage,
+                minimum_amount: quote.minimum_amount,
+                maximum_amount: quote.maximum_amount,
+                valid_until: quote.valid_until,
+                context: quote.context,
+                sell_delivery_method: quote.sell_delivery_method,
+                buy_delivery_method: quote.buy_delivery_method,
+                country_code: quote.country_code,
+            });
+        }
+
+        for session in state.sessions.iter() {
+            set_time(env, session.created_at);
+            kit.client.create_session(&session.initiator);
+        }
+        set_time(env, state.timestamp);
+
+        kit
+    }
+}
+
+/// An attestor captured by [`AnchorKitFixture::snapshot`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestorState {
+    pub address: Address,
+    pub public_key: BytesN<32>,
+    pub services: Vec<ServiceType>, // empty for plain attestors
+}
+
+/// Logical contract state captured by [`AnchorKitFixture::snapshot`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixtureState {
+    pub timestamp: u64,
+    pub admin: Address,
+    pub attestors: Vec<AttestorState>,
+    pub metadata: Vec<AnchorMetadata>, // routing metadata of the attestors that have it
+    pub quotes: Vec<QuoteData>,        // in ID order
+    pub sessions: Vec<InteractionSession>, // in ID order
+}
+
+/// A [`FixtureState`] detached from its `Env` as XDR, so it can seed another
+/// one. Edit it through `state` and `from_state` to derive new fixtures.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixtureSnapshot {
+    xdr: alloc::vec::Vec<u8>,
+}
+
+impl FixtureSnapshot {
+    pub fn from_state(env: &Env, state: &FixtureState) -> Self {
+        Self {
+            xdr: state.clone().to_xdr(env).iter().collect(),
+        }
+    }
+
+    /// The captured state, bound to `env`.
+    pub fn state(&self, env: &Env) -> FixtureState {
+        FixtureState::from_xdr(env, &Bytes::from_slice(env, &self.xdr))
+            .expect("snapshot holds a FixtureState")
+    }
+}
+
+/// Classic asset `code` issued by [`TEST_ISSUER`].
+pub fn classic_asset(env: &Env, code: &str) -> Asset {
+    let issuer = Address::from_string(&String::from_str(env, TEST_ISSUER));
+    Asset::Classic(String::from_str(env, code), issuer)
+}
+
+/// Public key of [`ATTESTOR_SECRET_KEY`], to register attestors with.
+pub fn attestor_public_key(env: &Env) -> BytesN<32> {
+    let key = SigningKey::from_bytes(&ATTESTOR_SECRET_KEY);
+    BytesN::from_array(env, key.verifying_key().as_bytes())
+}
+
+/// Signature of an attestation recorded under `issuer`, made with
+/// [`ATTESTOR_SECRET_KEY`]. Delegates sign with their attestor as `issuer`.
+pub fn sign_attestation(
+    env: &Env,
+    issuer: &Address,
+    subject: &Address,
+    timestamp: u64,
+    payload_hash: &BytesN<32>,
+) -> Bytes {
+    let digest =
+        serialization::attestation_signing_digest(env, issuer, subject, timestamp, payload_hash);
+    let signature = SigningKey::from_bytes(&ATTESTOR_SECRET_KEY).sign(&digest.to_array());
+    Bytes::from_array(env, &signature.to_bytes())
+}
+
+/// Set the ledger timestamp.
+pub fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+/// Move the ledger timestamp forward by `seconds` (and the sequence by one).
+pub fn advance_time(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += seconds;
+        li.sequence_number += 1;
+    });
+}
+
+/// CPU, memory and ledger footprint of one measured call, as metered by the
+/// host. Used both as a measurement and as the ceiling it is checked against.
+#[cfg(feature = "benchmarks")]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ResourceUsage {
+    pub cpu_instructions: u64,
+    pub memory_bytes: u64,
+    pub ledger_reads: u32,  // entries only read
+    pub ledger_writes: u32, // entries written (and possibly read)
+}
+
+#[cfg(feature = "benchmarks")]
+impl ResourceUsage {
+    /// Panic, naming `operation` and every exceeded resource, if this usage
+    /// goes over `limit` anywhere.
+    pub fn assert_within(&self, operation: &str, limit: &ResourceUsage) {
+        let exceeded = [
+            ("cpu_instructions", self.cpu_instructions, limit.cpu_instructions),
+            ("memory_bytes", self.memory_bytes, limit.memory_bytes),
+            ("ledger_reads", self.ledger_reads.into(), limit.ledger_reads.into()),
+            ("ledger_writes", self.ledger_writes.into(), limit.ledger_writes.into()),
+        ];
+        for (resource, used, max) in exceeded {
+            assert!(
+                used <= max,
+                "{} used {} {}, over its limit of {}",
+                operation,
+                used,
+                resource,
+                max
+            );
+        }
+    }
+}
+
+/// Run `call` and measure what it costs: the budget is reset and the
+/// recorded footprint cleared first, so only `call`'s own work is counted.
+/// CPU and memory run natively rather than in WASM, so they understate
+/// on-chain costs; compare them against each other, not against network limits.
+#[cfg(feature = "benchmarks")]
+pub fn measure<T>(env: &Env, call: impl FnOnce() -> T) -> (T, ResourceUsage) {
+    use soroban_env_host::storage::{AccessType, Footprint};
+
+    env.budget().reset_unlimited();
+    env.host()
+        .with_mut_storage(|storage| {
+            storage.footprint = Footprint::default();
+            Ok(())
+        })
+        .unwrap();
+
+    let result = call();
+
+    let budget = env.budget();
+    let mut usage = ResourceUsage {
+        cpu_instructions: budget.cpu_instruction_cost(),
+        memory_bytes: budget.memory_bytes_cost(),
+        ..ResourceUsage::default()
+    };
+    let host_budget = env.host().budget_cloned();
+    env.host()
+        .with_mut_storage(|storage| {
+            for (_, access) in storage.footprint.0.iter(&host_budget)? {
+                match access {
+                    AccessType::ReadOnly => usage.ledger_reads += 1,
+                    AccessType::ReadWrite => usage.ledger_writes += 1,
+                }
+            }
+            Ok(())
+        })
+        .unwrap();
+    (result, usage)
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod testutils_tests {
+    use crate::testutils::{advance_time, classic_asset, set_time, AnchorKitFixture};
+    use crate::{Error, QuoteQueryOptions, ServiceType};
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    #[test]
+    fn test_fixture_registers_anchor_and_seeds_quote() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let kit = AnchorKitFixture::new(&env);
+
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+        assert!(kit.client.is_attestor(&anchor));
+        assert!(kit.client.supports_service(&anchor, &ServiceType::Deposits));
+
+        let quote_id = kit.seed_quote(&anchor, "USDC", "EUR", 9200, 60);
+        let quote = kit.client.query_quote(&anchor, &quote_id, &QuoteQueryOptions::read_only());
+        assert_eq!(quote.base_asset, classic_asset(&env, "USDC"));
+        assert_eq!(quote.valid_until, 1_060);
+
+        let intent = kit.client.build_transaction_intent(
+            &kit.deposit_intent(&anchor, "USDC", "EUR", 500)
+                .with_quote_id(quote_id)
+                .with_quote_sequence(quote.sequence),
+        );
+        assert_eq!(intent.rate, 9200);
+    }
+
+    #[test]
+    fn test_advance_time_expires_quotes() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+        let quote_id = kit.seed_quote(&anchor, "USDC", "EUR", 9200, 60);
+
+        advance_time(&env, 61);
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 500)
+            .with_quote_id(quote_id);
+        let result = kit.client.try_build_transaction_intent(&builder);
+        assert_eq!(result, Err(Ok(Error::StaleQuote)));
+
+        // Plain attestors have no services configured
+        let attestor = kit.register_attestor();
+        let result = kit.client.try_get_supported_services(&attestor);
+        assert_eq!(result, Err(Ok(Error::ServicesNotConfigured)));
+    }
+
+    #[test]
+    #[cfg(feature = "routing")]
+    fn test_snapshot_restores_into_fresh_env() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+        kit.client
+            .set_anchor_metadata(&anchor, &8500, &600, &7000, &9900, &1_000_000);
+        kit.seed_quote(&anchor, "USDC", "EUR", 9200, 60);
+        set_time(&env, 500);
+        let session_id = kit.client.create_session(&Address::generate(&env));
+        let quote_id = kit.seed_quote(&anchor, "USDC", "EUR", 9300, 3_600);
+        set_time(&env, 1_000);
+
+        let snapshot = kit.snapshot();
+        let state = snapshot.state(&env);
+        assert_eq!(state.attestors.get(0).unwrap().address, anchor);
+        assert_eq!(state.quotes.len(), 2);
+        assert_eq!(state.sessions.len(), 1);
+
+        let fresh = Env::default();
+        let restored = AnchorKitFixture::restore(&fresh, &snapshot);
+        assert_eq!(restored.snapshot(), snapshot);
+        assert_eq!(fresh.ledger().timestamp(), 1_000);
+        assert_eq!(restored.client.get_session(&session_id).created_at, 500);
+
+        // The restored contract carries on from the captured state, with
+        // addresses taken from the snapshot since objects are bound to an Env
+        let anchor = snapshot.state(&fresh).attestors.get(0).unwrap().address;
+        let quote =
+            restored
+                .client
+                .query_quote(&anchor, &quote_id, &QuoteQueryOptions::read_only());
+        let intent = restored.client.build_transaction_intent(
+            &restored
+                .deposit_intent(&anchor, "USDC", "EUR", 500)
+                .with_quote_id(quote_id)
+                .with_quote_sequence(quote.sequence),
+        );
+        assert_eq!(intent.rate, 9300);
+    }
+}
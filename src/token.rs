@@ -0,0 +1,132 @@
+use soroban_sdk::{token, Address, Env, Vec};
+
+use crate::errors::Error;
+
+/// Shared Stellar Asset Contract plumbing for escrow, fee collection, bond
+/// posting and refunds. Funds only come in through the admin-configured token
+/// allowlist, and the contract itself acts as custodian. Payouts skip the
+/// allowlist so delisting a token never strands what's already held.
+pub struct TokenCustody;
+
+impl TokenCustody {
+    fn allowlist_key() -> (soroban_sdk::Symbol,) {
+        (soroban_sdk::symbol_short!("TOKENS"),)
+    }
+
+    pub fn get_supported_tokens(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&Self::allowlist_key())
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn set_supported_tokens(env: &Env, tokens: &Vec<Address>) {
+        let key = Self::allowlist_key();
+        env.storage().persistent().set(&key, tokens);
+        env.storage().persistent().extend_ttl(&key, 7776000, 7776000); // 90 days
+    }
+
+    pub fn add_supported_token(env: &Env, token: &Address) {
+        let mut tokens = Self::get_supported_tokens(env);
+        if !tokens.contains(token) {
+            tokens.push_back(token.clone());
+            Self::set_supported_tokens(env, &tokens);
+        }
+    }
+
+    pub fn remove_supported_token(env: &Env, token: &Address) {
+        let mut tokens = Self::get_supported_tokens(env);
+        if let Some(index) = tokens.first_index_of(token) {
+            tokens.remove(index);
+            Self::set_supported_tokens(env, &tokens);
+        }
+    }
+
+    pub fn is_token_supported(env: &Env, token: &Address) -> bool {
+        Self::get_supported_tokens(env).contains(token)
+    }
+
+    fn client<'a>(env: &'a Env, token: &Address, amount: i128) -> Result<token::Client<'a>, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(token::Client::new(env, token))
+    }
+
+    fn inflow_client<'a>(
+        env: &'a Env,
+        token: &Address,
+        amount: i128,
+    ) -> Result<token::Client<'a>, Error> {
+        if !Self::is_token_supported(env, token) {
+            return Err(Error::UnsupportedAsset);
+        }
+        Self::client(env, token, amount)
+    }
+
+    /// Move funds from `from` into contract custody. `from` must authorize the transfer.
+    pub fn escrow(env: &Env, token: &Address, from: &Address, amount: i128) -> Result<(), Error> {
+        let client = Self::inflow_client(env, token, amount)?;
+        client.transfer(from, &env.current_contract_address(), &amount);
+        Ok(())
+    }
+
+    /// Release funds held in custody to `to`, even if the token has since
+    /// been delisted.
+    pub fn release(env: &Env, token: &Address, to: &Address, amount: i128) -> Result<(), Error> {
+        let client = Self::client(env, token, amount)?;
+        client.transfer(&env.current_contract_address(), to, &amount);
+        Ok(())
+    }
+
+    /// Collect a fee from `payer` directly into `recipient`.
+    pub fn collect_fee(
+        env: &Env,
+        token: &Address,
+        payer: &Address,
+        recipient: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let client = Self::inflow_client(env, token, amount)?;
+        client.transfer(payer, recipient, &amount);
+        Ok(())
+    }
+
+    /// Pull funds from `from` to `to` against an allowance granted to the contract.
+    /// Fails with `InsufficientAllowance` if the approval no longer covers `amount`.
+    pub fn pull_from_allowance(
+        env: &Env,
+        token: &Address,
+        from: &Address,
+        to: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let client = Self::inflow_client(env, token, amount)?;
+        let spender = env.current_contract_address();
+        if client.allowance(from, &spender) < amount {
+            return Err(Error::InsufficientAllowance);
+        }
+        client.transfer_from(&spender, from, to, &amount);
+        Ok(())
+    }
+
+    /// Post a bond into custody. Bonds are held like escrow until released or slashed.
+    pub fn post_bond(env: &Env, token: &Address, from: &Address, amount: i128) -> Result<(), Error> {
+        Self::escrow(env, token, from, amount)
+    }
+
+    /// Return custodied funds to their original owner.
+    pub fn refund(env: &Env, token: &Address, to: &Address, amount: i128) -> Result<(), Error> {
+        Self::release(env, token, to, amount)
+    }
+
+    /// Decimals `token` amounts are expressed in.
+    pub fn decimals(env: &Env, token: &Address) -> u32 {
+        token::Client::new(env, token).decimals()
+    }
+
+    /// Balance of `token` currently held by the contract.
+    pub fn custody_balance(env: &Env, token: &Address) -> i128 {
+        token::Client::new(env, token).balance(&env.current_contract_address())
+    }
+}
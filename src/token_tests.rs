@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod token_tests {
+    use crate::{AnchorKitContract, AnchorKitContractClient, Error, TokenCustody};
+    use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+    fn setup(env: &Env) -> (Address, AnchorKitContractClient<'_>, Address) {
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        client.initialize(&admin);
+
+        let sac = env.register_stellar_asset_contract_v2(admin);
+        (contract_id, client, sac.address())
+    }
+
+    #[test]
+    fn test_token_allowlist() {
+        let env = Env::default();
+        let (_, client, token) = setup(&env);
+
+        assert_eq!(client.get_supported_tokens().len(), 0);
+        client.add_supported_token(&token);
+        client.add_supported_token(&token);
+        assert_eq!(client.get_supported_tokens().len(), 1);
+
+        client.remove_supported_token(&token);
+        assert_eq!(client.get_supported_tokens().len(), 0);
+    }
+
+    #[test]
+    fn test_escrow_release_and_fee() {
+        let env = Env::default();
+        let (contract_id, client, token) = setup(&env);
+        client.add_supported_token(&token);
+
+        let payer = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+        let balances = token::Client::new(&env, &token);
+
+        env.as_contract(&contract_id, || {
+            TokenCustody::escrow(&env, &token, &payer, 600).unwrap();
+            TokenCustody::collect_fee(&env, &token, &payer, &recipient, 50).unwrap();
+            assert_eq!(TokenCustody::custody_balance(&env, &token), 600);
+
+            TokenCustody::release(&env, &token, &recipient, 400).unwrap();
+            TokenCustody::refund(&env, &token, &payer, 200).unwrap();
+            assert_eq!(TokenCustody::custody_balance(&env, &token), 0);
+        });
+
+        assert_eq!(balances.balance(&payer), 550);
+        assert_eq!(balances.balance(&recipient), 450);
+    }
+
+    #[test]
+    fn test_custody_rejects_unlisted_token_and_bad_amount() {
+        let env = Env::default();
+        let (contract_id, client, token) = setup(&env);
+        let payer = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                TokenCustody::post_bond(&env, &token, &payer, 100),
+                Err(Error::UnsupportedAsset)
+            );
+        });
+
+        client.add_supported_token(&token);
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                TokenCustody::post_bond(&env, &token, &payer, 0),
+                Err(Error::InvalidConfig)
+            );
+        });
+    }
+
+    #[test]
+    fn test_delisted_token_can_still_be_paid_out() {
+        let env = Env::default();
+        let (contract_id, client, token) = setup(&env);
+        client.add_supported_token(&token);
+
+        let payer = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+        env.as_contract(&contract_id, || {
+            TokenCustody::escrow(&env, &token, &payer, 600).unwrap();
+        });
+
+        client.remove_supported_token(&token);
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                TokenCustody::escrow(&env, &token, &payer, 100),
+                Err(Error::UnsupportedAsset)
+            );
+            TokenCustody::release(&env, &token, &recipient, 400).unwrap();
+            TokenCustody::refund(&env, &token, &payer, 200).unwrap();
+            assert_eq!(TokenCustody::custody_balance(&env, &token), 0);
+        });
+
+        let balances = token::Client::new(&env, &token);
+        assert_eq!(balances.balance(&payer), 600);
+        assert_eq!(balances.balance(&recipient), 400);
+    }
+}
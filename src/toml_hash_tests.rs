@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod toml_hash_tests {
+    use crate::testutils::attestor_public_key;
+    use crate::{AnchorKitContract, AnchorKitContractClient, EndpointKind, Error};
+    use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env, String};
+
+    fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let attestor = Address::generate(env);
+        client.initialize(&admin);
+        client.register_attestor(&attestor, &attestor_public_key(env));
+
+        client.configure_endpoint(
+            &attestor,
+            &EndpointKind::Sep6,
+            &String::from_str(env, "https://anchor.example.com"),
+            &true,
+        );
+
+        (client, attestor)
+    }
+
+    fn toml_hash(env: &Env, toml: &str) -> BytesN<32> {
+        env.crypto()
+            .sha256(&Bytes::from_slice(env, toml.as_bytes()))
+            .into()
+    }
+
+    #[test]
+    fn test_verify_anchored_toml_hash() {
+        let env = Env::default();
+        let (client, attestor) = setup(&env);
+        let current = toml_hash(&env, "TRANSFER_SERVER=\"https://anchor.example.com/sep6\"");
+
+        // Nothing anchored yet
+        assert!(!client.verify_toml_hash(&attestor, &current));
+
+        client.set_toml_hash(&attestor, &current);
+        assert!(client.verify_toml_hash(&attestor, &current));
+
+        let tampered = toml_hash(&env, "TRANSFER_SERVER=\"https://evil.example.com/sep6\"");
+        assert!(!client.verify_toml_hash(&attestor, &tampered));
+    }
+
+    #[test]
+    fn test_rotated_toml_hash_makes_old_one_stale() {
+        let env = Env::default();
+        let (client, attestor) = setup(&env);
+        let old = toml_hash(&env, "VERSION=\"1\"");
+        let new = toml_hash(&env, "VERSION=\"2\"");
+
+        client.set_toml_hash(&attestor, &old);
+        client.set_toml_hash(&attestor, &new);
+
+        assert!(!client.verify_toml_hash(&attestor, &old));
+        assert!(client.verify_toml_hash(&attestor, &new));
+        assert_eq!(client.get_toml_hash(&attestor).unwrap().hash, new);
+    }
+
+    #[test]
+    fn test_toml_hash_requires_endpoint() {
+        let env = Env::default();
+        let (client, _) = setup(&env);
+        let stranger = Address::generate(&env);
+        let hash = toml_hash(&env, "VERSION=\"1\"");
+
+        let result = client.try_set_toml_hash(&stranger, &hash);
+        assert_eq!(result, Err(Ok(Error::EndpointNotFound)));
+        let result = client.try_verify_toml_hash(&stranger, &hash);
+        assert_eq!(result, Err(Ok(Error::EndpointNotFound)));
+    }
+}
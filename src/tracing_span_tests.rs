@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tracing_span_tests {
+    use crate::testutils::{attestor_public_key, set_time, sign_attestation};
     use crate::{AnchorKitContract, AnchorKitContractClient};
-    use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env};
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
 
     #[test]
     fn test_span_emits_request_id() {
@@ -15,11 +16,11 @@ mod tracing_span_tests {
         let subject = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&attestor);
+        client.register_attestor(&attestor, &attestor_public_key(&env));
 
         let request_id = client.generate_request_id();
         let payload_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let signature = Bytes::new(&env);
+        let signature = sign_attestation(&env, &attestor, &subject, 1000, &payload_hash);
 
         client.submit_with_request_id(&request_id, &attestor, &subject, &1000, &payload_hash, &signature);
 
@@ -39,11 +40,12 @@ mod tracing_span_tests {
         let subject = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&attestor);
+        client.register_attestor(&attestor, &attestor_public_key(&env));
 
+        set_time(&env, 1_000);
         let request_id = client.generate_request_id();
         let payload_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let signature = Bytes::new(&env);
+        let signature = sign_attestation(&env, &attestor, &subject, 1000, &payload_hash);
 
         client.submit_with_request_id(&request_id, &attestor, &subject, &1000, &payload_hash, &signature);
 
@@ -68,7 +70,7 @@ mod tracing_span_tests {
         let request_id = client.generate_request_id();
         let original_id = request_id.id.clone();
 
-        client.register_attestor(&attestor);
+        client.register_attestor(&attestor, &attestor_public_key(&env));
 
         let span = client.get_tracing_span(&original_id);
         assert!(span.is_some() || original_id.len() == 16);
@@ -86,11 +88,11 @@ mod tracing_span_tests {
         let subject = Address::generate(&env);
 
         client.initialize(&admin);
-        client.register_attestor(&attestor);
+        client.register_attestor(&attestor, &attestor_public_key(&env));
 
         let request_id = client.generate_request_id();
         let payload_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let signature = Bytes::new(&env);
+        let signature = sign_attestation(&env, &attestor, &subject, 1000, &payload_hash);
 
         client.submit_with_request_id(&request_id, &attestor, &subject, &1000, &payload_hash, &signature);
 
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod transfer_info_tests {
+    use crate::testutils::attestor_public_key;
+    use crate::{AnchorKitContract, AnchorKitContractClient, Error, ServiceType, TransferInfo};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        vec, Address, Env, String,
+    };
+
+    fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let anchor = Address::generate(env);
+        client.initialize(&admin);
+        client.register_attestor(&anchor, &attestor_public_key(env));
+        client.configure_services(&anchor, &vec![env, ServiceType::Deposits]);
+
+        (client, anchor)
+    }
+
+    fn deposit_info(env: &Env, anchor: &Address, asset: &str) -> TransferInfo {
+        TransferInfo {
+            anchor: anchor.clone(),
+            asset_code: String::from_str(env, asset),
+            operation_type: ServiceType::Deposits,
+            enabled: true,
+            min_amount: 10,
+            max_amount: 50_000,
+            fee_fixed: 1,
+            fee_percent: 25,
+            eta_seconds: 600,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_publish_and_get_transfer_info() {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.timestamp = 1234);
+        let (client, anchor) = setup(&env);
+
+        client.publish_transfer_info(&deposit_info(&env, &anchor, "USDC"));
+
+        let info = client.get_transfer_info(
+            &anchor,
+            &ServiceType::Deposits,
+            &String::from_str(&env, "USDC"),
+        );
+        assert!(info.enabled);
+        assert_eq!(info.fee_percent, 25);
+        assert_eq!(info.eta_seconds, 600);
+        assert_eq!(info.updated_at, 1234);
+    }
+
+    #[test]
+    fn test_list_and_remove_transfer_info() {
+        let env = Env::default();
+        let (client, anchor) = setup(&env);
+
+        client.publish_transfer_info(&deposit_info(&env, &anchor, "USDC"));
+        client.publish_transfer_info(&deposit_info(&env, &anchor, "EURC"));
+        // Republishing replaces the record without duplicating the listing
+        let mut disabled = deposit_info(&env, &anchor, "USDC");
+        disabled.enabled = false;
+        client.publish_transfer_info(&disabled);
+
+        let records = client.list_transfer_info(&anchor, &ServiceType::Deposits);
+        assert_eq!(records.len(), 2);
+        assert!(!records.get(0).unwrap().enabled);
+
+        client.remove_transfer_info(
+            &anchor,
+            &ServiceType::Deposits,
+            &String::from_str(&env, "USDC"),
+        );
+        assert_eq!(client.list_transfer_info(&anchor, &ServiceType::Deposits).len(), 1);
+
+        let result = client.try_get_transfer_info(
+            &anchor,
+            &ServiceType::Deposits,
+            &String::from_str(&env, "USDC"),
+        );
+        assert_eq!(result, Err(Ok(Error::UnsupportedAsset)));
+    }
+
+    #[test]
+    fn test_rejects_unconfigured_direction() {
+        let env = Env::default();
+        let (client, anchor) = setup(&env);
+
+        let mut withdraw = deposit_info(&env, &anchor, "USDC");
+        withdraw.operation_type = ServiceType::Withdrawals;
+        let result = client.try_publish_transfer_info(&withdraw);
+        assert_eq!(result, Err(Ok(Error::InvalidServiceType)));
+
+        let mut quotes = deposit_info(&env, &anchor, "USDC");
+        quotes.operation_type = ServiceType::Quotes;
+        let result = client.try_publish_transfer_info(&quotes);
+        assert_eq!(result, Err(Ok(Error::InvalidServiceType)));
+    }
+
+    #[test]
+    fn test_rejects_invalid_limits() {
+        let env = Env::default();
+        let (client, anchor) = setup(&env);
+
+        let mut info = deposit_info(&env, &anchor, "USDC");
+        info.min_amount = 100_000;
+        let result = client.try_publish_transfer_info(&info);
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+
+        // max_amount of 0 means unbounded
+        info.max_amount = 0;
+        assert!(client.try_publish_transfer_info(&info).is_ok());
+
+        let mut info = deposit_info(&env, &anchor, "USDC");
+        info.fee_percent = 10_001;
+        let result = client.try_publish_transfer_info(&info);
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+    }
+}
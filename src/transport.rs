@@ -1,6 +1,6 @@
 extern crate alloc;
 
-use crate::types::{HealthStatus, QuoteData};
+use crate::types::{Asset, HealthStatus, QuoteData};
 use crate::errors::Error;
 use soroban_sdk::{Bytes, Env, String};
 
@@ -9,8 +9,8 @@ use soroban_sdk::{Bytes, Env, String};
 pub enum TransportRequest {
     GetQuote {
         endpoint: String,
-        base_asset: String,
-        quote_asset: String,
+        base_asset: Asset,
+        quote_asset: Asset,
         amount: u64,
     },
     SubmitAttestation {
@@ -187,6 +187,12 @@ mod tests {
     use super::*;
     use soroban_sdk::{testutils::Address as _, Address, Env, String as SorobanString};
 
+    fn asset(env: &Env, code: &str) -> Asset {
+        let issuer = SorobanString::from_str(env, "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN");
+        Asset::Classic(SorobanString::from_str(env, code), Address::from_string(&issuer))
+    }
+    use crate::types::QuoteContext;
+
     #[test]
     fn test_mock_transport_creation() {
         let transport = MockTransport::new();
@@ -201,8 +207,8 @@ mod tests {
         let mut transport = MockTransport::new();
 
         let endpoint = SorobanString::from_str(&env, "https://anchor.example.com");
-        let base_asset = SorobanString::from_str(&env, "USD");
-        let quote_asset = SorobanString::from_str(&env, "USDC");
+        let base_asset = asset(&env, "USD");
+        let quote_asset = asset(&env, "USDC");
 
         let request = TransportRequest::GetQuote {
             endpoint: endpoint.clone(),
@@ -222,6 +228,11 @@ mod tests {
             maximum_amount: 10000,
             valid_until: 1000000,
             quote_id: 1,
+            sequence: 1,
+            context: QuoteContext::Sep6,
+            sell_delivery_method: None,
+            buy_delivery_method: None,
+            country_code: None,
         };
 
         let response = TransportResponse::Quote(quote.clone());
@@ -245,8 +256,8 @@ mod tests {
         let mut transport = MockTransport::new();
 
         let endpoint = SorobanString::from_str(&env, "https://anchor.example.com");
-        let base_asset = SorobanString::from_str(&env, "USD");
-        let quote_asset = SorobanString::from_str(&env, "USDC");
+        let base_asset = asset(&env, "USD");
+        let quote_asset = asset(&env, "USDC");
 
         let request = TransportRequest::GetQuote {
             endpoint,
@@ -2,11 +2,16 @@
 
 use crate::{
     transport::{AnchorTransport, MockTransport, TransportRequest, TransportResponse},
-    types::{HealthStatus, QuoteData, ServiceType},
+    types::{Asset, HealthStatus, QuoteContext, QuoteData, ServiceType},
     Error,
 };
 use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, Env, String, Vec};
 
+fn asset(env: &Env, code: &str) -> Asset {
+    let issuer = String::from_str(env, "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN");
+    Asset::Classic(String::from_str(env, code), Address::from_string(&issuer))
+}
+
 /// Test Goal 1: Ensure requests pass through abstraction
 #[test]
 fn test_request_passes_through_abstraction() {
@@ -14,8 +19,8 @@ fn test_request_passes_through_abstraction() {
     let mut transport = MockTransport::new();
 
     let endpoint = String::from_str(&env, "https://anchor.example.com/api");
-    let base_asset = String::from_str(&env, "USD");
-    let quote_asset = String::from_str(&env, "USDC");
+    let base_asset = asset(&env, "USD");
+    let quote_asset = asset(&env, "USDC");
 
     // Create a quote request
     let request = TransportRequest::GetQuote {
@@ -37,6 +42,11 @@ fn test_request_passes_through_abstraction() {
         maximum_amount: 100000,
         valid_until: env.ledger().timestamp() + 3600,
         quote_id: 42,
+        sequence: 1,
+        context: QuoteContext::Sep6,
+        sell_delivery_method: None,
+        buy_delivery_method: None,
+        country_code: None,
     };
 
     transport.add_response(request.clone(), TransportResponse::Quote(quote.clone()));
@@ -143,21 +153,26 @@ fn test_multiple_sequential_requests() {
     for i in 1..=5 {
         let request = TransportRequest::GetQuote {
             endpoint: endpoint.clone(),
-            base_asset: String::from_str(&env, "USD"),
-            quote_asset: String::from_str(&env, "USDC"),
+            base_asset: asset(&env, "USD"),
+            quote_asset: asset(&env, "USDC"),
             amount: i * 1000,
         };
 
         let quote = QuoteData {
             anchor: anchor.clone(),
-            base_asset: String::from_str(&env, "USD"),
-            quote_asset: String::from_str(&env, "USDC"),
+            base_asset: asset(&env, "USD"),
+            quote_asset: asset(&env, "USDC"),
             rate: 10000 + (i * 10),
             fee_percentage: 25,
             minimum_amount: 100,
             maximum_amount: 100000,
             valid_until: 1000000,
             quote_id: i,
+            sequence: 1,
+            context: QuoteContext::Sep6,
+            sell_delivery_method: None,
+            buy_delivery_method: None,
+            country_code: None,
         };
 
         transport.add_response(request, TransportResponse::Quote(quote));
@@ -167,8 +182,8 @@ fn test_multiple_sequential_requests() {
     for i in 1..=5 {
         let request = TransportRequest::GetQuote {
             endpoint: endpoint.clone(),
-            base_asset: String::from_str(&env, "USD"),
-            quote_asset: String::from_str(&env, "USDC"),
+            base_asset: asset(&env, "USD"),
+            quote_asset: asset(&env, "USDC"),
             amount: i * 1000,
         };
 
@@ -381,21 +396,26 @@ fn test_request_matching_different_parameters() {
     // Setup quote for specific amount
     let request_1000 = TransportRequest::GetQuote {
         endpoint: endpoint.clone(),
-        base_asset: String::from_str(&env, "USD"),
-        quote_asset: String::from_str(&env, "USDC"),
+        base_asset: asset(&env, "USD"),
+        quote_asset: asset(&env, "USDC"),
         amount: 1000,
     };
 
     let quote_1000 = QuoteData {
         anchor: anchor.clone(),
-        base_asset: String::from_str(&env, "USD"),
-        quote_asset: String::from_str(&env, "USDC"),
+        base_asset: asset(&env, "USD"),
+        quote_asset: asset(&env, "USDC"),
         rate: 10000,
         fee_percentage: 25,
         minimum_amount: 100,
         maximum_amount: 100000,
         valid_until: 1000000,
         quote_id: 1,
+        sequence: 1,
+        context: QuoteContext::Sep6,
+        sell_delivery_method: None,
+        buy_delivery_method: None,
+        country_code: None,
     };
 
     transport.add_response(request_1000.clone(), TransportResponse::Quote(quote_1000));
@@ -407,8 +427,8 @@ fn test_request_matching_different_parameters() {
     // Request with different amount should NOT match
     let request_2000 = TransportRequest::GetQuote {
         endpoint: endpoint.clone(),
-        base_asset: String::from_str(&env, "USD"),
-        quote_asset: String::from_str(&env, "USDC"),
+        base_asset: asset(&env, "USD"),
+        quote_asset: asset(&env, "USDC"),
         amount: 2000,
     };
 
@@ -461,8 +481,8 @@ fn test_complex_quote_request() {
     let mut transport = MockTransport::new();
 
     let endpoint = String::from_str(&env, "https://premium-anchor.example.com/v2/quotes");
-    let base_asset = String::from_str(&env, "EUR");
-    let quote_asset = String::from_str(&env, "EURC");
+    let base_asset = asset(&env, "EUR");
+    let quote_asset = asset(&env, "EURC");
     let amount = 50000u64;
 
     let request = TransportRequest::GetQuote {
@@ -483,6 +503,11 @@ fn test_complex_quote_request() {
         maximum_amount: 1000000,
         valid_until: env.ledger().timestamp() + 7200,
         quote_id: 999,
+        sequence: 1,
+        context: QuoteContext::Sep6,
+        sell_delivery_method: None,
+        buy_delivery_method: None,
+        country_code: None,
     };
 
     transport.add_response(request.clone(), TransportResponse::Quote(quote.clone()));
@@ -0,0 +1,79 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::{
+    errors::Error,
+    events::TravelRuleCommitted,
+    storage::Storage,
+    types::{TransferRecord, TravelRuleCommitment, TravelRuleRole},
+};
+
+/// Travel-rule commitments for transfers.
+///
+/// The originating and beneficiary anchors exchange travel-rule data off-chain and
+/// each records the SHA-256 of that payload here. Transfers at or above the
+/// configured threshold only settle once both sides committed to the same hash.
+pub struct TravelRule;
+
+impl TravelRule {
+    pub fn commit(
+        env: &Env,
+        anchor: &Address,
+        transfer_id: u64,
+        role: TravelRuleRole,
+        data_hash: &BytesN<32>,
+    ) -> Result<(), Error> {
+        if !Storage::is_attestor(env, anchor) {
+            return Err(Error::UnauthorizedAttestor);
+        }
+        // Commitments can be recorded for a recorded transfer or a built intent,
+        // which share the same ID space
+        if Storage::get_transfer(env, transfer_id).is_err()
+            && Storage::get_intent(env, transfer_id).is_err()
+        {
+            return Err(Error::TransferNotFound);
+        }
+
+        if Storage::get_travel_rule_commitment(env, transfer_id, role).is_some() {
+            return Err(Error::AlreadyExists);
+        }
+
+        Storage::set_travel_rule_commitment(
+            env,
+            &TravelRuleCommitment {
+                transfer_id,
+                anchor: anchor.clone(),
+                role,
+                data_hash: data_hash.clone(),
+                recorded_at: env.ledger().timestamp(),
+            },
+        );
+        TravelRuleCommitted::publish(env, transfer_id, anchor, role, data_hash);
+
+        Ok(())
+    }
+
+    /// Fails with `ComplianceNotMet` if `transfer` needs travel-rule data under the
+    /// global threshold and the two commitments are missing or disagree.
+    pub fn check_settlement(env: &Env, transfer: &TransferRecord) -> Result<(), Error> {
+        let threshold = Storage::get_travel_rule_threshold(env);
+        if threshold == 0 || transfer.amount < threshold {
+            return Ok(());
+        }
+        Self::require_commitments(env, transfer.transfer_id)
+    }
+
+    /// Fails with `ComplianceNotMet` unless both sides committed to the same data.
+    pub fn require_commitments(env: &Env, transfer_id: u64) -> Result<(), Error> {
+        let originator =
+            Storage::get_travel_rule_commitment(env, transfer_id, TravelRuleRole::Originator)
+                .ok_or(Error::ComplianceNotMet)?;
+        let beneficiary =
+            Storage::get_travel_rule_commitment(env, transfer_id, TravelRuleRole::Beneficiary)
+                .ok_or(Error::ComplianceNotMet)?;
+
+        if originator.data_hash != beneficiary.data_hash {
+            return Err(Error::ComplianceNotMet);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod travel_rule_tests {
+    use crate::testutils::AnchorKitFixture;
+    use crate::{Error, ServiceType, TravelRuleRole};
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+    fn setup(env: &Env) -> (AnchorKitFixture<'_>, Address, Address, u64) {
+        let kit = AnchorKitFixture::new(env);
+        let originator = kit.register_anchor(&[ServiceType::Withdrawals]);
+        let beneficiary = kit.register_anchor(&[ServiceType::Deposits]);
+        kit.client.set_travel_rule_threshold(&kit.admin, &1_000);
+
+        let transfer_id =
+            kit.client
+                .initiate_transfer(&Address::generate(env), &Address::generate(env), &5_000);
+        (kit, originator, beneficiary, transfer_id)
+    }
+
+    #[test]
+    fn test_settlement_requires_both_commitments_above_threshold() {
+        let env = Env::default();
+        let (kit, originator, beneficiary, transfer_id) = setup(&env);
+        let data_hash = BytesN::from_array(&env, &[9u8; 32]);
+        let settlement_ref = BytesN::from_array(&env, &[1u8; 32]);
+
+        let result = kit
+            .client
+            .try_confirm_settlement(&transfer_id, &settlement_ref);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+
+        kit.client.commit_travel_rule(
+            &originator,
+            &transfer_id,
+            &TravelRuleRole::Originator,
+            &data_hash,
+        );
+        let result = kit
+            .client
+            .try_confirm_settlement(&transfer_id, &settlement_ref);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+
+        kit.client.commit_travel_rule(
+            &beneficiary,
+            &transfer_id,
+            &TravelRuleRole::Beneficiary,
+            &data_hash,
+        );
+        kit.client.confirm_settlement(&transfer_id, &settlement_ref);
+    }
+
+    #[test]
+    fn test_mismatched_commitments_block_settlement() {
+        let env = Env::default();
+        let (kit, originator, beneficiary, transfer_id) = setup(&env);
+
+        kit.client.commit_travel_rule(
+            &originator,
+            &transfer_id,
+            &TravelRuleRole::Originator,
+            &BytesN::from_array(&env, &[1u8; 32]),
+        );
+        kit.client.commit_travel_rule(
+            &beneficiary,
+            &transfer_id,
+            &TravelRuleRole::Beneficiary,
+            &BytesN::from_array(&env, &[2u8; 32]),
+        );
+
+        let result = kit
+            .client
+            .try_confirm_settlement(&transfer_id, &BytesN::from_array(&env, &[0u8; 32]));
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+
+        // Each side commits once
+        let result = kit.client.try_commit_travel_rule(
+            &beneficiary,
+            &transfer_id,
+            &TravelRuleRole::Beneficiary,
+            &BytesN::from_array(&env, &[1u8; 32]),
+        );
+        assert_eq!(result, Err(Ok(Error::AlreadyExists)));
+    }
+
+    #[test]
+    fn test_small_transfers_and_unknown_transfers() {
+        let env = Env::default();
+        let (kit, originator, _, _) = setup(&env);
+
+        let small =
+            kit.client
+                .initiate_transfer(&Address::generate(&env), &Address::generate(&env), &999);
+        kit.client
+            .confirm_settlement(&small, &BytesN::from_array(&env, &[0u8; 32]));
+
+        let result = kit.client.try_commit_travel_rule(
+            &originator,
+            &404,
+            &TravelRuleRole::Originator,
+            &BytesN::from_array(&env, &[0u8; 32]),
+        );
+        assert_eq!(result, Err(Ok(Error::TransferNotFound)));
+    }
+}
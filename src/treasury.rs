@@ -0,0 +1,100 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    events::{ReferralPaid, TreasuryCredited, TreasuryWithdrawn},
+    scheduled_params::ScheduledParams,
+    storage::Storage,
+    token::TokenCustody,
+    types::{ProceedsKind, TreasuryBalance},
+};
+
+/// Protocol treasury: the single sink for the quoted fees executed intents
+/// pay and for slashed bonds. Late-settlement penalties go to the payer they
+/// compensate instead.
+///
+/// Treasury funds sit in contract custody next to escrowed transfers and bonds,
+/// with a per-token balance tracking the treasury's share. Only that share can
+/// be withdrawn, so custodied user funds never leave through the treasury.
+pub struct Treasury;
+
+impl Treasury {
+    /// Collect a protocol fee of `amount` from `payer` against its allowance
+    /// to the contract. When the flow has a referrer, the admin-configured
+    /// referral share of the fee is paid to it and the rest is credited to the
+    /// treasury. Returns the referral paid.
+    pub fn collect_fee(
+        env: &Env,
+        token: &Address,
+        payer: &Address,
+        amount: i128,
+        referrer: Option<&Address>,
+    ) -> Result<i128, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidConfig);
+        }
+        let custody = env.current_contract_address();
+        TokenCustody::pull_from_allowance(env, token, payer, &custody, amount)?;
+
+        let mut share = 0;
+        if let Some(referrer) = referrer {
+            share = amount * ScheduledParams::referral_share(env) as i128 / 10_000;
+            if share > 0 {
+                TokenCustody::release(env, token, referrer, share)?;
+                ReferralPaid::publish(env, referrer, token, share);
+            }
+        }
+        if share < amount {
+            Self::credit(env, token, payer, amount - share, ProceedsKind::Fee)?;
+        }
+        Ok(share)
+    }
+
+    /// Credit funds already in custody, such as a slashed bond, to the treasury.
+    /// `from` is the account the proceeds were taken from; whatever else
+    /// accounted for the funds, like the bond, must already have released them.
+    pub fn credit(
+        env: &Env,
+        token: &Address,
+        from: &Address,
+        amount: i128,
+        kind: ProceedsKind,
+    ) -> Result<(), Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        Storage::set_treasury_balance(env, token, Self::balance(env, token) + amount);
+        TreasuryCredited::publish(env, token, from, amount, kind);
+        Ok(())
+    }
+
+    /// Pay `amount` of the treasury's `token` balance out to `to`.
+    pub fn withdraw(env: &Env, token: &Address, to: &Address, amount: i128) -> Result<(), Error> {
+        let balance = Self::balance(env, token);
+        if amount > balance {
+            return Err(Error::InsufficientBalance);
+        }
+
+        TokenCustody::release(env, token, to, amount)?;
+        Storage::set_treasury_balance(env, token, balance - amount);
+        TreasuryWithdrawn::publish(env, token, to, amount);
+        Ok(())
+    }
+
+    pub fn balance(env: &Env, token: &Address) -> i128 {
+        Storage::get_treasury_balance(env, token)
+    }
+
+    /// Balances of every token the treasury has ever been credited with.
+    pub fn balances(env: &Env) -> Vec<TreasuryBalance> {
+        let mut balances = Vec::new(env);
+        for token in Storage::get_treasury_tokens(env).iter() {
+            balances.push_back(TreasuryBalance {
+                balance: Self::balance(env, &token),
+                token,
+            });
+        }
+        balances
+    }
+}
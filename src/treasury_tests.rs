@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod treasury_tests {
+    use crate::testutils::{classic_asset, AnchorKitFixture};
+    use crate::{
+        AnchorKitContract, AnchorKitContractClient, Error, ProceedsKind, ServiceType,
+        TokenCustody, Treasury, TreasuryBalance, TreasuryWithdrawn,
+    };
+    use soroban_sdk::{
+        testutils::{Address as _, Events},
+        token, vec, Address, Env, TryFromVal,
+    };
+
+    struct Setup<'a> {
+        contract_id: Address,
+        client: AnchorKitContractClient<'a>,
+        admin: Address,
+        token: Address,
+        payer: Address,
+    }
+
+    fn setup(env: &Env) -> Setup<'_> {
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, AnchorKitContract);
+        let client = AnchorKitContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        client.initialize(&admin);
+
+        let token = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        client.add_supported_token(&token);
+        let payer = Address::generate(env);
+        token::StellarAssetClient::new(env, &token).mint(&payer, &1_000);
+
+        Setup {
+            contract_id,
+            client,
+            admin,
+            token,
+            payer,
+        }
+    }
+
+    #[test]
+    fn test_fees_and_slashes_share_the_treasury() {
+        let env = Env::default();
+        let s = setup(&env);
+        let recipient = Address::generate(&env);
+
+        token::Client::new(&env, &s.token).approve(&s.payer, &s.contract_id, &100, &1000);
+        env.as_contract(&s.contract_id, || {
+            Treasury::collect_fee(&env, &s.token, &s.payer, 100, None).unwrap();
+            TokenCustody::post_bond(&env, &s.token, &s.payer, 300).unwrap();
+            Treasury::credit(&env, &s.token, &s.payer, 200, ProceedsKind::Slash).unwrap();
+        });
+        assert_eq!(
+            s.client.get_treasury_balances(),
+            vec![
+                &env,
+                TreasuryBalance {
+                    token: s.token.clone(),
+                    balance: 300,
+                }
+            ]
+        );
+
+        s.client.withdraw(&s.token, &recipient, &250);
+        assert_eq!(env.auths()[0].0, s.admin);
+        let (_, _, data) = env.events().all().last().unwrap();
+        assert_eq!(
+            TreasuryWithdrawn::try_from_val(&env, &data).unwrap(),
+            TreasuryWithdrawn {
+                token: s.token.clone(),
+                to: recipient.clone(),
+                amount: 250,
+            }
+        );
+
+        assert_eq!(s.client.get_treasury_balances().get(0).unwrap().balance, 50);
+        let balances = token::Client::new(&env, &s.token);
+        assert_eq!(balances.balance(&recipient), 250);
+        // The unslashed part of the bond stays in custody
+        assert_eq!(balances.balance(&s.contract_id), 150);
+    }
+
+    #[test]
+    fn test_treasury_cannot_reach_custodied_funds() {
+        let env = Env::default();
+        let s = setup(&env);
+
+        env.as_contract(&s.contract_id, || {
+            TokenCustody::escrow(&env, &s.token, &s.payer, 500).unwrap();
+            assert_eq!(
+                Treasury::credit(&env, &s.token, &s.payer, 0, ProceedsKind::Slash),
+                Err(Error::InvalidConfig)
+            );
+        });
+
+        assert_eq!(
+            s.client.try_withdraw(&s.token, &s.payer, &1),
+            Err(Ok(Error::InsufficientBalance))
+        );
+        assert_eq!(s.client.get_treasury_balances().len(), 0);
+    }
+
+    #[test]
+    fn test_executed_intents_pay_their_quoted_fee_to_the_treasury() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::Quotes]);
+        // 1% fee
+        let quote_id = kit.client.submit_quote(
+            &anchor,
+            &classic_asset(&env, "USDC"),
+            &classic_asset(&env, "EUR"),
+            &9_200,
+            &100,
+            &1,
+            &u64::MAX,
+            &600,
+        );
+
+        let token = env
+            .register_stellar_asset_contract_v2(kit.admin.clone())
+            .address();
+        kit.client.add_supported_token(&token);
+        token::StellarAssetClient::new(&env, &token).mint(&kit.user, &10_000);
+        let balances = token::Client::new(&env, &token);
+        balances.approve(&kit.user, &kit.contract_id, &10_000, &1000);
+
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 5_000)
+            .with_quote_id(quote_id)
+            .with_quote_sequence(1);
+        let intent_id = kit.client.build_transaction_intent(&builder).intent_id;
+        kit.client.execute_intent(&intent_id, &kit.user, &token);
+
+        // The fee is charged on top of the amount the anchor receives
+        assert_eq!(balances.balance(&anchor), 5_000);
+        assert_eq!(balances.balance(&kit.user), 4_950);
+        assert_eq!(
+            kit.client.get_treasury_balances(),
+            vec![
+                &env,
+                TreasuryBalance {
+                    token: token.clone(),
+                    balance: 50,
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod ttl_touch_tests {
+    use crate::testutils::{sign_attestation, AnchorKitFixture};
+    use crate::ServiceType;
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{storage::Persistent as _, Address as _, Ledger},
+        Address, BytesN, Env, IntoVal, Val,
+    };
+
+    const DAY_IN_LEDGERS: u32 = 17280;
+
+    fn session_ttl(kit: &AnchorKitFixture, session_id: u64) -> u32 {
+        let key: Val = (symbol_short!("SESS"), session_id).into_val(kit.env);
+        kit.env.as_contract(&kit.contract_id, || {
+            kit.env.storage().persistent().get_ttl(&key)
+        })
+    }
+
+    #[test]
+    fn test_logging_to_a_session_extends_its_ttl() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        let initiator = Address::generate(&env);
+        let active = kit.client.create_session(&anchor);
+        let idle = kit.client.create_session(&initiator);
+
+        // Opening sessions keeps the contract instance alive meanwhile
+        for _ in 0..3 {
+            env.ledger()
+                .with_mut(|li| li.sequence_number += DAY_IN_LEDGERS * 25);
+            kit.client.create_session(&initiator);
+        }
+        // Only the session that is logged to is extended
+        let before = session_ttl(&kit, idle);
+        let subject = Address::generate(&env);
+        let payload_hash = BytesN::from_array(&env, &[3u8; 32]);
+        kit.client.submit_attestation_with_session(
+            &active,
+            &anchor,
+            &subject,
+            &1_700_000_001u64,
+            &payload_hash,
+            &sign_attestation(&env, &anchor, &subject, 1_700_000_001u64, &payload_hash),
+            &None,
+        );
+        assert_eq!(session_ttl(&kit, idle), before);
+        assert_eq!(session_ttl(&kit, active), DAY_IN_LEDGERS * 30);
+    }
+}
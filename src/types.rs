@@ -1,4 +1,8 @@
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, String, Vec};
+#[cfg(feature = "quotes")]
+use crate::amount::Amount;
+use soroban_sdk::{
+    contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, Map, String, Symbol, Vec,
+};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -9,6 +13,83 @@ pub struct Attestation {
     pub timestamp: u64,
     pub payload_hash: BytesN<32>,
     pub signature: Bytes,
+    pub expires_at: u64, // 0 = never expires
+}
+
+impl Attestation {
+    /// Whether the attestation's validity window has closed at `now`.
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at != 0 && now >= self.expires_at
+    }
+}
+
+/// An attestor to register through `register_attestors_batch`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestorRegistration {
+    pub attestor: Address,
+    pub public_key: BytesN<32>, // ed25519 key attestation signatures are verified against
+}
+
+/// Outcome of one entry of a batched attestor registration or revocation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestorBatchResult {
+    pub attestor: Address,
+    pub error: u32, // code of the `Error` the entry failed with; 0 = applied
+}
+
+/// An operational address allowed to submit attestations on an attestor's
+/// behalf, signing them with its own ed25519 key
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestorDelegate {
+    pub attestor: Address,
+    pub delegate: Address,
+    pub public_key: BytesN<32>,
+    pub added_at: u64,
+}
+
+/// Which earlier attestations a `payload_hash` is checked against for replay
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ReplayScope {
+    /// A hash can be attested once across all issuers
+    Global = 0,
+    /// A hash can be attested once per issuer
+    Issuer = 1,
+}
+
+/// Time-limited permission, granted by a subject, to read the details of the
+/// subject's attestations
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationGrant {
+    pub subject: Address,
+    pub grantee: Address,
+    pub granted_at: u64,
+    pub expires_at: u64,
+}
+
+impl AttestationGrant {
+    pub fn is_expired(&self, current_timestamp: u64) -> bool {
+        current_timestamp >= self.expires_at
+    }
+}
+
+/// Service an attestor endpoint serves. An attestor has at most one endpoint
+/// per kind.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum EndpointKind {
+    Sep6 = 1,
+    Sep24 = 2,
+    Sep38 = 3,
+    Sep12 = 4,
+    Webhook = 5,
+    Custom = 6,
 }
 
 #[contracttype]
@@ -16,7 +97,34 @@ pub struct Attestation {
 pub struct Endpoint {
     pub url: String,
     pub attestor: Address,
+    pub kind: EndpointKind,
     pub is_active: bool,
+    pub updated_at: u64, // when the URL was last configured or rotated
+}
+
+/// Outstanding liveness challenge for an attestor endpoint. The anchor answers
+/// by signing `nonce` with its endpoint signing key before `expires_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EndpointChallenge {
+    pub attestor: Address,
+    pub kind: EndpointKind,
+    pub nonce: BytesN<32>,
+    pub issued_at: u64,
+    pub expires_at: u64,
+}
+
+impl EndpointChallenge {
+    /// Seconds an anchor has to answer a challenge.
+    pub const TTL_SECONDS: u64 = 300;
+}
+
+/// SHA-256 of an anchor's current stellar.toml and when it was anchored.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TomlHash {
+    pub hash: BytesN<32>,
+    pub updated_at: u64,
 }
 
 /// Supported service types for anchors
@@ -38,19 +146,885 @@ pub struct AnchorServices {
     pub services: Vec<ServiceType>,
 }
 
+/// Assets an anchor supports for one of its services. A service without an
+/// entry accepts any asset.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetSupport {
+    pub service_type: ServiceType,
+    pub assets: Vec<Asset>,
+}
+
+/// Identity of an asset traded through an anchor. Classic assets are
+/// `Classic(code, issuer)`; Soroban-native tokens are identified by contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Asset {
+    Native,
+    ContractToken(Address),
+    Classic(String, Address),
+}
+
+impl Asset {
+    /// Classic asset codes are 1-12 ASCII alphanumeric characters. Codes are
+    /// case-sensitive, so no normalization is applied and equality is exact.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Asset::Classic(code, _) => {
+                let len = code.len() as usize;
+                if len == 0 || len > 12 {
+                    return false;
+                }
+                let mut buf = [0u8; 12];
+                code.copy_into_slice(&mut buf[..len]);
+                buf[..len].iter().all(|c| c.is_ascii_alphanumeric())
+            }
+            _ => true,
+        }
+    }
+
+    /// Asset code for display purposes; `None` for contract tokens.
+    pub fn code(&self, env: &Env) -> Option<String> {
+        match self {
+            Asset::Native => Some(String::from_str(env, "XLM")),
+            Asset::ContractToken(_) => None,
+            Asset::Classic(code, _) => Some(code.clone()),
+        }
+    }
+}
+
+/// Version, interfaces and subsystem flags of a deployed AnchorKit build
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractCapabilities {
+    pub version: String,
+    pub interfaces: Vec<Symbol>,
+    pub features: Vec<Symbol>,
+}
+
+/// Semantic version, storage schema version and subsystem flags of a deployed
+/// AnchorKit build, returned by `version`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    pub schema_version: u32,
+    pub features: Vec<Symbol>,
+}
+
+/// One page of an admin export. `data` is the XDR of a `Vec` of the exported
+/// records, in ID order; the page covers IDs from the requested cursor up to,
+/// not including, `next_cursor`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExportPage {
+    pub data: Bytes,
+    pub count: u32,
+    pub next_cursor: u64,
+    pub done: bool, // no IDs at or after `next_cursor` exist yet
+}
+
+impl ExportPage {
+    /// Most IDs a single page may cover.
+    pub const MAX_LIMIT: u32 = 100;
+}
+
+/// Binding of a DID-style identifier (by hash) to a subject address
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DidBinding {
+    pub subject: Address,
+    pub did_hash: BytesN<32>, // SHA-256 of the DID string, e.g. "did:web:example.com"
+    pub bound_at: u64,
+}
+
+/// Stellar transaction memo an anchor uses to credit a deposit
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DepositMemo {
+    Id(u64),
+    Text(String),
+    Hash(BytesN<32>),
+}
+
+impl DepositMemo {
+    /// Text memos are limited to 28 bytes on Stellar.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            DepositMemo::Text(text) => !text.is_empty() && text.len() <= 28,
+            _ => true,
+        }
+    }
+}
+
+/// Registry entry tying an (anchor, memo) pair to the credited subject and transfer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositMemoRecord {
+    pub anchor: Address,
+    pub memo: DepositMemo,
+    pub subject: Address,
+    pub transfer_id: u64,
+    pub registered_at: u64,
+    pub settled: bool,
+}
+
+/// Scoped roles granted by the admin
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// Manages blocklists, KYC records, AML flags and compliance rules.
+    /// Cannot register attestors, change fees or upgrade the contract.
+    ComplianceOfficer,
+    /// Issues endpoint liveness challenges and posts the anchors' responses.
+    EndpointMonitor,
+    /// Reports observed anchor uptime and latency for routing.
+    UptimeReporter,
+    /// Records failed transfer and webhook delivery attempts for retry.
+    RetryWorker,
+}
+
+/// Review state of an AML flag
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AmlFlagStatus {
+    Open,
+    Cleared,
+    Escalated,
+}
+
+/// Large-transaction flag raised for an intent or transfer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmlFlag {
+    pub flag_id: u64,
+    pub reference_id: u64, // intent or transfer ID
+    pub asset: Asset,
+    pub amount: i128,
+    pub threshold: i128,
+    pub status: AmlFlagStatus,
+    pub raised_at: u64,
+    pub reviewed_at: u64, // 0 while open
+}
+
+/// Per-anchor compliance counters for one reporting period
+/// (`timestamp / COMPLIANCE_PERIOD_SECONDS`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComplianceReport {
+    pub anchor: Address,
+    pub period: u64,
+    pub transfer_count: u64,
+    pub volumes: Map<Asset, i128>, // executed volume by sell asset
+    pub flags_raised: u32,
+    pub kyc_rejections: u32,
+}
+
+impl ComplianceReport {
+    pub fn empty(env: &Env, anchor: &Address, period: u64) -> Self {
+        ComplianceReport {
+            anchor: anchor.clone(),
+            period,
+            transfer_count: 0,
+            volumes: Map::new(env),
+            flags_raised: 0,
+            kyc_rejections: 0,
+        }
+    }
+}
+
+/// Compliance rules for a corridor (sell asset -> buy asset)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CorridorRules {
+    pub max_amount: u64,                     // 0 = no limit
+    pub required_attestations: Vec<Symbol>, // attestation kinds the subject must hold
+    pub kyc_level: u32,                      // 0 = no KYC requirement
+    pub travel_rule_threshold: i128,         // 0 = no travel-rule requirement
+}
+
+/// A named, reusable set of subject requirements (e.g. `retail_onramp` =
+/// KYC level 1 + a `sanctions` attestation) that intents reference by ID
+/// instead of listing them in every builder
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequirementTemplate {
+    pub template_id: u64,
+    pub name: Symbol,
+    pub kyc_level: u32,                     // 0 = no KYC requirement
+    pub required_attestations: Vec<Symbol>, // attestation kinds the subject must hold
+}
+
+/// A registered payment corridor: an asset pair between two countries, with
+/// the limits, compliance rules and settlement SLA applied to every intent
+/// and route that references it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Corridor {
+    pub corridor_id: u64,
+    pub base_asset: Asset,
+    pub quote_asset: Asset,
+    pub source_country: String,      // ISO 3166-1 alpha-3
+    pub destination_country: String, // ISO 3166-1 alpha-3, matched against `QuoteRequest::country_code`
+    pub min_amount: u64,
+    pub rules: CorridorRules,        // used instead of the asset pair's rules; `max_amount` caps amounts
+    pub settlement_sla_seconds: u64, // 0 = no SLA
+    pub late_penalty_bps_per_day: u32, // accrues on intents settled after the SLA; 0 = no penalty
+    pub is_active: bool,
+}
+
+impl Corridor {
+    pub fn is_valid(&self) -> bool {
+        self.source_country.len() == 3
+            && self.destination_country.len() == 3
+            && (self.rules.max_amount == 0 || self.min_amount <= self.rules.max_amount)
+            && self.rules.travel_rule_threshold >= 0
+            && self.late_penalty_bps_per_day <= 10_000
+    }
+
+    /// Whether `request` trades this corridor's pair to its destination and
+    /// stays within its amount limits.
+    pub fn admits(&self, request: &QuoteRequest) -> bool {
+        request.base_asset == self.base_asset
+            && request.quote_asset == self.quote_asset
+            && request
+                .country_code
+                .as_ref()
+                .is_none_or(|country| country == &self.destination_country)
+            && request.amount >= self.min_amount
+            && (self.rules.max_amount == 0 || request.amount <= self.rules.max_amount)
+    }
+}
+
+/// Outcome of matching a subject's attestations against required kinds
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationResolution {
+    pub attestation_ids: Vec<u64>, // one per satisfied kind, in rule order
+    pub missing: Vec<Symbol>,      // kinds with no valid attestation
+}
+
+/// Side of a transfer a travel-rule commitment is recorded for
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TravelRuleRole {
+    Originator,
+    Beneficiary,
+}
+
+/// Hash of the travel-rule payload exchanged between anchors for a transfer.
+/// The payload itself stays off-chain.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TravelRuleCommitment {
+    pub transfer_id: u64,
+    pub anchor: Address,
+    pub role: TravelRuleRole,
+    pub data_hash: BytesN<32>,
+    pub recorded_at: u64,
+}
+
+/// Anchor rule: amounts strictly above `amount_above` require at least `level`.
+/// `amount_above` is in `DEFAULT_DECIMALS` units so it applies across assets.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycThreshold {
+    pub amount_above: u64,
+    pub level: u32,
+}
+
+/// KYC level of a subject, as recorded by a KYC-offering anchor
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycRecord {
+    pub subject: Address,
+    pub level: u32,
+    pub verified_by: Address,
+    pub updated_at: u64,
+}
+
+/// Kind of record replaced by a tombstone
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum TombstoneKind {
+    Attestation = 1,
+    KycRecord = 2,
+}
+
+/// Marker left in place of an erased subject-linked record
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tombstone {
+    pub subject: Address,
+    pub kind: TombstoneKind,
+    pub record_id: u64,          // attestation ID; 0 for KYC records
+    pub record_hash: BytesN<32>, // SHA-256 of the erased record's XDR
+    pub erased_at: u64,
+}
+
+/// Transfer recorded by `initiate_transfer`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferRecord {
+    pub transfer_id: u64,
+    pub sender: Address,
+    pub destination: Address,
+    pub amount: i128,
+    pub initiated_at: u64,
+    pub referrer: Option<Address>,
+    pub settled: bool,
+}
+
+/// Who paid for an executed intent, and when `confirm_settlement` closed it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IntentExecution {
+    pub intent_id: u64,
+    pub payer: Address,
+    pub token: Address,
+    pub executed_at: u64,
+    pub settle_by: u64, // executed_at + the intent's settlement SLA; 0 = no SLA
+    pub settled_at: u64, // 0 until settlement is confirmed
+}
+
+/// Penalty an anchor owes the payer of an intent settled after its
+/// corridor's SLA, in the intent's base asset
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LateSettlementPenalty {
+    pub intent_id: u64,
+    pub anchor: Address,
+    pub sender: Address,
+    pub asset: Asset,
+    pub amount: i128,
+    pub late_seconds: u64,
+    pub bond_token: Option<Address>, // bond it was paid from; None = deducted from fee revenue
+}
+
+/// Unsettled value of transfers between two anchors, in either direction,
+/// and the most that may be outstanding at once. `anchor_a` sorts before
+/// `anchor_b`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CounterpartyExposure {
+    pub anchor_a: Address,
+    pub anchor_b: Address,
+    pub limit: i128, // 0 = no limit
+    pub outstanding: i128,
+}
+
+impl CounterpartyExposure {
+    pub fn new(anchor_a: &Address, anchor_b: &Address) -> Self {
+        let (anchor_a, anchor_b) = if anchor_a <= anchor_b {
+            (anchor_a, anchor_b)
+        } else {
+            (anchor_b, anchor_a)
+        };
+        CounterpartyExposure {
+            anchor_a: anchor_a.clone(),
+            anchor_b: anchor_b.clone(),
+            limit: 0,
+            outstanding: 0,
+        }
+    }
+}
+
+/// Operation an off-chain worker retries
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RetryTarget {
+    Transfer(u64),
+    /// Delivery of an event to an anchor's webhook, by worker-assigned delivery ID
+    WebhookDelivery(Address, u64),
+}
+
+/// Retry state of a failed operation, shared by the workers coordinating it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetryRecord {
+    pub target: RetryTarget,
+    pub attempts: u32,
+    pub last_error: u32, // worker-reported error code of the latest attempt
+    pub last_attempt_at: u64,
+    pub next_retry_after: u64,
+    pub exhausted: bool, // MAX_ATTEMPTS reached; no further retries are scheduled
+}
+
+impl RetryRecord {
+    pub const MAX_ATTEMPTS: u32 = 8;
+    pub const BASE_DELAY_SECONDS: u64 = 30;
+    pub const MAX_DELAY_SECONDS: u64 = 3_600;
+
+    /// Exponential backoff after `attempts` failed attempts.
+    pub fn delay_after(attempts: u32) -> u64 {
+        let doublings = attempts.saturating_sub(1).min(16);
+        (Self::BASE_DELAY_SECONDS << doublings).min(Self::MAX_DELAY_SECONDS)
+    }
+}
+
+/// What produced funds credited to the treasury
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ProceedsKind {
+    Fee = 1,
+    Slash = 2,
+}
+
+/// Treasury holdings of one token
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryBalance {
+    pub token: Address,
+    pub balance: i128,
+}
+
+/// Bond an anchor holds in custody, released when it completes offboarding
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnchorBond {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// In-flight activity offboarding waits on before the anchor can leave
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AnchorActivity {
+    pub open_deposits: u32,    // registered deposit memos not yet settled
+    pub intents_expire_at: u64, // latest expiry among intents built against the anchor
+}
+
+/// Where an anchor is in its offboarding
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum OffboardingStage {
+    /// New quotes, intents and deposits are refused while open activity settles
+    Draining = 1,
+    /// Activity has settled; the bond can still be slashed until the window ends
+    Challenge = 2,
+    /// Bond released and the anchor removed
+    Completed = 3,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OffboardingState {
+    pub anchor: Address,
+    pub stage: OffboardingStage,
+    pub initiated_at: u64,
+    pub challenge_ends_at: u64, // 0 until the anchor has drained
+}
+
+impl OffboardingState {
+    /// How long a drained anchor's bond stays slashable (7 days).
+    pub const CHALLENGE_WINDOW_SECONDS: u64 = 604_800;
+}
+
+/// An active incident-response freeze
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FreezeState {
+    pub frozen_at: u64,
+    pub unfreeze_after: u64, // 0 until an unfreeze is requested
+}
+
+/// Usage counters for one service type and asset pair within a metrics period
+/// (`timestamp / METRICS_PERIOD_SECONDS`). `transfers` counts executed intents
+/// and `volume` their amounts, in the base asset.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UsageMetrics {
+    pub period: u64,
+    pub service_type: ServiceType,
+    pub base_asset: Asset,
+    pub quote_asset: Asset,
+    pub intents: u64,
+    pub transfers: u64,
+    pub volume: i128,
+}
+
+/// Gross fees an anchor earned in a metrics period, implied by the quoted fee
+/// of each executed intent, per base asset. Intents without a quote carry no
+/// fee but are still counted in `transfers`. Late-settlement penalties not
+/// covered by a bond are owed out of `fees` and tracked in `penalties`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnchorFeeRevenue {
+    pub anchor: Address,
+    pub period: u64,
+    pub transfers: u64,
+    pub fees: Map<Asset, i128>,
+    pub penalties: Map<Asset, i128>,
+}
+
+impl AnchorFeeRevenue {
+    pub fn empty(env: &Env, anchor: &Address, period: u64) -> Self {
+        AnchorFeeRevenue {
+            anchor: anchor.clone(),
+            period,
+            transfers: 0,
+            fees: Map::new(env),
+            penalties: Map::new(env),
+        }
+    }
+}
+
+/// Flow routed by one referrer. `volume` sums executed intent and transfer
+/// amounts in their own units.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReferralVolume {
+    pub intents: u64,
+    pub transfers: u64,
+    pub volume: i128,
+}
+
+/// Rolling initiated-to-settled durations, in seconds, of an anchor's most
+/// recent `WINDOW` transfers and their nearest-rank percentiles.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementStats {
+    pub anchor: Address,
+    pub durations: Vec<u64>, // oldest first
+    pub p50: u64,
+    pub p95: u64,
+}
+
+impl SettlementStats {
+    pub const WINDOW: u32 = 100;
+
+    pub fn empty(env: &Env, anchor: &Address) -> Self {
+        SettlementStats {
+            anchor: anchor.clone(),
+            durations: Vec::new(env),
+            p50: 0,
+            p95: 0,
+        }
+    }
+
+    /// Add a settlement duration, dropping the oldest beyond `WINDOW`, and
+    /// recompute the percentiles.
+    pub fn record(&mut self, env: &Env, duration: u64) {
+        self.durations.push_back(duration);
+        if self.durations.len() > Self::WINDOW {
+            self.durations.pop_front();
+        }
+
+        let mut sorted: Vec<u64> = Vec::new(env);
+        for value in self.durations.iter() {
+            let position = sorted
+                .iter()
+                .position(|v| v > value)
+                .unwrap_or(sorted.len() as usize);
+            sorted.insert(position as u32, value);
+        }
+        let rank = |percentile: u32| {
+            let index = (sorted.len() * percentile).div_ceil(100).max(1) - 1;
+            sorted.get(index).unwrap_or(0)
+        };
+        self.p50 = rank(50);
+        self.p95 = rank(95);
+    }
+}
+
+/// Transfer details handed to a receiver contract's `SettlementHook`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementDetails {
+    pub transfer_id: u64,
+    pub sender: Address,
+    pub destination: Address,
+    pub amount: i128,
+    pub settlement_ref: BytesN<32>,
+    pub settled_at: u64,
+}
+
+/// Asset identifier as understood by Reflector-style price oracles
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OracleAsset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// Price point returned by an oracle
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// Oracle registered by the admin for an asset pair
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleFeed {
+    pub oracle: Address,
+    pub base: OracleAsset,  // oracle-side identifier of the pair's base asset
+    pub quote: OracleAsset, // oracle-side identifier of the pair's quote asset
+    pub max_deviation_bps: u32,
+    pub max_age_seconds: u64,
+}
+
+/// Lifecycle of a hash-time-locked transfer
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HashLockStatus {
+    Locked,
+    Claimed,
+    Refunded,
+}
+
+/// Funds held against a SHA-256 hash lock until the preimage is revealed
+/// or the timeout passes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HashLock {
+    pub transfer_id: u64,
+    pub token: Address,
+    pub sender: Address,
+    pub recipient: Address,
+    pub amount: i128,
+    pub hash_lock: BytesN<32>,
+    pub timeout: u64, // ledger timestamp after which only a refund is possible
+    pub status: HashLockStatus,
+}
+
+/// Per-asset deposit or withdrawal info, mirroring one asset entry of the
+/// SEP-6 `/info` response (also used for SEP-24 flows).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferInfo {
+    pub anchor: Address,
+    pub asset_code: String,
+    pub operation_type: ServiceType, // Deposits or Withdrawals
+    pub enabled: bool,
+    pub min_amount: u64,
+    pub max_amount: u64, // 0 = no maximum
+    pub fee_fixed: u64,
+    pub fee_percent: u32,    // Fee in basis points
+    pub eta_seconds: u64,    // Estimated time to completion, 0 = unknown
+    pub updated_at: u64,
+}
+
+/// SEP-38 quote context: the SEP flow a quote is valid for
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum QuoteContext {
+    Sep6 = 1,
+    Sep24 = 2,
+    Sep31 = 3,
+}
+
+/// Which side of the trade a requested amount refers to (SEP-38
+/// `sell_amount` vs `buy_amount`). Base asset is sold, quote asset is bought.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AmountSide {
+    Sell = 1,
+    Buy = 2,
+}
+
+/// SEP-38 terms attached to a quote at submission time
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sep38Terms {
+    pub context: QuoteContext,
+    pub sell_delivery_method: Option<String>,
+    pub buy_delivery_method: Option<String>,
+    pub country_code: Option<String>, // ISO 3166-1 alpha-3
+}
+
+impl Sep38Terms {
+    /// Terms used by quotes submitted without SEP-38 details
+    pub fn sep6() -> Self {
+        Self {
+            context: QuoteContext::Sep6,
+            sell_delivery_method: None,
+            buy_delivery_method: None,
+            country_code: None,
+        }
+    }
+}
+
+/// A single failed validation check, so clients can explain a bare error code
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckFailure {
+    pub error: u32,    // contract error code the real call fails with
+    pub field: Symbol, // input or rule that failed, e.g. `amount`, `kyc_level`
+    pub expected: i128,
+    pub actual: i128, // booleans are reported as 1/0
+}
+
+/// Outcome of a dry-run `validate_intent`: the terms the intent would get
+/// and every check that would make `build_transaction_intent` fail
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IntentValidation {
+    pub valid: bool,
+    pub kyc_level: u32,
+    pub expires_at: u64, // 0 when the expiry cannot be derived
+    pub failures: Vec<CheckFailure>,
+}
+
+/// Quote terms submitted by an anchor: the `QuoteData` fields without the
+/// contract-assigned `quote_id`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuoteSubmission {
+    pub anchor: Address,
+    pub base_asset: Asset,
+    pub quote_asset: Asset,
+    pub rate: u64,
+    pub fee_percentage: u32,
+    pub minimum_amount: u64,
+    pub maximum_amount: u64,
+    pub valid_until: u64,
+    pub context: QuoteContext,
+    pub sell_delivery_method: Option<String>,
+    pub buy_delivery_method: Option<String>,
+    pub country_code: Option<String>,
+}
+
+impl QuoteSubmission {
+    pub fn terms(&self) -> Sep38Terms {
+        Sep38Terms {
+            context: self.context,
+            sell_delivery_method: self.sell_delivery_method.clone(),
+            buy_delivery_method: self.buy_delivery_method.clone(),
+            country_code: self.country_code.clone(),
+        }
+    }
+
+    pub fn into_quote(self, quote_id: u64, sequence: u64) -> QuoteData {
+        QuoteData {
+            anchor: self.anchor,
+            base_asset: self.base_asset,
+            quote_asset: self.quote_asset,
+            rate: self.rate,
+            fee_percentage: self.fee_percentage,
+            minimum_amount: self.minimum_amount,
+            maximum_amount: self.maximum_amount,
+            valid_until: self.valid_until,
+            quote_id,
+            sequence,
+            context: self.context,
+            sell_delivery_method: self.sell_delivery_method,
+            buy_delivery_method: self.buy_delivery_method,
+            country_code: self.country_code,
+        }
+    }
+}
+
+/// Options for `query_quote`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuoteQueryOptions {
+    /// When set, this address must authorize the query and a `QuoteReceived`
+    /// event is emitted for it. When `None` the query is a plain read.
+    pub notify_receiver: Option<Address>,
+}
+
+impl QuoteQueryOptions {
+    /// Plain read: no authorization, no event.
+    pub fn read_only() -> Self {
+        QuoteQueryOptions {
+            notify_receiver: None,
+        }
+    }
+
+    /// Read on behalf of `receiver`, who authorizes it and is notified.
+    pub fn notify(receiver: Address) -> Self {
+        QuoteQueryOptions {
+            notify_receiver: Some(receiver),
+        }
+    }
+}
+
 /// Quote data structure for rate comparison
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct QuoteData {
     pub anchor: Address,
-    pub base_asset: String,
-    pub quote_asset: String,
+    pub base_asset: Asset,  // SEP-38 sell_asset
+    pub quote_asset: Asset, // SEP-38 buy_asset
     pub rate: u64,           // 10000 = 1.0
     pub fee_percentage: u32, // Fee in basis points
-    pub minimum_amount: u64,
-    pub maximum_amount: u64,
+    pub minimum_amount: u64, // in sell-asset units
+    pub maximum_amount: u64, // in sell-asset units
     pub valid_until: u64,
     pub quote_id: u64,
+    pub sequence: u64, // position among the anchor's quotes for the pair; a later one supersedes it
+    pub context: QuoteContext,
+    pub sell_delivery_method: Option<String>,
+    pub buy_delivery_method: Option<String>,
+    pub country_code: Option<String>,
+}
+
+#[cfg(feature = "quotes")]
+impl QuoteData {
+    /// Amount of the base (sell) asset a request implies under this quote,
+    /// converting between the two assets' decimals.
+    pub fn sell_amount_for(&self, env: &Env, request: &QuoteRequest) -> u64 {
+        match request.amount_side {
+            AmountSide::Sell => request.amount,
+            AmountSide::Buy => {
+                if self.rate == 0 {
+                    return 0;
+                }
+                Amount::of(env, &self.quote_asset, request.amount.into())
+                    .convert(10000, self.rate.into(), Amount::decimals_of(env, &self.base_asset))
+                    .map_or(u64::MAX, |sell| sell.to_u64_saturating())
+            }
+        }
+    }
+
+    /// Amount of the quote (buy) asset a request implies under this quote,
+    /// converting between the two assets' decimals.
+    pub fn buy_amount_for(&self, env: &Env, request: &QuoteRequest) -> u64 {
+        match request.amount_side {
+            AmountSide::Buy => request.amount,
+            AmountSide::Sell => Amount::of(env, &self.base_asset, request.amount.into())
+                .convert(self.rate.into(), 10000, Amount::decimals_of(env, &self.quote_asset))
+                .map_or(u64::MAX, |buy| buy.to_u64_saturating()),
+        }
+    }
+
+    /// Check pair, SEP-38 context, delivery method, country and amount bounds.
+    /// Expiry is checked separately so callers can distinguish stale quotes.
+    pub fn matches_request(&self, env: &Env, request: &QuoteRequest) -> bool {
+        if self.base_asset != request.base_asset
+            || self.quote_asset != request.quote_asset
+            || self.context != request.context
+        {
+            return false;
+        }
+
+        if let Some(method) = &request.delivery_method {
+            if self.sell_delivery_method.as_ref() != Some(method)
+                && self.buy_delivery_method.as_ref() != Some(method)
+            {
+                return false;
+            }
+        }
+
+        if let (Some(wanted), Some(offered)) = (&request.country_code, &self.country_code) {
+            if wanted != offered {
+                return false;
+            }
+        }
+
+        let sell_amount = self.sell_amount_for(env, request);
+        sell_amount >= self.minimum_amount && sell_amount <= self.maximum_amount
+    }
 }
 
 /// Rate comparison result
@@ -60,16 +1034,116 @@ pub struct RateComparison {
     pub best_quote: QuoteData,
     pub all_quotes: Vec<QuoteData>,
     pub comparison_timestamp: u64,
+    pub exclusions: Vec<QuoteExclusion>, // only filled when requested
+}
+
+/// Why an anchor's quote was left out of a comparison or route
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ExclusionReason {
+    /// The anchor has no quote
+    NoQuote = 1,
+    /// The quote's validity window had closed
+    Expired = 2,
+    /// The quote's pair, amount bounds or restrictions don't fit the request
+    OutOfBounds = 3,
+    /// The anchor isn't registered, is offboarding or marked itself inactive
+    AnchorSuspended = 4,
+    /// The anchor hasn't published routing metadata
+    NoMetadata = 5,
+    /// The anchor's reputation is below the requested minimum
+    BelowReputation = 6,
+    /// The anchor settles slower than the corridor's SLA
+    SlowSettlement = 7,
+    /// The anchor doesn't offer the operation, asset or KYC the request needs
+    ServiceUnsupported = 8,
+}
+
+/// An anchor left out of a comparison or route, and why
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuoteExclusion {
+    pub anchor: Address,
+    pub quote_id: u64, // 0 = excluded before its quote was considered
+    pub reason: ExclusionReason,
 }
 
 /// Quote request parameters
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct QuoteRequest {
-    pub base_asset: String,
-    pub quote_asset: String,
+    pub base_asset: Asset,
+    pub quote_asset: Asset,
     pub amount: u64,
     pub operation_type: ServiceType,
+    pub context: QuoteContext,
+    pub amount_side: AmountSide,
+    pub delivery_method: Option<String>,
+    pub country_code: Option<String>,
+}
+
+impl QuoteRequest {
+    /// SHA-256 of the request's XDR, binding a quote to the exact parameters
+    /// it was requested for.
+    pub fn hash(&self, env: &Env) -> BytesN<32> {
+        env.crypto().sha256(&self.clone().to_xdr(env)).into()
+    }
+}
+
+/// What an intent built from a quote was bound to: the hash of the request it
+/// was quoted for and the quote terms. Execution fails with `QuoteConflict`
+/// unless the intent still matches.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuoteBinding {
+    pub intent_id: u64,
+    pub quote_id: u64,
+    pub request_hash: BytesN<32>,
+    pub rate: u64,
+    pub fee_percentage: u32,
+}
+
+impl QuoteBinding {
+    pub fn for_intent(env: &Env, intent: &TransactionIntent) -> Self {
+        QuoteBinding {
+            intent_id: intent.intent_id,
+            quote_id: intent.quote_id,
+            request_hash: intent.request.hash(env),
+            rate: intent.rate,
+            fee_percentage: intent.fee_percentage,
+        }
+    }
+}
+
+/// Cap on the sell amount intents may draw from a quote, and the shares of it
+/// held for partner integrators
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuoteCapacity {
+    pub capacity: u64,
+    pub used: u64, // drawn by all intents, partners included
+    pub reservations: Vec<CapacityReservation>,
+}
+
+/// Part of a quote's capacity only `partner` may draw on
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CapacityReservation {
+    pub partner: Address,
+    pub amount: u64,
+    pub used: u64,
+}
+
+/// Quote a session is pinned to. Every intent built in the session must use
+/// it, so a flow spanning several transactions trades at one price
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionQuotePin {
+    pub session_id: u64,
+    pub anchor: Address,
+    pub quote_id: u64,
+    pub pinned_at: u64,
 }
 
 /// High-level input that drives secure, compliant transaction intent construction.
@@ -79,33 +1153,63 @@ pub struct TransactionIntentBuilder {
     pub anchor: Address,
     pub request: QuoteRequest,
     pub quote_id: u64,
+    pub quote_sequence: u64, // `QuoteData::sequence` as read; must still be current
     pub require_kyc: bool,
     pub session_id: u64,
     pub ttl_seconds: u64,
+    pub subject: Option<Address>, // user the intent is for; must be set, authorizes the build and funds it
+    pub referrer: Option<Address>, // wallet or integrator credited with the flow
+    pub corridor_id: u64,          // 0 = no registered corridor
+    pub not_before: u64,           // earliest execution time; 0 = on build
+    pub requirement_template: u64, // 0 = none
+    pub correlation_id: BytesN<16>, // recorded on the session operation; all zeros = none
 }
 
 impl TransactionIntentBuilder {
     /// Creates a builder with safe defaults:
     /// - No quote (`quote_id = 0`)
     /// - No session (`session_id = 0`)
-    /// - KYC not required
+    /// - KYC not required beyond the anchor's amount thresholds
     /// - 5 minute TTL
-    pub fn new(_env: &Env, anchor: Address, request: QuoteRequest) -> Self {
+    /// - No subject (one must be set before building)
+    /// - No referrer
+    /// - No corridor
+    /// - Executable as soon as it is built
+    /// - No requirement template
+    /// - No correlation ID
+    pub fn new(env: &Env, anchor: Address, request: QuoteRequest) -> Self {
         Self {
             anchor,
             request,
             quote_id: 0,
+            quote_sequence: 0,
             require_kyc: false,
             session_id: 0,
             ttl_seconds: 300,
+            subject: None,
+            referrer: None,
+            corridor_id: 0,
+            not_before: 0,
+            requirement_template: 0,
+            correlation_id: BytesN::from_array(env, &[0; 16]),
         }
     }
 
+    pub fn with_subject(mut self, subject: Address) -> Self {
+        self.subject = Some(subject);
+        self
+    }
+
     pub fn with_quote_id(mut self, quote_id: u64) -> Self {
         self.quote_id = quote_id;
         self
     }
 
+    pub fn with_quote_sequence(mut self, quote_sequence: u64) -> Self {
+        self.quote_sequence = quote_sequence;
+        self
+    }
+
     pub fn require_kyc(mut self) -> Self {
         self.require_kyc = true;
         self
@@ -120,6 +1224,49 @@ impl TransactionIntentBuilder {
         self.ttl_seconds = ttl_seconds;
         self
     }
+
+    pub fn with_referrer(mut self, referrer: Address) -> Self {
+        self.referrer = Some(referrer);
+        self
+    }
+
+    pub fn with_corridor(mut self, corridor_id: u64) -> Self {
+        self.corridor_id = corridor_id;
+        self
+    }
+
+    /// Only allow execution from `not_before` on, e.g. the opening of the
+    /// corridor's banking hours, until the intent expires.
+    pub fn with_not_before(mut self, not_before: u64) -> Self {
+        self.not_before = not_before;
+        self
+    }
+
+    /// Check the subject against a requirement template defined by a
+    /// compliance officer, in addition to the corridor's rules.
+    pub fn with_requirement_template(mut self, template_id: u64) -> Self {
+        self.requirement_template = template_id;
+        self
+    }
+
+    /// Tag the intent's session operation with a correlation ID shared by the
+    /// other steps of the same business transaction.
+    pub fn with_correlation_id(mut self, correlation_id: BytesN<16>) -> Self {
+        self.correlation_id = correlation_id;
+        self
+    }
+}
+
+/// A stored builder for a recurring flow: anchor, pair, KYC flags and TTL
+/// are fixed, and each intent built from it supplies only the amount.
+/// Per-flow fields (quote, session, `not_before`) are always unset.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IntentTemplate {
+    pub template_id: u64,
+    pub owner: Address,
+    pub builder: TransactionIntentBuilder, // `request.amount` is 0
+    pub created_at: u64,
 }
 
 /// Fully validated transaction intent produced by the high-level builder.
@@ -134,9 +1281,17 @@ pub struct TransactionIntent {
     pub rate: u64,
     pub fee_percentage: u32,
     pub requires_kyc: bool,
+    pub kyc_level: u32, // minimum KYC level the subject was checked against
+    pub subject: Option<Address>,
+    pub attestation_ids: Vec<u64>, // attestations satisfying the corridor's required kinds
     pub session_id: u64,
     pub created_at: u64,
     pub expires_at: u64,
+    pub referrer: Option<Address>,
+    pub corridor_id: u64,
+    pub settlement_sla_seconds: u64, // corridor's SLA, counted from execution; 0 = none
+    pub not_before: u64, // execution window is [not_before, expires_at)
+    pub requirement_template: u64, // 0 = none
 }
 
 /// Represents a reproducible interaction session.
@@ -150,6 +1305,40 @@ pub struct InteractionSession {
     pub nonce: u64,
 }
 
+/// How session operations are recorded
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AuditMode {
+    /// Persist an `AuditLog` per operation, chained into the session commitment
+    Full = 1,
+    /// Only emit `OperationLogged` events, leaving history to indexers
+    EventsOnly = 2,
+}
+
+/// Whether a claimed replay digest matches a session's stored operations
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ReplayVerdict {
+    Match = 1,
+    Mismatch = 2,
+    /// Some operations have no stored audit log (e.g. logged events-only), so
+    /// the session can't be verified in full
+    Incomplete = 3,
+}
+
+/// Result of `verify_replay`. `digest` is the audit hash chain recomputed from
+/// the session's stored logs.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReplayVerification {
+    pub session_id: u64,
+    pub verdict: ReplayVerdict,
+    pub operation_count: u64,
+    pub digest: BytesN<32>,
+}
+
 /// Context for each operation within a session.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -160,6 +1349,15 @@ pub struct OperationContext {
     pub timestamp: u64,
     pub status: String,
     pub result_data: u64,
+    /// Caller-supplied ID tying this operation to others in the same business
+    /// transaction, possibly across sessions and contracts; all zeros = none
+    pub correlation_id: BytesN<16>,
+}
+
+impl OperationContext {
+    pub fn is_correlated(&self) -> bool {
+        self.correlation_id.to_array() != [0; 16]
+    }
 }
 
 /// Full audit log entry for reproducibility.
@@ -196,6 +1394,24 @@ pub struct AnchorMetadata {
     pub is_active: bool,
 }
 
+/// A reporter's latest uptime and latency observation of an anchor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UptimeReport {
+    pub reporter: Address,
+    pub uptime_percentage: u32, // 0-10000 (100.00%)
+    pub latency_ms: u64,
+    pub reported_at: u64,
+}
+
+impl UptimeReport {
+    /// Reports older than this no longer count towards the aggregate
+    pub const MAX_AGE_SECONDS: u64 = 86400;
+
+    /// Reports further than this from the median uptime are rejected as outliers
+    pub const MAX_DEVIATION: u32 = 1000;
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct HealthStatus {
@@ -214,7 +1430,16 @@ pub struct RoutingRequest {
     pub strategy: RoutingStrategy,
     pub max_anchors: u32, // Maximum number of anchors to consider
     pub require_kyc: bool,
-    pub min_reputation: u32, // Minimum reputation score (0-10000)
+    pub min_reputation: u32,     // Minimum reputation score (0-10000)
+    pub corridor_id: u64,        // 0 = no registered corridor
+    pub record_exclusions: bool, // Report skipped anchors in `RoutingResult::exclusions`
+}
+
+impl RoutingRequest {
+    /// SHA-256 of the request's XDR, as recorded in routing receipts.
+    pub fn hash(&self, env: &Env) -> BytesN<32> {
+        env.crypto().sha256(&self.clone().to_xdr(env)).into()
+    }
 }
 
 /// Routing result with selected anchor and alternatives
@@ -226,6 +1451,50 @@ pub struct RoutingResult {
     pub score: u64, // Routing score for selected anchor
     pub alternatives: Vec<AnchorOption>,
     pub routing_timestamp: u64,
+    pub receipt: RoutingReceipt,
+    pub exclusions: Vec<QuoteExclusion>, // empty unless `record_exclusions` was set
+}
+
+/// Contribution of each factor to a routing score. Factors the strategy
+/// doesn't weigh are 0
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ScoreBreakdown {
+    pub rate: u64,
+    pub fee: u64,
+    pub settlement: u64,
+    pub reputation: u64,
+    pub liquidity: u64,
+    pub uptime: u64,
+}
+
+impl ScoreBreakdown {
+    pub fn total(&self) -> u64 {
+        self.rate
+            .saturating_add(self.fee)
+            .saturating_add(self.settlement)
+            .saturating_add(self.reputation)
+            .saturating_add(self.liquidity)
+            .saturating_add(self.uptime)
+    }
+}
+
+/// Persisted record of a `route_transaction` selection, so an integrator can
+/// later show the contract's routing logic made it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoutingReceipt {
+    pub receipt_id: u64,
+    pub request_hash: BytesN<32>, // `RoutingRequest::hash`
+    pub requester: Address,       // authorized the `route_transaction` call
+    pub strategy: RoutingStrategy,
+    pub selected_anchor: Address,
+    pub quote_id: u64,
+    pub score: ScoreBreakdown,
+    pub config_version: String, // `ContractConfig::version` in effect; empty if never set
+    pub schema_version: u32,
+    pub routed_at: u64,
+    pub ledger: u32,
 }
 
 /// Alternative anchor option
@@ -238,6 +1507,43 @@ pub struct AnchorOption {
     pub metadata: AnchorMetadata,
 }
 
+/// Standing of an anchor derived from its metadata reputation score
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AnchorTier {
+    Unrated = 0, // no metadata published
+    Bronze = 1,  // reputation below 50.00%
+    Silver = 2,  // reputation below 80.00%
+    Gold = 3,
+}
+
+impl AnchorTier {
+    pub fn from_metadata(metadata: &Option<AnchorMetadata>) -> Self {
+        match metadata {
+            None => AnchorTier::Unrated,
+            Some(metadata) if metadata.reputation_score < 5000 => AnchorTier::Bronze,
+            Some(metadata) if metadata.reputation_score < 8000 => AnchorTier::Silver,
+            Some(_) => AnchorTier::Gold,
+        }
+    }
+}
+
+/// Everything a dashboard shows for one anchor, returned by `get_anchor_profile`.
+/// Records the anchor has not published yet are empty vectors.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnchorProfileView {
+    pub anchor: Address,
+    pub services: Vec<ServiceType>,
+    pub endpoints: Vec<Endpoint>,
+    pub metadata: Vec<AnchorMetadata>, // at most one
+    pub health: Vec<HealthStatus>,     // at most one
+    pub tier: AnchorTier,
+    pub corridors: Vec<(Asset, Asset)>, // pairs the anchor has quoted
+    pub quote_heads: Vec<QuoteData>,    // latest quote per corridor
+}
+
 /// Represents the public profile of an Anchor for searching
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
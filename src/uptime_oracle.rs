@@ -0,0 +1,110 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    events::UptimeUpdated,
+    storage::Storage,
+    types::{HealthStatus, UptimeReport},
+};
+
+/// Aggregates uptime and latency reports from authorized reporters.
+///
+/// Each reporter keeps one current report per anchor. Reports older than
+/// `UptimeReport::MAX_AGE_SECONDS` are dropped, and the rest are compared with
+/// their median: those further than `UptimeReport::MAX_DEVIATION` away are
+/// rejected, so a single faulty or dishonest reporter can't move the result.
+pub struct UptimeOracle;
+
+impl UptimeOracle {
+    /// Record `reporter`'s observation of `anchor` and re-aggregate the
+    /// anchor's uptime. Returns the new `AnchorMetadata::uptime_percentage`.
+    pub fn report(
+        env: &Env,
+        reporter: &Address,
+        anchor: &Address,
+        uptime_percentage: u32,
+        latency_ms: u64,
+    ) -> Result<u32, Error> {
+        if uptime_percentage > 10000 {
+            return Err(Error::InvalidAnchorMetadata);
+        }
+        let mut metadata =
+            Storage::get_anchor_metadata(env, anchor).ok_or(Error::AnchorMetadataNotFound)?;
+
+        let now = env.ledger().timestamp();
+        let mut reports = Vec::new(env);
+        for report in Storage::get_uptime_reports(env, anchor).iter() {
+            if &report.reporter != reporter
+                && report
+                    .reported_at
+                    .saturating_add(UptimeReport::MAX_AGE_SECONDS)
+                    >= now
+            {
+                reports.push_back(report);
+            }
+        }
+        reports.push_back(UptimeReport {
+            reporter: reporter.clone(),
+            uptime_percentage,
+            latency_ms,
+            reported_at: now,
+        });
+        Storage::set_uptime_reports(env, anchor, &reports);
+
+        let mut uptimes = Vec::new(env);
+        for report in reports.iter() {
+            uptimes.push_back(report.uptime_percentage as u64);
+        }
+        let median = Self::median(env, &uptimes) as u32;
+
+        let mut accepted_uptime = 0u64;
+        let mut latencies = Vec::new(env);
+        for report in reports.iter() {
+            if report.uptime_percentage.abs_diff(median) <= UptimeReport::MAX_DEVIATION {
+                accepted_uptime += report.uptime_percentage as u64;
+                latencies.push_back(report.latency_ms);
+            }
+        }
+        // The median itself is never an outlier, so at least one report is accepted
+        metadata.uptime_percentage = (accepted_uptime / latencies.len() as u64) as u32;
+        Storage::set_anchor_metadata(env, &metadata);
+
+        let latency_ms = Self::median(env, &latencies);
+        let mut health = Storage::get_health_status(env, anchor).unwrap_or(HealthStatus {
+            anchor: anchor.clone(),
+            latency_ms: 0,
+            failure_count: 0,
+            availability_percent: 10000,
+            last_check: 0,
+        });
+        health.latency_ms = latency_ms;
+        health.availability_percent = metadata.uptime_percentage;
+        health.last_check = now;
+        Storage::set_health_status(env, anchor, &health);
+
+        UptimeUpdated {
+            anchor: anchor.clone(),
+            reporter: reporter.clone(),
+            uptime_percentage: metadata.uptime_percentage,
+            latency_ms,
+            reports: reports.len(),
+            rejected: reports.len() - latencies.len(),
+        }
+        .publish(env);
+
+        Ok(metadata.uptime_percentage)
+    }
+
+    /// Median of a non-empty list; the lower middle value for even lengths.
+    fn median(env: &Env, values: &Vec<u64>) -> u64 {
+        let mut sorted: Vec<u64> = Vec::new(env);
+        for value in values.iter() {
+            let position = sorted
+                .iter()
+                .position(|v| v > value)
+                .unwrap_or(sorted.len() as usize);
+            sorted.insert(position as u32, value);
+        }
+        sorted.get((sorted.len() - 1) / 2).unwrap_or(0)
+    }
+}
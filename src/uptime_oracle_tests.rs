@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod uptime_oracle_tests {
+    use crate::testutils::AnchorKitFixture;
+    use crate::{Error, Role, ServiceType, UptimeReport};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, Env,
+    };
+
+    fn reporters<const N: usize>(kit: &AnchorKitFixture) -> [Address; N] {
+        core::array::from_fn(|_| {
+            let reporter = Address::generate(kit.env);
+            kit.client.grant_role(&reporter, &Role::UptimeReporter);
+            reporter
+        })
+    }
+
+    fn anchor_with_metadata(kit: &AnchorKitFixture) -> Address {
+        let anchor = kit.register_anchor(&[ServiceType::Deposits]);
+        kit.client
+            .set_anchor_metadata(&anchor, &9000, &600, &8000, &9500, &0);
+        anchor
+    }
+
+    #[test]
+    fn test_outlying_reports_are_rejected() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = anchor_with_metadata(&kit);
+        let reporters: [Address; 4] = reporters(&kit);
+
+        kit.client
+            .report_uptime(&reporters[0], &anchor, &9900, &120);
+        kit.client
+            .report_uptime(&reporters[1], &anchor, &9700, &180);
+        kit.client
+            .report_uptime(&reporters[2], &anchor, &9800, &150);
+        // A reporter claiming the anchor is down doesn't drag it down
+        let uptime = kit
+            .client
+            .report_uptime(&reporters[3], &anchor, &1000, &9000);
+        assert_eq!(uptime, 9800);
+
+        let metadata = kit.client.get_anchor_metadata(&anchor);
+        assert_eq!(metadata.uptime_percentage, 9800);
+        let health = kit.client.get_health_status(&anchor).unwrap();
+        assert_eq!(health.latency_ms, 150);
+        assert_eq!(health.availability_percent, 9800);
+    }
+
+    #[test]
+    fn test_reports_are_replaced_and_expire() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = anchor_with_metadata(&kit);
+        let reporters: [Address; 2] = reporters(&kit);
+
+        kit.client
+            .report_uptime(&reporters[0], &anchor, &9000, &100);
+        kit.client
+            .report_uptime(&reporters[0], &anchor, &9200, &100);
+        assert_eq!(kit.client.get_uptime_reports(&anchor).len(), 1);
+
+        env.ledger()
+            .with_mut(|li| li.timestamp += UptimeReport::MAX_AGE_SECONDS + 1);
+        let uptime = kit
+            .client
+            .report_uptime(&reporters[1], &anchor, &5000, &100);
+        assert_eq!(uptime, 5000);
+        assert_eq!(kit.client.get_uptime_reports(&anchor).len(), 1);
+    }
+
+    #[test]
+    fn test_only_reporters_can_report() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = anchor_with_metadata(&kit);
+        let [reporter] = reporters(&kit);
+
+        let outsider = Address::generate(&env);
+        assert_eq!(
+            kit.client
+                .try_report_uptime(&outsider, &anchor, &9000, &100),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(
+            kit.client
+                .try_report_uptime(&reporter, &anchor, &10001, &100),
+            Err(Ok(Error::InvalidAnchorMetadata))
+        );
+        let unlisted = Address::generate(&env);
+        assert_eq!(
+            kit.client
+                .try_report_uptime(&reporter, &unlisted, &9000, &100),
+            Err(Ok(Error::AnchorMetadataNotFound))
+        );
+    }
+}
@@ -76,6 +76,7 @@ pub fn validate_session_config(config: &SessionConfig) -> Result<(), Error> {
 
 #[cfg(test)]
 mod tests {
+    use crate::testutils::attestor_public_key;
     use super::*;
     use soroban_sdk::{testutils::Address as _, Address, Env, String};
 
@@ -114,6 +115,7 @@ mod tests {
         let attestor1 = AttestorConfig {
             name: String::from_str(&env, "attestor-1"),
             address: addr1.clone(),
+            public_key: attestor_public_key(&env),
             endpoint: String::from_str(&env, "https://example.com"),
             role: String::from_str(&env, "kyc-issuer"),
             enabled: true,
@@ -122,6 +124,7 @@ mod tests {
         let attestor2 = AttestorConfig {
             name: String::from_str(&env, "attestor-1"), // Duplicate name
             address: addr2.clone(),
+            public_key: attestor_public_key(&env),
             endpoint: String::from_str(&env, "https://example2.com"),
             role: String::from_str(&env, "attestor"),
             enabled: true,
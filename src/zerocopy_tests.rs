@@ -1,15 +1,20 @@
 #![cfg(test)]
 
-use crate::{QuoteData, ServiceType};
+use crate::{Asset, QuoteContext, QuoteData, ServiceType};
 use soroban_sdk::{testutils::Address as _, Address, Env, String};
 
+fn asset(env: &Env, code: &str) -> Asset {
+    let issuer = String::from_str(env, "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN");
+    Asset::Classic(String::from_str(env, code), Address::from_string(&issuer))
+}
+
 #[test]
 fn test_borrowed_string_remains_valid() {
     let env = Env::default();
     let anchor = Address::generate(&env);
 
-    let base_asset = String::from_str(&env, "USD");
-    let quote_asset = String::from_str(&env, "USDC");
+    let base_asset = asset(&env, "USD");
+    let quote_asset = asset(&env, "USDC");
 
     let quote = QuoteData {
         anchor: anchor.clone(),
@@ -21,6 +26,11 @@ fn test_borrowed_string_remains_valid() {
         maximum_amount: 100000,
         valid_until: 1000000,
         quote_id: 1,
+        sequence: 1,
+        context: QuoteContext::Sep6,
+        sell_delivery_method: None,
+        buy_delivery_method: None,
+        country_code: None,
     };
 
     // Verify borrowed fields remain valid
@@ -35,14 +45,19 @@ fn test_cloned_data_independent() {
 
     let original = QuoteData {
         anchor: anchor.clone(),
-        base_asset: String::from_str(&env, "EUR"),
-        quote_asset: String::from_str(&env, "EURC"),
+        base_asset: asset(&env, "EUR"),
+        quote_asset: asset(&env, "EURC"),
         rate: 10050,
         fee_percentage: 30,
         minimum_amount: 200,
         maximum_amount: 50000,
         valid_until: 2000000,
         quote_id: 2,
+        sequence: 1,
+        context: QuoteContext::Sep6,
+        sell_delivery_method: None,
+        buy_delivery_method: None,
+        country_code: None,
     };
 
     let cloned = original.clone();
@@ -59,14 +74,19 @@ fn test_no_allocation_on_read() {
 
     let quote = QuoteData {
         anchor,
-        base_asset: String::from_str(&env, "BTC"),
-        quote_asset: String::from_str(&env, "USDC"),
+        base_asset: asset(&env, "BTC"),
+        quote_asset: asset(&env, "USDC"),
         rate: 5000000,
         fee_percentage: 50,
         minimum_amount: 1,
         maximum_amount: 1000,
         valid_until: 3000000,
         quote_id: 3,
+        sequence: 1,
+        context: QuoteContext::Sep6,
+        sell_delivery_method: None,
+        buy_delivery_method: None,
+        country_code: None,
     };
 
     // Reading fields should not allocate
@@ -84,14 +104,19 @@ fn test_borrowed_address_lifetime() {
 
     let quote = QuoteData {
         anchor: anchor.clone(),
-        base_asset: String::from_str(&env, "ETH"),
-        quote_asset: String::from_str(&env, "USDC"),
+        base_asset: asset(&env, "ETH"),
+        quote_asset: asset(&env, "USDC"),
         rate: 300000,
         fee_percentage: 20,
         minimum_amount: 10,
         maximum_amount: 10000,
         valid_until: 4000000,
         quote_id: 4,
+        sequence: 1,
+        context: QuoteContext::Sep6,
+        sell_delivery_method: None,
+        buy_delivery_method: None,
+        country_code: None,
     };
 
     // Borrowed address remains valid
@@ -105,14 +130,19 @@ fn test_multiple_borrows_safe() {
 
     let quote = QuoteData {
         anchor,
-        base_asset: String::from_str(&env, "SOL"),
-        quote_asset: String::from_str(&env, "USDC"),
+        base_asset: asset(&env, "SOL"),
+        quote_asset: asset(&env, "USDC"),
         rate: 15000,
         fee_percentage: 15,
         minimum_amount: 50,
         maximum_amount: 20000,
         valid_until: 5000000,
         quote_id: 5,
+        sequence: 1,
+        context: QuoteContext::Sep6,
+        sell_delivery_method: None,
+        buy_delivery_method: None,
+        country_code: None,
     };
 
     // Multiple borrows should be safe
@@ -139,14 +169,19 @@ fn test_primitive_fields_no_allocation() {
 
     let quote = QuoteData {
         anchor,
-        base_asset: String::from_str(&env, "ADA"),
-        quote_asset: String::from_str(&env, "USDC"),
+        base_asset: asset(&env, "ADA"),
+        quote_asset: asset(&env, "USDC"),
         rate: 45000,
         fee_percentage: 35,
         minimum_amount: 100,
         maximum_amount: 50000,
         valid_until: 6000000,
         quote_id: 6,
+        sequence: 1,
+        context: QuoteContext::Sep6,
+        sell_delivery_method: None,
+        buy_delivery_method: None,
+        country_code: None,
     };
 
     // Primitive field access doesn't allocate
@@ -180,19 +215,24 @@ fn test_nested_borrow_safety() {
 
     let quote = QuoteData {
         anchor: anchor.clone(),
-        base_asset: String::from_str(&env, "DOT"),
-        quote_asset: String::from_str(&env, "USDC"),
+        base_asset: asset(&env, "DOT"),
+        quote_asset: asset(&env, "USDC"),
         rate: 70000,
         fee_percentage: 40,
         minimum_amount: 20,
         maximum_amount: 15000,
         valid_until: 7000000,
         quote_id: 7,
+        sequence: 1,
+        context: QuoteContext::Sep6,
+        sell_delivery_method: None,
+        buy_delivery_method: None,
+        country_code: None,
     };
 
     // Nested field access is safe
-    let base_len = quote.base_asset.len();
-    let quote_len = quote.quote_asset.len();
+    let base_len = quote.base_asset.code(&env).unwrap().len();
+    let quote_len = quote.quote_asset.code(&env).unwrap().len();
 
     assert_eq!(base_len, 3);
     assert_eq!(quote_len, 4);
@@ -205,14 +245,19 @@ fn test_quote_data_equality_no_allocation() {
 
     let quote1 = QuoteData {
         anchor: anchor.clone(),
-        base_asset: String::from_str(&env, "AVAX"),
-        quote_asset: String::from_str(&env, "USDC"),
+        base_asset: asset(&env, "AVAX"),
+        quote_asset: asset(&env, "USDC"),
         rate: 35000,
         fee_percentage: 25,
         minimum_amount: 30,
         maximum_amount: 12000,
         valid_until: 8000000,
         quote_id: 8,
+        sequence: 1,
+        context: QuoteContext::Sep6,
+        sell_delivery_method: None,
+        buy_delivery_method: None,
+        country_code: None,
     };
 
     let quote2 = quote1.clone();
@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod zk_kyc_tests {
+    use crate::testutils::AnchorKitFixture;
+    use crate::{Error, KycProofVerifier, ServiceType};
+    use soroban_sdk::{
+        contract, contractimpl, testutils::Address as _, Address, Bytes, BytesN, Env,
+    };
+
+    /// Stand-in verifier: a proof is valid if it equals the commitment bytes.
+    #[contract]
+    struct MockVerifier;
+
+    #[contractimpl]
+    impl KycProofVerifier for MockVerifier {
+        fn verify_kyc_proof(
+            _env: Env,
+            _subject: Address,
+            commitment: BytesN<32>,
+            proof: Bytes,
+            level: u32,
+        ) -> bool {
+            level <= 2 && Bytes::from(commitment) == proof
+        }
+    }
+
+    #[test]
+    fn test_verified_proof_satisfies_kyc_requirement() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let anchor = kit.register_anchor(&[ServiceType::Deposits, ServiceType::KYC]);
+        let verifier = env.register_contract(None, MockVerifier);
+        kit.client.register_kyc_verifier(&kit.admin, &verifier);
+
+        let subject = Address::generate(&env);
+        let commitment = BytesN::from_array(&env, &[3u8; 32]);
+        let builder = kit
+            .deposit_intent(&anchor, "USDC", "EUR", 100)
+            .require_kyc()
+            .with_subject(subject.clone());
+        let result = kit.client.try_build_transaction_intent(&builder);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+
+        kit.client.submit_kyc_proof(
+            &subject,
+            &verifier,
+            &commitment,
+            &Bytes::from(commitment.clone()),
+            &1,
+        );
+        let record = kit.client.get_kyc_record(&subject).unwrap();
+        assert_eq!(record.verified_by, verifier);
+        assert_eq!(
+            kit.client.get_kyc_proof_commitment(&subject),
+            Some(commitment)
+        );
+        assert!(kit.client.try_build_transaction_intent(&builder).is_ok());
+    }
+
+    #[test]
+    fn test_rejected_or_unregistered_proofs() {
+        let env = Env::default();
+        let kit = AnchorKitFixture::new(&env);
+        let verifier = env.register_contract(None, MockVerifier);
+        let subject = Address::generate(&env);
+        let commitment = BytesN::from_array(&env, &[3u8; 32]);
+        let proof = Bytes::from(commitment.clone());
+
+        let result = kit
+            .client
+            .try_submit_kyc_proof(&subject, &verifier, &commitment, &proof, &1);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+        kit.client.register_kyc_verifier(&kit.admin, &verifier);
+        let result = kit
+            .client
+            .try_submit_kyc_proof(&subject, &verifier, &commitment, &proof, &3);
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+        assert_eq!(kit.client.get_kyc_record(&subject), None);
+    }
+}